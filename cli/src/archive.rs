@@ -0,0 +1,365 @@
+//! Extraction of the files contained in archives.
+//!
+//! This is used by `yr scan --scan-archives` for scanning the files inside
+//! zip, tar and gzip archives. The files are extracted in memory, and nested
+//! archives (like a `.tar.gz`) are extracted recursively.
+use std::io::{Cursor, Read};
+
+use flate2::read::GzDecoder;
+
+/// Separator between the path of an archive and the path of a file within
+/// the archive, as in `archive.zip!dir/file.txt`.
+pub(crate) const PATH_SEPARATOR: char = '!';
+
+/// Limits that apply while extracting the files in an archive.
+///
+/// The limits apply to the archive as a whole, including any nested archive.
+#[derive(Clone, Debug)]
+pub(crate) struct Limits {
+    /// Maximum number of files that are extracted.
+    pub max_entries: usize,
+    /// Maximum number of bytes that are extracted, adding up the size of
+    /// all the extracted files.
+    pub max_size: u64,
+    /// Maximum nesting level for archives contained in other archives.
+    pub max_depth: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self { max_entries: 10_000, max_size: 1 << 30, max_depth: 4 }
+    }
+}
+
+/// Archive formats.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    Zip,
+    Tar,
+    Gzip,
+}
+
+impl Kind {
+    /// Determines the kind of archive by looking at the first bytes of the
+    /// data. Returns `None` if the data is not an archive.
+    fn detect(data: &[u8]) -> Option<Self> {
+        if data.starts_with(b"PK\x03\x04") {
+            Some(Self::Zip)
+        } else if data.starts_with(b"\x1f\x8b\x08") {
+            Some(Self::Gzip)
+        } else if data.get(257..262) == Some(b"ustar") {
+            Some(Self::Tar)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns true if `header` looks like the start of an archive.
+///
+/// `header` must contain at least the first 512 bytes of the file, or the
+/// whole file if it's smaller.
+pub(crate) fn is_archive(header: &[u8]) -> bool {
+    Kind::detect(header).is_some()
+}
+
+/// Calls `f` for every file contained in the archive `data`.
+///
+/// `f` receives the path of the file within the archive and its content.
+/// For files contained in nested archives, the path includes the path of
+/// each archive, separated by [`PATH_SEPARATOR`]. `name` is the name of the
+/// archive itself, which is used as the name of the file contained in a
+/// gzip archive that doesn't store the original file name.
+///
+/// Errors found while extracting the files, like a corrupted archive or
+/// an exceeded limit, are passed to `warn` together with the path of the
+/// affected file or nested archive (empty for the archive itself). They
+/// don't stop the extraction of other files, except when the limits are
+/// reached. Errors returned by `f` are returned by this function
+/// immediately.
+pub(crate) fn for_each_entry<F, W>(
+    name: &str,
+    data: &[u8],
+    limits: &Limits,
+    f: F,
+    warn: W,
+) -> anyhow::Result<()>
+where
+    F: FnMut(&str, &[u8]) -> anyhow::Result<()>,
+    W: FnMut(&str, String),
+{
+    let mut extractor = Extractor {
+        limits,
+        f,
+        warn,
+        num_entries: 0,
+        total_size: 0,
+        limit_reached: false,
+    };
+    extractor.extract("", name, data, 0)
+}
+
+struct Extractor<'a, F, W> {
+    limits: &'a Limits,
+    f: F,
+    warn: W,
+    num_entries: usize,
+    total_size: u64,
+    limit_reached: bool,
+}
+
+impl<F, W> Extractor<'_, F, W>
+where
+    F: FnMut(&str, &[u8]) -> anyhow::Result<()>,
+    W: FnMut(&str, String),
+{
+    /// Extracts the files in archive `data`, whose path is `path`.
+    fn extract(
+        &mut self,
+        path: &str,
+        name: &str,
+        data: &[u8],
+        depth: usize,
+    ) -> anyhow::Result<()> {
+        match Kind::detect(data) {
+            Some(Kind::Zip) => self.extract_zip(path, data, depth),
+            Some(Kind::Tar) => self.extract_tar(path, data, depth),
+            Some(Kind::Gzip) => self.extract_gzip(path, name, data, depth),
+            None => Ok(()),
+        }
+    }
+
+    fn extract_zip(
+        &mut self,
+        path: &str,
+        data: &[u8],
+        depth: usize,
+    ) -> anyhow::Result<()> {
+        let mut zip = match zip::ZipArchive::new(Cursor::new(data)) {
+            Ok(zip) => zip,
+            Err(err) => {
+                (self.warn)(path, format!("can't open zip archive: {err}"));
+                return Ok(());
+            }
+        };
+
+        for i in 0..zip.len() {
+            if self.limit_reached {
+                break;
+            }
+
+            let mut file = match zip.by_index(i) {
+                Ok(file) => file,
+                Err(err) => {
+                    (self.warn)(path, format!("can't extract file: {err}"));
+                    continue;
+                }
+            };
+
+            if file.is_dir() {
+                continue;
+            }
+
+            let name = file.name().to_string();
+            let entry_path = join(path, &name);
+
+            if let Some(content) = self.read(&entry_path, &mut file)? {
+                self.entry(&entry_path, &name, &content, depth)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn extract_tar(
+        &mut self,
+        path: &str,
+        data: &[u8],
+        depth: usize,
+    ) -> anyhow::Result<()> {
+        let mut offset = 0;
+        // Name of the next file, set by GNU long name headers.
+        let mut long_name = None;
+
+        while let Some(header) = data.get(offset..offset + 512) {
+            if self.limit_reached {
+                break;
+            }
+
+            // The end of the archive is marked by zeroed blocks.
+            if header.iter().all(|b| *b == 0) {
+                break;
+            }
+
+            let start = offset + 512;
+            let content = parse_octal(&header[124..136])
+                .and_then(|size| data.get(start..start.checked_add(size)?));
+
+            let Some(content) = content else {
+                (self.warn)(path, "corrupted tar archive".to_string());
+                break;
+            };
+
+            offset = start + content.len().div_ceil(512) * 512;
+
+            match header[156] {
+                // GNU extension, the file contains the name of the next file.
+                b'L' => long_name = Some(c_str(content)),
+                // Regular file.
+                b'0' | b'\0' | b'7' => {
+                    let name = long_name.take().unwrap_or_else(|| {
+                        let name = c_str(&header[0..100]);
+                        let prefix = c_str(&header[345..500]);
+                        if &header[257..262] == b"ustar" && !prefix.is_empty()
+                        {
+                            format!("{prefix}/{name}")
+                        } else {
+                            name
+                        }
+                    });
+
+                    let entry_path = join(path, &name);
+
+                    if let Some(content) = self.read(&entry_path, content)? {
+                        self.entry(&entry_path, &name, &content, depth)?;
+                    }
+                }
+                // Directories, links, and any other kind of entry.
+                _ => long_name = None,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn extract_gzip(
+        &mut self,
+        path: &str,
+        name: &str,
+        data: &[u8],
+        depth: usize,
+    ) -> anyhow::Result<()> {
+        let mut decoder = GzDecoder::new(data);
+
+        // Use the original file name if stored in the gzip header, or the
+        // name of the archive without the `.gz` extension if otherwise.
+        let name = decoder
+            .header()
+            .and_then(|header| header.filename())
+            .map(|filename| String::from_utf8_lossy(filename).into_owned())
+            .unwrap_or_else(|| {
+                let name = name.rsplit(['/', '\\']).next().unwrap_or(name);
+                if let Some(name) = name.strip_suffix(".tgz") {
+                    format!("{name}.tar")
+                } else {
+                    name.strip_suffix(".gz").unwrap_or(name).to_string()
+                }
+            });
+
+        let entry_path = join(path, &name);
+
+        if let Some(content) = self.read(&entry_path, &mut decoder)? {
+            self.entry(&entry_path, &name, &content, depth)?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads the content of a file, enforcing the limits. Returns `None` if
+    /// the file can't be read.
+    fn read<R: Read>(
+        &mut self,
+        path: &str,
+        reader: R,
+    ) -> anyhow::Result<Option<Vec<u8>>> {
+        if self.num_entries >= self.limits.max_entries {
+            self.limit_reached = true;
+            (self.warn)(
+                path,
+                format!(
+                    "maximum number of files ({}) reached, skipping the rest",
+                    self.limits.max_entries
+                ),
+            );
+            return Ok(None);
+        }
+
+        let remaining = self.limits.max_size - self.total_size;
+        let mut content = Vec::new();
+
+        // Read one byte more than the remaining size, for detecting when
+        // the file exceeds that size.
+        if let Err(err) = reader.take(remaining + 1).read_to_end(&mut content)
+        {
+            (self.warn)(path, format!("can't extract file: {err}"));
+            return Ok(None);
+        }
+
+        if content.len() as u64 > remaining {
+            self.limit_reached = true;
+            (self.warn)(
+                path,
+                format!(
+                    "maximum extracted size ({} bytes) reached, skipping the rest",
+                    self.limits.max_size
+                ),
+            );
+            return Ok(None);
+        }
+
+        self.num_entries += 1;
+        self.total_size += content.len() as u64;
+
+        Ok(Some(content))
+    }
+
+    /// Handles a file extracted from an archive.
+    fn entry(
+        &mut self,
+        path: &str,
+        name: &str,
+        content: &[u8],
+        depth: usize,
+    ) -> anyhow::Result<()> {
+        (self.f)(path, content)?;
+
+        if Kind::detect(content).is_some() {
+            if depth + 1 < self.limits.max_depth {
+                self.extract(path, name, content, depth + 1)?;
+            } else {
+                (self.warn)(
+                    path,
+                    "maximum archive nesting level reached".to_string(),
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Joins the path of an archive and the path of a file within the archive.
+fn join(archive_path: &str, path: &str) -> String {
+    if archive_path.is_empty() {
+        path.to_string()
+    } else {
+        format!("{archive_path}{PATH_SEPARATOR}{path}")
+    }
+}
+
+/// Parses a numeric field in a tar header, which contains a number in octal
+/// terminated by a space or a null character.
+fn parse_octal(field: &[u8]) -> Option<usize> {
+    let s = std::str::from_utf8(field).ok()?;
+    let s = s.trim_matches(|c: char| c == ' ' || c == '\0');
+    if s.is_empty() {
+        return Some(0);
+    }
+    usize::from_str_radix(s, 8).ok()
+}
+
+/// Returns the null-terminated string at the start of `bytes`.
+fn c_str(bytes: &[u8]) -> String {
+    let len = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..len]).into_owned()
+}
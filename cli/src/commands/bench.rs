@@ -0,0 +1,194 @@
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Context};
+use clap::{arg, value_parser, Arg, ArgAction, ArgMatches, Command};
+use yansi::Color::Green;
+use yansi::Paint;
+
+use yara_x::Scanner;
+
+use crate::commands::{
+    compilation_args, get_external_vars, load_rules,
+    path_with_namespace_parser,
+};
+use crate::config::Config;
+use crate::help;
+use crate::walk::Walker;
+
+#[rustfmt::skip]
+pub fn bench() -> Command {
+    super::command("bench")
+        .about("Measure the scanning performance of a set of rules")
+        .long_about(help::BENCH_LONG_HELP)
+        .arg(
+            Arg::new("[NAMESPACE:]RULES_PATH")
+                .required(true)
+                .help("Path to a YARA source file or directory (optionally prefixed with a namespace)")
+                .value_parser(path_with_namespace_parser)
+                .action(ArgAction::Append)
+        )
+        .arg(
+            arg!(<CORPUS_PATH>)
+                .help("Path to the file or directory that will be scanned")
+                .value_parser(value_parser!(PathBuf))
+        )
+        .args(itertools::merge(compilation_args(), [
+            arg!(-C --"compiled-rules")
+                .help("Indicate that RULES_PATH is a file with compiled rules")
+                .long_help(help::COMPILED_RULES_LONG_HELP),
+            arg!(-r --"recursive" [MAX_DEPTH])
+                .help("Scan directories recursively")
+                .long_help(help::SCAN_RECURSIVE_LONG_HELP)
+                .default_missing_value("1000")
+                .require_equals(true)
+                .value_parser(value_parser!(usize)),
+            arg!(-n --"repetitions" <N>)
+                .help("Number of times each file is scanned")
+                .default_value("1")
+                .value_parser(value_parser!(u64).range(1..)),
+            arg!(-p --"threads" <NUM_THREADS>)
+                .help("Use the given number of threads")
+                .long_help(help::THREADS_LONG_HELP)
+                .value_parser(value_parser!(u8).range(1..)),
+        ]))
+}
+
+pub fn exec_bench(args: &ArgMatches, config: &Config) -> anyhow::Result<()> {
+    let rules_path = args
+        .get_many::<(Option<String>, PathBuf)>("[NAMESPACE:]RULES_PATH")
+        .unwrap();
+
+    let corpus_path = args.get_one::<PathBuf>("CORPUS_PATH").unwrap();
+    let compiled_rules = args.get_flag("compiled-rules");
+    let recursive = args.get_one::<usize>("recursive");
+    let repetitions = *args.get_one::<u64>("repetitions").unwrap() as usize;
+
+    let num_threads =
+        args.get_one::<u8>("threads").map(|n| *n as usize).unwrap_or_else(
+            || thread::available_parallelism().map(|n| n.get()).unwrap_or(1),
+        );
+
+    let external_vars = get_external_vars(args);
+
+    let compile_start = Instant::now();
+
+    let rules = load_rules(rules_path, compiled_rules, args, config)?;
+
+    let compile_time = compile_start.elapsed();
+
+    // Load the whole corpus in memory before scanning, this way the time
+    // spent reading files from disk is not included in the measurements.
+    let mut corpus = Vec::new();
+    let mut w = Walker::path(corpus_path);
+
+    w.max_depth(*recursive.unwrap_or(&0));
+    w.walk(
+        |file_path| {
+            let data = std::fs::read(file_path).with_context(|| {
+                format!("can not read `{}`", file_path.display())
+            })?;
+            corpus.push(data);
+            Ok(())
+        },
+        Err,
+    )?;
+
+    if corpus.is_empty() {
+        bail!("no files found in `{}`", corpus_path.display());
+    }
+
+    let num_scans = corpus.len() * repetitions;
+    let next_scan = AtomicUsize::new(0);
+    let latencies = Mutex::new(Vec::with_capacity(num_scans));
+    let scan_start = Instant::now();
+
+    thread::scope(|s| {
+        let mut handles = Vec::with_capacity(num_threads);
+
+        for _ in 0..num_threads {
+            handles.push(s.spawn(|| -> anyhow::Result<()> {
+                let mut scanner = Scanner::new(&rules);
+
+                if let Some(ref vars) = external_vars {
+                    for (ident, value) in vars {
+                        scanner.set_global(ident.as_str(), value)?;
+                    }
+                }
+
+                let mut thread_latencies = Vec::new();
+
+                loop {
+                    let i = next_scan.fetch_add(1, Ordering::Relaxed);
+                    if i >= num_scans {
+                        break;
+                    }
+                    let data = corpus[i % corpus.len()].as_slice();
+                    let start = Instant::now();
+                    scanner.scan(data)?;
+                    thread_latencies.push(start.elapsed());
+                }
+
+                latencies.lock().unwrap().extend(thread_latencies);
+                Ok(())
+            }));
+        }
+
+        handles.into_iter().try_for_each(|handle| handle.join().unwrap())
+    })?;
+
+    let scan_time = scan_start.elapsed();
+
+    let mut latencies = latencies.into_inner().unwrap();
+    latencies.sort();
+
+    let corpus_size: usize = corpus.iter().map(|data| data.len()).sum();
+    let scanned_bytes = (corpus_size * repetitions) as f64;
+    let secs = scan_time.as_secs_f64();
+
+    println!(
+        "{} {} file(s), {} repetition(s), {} thread(s)",
+        "Corpus:".paint(Green).bold(),
+        corpus.len(),
+        repetitions,
+        num_threads,
+    );
+    println!(
+        "{} {:.2?}",
+        "Rules loaded in:".paint(Green).bold(),
+        compile_time,
+    );
+    println!(
+        "{} {} scans in {:.2?}",
+        "Scanned:".paint(Green).bold(),
+        num_scans,
+        scan_time,
+    );
+    println!(
+        "{} {:.2} MB/s, {:.2} scans/s",
+        "Throughput:".paint(Green).bold(),
+        scanned_bytes / (1024.0 * 1024.0) / secs,
+        num_scans as f64 / secs,
+    );
+    println!(
+        "{} min={:.2?} p50={:.2?} p90={:.2?} p99={:.2?} max={:.2?}",
+        "Latency:".paint(Green).bold(),
+        latencies[0],
+        percentile(&latencies, 50),
+        percentile(&latencies, 90),
+        percentile(&latencies, 99),
+        latencies[latencies.len() - 1],
+    );
+
+    Ok(())
+}
+
+/// Returns the `p`-th percentile of a sorted, non-empty slice of durations
+/// using the nearest-rank method.
+fn percentile(sorted: &[Duration], p: usize) -> Duration {
+    let rank = (p * sorted.len()).div_ceil(100);
+    sorted[rank.saturating_sub(1)]
+}
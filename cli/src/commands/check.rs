@@ -1,9 +1,10 @@
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
 use std::{fs, io, process};
 
 use anyhow::Context;
-use clap::{arg, value_parser, ArgAction, ArgMatches, Command};
+use clap::{arg, value_parser, ArgAction, ArgMatches, Command, ValueEnum};
 use crossterm::tty::IsTty;
 use superconsole::{Component, Line, Lines, Span};
 use yansi::Color::{Green, Red, Yellow};
@@ -12,9 +13,18 @@ use yara_x::{linters, SourceCode};
 use yara_x_parser::ast::MetaValue;
 
 use crate::config::{Config, MetaValueType};
+use crate::sarif::SarifLog;
 use crate::walk::Message;
 use crate::{help, walk};
 
+#[derive(Clone, ValueEnum)]
+enum OutputFormats {
+    /// Human-readable output.
+    Text,
+    /// SARIF (Static Analysis Results Interchange Format).
+    Sarif,
+}
+
 pub fn check() -> Command {
     super::command("check")
         .about("Check if source files are syntactically correct")
@@ -34,6 +44,12 @@ pub fn check() -> Command {
                 .long_help(help::FILTER_LONG_HELP)
                 .action(ArgAction::Append),
         )
+        .arg(
+            arg!(-o --"output-format" <FORMAT>)
+                .help("Output format for errors and warnings")
+                .long_help(help::CHECK_OUTPUT_FORMAT_LONG_HELP)
+                .value_parser(value_parser!(OutputFormats)),
+        )
         .arg(
             arg!(-r - -"recursive"[MAX_DEPTH])
                 .help("Walk directories recursively up to a given depth")
@@ -49,6 +65,14 @@ pub fn check() -> Command {
                 .required(false)
                 .value_parser(value_parser!(u8).range(1..)),
         )
+        .arg(
+            arg!(--"warn-performance"[THRESHOLD])
+                .help("Warn about rules that are potentially slow")
+                .long_help(help::WARN_PERFORMANCE_LONG_HELP)
+                .default_missing_value("100")
+                .require_equals(true)
+                .value_parser(value_parser!(u64)),
+        )
 }
 
 fn is_sha256(s: &str) -> bool {
@@ -68,6 +92,11 @@ pub fn exec_check(args: &ArgMatches, config: &Config) -> anyhow::Result<()> {
     let recursive = args.get_one::<usize>("recursive");
     let filters = args.get_many::<String>("filter");
     let num_threads = args.get_one::<u8>("threads");
+    let warn_performance = args.get_one::<u64>("warn-performance");
+    let sarif = matches!(
+        args.get_one::<OutputFormats>("output-format"),
+        Some(OutputFormats::Sarif)
+    );
 
     let mut w = walk::ParWalker::path(rules_path);
 
@@ -87,7 +116,7 @@ pub fn exec_check(args: &ArgMatches, config: &Config) -> anyhow::Result<()> {
     w.max_depth(*recursive.unwrap_or(&0));
 
     let state = w.walk(
-        CheckState::new(),
+        CheckState::new(sarif),
         // Initialization
         |_, _| {},
         // Action
@@ -194,6 +223,12 @@ pub fn exec_check(args: &ArgMatches, config: &Config) -> anyhow::Result<()> {
                     linters::rule_name(re)?.error(config.check.rule_name.error));
             }
 
+            if let Some(max_length) = config.check.rule_name.max_length {
+                compiler.add_linter(
+                    linters::rule_name_length(max_length)
+                        .error(config.check.rule_name.error));
+            }
+
             // Prefer allowed list over the regex, as it is more explicit.
             if !config.check.tags.allowed.is_empty() {
                 compiler.add_linter(
@@ -210,9 +245,37 @@ pub fn exec_check(args: &ArgMatches, config: &Config) -> anyhow::Result<()> {
                 );
             }
 
+            if !config.check.tags.required.is_empty() {
+                compiler.add_linter(
+                    linters::tags_required(config.check.tags.required.clone())
+                        .error(config.check.tags.error));
+            }
+
+            if let Some(threshold) = warn_performance {
+                compiler.warn_on_costly_rules(*threshold);
+            }
+
             compiler.colorize_errors(io::stdout().is_tty());
 
-            match compiler.add_source(src) {
+            let result = compiler.add_source(src);
+
+            // In SARIF mode errors and warnings are collected in the log
+            // that is printed at the end, instead of being printed as they
+            // are found.
+            if let Some(sarif) = &state.sarif {
+                let mut sarif = sarif.lock().unwrap();
+                for error in compiler.errors() {
+                    sarif.add_error(error);
+                }
+                for warning in compiler.warnings() {
+                    sarif.add_warning(warning);
+                }
+                state.errors.fetch_add(compiler.errors().len(), Ordering::Relaxed);
+                state.warnings.fetch_add(compiler.warnings().len(), Ordering::Relaxed);
+                return Ok(());
+            }
+
+            match result {
                 Ok(compiler) => {
                     if compiler.warnings().is_empty() {
                         state.files_passed.fetch_add(1, Ordering::Relaxed);
@@ -268,6 +331,14 @@ pub fn exec_check(args: &ArgMatches, config: &Config) -> anyhow::Result<()> {
     )
         .unwrap();
 
+    if let Some(sarif) = &state.sarif {
+        serde_json::to_writer_pretty(
+            io::stdout(),
+            &sarif.lock().unwrap().to_json(),
+        )?;
+        println!();
+    }
+
     // Exit code is 1 if errors were found.
     if state.errors.load(Ordering::Relaxed) > 0 {
         process::exit(1)
@@ -281,29 +352,44 @@ pub fn exec_check(args: &ArgMatches, config: &Config) -> anyhow::Result<()> {
     Ok(())
 }
 
-#[derive(Debug)]
 struct CheckState {
     files_passed: AtomicUsize,
     warnings: AtomicUsize,
     errors: AtomicUsize,
+    sarif: Option<Mutex<SarifLog>>,
 }
 
 impl CheckState {
-    fn new() -> Self {
+    fn new(sarif: bool) -> Self {
         Self {
             files_passed: AtomicUsize::new(0),
             warnings: AtomicUsize::new(0),
             errors: AtomicUsize::new(0),
+            sarif: sarif.then(|| Mutex::new(SarifLog::new())),
         }
     }
 }
 
+impl std::fmt::Debug for CheckState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CheckState")
+            .field("files_passed", &self.files_passed)
+            .field("warnings", &self.warnings)
+            .field("errors", &self.errors)
+            .finish()
+    }
+}
+
 impl Component for CheckState {
     fn draw_unchecked(
         &self,
         _dimensions: superconsole::Dimensions,
         mode: superconsole::DrawMode,
     ) -> anyhow::Result<superconsole::Lines> {
+        // In SARIF mode the only output is the SARIF log itself.
+        if self.sarif.is_some() {
+            return Ok(Lines::new());
+        }
         let res = match mode {
             superconsole::DrawMode::Normal | superconsole::DrawMode::Final => {
                 let ok = format!(
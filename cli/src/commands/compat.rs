@@ -0,0 +1,111 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+use std::time::Duration;
+
+use anyhow::Context;
+use clap::{arg, value_parser, ArgMatches, Command};
+use yansi::Color::{Green, Red};
+use yansi::Paint;
+
+use yara_x::compat::Harness;
+
+use crate::help;
+use crate::walk::Walker;
+
+#[rustfmt::skip]
+pub fn compat() -> Command {
+    super::command("compat")
+        .about("Compare the results produced by libyara and YARA-X")
+        .long_about(help::COMPAT_LONG_HELP)
+        .arg(
+            arg!(<RULES_PATH>)
+                .help("Path to a YARA source file")
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(<TARGET_PATH>)
+                .help("Path to the file or directory that will be scanned")
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(-r --"recursive" [MAX_DEPTH])
+                .help("Scan directories recursively")
+                .long_help(help::SCAN_RECURSIVE_LONG_HELP)
+                .default_missing_value("1000")
+                .require_equals(true)
+                .value_parser(value_parser!(usize)),
+        )
+        .arg(
+            arg!(-a --"timeout" <SECONDS>)
+                .help("Abort scanning after the given number of seconds")
+                .value_parser(value_parser!(u64).range(1..)),
+        )
+}
+
+pub fn exec_compat(args: &ArgMatches) -> anyhow::Result<()> {
+    let rules_path = args.get_one::<PathBuf>("RULES_PATH").unwrap();
+    let target_path = args.get_one::<PathBuf>("TARGET_PATH").unwrap();
+    let recursive = args.get_one::<usize>("recursive");
+    let timeout = args.get_one::<u64>("timeout");
+
+    let source = fs::read_to_string(rules_path)
+        .with_context(|| format!("can not read `{}`", rules_path.display()))?;
+
+    let mut harness = Harness::new(&source)?;
+
+    if let Some(timeout) = timeout {
+        harness.set_timeout(Duration::from_secs(*timeout));
+    }
+
+    let mut num_files = 0;
+    let mut num_divergent = 0;
+
+    let mut w = Walker::path(target_path);
+
+    w.max_depth(*recursive.unwrap_or(&0));
+    w.walk(
+        |file_path| {
+            let data = fs::read(file_path).with_context(|| {
+                format!("can not read `{}`", file_path.display())
+            })?;
+
+            let divergences = harness.compare(&data).with_context(|| {
+                format!("error scanning `{}`", file_path.display())
+            })?;
+
+            num_files += 1;
+
+            if divergences.is_empty() {
+                println!(
+                    "[ {} ] {}",
+                    "SAME".paint(Green).bold(),
+                    file_path.display()
+                );
+            } else {
+                num_divergent += 1;
+                println!(
+                    "[ {} ] {}",
+                    "DIFF".paint(Red).bold(),
+                    file_path.display()
+                );
+                for divergence in divergences {
+                    println!("    {divergence}");
+                }
+            }
+
+            Ok(())
+        },
+        Err,
+    )?;
+
+    println!(
+        "\n{num_files} file(s) scanned, {num_divergent} with different results"
+    );
+
+    if num_divergent > 0 {
+        process::exit(1)
+    }
+
+    Ok(())
+}
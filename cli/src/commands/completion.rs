@@ -8,6 +8,7 @@ pub fn completion() -> Command {
     super::command("completion")
         .about("Output shell completion code for the specified shell")
         .long_about(help::COMPLETION_LONG_HELP)
+        .visible_alias("completions")
         .arg(
             arg!(<SHELL>)
                 .help("Shell name")
@@ -0,0 +1,161 @@
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+use anyhow::{bail, Context};
+use clap::{arg, value_parser, Arg, ArgAction, ArgMatches, Command};
+use yansi::Color::{Green, Red, Yellow};
+use yansi::Paint;
+
+use yara_x::{Rule, Scanner};
+
+use crate::commands::{
+    compilation_args, get_external_vars, load_rules,
+    path_with_namespace_parser,
+};
+use crate::config::Config;
+use crate::help;
+use crate::walk::Walker;
+
+#[rustfmt::skip]
+pub fn coverage() -> Command {
+    super::command("coverage")
+        .about("Report which rules and patterns match in a corpus of files")
+        .long_about(help::COVERAGE_LONG_HELP)
+        .arg(
+            Arg::new("[NAMESPACE:]RULES_PATH")
+                .required(true)
+                .help("Path to a YARA source file or directory (optionally prefixed with a namespace)")
+                .value_parser(path_with_namespace_parser)
+                .action(ArgAction::Append)
+        )
+        .arg(
+            arg!(<CORPUS_PATH>)
+                .help("Path to the file or directory that will be scanned")
+                .value_parser(value_parser!(PathBuf))
+        )
+        .args(itertools::merge(compilation_args(), [
+            arg!(-C --"compiled-rules")
+                .help("Indicate that RULES_PATH is a file with compiled rules")
+                .long_help(help::COMPILED_RULES_LONG_HELP),
+            arg!(-r --"recursive" [MAX_DEPTH])
+                .help("Scan directories recursively")
+                .long_help(help::SCAN_RECURSIVE_LONG_HELP)
+                .default_missing_value("1000")
+                .require_equals(true)
+                .value_parser(value_parser!(usize)),
+        ]))
+}
+
+pub fn exec_coverage(
+    args: &ArgMatches,
+    config: &Config,
+) -> anyhow::Result<()> {
+    let rules_path = args
+        .get_many::<(Option<String>, PathBuf)>("[NAMESPACE:]RULES_PATH")
+        .unwrap();
+
+    let corpus_path = args.get_one::<PathBuf>("CORPUS_PATH").unwrap();
+    let compiled_rules = args.get_flag("compiled-rules");
+    let recursive = args.get_one::<usize>("recursive");
+
+    let rules = load_rules(rules_path, compiled_rules, args, config)?;
+
+    let mut scanner = Scanner::new(&rules);
+
+    if let Some(vars) = get_external_vars(args) {
+        for (ident, value) in vars {
+            scanner.set_global(ident.as_str(), value)?;
+        }
+    }
+
+    // Rules are identified by namespace and name, patterns by the
+    // namespace and name of the rule, plus the pattern identifier.
+    let mut files_per_rule = HashMap::new();
+    let mut matching_patterns = HashSet::new();
+    let mut num_files = 0;
+
+    let mut w = Walker::path(corpus_path);
+
+    w.max_depth(*recursive.unwrap_or(&0));
+    w.walk(
+        |file_path| {
+            let results = scanner.scan_file(file_path).with_context(|| {
+                format!("error scanning `{}`", file_path.display())
+            })?;
+
+            for rule in results.matching_rules().include_private(true) {
+                *files_per_rule.entry(rule_name(&rule)).or_insert(0) += 1;
+            }
+
+            for (rule, pattern) in results.matching_patterns() {
+                matching_patterns
+                    .insert((rule_name(&rule), pattern.identifier()));
+            }
+
+            num_files += 1;
+            Ok(())
+        },
+        Err,
+    )?;
+
+    if num_files == 0 {
+        bail!("no files found in `{}`", corpus_path.display());
+    }
+
+    println!("{} {} file(s)", "Corpus:".paint(Green).bold(), num_files);
+
+    let mut dead_rules = 0;
+    let mut dead_patterns = 0;
+
+    for rule in rules.iter() {
+        let name = rule_name(&rule);
+        let num_matching_files =
+            files_per_rule.get(&name).copied().unwrap_or(0);
+
+        let never_matched: Vec<_> = rule
+            .patterns()
+            .include_private(true)
+            .map(|pattern| pattern.identifier())
+            .filter(|ident| {
+                !matching_patterns.contains(&(name.clone(), *ident))
+            })
+            .collect();
+
+        let status = if num_matching_files == 0 {
+            dead_rules += 1;
+            "NONE".paint(Red).bold()
+        } else if !never_matched.is_empty() {
+            "PART".paint(Yellow).bold()
+        } else {
+            "FULL".paint(Green).bold()
+        };
+
+        println!("[ {status} ] {name}: {num_matching_files} file(s)");
+
+        if !never_matched.is_empty() {
+            dead_patterns += never_matched.len();
+            println!(
+                "    patterns never matched: {}",
+                never_matched.join(", ")
+            );
+        }
+    }
+
+    println!(
+        "\n{} {} rule(s) and {} pattern(s) never matched",
+        "Summary:".paint(Green).bold(),
+        dead_rules,
+        dead_patterns,
+    );
+
+    Ok(())
+}
+
+/// Returns the rule's name, qualified with the namespace if the namespace
+/// is not the default one.
+fn rule_name(rule: &Rule) -> String {
+    match rule.namespace() {
+        "default" => rule.identifier().to_string(),
+        namespace => format!("{}:{}", namespace, rule.identifier()),
+    }
+}
@@ -4,7 +4,7 @@ use std::io::stdout;
 use std::path::PathBuf;
 
 use anyhow::Context;
-use clap::{arg, value_parser, ArgAction, ArgMatches, Command};
+use clap::{arg, value_parser, ArgAction, ArgMatches, Command, ValueEnum};
 
 use yara_x::SourceCode;
 use yara_x_parser::ast::AST;
@@ -17,6 +17,14 @@ use crate::commands::{
 use crate::config::Config;
 use crate::help;
 
+#[derive(Clone, ValueEnum)]
+enum AstOutputFormats {
+    /// Human-readable tree.
+    Text,
+    /// JSON output, including the span of every node.
+    Json,
+}
+
 pub fn ast() -> Command {
     super::command("ast")
         .about("Print Abstract Syntax Tree (AST) for a YARA source file")
@@ -25,6 +33,11 @@ pub fn ast() -> Command {
                 .help("Path to YARA source file")
                 .value_parser(value_parser!(PathBuf)),
         )
+        .arg(
+            arg!(-o --"output-format" <FORMAT>)
+                .help("Output format for the AST")
+                .value_parser(value_parser!(AstOutputFormats)),
+        )
 }
 
 pub fn cst() -> Command {
@@ -37,24 +50,6 @@ pub fn cst() -> Command {
         )
 }
 
-pub fn ir() -> Command {
-    super::command("ir")
-        .about("Print Intermediate Representation (IR) for a YARA source file")
-        .arg(
-            arg!(<RULES_PATH>)
-                .help("Path to YARA source file")
-                .value_parser(value_parser!(PathBuf)),
-        )
-        .arg(
-            arg!(-d - -"define")
-                .help("Define external variable")
-                .long_help(help::DEFINE_LONG_HELP)
-                .value_name("VAR=VALUE")
-                .value_parser(external_var_parser)
-                .action(ArgAction::Append),
-        )
-}
-
 pub fn wasm() -> Command {
     super::command("wasm")
         .about(
@@ -65,6 +60,10 @@ pub fn wasm() -> Command {
                 .help("Path to YARA source file")
                 .value_parser(value_parser!(PathBuf)),
         )
+        .arg(
+            arg!(--"wat")
+                .help("Emit a .wat file in WebAssembly text format instead"),
+        )
         .arg(
             arg!(-d - -"define")
                 .help("Define external variable")
@@ -85,18 +84,25 @@ pub fn debug() -> Command {
         .arg_required_else_help(true)
         .subcommand(ast())
         .subcommand(cst())
-        .subcommand(ir())
         .subcommand(wasm())
         .subcommand(modules())
+        // `yr debug ir` was moved to `yr ir`, it's kept as a hidden alias
+        // for backward compatibility.
+        .subcommand(super::ir().hide(true))
 }
 
 pub fn exec_debug(args: &ArgMatches, config: &Config) -> anyhow::Result<()> {
     match args.subcommand() {
         Some(("ast", args)) => exec_ast(args, config),
         Some(("cst", args)) => exec_cst(args, config),
-        Some(("ir", args)) => exec_ir(args, config),
         Some(("wasm", args)) => exec_wasm(args, config),
         Some(("modules", args)) => exec_modules(args, config),
+        Some(("ir", args)) => {
+            eprintln!(
+                "warning: `yr debug ir` is deprecated, use `yr ir` instead"
+            );
+            super::exec_ir(args, config)
+        }
         _ => unreachable!(),
     }
 }
@@ -110,7 +116,16 @@ pub fn exec_ast(args: &ArgMatches, _config: &Config) -> anyhow::Result<()> {
     let parser = Parser::new(src.as_slice());
     let ast: AST = parser.into();
 
-    println!("{ast:?}");
+    match args.get_one::<AstOutputFormats>("output-format") {
+        Some(AstOutputFormats::Json) => {
+            serde_json::to_writer_pretty(stdout(), &ast)?;
+            println!();
+        }
+        None | Some(AstOutputFormats::Text) => {
+            println!("{ast:?}");
+        }
+    }
+
     Ok(())
 }
 
@@ -127,21 +142,6 @@ pub fn exec_cst(args: &ArgMatches, _config: &Config) -> anyhow::Result<()> {
     Ok(())
 }
 
-pub fn exec_ir(args: &ArgMatches, config: &Config) -> anyhow::Result<()> {
-    let rules_path = args.get_one::<PathBuf>("RULES_PATH").unwrap();
-
-    let src = fs::read(rules_path)
-        .with_context(|| format!("can not read `{}`", rules_path.display()))?;
-
-    let external_vars = get_external_vars(args);
-    let mut compiler = create_compiler(external_vars, args, config)?;
-
-    compiler.set_ir_writer(stdout());
-    compiler.add_source(src.as_slice())?;
-
-    Ok(())
-}
-
 fn exec_wasm(args: &ArgMatches, config: &Config) -> anyhow::Result<()> {
     let mut rules_path =
         args.get_one::<PathBuf>("RULES_PATH").unwrap().to_path_buf();
@@ -152,13 +152,20 @@ fn exec_wasm(args: &ArgMatches, config: &Config) -> anyhow::Result<()> {
     let src = SourceCode::from(src.as_slice())
         .with_origin(rules_path.as_os_str().to_str().unwrap());
 
-    rules_path.set_extension("wasm");
+    let wat = args.get_flag("wat");
+
+    rules_path.set_extension(if wat { "wat" } else { "wasm" });
 
     let external_vars = get_external_vars(args);
     let mut compiler = create_compiler(external_vars, args, config)?;
 
     compiler.add_source(src)?;
-    compiler.emit_wasm_file(rules_path.as_path())?;
+
+    if wat {
+        compiler.emit_wat_file(rules_path.as_path())?;
+    } else {
+        compiler.emit_wasm_file(rules_path.as_path())?;
+    }
 
     Ok(())
 }
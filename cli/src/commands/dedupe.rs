@@ -0,0 +1,150 @@
+use std::fmt::Write;
+use std::fs;
+use std::io::{stderr, stdout};
+use std::path::PathBuf;
+
+use anyhow::{bail, Context};
+use clap::{arg, value_parser, ArgAction, ArgMatches, Command, ValueEnum};
+use crossterm::tty::IsTty;
+
+use yara_x::{Compiler, SourceCode};
+use yara_x_parser::dedupe::{Cluster, Deduplicator, DuplicateKind};
+
+use crate::help;
+use crate::walk::Walker;
+
+#[derive(Clone, ValueEnum)]
+enum OutputFormats {
+    /// Human-readable text, with one paragraph per cluster.
+    Text,
+    /// JSON array with one object per cluster.
+    Json,
+}
+
+pub fn dedupe() -> Command {
+    super::command("dedupe")
+        .about("Find duplicate rules in YARA source files")
+        .long_about(help::DEDUPE_LONG_HELP)
+        // Keep options sorted alphabetically by their long name.
+        // For instance, --bar goes before --foo.
+        .arg(
+            arg!(<RULES_PATH>)
+                .help("Path to YARA source file or directory")
+                .value_parser(value_parser!(PathBuf))
+                .action(ArgAction::Append),
+        )
+        .arg(
+            arg!(-f --filter <PATTERN>)
+                .help("Check files that match the given pattern only")
+                .long_help(help::FILTER_LONG_HELP)
+                .action(ArgAction::Append),
+        )
+        .arg(
+            arg!(-o --"output-format" <FORMAT>)
+                .help("Output format")
+                .default_value("text")
+                .value_parser(value_parser!(OutputFormats)),
+        )
+        .arg(
+            arg!(-r - -"recursive"[MAX_DEPTH])
+                .help("Walk directories recursively up to a given depth")
+                .long_help(help::RECURSIVE_LONG_HELP)
+                .default_missing_value("1000")
+                .require_equals(true)
+                .value_parser(value_parser!(usize)),
+        )
+}
+
+pub fn exec_dedupe(args: &ArgMatches) -> anyhow::Result<()> {
+    let rules_paths = args.get_many::<PathBuf>("RULES_PATH").unwrap();
+    let filters = args.get_many::<String>("filter");
+    let recursive = args.get_one::<usize>("recursive");
+    let output_format =
+        args.get_one::<OutputFormats>("output-format").unwrap();
+
+    let mut deduplicator = Deduplicator::new();
+
+    for rules_path in rules_paths {
+        let mut w = Walker::path(rules_path);
+
+        if let Some(filters) = filters.clone() {
+            for filter in filters {
+                w.filter(filter);
+            }
+        } else {
+            // Default filters are `**/*.yar` and `**/*.yara`.
+            w.filter("**/*.yar").filter("**/*.yara");
+        }
+
+        w.max_depth(*recursive.unwrap_or(&0));
+        w.walk(
+            |file_path| {
+                let src =
+                    fs::read_to_string(file_path).with_context(|| {
+                        format!("can not read `{}`", file_path.display())
+                    })?;
+
+                let origin = file_path.display().to_string();
+
+                if deduplicator.add_source(&origin, src.as_str()).is_err() {
+                    // The source code has syntax errors, use the compiler
+                    // for producing a proper error report.
+                    let mut compiler = Compiler::new();
+                    compiler.colorize_errors(stderr().is_tty());
+                    compiler.add_source(
+                        SourceCode::from(src.as_str())
+                            .with_origin(origin.as_str()),
+                    )?;
+                    bail!("can not parse `{}`", file_path.display());
+                }
+
+                Ok(())
+            },
+            // Any error occurred during walk is aborts the walk.
+            Err,
+        )?;
+    }
+
+    let clusters = deduplicator.clusters();
+
+    match output_format {
+        OutputFormats::Json => {
+            serde_json::to_writer_pretty(stdout(), &clusters)?;
+            println!();
+        }
+        OutputFormats::Text => {
+            print!("{}", text(&clusters));
+        }
+    }
+
+    Ok(())
+}
+
+/// Renders the clusters as human-readable text.
+fn text(clusters: &[Cluster]) -> String {
+    let mut output = String::new();
+
+    for (i, cluster) in clusters.iter().enumerate() {
+        if i > 0 {
+            output.push('\n');
+        }
+
+        let kind = match cluster.kind {
+            DuplicateKind::Exact => "exact duplicates",
+            DuplicateKind::Near => "near duplicates",
+        };
+
+        writeln!(output, "{kind}:").unwrap();
+
+        for rule in &cluster.rules {
+            writeln!(
+                output,
+                "  {}:{}:{} {}",
+                rule.origin, rule.line, rule.column, rule.identifier
+            )
+            .unwrap();
+        }
+    }
+
+    output
+}
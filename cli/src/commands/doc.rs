@@ -0,0 +1,205 @@
+use std::fmt::Write;
+use std::fs;
+use std::io::{stderr, stdout};
+use std::path::PathBuf;
+
+use anyhow::{bail, Context};
+use clap::{arg, value_parser, ArgAction, ArgMatches, Command, ValueEnum};
+use crossterm::tty::IsTty;
+use serde::Serialize;
+
+use yara_x::{Compiler, SourceCode};
+use yara_x_parser::doc::{rule_docs, RuleDoc};
+
+use crate::help;
+use crate::walk::Walker;
+
+#[derive(Clone, ValueEnum)]
+enum OutputFormats {
+    /// Markdown document, with one section per rule.
+    Markdown,
+    /// JSON array with one object per source file.
+    Json,
+}
+
+/// The documentation for all the rules in a source file.
+#[derive(Serialize)]
+struct FileDocs {
+    path: String,
+    rules: Vec<RuleDoc>,
+}
+
+pub fn doc() -> Command {
+    super::command("doc")
+        .about("Extract documentation from YARA source files")
+        .long_about(help::DOC_LONG_HELP)
+        // Keep options sorted alphabetically by their long name.
+        // For instance, --bar goes before --foo.
+        .arg(
+            arg!(<RULES_PATH>)
+                .help("Path to YARA source file or directory")
+                .value_parser(value_parser!(PathBuf))
+                .action(ArgAction::Append),
+        )
+        .arg(
+            arg!(-f --filter <PATTERN>)
+                .help("Document files that match the given pattern only")
+                .long_help(help::FILTER_LONG_HELP)
+                .action(ArgAction::Append),
+        )
+        .arg(
+            arg!(-o --"output-format" <FORMAT>)
+                .help("Output format")
+                .default_value("markdown")
+                .value_parser(value_parser!(OutputFormats)),
+        )
+        .arg(
+            arg!(-r - -"recursive"[MAX_DEPTH])
+                .help("Walk directories recursively up to a given depth")
+                .long_help(help::RECURSIVE_LONG_HELP)
+                .default_missing_value("1000")
+                .require_equals(true)
+                .value_parser(value_parser!(usize)),
+        )
+}
+
+pub fn exec_doc(args: &ArgMatches) -> anyhow::Result<()> {
+    let rules_paths = args.get_many::<PathBuf>("RULES_PATH").unwrap();
+    let filters = args.get_many::<String>("filter");
+    let recursive = args.get_one::<usize>("recursive");
+    let output_format =
+        args.get_one::<OutputFormats>("output-format").unwrap();
+
+    let mut files = Vec::new();
+
+    for rules_path in rules_paths {
+        let mut w = Walker::path(rules_path);
+
+        if let Some(filters) = filters.clone() {
+            for filter in filters {
+                w.filter(filter);
+            }
+        } else {
+            // Default filters are `**/*.yar` and `**/*.yara`.
+            w.filter("**/*.yar").filter("**/*.yara");
+        }
+
+        w.max_depth(*recursive.unwrap_or(&0));
+        w.walk(
+            |file_path| {
+                let src =
+                    fs::read_to_string(file_path).with_context(|| {
+                        format!("can not read `{}`", file_path.display())
+                    })?;
+
+                let rules = match rule_docs(src.as_str()) {
+                    Ok(rules) => rules,
+                    Err(_) => {
+                        // The source code has syntax errors, use the compiler
+                        // for producing a proper error report.
+                        let mut compiler = Compiler::new();
+                        compiler.colorize_errors(stderr().is_tty());
+                        compiler.add_source(
+                            SourceCode::from(src.as_str()).with_origin(
+                                file_path.as_os_str().to_str().unwrap(),
+                            ),
+                        )?;
+                        bail!("can not parse `{}`", file_path.display());
+                    }
+                };
+
+                files.push(FileDocs {
+                    path: file_path.display().to_string(),
+                    rules,
+                });
+
+                Ok(())
+            },
+            // Any error occurred during walk is aborts the walk.
+            Err,
+        )?;
+    }
+
+    match output_format {
+        OutputFormats::Json => {
+            serde_json::to_writer_pretty(stdout(), &files)?;
+            println!();
+        }
+        OutputFormats::Markdown => {
+            print!("{}", markdown(&files));
+        }
+    }
+
+    Ok(())
+}
+
+/// Renders the documentation as a Markdown document.
+fn markdown(files: &[FileDocs]) -> String {
+    let mut md = String::new();
+
+    for file in files {
+        writeln!(md, "# `{}`\n", file.path).unwrap();
+
+        for rule in &file.rules {
+            writeln!(md, "## {}\n", rule.identifier).unwrap();
+
+            if !rule.comments.is_empty() {
+                writeln!(md, "{}\n", rule.comments.join("\n")).unwrap();
+            }
+
+            let mut flags = Vec::new();
+
+            if rule.global {
+                flags.push("`global`");
+            }
+
+            if rule.private {
+                flags.push("`private`");
+            }
+
+            if !flags.is_empty() {
+                writeln!(md, "* **Flags:** {}", flags.join(", ")).unwrap();
+            }
+
+            if !rule.tags.is_empty() {
+                writeln!(md, "* **Tags:** {}", code_list(&rule.tags)).unwrap();
+            }
+
+            if !rule.modules.is_empty() {
+                writeln!(md, "* **Modules:** {}", code_list(&rule.modules))
+                    .unwrap();
+            }
+
+            if !flags.is_empty()
+                || !rule.tags.is_empty()
+                || !rule.modules.is_empty()
+            {
+                md.push('\n');
+            }
+
+            if !rule.meta.is_empty() {
+                md.push_str("| Metadata | Value |\n");
+                md.push_str("|----------|-------|\n");
+                for meta in &rule.meta {
+                    writeln!(
+                        md,
+                        "| {} | {} |",
+                        meta.identifier,
+                        meta.value
+                            .to_string()
+                            .replace('|', "\\|")
+                            .replace('\n', " ")
+                    )
+                    .unwrap();
+                }
+                md.push('\n');
+            }
+        }
+    }
+
+    md
+}
+
+fn code_list(items: &[String]) -> String {
+    items.iter().map(|item| format!("`{item}`")).collect::<Vec<_>>().join(", ")
+}
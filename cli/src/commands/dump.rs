@@ -22,6 +22,12 @@ enum SupportedModules {
     Dotnet,
     Crx,
     Dex,
+    Regf,
+    Email,
+    X509,
+    Iso9660,
+    Wasm,
+    Pcap,
 }
 
 #[derive(Debug, Clone, ValueEnum)]
@@ -119,6 +125,24 @@ pub fn exec_dump(args: &ArgMatches) -> anyhow::Result<()> {
         if !requested_modules.contains(&&SupportedModules::Dex) {
             module_output.dex = MessageField::none()
         }
+        if !requested_modules.contains(&&SupportedModules::Regf) {
+            module_output.regf = MessageField::none()
+        }
+        if !requested_modules.contains(&&SupportedModules::Email) {
+            module_output.email = MessageField::none()
+        }
+        if !requested_modules.contains(&&SupportedModules::X509) {
+            module_output.x509 = MessageField::none()
+        }
+        if !requested_modules.contains(&&SupportedModules::Iso9660) {
+            module_output.iso9660 = MessageField::none()
+        }
+        if !requested_modules.contains(&&SupportedModules::Wasm) {
+            module_output.wasm = MessageField::none()
+        }
+        if !requested_modules.contains(&&SupportedModules::Pcap) {
+            module_output.pcap = MessageField::none()
+        }
     } else {
         // Module was not specified, only show those that produced meaningful
         // results, the rest are cleared out.
@@ -145,6 +169,24 @@ pub fn exec_dump(args: &ArgMatches) -> anyhow::Result<()> {
         if !module_output.dex.is_dex() {
             module_output.dex = MessageField::none()
         }
+        if !module_output.regf.is_regf() {
+            module_output.regf = MessageField::none()
+        }
+        if !module_output.email.is_email() {
+            module_output.email = MessageField::none()
+        }
+        if !module_output.x509.is_x509() {
+            module_output.x509 = MessageField::none()
+        }
+        if !module_output.iso9660.is_iso9660() {
+            module_output.iso9660 = MessageField::none()
+        }
+        if !module_output.wasm.is_wasm() {
+            module_output.wasm = MessageField::none()
+        }
+        if !module_output.pcap.is_pcap() {
+            module_output.pcap = MessageField::none()
+        }
     }
 
     match output_format {
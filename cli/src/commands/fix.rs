@@ -4,15 +4,20 @@ use std::collections::HashMap;
 use std::fs;
 use std::fs::File;
 use std::io::Write;
+use std::ops::Range;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 use anyhow::Context;
 use clap::{arg, value_parser, Arg, ArgAction, ArgMatches, Command};
+use similar::TextDiff;
 use superconsole::{Component, Line, Lines, Span};
 use yansi::Color::{Green, Red, Yellow};
 use yansi::Paint;
-use yara_x::Patch;
+use yara_x::{Compiler, Patch, SourceCode};
+use yara_x_parser::ast::AST;
+use yara_x_parser::cst::{CSTStream, Event, SyntaxKind};
+use yara_x_parser::Parser;
 
 use crate::commands::{
     compilation_args, compile_rules, path_with_namespace_parser,
@@ -26,6 +31,7 @@ pub fn fix() -> Command {
         .about("Utilities for fixing source code")
         .arg_required_else_help(true)
         .subcommand(fix_encoding())
+        .subcommand(fix_legacy())
         .subcommand(fix_warnings())
 }
 
@@ -64,6 +70,44 @@ pub fn fix_encoding() -> Command {
         )
 }
 
+pub fn fix_legacy() -> Command {
+    super::command("legacy")
+        .about("Migrate legacy YARA syntax to YARA-X")
+        .long_about(help::FIX_LEGACY_LONG_HELP)
+        .arg(
+            arg!(<RULES_PATH>)
+                .help("Path to YARA source file or directory")
+                .value_parser(value_parser!(PathBuf)),
+        )
+        // Keep options sorted alphabetically by their long name.
+        // For instance, --bar goes before --foo.
+        .arg(
+            arg!(-d - -"dry-run")
+                .help("Don't modify source files, print a diff instead"),
+        )
+        .arg(
+            arg!(-f --filter <PATTERN>)
+                .help("Check files that match the given pattern only")
+                .long_help(help::FILTER_LONG_HELP)
+                .action(ArgAction::Append),
+        )
+        .arg(
+            arg!(-r - -"recursive"[MAX_DEPTH])
+                .help("Walk directories recursively up to a given depth")
+                .long_help(help::RECURSIVE_LONG_HELP)
+                .default_missing_value("1000")
+                .require_equals(true)
+                .value_parser(value_parser!(usize)),
+        )
+        .arg(
+            arg!(-p --"threads" <NUM_THREADS>)
+                .help("Use the given number of threads")
+                .long_help(help::THREADS_LONG_HELP)
+                .required(false)
+                .value_parser(value_parser!(u8).range(1..)),
+        )
+}
+
 pub fn fix_warnings() -> Command {
     super::command("warnings")
         .about("Automatically fix warnings")
@@ -81,6 +125,7 @@ pub fn fix_warnings() -> Command {
 pub fn exec_fix(args: &ArgMatches, config: &Config) -> anyhow::Result<()> {
     match args.subcommand() {
         Some(("encoding", args)) => exec_fix_encoding(args, config),
+        Some(("legacy", args)) => exec_fix_legacy(args, config),
         Some(("warnings", args)) => exec_fix_warnings(args, config),
         _ => unreachable!(),
     }
@@ -114,7 +159,7 @@ pub fn exec_fix_encoding(
     }
 
     w.walk(
-        FixEncodingState::new(),
+        FixState::new(),
         // Initialization
         |_, _| {},
         // Action
@@ -171,6 +216,235 @@ pub fn exec_fix_encoding(
     Ok(())
 }
 
+pub fn exec_fix_legacy(
+    args: &ArgMatches,
+    _config: &Config,
+) -> anyhow::Result<()> {
+    let rules_path = args.get_one::<PathBuf>("RULES_PATH").unwrap();
+    let filters = args.get_many::<String>("filter");
+    let dry_run = args.get_flag("dry-run");
+    let recursive = args.get_one::<usize>("recursive");
+    let num_threads = args.get_one::<u8>("threads");
+
+    let mut w = walk::ParWalker::path(rules_path);
+
+    w.max_depth(*recursive.unwrap_or(&0));
+
+    if let Some(num_threads) = num_threads {
+        w.num_threads(*num_threads);
+    }
+
+    if let Some(filters) = filters {
+        for filter in filters {
+            w.filter(filter);
+        }
+    } else {
+        // Default filters are `**/*.yar` and `**/*.yara`.
+        w.filter("**/*.yar").filter("**/*.yara");
+    }
+
+    w.walk(
+        FixState::new(),
+        // Initialization
+        |_, _| {},
+        // Action
+        |state, output, file_path, _| {
+            let src = fs::read_to_string(&file_path).with_context(|| {
+                format!(
+                    "can not read `{}`, use `yr fix encoding` if it is not UTF-8",
+                    file_path.display()
+                )
+            })?;
+
+            let fixed = migrate_legacy_syntax(&src);
+
+            if fixed == src {
+                return Ok(());
+            }
+
+            if dry_run {
+                let path = file_path.display().to_string();
+                let diff = TextDiff::from_lines(&src, &fixed)
+                    .unified_diff()
+                    .header(&path, &path)
+                    .to_string();
+
+                output.send(Message::Info(diff.trim_end().to_string()))?;
+            } else {
+                fs::write(&file_path, fixed)?;
+                state.files_modified.fetch_add(1, Ordering::Relaxed);
+
+                output.send(Message::Info(format!(
+                    "{:>7} {}",
+                    "fixed".paint(Green).bold(),
+                    file_path.display()
+                )))?;
+            }
+
+            Ok(())
+        },
+        // Finalization
+        |_, _| {},
+        // Walk done
+        |_| {},
+        // Error handling
+        |err, output| {
+            let _ = output.send(Message::Error(format!(
+                "{} {}",
+                "error:".paint(Red).bold(),
+                err
+            )));
+
+            Ok(())
+        },
+    )
+    .unwrap();
+
+    Ok(())
+}
+
+/// Rewrites legacy YARA constructs into their YARA-X equivalents.
+///
+/// The following constructs are rewritten:
+///
+/// * Octal escape sequences in string literals (e.g: `"\101"`), which are
+///   replaced with hex escape sequences (e.g: `"\x41"`).
+/// * The `entrypoint` keyword, which is replaced with `pe.entry_point`. The
+///   `pe` module is imported if necessary.
+/// * Non-boolean expressions used as booleans (e.g: `filesize and ...`),
+///   which are compared explicitly with zero or the empty string.
+fn migrate_legacy_syntax(src: &str) -> String {
+    let mut src = apply_patches(src, octal_escape_patches(src));
+
+    // Fixing some issues can make the compiler go further and find new ones.
+    // For instance, the condition of a rule that uses `entrypoint` is not
+    // compiled completely, so other issues in that condition are found only
+    // after `entrypoint` is replaced. For that reason the source is compiled
+    // repeatedly, until there's nothing else to fix.
+    for _ in 0..MAX_FIX_ITERATIONS {
+        let mut compiler = Compiler::new();
+        let _ = compiler.add_source(SourceCode::from(src.as_str()));
+
+        let mut patches = Vec::new();
+        let mut entrypoint_fixed = false;
+
+        for err in compiler.errors() {
+            if err.code() == "E017" {
+                entrypoint_fixed = true;
+                patches.extend(err.patches().map(|patch| {
+                    (patch.span().range(), patch.replacement().to_string())
+                }));
+            }
+        }
+
+        for warning in compiler.warnings() {
+            if warning.code() == "non_bool_expr" {
+                patches.extend(warning.patches().map(|patch| {
+                    (patch.span().range(), patch.replacement().to_string())
+                }));
+            }
+        }
+
+        if patches.is_empty() {
+            break;
+        }
+
+        src = apply_patches(&src, patches);
+
+        // `pe.entry_point` requires importing the `pe` module.
+        if entrypoint_fixed
+            && !AST::from(Parser::new(src.as_bytes()))
+                .imports()
+                .any(|import| import.module_name == "pe")
+        {
+            src = if src.starts_with("import") {
+                format!("import \"pe\"\n{src}")
+            } else {
+                format!("import \"pe\"\n\n{src}")
+            };
+        }
+    }
+
+    src
+}
+
+/// Maximum number of times that the source code is compiled while fixing
+/// legacy syntax.
+const MAX_FIX_ITERATIONS: usize = 10;
+
+/// Returns patches that replace octal escape sequences in string literals
+/// with the equivalent hex escape sequences.
+fn octal_escape_patches(src: &str) -> Vec<(Range<usize>, String)> {
+    let mut patches = Vec::new();
+
+    for event in CSTStream::from(Parser::new(src.as_bytes())) {
+        let span = match event {
+            Event::Token { kind: SyntaxKind::STRING_LIT, span } => span,
+            _ => continue,
+        };
+
+        let literal = &src.as_bytes()[span.range()];
+        let mut i = 0;
+
+        while i < literal.len() {
+            if literal[i] != b'\\' {
+                i += 1;
+                continue;
+            }
+            // Number of octal digits after the backslash, up to 3.
+            let digits = literal[i + 1..]
+                .iter()
+                .take(3)
+                .take_while(|c| (b'0'..=b'7').contains(c))
+                .count();
+
+            if digits == 0 {
+                // Not an octal escape, skip the backslash and the escaped
+                // character, which can be a backslash too.
+                i += 2;
+                continue;
+            }
+
+            let octal =
+                &src[span.start() + i + 1..span.start() + i + 1 + digits];
+
+            if let Ok(value) = u8::from_str_radix(octal, 8) {
+                let start = span.start() + i;
+                patches.push((
+                    start..start + 1 + digits,
+                    format!("\\x{value:02x}"),
+                ));
+            }
+
+            i += 1 + digits;
+        }
+    }
+
+    patches
+}
+
+/// Applies the patches to the source code. Each patch is a range within the
+/// source code, and the text that replaces it. Patches must not overlap.
+fn apply_patches(
+    src: &str,
+    mut patches: Vec<(Range<usize>, String)>,
+) -> String {
+    patches.sort_by_key(|(range, _)| range.start);
+    patches.dedup();
+
+    let mut result = String::with_capacity(src.len());
+    let mut pos = 0;
+
+    for (range, replacement) in patches {
+        result.push_str(&src[pos..range.start]);
+        result.push_str(&replacement);
+        pos = range.end;
+    }
+
+    result.push_str(&src[pos..]);
+    result
+}
+
 pub fn exec_fix_warnings(
     args: &ArgMatches,
     config: &Config,
@@ -236,17 +510,17 @@ pub fn exec_fix_warnings(
 }
 
 #[derive(Debug)]
-struct FixEncodingState {
+struct FixState {
     files_modified: AtomicUsize,
 }
 
-impl FixEncodingState {
+impl FixState {
     fn new() -> Self {
         Self { files_modified: AtomicUsize::new(0) }
     }
 }
 
-impl Component for FixEncodingState {
+impl Component for FixState {
     fn draw_unchecked(
         &self,
         _dimensions: superconsole::Dimensions,
@@ -8,13 +8,14 @@ use yara_x_fmt::{Formatter, Indentation};
 
 use crate::config::Config;
 use crate::help;
+use crate::walk::Walker;
 
 pub fn fmt() -> Command {
     super::command("fmt")
         .about("Format YARA source files")
         .arg(
             arg!(<FILE>)
-                .help("Path to YARA source file")
+                .help("Path to YARA source file or directory")
                 .required(true)
                 .value_parser(value_parser!(PathBuf))
                 .action(ArgAction::Append),
@@ -23,6 +24,20 @@ pub fn fmt() -> Command {
             arg!(-c --check  "Run in 'check' mode")
                 .long_help(help::FMT_CHECK_MODE),
         )
+        .arg(
+            arg!(-f --filter <PATTERN>)
+                .help("Format files that match the given pattern only")
+                .long_help(help::FILTER_LONG_HELP)
+                .action(ArgAction::Append),
+        )
+        .arg(
+            arg!(-r - -"recursive"[MAX_DEPTH])
+                .help("Walk directories recursively up to a given depth")
+                .long_help(help::RECURSIVE_LONG_HELP)
+                .default_missing_value("1000")
+                .require_equals(true)
+                .value_parser(value_parser!(usize)),
+        )
         .arg(
             arg!(-t - -"tab-size" <NUM_SPACES>)
                 .help("Tab size (in spaces) used in source files")
@@ -35,12 +50,17 @@ pub fn fmt() -> Command {
 pub fn exec_fmt(args: &ArgMatches, config: &Config) -> anyhow::Result<()> {
     let files = args.get_many::<PathBuf>("FILE").unwrap();
     let check = args.get_flag("check");
+    let filters = args.get_many::<String>("filter");
+    let recursive = args.get_one::<usize>("recursive");
     let tab_size = args.get_one::<usize>("tab-size").unwrap();
 
     let formatter = Formatter::new()
         .input_tab_size(*tab_size)
         .align_metadata(config.fmt.meta.align_values)
         .align_patterns(config.fmt.patterns.align_values)
+        .sort_metadata(config.fmt.meta.sort)
+        .sort_patterns(config.fmt.patterns.sort)
+        .normalize_hex_patterns(config.fmt.patterns.normalize_hex)
         .indent_section_headers(config.fmt.rule.indent_section_headers)
         .indent_section_contents(config.fmt.rule.indent_section_contents)
         .indentation(if config.fmt.rule.indent_spaces == 0 {
@@ -56,27 +76,48 @@ pub fn exec_fmt(args: &ArgMatches, config: &Config) -> anyhow::Result<()> {
             config.fmt.rule.empty_line_after_section_header,
         );
 
-    let mut modified_files: Vec<&PathBuf> = Vec::new();
+    let mut modified_files: Vec<PathBuf> = Vec::new();
 
-    for file in files {
-        let input = fs::read(file.as_path())?;
-        let file_modified = if check {
-            formatter.format(input.as_slice(), io::sink())?
-        } else {
-            let mut formatted = Cursor::new(Vec::with_capacity(input.len()));
-            if formatter.format(input.as_slice(), &mut formatted)? {
-                formatted.seek(SeekFrom::Start(0))?;
-                let mut output_file = File::create(file.as_path())?;
-                io::copy(&mut formatted, &mut output_file)?;
-                true
-            } else {
-                false
-            }
-        };
+    for path in files {
+        let mut w = Walker::path(path);
 
-        if file_modified {
-            modified_files.push(file);
+        if let Some(filters) = filters.clone() {
+            for filter in filters {
+                w.filter(filter);
+            }
+        } else {
+            // Default filters are `**/*.yar` and `**/*.yara`.
+            w.filter("**/*.yar").filter("**/*.yara");
         }
+
+        w.max_depth(*recursive.unwrap_or(&0));
+        w.walk(
+            |file| {
+                let input = fs::read(file)?;
+                let file_modified = if check {
+                    formatter.format(input.as_slice(), io::sink())?
+                } else {
+                    let mut formatted =
+                        Cursor::new(Vec::with_capacity(input.len()));
+                    if formatter.format(input.as_slice(), &mut formatted)? {
+                        formatted.seek(SeekFrom::Start(0))?;
+                        let mut output_file = File::create(file)?;
+                        io::copy(&mut formatted, &mut output_file)?;
+                        true
+                    } else {
+                        false
+                    }
+                };
+
+                if file_modified {
+                    modified_files.push(file.to_path_buf());
+                }
+
+                Ok(())
+            },
+            // Any error occurred during walk aborts the walk.
+            Err,
+        )?;
     }
 
     if !modified_files.is_empty() {
@@ -0,0 +1,604 @@
+use std::io::{BufRead, BufReader, BufWriter, ErrorKind, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context};
+use clap::{arg, value_parser, Arg, ArgAction, ArgMatches, Command};
+use crossbeam::channel::bounded;
+
+use yara_x::{MetaValue, Rule, Rules, Scanner};
+
+use crate::commands::{
+    compilation_args, get_external_vars, load_rules,
+    path_with_namespace_parser,
+};
+use crate::config::Config;
+use crate::help;
+
+/// Maximum size for the headers of an ICAP request, including the
+/// encapsulated HTTP headers.
+const MAX_HEADERS_SIZE: usize = 64 * 1024;
+
+/// Page returned to the HTTP client when some content is blocked, unless
+/// other template is specified with `--block-page`.
+const DEFAULT_BLOCK_PAGE: &str = r#"<!DOCTYPE html>
+<html>
+<head><title>Content blocked</title></head>
+<body>
+<h1>Content blocked</h1>
+<p>The requested content was blocked because it matched the following rules: {{rules}}</p>
+</body>
+</html>
+"#;
+
+#[rustfmt::skip]
+pub fn icap() -> Command {
+    super::command("icap")
+        .about("Start an ICAP server that scans the content sent by clients")
+        .long_about(help::ICAP_LONG_HELP)
+        .arg(
+            Arg::new("[NAMESPACE:]RULES_PATH")
+                .required(true)
+                .help("Path to a YARA source file or directory (optionally prefixed with a namespace)")
+                .value_parser(path_with_namespace_parser)
+                .action(ArgAction::Append)
+        )
+        .args(itertools::merge(compilation_args(), [
+            arg!(--"address" <ADDRESS>)
+                .help("Address where the server listens for connections")
+                .default_value("127.0.0.1:1344"),
+            arg!(--"block-page" <FILE>)
+                .help("HTML template for the page returned when content is blocked")
+                .long_help(help::ICAP_BLOCK_PAGE_LONG_HELP)
+                .value_parser(value_parser!(PathBuf)),
+            arg!(--"block-severity" <SEVERITY>)
+                .help("Block content matching rules with this severity or higher")
+                .long_help(help::ICAP_BLOCK_SEVERITY_LONG_HELP)
+                .value_parser(value_parser!(i64)),
+            arg!(--"block-tag" <TAG>)
+                .help("Block content matching rules with this tag")
+                .long_help(help::ICAP_BLOCK_TAG_LONG_HELP)
+                .action(ArgAction::Append),
+            arg!(-C --"compiled-rules")
+                .help("Indicate that RULES_PATH is a file with compiled rules")
+                .long_help(help::COMPILED_RULES_LONG_HELP),
+            arg!(--"max-body-size" <BYTES>)
+                .help("Reject requests with a body larger than the given size")
+                .default_value("104857600")
+                .value_parser(value_parser!(usize)),
+            arg!(--"max-connections" <NUM>)
+                .help("Maximum number of connections handled at the same time")
+                .long_help(help::ICAP_MAX_CONNECTIONS_LONG_HELP)
+                .default_value("32")
+                .value_parser(value_parser!(u16).range(1..)),
+            arg!(--"read-timeout" <SECONDS>)
+                .help("Close connections where the client doesn't send data for the given number of seconds")
+                .default_value("30")
+                .value_parser(value_parser!(u64).range(1..)),
+            arg!(--"severity-meta" <KEY>)
+                .help("Metadata key that contains the severity of each rule")
+                .default_value("severity"),
+            arg!(-a --"timeout" <SECONDS>)
+                .help("Abort scanning after the given number of seconds")
+                .value_parser(value_parser!(u64).range(1..)),
+        ]))
+}
+
+/// Settings that determine how the ICAP server handles requests.
+struct IcapServer {
+    rules: Rules,
+    external_vars: Option<Vec<(String, serde_json::Value)>>,
+    block_tags: Vec<String>,
+    block_severity: Option<i64>,
+    severity_meta: String,
+    block_page: String,
+    timeout: Option<Duration>,
+    read_timeout: Duration,
+    max_body_size: usize,
+}
+
+pub fn exec_icap(args: &ArgMatches, config: &Config) -> anyhow::Result<()> {
+    let rules_path = args
+        .get_many::<(Option<String>, PathBuf)>("[NAMESPACE:]RULES_PATH")
+        .unwrap();
+
+    let address = args.get_one::<String>("address").unwrap();
+    let max_connections = *args.get_one::<u16>("max-connections").unwrap();
+    let compiled_rules = args.get_flag("compiled-rules");
+
+    let rules = load_rules(rules_path, compiled_rules, args, config)?;
+
+    let block_page = match args.get_one::<PathBuf>("block-page") {
+        Some(path) => std::fs::read_to_string(path)
+            .with_context(|| format!("can not read `{}`", path.display()))?,
+        None => DEFAULT_BLOCK_PAGE.to_string(),
+    };
+
+    let server = IcapServer {
+        rules,
+        external_vars: get_external_vars(args),
+        block_tags: args
+            .get_many::<String>("block-tag")
+            .map(|tags| tags.cloned().collect())
+            .unwrap_or_default(),
+        block_severity: args.get_one::<i64>("block-severity").cloned(),
+        severity_meta: args
+            .get_one::<String>("severity-meta")
+            .unwrap()
+            .clone(),
+        block_page,
+        timeout: args
+            .get_one::<u64>("timeout")
+            .map(|t| Duration::from_secs(*t)),
+        read_timeout: Duration::from_secs(
+            *args.get_one::<u64>("read-timeout").unwrap(),
+        ),
+        max_body_size: *args.get_one::<usize>("max-body-size").unwrap(),
+    };
+
+    let listener = TcpListener::bind(address)
+        .with_context(|| format!("can not listen on `{address}`"))?;
+
+    println!("listening on {}", listener.local_addr()?);
+    std::io::stdout().flush()?;
+
+    // Connections are handled by a fixed number of threads. When all of
+    // them are busy, new connections wait in the listener's backlog until
+    // some thread finishes with its current connection.
+    let (sender, receiver) = bounded::<TcpStream>(0);
+
+    thread::scope(|s| -> anyhow::Result<()> {
+        for _ in 0..max_connections {
+            let receiver = receiver.clone();
+            let server = &server;
+            s.spawn(move || {
+                for stream in receiver {
+                    if let Err(err) = server.handle_connection(stream) {
+                        eprintln!("error: {err}");
+                    }
+                }
+            });
+        }
+
+        for stream in listener.incoming() {
+            match stream {
+                Ok(stream) => sender.send(stream)?,
+                Err(err) => eprintln!("error: {err}"),
+            }
+        }
+
+        Ok(())
+    })
+}
+
+/// An ICAP request.
+struct IcapRequest {
+    method: String,
+    headers: Vec<(String, String)>,
+    /// Value of the `Encapsulated` header, if any.
+    encapsulated: Option<String>,
+    /// Encapsulated HTTP headers.
+    http_headers: Vec<u8>,
+    /// Encapsulated HTTP body, if any.
+    body: Option<Vec<u8>>,
+    /// True if the whole body was received in the preview, and therefore
+    /// the response is still part of the preview phase, as no
+    /// `100 Continue` was sent.
+    in_preview: bool,
+}
+
+impl IcapRequest {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Returns true if the client accepts `204 No Content` responses.
+    ///
+    /// Responses to a preview can always be `204 No Content`, but once the
+    /// client was asked for the rest of the body with `100 Continue`, this
+    /// response is accepted only if the client sent `Allow: 204` (RFC 3507,
+    /// section 4.6).
+    fn allows_204(&self) -> bool {
+        self.in_preview
+            || self.header("Allow").is_some_and(|allow| {
+                allow.split(',').any(|v| v.trim() == "204")
+            })
+    }
+}
+
+impl IcapServer {
+    fn handle_connection(&self, stream: TcpStream) -> anyhow::Result<()> {
+        // Without a timeout, a client that stops sending data would keep
+        // the thread busy forever.
+        stream.set_read_timeout(Some(self.read_timeout))?;
+        stream.set_write_timeout(Some(self.read_timeout))?;
+
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let mut writer = BufWriter::new(stream);
+        let mut scanner = Scanner::new(&self.rules);
+
+        if let Some(vars) = &self.external_vars {
+            for (ident, value) in vars {
+                // It's ok to use `unwrap()`, this can not fail because
+                // the variables were already validated during compilation.
+                scanner.set_global(ident.as_str(), value.clone()).unwrap();
+            }
+        }
+
+        if let Some(timeout) = self.timeout {
+            scanner.set_timeout(timeout);
+        }
+
+        // ICAP connections are persistent, the client can send multiple
+        // requests over the same connection.
+        loop {
+            let request = match read_request(
+                &mut reader,
+                &mut writer,
+                self.max_body_size,
+            ) {
+                Ok(Some(request)) => request,
+                Ok(None) => return Ok(()),
+                // The client was idle for too long, the connection is
+                // closed without a response.
+                Err(err) if is_timeout(&err) => return Ok(()),
+                Err(err) => {
+                    write_status(&mut writer, 400, "Bad Request")?;
+                    writer.flush()?;
+                    return Err(err);
+                }
+            };
+
+            match request.method.as_str() {
+                "OPTIONS" => self.options(&mut writer)?,
+                "REQMOD" | "RESPMOD" => {
+                    self.modify(&mut scanner, &request, &mut writer)?
+                }
+                _ => write_status(&mut writer, 405, "Method Not Allowed")?,
+            }
+
+            writer.flush()?;
+        }
+    }
+
+    /// Responds to an `OPTIONS` request.
+    fn options(&self, writer: &mut impl Write) -> anyhow::Result<()> {
+        write!(writer, "ICAP/1.0 200 OK\r\n")?;
+        write!(writer, "Methods: REQMOD, RESPMOD\r\n")?;
+        write!(writer, "Service: YARA-X {}\r\n", env!("CARGO_PKG_VERSION"))?;
+        write!(writer, "ISTag: \"{}\"\r\n", istag())?;
+        write!(writer, "Allow: 204\r\n")?;
+        write!(writer, "Encapsulated: null-body=0\r\n\r\n")?;
+        Ok(())
+    }
+
+    /// Responds to `REQMOD` and `RESPMOD` requests, scanning the body of the
+    /// encapsulated HTTP message.
+    fn modify(
+        &self,
+        scanner: &mut Scanner,
+        request: &IcapRequest,
+        writer: &mut impl Write,
+    ) -> anyhow::Result<()> {
+        let body = request.body.as_deref().unwrap_or_default();
+
+        let blocking_rules: Vec<String> = match scanner.scan(body) {
+            Ok(results) => results
+                .matching_rules()
+                .filter(|rule| self.is_blocking(rule))
+                .map(|rule| rule.identifier().to_string())
+                .collect(),
+            Err(err) => {
+                eprintln!("error: {err}");
+                write_status(writer, 500, "Server Error")?;
+                return Ok(());
+            }
+        };
+
+        if !blocking_rules.is_empty() {
+            let page = self
+                .block_page
+                .replace("{{rules}}", &blocking_rules.join(", "));
+            let http_headers = format!(
+                "HTTP/1.1 403 Forbidden\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\n\r\n",
+                page.len()
+            );
+            write!(writer, "ICAP/1.0 200 OK\r\n")?;
+            write!(writer, "ISTag: \"{}\"\r\n", istag())?;
+            write!(
+                writer,
+                "X-Infection-Found: Type=0; Resolution=2; Threat={};\r\n",
+                blocking_rules[0]
+            )?;
+            write!(
+                writer,
+                "X-Violations-Found: {}\r\n",
+                blocking_rules.len()
+            )?;
+            write!(
+                writer,
+                "Encapsulated: res-hdr=0, res-body={}\r\n\r\n",
+                http_headers.len()
+            )?;
+            writer.write_all(http_headers.as_bytes())?;
+            write_chunked(writer, page.as_bytes())?;
+        } else if request.allows_204() {
+            write!(writer, "ICAP/1.0 204 No Content\r\n")?;
+            write!(writer, "ISTag: \"{}\"\r\n\r\n", istag())?;
+        } else {
+            // The client doesn't accept `204 No Content`, the encapsulated
+            // message is returned unmodified.
+            write!(writer, "ICAP/1.0 200 OK\r\n")?;
+            write!(writer, "ISTag: \"{}\"\r\n", istag())?;
+            if let Some(encapsulated) = &request.encapsulated {
+                write!(writer, "Encapsulated: {encapsulated}\r\n")?;
+            }
+            write!(writer, "\r\n")?;
+            writer.write_all(request.http_headers.as_slice())?;
+            if let Some(body) = &request.body {
+                write_chunked(writer, body)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if a matching rule must cause the content to be blocked.
+    ///
+    /// When neither `--block-tag` nor `--block-severity` are used, any
+    /// matching rule blocks the content.
+    fn is_blocking(&self, rule: &Rule) -> bool {
+        if self.block_tags.is_empty() && self.block_severity.is_none() {
+            return true;
+        }
+
+        if rule
+            .tags()
+            .any(|tag| self.block_tags.iter().any(|t| t == tag.identifier()))
+        {
+            return true;
+        }
+
+        let Some(block_severity) = self.block_severity else {
+            return false;
+        };
+
+        rule.metadata().any(|(key, value)| {
+            key == self.severity_meta
+                && match value {
+                    MetaValue::Integer(severity) => severity >= block_severity,
+                    MetaValue::String(severity) => severity
+                        .parse::<i64>()
+                        .is_ok_and(|severity| severity >= block_severity),
+                    _ => false,
+                }
+        })
+    }
+}
+
+/// Returns the ISTag that identifies the current state of the ICAP service.
+fn istag() -> String {
+    format!("yara-x-{}", env!("CARGO_PKG_VERSION"))
+}
+
+/// Writes an ICAP response that consists only of a status line.
+fn write_status(
+    writer: &mut impl Write,
+    code: u16,
+    reason: &str,
+) -> anyhow::Result<()> {
+    write!(writer, "ICAP/1.0 {code} {reason}\r\n")?;
+    write!(writer, "ISTag: \"{}\"\r\n", istag())?;
+    write!(writer, "Encapsulated: null-body=0\r\n\r\n")?;
+    Ok(())
+}
+
+/// Writes `data` using the chunked transfer encoding, as a single chunk
+/// followed by the terminating zero-length chunk.
+fn write_chunked(writer: &mut impl Write, data: &[u8]) -> anyhow::Result<()> {
+    if !data.is_empty() {
+        write!(writer, "{:x}\r\n", data.len())?;
+        writer.write_all(data)?;
+        write!(writer, "\r\n")?;
+    }
+    write!(writer, "0\r\n\r\n")?;
+    Ok(())
+}
+
+/// Returns true if `err` is caused by the read timeout of a connection.
+fn is_timeout(err: &anyhow::Error) -> bool {
+    err.downcast_ref::<std::io::Error>().is_some_and(|err| {
+        matches!(err.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut)
+    })
+}
+
+/// Reads a line terminated by CRLF, returning it without the terminator.
+/// Returns `None` if the end of the stream is reached.
+///
+/// Lines longer than [`MAX_HEADERS_SIZE`] are rejected before reading them
+/// completely, so that a client can't make the server buffer an arbitrarily
+/// large line.
+fn read_line(reader: &mut impl BufRead) -> anyhow::Result<Option<String>> {
+    let mut line = String::new();
+    let n =
+        reader.by_ref().take(MAX_HEADERS_SIZE as u64).read_line(&mut line)?;
+    if n == 0 {
+        return Ok(None);
+    }
+    if n == MAX_HEADERS_SIZE && !line.ends_with('\n') {
+        bail!("line is too long");
+    }
+    Ok(Some(line.trim_end_matches(['\r', '\n']).to_string()))
+}
+
+/// Reads an ICAP request from `reader`. Returns `None` if the client closed
+/// the connection.
+///
+/// `writer` is used for sending the `100 Continue` response when the client
+/// sends a preview of the body and more data is needed. Requests with a body
+/// larger than `max_body_size` are rejected.
+fn read_request(
+    reader: &mut impl BufRead,
+    writer: &mut impl Write,
+    max_body_size: usize,
+) -> anyhow::Result<Option<IcapRequest>> {
+    // Read the request line, ignoring any empty lines before it.
+    let request_line = loop {
+        match read_line(reader)? {
+            Some(line) if line.is_empty() => continue,
+            Some(line) => break line,
+            None => return Ok(None),
+        }
+    };
+
+    let mut parts = request_line.split_whitespace();
+
+    let (Some(method), Some(_uri), Some(version)) =
+        (parts.next(), parts.next(), parts.next())
+    else {
+        bail!("invalid request line: `{request_line}`");
+    };
+
+    if version != "ICAP/1.0" {
+        bail!("unsupported ICAP version: `{version}`");
+    }
+
+    let mut headers = Vec::new();
+    let mut headers_size = 0;
+
+    loop {
+        let line = match read_line(reader)? {
+            Some(line) if line.is_empty() => break,
+            Some(line) => line,
+            None => bail!("unexpected end of request"),
+        };
+        headers_size += line.len();
+        if headers_size > MAX_HEADERS_SIZE {
+            bail!("request headers are too large");
+        }
+        let Some((name, value)) = line.split_once(':') else {
+            bail!("invalid header: `{line}`");
+        };
+        headers.push((name.trim().to_string(), value.trim().to_string()));
+    }
+
+    let mut request = IcapRequest {
+        method: method.to_string(),
+        encapsulated: headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case("Encapsulated"))
+            .map(|(_, value)| value.clone()),
+        headers,
+        http_headers: Vec::new(),
+        body: None,
+        in_preview: false,
+    };
+
+    let Some(encapsulated) = &request.encapsulated else {
+        return Ok(Some(request));
+    };
+
+    // The `Encapsulated` header looks like `req-hdr=0, res-hdr=137,
+    // res-body=296`. The last entry is the body (or `null-body`), and its
+    // offset is the total size of the encapsulated HTTP headers.
+    let mut sections = Vec::new();
+
+    for entry in encapsulated.split(',') {
+        let Some((name, offset)) = entry.trim().split_once('=') else {
+            bail!("invalid `Encapsulated` header: `{encapsulated}`");
+        };
+        let offset = offset.trim().parse::<usize>().with_context(|| {
+            format!("invalid `Encapsulated` header: `{encapsulated}`")
+        })?;
+        sections.push((name.trim().to_string(), offset));
+    }
+
+    let Some((last_section, headers_len)) = sections.last().cloned() else {
+        bail!("invalid `Encapsulated` header: `{encapsulated}`");
+    };
+
+    if headers_len > MAX_HEADERS_SIZE {
+        bail!("encapsulated headers are too large");
+    }
+
+    request.http_headers = vec![0; headers_len];
+    reader.read_exact(request.http_headers.as_mut_slice())?;
+
+    if last_section.ends_with("-body") && last_section != "null-body" {
+        let mut body = Vec::new();
+        let ieof = read_chunks(reader, &mut body, max_body_size)?;
+        // When the client sends a preview of the body, and the preview
+        // is not the whole body, the rest of the body is requested by
+        // responding with `100 Continue`, which ends the preview phase.
+        if request.header("Preview").is_some() {
+            if ieof {
+                request.in_preview = true;
+            } else {
+                write!(writer, "ICAP/1.0 100 Continue\r\n\r\n")?;
+                writer.flush()?;
+                read_chunks(reader, &mut body, max_body_size)?;
+            }
+        }
+        request.body = Some(body);
+    }
+
+    Ok(Some(request))
+}
+
+/// Reads chunks in chunked transfer encoding until the terminating
+/// zero-length chunk is found, appending their data to `body`. Fails if the
+/// size of `body` would exceed `max_body_size`.
+///
+/// Returns true if the zero-length chunk has the `ieof` extension, which
+/// indicates that there's no more data after a preview.
+fn read_chunks(
+    reader: &mut impl BufRead,
+    body: &mut Vec<u8>,
+    max_body_size: usize,
+) -> anyhow::Result<bool> {
+    loop {
+        let Some(line) = read_line(reader)? else {
+            bail!("unexpected end of chunked body");
+        };
+        let (size, extension) = match line.split_once(';') {
+            Some((size, extension)) => (size, Some(extension.trim())),
+            None => (line.as_str(), None),
+        };
+        let size = usize::from_str_radix(size.trim(), 16)
+            .with_context(|| format!("invalid chunk size: `{line}`"))?;
+
+        if size == 0 {
+            // Skip any trailer headers until the empty line.
+            let mut trailers_size = 0;
+            while let Some(line) = read_line(reader)? {
+                if line.is_empty() {
+                    break;
+                }
+                trailers_size += line.len();
+                if trailers_size > MAX_HEADERS_SIZE {
+                    bail!("chunk trailers are too large");
+                }
+            }
+            return Ok(extension == Some("ieof"));
+        }
+
+        let start = body.len();
+        let end = start
+            .checked_add(size)
+            .filter(|end| *end <= max_body_size)
+            .ok_or_else(|| {
+                anyhow!("body is larger than {max_body_size} bytes")
+            })?;
+
+        body.resize(end, 0);
+        reader.read_exact(&mut body[start..])?;
+
+        // Each chunk is followed by a CRLF.
+        read_line(reader)?;
+    }
+}
@@ -0,0 +1,47 @@
+use std::fs;
+use std::io::stdout;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use clap::{arg, value_parser, ArgMatches, Command};
+use yara_x::SourceCode;
+
+use crate::commands::{compilation_args, create_compiler, get_external_vars};
+use crate::config::Config;
+use crate::help;
+
+pub fn ir() -> Command {
+    super::command("ir")
+        .about(
+            "Print the Intermediate Representation (IR) for a YARA source file",
+        )
+        .long_about(help::IR_LONG_HELP)
+        .arg(
+            arg!(<RULES_PATH>)
+                .help("Path to YARA source file")
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .args(
+            compilation_args()
+                .into_iter()
+                .filter(|arg| arg.get_id() != "path-as-namespace"),
+        )
+}
+
+pub fn exec_ir(args: &ArgMatches, config: &Config) -> anyhow::Result<()> {
+    let rules_path = args.get_one::<PathBuf>("RULES_PATH").unwrap();
+
+    let src = fs::read(rules_path)
+        .with_context(|| format!("can not read `{}`", rules_path.display()))?;
+
+    let src = SourceCode::from(src.as_slice())
+        .with_origin(rules_path.as_os_str().to_str().unwrap());
+
+    let external_vars = get_external_vars(args);
+    let mut compiler = create_compiler(external_vars, args, config)?;
+
+    compiler.set_ir_writer(stdout());
+    compiler.add_source(src)?;
+
+    Ok(())
+}
@@ -0,0 +1,19 @@
+#![cfg(feature = "lsp")]
+use clap::{ArgMatches, Command};
+
+use crate::help;
+
+pub fn lsp() -> Command {
+    super::command("lsp")
+        .about("Start the YARA-X language server")
+        .long_about(help::LSP_LONG_HELP)
+}
+
+pub fn exec_lsp(_args: &ArgMatches) -> anyhow::Result<()> {
+    let runtime =
+        tokio::runtime::Builder::new_current_thread().enable_all().build()?;
+
+    runtime.block_on(yara_x_ls::serve_stdio())?;
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use anyhow::{bail, Context};
+use clap::{arg, value_parser, ArgMatches, Command};
+use clap_mangen::Man;
+use yansi::Paint;
+
+use crate::commands::cli;
+use crate::help;
+
+pub fn man() -> Command {
+    super::command("man")
+        .about("Output man pages for the CLI")
+        .long_about(help::MAN_LONG_HELP)
+        // Keep options sorted alphabetically by their long name.
+        // For instance, --bar goes before --foo.
+        .arg(
+            arg!([COMMAND])
+                .help("Command whose man page will be printed")
+                .conflicts_with("output-dir"),
+        )
+        .arg(
+            arg!(-o --"output-dir" <DIR>)
+                .help("Write the man pages for all commands to DIR")
+                .value_parser(value_parser!(PathBuf)),
+        )
+}
+
+pub fn exec_man(args: &ArgMatches) -> anyhow::Result<()> {
+    // The command's name defaults to the package name, but man pages must
+    // use the name of the binary.
+    let mut cli =
+        cli().name("yr").bin_name("yr").disable_help_subcommand(true);
+
+    if let Some(output_dir) = args.get_one::<PathBuf>("output-dir") {
+        fs::create_dir_all(output_dir)
+            .with_context(|| format!("can not create {:?}", output_dir))?;
+        clap_mangen::generate_to(cli, output_dir)
+            .with_context(|| format!("can not write to {:?}", output_dir))?;
+        return Ok(());
+    }
+
+    // Building the command sets the names of subcommands to their full
+    // form (e.g: `yr-scan`), which is the name used in their man pages.
+    cli.build();
+
+    let cmd = match args.get_one::<String>("COMMAND") {
+        Some(name) => match cli.find_subcommand(name) {
+            Some(cmd) => cmd.clone(),
+            None => bail!("unknown command '{}'", Paint::bold(name)),
+        },
+        None => cli,
+    };
+
+    Man::new(cmd).render(&mut io::stdout())?;
+
+    Ok(())
+}
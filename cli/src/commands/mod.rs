@@ -1,21 +1,46 @@
+mod bench;
 mod check;
+#[cfg(feature = "compat")]
+mod compat;
 mod compile;
 mod completion;
+mod coverage;
 mod debug;
+mod dedupe;
+mod doc;
 mod dump;
 mod fix;
 mod fmt;
+mod icap;
+mod ir;
+mod lsp;
+mod man;
 mod scan;
+mod test;
+mod worker;
 
+pub use bench::*;
 pub use check::*;
+#[cfg(feature = "compat")]
+pub use compat::*;
 pub use compile::*;
 pub use completion::*;
+pub use coverage::*;
 #[cfg(feature = "debug-cmd")]
 pub use debug::*;
+pub use dedupe::*;
+pub use doc::*;
 pub use dump::*;
 pub use fix::*;
 pub use fmt::*;
+pub use icap::*;
+pub use ir::*;
+#[cfg(feature = "lsp")]
+pub use lsp::*;
+pub use man::*;
 pub use scan::*;
+pub use test::*;
+pub use worker::*;
 
 use std::borrow::Cow;
 use std::fs;
@@ -64,12 +89,25 @@ pub fn cli() -> Command {
             commands::scan(),
             commands::compile(),
             commands::check(),
+            commands::bench(),
+            #[cfg(feature = "compat")]
+            commands::compat(),
+            commands::coverage(),
             #[cfg(feature = "debug-cmd")]
             commands::debug(),
+            commands::dedupe(),
+            commands::doc(),
             commands::dump(),
             commands::fmt(),
             commands::fix(),
+            commands::icap(),
+            commands::ir(),
+            commands::test(),
+            commands::worker(),
+            #[cfg(feature = "lsp")]
+            commands::lsp(),
             commands::completion(),
+            commands::man(),
         ])
 }
 
@@ -83,7 +121,14 @@ fn get_external_vars(
 }
 
 /// Parses the arguments to the `--define` option, which have the form
-/// `VAR=VALUE`.
+/// `VAR=VALUE` or `VAR:TYPE=VALUE`.
+///
+/// When the type is omitted it's inferred from the value: `true` and `false`
+/// are booleans, numbers are integers or floats, and anything else is a
+/// string. Strings can also be enclosed in double quotes, which is useful
+/// for strings that would be inferred as another type, like `"true"`. The
+/// type can be given explicitly with a `:int`, `:float`, `:bool` or `:str`
+/// suffix in the variable name.
 ///
 /// Returns the variable name and the value as a [`serde_json::Value`].
 fn external_var_parser(
@@ -93,11 +138,44 @@ fn external_var_parser(
         "the equal sign is missing, use the syntax VAR=VALUE (example: {option}=10)",
     ))?;
 
-    let value = serde_json::from_str(value).map_err(|_| {
-        anyhow!(
-            "`{value}` is not a valid value, did you mean \\\"{value}\\\"?"
-        )
-    })?;
+    let (var, ty) = match var.split_once(':') {
+        Some((var, ty)) => (var, Some(ty)),
+        None => (var, None),
+    };
+
+    let invalid_value =
+        || anyhow!("`{value}` is not a valid value of type `{}`", ty.unwrap());
+
+    let value = match ty {
+        None => match serde_json::from_str(value) {
+            Ok(
+                value @ (serde_json::Value::Bool(_)
+                | serde_json::Value::Number(_)
+                | serde_json::Value::String(_)),
+            ) => value,
+            _ => serde_json::Value::String(value.to_string()),
+        },
+        Some("int") => value
+            .parse::<i64>()
+            .map(serde_json::Value::from)
+            .map_err(|_| invalid_value())?,
+        Some("float") => value
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map(serde_json::Value::Number)
+            .ok_or_else(invalid_value)?,
+        Some("bool") => value
+            .parse::<bool>()
+            .map(serde_json::Value::Bool)
+            .map_err(|_| invalid_value())?,
+        Some("str") => serde_json::Value::String(value.to_string()),
+        Some(ty) => {
+            bail!(
+                "unknown type `{ty}`, valid types are: int, float, bool, str"
+            )
+        }
+    };
 
     Ok((var.to_string(), value))
 }
@@ -163,7 +241,9 @@ pub fn create_compiler<'a>(
                 .cloned()
                 .unwrap_or_default(),
         )
-        .colorize_errors(stdout().is_tty());
+        // Colors are disabled when stdout is not a tty, and by options
+        // like `--no-color`.
+        .colorize_errors(stdout().is_tty() && yansi::is_enabled());
 
     for module in args
         .try_get_many::<String>("ignore-module")
@@ -247,6 +327,46 @@ pub fn compilation_args() -> [Arg; 6] {
     ]
 }
 
+/// Loads the rules passed as `[NAMESPACE:]RULES_PATH`.
+///
+/// If `compiled` is true the rules are deserialized from a single file
+/// produced by `yr compile`, as required by `--compiled-rules`. Otherwise,
+/// the rules are compiled from source code with [`compile_rules`].
+pub fn load_rules<'a, P>(
+    mut paths: P,
+    compiled: bool,
+    args: &ArgMatches,
+    config: &Config,
+) -> Result<Rules, anyhow::Error>
+where
+    P: ExactSizeIterator<Item = &'a (Option<String>, PathBuf)>,
+{
+    if !compiled {
+        return compile_rules(paths, args, config);
+    }
+
+    if paths.len() > 1 {
+        bail!(
+            "can't use '{}' with more than one RULES_PATH",
+            Paint::bold("--compiled-rules")
+        );
+    }
+
+    let (namespace, rules_path) = paths.next().unwrap();
+
+    if namespace.is_some() {
+        bail!(
+            "can't use namespace with '{}'",
+            Paint::bold("--compiled-rules")
+        );
+    }
+
+    let file = fs::File::open(rules_path)
+        .with_context(|| format!("can not open {:?}", &rules_path))?;
+
+    Ok(Rules::deserialize_from(file)?)
+}
+
 pub fn compile_rules<'a, P>(
     paths: P,
     args: &ArgMatches,
@@ -1,10 +1,14 @@
 use std::borrow::Cow;
 use std::cmp::min;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs::File;
+use std::io::{Read, Write};
+use std::mem;
 use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::process;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::Mutex;
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime};
 
 use anyhow::{bail, Context, Error};
 use clap::{
@@ -14,21 +18,30 @@ use crossbeam::channel::Sender;
 use itertools::Itertools;
 use superconsole::style::Stylize;
 use superconsole::{Component, Line, Lines, Span};
-#[cfg(feature = "rules-profiling")]
-use yansi::Color::Green;
-use yansi::Color::{Cyan, Red, Yellow};
+use yansi::Color::{Cyan, Green, Red, Yellow};
 use yansi::Paint;
 
 use yara_x::errors::ScanError;
-use yara_x::{MetaValue, Patterns, Rule, Rules, ScanOptions, Scanner};
+use yara_x::{
+    MetaValue, ModuleOutputs, OversizedDataPolicy, Patterns, Rule, Rules,
+    ScanOptions, ScanResults, Scanner,
+};
 
 use crate::commands::{
-    compilation_args, compile_rules, get_external_vars,
+    compilation_args, compile_rules, get_external_vars, load_rules,
     meta_file_value_parser, path_with_namespace_parser,
     truncate_with_ellipsis,
 };
 use crate::walk::Message;
-use crate::{help, walk};
+use crate::{archive, help, process_memory, walk};
+
+#[derive(Clone, ValueEnum)]
+enum GroupBy {
+    /// Print the matching rules for each file (default).
+    File,
+    /// Print the matching files for each rule, once all files are scanned.
+    Rule,
+}
 
 #[derive(Clone, ValueEnum)]
 enum OutputFormats {
@@ -38,6 +51,12 @@ enum OutputFormats {
     Ndjson,
     /// JSON output (i.e: one JSON object for all results, only printed out at the end).
     Json,
+    /// STIX 2.1 bundle with indicators and sightings, printed out at the end.
+    Stix,
+    /// MISP event with attributes for the matching files, printed out at the end.
+    Misp,
+    /// Length-delimited protobuf messages (i.e: one `ScanResults` message per file).
+    Proto,
 }
 
 #[rustfmt::skip]
@@ -45,9 +64,17 @@ pub fn scan() -> Command {
     super::command("scan")
         .about("Scan a file or directory")
         .long_about(help::SCAN_LONG_HELP)
+        // When `--files-from` or `--pid` are used <TARGET_PATH> is omitted,
+        // and the only positional argument is a rules path. Clap assigns it
+        // to <TARGET_PATH>, `exec_scan` moves it to the rules paths.
+        .allow_missing_positional(true)
+        .override_usage(
+            "yr scan [OPTIONS] <[NAMESPACE:]RULES_PATH>... <TARGET_PATH>\n       \
+             yr scan [OPTIONS] --files-from <LIST> <[NAMESPACE:]RULES_PATH>...\n       \
+             yr scan [OPTIONS] --pid <PID> <[NAMESPACE:]RULES_PATH>...")
         .arg(
             Arg::new("[NAMESPACE:]RULES_PATH")
-                .required(true)
+                .required_unless_present_any(["files-from", "pid"])
                 .help("Path to a YARA source file or directory (optionally prefixed with a namespace)")
                 .value_parser(path_with_namespace_parser)
                 .action(ArgAction::Append)
@@ -65,6 +92,36 @@ pub fn scan() -> Command {
                 .help("Print only the number of matches per file"),
             arg!(--"disable-console-logs")
                 .help("Disable printing console log messages"),
+            arg!(--"fail-on-error")
+                .help("Exit with code 2 if any error occurs")
+                .long_help(help::SCAN_FAIL_ON_ERROR_LONG_HELP)
+                .conflicts_with("watch"),
+            arg!(--"fail-on-match")
+                .help("Exit with code 1 if any file matches")
+                .long_help(help::SCAN_FAIL_ON_MATCH_LONG_HELP)
+                .conflicts_with("watch"),
+            arg!(--"files-from" <LIST>)
+                .help("Scan the files listed in LIST, or in stdin if LIST is -")
+                .long_help(help::FILES_FROM_LONG_HELP)
+                .conflicts_with("scan-list")
+                .value_parser(value_parser!(PathBuf)),
+            arg!(--"group-by" <GROUP>)
+                .help("Group results by file or by rule")
+                .long_help(help::SCAN_GROUP_BY_LONG_HELP)
+                .conflicts_with("count")
+                .value_parser(value_parser!(GroupBy)),
+            arg!(--"max-archive-entries" <NUM_FILES>)
+                .help("Maximum number of files extracted from each archive")
+                .requires("scan-archives")
+                .value_parser(value_parser!(usize)),
+            arg!(--"max-archive-size" <SIZE>)
+                .help("Maximum number of bytes extracted from each archive")
+                .requires("scan-archives")
+                .value_parser(value_parser!(u64)),
+            arg!(--"max-loop-iterations" <ITERATIONS>)
+                .help("Maximum number of iterations for loops with unknown bounds")
+                .long_help(help::MAX_LOOP_ITERATIONS_LONG_HELP)
+                .value_parser(value_parser!(u64)),
             arg!(--"max-matches-per-pattern" <MATCHES>)
                 .help("Maximum number of matches per pattern")
                 .long_help(help::MAX_MATCHES_PER_PATTERN_LONG_HELP)
@@ -78,6 +135,8 @@ pub fn scan() -> Command {
                 .action(ArgAction::Append),
             arg!(-n --"negate")
                 .help("Print non-satisfied rules only"),
+            arg!(--"no-color")
+                .help("Turn off colors in the output"),
             arg!(--"no-mmap")
                 .help("Don't use memory-mapped files")
                 .long_help(help::NO_MMAP_LONG_HELP),
@@ -85,6 +144,11 @@ pub fn scan() -> Command {
                 .help("Output format for results")
                 .long_help(help::OUTPUT_FORMAT_LONG_HELP)
                 .value_parser(value_parser!(OutputFormats)),
+            arg!(--"pid" <PID>)
+                .help("Scan the memory of the process with the given PID")
+                .long_help(help::PID_LONG_HELP)
+                .conflicts_with_all(["files-from", "scan-list", "recursive", "scan-archives"])
+                .value_parser(value_parser!(u32)),
             arg!(-m --"print-meta")
                 .help("Print rule metadata"),
             arg!(-e --"print-namespace")
@@ -98,19 +162,30 @@ pub fn scan() -> Command {
             arg!(-g --"print-tags")
                 .help("Print rule tags"),
             arg!(--"profiling")
-                .help("Show profiling information"),
+                .help("Show profiling information")
+                .visible_alias("profile"),
+            arg!(-q --"quiet")
+                .help("Print only the paths of matching files")
+                .long_help(help::SCAN_QUIET_LONG_HELP)
+                .conflicts_with_all(["count", "group-by", "print-meta", "print-namespace", "print-strings", "print-tags"]),
             arg!(-r --"recursive" [MAX_DEPTH])
                 .help("Scan directories recursively")
                 .long_help(help::SCAN_RECURSIVE_LONG_HELP)
                 .default_missing_value("1000")
                 .require_equals(true)
                 .value_parser(value_parser!(usize)),
+            arg!(--"scan-archives")
+                .help("Scan the files inside zip, tar and gzip archives")
+                .long_help(help::SCAN_ARCHIVES_LONG_HELP),
             arg!(--"scan-list")
                 .help("Indicate that TARGET_PATH is a file containing the paths to be scanned")
                 .long_help(help::SCAN_LIST_LONG_HELP),
             arg!(-z --"skip-larger" <FILE_SIZE>)
                 .help("Skip files larger than the given size")
                 .value_parser(value_parser!(u64)),
+            arg!(--"stats")
+                .help("Print scan statistics for each file and a final summary")
+                .long_help(help::SCAN_STATS_LONG_HELP),
             arg!(-t --"tag" <TAG>)
                 .help("Print only rules tagged as TAG")
                 .value_parser(value_parser!(String)),
@@ -120,11 +195,415 @@ pub fn scan() -> Command {
                 .value_parser(value_parser!(u8).range(1..)),
             arg!(-a --"timeout" <SECONDS>)
                 .help("Abort scanning after the given number of seconds")
-                .value_parser(value_parser!(u64).range(1..))
+                .value_parser(value_parser!(u64).range(1..)),
+            arg!(--"truncate-larger" <FILE_SIZE>)
+                .help("Scan only the first FILE_SIZE bytes of larger files")
+                .value_parser(value_parser!(u64)),
+            arg!(--"watch")
+                .help("Rescan when the rules or the scanned files change")
+                .long_help(help::WATCH_LONG_HELP)
+                .conflicts_with_all(["compiled-rules", "files-from", "pid", "scan-list"])
 
     ]))
 }
 
+/// Scans the files inside `file_path` if it is an archive.
+///
+/// The results for each file are passed to `handle_results`, together with
+/// a path formed by the path of the archive and the path of the file within
+/// the archive, like `archive.zip!dir/file.txt`.
+fn scan_archive<'a, O, H>(
+    file_path: &Path,
+    limits: &archive::Limits,
+    scanner: &mut Scanner,
+    scan_options: O,
+    handle_results: H,
+    output: &Sender<Message>,
+) -> anyhow::Result<()>
+where
+    O: Fn() -> ScanOptions<'a>,
+    H: Fn(&Path, ScanResults),
+{
+    let entry_path = |path: &str| {
+        let mut entry_path = file_path.as_os_str().to_os_string();
+        if !path.is_empty() {
+            entry_path.push(archive::PATH_SEPARATOR.to_string());
+            entry_path.push(path);
+        }
+        PathBuf::from(entry_path)
+    };
+
+    // Read the first bytes of the file for determining if it is an archive,
+    // before reading the whole file.
+    let mut header = Vec::with_capacity(512);
+    File::open(file_path)?.take(512).read_to_end(&mut header)?;
+
+    if !archive::is_archive(&header) {
+        return Ok(());
+    }
+
+    let data = std::fs::read(file_path)?;
+    let name = file_path.file_name().unwrap_or_default().to_string_lossy();
+
+    archive::for_each_entry(
+        &name,
+        &data,
+        limits,
+        |path, content| {
+            let path = entry_path(path);
+            let scan_results = scanner
+                .scan_with_options(content, scan_options())
+                .with_context(|| format!("scanning {:?}", &path))?;
+            handle_results(&path, scan_results);
+            Ok(())
+        },
+        |path, msg| {
+            output
+                .send(Message::Error(format!(
+                    "{}{}: {}",
+                    "warning: ".paint(Yellow).bold(),
+                    entry_path(path).display(),
+                    msg
+                )))
+                .unwrap();
+        },
+    )
+}
+
+/// Sends the results for a scanned file or memory region to the output
+/// handler.
+///
+/// `base_offset` is added to the offsets of the matches. Returns `true` if
+/// the results were included in the output, see
+/// [`OutputHandler::on_file_scanned`].
+fn report_results(
+    path: &Path,
+    base_offset: usize,
+    scan_results: &ScanResults,
+    negate: bool,
+    output_handler: &dyn OutputHandler,
+    output: &Sender<Message>,
+) -> bool {
+    // Warn about patterns that reached the maximum number of matches, as
+    // some of their matches won't be reported.
+    for rule in scan_results.matching_rules() {
+        for pattern in rule.patterns().filter(|p| p.limit_reached()) {
+            output
+                .send(Message::Error(format!(
+                    "{}{}: pattern `{}` in rule `{}` reached the maximum number of matches",
+                    "warning: ".paint(Yellow).bold(),
+                    path.display(),
+                    pattern.identifier(),
+                    rule.identifier(),
+                )))
+                .unwrap();
+        }
+    }
+
+    // Warn about rules with loops that reached the maximum number of
+    // iterations, as their results may not be accurate.
+    for rule in scan_results.loop_limit_reached() {
+        output
+            .send(Message::Error(format!(
+                "{}{}: a loop in rule `{}` reached the maximum number of iterations",
+                "warning: ".paint(Yellow).bold(),
+                path.display(),
+                rule.identifier(),
+            )))
+            .unwrap();
+    }
+
+    let mut wanted_rules = match negate {
+        true => Box::new(scan_results.non_matching_rules())
+            as Box<dyn ExactSizeIterator<Item = Rule>>,
+        false => Box::new(scan_results.matching_rules()),
+    };
+
+    // The scanned data is passed to the output handler only if it was
+    // scanned completely, a truncated prefix doesn't identify the file.
+    let scanned_data =
+        scan_results.data().filter(|_| !scan_results.is_truncated());
+
+    output_handler.on_file_scanned(
+        path,
+        base_offset,
+        scanned_data,
+        &mut wanted_rules,
+        scan_results.module_outputs(),
+        output,
+    )
+}
+
+/// Scans the memory of the process identified by `pid`.
+///
+/// Each readable memory region in the process is scanned individually, and
+/// the results are reported with paths like `pid:1234:0x7f00-0x7f80`, where
+/// the range is the range of addresses occupied by the region. The offsets
+/// of the matches are virtual addresses in the process, not offsets relative
+/// to the start of the region. Regions that can't be read, or are larger than
+/// `max_region_size`, are skipped.
+///
+/// Errors while scanning a region are reported and scanning continues with
+/// the next region, except for timeouts, which abort the scan. Returns the
+/// number of regions that were included in the output and the number of
+/// errors.
+fn scan_process(
+    pid: u32,
+    scanner: &mut Scanner,
+    max_region_size: u64,
+    negate: bool,
+    console_logs: bool,
+    output_handler: &dyn OutputHandler,
+) -> anyhow::Result<(usize, usize)> {
+    let process = process_memory::Process::open(pid)?;
+    let mut num_matching_regions = 0;
+    let mut num_errors = 0;
+    let (output, messages) = crossbeam::channel::unbounded();
+    let binary_output = output_handler.binary_output();
+
+    let print_messages = || {
+        for msg in messages.try_iter() {
+            match msg {
+                Message::Info(s) if binary_output => eprintln!("{s}"),
+                Message::Info(s) => println!("{s}"),
+                Message::Data(data) => {
+                    let _ = std::io::stdout().write_all(&data);
+                }
+                Message::Error(s) => eprintln!("{s}"),
+                Message::Abort => {}
+            }
+        }
+    };
+
+    if console_logs {
+        let output = output.clone();
+        scanner.console_log(move |msg| {
+            output
+                .send(Message::Error(format!(
+                    "{}: {}",
+                    format!("pid:{pid}").paint(Yellow),
+                    msg.paint(Yellow)
+                )))
+                .unwrap();
+        });
+    }
+
+    for region in process.regions()? {
+        if region.size() > max_region_size {
+            continue;
+        }
+
+        let Ok(data) = process.read(&region) else {
+            continue;
+        };
+
+        let path = PathBuf::from(format!(
+            "pid:{pid}:{:#x}-{:#x}",
+            region.range.start, region.range.end
+        ));
+
+        let scan_results = match scanner.scan(data.as_slice()) {
+            Ok(scan_results) => scan_results,
+            Err(ScanError::Timeout) => {
+                print_messages();
+                return Err(ScanError::Timeout)
+                    .with_context(|| format!("scanning {}", path.display()));
+            }
+            Err(err) => {
+                num_errors += 1;
+                let err = Error::from(err)
+                    .context(format!("scanning {}", path.display()));
+                output.send(Message::Error(error_message(&err))).unwrap();
+                print_messages();
+                continue;
+            }
+        };
+
+        if report_results(
+            &path,
+            region.range.start as usize,
+            &scan_results,
+            negate,
+            output_handler,
+            &output,
+        ) {
+            num_matching_regions += 1;
+        }
+
+        print_messages();
+    }
+
+    output_handler.on_done(&output);
+    print_messages();
+
+    Ok((num_matching_regions, num_errors))
+}
+
+/// Formats an error for printing it to stderr, including its root cause
+/// when it's different from the error itself.
+fn error_message(err: &anyhow::Error) -> String {
+    let error = err.to_string();
+    let root_cause = err.root_cause().to_string();
+    if error != root_cause {
+        format!("{}{error}: {root_cause}", "error: ".paint(Red).bold())
+    } else {
+        format!("{}{error}", "error: ".paint(Red).bold())
+    }
+}
+
+/// Interval between consecutive checks for changes in `--watch` mode.
+const WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Implements the `--watch` mode.
+///
+/// Compiles the rules and scans `target_path` every time that any of the
+/// rule files or scanned files changes. After each scan only the differences
+/// with respect to the previous scan are printed: matches that are new are
+/// prefixed with `+`, and matches that disappeared are prefixed with `-`.
+/// This function never returns, except in case of error.
+fn watch(
+    rules_paths: &[(Option<String>, PathBuf)],
+    target_path: &Path,
+    max_depth: usize,
+    args: &ArgMatches,
+    config: &Config,
+) -> anyhow::Result<()> {
+    let mut prev_matches = BTreeSet::new();
+
+    loop {
+        let snapshot = watched_files(rules_paths, target_path, max_depth)?;
+
+        match compile_rules(rules_paths.iter(), args, config) {
+            Ok(rules) => {
+                let matches =
+                    watch_scan(&rules, target_path, max_depth, args)?;
+
+                for (path, rule) in matches.difference(&prev_matches) {
+                    println!(
+                        "{} {} {}",
+                        "+".paint(Green).bold(),
+                        rule.paint(Cyan).bold(),
+                        path.display()
+                    );
+                }
+
+                for (path, rule) in prev_matches.difference(&matches) {
+                    println!(
+                        "{} {} {}",
+                        "-".paint(Red).bold(),
+                        rule.paint(Cyan).bold(),
+                        path.display()
+                    );
+                }
+
+                prev_matches = matches;
+            }
+            Err(err) => {
+                eprintln!("{}{err}", "error: ".paint(Red).bold());
+            }
+        }
+
+        eprintln!(
+            "{}",
+            format!(
+                "{} match(es), watching for changes...",
+                prev_matches.len()
+            )
+            .dim()
+        );
+
+        while watched_files(rules_paths, target_path, max_depth)? == snapshot {
+            std::thread::sleep(WATCH_INTERVAL);
+        }
+    }
+}
+
+/// Scans `target_path` with `rules` in `--watch` mode.
+///
+/// Returns the matches found as a set of (file path, rule) pairs.
+fn watch_scan(
+    rules: &Rules,
+    target_path: &Path,
+    max_depth: usize,
+    args: &ArgMatches,
+) -> anyhow::Result<BTreeSet<(PathBuf, String)>> {
+    let mut scanner = Scanner::new(rules);
+    let mut matches = BTreeSet::new();
+    let mut w = walk::Walker::path(target_path);
+
+    w.max_depth(max_depth);
+
+    if !args.get_flag("disable-console-logs") {
+        scanner.console_log(|msg| eprintln!("{}", msg.paint(Yellow)));
+    }
+
+    w.walk(
+        |file_path| {
+            let scan_results = scanner
+                .scan_file(file_path)
+                .with_context(|| format!("scanning {file_path:?}"))?;
+
+            let rules = match args.get_flag("negate") {
+                true => Box::new(scan_results.non_matching_rules())
+                    as Box<dyn ExactSizeIterator<Item = Rule>>,
+                false => Box::new(scan_results.matching_rules()),
+            };
+
+            for rule in rules {
+                let rule = if args.get_flag("print-namespace") {
+                    format!("{}:{}", rule.namespace(), rule.identifier())
+                } else {
+                    rule.identifier().to_string()
+                };
+                matches.insert((file_path.to_path_buf(), rule));
+            }
+
+            Ok(())
+        },
+        // Errors while scanning some file don't abort the scan.
+        |err| {
+            eprintln!("{}{err:#}", "error: ".paint(Red).bold());
+            Ok(())
+        },
+    )?;
+
+    Ok(matches)
+}
+
+/// Returns the modification time of every rule file and scanned file in
+/// `--watch` mode.
+///
+/// Any change in the returned map, including files that were added or
+/// removed, triggers a new scan.
+fn watched_files(
+    rules_paths: &[(Option<String>, PathBuf)],
+    target_path: &Path,
+    max_depth: usize,
+) -> anyhow::Result<BTreeMap<PathBuf, Option<SystemTime>>> {
+    let mut files = BTreeMap::new();
+
+    let mut add_file = |path: &Path| {
+        files.insert(
+            path.to_path_buf(),
+            path.metadata().and_then(|m| m.modified()).ok(),
+        );
+        Ok(())
+    };
+
+    for (_, rules_path) in rules_paths {
+        let mut w = walk::Walker::path(rules_path);
+        w.filter("**/*.yar");
+        w.filter("**/*.yara");
+        w.walk(&mut add_file, |_| Ok(()))?;
+    }
+
+    let mut w = walk::Walker::path(target_path);
+    w.max_depth(max_depth);
+    w.walk(&mut add_file, |_| Ok(()))?;
+
+    Ok(files)
+}
+
 #[cfg(feature = "rules-profiling")]
 struct ProfilingData {
     pub namespace: String,
@@ -150,6 +629,8 @@ impl From<yara_x::ProfilingData<'_>> for ProfilingData {
 
 struct OutputOptions {
     count_only: bool,
+    quiet: bool,
+    group_by_rule: bool,
     include_namespace: bool,
     include_meta: bool,
     include_tags: bool,
@@ -161,6 +642,11 @@ impl From<&ArgMatches> for OutputOptions {
     fn from(args: &ArgMatches) -> Self {
         Self {
             count_only: args.get_flag("count"),
+            quiet: args.get_flag("quiet"),
+            group_by_rule: matches!(
+                args.get_one::<GroupBy>("group-by"),
+                Some(GroupBy::Rule)
+            ),
             include_namespace: args.get_flag("print-namespace"),
             include_meta: args.get_flag("print-meta"),
             include_tags: args.get_flag("print-tags"),
@@ -171,21 +657,55 @@ impl From<&ArgMatches> for OutputOptions {
 }
 
 pub fn exec_scan(args: &ArgMatches, config: &Config) -> anyhow::Result<()> {
-    let mut rules_path = args
+    let mut rules_paths = args
         .get_many::<(Option<String>, PathBuf)>("[NAMESPACE:]RULES_PATH")
-        .unwrap();
+        .into_iter()
+        .flatten()
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let mut target_path = args.get_one::<PathBuf>("TARGET_PATH");
+    let files_from = args.get_one::<PathBuf>("files-from");
+    let pid = args.get_one::<u32>("pid");
+
+    // When `--files-from` or `--pid` are used there's no <TARGET_PATH>, and
+    // the last positional argument is actually a rules path.
+    if files_from.is_some() || pid.is_some() {
+        if let Some(path) = target_path.take() {
+            rules_paths.push(path_with_namespace_parser(
+                path.to_string_lossy().as_ref(),
+            )?);
+        }
+    }
 
-    let target_path = args.get_one::<PathBuf>("TARGET_PATH").unwrap();
     let compiled_rules = args.get_flag("compiled-rules");
     let profiling = args.get_flag("profiling");
     let num_threads = args.get_one::<u8>("threads");
     let skip_larger = args.get_one::<u64>("skip-larger");
+    let truncate_larger = args.get_one::<u64>("truncate-larger");
     let disable_console_logs = args.get_flag("disable-console-logs");
     let scan_list = args.get_flag("scan-list");
     let recursive = args.get_one::<usize>("recursive");
     let no_mmap = args.get_flag("no-mmap");
     let max_matches_per_pattern =
         args.get_one::<usize>("max-matches-per-pattern");
+    let max_loop_iterations = args.get_one::<u64>("max-loop-iterations");
+    let scan_archives = args.get_flag("scan-archives");
+    let stats = args.get_flag("stats");
+    let fail_on_match = args.get_flag("fail-on-match");
+    let fail_on_error = args.get_flag("fail-on-error");
+
+    let mut archive_limits = archive::Limits::default();
+
+    if let Some(max_entries) = args.get_one::<usize>("max-archive-entries") {
+        archive_limits.max_entries = *max_entries;
+    }
+
+    if let Some(max_size) = args.get_one::<u64>("max-archive-size") {
+        archive_limits.max_size = *max_size;
+    }
+
+    let archive_limits = &archive_limits;
 
     let timeout =
         args.get_one::<u64>("timeout").map(|t| Duration::from_secs(*t));
@@ -207,67 +727,183 @@ pub fn exec_scan(args: &ArgMatches, config: &Config) -> anyhow::Result<()> {
         );
     }
 
-    if recursive.is_some() && target_path.is_file() {
+    if args.get_flag("no-color") {
+        yansi::disable();
+    }
+
+    // `--quiet` and `--group-by` only apply to the text output format, and
+    // `--count` doesn't apply to the STIX, MISP and protobuf formats. The
+    // report printed by `--profiling` would corrupt the protobuf stream.
+    if let Some(format) = args.get_one::<OutputFormats>("output-format") {
+        let options: &[&str] = match format {
+            OutputFormats::Text => &[],
+            OutputFormats::Json | OutputFormats::Ndjson => {
+                &["quiet", "group-by"]
+            }
+            OutputFormats::Stix | OutputFormats::Misp => {
+                &["quiet", "group-by", "count"]
+            }
+            OutputFormats::Proto => {
+                &["quiet", "group-by", "count", "profiling"]
+            }
+        };
+
+        for option in options {
+            if args.value_source(option)
+                == Some(clap::parser::ValueSource::CommandLine)
+            {
+                bail!(
+                    "can't use '{}' with '{}'",
+                    Paint::bold(&format!("--{option}")),
+                    Paint::bold(&format!(
+                        "--output-format={}",
+                        format.to_possible_value().unwrap().get_name()
+                    ))
+                );
+            }
+        }
+    }
+
+    if recursive.is_some() && target_path.is_some_and(|p| p.is_file()) {
         bail!(
             "can't use '{}' when <TARGET_PATH> is a file",
             Paint::bold("--recursive")
         );
     }
 
-    let rules = if compiled_rules {
-        if rules_path.len() > 1 {
-            bail!(
-                "can't use '{}' with more than one RULES_PATH",
-                Paint::bold("--compiled-rules")
-            );
-        }
+    if args.get_flag("watch") {
+        // `--watch` conflicts with `--files-from` and `--pid`, so
+        // <TARGET_PATH> is always present.
+        return watch(
+            &rules_paths,
+            target_path.unwrap(),
+            *recursive.unwrap_or(&0),
+            args,
+            config,
+        );
+    }
 
-        let (namespace, rules_path) = rules_path.next().unwrap();
+    let rules = load_rules(rules_paths.iter(), compiled_rules, args, config)?;
 
-        if namespace.is_some() {
-            bail!(
-                "can't use namespace with '{}'",
-                Paint::bold("--compiled-rules")
-            );
+    // If the user is defining external variables for compiled rules, make
+    // sure that these variables are valid. A scanner is created only with
+    // the purpose of validating the variables.
+    if compiled_rules {
+        if let Some(ref vars) = external_vars {
+            let mut scanner = Scanner::new(&rules);
+            for (ident, value) in vars {
+                scanner.set_global(ident.as_str(), value)?;
+            }
         }
+    }
 
-        let file = File::open(rules_path)
-            .with_context(|| format!("can not open {:?}", &rules_path))?;
+    let rules_ref = &rules;
 
-        let rules = Rules::deserialize_from(file)?;
+    let new_scanner = || {
+        let mut scanner = Scanner::new(rules_ref);
 
-        // If the user is defining external variables, make sure that these
-        // variables are valid. A scanner is created only with the purpose
-        // of validating the variables.
         if let Some(ref vars) = external_vars {
-            let mut scanner = Scanner::new(&rules);
             for (ident, value) in vars {
-                scanner.set_global(ident.as_str(), value)?;
+                // It's ok to use `unwrap()`, this can not fail because
+                // we already verified that external variables are correct.
+                scanner.set_global(ident.as_str(), value).unwrap();
             }
         }
 
-        rules
-    } else {
-        // With `take()` we pass the external variables to `compile_rules`,
-        // while leaving a `None` in `external_vars`. This way external
-        // variables are not set again in the scanner.
-        compile_rules(rules_path, args, config)?
+        if no_mmap {
+            scanner.use_mmap(false);
+        }
+
+        if let Some(max_matches_per_pattern) = max_matches_per_pattern {
+            scanner.max_matches_per_pattern(*max_matches_per_pattern);
+        }
+
+        if let Some(max_loop_iterations) = max_loop_iterations {
+            scanner.max_loop_iterations(*max_loop_iterations);
+        }
+
+        if let Some(truncate_larger) = truncate_larger {
+            scanner
+                .max_file_size(*truncate_larger)
+                .oversized_data_policy(OversizedDataPolicy::Truncate);
+        }
+
+        scanner
     };
 
-    let rules_ref = &rules;
+    let output_handler = match args.get_one::<OutputFormats>("output-format") {
+        Some(OutputFormats::Json) => {
+            Box::new(JsonOutputHandler::new(args.into()))
+                as Box<dyn OutputHandler>
+        }
+        Some(OutputFormats::Ndjson) => {
+            Box::new(NdjsonOutputHandler::new(args.into()))
+        }
+        Some(OutputFormats::Stix) => Box::new(ThreatIntelOutputHandler::new(
+            ThreatIntelFormat::Stix,
+            args.into(),
+        )),
+        Some(OutputFormats::Misp) => Box::new(ThreatIntelOutputHandler::new(
+            ThreatIntelFormat::Misp,
+            args.into(),
+        )),
+        Some(OutputFormats::Proto) => {
+            Box::new(ProtoOutputHandler::new(args.into()))
+        }
+        None | Some(OutputFormats::Text) => {
+            Box::new(TextOutputHandler::new(args.into()))
+        }
+    };
 
-    let mut w = if scan_list {
-        walk::ParWalker::file_list(target_path)
-    } else {
-        walk::ParWalker::path(target_path)
+    if let Some(pid) = pid {
+        let mut scanner = new_scanner();
+        if let Some(timeout) = timeout {
+            scanner.set_timeout(timeout);
+        }
+        let (num_matching_regions, num_errors) = scan_process(
+            *pid,
+            &mut scanner,
+            skip_larger
+                .copied()
+                .unwrap_or(process_memory::DEFAULT_MAX_REGION_SIZE),
+            args.get_flag("negate"),
+            !disable_console_logs,
+            output_handler.as_ref(),
+        )?;
+
+        // Exit code is 2 if some region couldn't be scanned and
+        // `--fail-on-error` was used.
+        if fail_on_error && num_errors > 0 {
+            process::exit(2);
+        }
+
+        // Exit code is 1 if some region matched and `--fail-on-match`
+        // was used.
+        if fail_on_match && num_matching_regions > 0 {
+            process::exit(1);
+        }
+
+        return Ok(());
+    }
+
+    let mut w = match (target_path, files_from) {
+        (_, Some(files_from)) => walk::ParWalker::file_list(files_from),
+        (Some(target_path), None) if scan_list => {
+            walk::ParWalker::file_list(target_path)
+        }
+        (Some(target_path), None) => walk::ParWalker::path(target_path),
+        // Either `TARGET_PATH` or `--files-from` is required.
+        (None, None) => unreachable!(),
     };
 
     if let Some(num_threads) = num_threads {
         w.num_threads(*num_threads);
     }
 
+    w.binary_output(output_handler.binary_output());
+
     if let Some(max_file_size) = skip_larger {
-        w.metadata_filter(|metadata| metadata.len() <= *max_file_size);
+        w.max_file_size(*max_file_size);
     }
 
     w.max_depth(*recursive.unwrap_or(&0));
@@ -283,46 +919,21 @@ pub fn exec_scan(args: &ArgMatches, config: &Config) -> anyhow::Result<()> {
         })
         .collect::<Result<Vec<_>, _>>()?;
 
-    let output_handler = match args.get_one::<OutputFormats>("output-format") {
-        Some(OutputFormats::Json) => {
-            Box::new(JsonOutputHandler::new(args.into()))
-                as Box<dyn OutputHandler>
-        }
-        Some(OutputFormats::Ndjson) => {
-            Box::new(NdjsonOutputHandler::new(args.into()))
-        }
-        None | Some(OutputFormats::Text) => {
-            Box::new(TextOutputHandler::new(args.into()))
-        }
-    };
-
     #[cfg(feature = "rules-profiling")]
     let slowest_rules: Mutex<Vec<ProfilingData>> = Mutex::new(Vec::new());
 
+    // Aggregated statistics printed at the end when `--stats` is used.
+    let scan_stats = ScanStats::default();
+
+    // The walker takes ownership of `state`, these counters are used for
+    // computing the exit code once the walk is done.
+    let num_matching_files = AtomicUsize::new(0);
+    let num_errors = AtomicUsize::new(0);
+
     w.walk(
         state,
         // Initialization
-        |_, _| {
-            let mut scanner = Scanner::new(rules_ref);
-
-            if let Some(ref vars) = external_vars {
-                for (ident, value) in vars {
-                    // It's ok to use `unwrap()`, this can not fail because
-                    // we already verified that external variables are correct.
-                    scanner.set_global(ident.as_str(), value).unwrap();
-                }
-            }
-
-            if no_mmap {
-                scanner.use_mmap(false);
-            }
-
-            if let Some(max_matches_per_pattern) = max_matches_per_pattern {
-                scanner.max_matches_per_pattern(*max_matches_per_pattern);
-            }
-
-            scanner
-        },
+        |_, _| new_scanner(),
         // File handler. Called for every file found while walking the path.
         |state, output, file_path, scanner| {
             if !disable_console_logs {
@@ -355,44 +966,86 @@ pub fn exec_scan(args: &ArgMatches, config: &Config) -> anyhow::Result<()> {
                 .unwrap()
                 .push((file_path.to_path_buf(), now));
 
-            let scan_options = all_metadata.iter().fold(
+            let scan_options = || all_metadata.iter().fold(
                 ScanOptions::new(),
                 |acc, (module_name, meta)| {
                     acc.set_module_metadata(module_name, meta)
                 },
             );
 
+            // Sends the results for a scanned file to the output handler.
+            let handle_results = |path: &Path, scan_results: ScanResults| {
+                state.num_scanned_files.fetch_add(1, Ordering::Relaxed);
+
+                // The number of matching files is incremented only if
+                // the match is actually included in the output and not
+                // ignored.
+                if report_results(
+                    path,
+                    0,
+                    &scan_results,
+                    args.get_flag("negate"),
+                    output_handler.as_ref(),
+                    output,
+                ) {
+                    state.num_matching_files.fetch_add(1, Ordering::Relaxed);
+                    num_matching_files.fetch_add(1, Ordering::Relaxed);
+                }
+            };
+
             let scan_results = scanner
-                .scan_file_with_options(file_path.as_path(), scan_options)
+                .scan_file_with_options(file_path.as_path(), scan_options())
                 .with_context(|| format!("scanning {:?}", &file_path));
 
+            let scan_results = match scan_results {
+                Ok(scan_results) => {
+                    if stats {
+                        let scan_time = now.elapsed();
+                        let file_size = std::fs::metadata(&file_path)
+                            .map_or(0, |metadata| metadata.len());
+                        let num_bytes = truncate_larger
+                            .map_or(file_size, |max| min(file_size, *max));
+                        let num_pattern_matches =
+                            scan_results.num_pattern_matches();
+
+                        scan_stats.add(num_bytes, num_pattern_matches);
+
+                        output
+                            .send(Message::Error(format!(
+                                "{}: {:.2?}, {:.2} MB/s, {} pattern match(es)",
+                                file_path.display(),
+                                scan_time,
+                                num_bytes as f64
+                                    / (1024.0 * 1024.0)
+                                    / scan_time.as_secs_f64(),
+                                num_pattern_matches,
+                            )))
+                            .unwrap();
+                    }
+                    handle_results(&file_path, scan_results);
+                    if scan_archives {
+                        scan_archive(
+                            &file_path,
+                            archive_limits,
+                            scanner,
+                            scan_options,
+                            handle_results,
+                            output,
+                        )
+                    } else {
+                        Ok(())
+                    }
+                }
+                Err(err) => Err(err),
+            };
+
             state
                 .files_in_progress
                 .lock()
                 .unwrap()
                 .retain(|(p, _)| !file_path.eq(p));
 
-            let scan_results = scan_results?;
-            let mut wanted_rules = match args.get_flag("negate") {
-                true => Box::new(scan_results.non_matching_rules())
-                    as Box<dyn ExactSizeIterator<Item=Rule>>,
-                false => Box::new(scan_results.matching_rules()),
-            };
-
-            state.num_scanned_files.fetch_add(1, Ordering::Relaxed);
-
-            // The number of matching files is incremented only if
-            // `on_file_scanned` returns `true`, which indicates that the
-            // match is actually included in the output and not ignored.
-            if output_handler.on_file_scanned(
-                &file_path,
-                &mut wanted_rules,
-                output,
-            ) {
-                state.num_matching_files.fetch_add(1, Ordering::Relaxed);
-            }
-
-            Ok(())
+            scan_results
         },
         // Finalization
         #[allow(unused_variables)]
@@ -421,18 +1074,9 @@ pub fn exec_scan(args: &ArgMatches, config: &Config) -> anyhow::Result<()> {
         |output| output_handler.on_done(output),
         // Error handler
         |err, output| {
-            let error = err.to_string();
-            let root_cause = err.root_cause().to_string();
-            let msg = if error != root_cause {
-                format!(
-                    "{}{error}: {root_cause}",
-                    "error: ".paint(Red).bold(),
-                )
-            } else {
-                format!("{}{error}", "error: ".paint(Red).bold())
-            };
+            num_errors.fetch_add(1, Ordering::Relaxed);
 
-            let _ = output.send(Message::Error(msg));
+            let _ = output.send(Message::Error(error_message(&err)));
 
             // In case of timeout walk is aborted.
             if let Ok(scan_err) = err.downcast::<ScanError>() {
@@ -446,6 +1090,10 @@ pub fn exec_scan(args: &ArgMatches, config: &Config) -> anyhow::Result<()> {
     )
         .unwrap();
 
+    if stats {
+        scan_stats.print_summary(start_time.elapsed());
+    }
+
     #[cfg(feature = "rules-profiling")]
     if profiling {
         let mut mer = slowest_rules.lock().unwrap();
@@ -481,9 +1129,58 @@ pub fn exec_scan(args: &ArgMatches, config: &Config) -> anyhow::Result<()> {
         }
     }
 
+    // Exit code is 2 if some error occurred and `--fail-on-error` was used.
+    if fail_on_error && num_errors.load(Ordering::Relaxed) > 0 {
+        process::exit(2);
+    }
+
+    // Exit code is 1 if some file matched and `--fail-on-match` was used.
+    if fail_on_match && num_matching_files.load(Ordering::Relaxed) > 0 {
+        process::exit(1);
+    }
+
     Ok(())
 }
 
+/// Statistics aggregated across all the files scanned with `--stats`.
+#[derive(Debug, Default)]
+struct ScanStats {
+    num_files: AtomicUsize,
+    num_bytes: AtomicU64,
+    num_pattern_matches: AtomicUsize,
+}
+
+impl ScanStats {
+    fn add(&self, num_bytes: u64, num_pattern_matches: usize) {
+        self.num_files.fetch_add(1, Ordering::Relaxed);
+        self.num_bytes.fetch_add(num_bytes, Ordering::Relaxed);
+        self.num_pattern_matches
+            .fetch_add(num_pattern_matches, Ordering::Relaxed);
+    }
+
+    fn print_summary(&self, elapsed: Duration) {
+        let num_bytes = self.num_bytes.load(Ordering::Relaxed);
+
+        eprintln!(
+            "{} {} file(s), {} byte(s) in {:.2?}",
+            "Scanned:".paint(Green).bold(),
+            self.num_files.load(Ordering::Relaxed),
+            num_bytes,
+            elapsed,
+        );
+        eprintln!(
+            "{} {:.2} MB/s",
+            "Throughput:".paint(Green).bold(),
+            num_bytes as f64 / (1024.0 * 1024.0) / elapsed.as_secs_f64(),
+        );
+        eprintln!(
+            "{} {}",
+            "Pattern matches:".paint(Green).bold(),
+            self.num_pattern_matches.load(Ordering::Relaxed),
+        );
+    }
+}
+
 #[derive(Debug)]
 struct ScanState {
     start_time: Instant,
@@ -590,49 +1287,78 @@ impl Component for ScanState {
 use crate::config::Config;
 use output_handler::*;
 
+#[cfg(test)]
+pub(crate) use output_handler::json_schema;
+
 mod output_handler {
     use super::*;
     use std::collections::HashMap;
     use yara_x::PatternKind;
 
-    #[derive(serde::Serialize)]
+    /// Version of the schema followed by the JSON produced by the `json`
+    /// and `ndjson` output formats. The minor version is incremented when
+    /// new fields are added, and the major version when existing fields
+    /// are changed or removed.
+    const SCHEMA_VERSION: &str = "1.0";
+
+    /// A pattern match.
+    #[derive(serde::Serialize, schemars::JsonSchema)]
     struct PatternJson {
+        /// Pattern identifier, including the `$` prefix.
         identifier: String,
+        /// Offset where the match starts.
         offset: usize,
+        /// Matching data, with non-printable bytes escaped.
         r#match: String,
+        /// Key used for decoding the data, for patterns with the `xor`
+        /// modifier.
         #[serde(skip_serializing_if = "Option::is_none")]
         xor_key: Option<u8>,
+        /// Matching data after being decoded with `xor_key`.
         #[serde(skip_serializing_if = "Option::is_none")]
         plaintext: Option<String>,
     }
 
-    #[derive(serde::Serialize)]
+    /// A matching rule.
+    #[derive(serde::Serialize, schemars::JsonSchema)]
     struct RuleJson {
+        /// Rule identifier.
         identifier: String,
+        /// Rule namespace, only with `--print-namespace`.
         #[serde(skip_serializing_if = "Option::is_none")]
         namespace: Option<String>,
+        /// Rule metadata, only with `--print-meta`.
         #[serde(skip_serializing_if = "Option::is_none")]
         meta: Option<serde_json::Value>,
+        /// Rule tags, only with `--print-tags`.
         #[serde(skip_serializing_if = "Option::is_none")]
         tags: Option<Vec<String>>,
+        /// Pattern matches, only with `--print-strings`.
         #[serde(skip_serializing_if = "Option::is_none")]
         strings: Option<Vec<PatternJson>>,
     }
 
-    #[derive(serde::Serialize)]
+    /// The rules that matched a file.
+    #[derive(serde::Serialize, schemars::JsonSchema)]
     struct JsonOutput<'a> {
+        /// Path of the scanned file.
         path: &'a str,
+        /// Matching rules.
         rules: &'a [RuleJson],
     }
 
-    #[derive(serde::Serialize)]
+    /// The number of rules that matched a file, with `--count`.
+    #[derive(serde::Serialize, schemars::JsonSchema)]
     struct JsonCountOutput<'a> {
+        /// Path of the scanned file.
         path: &'a str,
+        /// Number of matching rules.
         count: usize,
     }
 
     fn rules_to_json(
         output_options: &OutputOptions,
+        base_offset: usize,
         scan_results: &mut dyn ExactSizeIterator<Item = Rule>,
     ) -> Vec<RuleJson> {
         scan_results
@@ -654,15 +1380,16 @@ mod output_handler {
                         .map(|t| t.identifier().to_string())
                         .collect::<Vec<_>>()
                 }),
-                strings: output_options
-                    .include_strings
-                    .map(|limit| patterns_to_json(rule.patterns(), limit)),
+                strings: output_options.include_strings.map(|limit| {
+                    patterns_to_json(rule.patterns(), base_offset, limit)
+                }),
             })
             .collect()
     }
 
     fn patterns_to_json(
         patterns: Patterns<'_, '_>,
+        base_offset: usize,
         string_limit: usize,
     ) -> Vec<PatternJson> {
         patterns
@@ -693,7 +1420,7 @@ mod output_handler {
 
                     PatternJson {
                         identifier: identifier.to_owned(),
-                        offset: match_range.start,
+                        offset: base_offset + match_range.start,
                         r#match: string,
                         xor_key: pattern_match.xor_key(),
                         plaintext: pattern_match.xor_key().map(|xor_key| {
@@ -718,23 +1445,193 @@ mod output_handler {
         ///
         /// Must return `true` when the file was included in the output,
         /// or `false` if the file was ignored.
+        ///
+        /// `base_offset` is added to the offsets of the matches, it's
+        /// non-zero when scanning the memory regions of a process, as the
+        /// offsets are reported as virtual addresses. `scanned_data` is the
+        /// content of the file, or `None` if it was truncated.
+        /// `module_outputs` are the structures produced by the modules for
+        /// the scanned file.
         fn on_file_scanned(
             &self,
             file_path: &Path,
+            base_offset: usize,
+            scanned_data: Option<&[u8]>,
             scan_results: &mut dyn ExactSizeIterator<Item = Rule>,
+            module_outputs: ModuleOutputs,
             output: &Sender<Message>,
         ) -> bool;
         /// Called when the last file has been scanned.
         fn on_done(&self, _output: &Sender<Message>);
+        /// Returns true if the handler writes binary data to `stdout`. In
+        /// that case any other message is printed to `stderr`.
+        fn binary_output(&self) -> bool {
+            false
+        }
     }
 
+    /// Matching files for each rule, keyed by the rule's namespace and
+    /// identifier. Values are the text that identifies the rule and one
+    /// entry per file where the rule matched.
+    type RuleGroups = BTreeMap<(String, String), (String, Vec<String>)>;
+
     pub(super) struct TextOutputHandler {
         output_options: OutputOptions,
+        /// Results accumulated until the end of the scan when `--group-by
+        /// rule` is used.
+        rules: Mutex<RuleGroups>,
     }
 
     impl TextOutputHandler {
         pub(super) fn new(output_options: OutputOptions) -> Self {
-            Self { output_options }
+            Self { output_options, rules: Mutex::new(BTreeMap::new()) }
+        }
+
+        /// Returns the text that identifies a rule in the output, including
+        /// its namespace, tags and metadata when requested.
+        fn rule_header(&self, matching_rule: &Rule) -> String {
+            let mut msg = if self.output_options.include_namespace {
+                format!(
+                    "{}:{}",
+                    matching_rule.namespace().paint(Cyan).bold(),
+                    matching_rule.identifier().paint(Cyan).bold()
+                )
+            } else {
+                format!("{}", matching_rule.identifier().paint(Cyan).bold())
+            };
+
+            let tags = matching_rule.tags();
+
+            if self.output_options.include_tags && !tags.is_empty() {
+                msg.push_str(" [");
+                for (pos, tag) in tags.with_position() {
+                    msg.push_str(tag.identifier());
+                    if !matches!(pos, itertools::Position::Last) {
+                        msg.push(',');
+                    }
+                }
+                msg.push(']');
+            }
+
+            let metadata = matching_rule.metadata();
+
+            if self.output_options.include_meta && !metadata.is_empty() {
+                msg.push_str(" [");
+                for (pos, (m, v)) in metadata.with_position() {
+                    match v {
+                        MetaValue::Bool(v) => {
+                            msg.push_str(&format!("{m}={v}"))
+                        }
+                        MetaValue::Integer(v) => {
+                            msg.push_str(&format!("{m}={v}"))
+                        }
+                        MetaValue::Float(v) => {
+                            msg.push_str(&format!("{m}={v}"))
+                        }
+                        MetaValue::String(v) => {
+                            msg.push_str(&format!("{m}=\"{v}\""))
+                        }
+                        MetaValue::Bytes(v) => msg.push_str(&format!(
+                            "{}=\"{}\"",
+                            m,
+                            v.escape_ascii()
+                        )),
+                    };
+                    if !matches!(pos, itertools::Position::Last) {
+                        msg.push(',');
+                    }
+                }
+                msg.push(']');
+            }
+
+            msg
+        }
+
+        /// Returns the matches of the rule's patterns, one per line, when
+        /// `--print-strings` is used. Otherwise, returns an empty string.
+        fn match_strings(
+            &self,
+            base_offset: usize,
+            matching_rule: &Rule,
+        ) -> String {
+            let mut msg = String::new();
+
+            if let Some(limit) = self.output_options.include_strings {
+                for p in matching_rule.patterns() {
+                    for m in p.matches() {
+                        let match_range = m.range();
+                        let match_data = m.data();
+
+                        let mut match_str = format!(
+                            "\n{:#x}:{}:{}",
+                            base_offset + match_range.start,
+                            match_range.len(),
+                            p.identifier(),
+                        );
+
+                        match m.xor_key() {
+                            Some(k) => {
+                                match_str.push_str(
+                                    format!(" xor({k:#x},").as_str(),
+                                );
+                                for b in
+                                    &match_data[..min(match_data.len(), limit)]
+                                {
+                                    for c in (b ^ k).escape_ascii() {
+                                        match_str.push_str(
+                                            format!("{}", c as char).as_str(),
+                                        );
+                                    }
+                                }
+                                match_str.push_str("): ");
+                            }
+                            _ => {
+                                match_str.push_str(": ");
+                            }
+                        }
+
+                        let data = &match_data[..min(match_data.len(), limit)];
+
+                        match p.kind() {
+                            PatternKind::Text | PatternKind::Regexp => {
+                                for b in data {
+                                    for c in b.escape_ascii() {
+                                        match_str.push_str(
+                                            format!("{}", c as char).as_str(),
+                                        );
+                                    }
+                                }
+                            }
+                            PatternKind::Hex => {
+                                for (pos, b) in data.iter().with_position() {
+                                    match_str
+                                        .push_str(format!("{b:02x}").as_str());
+                                    if !matches!(
+                                        pos,
+                                        itertools::Position::Last
+                                    ) {
+                                        match_str.push(' ');
+                                    }
+                                }
+                            }
+                        }
+
+                        if match_data.len() > limit {
+                            match_str.push_str(
+                                format!(
+                                    " ... {} more bytes",
+                                    match_data.len().saturating_sub(limit)
+                                )
+                                .as_str(),
+                            );
+                        }
+
+                        msg.push_str(&match_str)
+                    }
+                }
+            }
+
+            msg
         }
     }
 
@@ -742,7 +1639,10 @@ mod output_handler {
         fn on_file_scanned(
             &self,
             file_path: &Path,
+            base_offset: usize,
+            _scanned_data: Option<&[u8]>,
             scan_results: &mut dyn ExactSizeIterator<Item = Rule>,
+            _module_outputs: ModuleOutputs,
             output: &Sender<Message>,
         ) -> bool {
             if self.output_options.count_only {
@@ -770,154 +1670,56 @@ mod output_handler {
 
                 result = true;
 
-                let mut msg = if self.output_options.include_namespace {
-                    format!(
-                        "{}:{}",
-                        matching_rule.namespace().paint(Cyan).bold(),
-                        matching_rule.identifier().paint(Cyan).bold()
-                    )
-                } else {
-                    format!(
-                        "{}",
-                        matching_rule.identifier().paint(Cyan).bold()
-                    )
-                };
-
-                let tags = matching_rule.tags();
-
-                if self.output_options.include_tags && !tags.is_empty() {
-                    msg.push_str(" [");
-                    for (pos, tag) in tags.with_position() {
-                        msg.push_str(tag.identifier());
-                        if !matches!(pos, itertools::Position::Last) {
-                            msg.push(',');
-                        }
-                    }
-                    msg.push(']');
-                }
-
-                let metadata = matching_rule.metadata();
-
-                if self.output_options.include_meta && !metadata.is_empty() {
-                    msg.push_str(" [");
-                    for (pos, (m, v)) in metadata.with_position() {
-                        match v {
-                            MetaValue::Bool(v) => {
-                                msg.push_str(&format!("{m}={v}"))
-                            }
-                            MetaValue::Integer(v) => {
-                                msg.push_str(&format!("{m}={v}"))
-                            }
-                            MetaValue::Float(v) => {
-                                msg.push_str(&format!("{m}={v}"))
-                            }
-                            MetaValue::String(v) => {
-                                msg.push_str(&format!("{m}=\"{v}\""))
-                            }
-                            MetaValue::Bytes(v) => msg.push_str(&format!(
-                                "{}=\"{}\"",
-                                m,
-                                v.escape_ascii()
-                            )),
-                        };
-                        if !matches!(pos, itertools::Position::Last) {
-                            msg.push(',');
-                        }
-                    }
-                    msg.push(']');
+                // In quiet mode only the file path is printed, no matter
+                // how many rules matched.
+                if self.output_options.quiet {
+                    output
+                        .send(Message::Info(file_path.display().to_string()))
+                        .unwrap();
+                    break;
                 }
 
-                msg.push(' ');
-                msg.push_str(&file_path.display().to_string());
-
-                if let Some(limit) = self.output_options.include_strings {
-                    for p in matching_rule.patterns() {
-                        for m in p.matches() {
-                            let match_range = m.range();
-                            let match_data = m.data();
-
-                            let mut match_str = format!(
-                                "\n{:#x}:{}:{}",
-                                match_range.start,
-                                match_range.len(),
-                                p.identifier(),
-                            );
-
-                            match m.xor_key() {
-                                Some(k) => {
-                                    match_str.push_str(
-                                        format!(" xor({k:#x},").as_str(),
-                                    );
-                                    for b in &match_data
-                                        [..min(match_data.len(), limit)]
-                                    {
-                                        for c in (b ^ k).escape_ascii() {
-                                            match_str.push_str(
-                                                format!("{}", c as char)
-                                                    .as_str(),
-                                            );
-                                        }
-                                    }
-                                    match_str.push_str("): ");
-                                }
-                                _ => {
-                                    match_str.push_str(": ");
-                                }
-                            }
-
-                            let data =
-                                &match_data[..min(match_data.len(), limit)];
-
-                            match p.kind() {
-                                PatternKind::Text | PatternKind::Regexp => {
-                                    for b in data {
-                                        for c in b.escape_ascii() {
-                                            match_str.push_str(
-                                                format!("{}", c as char)
-                                                    .as_str(),
-                                            );
-                                        }
-                                    }
-                                }
-                                PatternKind::Hex => {
-                                    for (pos, b) in data.iter().with_position()
-                                    {
-                                        match_str.push_str(
-                                            format!("{b:02x}").as_str(),
-                                        );
-                                        if !matches!(
-                                            pos,
-                                            itertools::Position::Last
-                                        ) {
-                                            match_str.push(' ');
-                                        }
-                                    }
-                                }
-                            }
-
-                            if match_data.len() > limit {
-                                match_str.push_str(
-                                    format!(
-                                        " ... {} more bytes",
-                                        match_data.len().saturating_sub(limit)
-                                    )
-                                    .as_str(),
-                                );
-                            }
-
-                            msg.push_str(&match_str)
-                        }
-                    }
+                let header = self.rule_header(&matching_rule);
+                let strings = self.match_strings(base_offset, &matching_rule);
+
+                if self.output_options.group_by_rule {
+                    let mut rules = self.rules.lock().unwrap();
+                    let (_, entries) = rules
+                        .entry((
+                            matching_rule.namespace().to_string(),
+                            matching_rule.identifier().to_string(),
+                        ))
+                        .or_insert_with(|| (header, Vec::new()));
+                    entries.push(format!(
+                        "  {}{}",
+                        file_path.display(),
+                        strings.replace('\n', "\n  ")
+                    ));
+                } else {
+                    output
+                        .send(Message::Info(format!(
+                            "{header} {}{strings}",
+                            file_path.display()
+                        )))
+                        .unwrap();
                 }
-
-                output.send(Message::Info(msg)).unwrap();
             }
 
             result
         }
 
-        fn on_done(&self, _output: &Sender<Message>) {
-            // Nothing to do here.
+        fn on_done(&self, output: &Sender<Message>) {
+            // Files are scanned in parallel, the files where each rule
+            // matched are sorted to make the output deterministic.
+            for (_, (header, mut entries)) in
+                mem::take(&mut *self.rules.lock().unwrap())
+            {
+                entries.sort();
+                output.send(Message::Info(header)).unwrap();
+                for entry in entries {
+                    output.send(Message::Info(entry)).unwrap();
+                }
+            }
         }
     }
 
@@ -935,7 +1737,10 @@ mod output_handler {
         fn on_file_scanned(
             &self,
             file_path: &Path,
+            base_offset: usize,
+            _scanned_data: Option<&[u8]>,
             scan_results: &mut dyn ExactSizeIterator<Item = Rule>,
+            _module_outputs: ModuleOutputs,
             output: &Sender<Message>,
         ) -> bool {
             let path = file_path.to_str().unwrap();
@@ -952,7 +1757,7 @@ mod output_handler {
             }
 
             let matching_rules =
-                rules_to_json(&self.output_options, scan_results);
+                rules_to_json(&self.output_options, base_offset, scan_results);
 
             let line = serde_json::to_string(&JsonOutput {
                 path,
@@ -971,35 +1776,99 @@ mod output_handler {
         }
     }
 
-    #[derive(serde::Serialize, Clone)]
+    /// A pattern match.
+    #[derive(serde::Serialize, schemars::JsonSchema, Clone)]
     struct StringJson {
+        /// Pattern identifier, including the `$` prefix.
         identifier: String,
+        /// Offset where the match starts.
         offset: usize,
+        /// Matching data, with non-printable bytes escaped.
         r#match: String,
+        /// Key used for decoding the data, for patterns with the `xor`
+        /// modifier.
         #[serde(skip_serializing_if = "Option::is_none")]
         xor_key: Option<u8>,
+        /// Matching data after being decoded with `xor_key`.
         #[serde(skip_serializing_if = "Option::is_none")]
         plaintext: Option<String>,
     }
 
-    #[derive(serde::Serialize, Clone)]
+    /// A rule that matched a file.
+    #[derive(serde::Serialize, schemars::JsonSchema, Clone)]
     struct MatchJson {
+        /// Rule identifier.
         rule: String,
+        /// Absolute path of the scanned file.
         file: String,
+        /// Rule metadata, only with `--print-meta`. Keys that appear more
+        /// than once have an array with all their values.
         #[serde(skip_serializing_if = "Option::is_none")]
         meta: Option<HashMap<String, serde_json::Value>>,
+        /// Rule tags, only with `--print-tags`.
         #[serde(skip_serializing_if = "Option::is_none")]
         tags: Option<Vec<String>>,
+        /// Pattern matches, only with `--print-strings`.
         #[serde(skip_serializing_if = "Option::is_none")]
         strings: Option<Vec<StringJson>>,
     }
 
-    #[derive(serde::Serialize)]
+    /// The results of a scan.
+    #[derive(serde::Serialize, schemars::JsonSchema)]
     struct OutputJson {
+        /// Version of the schema followed by this output.
+        schema_version: String,
+        /// Version of YARA-X that produced this output.
         version: String,
+        /// Rules that matched each file.
         matches: Vec<MatchJson>,
     }
 
+    /// Root types of the JSON produced by the `json` output format. Only
+    /// used for generating the schema.
+    #[cfg(test)]
+    #[derive(schemars::JsonSchema)]
+    #[schemars(untagged)]
+    #[allow(dead_code)]
+    enum JsonFormat<'a> {
+        Results(OutputJson),
+        Counts(Vec<JsonCountOutput<'a>>),
+    }
+
+    /// Root types of each line produced by the `ndjson` output format.
+    /// Only used for generating the schema.
+    #[cfg(test)]
+    #[derive(schemars::JsonSchema)]
+    #[schemars(untagged)]
+    #[allow(dead_code)]
+    enum NdjsonFormat<'a> {
+        Results(JsonOutput<'a>),
+        Count(JsonCountOutput<'a>),
+    }
+
+    /// Returns the JSON schema followed by the `json` output format, or by
+    /// each line in the `ndjson` output format if `ndjson` is true.
+    #[cfg(test)]
+    pub(crate) fn json_schema(ndjson: bool) -> serde_json::Value {
+        let (mut schema, title, description) = if ndjson {
+            (
+                schemars::schema_for!(NdjsonFormat),
+                "YARA-X scan results (ndjson)",
+                "Each line produced by `yr scan --output-format=ndjson`.",
+            )
+        } else {
+            (
+                schemars::schema_for!(JsonFormat),
+                "YARA-X scan results (json)",
+                "Output produced by `yr scan --output-format=json`.",
+            )
+        };
+        schema.insert("title".into(), title.into());
+        schema.insert("description".into(), description.into());
+        schema.insert("version".into(), SCHEMA_VERSION.into());
+        schema.to_value()
+    }
+
     pub(super) struct JsonOutputHandler {
         output_options: OutputOptions,
         output_buffer: std::sync::Arc<std::sync::Mutex<Vec<MatchJson>>>,
@@ -1014,6 +1883,7 @@ mod output_handler {
 
     fn patterns_to_string_jsons(
         patterns: Patterns<'_, '_>,
+        base_offset: usize,
         string_limit: usize,
     ) -> Vec<StringJson> {
         patterns
@@ -1044,7 +1914,7 @@ mod output_handler {
 
                     StringJson {
                         identifier: identifier.to_owned(),
-                        offset: match_range.start,
+                        offset: base_offset + match_range.start,
                         r#match: string.clone(),
                         xor_key: pattern_match.xor_key(),
                         plaintext: pattern_match.xor_key().map(|xor_key| {
@@ -1066,16 +1936,22 @@ mod output_handler {
         fn on_file_scanned(
             &self,
             file_path: &Path,
+            base_offset: usize,
+            _scanned_data: Option<&[u8]>,
             scan_results: &mut dyn ExactSizeIterator<Item = Rule>,
+            _module_outputs: ModuleOutputs,
             _output: &Sender<Message>,
         ) -> bool {
+            // Paths that don't exist in the file system, like the ones for
+            // files inside archives or process memory regions, are used
+            // as they are.
             let path = file_path
                 .canonicalize()
                 .ok()
                 .as_ref()
                 .and_then(|absolute| absolute.to_str())
                 .map(|s| s.to_string())
-                .unwrap_or_default();
+                .unwrap_or_else(|| file_path.to_string_lossy().into_owned());
 
             // prepare the increment *outside* the critical section
             let matching_rules = scan_results
@@ -1128,6 +2004,7 @@ mod output_handler {
                         |strings_limit| {
                             patterns_to_string_jsons(
                                 rule.patterns(),
+                                base_offset,
                                 strings_limit,
                             )
                         },
@@ -1171,7 +2048,11 @@ mod output_handler {
                     serde_json::to_string_pretty(&json_output)
                 }
                 false => {
-                    let output_json = OutputJson { matches, version };
+                    let output_json = OutputJson {
+                        schema_version: SCHEMA_VERSION.to_string(),
+                        version,
+                        matches,
+                    };
 
                     serde_json::to_string_pretty(&output_json)
                 }
@@ -1181,4 +2062,389 @@ mod output_handler {
             output.send(Message::Info(rendered_json)).unwrap();
         }
     }
+
+    /// Hashes and size of a scanned file.
+    struct FileHashes {
+        md5: String,
+        sha1: String,
+        sha256: String,
+        size: u64,
+    }
+
+    /// Computes the hashes of the scanned data, so that the file is not
+    /// read again.
+    fn hash_data(data: &[u8]) -> FileHashes {
+        use md5::Digest;
+
+        FileHashes {
+            md5: hex::encode(md5::Md5::digest(data)),
+            sha1: hex::encode(sha1::Sha1::digest(data)),
+            sha256: hex::encode(sha2::Sha256::digest(data)),
+            size: data.len() as u64,
+        }
+    }
+
+    /// A rule that matched some file, together with the identifier and
+    /// offset of each pattern match.
+    struct RuleDetection {
+        namespace: String,
+        identifier: String,
+        tags: Vec<String>,
+        matches: Vec<(String, usize)>,
+    }
+
+    impl RuleDetection {
+        /// Returns a text describing the rule and where it matched.
+        fn context(&self) -> String {
+            let mut context = format!(
+                "YARA rule {}:{} matched",
+                self.namespace, self.identifier
+            );
+            if !self.matches.is_empty() {
+                context.push_str(" at ");
+                context.push_str(
+                    &self
+                        .matches
+                        .iter()
+                        .map(|(ident, offset)| format!("{ident}@{offset:#x}"))
+                        .join(", "),
+                );
+            }
+            context
+        }
+    }
+
+    /// A file where at least one rule matched.
+    struct FileDetection {
+        path: String,
+        hashes: Option<FileHashes>,
+        rules: Vec<RuleDetection>,
+    }
+
+    /// Formats supported by [`ThreatIntelOutputHandler`].
+    pub(super) enum ThreatIntelFormat {
+        Stix,
+        Misp,
+    }
+
+    /// Output handler that produces the results in formats understood by
+    /// threat intelligence platforms, like STIX 2.1 bundles and MISP events.
+    /// The results are printed as a single JSON object at the end of the
+    /// scan.
+    pub(super) struct ThreatIntelOutputHandler {
+        format: ThreatIntelFormat,
+        output_options: OutputOptions,
+        detections: Mutex<Vec<FileDetection>>,
+    }
+
+    impl ThreatIntelOutputHandler {
+        pub(super) fn new(
+            format: ThreatIntelFormat,
+            output_options: OutputOptions,
+        ) -> Self {
+            Self { format, output_options, detections: Default::default() }
+        }
+
+        fn stix_bundle(detections: &[FileDetection]) -> serde_json::Value {
+            let now = chrono::Utc::now()
+                .to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+
+            let new_id = |object_type: &str| {
+                format!("{object_type}--{}", uuid::Uuid::new_v4())
+            };
+
+            let mut objects = Vec::new();
+
+            for detection in detections {
+                let file_id = new_id("file");
+                let observed_data_id = new_id("observed-data");
+
+                let mut file = serde_json::json!({
+                    "type": "file",
+                    "spec_version": "2.1",
+                    "id": file_id,
+                    "name": Path::new(&detection.path)
+                        .file_name()
+                        .map(|name| name.to_string_lossy())
+                        .unwrap_or_else(|| detection.path.as_str().into()),
+                });
+
+                // Characters that must be escaped in string literals within
+                // STIX patterns.
+                let escape =
+                    |s: &str| s.replace('\\', "\\\\").replace('\'', "\\'");
+
+                let pattern = if let Some(hashes) = &detection.hashes {
+                    file["hashes"] = serde_json::json!({
+                        "MD5": hashes.md5,
+                        "SHA-1": hashes.sha1,
+                        "SHA-256": hashes.sha256,
+                    });
+                    file["size"] = hashes.size.into();
+                    format!("[file:hashes.'SHA-256' = '{}']", hashes.sha256)
+                } else {
+                    format!("[file:name = '{}']", escape(&detection.path))
+                };
+
+                objects.push(file);
+                objects.push(serde_json::json!({
+                    "type": "observed-data",
+                    "spec_version": "2.1",
+                    "id": observed_data_id,
+                    "created": now,
+                    "modified": now,
+                    "first_observed": now,
+                    "last_observed": now,
+                    "number_observed": 1,
+                    "object_refs": [file_id],
+                }));
+
+                for rule in &detection.rules {
+                    let indicator_id = new_id("indicator");
+
+                    let mut indicator = serde_json::json!({
+                        "type": "indicator",
+                        "spec_version": "2.1",
+                        "id": indicator_id,
+                        "created": now,
+                        "modified": now,
+                        "name": rule.identifier,
+                        "description": rule.context(),
+                        "indicator_types": ["malicious-activity"],
+                        "pattern": pattern,
+                        "pattern_type": "stix",
+                        "valid_from": now,
+                    });
+
+                    if !rule.tags.is_empty() {
+                        indicator["labels"] = rule.tags.clone().into();
+                    }
+
+                    objects.push(indicator);
+                    objects.push(serde_json::json!({
+                        "type": "sighting",
+                        "spec_version": "2.1",
+                        "id": new_id("sighting"),
+                        "created": now,
+                        "modified": now,
+                        "first_seen": now,
+                        "last_seen": now,
+                        "count": 1,
+                        "sighting_of_ref": indicator_id,
+                        "observed_data_refs": [observed_data_id],
+                        "x_yara_namespace": rule.namespace,
+                        "x_yara_rule": rule.identifier,
+                        "x_yara_matches": rule
+                            .matches
+                            .iter()
+                            .map(|(identifier, offset)| serde_json::json!({
+                                "identifier": identifier,
+                                "offset": offset,
+                            }))
+                            .collect::<Vec<_>>(),
+                    }));
+                }
+            }
+
+            serde_json::json!({
+                "type": "bundle",
+                "id": new_id("bundle"),
+                "objects": objects,
+            })
+        }
+
+        fn misp_event(detections: &[FileDetection]) -> serde_json::Value {
+            let now = chrono::Utc::now();
+            let mut attributes = Vec::new();
+
+            for detection in detections {
+                let comment = detection
+                    .rules
+                    .iter()
+                    .map(|rule| rule.context())
+                    .join("; ");
+
+                let tags = detection
+                    .rules
+                    .iter()
+                    .flat_map(|rule| rule.tags.iter())
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .map(|tag| serde_json::json!({ "name": tag }))
+                    .collect::<Vec<_>>();
+
+                let mut values = vec![("filename", detection.path.as_str())];
+
+                if let Some(hashes) = &detection.hashes {
+                    values.push(("md5", hashes.md5.as_str()));
+                    values.push(("sha1", hashes.sha1.as_str()));
+                    values.push(("sha256", hashes.sha256.as_str()));
+                }
+
+                for (attr_type, value) in values {
+                    let mut attribute = serde_json::json!({
+                        "type": attr_type,
+                        "category": "Payload delivery",
+                        "to_ids": attr_type != "filename",
+                        "value": value,
+                        "comment": comment,
+                    });
+                    if !tags.is_empty() {
+                        attribute["Tag"] = tags.clone().into();
+                    }
+                    attributes.push(attribute);
+                }
+            }
+
+            serde_json::json!({
+                "Event": {
+                    "uuid": uuid::Uuid::new_v4().to_string(),
+                    "info": "YARA-X scan results",
+                    "date": now.format("%Y-%m-%d").to_string(),
+                    "timestamp": now.timestamp().to_string(),
+                    "Attribute": attributes,
+                }
+            })
+        }
+    }
+
+    impl OutputHandler for ThreatIntelOutputHandler {
+        fn on_file_scanned(
+            &self,
+            file_path: &Path,
+            base_offset: usize,
+            scanned_data: Option<&[u8]>,
+            scan_results: &mut dyn ExactSizeIterator<Item = Rule>,
+            _module_outputs: ModuleOutputs,
+            _output: &Sender<Message>,
+        ) -> bool {
+            let rules = scan_results
+                .filter(|rule| {
+                    self.output_options.only_tag.as_ref().is_none_or(
+                        |only_tag| {
+                            rule.tags().any(|tag| tag.identifier() == only_tag)
+                        },
+                    )
+                })
+                .map(|rule| RuleDetection {
+                    namespace: rule.namespace().to_string(),
+                    identifier: rule.identifier().to_string(),
+                    tags: rule
+                        .tags()
+                        .map(|tag| tag.identifier().to_string())
+                        .collect(),
+                    matches: rule
+                        .patterns()
+                        .flat_map(|pattern| {
+                            pattern.matches().map(move |m| {
+                                (
+                                    pattern.identifier().to_string(),
+                                    base_offset + m.range().start,
+                                )
+                            })
+                        })
+                        .collect(),
+                })
+                .collect::<Vec<_>>();
+
+            if rules.is_empty() {
+                return false;
+            }
+
+            let detection = FileDetection {
+                path: file_path.to_string_lossy().into_owned(),
+                hashes: scanned_data.map(hash_data),
+                rules,
+            };
+
+            self.detections.lock().unwrap().push(detection);
+
+            true
+        }
+
+        fn on_done(&self, output: &Sender<Message>) {
+            let mut detections =
+                mem::take(&mut *self.detections.lock().unwrap());
+
+            // Files are scanned in parallel, sort them for producing
+            // a deterministic output.
+            detections.sort_by(|a, b| a.path.cmp(&b.path));
+
+            let json = match self.format {
+                ThreatIntelFormat::Stix => Self::stix_bundle(&detections),
+                ThreatIntelFormat::Misp => Self::misp_event(&detections),
+            };
+
+            output
+                .send(Message::Info(
+                    serde_json::to_string_pretty(&json).unwrap(),
+                ))
+                .unwrap();
+        }
+    }
+
+    /// Output handler that produces a `ScanResults` protobuf message for
+    /// each scanned file. Each message is preceded by its length encoded
+    /// as a varint.
+    pub(super) struct ProtoOutputHandler {
+        output_options: OutputOptions,
+    }
+
+    impl ProtoOutputHandler {
+        pub(super) fn new(output_options: OutputOptions) -> Self {
+            Self { output_options }
+        }
+    }
+
+    impl OutputHandler for ProtoOutputHandler {
+        fn on_file_scanned(
+            &self,
+            file_path: &Path,
+            base_offset: usize,
+            _scanned_data: Option<&[u8]>,
+            scan_results: &mut dyn ExactSizeIterator<Item = Rule>,
+            module_outputs: ModuleOutputs,
+            output: &Sender<Message>,
+        ) -> bool {
+            let mut results = yara_x::proto::ScanResults::new();
+
+            results.set_path(file_path.to_string_lossy().into_owned());
+            results.matching_rules = scan_results
+                .filter(|rule| {
+                    self.output_options.only_tag.as_ref().is_none_or(
+                        |only_tag| {
+                            rule.tags().any(|tag| tag.identifier() == only_tag)
+                        },
+                    )
+                })
+                .map(|rule| {
+                    let mut rule = rule.to_proto();
+                    for pattern in rule.patterns.iter_mut() {
+                        for m in pattern.matches.iter_mut() {
+                            m.set_offset(m.offset() + base_offset as u64);
+                        }
+                    }
+                    rule
+                })
+                .collect();
+            results.modules =
+                protobuf::MessageField::some(module_outputs.into_proto());
+
+            let data =
+                protobuf::Message::write_length_delimited_to_bytes(&results)
+                    .unwrap();
+
+            output.send(Message::Data(data)).unwrap();
+
+            !results.matching_rules.is_empty()
+        }
+
+        fn on_done(&self, _output: &Sender<Message>) {
+            // Nothing to do here.
+        }
+
+        fn binary_output(&self) -> bool {
+            true
+        }
+    }
 }
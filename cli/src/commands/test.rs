@@ -0,0 +1,67 @@
+use std::path::PathBuf;
+use std::process;
+
+use clap::{arg, value_parser, ArgMatches, Command};
+use yansi::Color::{Green, Red};
+use yansi::Paint;
+use yara_x_cli::testing::TestSpec;
+
+use crate::help;
+
+pub fn test() -> Command {
+    super::command("test")
+        .about("Test rules against positive and negative samples")
+        .long_about(help::TEST_LONG_HELP)
+        .arg(
+            arg!(<SPEC_PATH>)
+                .help("Path to test specification file")
+                .value_parser(value_parser!(PathBuf))
+                .action(clap::ArgAction::Append),
+        )
+}
+
+pub fn exec_test(args: &ArgMatches) -> anyhow::Result<()> {
+    let spec_paths = args.get_many::<PathBuf>("SPEC_PATH").unwrap();
+
+    let mut passed = 0;
+    let mut failed = 0;
+
+    for spec_path in spec_paths {
+        let spec = TestSpec::from_file(spec_path)?;
+
+        for result in spec.run()? {
+            if result.passed() {
+                passed += 1;
+                println!(
+                    "[ {} ] {} ({} samples)",
+                    "PASS".paint(Green).bold(),
+                    result.rule,
+                    result.num_samples
+                );
+            } else {
+                failed += 1;
+                println!(
+                    "[ {} ] {} ({} samples)",
+                    "FAIL".paint(Red).bold(),
+                    result.rule,
+                    result.num_samples
+                );
+                for failure in &result.failures {
+                    if failure.expected_match {
+                        println!("    expected match: {}", failure.sample);
+                    } else {
+                        println!("    unexpected match: {}", failure.sample);
+                    }
+                }
+            }
+        }
+    }
+
+    println!("\n{passed} passed, {failed} failed");
+
+    if failed > 0 {
+        process::exit(1)
+    }
+
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use std::io::{stdin, stdout, BufRead, Write};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use clap::{arg, value_parser, Arg, ArgAction, ArgMatches, Command};
+use crossbeam::channel::bounded;
+use serde_json::json;
+
+use yara_x::Scanner;
+
+use crate::commands::{
+    compilation_args, get_external_vars, load_rules,
+    path_with_namespace_parser,
+};
+use crate::config::Config;
+use crate::help;
+
+#[rustfmt::skip]
+pub fn worker() -> Command {
+    super::command("worker")
+        .about("Scan files whose paths are read from stdin and output the results as NDJSON")
+        .long_about(help::WORKER_LONG_HELP)
+        .arg(
+            Arg::new("[NAMESPACE:]RULES_PATH")
+                .required(true)
+                .help("Path to a YARA source file or directory (optionally prefixed with a namespace)")
+                .value_parser(path_with_namespace_parser)
+                .action(ArgAction::Append)
+        )
+        .args(itertools::merge(compilation_args(), [
+            arg!(-C --"compiled-rules")
+                .help("Indicate that RULES_PATH is a file with compiled rules")
+                .long_help(help::COMPILED_RULES_LONG_HELP),
+            arg!(-p --"threads" <NUM_THREADS>)
+                .help("Use the given number of threads")
+                .long_help(help::THREADS_LONG_HELP)
+                .value_parser(value_parser!(u8).range(1..)),
+            arg!(-a --"timeout" <SECONDS>)
+                .help("Abort scanning after the given number of seconds")
+                .value_parser(value_parser!(u64).range(1..)),
+        ]))
+}
+
+pub fn exec_worker(args: &ArgMatches, config: &Config) -> anyhow::Result<()> {
+    let rules_path = args
+        .get_many::<(Option<String>, PathBuf)>("[NAMESPACE:]RULES_PATH")
+        .unwrap();
+
+    let compiled_rules = args.get_flag("compiled-rules");
+    let timeout =
+        args.get_one::<u64>("timeout").map(|t| Duration::from_secs(*t));
+
+    let num_threads =
+        args.get_one::<u8>("threads").map(|n| *n as usize).unwrap_or_else(
+            || thread::available_parallelism().map(|n| n.get()).unwrap_or(1),
+        );
+
+    let external_vars = get_external_vars(args);
+
+    let rules = load_rules(rules_path, compiled_rules, args, config)?;
+
+    // Tasks are sent from the thread that reads stdin to the scanning
+    // threads through a bounded channel, so that the reader doesn't get too
+    // ahead of the scanners.
+    let (sender, receiver) = bounded::<String>(num_threads * 2);
+
+    // Results are written one line at a time, and flushed immediately, so
+    // that consumers can process them as soon as they are produced.
+    let output = Mutex::new(stdout());
+
+    thread::scope(|s| -> anyhow::Result<()> {
+        for _ in 0..num_threads {
+            let receiver = receiver.clone();
+            let rules = &rules;
+            let output = &output;
+            let external_vars = &external_vars;
+
+            s.spawn(move || {
+                let mut scanner = Scanner::new(rules);
+
+                if let Some(vars) = external_vars {
+                    for (ident, value) in vars {
+                        // It's ok to use `unwrap()`, this can not fail
+                        // because the variables were already validated
+                        // during compilation.
+                        scanner.set_global(ident.as_str(), value).unwrap();
+                    }
+                }
+
+                if let Some(timeout) = timeout {
+                    scanner.set_timeout(timeout);
+                }
+
+                for task in receiver {
+                    let result = match scan_task(&mut scanner, &task) {
+                        Ok(rules) => json!({ "path": task, "rules": rules }),
+                        Err(err) => {
+                            json!({ "path": task, "error": err.to_string() })
+                        }
+                    };
+                    let mut output = output.lock().unwrap();
+                    // Errors while writing to stdout are ignored, they
+                    // happen when the consumer has gone away.
+                    let _ = writeln!(output, "{result}");
+                    let _ = output.flush();
+                }
+            });
+        }
+
+        // The receiver is not needed in this thread anymore, dropping it
+        // makes sure that the `send` below fails if all scanning threads
+        // have finished.
+        drop(receiver);
+
+        // Each line is a path. `lines` removes only the line terminator
+        // (`\n` or `\r\n`), any other whitespace is part of the path.
+        for line in stdin().lock().lines() {
+            let task = line?;
+            if task.is_empty() {
+                continue;
+            }
+            sender.send(task)?;
+        }
+
+        // Dropping the sender tells the scanning threads that there are no
+        // more tasks.
+        drop(sender);
+
+        Ok(())
+    })?;
+
+    Ok(())
+}
+
+/// Scans the file whose path is `task`. Returns the list of matching rules.
+fn scan_task(
+    scanner: &mut Scanner,
+    task: &str,
+) -> anyhow::Result<Vec<serde_json::Value>> {
+    let scan_results = scanner.scan_file(task)?;
+
+    Ok(scan_results
+        .matching_rules()
+        .map(|rule| {
+            json!({
+                "namespace": rule.namespace(),
+                "identifier": rule.identifier(),
+            })
+        })
+        .collect())
+}
@@ -94,8 +94,11 @@ pub struct TagConfig {
     pub allowed: Vec<String>,
     /// Regexp that must match all tags.
     pub regexp: Option<String>,
-    /// If `true`, an incorrect tag name will raise an error instead of a
-    /// warning.
+    /// List of tags that all rules must have.
+    #[serde(default)]
+    pub required: Vec<String>,
+    /// If `true`, an incorrect tag name or a missing tag will raise an
+    /// error instead of a warning.
     #[serde(default)]
     pub error: bool,
 }
@@ -106,6 +109,8 @@ pub struct TagConfig {
 pub struct RuleNameConfig {
     /// Regexp used to validate the rule name.
     pub regexp: Option<String>,
+    /// Maximum length for rule names.
+    pub max_length: Option<usize>,
     /// If `true`, an incorrect rule name will raise an error instead of a
     /// warning.
     #[serde(default)]
@@ -154,6 +159,8 @@ pub struct RuleFormatConfig {
 pub struct MetaFormatConfig {
     /// Align values to longest key.
     pub align_values: bool,
+    /// Sort metadata alphabetically by key.
+    pub sort: bool,
 }
 
 /// Pattern specific formatting information.
@@ -162,6 +169,10 @@ pub struct MetaFormatConfig {
 pub struct PatternsFormatConfig {
     /// Align patterns to the longest name.
     pub align_values: bool,
+    /// Sort patterns alphabetically by identifier.
+    pub sort: bool,
+    /// Write bytes in hex patterns in uppercase.
+    pub normalize_hex: bool,
 }
 
 /// Configuration for warnings.
@@ -186,13 +197,17 @@ impl Default for RuleFormatConfig {
 
 impl Default for MetaFormatConfig {
     fn default() -> MetaFormatConfig {
-        MetaFormatConfig { align_values: true }
+        MetaFormatConfig { align_values: true, sort: false }
     }
 }
 
 impl Default for PatternsFormatConfig {
     fn default() -> PatternsFormatConfig {
-        PatternsFormatConfig { align_values: true }
+        PatternsFormatConfig {
+            align_values: true,
+            sort: false,
+            normalize_hex: false,
+        }
     }
 }
 
@@ -1,8 +1,62 @@
+pub const BENCH_LONG_HELP: &str = r#"Measure the scanning performance of a set of rules
+
+Rules are compiled once, and then every file in <CORPUS_PATH> is scanned the number
+of times indicated by `--repetitions`, distributing the scans among multiple threads.
+Files are loaded in memory before scanning, so the time spent reading from disk is not
+included in the results.
+
+The command reports the throughput and the latency percentiles for individual scans.
+
+Examples:
+
+yr bench rules_file.yar corpus_dir
+yr bench --repetitions=10 --threads=1 rules_file.yar corpus_dir"#;
+
+#[cfg(feature = "compat")]
+pub const COMPAT_LONG_HELP: &str = r#"Compare the results produced by libyara and YARA-X
+
+The rules in <RULES_PATH> are compiled with both libyara and YARA-X, and every file
+in <TARGET_PATH> is scanned with both. For each file the command prints SAME if the
+results are identical, or DIFF followed by the differences, which can be rules that
+matched with only one of them, or patterns with different matches.
+
+The exit code is 1 if the results differ for any file.
+
+Examples:
+
+yr compat rules_file.yar samples_dir
+yr compat --recursive rules_file.yar samples_dir"#;
+
+pub const COVERAGE_LONG_HELP: &str = r#"Report which rules and patterns match in a corpus of files
+
+Every file in <CORPUS_PATH> is scanned with the rules, and for each rule the command
+reports the number of files that matched it, and the patterns that didn't match in
+any file, regardless of whether the rule matched or not. This helps finding rules
+and patterns that are never used.
+
+Patterns are searched only when the condition of some rule needs them, so a pattern
+may be reported as never matched if no condition needed it while scanning the files.
+
+Examples:
+
+yr coverage rules_file.yar corpus_dir
+yr coverage --recursive rules_dir corpus_dir"#;
+
 pub const CHECK_LONG_HELP: &str = r#"Check if YARA source files are correct
 
 If <RULES_PATH> is a directory, all files with extensions `.yar` and `.yara` will be checked.
 This behavior can be changed by using the `--filter` option."#;
 
+pub const CHECK_OUTPUT_FORMAT_LONG_HELP: &str = r#"Output format for errors and warnings
+
+The format can be either `text` (default) or `sarif`. With `sarif` all errors
+and warnings are printed to stdout as a single SARIF 2.1.0 log, which can be
+consumed by code scanning tools and CI pipelines.
+
+Examples:
+
+yr check --output-format=sarif rules_dir > results.sarif"#;
+
 pub const COMPILED_RULES_LONG_HELP: &str = r#"Indicate that <RULES_PATH> is a file containing compiled rules
 
 YARA rules can be compiled with the `yr compile` command. The file produced by
@@ -25,12 +79,47 @@ See https://virustotal.github.io/yara-x/docs/cli/config-file/ for supported opti
 
 pub const DEFINE_LONG_HELP: &str = r#"Define external variable
 
+The type of the variable is inferred from VALUE: `true` and `false` are
+booleans, numbers are integers or floats, and anything else is a string.
+Strings can be also enclosed in double quotes. The type can be set explicitly
+by adding a `:int`, `:float`, `:bool` or `:str` suffix to the variable name.
+This option can be used multiple times.
+
 Examples:
 
 --define some_int=1
 --define some_float=3.14
 --define some_bool=true
---define some_str=\"foobar\""#;
+--define some_str=foobar
+--define some_str=\"foobar\"
+--define some_str:str=1234
+-d filename=foo.exe -d extension=exe"#;
+
+pub const DEDUPE_LONG_HELP: &str = r#"Find duplicate rules in YARA source files
+
+This command groups together rules that are duplicates of each other, and reports
+the location of each rule in a cluster. Two kinds of duplicates are reported:
+
+* Exact duplicates: rules with the same logic, even if they differ in name, tags,
+  metadata, comments, formatting, or the order of their patterns.
+* Near duplicates: rules with the same patterns but different conditions.
+
+Examples:
+
+yr dedupe rules.yar
+yr dedupe --recursive --output-format=json rules/"#;
+
+pub const DOC_LONG_HELP: &str = r#"Extract documentation from YARA source files
+
+For each rule in the source files, this command extracts the rule's metadata, tags,
+the comments that appear right before the rule declaration, and the modules used in
+the rule's condition. The result is printed as a Markdown document or as JSON, which
+can be used for generating a catalog of the rules in a repository.
+
+Examples:
+
+yr doc rules.yar
+yr doc --recursive --output-format=json rules/"#;
 
 pub const DUMP_LONG_HELP: &str = r#"Show the data produced by YARA modules for a file
 
@@ -59,6 +148,21 @@ Examples:
 --disable-warnings=slow_rules,redundant_modifier"
 --disable-warnings=slow_rules --disable-warnings=redundant_modifier"#;
 
+pub const FILES_FROM_LONG_HELP: &str = r#"Scan the files listed in LIST, or in stdin if LIST is -
+
+<LIST> must be a text file containing one path per line. The paths must be
+either absolute paths, or relative to the current directory. Empty lines are
+ignored. Files are scanned while the list is being read, so this option can
+be used for scanning the output of tools like `find` without waiting for them
+to finish.
+
+When this option is used <TARGET_PATH> must be omitted.
+
+Examples:
+
+find /bin -newer rules.yar | yr scan --files-from - rules.yar
+yr scan rules.yar --files-from paths.txt"#;
+
 pub const FILTER_LONG_HELP: &str = r#"Only check files that match the given pattern
 
 Patterns can contains the following wildcards:
@@ -102,17 +206,90 @@ they are not.
 If <RULES_PATH> is a directory, all files with extensions `.yar` and `.yara` will
 be converted. This behavior can be changed by using the `--filter` option."#;
 
+pub const FIX_LEGACY_LONG_HELP: &str = r#"Migrate legacy YARA syntax to YARA-X
+
+Some constructs accepted by YARA are deprecated or not supported by YARA-X. This
+command rewrites them into their YARA-X equivalents:
+
+* The `entrypoint` keyword is replaced with `pe.entry_point`, and the `pe`
+  module is imported if necessary.
+* Octal escape sequences in strings (e.g: "\101") are replaced with hex escape
+  sequences (e.g: "\x41").
+* Non-boolean expressions used as booleans are compared explicitly with zero or
+  the empty string (e.g: `filesize` becomes `filesize != 0`).
+
+If <RULES_PATH> is a directory, all files with extensions `.yar` and `.yara` will
+be migrated. This behavior can be changed by using the `--filter` option. With
+`--dry-run` source files are not modified, and a diff with the changes is printed
+instead."#;
+
 pub const FIX_WARNINGS_LONG_HELP: &str = r#"Automatically fix warnings
 
 This command automatically resolves fixable YARA-X warnings. It accepts the same
 options as the compile command; however, instead of outputting a compiled rules file,
 it directly modifies the source files to fix the warnings."#;
 
+pub const ICAP_LONG_HELP: &str = r#"Start an ICAP server that scans the content sent by clients
+
+The server implements the Internet Content Adaptation Protocol (ICAP, RFC 3507),
+which allows mail and web gateways to send content to YARA-X for scanning. Both
+REQMOD and RESPMOD requests are supported, the body of the encapsulated HTTP
+message is scanned with the given rules.
+
+When the content must be blocked, the server replies with a `403 Forbidden` HTTP
+response containing an HTML page with the names of the matching rules, and sets
+the `X-Infection-Found` header. Otherwise, it replies with `204 No Content`, or
+returns the original message unmodified if the client doesn't accept `204`.
+
+By default, content matching any rule is blocked. With `--block-tag` and
+`--block-severity` only the rules that meet some of the criteria block the
+content.
+
+Examples:
+
+yr icap rules_dir
+yr icap --address 0.0.0.0:1344 --block-tag malware --block-severity 7 rules_dir"#;
+
+pub const ICAP_BLOCK_PAGE_LONG_HELP: &str = r#"HTML template for the page returned when content is blocked
+
+The placeholder {{rules}} in the template is replaced with a comma-separated list
+of the rules that caused the content to be blocked."#;
+
+pub const ICAP_BLOCK_SEVERITY_LONG_HELP: &str = r#"Block content matching rules with this severity or higher
+
+The severity of each rule is taken from the metadata key given by `--severity-meta`
+(`severity` by default), which must be an integer, or a string containing an
+integer. Rules without severity don't block the content unless they have some of
+the tags specified with `--block-tag`."#;
+
+pub const ICAP_BLOCK_TAG_LONG_HELP: &str = r#"Block content matching rules with this tag
+
+This option can be used more than once for specifying multiple tags. Content is
+blocked if it matches any rule that has some of the tags."#;
+
+pub const ICAP_MAX_CONNECTIONS_LONG_HELP: &str = r#"Maximum number of connections handled at the same time
+
+Each connection is handled by its own thread, and ICAP clients usually keep
+their connections open for sending multiple requests. When the limit is
+reached, new connections wait until some of the existing ones are closed,
+either by the client or because it didn't send data within `--read-timeout`."#;
+
 pub const INCLUDE_DIR_LONG_HELP: &str = r#"Directory in which to search for included files
 
 If not given, the current working directory is used. May be specified multiple 
 times; directories will be searched in order."#;
 
+pub const IR_LONG_HELP: &str = r#"Print the Intermediate Representation (IR) for a YARA source file
+
+The IR is the representation of rule conditions from which the executable code
+is generated. Contrary to the source code, the IR reflects the transformations
+done by the compiler, like constant folding or the hoisting of loop-invariant
+expressions, so it shows what the scanner will actually evaluate.
+
+The IR of each rule is printed as a tree where each line is an expression, with
+its sub-expressions indented below it. The format of this output is meant for
+humans and it may change between versions of YARA-X."#;
+
 pub const IGNORE_MODULE_LONG_HELP: &str = r#"Ignore rules that use the specified module
 
 Rules that use the specified module will be ignored, as well as any rules that
@@ -130,12 +307,46 @@ occur and the YARA-X process will crash.
 This option disables memory mapping and forces the scanner to always read files
 into an in-memory buffer instead."#;
 
+pub const LSP_LONG_HELP: &str = r#"Start the YARA-X language server
+
+The language server communicates with the editor using the Language Server Protocol
+(LSP) over stdin and stdout. It provides diagnostics, code completion, go-to-definition,
+hover information, formatting, and other features for YARA source files.
+
+This command is not meant to be used directly, but configured in your editor as the
+command that starts the language server for YARA files."#;
+
+pub const MAN_LONG_HELP: &str = r#"Output man pages for the CLI
+
+Without arguments, the man page for `yr` is printed to stdout. If COMMAND is
+given, the man page for that command is printed instead. With --output-dir,
+the man pages for `yr` and all its commands are written to the given directory,
+one file per command (e.g: yr.1, yr-scan.1, etc).
+
+Examples:
+
+yr man | man -l -
+yr man scan > yr-scan.1
+yr man --output-dir /usr/local/share/man/man1"#;
+
+pub const MAX_LOOP_ITERATIONS_LONG_HELP: &str = r#"Maximum number of iterations for loops with unknown bounds
+
+Loops that iterate over a range whose bounds are not known at compile time,
+like `for any i in (0..filesize) : (...)`, or over arrays and maps, like
+`for any s in pe.sections : (...)`, perform at most this number of
+iterations. This can affect the result of rules with such loops, as part of
+the range, array or map is not evaluated. A warning is shown for every rule
+containing some loop that reaches the limit. Loops over patterns, tuples of
+expressions or ranges with constant bounds are not limited. By default,
+there's no limit."#;
+
 pub const MAX_MATCHES_PER_PATTERN_LONG_HELP: &str = r#"Maximum number of matches per pattern
 
 When some pattern reaches the maximum number of occurrences it won't produce
 more matches. This can affect rules that rely on the number of occurrences of
 some pattern. For instance, the expression `#a > 100` will be false if this 
-limit is set to 100 or less."#;
+limit is set to 100 or less. A warning is shown for every pattern that reaches
+the limit. By default, the limit is 1000000."#;
 
 pub const MODULE_DATA_LONG_HELP: &str = r#"Pass FILE's content as extra data to MODULE
 
@@ -156,6 +367,15 @@ pub const OUTPUT_FORMAT_LONG_HELP: &str = r#"Output format
 The format in which results will be displayed. Any errors or warnings will not
 be in this format, only results.
 
+The `stix` and `misp` formats are intended for sharing the results with threat
+intelligence platforms. `stix` produces a STIX 2.1 bundle with an indicator and
+a sighting for each matching rule, while `misp` produces a MISP event with the
+name and hashes of each matching file as attributes.
+
+The `proto` format produces a `ScanResults` protobuf message for each scanned
+file, including the structures produced by the modules. Each message is
+preceded by its length, encoded as a varint.
+
 Examples:
 
 --output-format=ndjson"#;
@@ -176,10 +396,63 @@ Examples:
 --recursive
 --recursive=3"#;
 
+pub const TEST_LONG_HELP: &str = r#"Test rules against positive and negative samples
+
+<SPEC_PATH> is a TOML file that lists the files containing the rules under test,
+and for each rule the samples that it must match (positive) and the ones that it
+must not match (negative). Samples are paths to files, or inline data in hex. Paths
+are relative to the directory containing the specification file.
+
+rules = ["rules.yar"]
+
+[[test]]
+rule = "foo"
+positive = ["samples/foo.bin", { hex = "4d 5a 90 00" }]
+negative = ["samples/clean.bin"]
+
+The exit code is 1 if any test fails.
+
+Examples:
+
+yr test tests.toml"#;
+
 pub const THREADS_LONG_HELP: &str = r#"Use the specified number of threads
 
 The default value is automatically determined based on the number of CPU cores."#;
 
+pub const PID_LONG_HELP: &str = r#"Scan the memory of the process with the given PID
+
+Each readable memory region in the process is scanned individually, and the
+results are reported with paths like `pid:1234:0x7f0000-0x7f8000`, where the
+range is the range of addresses occupied by the region. The offsets shown by
+--print-strings are virtual addresses in the process.
+
+Regions larger than 256MB are skipped, unless other limit is specified with
+--skip-larger.
+
+Reading the memory of other processes usually requires elevated privileges.
+This option is currently supported only in Linux.
+
+When this option is used <TARGET_PATH> must be omitted.
+
+Examples:
+
+yr scan --pid 1234 rules.yar"#;
+
+pub const SCAN_ARCHIVES_LONG_HELP: &str = r#"Scan the files inside zip, tar and gzip archives
+
+When a scanned file is an archive, the archive itself is scanned, and then each
+file in the archive is extracted in memory and scanned individually. Archives
+inside archives are extracted too, like in the case of .tar.gz files. Results
+for the extracted files are reported with paths like `archive.zip!dir/file`,
+where `!` separates the path of the archive from the path of the file within
+the archive.
+
+The number of files and bytes extracted from each archive are limited by
+--max-archive-entries (default: 10000) and --max-archive-size (default: 1GB).
+When any of the limits is reached, the remaining files are not extracted and
+a warning is shown."#;
+
 pub const SCAN_LIST_LONG_HELP: &str = r#"Indicate that TARGET_PATH is a file containing the paths to be scanned
 
 <TARGET_PATH> must be a text file containing one path per line. The paths must
@@ -195,7 +468,8 @@ Each path can be prefixed with a namespace, the namespace and the path are separ
 a semicolon (`:`), like in `namespace:rules_file.yar`. All rules in the path will be put
 under the specified namespace, isolated from rules in other namespaces.
 
-<TARGET_PATH> is the file or directory that will be scanned.
+<TARGET_PATH> is the file or directory that will be scanned. It must be omitted when
+the files to scan are read from a list with --files-from.
 
 Examples:
 
@@ -214,6 +488,51 @@ Examples:
 --print-strings
 --print-strings=50"#;
 
+pub const SCAN_FAIL_ON_ERROR_LONG_HELP: &str = r#"Exit with code 2 if any error occurs
+
+By default, errors found while scanning individual files (like files that can't
+be read) are reported to stderr, but don't affect the exit code. With this
+option the exit code is 2 if any such error occurs.
+
+When either --fail-on-error or --fail-on-match are used, the exit codes are:
+
+  0: no errors, and no file matched (or matches are not checked)
+  1: some file matched (only with --fail-on-match)
+  2: some error occurred
+
+Errors take precedence over matches."#;
+
+pub const SCAN_FAIL_ON_MATCH_LONG_HELP: &str = r#"Exit with code 1 if any file matches
+
+By default, the exit code is 0 regardless of whether some file matched. With
+this option the exit code is 1 if at least one file was included in the output,
+and 0 otherwise. When --negate is used, files are included in the output when
+some rule is not satisfied.
+
+Errors that prevent the scan from starting, like rules that don't compile,
+exit with code 2 instead of 1 when this option is used, so they can be told
+apart from matches. See --fail-on-error."#;
+
+pub const SCAN_GROUP_BY_LONG_HELP: &str = r#"Group results by file or by rule
+
+With --group-by=file (the default) each line in the output contains a matching
+rule followed by the path of the file it matched, and lines are printed as
+files are scanned.
+
+With --group-by=rule the results are printed once all files are scanned. Each
+matching rule is printed once, followed by the paths of the files it matched,
+one per line.
+
+This option only applies to the text output format."#;
+
+pub const SCAN_QUIET_LONG_HELP: &str = r#"Print only the paths of matching files
+
+Each matching file is printed once, regardless of the number of rules that
+matched it. This is useful for piping the list of matching files to other
+tools. Warnings about the scanned files are still printed to stderr.
+
+This option only applies to the text output format."#;
+
 pub const SCAN_RECURSIVE_LONG_HELP: &str = r#"Scan directories recursively
 
 When <TARGET_PATH> is a directory, this option enables recursive scanning
@@ -230,3 +549,58 @@ Examples:
 
 --recursive
 --recursive=3"#;
+
+pub const SCAN_STATS_LONG_HELP: &str = r#"Print scan statistics for each file and a final summary
+
+For each scanned file this prints the time spent scanning it, the throughput
+in megabytes per second, and the number of pattern matches found. Once all
+files are scanned, a summary with the total number of files, bytes and pattern
+matches, and the overall throughput is printed.
+
+Statistics are printed to stderr, so they don't interfere with the output
+format selected with --output-format. Files inside archives scanned with
+--scan-archives are not included."#;
+
+pub const WARN_PERFORMANCE_LONG_HELP: &str = r#"Warn about rules that are potentially slow
+
+The compiler estimates the cost of each rule from the quality of the atoms
+extracted from its patterns, the number of patterns that must be verified with
+a regular expression engine, the loops in the condition, and the calls to module
+functions. Rules with an estimated cost higher than THRESHOLD produce a warning
+that describes the factors contributing to the cost. The default THRESHOLD is 100.
+
+Examples:
+
+--warn-performance
+--warn-performance=200"#;
+
+pub const WORKER_LONG_HELP: &str = r#"Scan files whose paths are read from stdin and output the results as NDJSON
+
+The worker reads the paths of local files from stdin, one per line, and scans
+them using multiple threads that share the same compiled rules. Lines are used
+as they are, except for the line terminator, so paths can contain leading or
+trailing spaces. Empty lines are ignored. Paths are processed as soon as they
+are read, and the worker runs until stdin is closed.
+
+For each path, a JSON object is printed in a separate line, containing either the
+matching rules, or the error that occurred while scanning the file. Results are
+printed as soon as they are available, so they can appear in a different order
+than the paths.
+
+Examples:
+
+find /data -type f | yr worker rules_dir"#;
+
+pub const WATCH_LONG_HELP: &str = r#"Rescan when the rules or the scanned files change
+
+In this mode the rules are compiled and <TARGET_PATH> is scanned as usual,
+and then YARA-X keeps watching the rule files and the scanned files for
+changes. Every time that any of the files is modified, added or removed, the
+rules are compiled again and <TARGET_PATH> is rescanned.
+
+After each scan only the differences with respect to the previous one are
+printed. Matches that didn't exist in the previous scan are prefixed with `+`,
+and matches that disappeared are prefixed with `-`. Compilation errors are
+printed, and the previous matches are kept until the errors are fixed.
+
+Press Ctrl+C for exiting this mode."#;
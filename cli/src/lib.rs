@@ -0,0 +1,11 @@
+/*! Library code shared by the YARA-X command-line interface.
+
+This crate contains the parts of the `yr` command-line tool that are useful
+for other programs, like the [`walk`] module, which walks directories and
+lists of files applying the same filters and policies used by commands like
+`yr scan`, `yr check` and `yr fmt`, and the [`testing`] module, which runs
+the tests used by `yr test`.
+*/
+
+pub mod testing;
+pub mod walk;
@@ -1,7 +1,9 @@
+mod archive;
 mod commands;
 mod config;
 mod help;
-mod walk;
+mod process_memory;
+mod sarif;
 
 #[cfg(test)]
 mod tests;
@@ -12,6 +14,7 @@ use std::path::PathBuf;
 use std::{io, panic, process};
 use yansi::Color::Red;
 use yansi::Paint;
+use yara_x_cli::walk;
 
 use crate::commands::cli;
 use crate::config::load_config_from_file;
@@ -26,6 +29,7 @@ const APP_HELP_TEMPLATE: &str = r#"YARA-X {version}, the pattern matching swiss
 "#;
 
 const EXIT_ERROR: i32 = 1;
+const EXIT_SCAN_ERROR: i32 = 2;
 const CONFIG_FILE: &str = ".yara-x.toml";
 
 fn main() -> anyhow::Result<()> {
@@ -85,12 +89,25 @@ fn main() -> anyhow::Result<()> {
         #[cfg(feature = "debug-cmd")]
         Some(("debug", args)) => commands::exec_debug(args, &config),
         Some(("check", args)) => commands::exec_check(args, &config),
+        Some(("bench", args)) => commands::exec_bench(args, &config),
+        #[cfg(feature = "compat")]
+        Some(("compat", args)) => commands::exec_compat(args),
+        Some(("coverage", args)) => commands::exec_coverage(args, &config),
         Some(("fix", args)) => commands::exec_fix(args, &config),
         Some(("fmt", args)) => commands::exec_fmt(args, &config),
+        Some(("icap", args)) => commands::exec_icap(args, &config),
+        Some(("ir", args)) => commands::exec_ir(args, &config),
         Some(("scan", args)) => commands::exec_scan(args, &config),
+        Some(("test", args)) => commands::exec_test(args),
+        Some(("worker", args)) => commands::exec_worker(args, &config),
+        Some(("dedupe", args)) => commands::exec_dedupe(args),
+        Some(("doc", args)) => commands::exec_doc(args),
         Some(("dump", args)) => commands::exec_dump(args),
         Some(("compile", args)) => commands::exec_compile(args, &config),
+        #[cfg(feature = "lsp")]
+        Some(("lsp", args)) => commands::exec_lsp(args),
         Some(("completion", args)) => commands::exec_completion(args),
+        Some(("man", args)) => commands::exec_man(args),
         _ => unreachable!(),
     };
 
@@ -100,6 +117,15 @@ fn main() -> anyhow::Result<()> {
         } else {
             eprintln!("{} {}", "error:".paint(Red).bold(), err);
         }
+        // With `--fail-on-match` or `--fail-on-error`, `yr scan` uses exit
+        // code 1 for indicating that some file matched, errors use exit
+        // code 2 instead.
+        if let Some(("scan", args)) = args.subcommand() {
+            if args.get_flag("fail-on-match") || args.get_flag("fail-on-error")
+            {
+                process::exit(EXIT_SCAN_ERROR);
+            }
+        }
         process::exit(EXIT_ERROR);
     }
 
@@ -0,0 +1,177 @@
+//! Access to the memory of running processes.
+//!
+//! This is used by `yr scan --pid` for scanning the memory of a process.
+//! The memory is scanned region by region, each region is a contiguous
+//! range of virtual addresses mapped into the process' address space.
+//!
+//! Reading the memory of a process is currently supported only in Linux,
+//! where it is done through the `/proc/<PID>/maps` and `/proc/<PID>/mem`
+//! files.
+#[cfg(target_os = "linux")]
+use std::fs::File;
+use std::ops::Range;
+
+#[cfg(target_os = "linux")]
+use anyhow::Context;
+
+/// Regions larger than this are skipped while scanning a process, unless
+/// other limit is specified with `--skip-larger`. Each region is loaded in
+/// memory before scanning it, and processes can have very large regions,
+/// like reserved address ranges or mappings of huge files.
+pub(crate) const DEFAULT_MAX_REGION_SIZE: u64 = 256 * 1024 * 1024;
+
+/// Size of the chunks in which the content of a region is read.
+#[cfg(target_os = "linux")]
+const READ_CHUNK_SIZE: usize = 1024 * 1024;
+
+/// A region of memory in a process.
+#[derive(Clone, Debug)]
+pub(crate) struct Region {
+    /// Range of virtual addresses occupied by the region.
+    pub range: Range<u64>,
+}
+
+impl Region {
+    /// Size of the region in bytes.
+    pub fn size(&self) -> u64 {
+        self.range.end - self.range.start
+    }
+}
+
+/// Memory of a running process.
+pub(crate) struct Process {
+    #[cfg(target_os = "linux")]
+    pid: u32,
+    #[cfg(target_os = "linux")]
+    mem: File,
+}
+
+impl Process {
+    /// Opens the process identified by `pid` for reading its memory.
+    ///
+    /// Fails if the process doesn't exist, or if the current user doesn't
+    /// have enough privileges for reading its memory. The error message
+    /// explains which privileges are required in the current platform.
+    #[cfg(target_os = "linux")]
+    pub fn open(pid: u32) -> anyhow::Result<Self> {
+        let mem = File::open(format!("/proc/{pid}/mem"))
+            .map_err(|err| open_error(pid, err))?;
+        Ok(Self { pid, mem })
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn open(pid: u32) -> anyhow::Result<Self> {
+        let _ = pid;
+        anyhow::bail!(
+            "scanning process memory is not supported in {}",
+            std::env::consts::OS
+        )
+    }
+
+    /// Returns the readable memory regions in the process.
+    #[cfg(target_os = "linux")]
+    pub fn regions(&self) -> anyhow::Result<Vec<Region>> {
+        let path = format!("/proc/{}/maps", self.pid);
+        let maps = std::fs::read_to_string(&path)
+            .with_context(|| format!("can not read {path}"))?;
+        Ok(maps.lines().filter_map(parse_maps_line).collect())
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn regions(&self) -> anyhow::Result<Vec<Region>> {
+        unreachable!()
+    }
+
+    /// Reads the content of a memory region.
+    ///
+    /// The region is read in chunks of [`READ_CHUNK_SIZE`] bytes, so that
+    /// memory is allocated only for the data that was actually read.
+    #[cfg(target_os = "linux")]
+    pub fn read(&self, region: &Region) -> std::io::Result<Vec<u8>> {
+        use std::os::unix::fs::FileExt;
+
+        let size = region.size() as usize;
+        let mut data = Vec::new();
+
+        // Regions can be partially readable, in that case only the
+        // readable part at the start of the region is returned.
+        while data.len() < size {
+            let pos = data.len();
+            let chunk_size = READ_CHUNK_SIZE.min(size - pos);
+            data.resize(pos + chunk_size, 0);
+            match self
+                .mem
+                .read_at(&mut data[pos..], region.range.start + pos as u64)
+            {
+                Ok(n) => {
+                    data.truncate(pos + n);
+                    if n == 0 {
+                        break;
+                    }
+                }
+                Err(err) if pos == 0 => return Err(err),
+                Err(_) => {
+                    data.truncate(pos);
+                    break;
+                }
+            }
+        }
+
+        Ok(data)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn read(&self, _region: &Region) -> std::io::Result<Vec<u8>> {
+        unreachable!()
+    }
+}
+
+/// Builds the error returned when the memory of process `pid` can't be
+/// opened.
+#[cfg(target_os = "linux")]
+fn open_error(pid: u32, err: std::io::Error) -> anyhow::Error {
+    match err.kind() {
+        std::io::ErrorKind::NotFound => {
+            anyhow::anyhow!("process {pid} not found")
+        }
+        std::io::ErrorKind::PermissionDenied => anyhow::anyhow!(
+            "can't read the memory of process {pid}: permission denied\n\n\
+             Reading the memory of another process requires running as root \
+             or with the CAP_SYS_PTRACE capability. Without them, processes \
+             owned by the same user can be read only if \
+             /proc/sys/kernel/yama/ptrace_scope is 0."
+        ),
+        _ => anyhow::Error::from(err)
+            .context(format!("can't read the memory of process {pid}")),
+    }
+}
+
+/// Parses a line in `/proc/<PID>/maps`, like this one:
+///
+/// ```text
+/// 7f2c4e400000-7f2c4e428000 r--p 00000000 08:01 1315 /usr/lib/libc.so.6
+/// ```
+///
+/// Returns `None` if the line can't be parsed, or if the region is not
+/// readable.
+#[cfg(target_os = "linux")]
+fn parse_maps_line(line: &str) -> Option<Region> {
+    let mut fields = line.splitn(6, ' ');
+
+    let (start, end) = fields.next()?.split_once('-')?;
+    let perms = fields.next()?;
+
+    // Skip the offset, device and inode fields.
+    let path = fields.nth(3).map(str::trim).filter(|path| !path.is_empty());
+
+    // Regions that are not readable are skipped, and also the `[vvar]`
+    // region, which can't be read through `/proc/<PID>/mem`.
+    if !perms.starts_with('r') || path == Some("[vvar]") {
+        return None;
+    }
+
+    Some(Region {
+        range: u64::from_str_radix(start, 16).ok()?
+            ..u64::from_str_radix(end, 16).ok()?,
+    })
+}
@@ -0,0 +1,126 @@
+/*! Serializes compiler errors and warnings in [SARIF][1] format.
+
+SARIF (Static Analysis Results Interchange Format) is understood by many CI
+systems, which use it for annotating the lines of code where issues were
+found. For instance, GitHub code scanning accepts SARIF files uploaded by
+the `github/codeql-action/upload-sarif` action.
+
+[1]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+ */
+
+use std::collections::BTreeSet;
+
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use yara_x::errors::CompileError;
+use yara_x::warnings::Warning;
+
+/// A SARIF log containing the errors and warnings produced while compiling
+/// one or more source files.
+#[derive(Default)]
+pub struct SarifLog {
+    rules: BTreeSet<String>,
+    results: Vec<Value>,
+}
+
+impl SarifLog {
+    /// Creates an empty log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an error to the log.
+    pub fn add_error(&mut self, err: &CompileError) {
+        self.add("error", err)
+    }
+
+    /// Adds a warning to the log.
+    pub fn add_warning(&mut self, warning: &Warning) {
+        self.add("warning", warning)
+    }
+
+    fn add<D: Serialize>(&mut self, level: &str, diagnostic: &D) {
+        // Errors and warnings are converted to JSON first, as this is the
+        // representation that exposes the line and column for each label.
+        let diagnostic = serde_json::to_value(diagnostic).unwrap();
+        let code = diagnostic["code"].as_str().unwrap_or_default();
+
+        let mut locations = Vec::new();
+        let mut related_locations = Vec::new();
+
+        for label in diagnostic["labels"].as_array().into_iter().flatten() {
+            let location = location(label);
+            // The first label with the same level as the diagnostic itself
+            // is the primary location, the rest are related locations.
+            if locations.is_empty() && label["level"] == level {
+                locations.push(location);
+            } else {
+                related_locations.push(location);
+            }
+        }
+
+        let mut result = json!({
+            "ruleId": code,
+            "level": level,
+            "message": { "text": diagnostic["title"] },
+            "locations": locations,
+        });
+
+        if !related_locations.is_empty() {
+            result["relatedLocations"] = related_locations.into();
+        }
+
+        self.rules.insert(code.to_string());
+        self.results.push(result);
+    }
+
+    /// Returns the log as a JSON value.
+    pub fn to_json(&self) -> Value {
+        json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "yara-x",
+                        "informationUri": "https://virustotal.github.io/yara-x/",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": self.rules
+                            .iter()
+                            .map(|code| json!({ "id": code }))
+                            .collect::<Vec<_>>(),
+                    }
+                },
+                // Columns reported by YARA-X are counted in characters.
+                "columnKind": "unicodeCodePoints",
+                "results": self.results,
+            }]
+        })
+    }
+}
+
+/// Converts a label, as serialized by YARA-X, into a SARIF location.
+fn location(label: &Value) -> Value {
+    let start = label["span"]["start"].as_u64().unwrap_or_default();
+    let end = label["span"]["end"].as_u64().unwrap_or_default();
+
+    let mut location = json!({
+        "physicalLocation": {
+            "region": {
+                "startLine": label["line"],
+                "startColumn": label["column"],
+                "byteOffset": start,
+                "byteLength": end.saturating_sub(start),
+            }
+        },
+        "message": { "text": label["text"] },
+    });
+
+    if let Some(origin) = label["code_origin"].as_str() {
+        location["physicalLocation"]["artifactLocation"] =
+            json!({ "uri": origin.replace('\\', "/") });
+    }
+
+    location
+}
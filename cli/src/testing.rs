@@ -0,0 +1,222 @@
+/*! Tests for YARA rules.
+
+A test specification is a TOML file that lists the files containing the
+rules under test, and for each rule the samples it must match (positive
+samples) and the samples it must not match (negative samples). Samples can
+be files, or inline data given as a string of hex digits.
+
+```toml
+# Paths are relative to the directory containing this file.
+rules = ["rules/malware.yar"]
+
+[[test]]
+rule = "foo"
+positive = ["samples/foo.bin", { hex = "4d 5a 90 00" }]
+negative = ["samples/clean.bin"]
+```
+
+The specification is loaded with [`TestSpec::from_file`], and executed with
+[`TestSpec::run`], which returns a [`RuleTestResult`] for each test.
+*/
+
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+use figment::providers::{Format, Toml};
+use figment::Figment;
+use serde::Deserialize;
+use yara_x::{Compiler, Rules, Scanner, SourceCode};
+
+/// A test specification.
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct TestSpec {
+    /// Files containing the rules under test.
+    pub rules: Vec<PathBuf>,
+    /// Tests for individual rules.
+    #[serde(rename = "test", default)]
+    pub tests: Vec<RuleTest>,
+}
+
+/// The samples that a rule must match, and the ones it must not match.
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct RuleTest {
+    /// Identifier of the rule under test.
+    pub rule: String,
+    /// Samples that must match the rule.
+    #[serde(default)]
+    pub positive: Vec<Sample>,
+    /// Samples that must not match the rule.
+    #[serde(default)]
+    pub negative: Vec<Sample>,
+}
+
+/// A sample scanned during a test.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum Sample {
+    /// Path to a file.
+    File(PathBuf),
+    /// Inline data, as a string of hex digits. Whitespaces are ignored.
+    Hex { hex: String },
+}
+
+impl Sample {
+    /// Returns the data in the sample.
+    pub fn data(&self) -> anyhow::Result<Vec<u8>> {
+        match self {
+            Sample::File(path) => fs::read(path)
+                .with_context(|| format!("can not read `{}`", path.display())),
+            Sample::Hex { hex } => {
+                let digits: String =
+                    hex.chars().filter(|c| !c.is_whitespace()).collect();
+                hex::decode(digits)
+                    .with_context(|| format!("invalid hex data `{hex}`"))
+            }
+        }
+    }
+}
+
+impl Display for Sample {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Sample::File(path) => write!(f, "{}", path.display()),
+            Sample::Hex { hex } => write!(f, "hex \"{hex}\""),
+        }
+    }
+}
+
+/// A sample that produced an unexpected result.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Failure {
+    /// The sample that produced the unexpected result.
+    pub sample: Sample,
+    /// `true` if the sample was expected to match the rule, but it didn't.
+    /// `false` if the sample was not expected to match, but it did.
+    pub expected_match: bool,
+}
+
+/// Result of the tests for a rule.
+#[derive(Debug)]
+pub struct RuleTestResult {
+    /// Identifier of the rule.
+    pub rule: String,
+    /// Number of samples scanned.
+    pub num_samples: usize,
+    /// Samples that produced unexpected results.
+    pub failures: Vec<Failure>,
+}
+
+impl RuleTestResult {
+    /// Returns `true` if all the samples produced the expected result.
+    pub fn passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+impl TestSpec {
+    /// Loads a test specification from a TOML file.
+    ///
+    /// Relative paths in the specification are resolved relative to the
+    /// directory containing the file.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+
+        let mut spec: TestSpec = Figment::from(Toml::file_exact(path))
+            .extract()
+            .with_context(|| format!("can not load `{}`", path.display()))?;
+
+        let base_dir = path.parent().unwrap_or(Path::new(""));
+
+        for rules_path in spec.rules.iter_mut() {
+            *rules_path = base_dir.join(&rules_path);
+        }
+
+        for test in spec.tests.iter_mut() {
+            for sample in
+                test.positive.iter_mut().chain(test.negative.iter_mut())
+            {
+                if let Sample::File(path) = sample {
+                    *path = base_dir.join(&path);
+                }
+            }
+        }
+
+        Ok(spec)
+    }
+
+    /// Compiles the rules in the specification.
+    pub fn compile(&self) -> anyhow::Result<Rules> {
+        let mut compiler = Compiler::new();
+
+        for path in &self.rules {
+            let src = fs::read(path).with_context(|| {
+                format!("can not read `{}`", path.display())
+            })?;
+
+            let src = SourceCode::from(src.as_slice())
+                .with_origin(path.as_os_str().to_str().unwrap());
+
+            compiler.add_source(src)?;
+        }
+
+        Ok(compiler.build())
+    }
+
+    /// Compiles the rules and runs the tests.
+    ///
+    /// Returns an error if the rules can't be compiled, a sample can't be
+    /// read, or some test refers to a rule that doesn't exist.
+    pub fn run(&self) -> anyhow::Result<Vec<RuleTestResult>> {
+        let rules = self.compile()?;
+
+        for test in &self.tests {
+            if !rules.iter().any(|rule| rule.identifier() == test.rule) {
+                bail!("rule `{}` not found", test.rule);
+            }
+        }
+
+        let mut scanner = Scanner::new(&rules);
+        let mut results = Vec::with_capacity(self.tests.len());
+
+        for test in &self.tests {
+            let mut failures = Vec::new();
+
+            let samples = test
+                .positive
+                .iter()
+                .map(|sample| (sample, true))
+                .chain(test.negative.iter().map(|sample| (sample, false)));
+
+            for (sample, expected_match) in samples {
+                let data = sample.data()?;
+                let scan_results = scanner
+                    .scan(data.as_slice())
+                    .with_context(|| format!("error scanning {sample}"))?;
+
+                let matched = scan_results
+                    .matching_rules()
+                    .include_private(true)
+                    .any(|rule| rule.identifier() == test.rule);
+
+                if matched != expected_match {
+                    failures.push(Failure {
+                        sample: sample.clone(),
+                        expected_match,
+                    });
+                }
+            }
+
+            results.push(RuleTestResult {
+                rule: test.rule.clone(),
+                num_samples: test.positive.len() + test.negative.len(),
+                failures,
+            });
+        }
+
+        Ok(results)
+    }
+}
@@ -0,0 +1,32 @@
+use assert_cmd::{cargo_bin, Command};
+use predicates::prelude::*;
+
+#[test]
+fn bench() {
+    Command::new(cargo_bin!("yr"))
+        .arg("bench")
+        .arg("--repetitions=3")
+        .arg("--threads=2")
+        .arg("src/tests/testdata/foo.yar")
+        .arg("src/tests/testdata/dummy.file")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "1 file(s), 3 repetition(s), 2 thread(s)",
+        ))
+        .stdout(predicate::str::contains("3 scans in"))
+        .stdout(predicate::str::contains("p50="));
+}
+
+#[test]
+fn bench_empty_corpus() {
+    let temp_dir = assert_fs::TempDir::new().unwrap();
+
+    Command::new(cargo_bin!("yr"))
+        .arg("bench")
+        .arg("src/tests/testdata/foo.yar")
+        .arg(temp_dir.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no files found"));
+}
@@ -176,6 +176,65 @@ error[E039]: rule name does not match regex `APT_.+`
         );
 }
 
+#[test]
+fn check_rule_name_max_length() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_file = temp_dir.child("config.toml");
+
+    config_file
+        .write_str(
+            r#"
+            [check.rule_name]
+            max_length = 2
+            error = true
+            "#,
+        )
+        .unwrap();
+
+    Command::new(cargo_bin!("yr"))
+        .arg("--config")
+        .arg(config_file.path())
+        .arg("check")
+        .arg("src/tests/testdata/foo.yar")
+        .assert()
+        .failure()
+        .code(1)
+        .stdout(
+            r#"[ FAIL ] src/tests/testdata/foo.yar
+error[E048]: rule name is too long
+ --> src/tests/testdata/foo.yar:1:6
+  |
+1 | rule foo : bar baz {
+  |      ^^^ this rule name has 3 characters, the maximum is 2
+"#,
+        );
+}
+
+#[test]
+fn check_required_tags() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_file = temp_dir.child("config.toml");
+
+    config_file
+        .write_str(
+            r#"
+            [check.tags]
+            required = ["bar", "qux"]
+            "#,
+        )
+        .unwrap();
+
+    Command::new(cargo_bin!("yr"))
+        .arg("--config")
+        .arg(config_file.path())
+        .arg("check")
+        .arg("src/tests/testdata/foo.yar")
+        .assert()
+        .failure()
+        .code(2)
+        .stdout("[ WARN ] src/tests/testdata/foo.yar\n");
+}
+
 #[test]
 fn config_error() {
     let temp_dir = TempDir::new().unwrap();
@@ -203,3 +262,70 @@ fn config_error() {
             )
         );
 }
+
+#[test]
+fn sarif_output() {
+    let temp_dir = TempDir::new().unwrap();
+    let yar_file = temp_dir.child("test.yar");
+
+    yar_file
+        .write_str(
+            r#"rule test {
+  condition:
+    foo
+}"#,
+        )
+        .unwrap();
+
+    let output = Command::new(cargo_bin!("yr"))
+        .arg("check")
+        .arg("--output-format=sarif")
+        .arg(yar_file.path())
+        .assert()
+        .code(1)
+        .get_output()
+        .stdout
+        .clone();
+
+    let log: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+    assert_eq!(log["version"], "2.1.0");
+
+    let result = &log["runs"][0]["results"][0];
+
+    assert_eq!(result["ruleId"], "E009");
+    assert_eq!(result["level"], "error");
+    assert_eq!(
+        result["locations"][0]["physicalLocation"]["region"]["startLine"],
+        3
+    );
+}
+
+#[test]
+fn warn_performance() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.child("rule.yar");
+
+    input_file
+        .write_str(
+            "rule test { condition: for any i in (0..filesize) : ( uint8(i) == 0 ) }",
+        )
+        .unwrap();
+
+    Command::new(cargo_bin!("yr"))
+        .arg("check")
+        .arg("--warn-performance")
+        .arg(input_file.path())
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "warning[costly_rule]: rule `test` is potentially slow",
+        ));
+
+    Command::new(cargo_bin!("yr"))
+        .arg("check")
+        .arg("--warn-performance=1000")
+        .arg(input_file.path())
+        .assert()
+        .stderr(predicate::str::contains("costly_rule").not());
+}
@@ -0,0 +1,50 @@
+use assert_cmd::{cargo_bin, Command};
+use assert_fs::prelude::*;
+use assert_fs::TempDir;
+use predicates::prelude::*;
+
+#[test]
+fn compat() {
+    let temp_dir = TempDir::new().unwrap();
+    let rules_file = temp_dir.child("rules.yar");
+
+    rules_file
+        .write_str(
+            r#"
+            rule foo { strings: $a = "foo" condition: $a }
+            rule jump { strings: $a = { 61 [-] 62 } condition: $a }
+            "#,
+        )
+        .unwrap();
+
+    temp_dir.child("samples/1.bin").write_str("foo").unwrap();
+
+    Command::new(cargo_bin!("yr"))
+        .arg("compat")
+        .arg(rules_file.path())
+        .arg(temp_dir.child("samples").path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("1.bin"))
+        .stdout(predicate::str::contains(
+            "1 file(s) scanned, 0 with different results",
+        ));
+
+    temp_dir
+        .child("samples/2.bin")
+        .write_binary(&[b"a".as_slice(), &[b'x'; 10000], b"b"].concat())
+        .unwrap();
+
+    Command::new(cargo_bin!("yr"))
+        .arg("compat")
+        .arg(rules_file.path())
+        .arg(temp_dir.child("samples").path())
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains(
+            "`default:jump` matched with libyara only",
+        ))
+        .stdout(predicate::str::contains(
+            "2 file(s) scanned, 1 with different results",
+        ));
+}
@@ -0,0 +1,37 @@
+use assert_cmd::{cargo_bin, Command};
+use assert_fs::prelude::*;
+use assert_fs::TempDir;
+use predicates::prelude::*;
+
+#[test]
+fn coverage() {
+    let temp_dir = TempDir::new().unwrap();
+    let rules_file = temp_dir.child("rules.yar");
+
+    rules_file
+        .write_str(
+            r#"
+            rule foo { strings: $a = "foo" $b = "bar" condition: $a or $b }
+            rule baz { strings: $a = "baz" condition: $a }
+            "#,
+        )
+        .unwrap();
+
+    temp_dir.child("corpus/1.bin").write_str("foo").unwrap();
+    temp_dir.child("corpus/2.bin").write_str("foofoo").unwrap();
+    temp_dir.child("corpus/3.bin").write_str("qux").unwrap();
+
+    Command::new(cargo_bin!("yr"))
+        .arg("coverage")
+        .arg(rules_file.path())
+        .arg(temp_dir.child("corpus").path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("3 file(s)"))
+        .stdout(predicate::str::contains("foo: 2 file(s)"))
+        .stdout(predicate::str::contains("patterns never matched: $b"))
+        .stdout(predicate::str::contains("baz: 0 file(s)"))
+        .stdout(predicate::str::contains(
+            "1 rule(s) and 2 pattern(s) never matched",
+        ));
+}
@@ -1,11 +1,10 @@
-use assert_cmd::{cargo::cargo_bin, Command};
+use assert_cmd::{cargo_bin, Command};
 use assert_fs::prelude::*;
 use assert_fs::TempDir;
 
 #[test]
 fn ast() {
     Command::new(cargo_bin!("yr"))
-        .unwrap()
         .arg("debug")
         .arg("ast")
         .arg("src/tests/testdata/foo.yar")
@@ -13,10 +12,22 @@ fn ast() {
         .success();
 }
 
+#[test]
+fn ast_json() {
+    Command::new(cargo_bin!("yr"))
+        .arg("debug")
+        .arg("ast")
+        .arg("--output-format=json")
+        .arg("src/tests/testdata/foo.yar")
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(r#""name": "foo""#))
+        .stdout(predicates::str::contains(r#""span": {"#));
+}
+
 #[test]
 fn cst() {
     Command::new(cargo_bin!("yr"))
-        .unwrap()
         .arg("debug")
         .arg("cst")
         .arg("src/tests/testdata/foo.yar")
@@ -32,7 +43,6 @@ fn wasm() {
     input_file.write_str("rule test { condition: true }").unwrap();
 
     Command::new(cargo_bin!("yr"))
-        .unwrap()
         .arg("debug")
         .arg("wasm")
         .arg(input_file.path())
@@ -43,3 +53,37 @@ fn wasm() {
         panic!("`yr debug wasm` didn't create .wasm file")
     }
 }
+
+#[test]
+fn wat() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.child("rule.yar");
+
+    input_file.write_str("rule test { condition: true }").unwrap();
+
+    Command::new(cargo_bin!("yr"))
+        .arg("debug")
+        .arg("wasm")
+        .arg("--wat")
+        .arg(input_file.path())
+        .assert()
+        .success();
+
+    let wat = std::fs::read_to_string(input_file.with_extension("wat"))
+        .expect("`yr debug wasm --wat` didn't create .wat file");
+
+    assert!(wat.contains("default.test"));
+}
+
+#[test]
+fn ir() {
+    // `yr debug ir` is a deprecated alias for `yr ir`.
+    Command::new(cargo_bin!("yr"))
+        .arg("debug")
+        .arg("ir")
+        .arg("src/tests/testdata/foo.yar")
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("RULE foo"))
+        .stderr(predicates::str::contains("deprecated"));
+}
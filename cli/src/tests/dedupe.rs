@@ -0,0 +1,98 @@
+use assert_cmd::{cargo_bin, Command};
+use assert_fs::prelude::*;
+use assert_fs::TempDir;
+use predicates::prelude::*;
+
+#[test]
+fn dedupe() {
+    let temp_dir = TempDir::new().unwrap();
+
+    temp_dir
+        .child("a.yar")
+        .write_str(
+            r#"rule foo { strings: $a = "foo" condition: $a }
+
+rule bar { strings: $a = "bar" condition: $a }"#,
+        )
+        .unwrap();
+
+    temp_dir
+        .child("b.yar")
+        .write_str(
+            r#"rule baz : tag {
+  strings:
+    $a = "foo"
+  condition:
+    $a
+}
+
+rule qux { strings: $a = "bar" condition: #a > 1 }"#,
+        )
+        .unwrap();
+
+    let a = temp_dir.child("a.yar").path().display().to_string();
+    let b = temp_dir.child("b.yar").path().display().to_string();
+
+    Command::new(cargo_bin!("yr"))
+        .arg("dedupe")
+        .arg(temp_dir.child("a.yar").path())
+        .arg(temp_dir.child("b.yar").path())
+        .assert()
+        .success()
+        .stdout(format!(
+            "exact duplicates:
+  {a}:1:6 foo
+  {b}:1:6 baz
+
+near duplicates:
+  {a}:3:6 bar
+  {b}:8:6 qux
+"
+        ));
+}
+
+#[test]
+fn dedupe_json() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.child("rules.yar");
+
+    input_file
+        .write_str(
+            r#"rule foo { condition: true and filesize > 0 }
+rule bar { condition: filesize > 0 and true }"#,
+        )
+        .unwrap();
+
+    let output = Command::new(cargo_bin!("yr"))
+        .arg("dedupe")
+        .arg("--output-format=json")
+        .arg(input_file.path())
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: serde_json::Value =
+        serde_json::from_slice(&output).expect("valid JSON output");
+
+    assert_eq!(json[0]["kind"], "exact");
+    assert_eq!(json[0]["rules"][0]["identifier"], "foo");
+    assert_eq!(json[0]["rules"][1]["identifier"], "bar");
+    assert_eq!(json[0]["rules"][1]["line"], 2);
+}
+
+#[test]
+fn dedupe_syntax_error() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.child("rules.yar");
+
+    input_file.write_str("rule foo { condition: }").unwrap();
+
+    Command::new(cargo_bin!("yr"))
+        .arg("dedupe")
+        .arg(input_file.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("error[E001]: syntax error"));
+}
@@ -0,0 +1,80 @@
+use assert_cmd::{cargo_bin, Command};
+use assert_fs::prelude::*;
+use assert_fs::TempDir;
+use predicates::prelude::*;
+
+#[test]
+fn doc_markdown() {
+    Command::new(cargo_bin!("yr"))
+        .arg("doc")
+        .arg("src/tests/testdata/foo.yar")
+        .assert()
+        .success()
+        .stdout(
+            r#"# `src/tests/testdata/foo.yar`
+
+## foo
+
+* **Tags:** `bar`, `baz`
+
+| Metadata | Value |
+|----------|-------|
+| string | foo |
+| bool | true |
+| int | 1 |
+| float | 3.14 |
+| regexp | foo |
+
+"#,
+        );
+}
+
+#[test]
+fn doc_json() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.child("rule.yar");
+
+    input_file
+        .write_str(
+            r#"import "pe"
+
+// Matches DLLs.
+private rule test { condition: pe.is_dll() }"#,
+        )
+        .unwrap();
+
+    let output = Command::new(cargo_bin!("yr"))
+        .arg("doc")
+        .arg("--output-format=json")
+        .arg(input_file.path())
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: serde_json::Value =
+        serde_json::from_slice(&output).expect("valid JSON output");
+
+    let rule = &json[0]["rules"][0];
+
+    assert_eq!(rule["identifier"], "test");
+    assert_eq!(rule["private"], true);
+    assert_eq!(rule["comments"], serde_json::json!(["Matches DLLs."]));
+    assert_eq!(rule["modules"], serde_json::json!(["pe"]));
+}
+
+#[test]
+fn doc_syntax_error() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.child("rule.yar");
+
+    input_file.write_str("rule test { condition: }").unwrap();
+
+    Command::new(cargo_bin!("yr"))
+        .arg("doc")
+        .arg(input_file.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("error[E001]: syntax error"));
+}
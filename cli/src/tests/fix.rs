@@ -44,3 +44,53 @@ rule test {
 "#,
     );
 }
+
+#[test]
+fn fix_legacy() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.child("test.yar");
+
+    let original = r#"rule test {
+  strings:
+    $a = "foo\101\\bar"
+  condition:
+    $a at entrypoint and filesize
+}
+"#;
+
+    input_file.write_str(original).unwrap();
+
+    // With --dry-run the file is not modified, but a diff is printed.
+    Command::new(cargo_bin!("yr"))
+        .arg("fix")
+        .arg("legacy")
+        .arg("--dry-run")
+        .arg(input_file.path())
+        .assert()
+        .stdout(predicate::str::contains(
+            r#"-    $a at entrypoint and filesize
++    $a at pe.entry_point and filesize != 0"#,
+        ))
+        .success();
+
+    input_file.assert(original);
+
+    Command::new(cargo_bin!("yr"))
+        .arg("fix")
+        .arg("legacy")
+        .arg(input_file.path())
+        .assert()
+        .success();
+
+    input_file.assert(
+        r#"import "pe"
+
+rule test {
+  strings:
+    $a = "foo\x41\\bar"
+  condition:
+    $a at pe.entry_point and filesize != 0
+}
+"#,
+    );
+}
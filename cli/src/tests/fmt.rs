@@ -39,6 +39,47 @@ fn fmt_check_shows_filenames() {
         .code(1);
 }
 
+#[test]
+fn fmt_dir() {
+    let temp_dir = TempDir::new().unwrap();
+
+    temp_dir.child("a.yar").write_str("rule a { condition: true }").unwrap();
+    temp_dir.child("b.txt").write_str("rule b { condition: true }").unwrap();
+    temp_dir
+        .child("sub/c.yara")
+        .write_str("rule c { condition: true }")
+        .unwrap();
+
+    Command::new(cargo_bin!("yr"))
+        .arg("fmt")
+        .arg("--check")
+        .arg(temp_dir.path())
+        .assert()
+        .stderr(predicate::str::contains("a.yar"))
+        .stderr(predicate::str::contains("b.txt").not())
+        .stderr(predicate::str::contains("c.yara").not())
+        .code(1);
+
+    Command::new(cargo_bin!("yr"))
+        .arg("fmt")
+        .arg("--recursive")
+        .arg(temp_dir.path())
+        .assert()
+        .code(1);
+
+    temp_dir.child("sub/c.yara").assert(predicate::str::contains("\n"));
+
+    Command::new(cargo_bin!("yr"))
+        .arg("fmt")
+        .arg("--check")
+        .arg("--recursive")
+        .arg("--filter=**/*.txt")
+        .arg(temp_dir.path())
+        .assert()
+        .stderr(predicate::str::contains("b.txt"))
+        .code(1);
+}
+
 #[test]
 fn utf8_error() {
     let temp_dir = TempDir::new().unwrap();
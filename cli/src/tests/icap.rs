@@ -0,0 +1,206 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Shutdown, TcpStream};
+use std::process::{Child, Stdio};
+
+use assert_cmd::cargo_bin;
+use assert_fs::prelude::*;
+use assert_fs::TempDir;
+
+/// Kills the ICAP server when the test finishes, even if it fails.
+struct Server(Child);
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+/// Starts the ICAP server with the given arguments, and returns the server
+/// together with the address where it is listening.
+fn start_server(args: &[&str]) -> (Server, String) {
+    let mut child = std::process::Command::new(cargo_bin!("yr"))
+        .arg("icap")
+        .arg("--address=127.0.0.1:0")
+        .args(args)
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let mut line = String::new();
+    BufReader::new(child.stdout.take().unwrap()).read_line(&mut line).unwrap();
+
+    let addr = line.trim().strip_prefix("listening on ").unwrap().to_string();
+
+    (Server(child), addr)
+}
+
+/// Sends a request to the server and returns the response.
+fn send(addr: &str, request: &[u8]) -> String {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    stream.write_all(request).unwrap();
+    stream.shutdown(Shutdown::Write).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+/// Builds a RESPMOD request where the encapsulated HTTP response has the
+/// given body.
+fn respmod(body: &str, allow_204: bool) -> Vec<u8> {
+    let http_headers = "HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\n\r\n";
+    format!(
+        "RESPMOD icap://127.0.0.1/yara-x ICAP/1.0\r\n\
+         Host: 127.0.0.1\r\n\
+         {}\
+         Encapsulated: res-hdr=0, res-body={}\r\n\
+         \r\n\
+         {}{:x}\r\n{}\r\n0\r\n\r\n",
+        if allow_204 { "Allow: 204\r\n" } else { "" },
+        http_headers.len(),
+        http_headers,
+        body.len(),
+        body,
+    )
+    .into_bytes()
+}
+
+fn rules(temp_dir: &TempDir) -> String {
+    let rules = temp_dir.child("rules.yar");
+    rules
+        .write_str(
+            r#"
+            rule malware : malware { strings: $a = "evil" condition: $a }
+            rule severe { meta: severity = 8 strings: $a = "bad" condition: $a }
+            rule mild { meta: severity = 2 strings: $a = "meh" condition: $a }
+            "#,
+        )
+        .unwrap();
+    rules.path().to_str().unwrap().to_string()
+}
+
+#[test]
+fn icap_options() {
+    let temp_dir = TempDir::new().unwrap();
+    let (_server, addr) = start_server(&[&rules(&temp_dir)]);
+
+    let response = send(
+        &addr,
+        b"OPTIONS icap://127.0.0.1/yara-x ICAP/1.0\r\nHost: 127.0.0.1\r\n\r\n",
+    );
+
+    assert!(response.starts_with("ICAP/1.0 200 OK\r\n"));
+    assert!(response.contains("Methods: REQMOD, RESPMOD\r\n"));
+    assert!(response.contains("Allow: 204\r\n"));
+}
+
+#[test]
+fn icap_respmod() {
+    let temp_dir = TempDir::new().unwrap();
+    let (_server, addr) = start_server(&[&rules(&temp_dir)]);
+
+    // Without `--block-tag` and `--block-severity`, any match blocks.
+    let response = send(&addr, &respmod("something meh", true));
+    assert!(response.starts_with("ICAP/1.0 200 OK\r\n"));
+    assert!(response
+        .contains("X-Infection-Found: Type=0; Resolution=2; Threat=mild;"));
+    assert!(response.contains("HTTP/1.1 403 Forbidden\r\n"));
+    assert!(response.contains("matched the following rules: mild"));
+
+    // Clean content.
+    let response = send(&addr, &respmod("all good", true));
+    assert!(response.starts_with("ICAP/1.0 204 No Content\r\n"));
+
+    // When the client doesn't accept 204, the original message is returned.
+    let response = send(&addr, &respmod("all good", false));
+    assert!(response.starts_with("ICAP/1.0 200 OK\r\n"));
+    assert!(response.contains("HTTP/1.1 200 OK\r\n"));
+    assert!(response.contains("8\r\nall good\r\n0\r\n\r\n"));
+
+    // A preview that contains the whole body can be answered with 204,
+    // even if the client doesn't send `Allow: 204`.
+    let response = send(
+        &addr,
+        b"RESPMOD icap://127.0.0.1/yara-x ICAP/1.0\r\n\
+          Preview: 8\r\n\
+          Encapsulated: res-body=0\r\n\r\n\
+          8\r\nall good\r\n0; ieof\r\n\r\n",
+    );
+    assert!(response.starts_with("ICAP/1.0 204 No Content\r\n"));
+
+    // After `100 Continue` the preview phase ends, and without `Allow: 204`
+    // the original message is returned.
+    let response = send(
+        &addr,
+        b"RESPMOD icap://127.0.0.1/yara-x ICAP/1.0\r\n\
+          Preview: 4\r\n\
+          Encapsulated: res-body=0\r\n\r\n\
+          4\r\nall \r\n0\r\n\r\n\
+          4\r\ngood\r\n0\r\n\r\n",
+    );
+    assert!(response.starts_with("ICAP/1.0 100 Continue\r\n\r\n"));
+    assert!(response.contains("ICAP/1.0 200 OK\r\n"));
+    assert!(response.contains("8\r\nall good\r\n0\r\n\r\n"));
+
+    // Unsupported methods.
+    let response =
+        send(&addr, b"FOO icap://127.0.0.1/yara-x ICAP/1.0\r\n\r\n");
+    assert!(response.starts_with("ICAP/1.0 405 Method Not Allowed\r\n"));
+}
+
+#[test]
+fn icap_block_thresholds() {
+    let temp_dir = TempDir::new().unwrap();
+    let block_page = temp_dir.child("block.html");
+    block_page.write_str("Blocked by {{rules}}").unwrap();
+
+    let (_server, addr) = start_server(&[
+        "--block-tag=malware",
+        "--block-severity=5",
+        "--block-page",
+        block_page.path().to_str().unwrap(),
+        &rules(&temp_dir),
+    ]);
+
+    let response = send(&addr, &respmod("evil and bad and meh", true));
+    assert!(response.contains("HTTP/1.1 403 Forbidden\r\n"));
+    assert!(response.contains("Blocked by malware, severe"));
+    assert!(response.contains("X-Violations-Found: 2\r\n"));
+
+    // Rules with low severity and without the tag don't block.
+    let response = send(&addr, &respmod("meh", true));
+    assert!(response.starts_with("ICAP/1.0 204 No Content\r\n"));
+}
+
+#[test]
+fn icap_limits() {
+    let temp_dir = TempDir::new().unwrap();
+    let (_server, addr) = start_server(&[
+        "--max-body-size=8",
+        "--read-timeout=1",
+        &rules(&temp_dir),
+    ]);
+
+    // Bodies that fit in `--max-body-size` are scanned as usual.
+    let response = send(&addr, &respmod("all good", true));
+    assert!(response.starts_with("ICAP/1.0 204 No Content\r\n"));
+
+    let response = send(&addr, &respmod("too large", true));
+    assert!(response.starts_with("ICAP/1.0 400 Bad Request\r\n"));
+
+    // Chunk sizes that overflow are rejected too.
+    let response = send(
+        &addr,
+        b"RESPMOD icap://127.0.0.1/yara-x ICAP/1.0\r\n\
+          Encapsulated: res-body=0\r\n\r\n\
+          1\r\na\r\nffffffffffffffff\r\n",
+    );
+    assert!(response.starts_with("ICAP/1.0 400 Bad Request\r\n"));
+
+    // Idle connections are closed after `--read-timeout`, without a
+    // response.
+    let mut stream = TcpStream::connect(&addr).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    assert!(response.is_empty());
+}
@@ -0,0 +1,21 @@
+use assert_cmd::{cargo_bin, Command};
+use assert_fs::prelude::*;
+use assert_fs::TempDir;
+
+#[test]
+fn ir() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.child("rule.yar");
+
+    input_file
+        .write_str("rule test { condition: filesize < 2 * 1024 }")
+        .unwrap();
+
+    Command::new(cargo_bin!("yr"))
+        .arg("ir")
+        .arg(input_file.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("RULE test"))
+        .stdout(predicates::str::contains("CONST integer(2048)"));
+}
@@ -0,0 +1,17 @@
+use assert_cmd::{cargo_bin, Command};
+use predicates::prelude::*;
+
+#[test]
+fn initialize() {
+    let request = r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{"capabilities":{}}}"#;
+
+    Command::new(cargo_bin!("yr"))
+        .arg("lsp")
+        .write_stdin(format!(
+            "Content-Length: {}\r\n\r\n{}",
+            request.len(),
+            request
+        ))
+        .assert()
+        .stdout(predicate::str::contains(r#""capabilities":"#));
+}
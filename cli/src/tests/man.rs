@@ -0,0 +1,44 @@
+use assert_cmd::{cargo_bin, Command};
+use assert_fs::prelude::*;
+use assert_fs::TempDir;
+use predicates::prelude::*;
+
+#[test]
+fn man() {
+    Command::new(cargo_bin!("yr"))
+        .arg("man")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(".TH yr 1"));
+
+    Command::new(cargo_bin!("yr"))
+        .arg("man")
+        .arg("scan")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(".TH yr-scan 1"))
+        .stdout(predicate::str::contains("fail\\-on\\-match"));
+
+    Command::new(cargo_bin!("yr"))
+        .arg("man")
+        .arg("foo")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown command 'foo'"));
+}
+
+#[test]
+fn man_output_dir() {
+    let temp_dir = TempDir::new().unwrap();
+
+    Command::new(cargo_bin!("yr"))
+        .arg("man")
+        .arg("--output-dir")
+        .arg(temp_dir.path())
+        .assert()
+        .success();
+
+    temp_dir.child("yr.1").assert(predicate::path::exists());
+    temp_dir.child("yr-scan.1").assert(predicate::path::exists());
+    temp_dir.child("yr-fix-legacy.1").assert(predicate::path::exists());
+}
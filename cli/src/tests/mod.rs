@@ -1,6 +1,19 @@
+mod bench;
 mod check;
+#[cfg(feature = "compat")]
+mod compat;
+mod coverage;
 #[cfg(feature = "debug-cmd")]
 mod debug;
+mod dedupe;
+mod doc;
 mod fix;
 mod fmt;
+mod icap;
+mod ir;
+#[cfg(feature = "lsp")]
+mod lsp;
+mod man;
 mod scan;
+mod test;
+mod worker;
@@ -1,8 +1,10 @@
+use std::fs::File;
+use std::io::Write;
+
 use assert_cmd::{cargo_bin, Command};
 use assert_fs::prelude::*;
 use assert_fs::TempDir;
 use predicates::prelude::*;
-use serde_json;
 
 #[test]
 fn always_true() {
@@ -106,6 +108,347 @@ fn disable_warning_config_file() {
         ));
 }
 
+#[test]
+fn max_matches_per_pattern() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.child("input");
+
+    input_file.write_str("foofoofoo").unwrap();
+
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--print-strings")
+        .arg("--max-matches-per-pattern=2")
+        .arg("src/tests/testdata/foo.yar")
+        .arg(input_file.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("0x3:3:$foo: foo"))
+        .stdout(predicate::str::contains("0x6:3:$foo: foo").not())
+        .stderr(predicate::str::contains(
+            "pattern `$foo` in rule `foo` reached the maximum number of matches",
+        ))
+        .stderr(predicate::str::contains(
+            "pattern `$foo_hex` in rule `foo` reached the maximum number of matches",
+        ));
+}
+
+#[test]
+fn max_loop_iterations() {
+    let temp_dir = TempDir::new().unwrap();
+    let rules_file = temp_dir.child("rules.yar");
+    let input_file = temp_dir.child("input");
+
+    rules_file
+        .write_str(
+            "rule loop { condition: for any i in (0..filesize-1) : ( uint8(i) == 0x41 ) }",
+        )
+        .unwrap();
+
+    input_file.write_str("...A").unwrap();
+
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg(rules_file.path())
+        .arg(input_file.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("loop "));
+
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--max-loop-iterations=2")
+        .arg(rules_file.path())
+        .arg(input_file.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("loop ").not())
+        .stderr(predicate::str::contains(
+            "a loop in rule `loop` reached the maximum number of iterations",
+        ));
+}
+
+#[test]
+fn files_from() {
+    let temp_dir = TempDir::new().unwrap();
+    let list = temp_dir.child("list");
+
+    list.write_str("src/tests/testdata/dummy.file\n\n").unwrap();
+
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--files-from")
+        .arg(list.path())
+        .arg("src/tests/testdata/foo.yar")
+        .assert()
+        .success()
+        .stdout("foo src/tests/testdata/dummy.file\n");
+
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--print-namespace")
+        .arg("--files-from=-")
+        .arg("src/tests/testdata/foo.yar")
+        .arg("bar:src/tests/testdata/foo.yar")
+        .write_stdin("src/tests/testdata/dummy.file\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "default:foo src/tests/testdata/dummy.file",
+        ))
+        .stdout(predicate::str::contains(
+            "bar:foo src/tests/testdata/dummy.file",
+        ));
+}
+
+#[test]
+fn scan_archives() {
+    let temp_dir = TempDir::new().unwrap();
+    let zip_file = temp_dir.child("archive.zip");
+    let gzip_file = temp_dir.child("file.txt.gz");
+
+    let mut zip = zip::ZipWriter::new(File::create(zip_file.path()).unwrap());
+    let options = zip::write::SimpleFileOptions::default();
+
+    zip.start_file("dir/a.txt", options).unwrap();
+    zip.write_all(b"foo").unwrap();
+    zip.start_file("b.txt", options).unwrap();
+    zip.write_all(b"bar").unwrap();
+    zip.finish().unwrap();
+
+    let mut gzip = flate2::write::GzEncoder::new(
+        File::create(gzip_file.path()).unwrap(),
+        flate2::Compression::default(),
+    );
+
+    gzip.write_all(b"foo").unwrap();
+    gzip.finish().unwrap();
+
+    let zip_path = zip_file.path().display().to_string();
+    let gzip_path = gzip_file.path().display().to_string();
+
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--scan-archives")
+        .arg("src/tests/testdata/foo.yar")
+        .arg(zip_file.path())
+        .assert()
+        .success()
+        .stdout(format!("foo {zip_path}!dir/a.txt\n"));
+
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--scan-archives")
+        .arg("src/tests/testdata/foo.yar")
+        .arg(gzip_file.path())
+        .assert()
+        .success()
+        .stdout(format!("foo {gzip_path}!file.txt\n"));
+
+    // Without --scan-archives the files inside the archives are not scanned.
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("src/tests/testdata/foo.yar")
+        .arg(zip_file.path())
+        .assert()
+        .success()
+        .stdout("");
+
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--scan-archives")
+        .arg("--max-archive-size=2")
+        .arg("src/tests/testdata/foo.yar")
+        .arg(zip_file.path())
+        .assert()
+        .success()
+        .stdout("")
+        .stderr(predicate::str::contains(format!(
+            "{zip_path}!dir/a.txt: maximum extracted size (2 bytes) reached"
+        )));
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn pid() {
+    let temp_dir = TempDir::new().unwrap();
+    let rules_file = temp_dir.child("rules.yar");
+
+    rules_file
+        .write_str(r#"rule sleep { strings: $a = "1234.5678" condition: $a }"#)
+        .unwrap();
+
+    // The argument passed to `sleep` is in the memory of the process.
+    let mut child =
+        std::process::Command::new("sleep").arg("1234.5678").spawn().unwrap();
+
+    let assert = Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--pid")
+        .arg(child.id().to_string())
+        .arg(rules_file.path())
+        .assert();
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+
+    assert.success().stdout(predicate::str::starts_with(format!(
+        "sleep pid:{}:",
+        child.id()
+    )));
+
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--pid")
+        .arg(u32::MAX.to_string())
+        .arg(rules_file.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(format!(
+            "process {} not found",
+            u32::MAX
+        )));
+}
+
+#[test]
+fn watch() {
+    // In watch mode `yr` runs until interrupted, the results of the initial
+    // scan are printed as new matches.
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--watch")
+        .arg("src/tests/testdata/foo.yar")
+        .arg("src/tests/testdata/dummy.file")
+        .timeout(std::time::Duration::from_secs(3))
+        .assert()
+        .interrupted()
+        .stdout("+ foo src/tests/testdata/dummy.file\n");
+}
+
+#[test]
+fn truncate_larger() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.child("input");
+
+    input_file.write_str("barbarfoo").unwrap();
+
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--truncate-larger=6")
+        .arg("src/tests/testdata/foo.yar")
+        .arg(input_file.path())
+        .assert()
+        .success()
+        .stdout("");
+
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--truncate-larger=9")
+        .arg("src/tests/testdata/foo.yar")
+        .arg(input_file.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("foo "));
+}
+
+#[test]
+fn stats() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.child("input");
+
+    input_file.write_str("foofoofoo").unwrap();
+
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--stats")
+        .arg("src/tests/testdata/foo.yar")
+        .arg(input_file.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("foo "))
+        // `foo.yar` has two patterns, each one matching three times.
+        .stderr(predicate::str::contains("MB/s, 6 pattern match(es)"))
+        .stderr(predicate::str::contains("1 file(s), 9 byte(s)"));
+}
+
+#[test]
+fn fail_on_match() {
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--fail-on-match")
+        .arg("src/tests/testdata/foo.yar")
+        .arg("src/tests/testdata/dummy.file")
+        .assert()
+        .code(1)
+        .stdout("foo src/tests/testdata/dummy.file\n");
+
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--fail-on-match")
+        .arg("--negate")
+        .arg("src/tests/testdata/foo.yar")
+        .arg("src/tests/testdata/dummy.file")
+        .assert()
+        .code(0);
+
+    // Errors while scanning individual files don't change the exit code.
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--fail-on-match")
+        .arg("src/tests/testdata/foo.yar")
+        .arg("src/tests/testdata/non-existing.file")
+        .assert()
+        .code(0);
+
+    // Errors that prevent the scan from starting use exit code 2, so that
+    // they can be told apart from matches.
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--fail-on-match")
+        .arg("src/tests/testdata/non-existing.yar")
+        .arg("src/tests/testdata/dummy.file")
+        .assert()
+        .code(2);
+}
+
+#[test]
+fn fail_on_error() {
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--fail-on-error")
+        .arg("src/tests/testdata/foo.yar")
+        .arg("src/tests/testdata/dummy.file")
+        .assert()
+        .code(0);
+
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--fail-on-error")
+        .arg("src/tests/testdata/foo.yar")
+        .arg("src/tests/testdata/non-existing.file")
+        .assert()
+        .code(2);
+
+    let temp_dir = TempDir::new().unwrap();
+    let list = temp_dir.child("list");
+
+    list.write_str(
+        "src/tests/testdata/dummy.file\nsrc/tests/testdata/non-existing.file\n",
+    )
+    .unwrap();
+
+    // Errors take precedence over matches.
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--fail-on-error")
+        .arg("--fail-on-match")
+        .arg("--files-from")
+        .arg(list.path())
+        .arg("src/tests/testdata/foo.yar")
+        .assert()
+        .code(2);
+}
+
 #[test]
 fn print_strings() {
     Command::new(cargo_bin!("yr"))
@@ -140,6 +483,123 @@ fn print_strings_n() {
         );
 }
 
+#[test]
+fn print_strings_private() {
+    let temp_dir = TempDir::new().unwrap();
+    let rules_file = temp_dir.child("rules.yar");
+    let input_file = temp_dir.child("input");
+
+    rules_file
+        .write_str(
+            r#"
+            rule test {
+              strings:
+                $a = "foo" private
+                $b = "bar"
+              condition:
+                $a and $b
+            }
+            "#,
+        )
+        .unwrap();
+
+    input_file.write_str("foobar").unwrap();
+
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--print-strings")
+        .arg(rules_file.path())
+        .arg(input_file.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("0x3:3:$b: bar"))
+        .stdout(predicate::str::contains("$a").not());
+
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--output-format=ndjson")
+        .arg("--print-strings")
+        .arg(rules_file.path())
+        .arg(input_file.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(r#""identifier":"$b""#))
+        .stdout(predicate::str::contains("$a").not());
+}
+
+#[test]
+fn quiet() {
+    let temp_dir = TempDir::new().unwrap();
+
+    temp_dir.child("foo").write_str("foo").unwrap();
+    temp_dir.child("bar").write_str("bar").unwrap();
+
+    // `always_true` and `foo` match the first file, but its path is
+    // printed only once. The second file matches only `always_true`.
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--quiet")
+        .arg("src/tests/testdata/true.yar")
+        .arg("src/tests/testdata/foo.yar")
+        .arg(temp_dir.child("foo").path())
+        .assert()
+        .success()
+        .stdout(format!("{}\n", temp_dir.child("foo").path().display()));
+
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--quiet")
+        .arg("src/tests/testdata/foo.yar")
+        .arg(temp_dir.child("bar").path())
+        .assert()
+        .success()
+        .stdout("");
+
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--quiet")
+        .arg("--output-format=json")
+        .arg("src/tests/testdata/foo.yar")
+        .arg(temp_dir.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "can't use '--quiet' with '--output-format=json'",
+        ));
+}
+
+#[test]
+fn group_by_rule() {
+    let temp_dir = TempDir::new().unwrap();
+
+    temp_dir.child("1").write_str("foo").unwrap();
+    temp_dir.child("2").write_str("bar").unwrap();
+    temp_dir.child("3").write_str("foo").unwrap();
+
+    let path = |name: &str| temp_dir.child(name).path().display().to_string();
+
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--group-by=rule")
+        .arg("src/tests/testdata/foo.yar")
+        .arg(temp_dir.path())
+        .assert()
+        .success()
+        .stdout(format!("foo\n  {}\n  {}\n", path("1"), path("3")));
+}
+
+#[test]
+fn no_color() {
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--no-color")
+        .arg("src/tests/testdata/foo.yar")
+        .arg("src/tests/testdata/dummy.file")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("\x1b[").not());
+}
+
 #[test]
 fn print_namespace() {
     Command::new(cargo_bin!("yr"))
@@ -203,6 +663,201 @@ fn format_ndjson() {
         .stdout("{\"path\":\"src/tests/testdata/dummy.file\",\"rules\":[{\"identifier\":\"foo\"}]}\n");
 }
 
+#[test]
+fn format_json_schema() {
+    // The schemas are published in the documentation site. Use
+    // `UPDATE_GOLDENFILES=1 cargo test` for updating them after changing
+    // the JSON output.
+    let mut mint = goldenfile::Mint::new("../site/static/schemas");
+
+    for (file_name, ndjson) in [
+        ("scan-results.schema.json", false),
+        ("scan-results-ndjson.schema.json", true),
+    ] {
+        let mut file = mint.new_goldenfile(file_name).unwrap();
+        serde_json::to_writer_pretty(
+            &mut file,
+            &crate::commands::json_schema(ndjson),
+        )
+        .unwrap();
+        writeln!(file).unwrap();
+    }
+
+    let output = Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--output-format=json")
+        .arg("src/tests/testdata/foo.yar")
+        .arg("src/tests/testdata/dummy.file")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+    assert_eq!(json["schema_version"], "1.0");
+}
+
+#[test]
+fn format_proto() {
+    let output = Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--output-format=proto")
+        .arg("src/tests/testdata/foo.yar")
+        .arg("src/tests/testdata/dummy.file")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let mut input = protobuf::CodedInputStream::from_bytes(&output);
+    let results: yara_x::proto::ScanResults = input.read_message().unwrap();
+
+    assert!(input.eof().unwrap());
+    assert_eq!(results.path(), "src/tests/testdata/dummy.file");
+    assert_eq!(results.matching_rules.len(), 1);
+
+    let rule = &results.matching_rules[0];
+
+    assert_eq!(rule.identifier(), "foo");
+    assert_eq!(rule.tags, ["bar", "baz"]);
+    assert_eq!(rule.patterns[0].identifier(), "$foo");
+    assert_eq!(rule.patterns[0].matches[0].offset(), 0);
+    assert_eq!(rule.patterns[0].matches[0].length(), 3);
+}
+
+#[test]
+fn format_proto_multiple_files() {
+    let temp_dir = TempDir::new().unwrap();
+
+    // Even files match the rule, odd files don't.
+    for i in 0..10 {
+        temp_dir
+            .child(format!("file{i}"))
+            .write_str(if i % 2 == 0 { "foo" } else { "bar" })
+            .unwrap();
+    }
+
+    let output = Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--output-format=proto")
+        .arg("src/tests/testdata/foo.yar")
+        .arg(temp_dir.path())
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    // The stream must contain one message per file and nothing else.
+    let mut input = protobuf::CodedInputStream::from_bytes(&output);
+    let mut files = Vec::new();
+
+    while !input.eof().unwrap() {
+        let results: yara_x::proto::ScanResults =
+            input.read_message().unwrap();
+        let i: usize = results
+            .path()
+            .rsplit_once("file")
+            .and_then(|(_, i)| i.parse().ok())
+            .unwrap();
+        assert_eq!(results.matching_rules.len(), (i % 2 == 0) as usize);
+        files.push(i);
+    }
+
+    files.sort();
+    assert_eq!(files, (0..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn format_stix() {
+    let output = Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--output-format=stix")
+        .arg("src/tests/testdata/foo.yar")
+        .arg("src/tests/testdata/dummy.file")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let bundle: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let objects = bundle["objects"].as_array().unwrap();
+
+    assert_eq!(bundle["type"], "bundle");
+    assert_eq!(
+        objects
+            .iter()
+            .map(|o| o["type"].as_str().unwrap())
+            .collect::<Vec<_>>(),
+        ["file", "observed-data", "indicator", "sighting"]
+    );
+
+    let (file, indicator, sighting) = (&objects[0], &objects[2], &objects[3]);
+
+    assert_eq!(
+        file["hashes"]["SHA-256"],
+        "2c26b46b68ffc68ff99b453c1d30413413422d706483bfa0f98a5e886266e7ae"
+    );
+    assert_eq!(indicator["name"], "foo");
+    assert_eq!(
+        indicator["pattern"],
+        "[file:hashes.'SHA-256' = '2c26b46b68ffc68ff99b453c1d30413413422d706483bfa0f98a5e886266e7ae']"
+    );
+    assert_eq!(indicator["labels"], serde_json::json!(["bar", "baz"]));
+    assert_eq!(sighting["sighting_of_ref"], indicator["id"]);
+    assert_eq!(sighting["observed_data_refs"][0], objects[1]["id"]);
+    assert_eq!(
+        sighting["x_yara_matches"][0],
+        serde_json::json!({"identifier": "$foo", "offset": 0})
+    );
+}
+
+#[test]
+fn format_misp() {
+    let output = Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--output-format=misp")
+        .arg("src/tests/testdata/foo.yar")
+        .arg("src/tests/testdata/dummy.file")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let event: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let attributes = event["Event"]["Attribute"].as_array().unwrap();
+
+    assert_eq!(
+        attributes
+            .iter()
+            .map(|a| a["type"].as_str().unwrap())
+            .collect::<Vec<_>>(),
+        ["filename", "md5", "sha1", "sha256"]
+    );
+    assert_eq!(attributes[1]["value"], "acbd18db4cc2f85cedef654fccc4a4d8");
+    assert_eq!(
+        attributes[1]["comment"],
+        "YARA rule default:foo matched at $foo@0x0, $foo_hex@0x0"
+    );
+
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("--output-format=misp")
+        .arg("--count")
+        .arg("src/tests/testdata/foo.yar")
+        .arg("src/tests/testdata/dummy.file")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "can't use '--count' with '--output-format=misp'",
+        ));
+}
+
 #[test]
 fn define() {
     Command::new(cargo_bin!("yr"))
@@ -217,6 +872,54 @@ fn define() {
         .stdout("test src/tests/testdata/dummy.file\n");
 }
 
+#[test]
+fn define_types() {
+    let temp_dir = TempDir::new().unwrap();
+    let rules_file = temp_dir.child("rules.yar");
+
+    rules_file
+        .write_str(
+            r#"rule test {
+              condition:
+                filename == "foo.exe" and quoted == "true" and
+                str == "1234" and float == 1.0 and int == 1 and bool
+            }"#,
+        )
+        .unwrap();
+
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("-d")
+        .arg("filename=foo.exe")
+        .arg("-d")
+        .arg(r#"quoted="true""#)
+        .arg("-d")
+        .arg("str:str=1234")
+        .arg("-d")
+        .arg("float:float=1")
+        .arg("-d")
+        .arg("int=1")
+        .arg("-d")
+        .arg("bool:bool=true")
+        .arg(rules_file.path())
+        .arg("src/tests/testdata/dummy.file")
+        .assert()
+        .success()
+        .stdout("test src/tests/testdata/dummy.file\n");
+
+    Command::new(cargo_bin!("yr"))
+        .arg("scan")
+        .arg("-d")
+        .arg("int:int=foo")
+        .arg(rules_file.path())
+        .arg("src/tests/testdata/dummy.file")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "`foo` is not a valid value of type `int`",
+        ));
+}
+
 #[test]
 fn console() {
     Command::new(cargo_bin!("yr"))
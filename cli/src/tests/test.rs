@@ -0,0 +1,81 @@
+use assert_cmd::{cargo_bin, Command};
+use assert_fs::prelude::*;
+use assert_fs::TempDir;
+
+#[test]
+fn test() {
+    let temp_dir = TempDir::new().unwrap();
+
+    temp_dir
+        .child("rules.yar")
+        .write_str(
+            r#"
+            rule foo { strings: $a = "foo" condition: $a }
+            rule bar { strings: $a = "bar" condition: $a }
+            "#,
+        )
+        .unwrap();
+
+    temp_dir.child("samples/foo.bin").write_str("xxfooxx").unwrap();
+    temp_dir.child("samples/bar.bin").write_str("xxbarxx").unwrap();
+
+    let spec = temp_dir.child("tests.toml");
+
+    spec.write_str(
+        r#"
+        rules = ["rules.yar"]
+
+        [[test]]
+        rule = "foo"
+        positive = ["samples/foo.bin", { hex = "66 6f 6f" }]
+        negative = ["samples/bar.bin"]
+        "#,
+    )
+    .unwrap();
+
+    Command::new(cargo_bin!("yr"))
+        .arg("test")
+        .arg(spec.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("PASS"))
+        .stdout(predicates::str::contains("foo (3 samples)"));
+
+    spec.write_str(
+        r#"
+        rules = ["rules.yar"]
+
+        [[test]]
+        rule = "bar"
+        positive = ["samples/foo.bin"]
+        negative = ["samples/bar.bin"]
+        "#,
+    )
+    .unwrap();
+
+    Command::new(cargo_bin!("yr"))
+        .arg("test")
+        .arg(spec.path())
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("FAIL"))
+        .stdout(predicates::str::contains("expected match:"))
+        .stdout(predicates::str::contains("unexpected match:"));
+
+    spec.write_str(
+        r#"
+        rules = ["rules.yar"]
+
+        [[test]]
+        rule = "baz"
+        "#,
+    )
+    .unwrap();
+
+    Command::new(cargo_bin!("yr"))
+        .arg("test")
+        .arg(spec.path())
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("rule `baz` not found"));
+}
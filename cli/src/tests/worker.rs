@@ -0,0 +1,40 @@
+use assert_cmd::{cargo_bin, Command};
+use predicates::prelude::*;
+
+#[test]
+fn worker() {
+    Command::new(cargo_bin!("yr"))
+        .arg("worker")
+        .arg("src/tests/testdata/foo.yar")
+        .write_stdin(
+            "src/tests/testdata/dummy.file\r\n\
+             \n\
+             src/tests/testdata/true.yar\n",
+        )
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            r#"{"path":"src/tests/testdata/dummy.file","rules":[{"namespace":"default","identifier":"foo"}]}"#,
+        ))
+        .stdout(predicate::str::contains(
+            r#"{"path":"src/tests/testdata/true.yar","rules":[]}"#,
+        ));
+}
+
+#[test]
+fn worker_errors() {
+    Command::new(cargo_bin!("yr"))
+        .arg("worker")
+        .arg("--threads=1")
+        .arg("src/tests/testdata/foo.yar")
+        .write_stdin("src/tests/testdata/missing.file\n src/tests/testdata/dummy.file\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            r#"{"path":"src/tests/testdata/missing.file","error":"can not open"#,
+        ))
+        // Spaces are part of the path.
+        .stdout(predicate::str::contains(
+            r#"{"path":" src/tests/testdata/dummy.file","error":"can not open"#,
+        ));
+}
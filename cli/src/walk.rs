@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 use std::fs::{File, Metadata};
-use std::io::BufRead;
+use std::io::{BufRead, Write};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -45,6 +45,15 @@ pub struct Walker<'a> {
     /// An optional function that allows filtering the walked files based on
     /// their metadata.
     metadata_filter: Option<Box<dyn Fn(Metadata) -> bool + Send + 'a>>,
+    /// Files larger than this size in bytes are ignored.
+    max_file_size: Option<u64>,
+    /// Files smaller than this size in bytes are ignored.
+    min_file_size: Option<u64>,
+    /// If true, symbolic links are followed while walking directories.
+    follow_symlinks: bool,
+    /// If true, hidden files and directories are ignored while walking
+    /// directories.
+    skip_hidden: bool,
 }
 
 impl<'a> Walker<'a> {
@@ -52,26 +61,31 @@ impl<'a> Walker<'a> {
     ///
     /// `path` can also point to an individual file instead of a directory.
     pub fn path(path: &'a Path) -> Self {
-        Self {
-            path,
-            filters: Vec::new(),
-            file_list: false,
-            max_depth: None,
-            metadata_filter: None,
-        }
+        Self::new(path, false)
     }
 
     /// Creates a [`Walker`] that walks the files listed in a text file
     /// containing one path per line.
     ///
     /// `path` points to the text file that contains the paths to be walked.
+    /// If `path` is `-`, the paths are read from `stdin`. Empty lines are
+    /// ignored. Each file is processed as soon as its path is read, without
+    /// waiting for the whole list to be read.
     pub fn file_list(path: &'a Path) -> Self {
+        Self::new(path, true)
+    }
+
+    fn new(path: &'a Path, file_list: bool) -> Self {
         Self {
             path,
             filters: Vec::new(),
-            file_list: true,
+            file_list,
             max_depth: None,
             metadata_filter: None,
+            max_file_size: None,
+            min_file_size: None,
+            follow_symlinks: false,
+            skip_hidden: false,
         }
     }
 
@@ -118,6 +132,42 @@ impl<'a> Walker<'a> {
         self
     }
 
+    /// Ignores files larger than the given size in bytes.
+    pub fn max_file_size(&mut self, size: u64) -> &mut Self {
+        self.max_file_size = Some(size);
+        self
+    }
+
+    /// Ignores files smaller than the given size in bytes.
+    pub fn min_file_size(&mut self, size: u64) -> &mut Self {
+        self.min_file_size = Some(size);
+        self
+    }
+
+    /// Specifies whether symbolic links are followed while walking
+    /// directories.
+    ///
+    /// When symbolic links are not followed, which is the default, symbolic
+    /// links found while walking a directory are ignored. Symbolic links
+    /// passed directly to [`Walker::path`] or listed in the file passed to
+    /// [`Walker::file_list`] are always followed.
+    pub fn follow_symlinks(&mut self, yes: bool) -> &mut Self {
+        self.follow_symlinks = yes;
+        self
+    }
+
+    /// Specifies whether hidden files and directories are ignored while
+    /// walking directories.
+    ///
+    /// Hidden files and directories are those whose names start with a dot.
+    /// They are not ignored by default. Files passed directly to
+    /// [`Walker::path`] or listed in the file passed to [`Walker::file_list`]
+    /// are never ignored because of their names.
+    pub fn skip_hidden(&mut self, yes: bool) -> &mut Self {
+        self.skip_hidden = yes;
+        self
+    }
+
     /// Sets a maximum depth while traversing the directory tree.
     ///
     /// When the maximum depth is 0 only the files that reside in the given
@@ -138,6 +188,12 @@ impl<'a> Walker<'a> {
         F: FnMut(&Path) -> anyhow::Result<()>,
         E: FnMut(anyhow::Error) -> anyhow::Result<()>,
     {
+        // When reading the list of files from `stdin` there's no path to
+        // check.
+        if self.file_list && self.path == Path::new("-") {
+            return self.walk_file_list(io::stdin().lock(), f, e);
+        }
+
         let metadata =
             match self.path.metadata().with_context(|| {
                 format!("can't open `{}`", self.path.display())
@@ -152,7 +208,8 @@ impl<'a> Walker<'a> {
             if !metadata.is_file() {
                 bail!("`{}` is not a file", self.path.display())
             }
-            self.walk_file_list(f, e)
+            let file = File::open(self.path)?;
+            self.walk_file_list(io::BufReader::new(file), f, e)
         } else {
             if metadata.is_file() {
                 if self.pass_metadata_filter(metadata) {
@@ -166,15 +223,23 @@ impl<'a> Walker<'a> {
         }
     }
 
-    fn walk_file_list<F, E>(self, mut f: F, mut e: E) -> anyhow::Result<()>
+    fn walk_file_list<R, F, E>(
+        self,
+        list: R,
+        mut f: F,
+        mut e: E,
+    ) -> anyhow::Result<()>
     where
+        R: BufRead,
         F: FnMut(&Path) -> anyhow::Result<()>,
         E: FnMut(anyhow::Error) -> anyhow::Result<()>,
     {
-        let file = File::open(self.path)?;
-
-        for line in io::BufReader::new(file).lines() {
-            let path = PathBuf::from(line?);
+        for line in list.lines() {
+            let line = line?;
+            if line.is_empty() {
+                continue;
+            }
+            let path = PathBuf::from(line);
             let metadata = match path
                 .metadata()
                 .with_context(|| format!("can't open `{}`", path.display()))
@@ -217,16 +282,22 @@ impl<'a> Walker<'a> {
             self.path
         };
 
-        let mut builder = if self.filters.is_empty() {
-            globwalk::GlobWalkerBuilder::from_patterns(path, &["**"])
+        let mut patterns = if self.filters.is_empty() {
+            vec!["**"]
         } else {
-            globwalk::GlobWalkerBuilder::from_patterns(
-                path,
-                self.filters.iter().as_ref(),
-            )
+            self.filters.iter().map(|f| f.as_str()).collect()
         };
 
-        builder = builder.file_type(FileType::FILE);
+        // Patterns starting with `!` exclude the matching paths. When the
+        // excluded path is a directory, the whole directory is skipped.
+        if self.skip_hidden {
+            patterns.push("!.*");
+        }
+
+        let mut builder =
+            globwalk::GlobWalkerBuilder::from_patterns(path, &patterns)
+                .file_type(FileType::FILE)
+                .follow_links(self.follow_symlinks);
 
         if let Some(max_depth) = self.max_depth {
             builder = builder.max_depth(max_depth + 1);
@@ -260,6 +331,12 @@ impl<'a> Walker<'a> {
     }
 
     fn pass_metadata_filter(&self, metadata: Metadata) -> bool {
+        if self.max_file_size.is_some_and(|max| metadata.len() > max) {
+            return false;
+        }
+        if self.min_file_size.is_some_and(|min| metadata.len() < min) {
+            return false;
+        }
         self.metadata_filter.as_ref().map(|f| f(metadata)).unwrap_or(true)
     }
 }
@@ -336,8 +413,9 @@ impl<'a> Walker<'a> {
 ///     }
 /// ).unwrap();
 /// ```
-pub(crate) struct ParWalker<'a> {
+pub struct ParWalker<'a> {
     num_threads: Option<u8>,
+    binary_output: bool,
     walker: Walker<'a>,
 }
 
@@ -346,7 +424,11 @@ impl<'a> ParWalker<'a> {
     ///
     /// `path` can also point to an individual file instead of a directory.
     pub fn path(path: &'a Path) -> Self {
-        Self { walker: Walker::path(path), num_threads: None }
+        Self {
+            walker: Walker::path(path),
+            num_threads: None,
+            binary_output: false,
+        }
     }
 
     /// Creates a [`ParWalker`] that walks the files listed in a text file
@@ -354,7 +436,11 @@ impl<'a> ParWalker<'a> {
     ///
     /// `path` points to the text file that contains the paths to be walked.
     pub fn file_list(path: &'a Path) -> Self {
-        Self { walker: Walker::file_list(path), num_threads: None }
+        Self {
+            walker: Walker::file_list(path),
+            num_threads: None,
+            binary_output: false,
+        }
     }
 
     /// Sets the number of threads used.
@@ -366,6 +452,16 @@ impl<'a> ParWalker<'a> {
         self
     }
 
+    /// Specifies whether the data sent with [`Message::Data`] is binary.
+    ///
+    /// In that case the console is not used, and [`Message::Info`] messages
+    /// are printed to `stderr` instead of `stdout`, so that `stdout` only
+    /// contains the binary data.
+    pub fn binary_output(&mut self, yes: bool) -> &mut Self {
+        self.binary_output = yes;
+        self
+    }
+
     /// Sets a maximum depth while traversing the directory tree.
     ///
     /// When the maximum depth is 0 only the files that reside in the given
@@ -384,6 +480,9 @@ impl<'a> ParWalker<'a> {
         self
     }
 
+    /// Sets a filter based in file metadata.
+    ///
+    /// See [`Walker::metadata_filter`] for details.
     pub fn metadata_filter(
         &mut self,
         filter: impl Fn(Metadata) -> bool + Send + 'a,
@@ -392,6 +491,36 @@ impl<'a> ParWalker<'a> {
         self
     }
 
+    /// Ignores files larger than the given size in bytes.
+    pub fn max_file_size(&mut self, size: u64) -> &mut Self {
+        self.walker.max_file_size(size);
+        self
+    }
+
+    /// Ignores files smaller than the given size in bytes.
+    pub fn min_file_size(&mut self, size: u64) -> &mut Self {
+        self.walker.min_file_size(size);
+        self
+    }
+
+    /// Specifies whether symbolic links are followed while walking
+    /// directories.
+    ///
+    /// See [`Walker::follow_symlinks`] for details.
+    pub fn follow_symlinks(&mut self, yes: bool) -> &mut Self {
+        self.walker.follow_symlinks(yes);
+        self
+    }
+
+    /// Specifies whether hidden files and directories are ignored while
+    /// walking directories.
+    ///
+    /// See [`Walker::skip_hidden`] for details.
+    pub fn skip_hidden(&mut self, yes: bool) -> &mut Self {
+        self.walker.skip_hidden(yes);
+        self
+    }
+
     /// Runs `action` on every file.
     ///
     /// See [`ParWalker`] for details.
@@ -497,7 +626,9 @@ impl<'a> ParWalker<'a> {
                 }
             }));
 
-            let mut console = if cfg!(feature = "logging") {
+            let binary_output = self.binary_output;
+
+            let mut console = if cfg!(feature = "logging") || binary_output {
                 None
             } else {
                 // `console` will be `None` if either stdout or stderr is not a tty
@@ -517,6 +648,7 @@ impl<'a> ParWalker<'a> {
                 Instant::now(),
                 msg_recv,
                 console.as_mut(),
+                binary_output,
                 state.clone(),
             );
 
@@ -533,6 +665,7 @@ impl<'a> ParWalker<'a> {
                         Instant::now(),
                         msg_recv,
                         console.as_mut(),
+                        binary_output,
                         state.clone(),
                     );
 
@@ -562,6 +695,7 @@ fn output_messages<S>(
     last_render: Instant,
     msg_recv: crossbeam::channel::Receiver<Message>,
     console: Option<&mut SuperConsole>,
+    binary_output: bool,
     state: Arc<S>,
 ) where
     S: Component,
@@ -576,10 +710,17 @@ fn output_messages<S>(
                     console.emit(Lines::from_colored_multiline_string(
                         s.as_str(),
                     ));
+                } else if binary_output {
+                    eprintln!("{s}")
                 } else {
                     println!("{s}")
                 }
             }
+            Ok(Message::Data(data)) => {
+                let mut stdout = std::io::stdout().lock();
+                let _ = stdout.write_all(&data);
+                let _ = stdout.flush();
+            }
             Ok(Message::Error(s)) => {
                 if let Some(console) = console.as_mut() {
                     console.emit(Lines::from_colored_multiline_string(
@@ -607,8 +748,97 @@ fn output_messages<S>(
     }
 }
 
+/// Messages sent to the console through the output channel passed to the
+/// functions invoked by [`ParWalker::walk`].
 pub enum Message {
+    /// A message printed to `stdout`, or to `stderr` when the output is
+    /// binary (see [`ParWalker::binary_output`]).
     Info(String),
+    /// Raw data written to `stdout` as is.
+    Data(Vec<u8>),
+    /// A message printed to `stderr`.
     Error(String),
+    /// Stops printing messages.
     Abort,
 }
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use assert_fs::prelude::*;
+    use assert_fs::TempDir;
+
+    use super::Walker;
+
+    /// Walks `dir` with the given walker and returns the paths of the
+    /// walked files relative to `dir`, sorted alphabetically.
+    fn walked_files(dir: &TempDir, walker: Walker) -> Vec<PathBuf> {
+        let mut files = Vec::new();
+        walker
+            .walk(
+                |path| {
+                    files.push(path.strip_prefix(dir.path())?.to_path_buf());
+                    Ok(())
+                },
+                Err,
+            )
+            .unwrap();
+        files.sort();
+        files
+    }
+
+    #[test]
+    fn walk() {
+        let dir = TempDir::new().unwrap();
+
+        dir.child("a.yar").write_str("12345").unwrap();
+        dir.child("b.txt").write_str("1").unwrap();
+        dir.child(".c.yar").write_str("").unwrap();
+        dir.child(".hidden/d.yar").write_str("").unwrap();
+        dir.child("sub/e.yar").write_str("").unwrap();
+
+        let mut w = Walker::path(dir.path());
+        w.filter("**/*.yar");
+
+        assert_eq!(
+            walked_files(&dir, w),
+            [".c.yar", ".hidden/d.yar", "a.yar", "sub/e.yar"]
+                .map(PathBuf::from)
+        );
+
+        let mut w = Walker::path(dir.path());
+        w.filter("**/*.yar").skip_hidden(true);
+
+        assert_eq!(
+            walked_files(&dir, w),
+            ["a.yar", "sub/e.yar"].map(PathBuf::from)
+        );
+
+        let mut w = Walker::path(dir.path());
+        w.max_depth(0).min_file_size(1).max_file_size(4);
+
+        assert_eq!(walked_files(&dir, w), [PathBuf::from("b.txt")]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn walk_symlinks() {
+        let dir = TempDir::new().unwrap();
+
+        dir.child("a.yar").write_str("").unwrap();
+        dir.child("b.yar").symlink_to_file(dir.child("a.yar")).unwrap();
+
+        let w = Walker::path(dir.path());
+
+        assert_eq!(walked_files(&dir, w), [PathBuf::from("a.yar")]);
+
+        let mut w = Walker::path(dir.path());
+        w.follow_symlinks(true);
+
+        assert_eq!(
+            walked_files(&dir, w),
+            ["a.yar", "b.yar"].map(PathBuf::from)
+        );
+    }
+}
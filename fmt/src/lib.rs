@@ -15,6 +15,7 @@ let output = File::create("formatted.yar").unwrap();
 Formatter::new().format(input, output).unwrap();
 ```
 */
+use std::borrow::Cow;
 use std::io;
 use std::io::Cursor;
 
@@ -28,6 +29,7 @@ use yara_x_parser::{Parser, Span};
 use crate::align::Align;
 use crate::format_hex_patterns::FormatHexPatterns;
 use crate::indentation::AddIndentation;
+use crate::sort::SortDefinitions;
 use crate::tokens::categories::*;
 use crate::tokens::*;
 use crate::trailing_spaces::RemoveTrailingSpaces;
@@ -38,6 +40,7 @@ mod comments;
 mod format_hex_patterns;
 mod indentation;
 mod processor;
+mod sort;
 mod tokens;
 mod trailing_spaces;
 
@@ -79,6 +82,9 @@ pub struct Formatter {
     newline_before_curly_brace: bool,
     empty_line_before_section_header: bool,
     empty_line_after_section_header: bool,
+    sort_metadata: bool,
+    sort_patterns: bool,
+    normalize_hex_patterns: bool,
     tab_size: usize,
     indentation: Indentation,
 }
@@ -101,6 +107,9 @@ impl Formatter {
             newline_before_curly_brace: false,
             empty_line_before_section_header: true,
             empty_line_after_section_header: false,
+            sort_metadata: false,
+            sort_patterns: false,
+            normalize_hex_patterns: false,
             tab_size: 4,
             indentation: Indentation::Spaces(2),
         }
@@ -366,11 +375,76 @@ impl Formatter {
         self
     }
 
+    /// Specify if metadata definitions must be sorted alphabetically by key.
+    ///
+    /// If true, the meta block will be converted from this...
+    ///
+    /// ```text
+    /// rule test {
+    ///   meta:
+    ///     version = 2
+    ///     author = "foo"
+    ///   condition:
+    ///     ...
+    /// }
+    /// ```
+    ///
+    /// ... to this ...
+    ///
+    /// ```text
+    /// rule test {
+    ///   meta:
+    ///     author = "foo"
+    ///     version = 2
+    ///   condition:
+    ///     ...
+    /// }
+    /// ```
+    ///
+    /// Comments in the same line as a definition, or in the lines that
+    /// precede it, are moved together with the definition. Definitions with
+    /// the same key keep their relative order.
+    ///
+    /// The default value is `false`.
+    pub fn sort_metadata(mut self, yes: bool) -> Self {
+        self.sort_metadata = yes;
+        self
+    }
+
+    /// Specify if pattern definitions must be sorted alphabetically by
+    /// identifier.
+    ///
+    /// This is similar to [`Formatter::sort_metadata`], but for the patterns
+    /// in the `strings:` section. Anonymous patterns (`$`) are placed before
+    /// named ones.
+    ///
+    /// The default value is `false`.
+    pub fn sort_patterns(mut self, yes: bool) -> Self {
+        self.sort_patterns = yes;
+        self
+    }
+
+    /// Specify if bytes in hex patterns must be written in uppercase.
+    ///
+    /// If true, `{ 4d 5a ?a ~0f }` becomes `{ 4D 5A ?A ~0F }`. Notice that
+    /// the spacing in hex patterns is normalized regardless of this option.
+    ///
+    /// The default value is `false`.
+    pub fn normalize_hex_patterns(mut self, yes: bool) -> Self {
+        self.normalize_hex_patterns = yes;
+        self
+    }
+
     /// Reads YARA source code from `input` and write it into `output` after
     /// formatting.
     ///
     /// Returns `true` if the output differs from the input.
     ///
+    /// Formatting is idempotent: formatting the output produced by this
+    /// function with the same options leaves it unchanged. Comments at the
+    /// end of a line, including those after section headers, pattern and
+    /// metadata definitions, stay in that same line.
+    ///
     /// This function will fail if it can't read from the input, write to the
     /// output, or when the input contains invalid UTF-8 characters.
     pub fn format<R, W>(
@@ -387,7 +461,15 @@ impl Formatter {
 
         input.read_to_end(&mut in_buf).map_err(Error::ReadError)?;
 
-        let cst_stream = CSTStream::from(Parser::new(in_buf.as_slice()));
+        // The original input is kept unmodified, as it will be compared with
+        // the output later.
+        let src = if self.normalize_hex_patterns {
+            Cow::Owned(Self::uppercase_hex_bytes(in_buf.clone()))
+        } else {
+            Cow::Borrowed(in_buf.as_slice())
+        };
+
+        let cst_stream = CSTStream::from(Parser::new(src.as_ref()));
 
         // Inspect the CST stream looking for events indicating the presence of
         // invalid UTF-8 sequences.
@@ -398,7 +480,7 @@ impl Formatter {
             }
         });
 
-        let tokens = Tokens::new(in_buf.as_slice(), events);
+        let tokens = Tokens::new(src.as_ref(), events);
         let mut out_buf = Cursor::new(Vec::new());
 
         self.format_impl(tokens)
@@ -419,6 +501,26 @@ impl Formatter {
 
 // Private API for formatter.
 impl Formatter {
+    /// Converts the bytes in hex patterns to uppercase.
+    fn uppercase_hex_bytes(mut src: Vec<u8>) -> Vec<u8> {
+        let spans: Vec<Span> = CSTStream::from(Parser::new(src.as_slice()))
+            .filter_map(|evt| match evt {
+                Event::Token { kind: SyntaxKind::HEX_BYTE, span } => {
+                    Some(span)
+                }
+                _ => Option::None,
+            })
+            .collect();
+
+        // Changing the case of ASCII characters doesn't change the length
+        // of the source code, so spans remain valid.
+        for span in spans {
+            src[span.range()].make_ascii_uppercase();
+        }
+
+        src
+    }
+
     fn format_impl<'a, I>(&self, input: I) -> impl TokenStream<'a> + 'a
     where
         I: TokenStream<'a> + 'a,
@@ -548,6 +650,31 @@ impl Formatter {
             |token| token.is(*NEWLINE),
         );
 
+        // Sort metadata and pattern definitions if required. At this point
+        // comments have been already moved inside the definition they belong
+        // to, so they are sorted together with the definition.
+        let tokens: Box<dyn Iterator<Item = Token<'a>>> = if self.sort_metadata
+        {
+            Box::new(SortDefinitions::new(
+                tokens,
+                SyntaxKind::META_BLK,
+                SyntaxKind::META_DEF,
+            ))
+        } else {
+            Box::new(tokens)
+        };
+
+        let tokens: Box<dyn Iterator<Item = Token<'a>>> = if self.sort_patterns
+        {
+            Box::new(SortDefinitions::new(
+                tokens,
+                SyntaxKind::PATTERNS_BLK,
+                SyntaxKind::PATTERN_DEF,
+            ))
+        } else {
+            Box::new(tokens)
+        };
+
         // Remove newlines in multiple cases.
         let tokens = processor::Processor::new(tokens)
             // Remove all newlines at the beginning of the file. When the
@@ -739,11 +866,20 @@ impl Formatter {
                                 | Keyword(b"condition")
                         )
                         && ctx.token(-1).is(*NEWLINE)
-                        && ctx.token(-2).eq(&LBRACE)
+                        && (ctx.token(-2).eq(&LBRACE)
+                            || matches!(ctx.token(-2), TailComment(_))
+                                && ctx.token(-3).eq(&LBRACE))
                 },
                 processor::actions::drop,
             );
 
+        // A tail comment in the same line as a section header stays in that
+        // line, like in:
+        //
+        //   strings:  // some comment
+        //
+        // In such cases the newline and empty line rules below are applied
+        // after the comment instead of after the colon.
         let tokens = if self.empty_line_after_section_header {
             Box::new(
                 processor::Processor::new(tokens)
@@ -757,18 +893,13 @@ impl Formatter {
                                         | Keyword(b"condition")
                                 )
                                 && ctx.token(1).is_not(*NEWLINE)
+                                && !matches!(ctx.token(1), TailComment(_))
                         },
                         processor::actions::emptyline,
                     )
                     .add_rule(
                         |ctx| {
-                            ctx.token(-1).eq(&COLON)
-                                && matches!(
-                                    ctx.token(-2),
-                                    Keyword(b"meta")
-                                        | Keyword(b"strings")
-                                        | Keyword(b"condition")
-                                )
+                            Self::after_section_header(ctx)
                                 && ctx.token(1).is(*NEWLINE)
                                 && ctx.token(2).is_not(*NEWLINE)
                         },
@@ -788,18 +919,13 @@ impl Formatter {
                                         | Keyword(b"condition")
                                 )
                                 && ctx.token(1).is_not(*NEWLINE)
+                                && !matches!(ctx.token(1), TailComment(_))
                         },
                         processor::actions::newline,
                     )
                     .add_rule(
                         |ctx| {
-                            ctx.token(-1).eq(&COLON)
-                                && matches!(
-                                    ctx.token(-2),
-                                    Keyword(b"meta")
-                                        | Keyword(b"strings")
-                                        | Keyword(b"condition")
-                                )
+                            Self::after_section_header(ctx)
                                 && ctx.token(1).is(*NEWLINE)
                                 && ctx.token(2).is(*NEWLINE)
                         },
@@ -923,6 +1049,27 @@ impl Formatter {
         tokens
     }
 
+    /// Returns true if the last tokens in the output are a section header
+    /// (e.g: `strings:`), optionally followed by a tail comment.
+    fn after_section_header<'a, T>(ctx: &processor::Context<'a, T>) -> bool
+    where
+        T: TokenStream<'a>,
+    {
+        let is_header = |keyword: &Token, colon: &Token| {
+            colon.eq(&COLON)
+                && matches!(
+                    keyword,
+                    Keyword(b"meta")
+                        | Keyword(b"strings")
+                        | Keyword(b"condition")
+                )
+        };
+
+        is_header(ctx.token(-2), ctx.token(-1))
+            || matches!(ctx.token(-1), TailComment(_))
+                && is_header(ctx.token(-3), ctx.token(-2))
+    }
+
     /// Indents the sections (meta, strings, condition) of a rule one level up.
     /// For example, for this input:
     ///
@@ -0,0 +1,117 @@
+use std::collections::VecDeque;
+
+use yara_x_parser::cst::SyntaxKind;
+
+use crate::tokens::{Token, TokenStream};
+
+/// Pipeline that sorts the definitions inside a block by identifier.
+///
+/// This is used for sorting metadata definitions in the `meta:` section
+/// (`block` is [`SyntaxKind::META_BLK`] and `def` is
+/// [`SyntaxKind::META_DEF`]), and pattern definitions in the `strings:`
+/// section (`block` is [`SyntaxKind::PATTERNS_BLK`] and `def` is
+/// [`SyntaxKind::PATTERN_DEF`]).
+///
+/// Each definition is moved together with the comments that belong to it,
+/// which are the ones enclosed between the `Begin(def)` and `End(def)`
+/// tokens. Any token outside the definitions, like the newlines that
+/// separate them, stays in its original position. The sort is stable,
+/// definitions with the same identifier keep their relative order.
+pub(crate) struct SortDefinitions<'a, T>
+where
+    T: TokenStream<'a>,
+{
+    input: T,
+    block: SyntaxKind,
+    def: SyntaxKind,
+    output_buffer: VecDeque<Token<'a>>,
+}
+
+impl<'a, T> SortDefinitions<'a, T>
+where
+    T: TokenStream<'a>,
+{
+    pub fn new(input: T, block: SyntaxKind, def: SyntaxKind) -> Self {
+        Self { input, block, def, output_buffer: VecDeque::new() }
+    }
+
+    /// Reads the tokens in a block, up to its `End(block)` token, and puts
+    /// them in the output buffer with the definitions sorted.
+    fn sort_block(&mut self) {
+        // Tokens in the block, with each definition replaced by `None`.
+        let mut skeleton = Vec::new();
+        let mut defs: Vec<Vec<Token<'a>>> = Vec::new();
+        let mut current_def: Option<Vec<Token<'a>>> = None;
+
+        for token in self.input.by_ref() {
+            match (&mut current_def, &token) {
+                (None, Token::End(kind)) if *kind == self.block => {
+                    skeleton.push(Some(token));
+                    break;
+                }
+                (None, Token::Begin(kind)) if *kind == self.def => {
+                    current_def = Some(vec![token]);
+                }
+                (None, _) => skeleton.push(Some(token)),
+                (Some(def), Token::End(kind)) if *kind == self.def => {
+                    def.push(token);
+                    defs.push(current_def.take().unwrap());
+                    skeleton.push(None);
+                }
+                (Some(def), _) => def.push(token),
+            }
+        }
+
+        // If the input ends in the middle of a definition, put the pending
+        // tokens back without sorting them.
+        if let Some(def) = current_def {
+            defs.push(def);
+            skeleton.push(None);
+        }
+
+        defs.sort_by(|a, b| Self::identifier(a).cmp(Self::identifier(b)));
+
+        let mut defs = defs.into_iter();
+
+        for token in skeleton {
+            match token {
+                Some(token) => self.output_buffer.push_back(token),
+                None => self.output_buffer.extend(defs.next().unwrap()),
+            }
+        }
+    }
+
+    /// Returns the identifier of a definition, which is the first identifier
+    /// token in it.
+    fn identifier<'b>(def: &'b [Token<'a>]) -> &'b [u8] {
+        def.iter()
+            .find_map(|token| match token {
+                Token::Identifier(ident) => Some(*ident),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl<'a, T> Iterator for SortDefinitions<'a, T>
+where
+    T: TokenStream<'a>,
+{
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(next) = self.output_buffer.pop_front() {
+            return Some(next);
+        }
+
+        let next = self.input.next()?;
+
+        if next == Token::Begin(self.block) {
+            self.output_buffer.push_back(next);
+            self.sort_block();
+            self.output_buffer.pop_front()
+        } else {
+            Some(next)
+        }
+    }
+}
@@ -133,6 +133,26 @@ fn format_config_options() {
             "align_rule.unformatted",
             "align_patterns_false.formatted",
         ),
+        (
+            Formatter::new().empty_line_after_section_header(true),
+            "comments_rule.unformatted",
+            "comments_empty_line_after_section_header_true.formatted",
+        ),
+        (
+            Formatter::new().sort_metadata(true),
+            "sort_rule.unformatted",
+            "sort_metadata_true.formatted",
+        ),
+        (
+            Formatter::new().sort_patterns(true),
+            "sort_rule.unformatted",
+            "sort_patterns_true.formatted",
+        ),
+        (
+            Formatter::new().normalize_hex_patterns(true),
+            "sort_rule.unformatted",
+            "normalize_hex_patterns_true.formatted",
+        ),
     ];
 
     let base = PathBuf::from("src/testdata/config_tests/");
@@ -154,3 +174,44 @@ fn format_config_options() {
         );
     }
 }
+
+#[test]
+fn idempotency() {
+    let files: Vec<_> = globwalk::glob("src/testdata/**/*.formatted")
+        .unwrap()
+        .flatten()
+        .map(|entry| entry.into_path())
+        .collect();
+
+    files.into_par_iter().for_each(|path| {
+        let input = fs::read_to_string(&path).expect("error reading file");
+        let mut output = Vec::new();
+
+        // Files in config_tests were formatted with non-default options, so
+        // formatting them with the default ones may produce changes. But in
+        // all cases formatting the output again must not change it.
+        let formatter = Formatter::new();
+        formatter
+            .format(input.as_bytes(), &mut output)
+            .expect("format failed");
+
+        let mut output2 = Vec::new();
+        formatter
+            .format(output.as_slice(), &mut output2)
+            .expect("format failed");
+
+        assert_eq!(
+            str::from_utf8(&output).unwrap(),
+            str::from_utf8(&output2).unwrap(),
+            "formatting {path:?} is not idempotent"
+        );
+
+        if path.starts_with("src/testdata/default_tests") {
+            assert_eq!(
+                input,
+                str::from_utf8(&output).unwrap(),
+                "formatting {path:?} produced changes"
+            );
+        }
+    });
+}
@@ -40,6 +40,7 @@ fn generate_module_files(proto_files: Vec<FileDescriptorProto>) {
                 module_options.rust_module,
                 module_options.cargo_feature,
                 module_options.root_message.unwrap(),
+                module_options.main_fn_in_scan_thread.unwrap_or(false),
             ));
         }
     }
@@ -72,7 +73,8 @@ fn generate_module_files(proto_files: Vec<FileDescriptorProto>) {
     // defines a YARA module. Each entry looks like:
     //
     //  #[cfg(feature = "foo_module")]
-    //  add_module!(modules, "foo", foo, Some(foo::__main__ as MainFn));
+    //  add_module!(modules, "foo", foo, "foo.Foo", Some("foo"),
+    //      Some(foo::__main__ as MainFn), false);
     //
     let mut add_modules_rs =
         File::create("src/modules/add_modules.rs").unwrap();
@@ -97,6 +99,7 @@ fn generate_module_files(proto_files: Vec<FileDescriptorProto>) {
         let rust_mod = m.2;
         let cargo_feature = m.3;
         let root_message = m.4;
+        let main_fn_in_scan_thread = m.5;
 
         // If the YARA module has an associated Rust module, this module must
         // have a function named "main". If the YARA module doesn't have an
@@ -133,7 +136,7 @@ mod {rust_mod};"#,
             add_modules_rs,
             r#"
 {cfg_feature}
-add_module!(modules, "{name}", {proto_mod}, "{root_message}", {rust_mod_name}, {main_fn});"#,
+add_module!(modules, "{name}", {proto_mod}, "{root_message}", {rust_mod_name}, {main_fn}, {main_fn_in_scan_thread});"#,
         )
             .unwrap();
     }
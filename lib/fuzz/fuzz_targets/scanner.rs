@@ -0,0 +1,43 @@
+#![no_main]
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use libfuzzer_sys::fuzz_target;
+
+// Imports every module, so that all the module parsers are invoked with the
+// fuzzed data, and uses patterns of every kind.
+static RULES: LazyLock<yara_x::Rules> = LazyLock::new(|| {
+    let mut compiler = yara_x::Compiler::new();
+
+    for module in yara_x::mods::module_names() {
+        compiler.add_source(format!(r#"import "{module}""#).as_str()).unwrap();
+    }
+
+    compiler
+        .add_source(
+            r#"
+            rule test {
+              strings:
+                $a = "MZ"
+                $b = { 50 45 00 00 [0-16] ?? 0? }
+                $c = /[a-z]{4,}\d+/ nocase wide ascii
+                $d = "This program" xor
+                $e = "kernel32" base64
+              condition:
+                any of them
+            }
+            "#,
+        )
+        .unwrap();
+
+    compiler.build()
+});
+
+fuzz_target!(|data: &[u8]| {
+    let mut scanner = yara_x::Scanner::new(&RULES);
+    // Bound the resources used by each input, slow inputs are reported
+    // by the fuzzer as timeouts, not as crashes.
+    scanner.set_timeout(Duration::from_secs(10));
+    scanner.max_matches_per_pattern(1000);
+    let _ = scanner.scan(data);
+});
@@ -0,0 +1,356 @@
+/*! Compares the results produced by YARA-X with the ones produced by libyara.
+
+This module is useful while migrating from YARA to YARA-X, as it allows
+checking that a set of rules produces the same results with both
+implementations. The same rules are compiled with both libyara and YARA-X,
+and then the same data is scanned with both, any difference in the matching
+rules or in the matches found for each pattern is reported as a
+[`Divergence`].
+
+# Example
+
+```rust
+# use yara_x::compat::Harness;
+let harness = Harness::new(r#"
+    rule test {
+      strings:
+        $a = "foo"
+      condition:
+        $a
+    }
+"#).unwrap();
+
+assert!(harness.compare(b"foobar").unwrap().is_empty());
+```
+*/
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+use std::time::Duration;
+
+use thiserror::Error;
+
+use crate::errors::CompileError;
+use crate::{Compiler, Rules, ScanError, Scanner};
+
+/// Error returned by [`Harness`].
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum CompatError {
+    /// The rules could not be compiled by YARA-X.
+    #[error("YARA-X failed to compile the rules: {0}")]
+    YaraXCompile(#[from] CompileError),
+    /// The rules could not be compiled by libyara.
+    #[error("libyara failed to compile the rules: {0}")]
+    LibyaraCompile(String),
+    /// YARA-X failed while scanning the data.
+    #[error("YARA-X failed to scan the data: {0}")]
+    YaraXScan(#[from] ScanError),
+    /// libyara failed while scanning the data.
+    #[error("libyara failed to scan the data: {0}")]
+    LibyaraScan(String),
+}
+
+/// A difference between the results produced by libyara and YARA-X.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Divergence {
+    /// The rule matched with libyara, but not with YARA-X.
+    OnlyLibyara {
+        /// Rule name, qualified with its namespace.
+        rule: String,
+    },
+    /// The rule matched with YARA-X, but not with libyara.
+    OnlyYaraX {
+        /// Rule name, qualified with its namespace.
+        rule: String,
+    },
+    /// The rule matched with both, but the matches found for some
+    /// pattern are different.
+    Matches {
+        /// Rule name, qualified with its namespace.
+        rule: String,
+        /// Pattern identifier (e.g. `$a`).
+        pattern: String,
+        /// Matches found by libyara.
+        libyara: Vec<Range<usize>>,
+        /// Matches found by YARA-X.
+        yara_x: Vec<Range<usize>>,
+    },
+}
+
+impl Display for Divergence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Divergence::OnlyLibyara { rule } => {
+                write!(f, "`{rule}` matched with libyara only")
+            }
+            Divergence::OnlyYaraX { rule } => {
+                write!(f, "`{rule}` matched with YARA-X only")
+            }
+            Divergence::Matches { rule, pattern, libyara, yara_x } => {
+                write!(
+                    f,
+                    "`{rule}` has different matches for `{pattern}`: \
+                     libyara {libyara:?}, YARA-X {yara_x:?}"
+                )
+            }
+        }
+    }
+}
+
+/// Matches found for each pattern in a matching rule. Patterns are
+/// identified by their identifier and their position among the patterns
+/// with the same identifier, as anonymous patterns all share the `$`
+/// identifier.
+type PatternMatches = BTreeMap<(String, usize), Vec<Range<usize>>>;
+
+/// Runs the same rules with libyara and YARA-X and compares the results.
+pub struct Harness {
+    yara_x: Rules,
+    libyara: yara::Rules,
+    timeout: Option<Duration>,
+}
+
+impl Harness {
+    /// Compiles the given YARA source code with both libyara and YARA-X.
+    ///
+    /// YARA-X is configured with relaxed regular expression syntax, so that
+    /// it accepts the same regular expressions that libyara accepts.
+    pub fn new(source: &str) -> Result<Self, CompatError> {
+        let mut compiler = Compiler::new();
+
+        compiler.relaxed_re_syntax(true);
+        compiler.add_source(source)?;
+
+        let libyara = yara::Compiler::new()
+            .map_err(yara::Error::from)
+            .and_then(|compiler| compiler.add_rules_str(source))
+            .and_then(|compiler| Ok(compiler.compile_rules()?))
+            .map_err(|err| CompatError::LibyaraCompile(err.to_string()))?;
+
+        Ok(Self { yara_x: compiler.build(), libyara, timeout: None })
+    }
+
+    /// Sets a timeout for the scans performed by both libyara and YARA-X.
+    ///
+    /// libyara's timeout has a granularity of seconds, so the timeout is
+    /// rounded up to the next second in that case.
+    pub fn set_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Scans `data` with both libyara and YARA-X and returns the differences
+    /// between their results. An empty vector means that both produced the
+    /// same results.
+    pub fn compare(
+        &self,
+        data: &[u8],
+    ) -> Result<Vec<Divergence>, CompatError> {
+        let libyara = self.scan_libyara(data)?;
+        let yara_x = self.scan_yara_x(data)?;
+
+        let mut divergences = Vec::new();
+
+        for (rule, libyara_patterns) in &libyara {
+            let Some(yara_x_patterns) = yara_x.get(rule) else {
+                divergences
+                    .push(Divergence::OnlyLibyara { rule: rule.clone() });
+                continue;
+            };
+
+            let mut keys: Vec<_> = libyara_patterns
+                .keys()
+                .chain(yara_x_patterns.keys())
+                .collect();
+
+            keys.sort();
+            keys.dedup();
+
+            for key in keys {
+                let libyara = libyara_patterns.get(key);
+                let yara_x = yara_x_patterns.get(key);
+                if libyara != yara_x {
+                    divergences.push(Divergence::Matches {
+                        rule: rule.clone(),
+                        pattern: key.0.clone(),
+                        libyara: libyara.cloned().unwrap_or_default(),
+                        yara_x: yara_x.cloned().unwrap_or_default(),
+                    });
+                }
+            }
+        }
+
+        for rule in yara_x.keys() {
+            if !libyara.contains_key(rule) {
+                divergences.push(Divergence::OnlyYaraX { rule: rule.clone() });
+            }
+        }
+
+        Ok(divergences)
+    }
+
+    fn scan_libyara(
+        &self,
+        data: &[u8],
+    ) -> Result<BTreeMap<String, PatternMatches>, CompatError> {
+        let timeout = self
+            .timeout
+            .map(|t| t.as_secs() + u64::from(t.subsec_nanos() > 0))
+            .map(|t| i32::try_from(t).unwrap_or(i32::MAX))
+            .unwrap_or(0);
+
+        let rules = self
+            .libyara
+            .scan_mem(data, timeout)
+            .map_err(|err| CompatError::LibyaraScan(err.to_string()))?;
+
+        Ok(rules
+            .into_iter()
+            .map(|rule| {
+                let patterns = index_patterns(rule.strings.iter().map(|s| {
+                    (
+                        s.identifier,
+                        s.matches
+                            .iter()
+                            .map(|m| {
+                                let start = m.base + m.offset;
+                                start..start + m.length
+                            })
+                            .collect(),
+                    )
+                }));
+                (qualified_name(rule.namespace, rule.identifier), patterns)
+            })
+            .collect())
+    }
+
+    fn scan_yara_x(
+        &self,
+        data: &[u8],
+    ) -> Result<BTreeMap<String, PatternMatches>, CompatError> {
+        let mut scanner = Scanner::new(&self.yara_x);
+
+        if let Some(timeout) = self.timeout {
+            scanner.set_timeout(timeout);
+        }
+
+        let results = scanner.scan(data)?;
+
+        Ok(results
+            .matching_rules()
+            .map(|rule| {
+                let patterns = index_patterns(rule.patterns().map(|p| {
+                    (p.identifier(), p.matches().map(|m| m.range()).collect())
+                }));
+                (qualified_name(rule.namespace(), rule.identifier()), patterns)
+            })
+            .collect())
+    }
+}
+
+/// Scans `data` with the given rules using both libyara and YARA-X, and
+/// panics if their results differ.
+///
+/// This is intended to be used in tests.
+pub fn assert_compatible(source: &str, data: &[u8]) {
+    let divergences = Harness::new(source)
+        .and_then(|harness| harness.compare(data))
+        .unwrap_or_else(|err| panic!("{err}"));
+
+    if !divergences.is_empty() {
+        panic!(
+            "libyara and YARA-X produced different results:\n{}",
+            divergences
+                .iter()
+                .map(|d| format!("  {d}"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+    }
+}
+
+fn qualified_name(namespace: &str, identifier: &str) -> String {
+    format!("{namespace}:{identifier}")
+}
+
+/// Builds a [`PatternMatches`] from a sequence of pattern identifiers and
+/// their matches, in declaration order. Matches are sorted, as libyara and
+/// YARA-X don't necessarily report them in the same order.
+fn index_patterns<'a>(
+    patterns: impl Iterator<Item = (&'a str, Vec<Range<usize>>)>,
+) -> PatternMatches {
+    let mut occurrences: BTreeMap<&str, usize> = BTreeMap::new();
+    patterns
+        .filter(|(_, matches)| !matches.is_empty())
+        .map(|(ident, mut matches)| {
+            let n = occurrences.entry(ident).or_default();
+            *n += 1;
+            matches.sort_by_key(|m| (m.start, m.end));
+            ((ident.to_string(), *n - 1), matches)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{assert_compatible, Divergence, Harness};
+
+    #[test]
+    fn compatible() {
+        assert_compatible(
+            r#"
+            rule test {
+              strings:
+                $a = "foo"
+                $ = "bar"
+                $ = "baz"
+              condition:
+                any of them
+            }"#,
+            b"foo bar foo",
+        );
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn divergences() {
+        // libyara finds matches for unbounded jumps regardless of the
+        // distance between both ends, while YARA-X limits that distance.
+        let harness = Harness::new(
+            r#"
+            rule jump {
+              strings:
+                $a = { 61 [-] 62 }
+              condition:
+                $a
+            }
+            rule jump_and_b {
+              strings:
+                $a = { 61 [-] 62 }
+                $b = "b"
+              condition:
+                $b or #a > 1
+            }"#,
+        )
+        .unwrap();
+
+        let data = [b"a".as_slice(), &[b'x'; 10000], b"b"].concat();
+
+        assert_eq!(
+            harness.compare(&data).unwrap(),
+            vec![
+                Divergence::OnlyLibyara { rule: "default:jump".to_string() },
+                Divergence::Matches {
+                    rule: "default:jump_and_b".to_string(),
+                    pattern: "$a".to_string(),
+                    libyara: vec![0..10002],
+                    yara_x: vec![],
+                },
+            ]
+        );
+
+        assert!(harness.compare(b"ab").unwrap().is_empty());
+    }
+}
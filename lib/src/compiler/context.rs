@@ -2,7 +2,7 @@ use std::mem::size_of;
 use std::rc::Rc;
 
 use itertools::Itertools;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use yara_x_parser::ast::{Ident, WithSpan};
 use yara_x_parser::Span;
@@ -11,7 +11,7 @@ use crate::compiler::errors::{CompileError, UnknownPattern};
 use crate::compiler::ir::{PatternIdx, IR};
 use crate::compiler::report::ReportBuilder;
 use crate::compiler::{ir, Warnings};
-use crate::errors::{UnknownField, UnknownIdentifier};
+use crate::errors::{CustomError, UnknownField, UnknownIdentifier};
 use crate::modules::BUILTIN_MODULES;
 use crate::symbols::{StackedSymbolTable, Symbol, SymbolLookup};
 use crate::types::Type;
@@ -47,6 +47,10 @@ pub(crate) struct CompileContext<'a, 'src> {
     /// Enabled features. See [`crate::Compiler::enable_feature`] for details.
     pub features: &'a FxHashSet<String>,
 
+    /// Symbols that can't be used in rule conditions. See
+    /// [`crate::Compiler::ban_symbol`] for details.
+    pub banned_symbols: &'a FxHashMap<String, (String, String)>,
+
     /// Stack of variables. These are local variables used during the
     /// evaluation of rule conditions, for example for storing loop variables.
     pub vars: VarStack,
@@ -54,6 +58,9 @@ pub(crate) struct CompileContext<'a, 'src> {
     /// Allow invalid escape sequences in regular expressions.
     pub relaxed_re_syntax: bool,
 
+    /// Use Unicode case folding for text patterns with `nocase`.
+    pub unicode_case_folding: bool,
+
     /// If true, a slow loop produces an error instead of a warning. A slow
     /// rule is one where the upper bound of the loop is potentially large.
     /// Like for example: `for all x in (0..filesize) : (...)`
@@ -101,6 +108,39 @@ impl<'src> CompileContext<'_, 'src> {
             })
     }
 
+    /// Returns an error if the symbol identified by `path` (e.g:
+    /// `pe.imphash`), or any of its parents (e.g: `pe`), has been banned
+    /// with [`crate::Compiler::ban_symbol`].
+    pub fn check_banned_symbol(
+        &self,
+        path: &str,
+        span: Span,
+    ) -> Result<(), CompileError> {
+        if self.banned_symbols.is_empty() {
+            return Ok(());
+        }
+
+        let prefixes = path
+            .match_indices('.')
+            .map(|(i, _)| &path[..i])
+            .chain(std::iter::once(path));
+
+        for prefix in prefixes {
+            if let Some((error_title, error_msg)) =
+                self.banned_symbols.get(prefix)
+            {
+                return Err(CustomError::build(
+                    self.report_builder,
+                    error_title.clone(),
+                    error_msg.clone(),
+                    self.report_builder.span_to_code_loc(span),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Search for an identifier in the symbol table.
     ///
     /// It first looks into the one-shot symbol table if possible, and then
@@ -0,0 +1,187 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::compiler::{atom_quality, SubPattern, SubPatternAtom};
+
+/// Atoms with this quality or higher don't increase the cost of a rule.
+/// This is roughly the quality of a 4-bytes atom with ASCII letters.
+const GOOD_ATOM_QUALITY: i32 = 80;
+
+/// Atoms with a quality lower than this are considered weak, and produce a
+/// warning. This includes atoms with two bytes or less, and four-bytes
+/// atoms with a single repeated byte, like `FF FF FF FF` or `20 20 20 20`.
+pub(in crate::compiler) const WEAK_ATOM_QUALITY: i32 = 45;
+
+/// Estimated cost of scanning data with a rule.
+///
+/// The cost is estimated by the compiler from the atoms extracted from the
+/// rule's patterns, the number of patterns that must be verified with a
+/// regular expression engine, the loops in the condition, and the calls
+/// to module functions. See [`crate::Rule::cost`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuleCost {
+    min_atom_quality: Option<i32>,
+    num_atoms: usize,
+    num_regexps: usize,
+    num_loops: usize,
+    num_unbounded_loops: usize,
+    num_func_calls: usize,
+}
+
+impl RuleCost {
+    /// Quality of the worst atom extracted from the rule's patterns, or
+    /// `None` if the rule doesn't have atoms.
+    ///
+    /// Atoms are the short sequences of bytes searched in the scanned data
+    /// for finding candidate matches for each pattern. Short and common
+    /// atoms, like `00 00`, appear very often in the scanned data, and each
+    /// occurrence must be verified. The higher the quality, the better.
+    /// A four-bytes atom formed by ASCII letters has a quality of 80.
+    pub fn min_atom_quality(&self) -> Option<i32> {
+        self.min_atom_quality
+    }
+
+    /// Number of atoms extracted from the rule's patterns.
+    pub fn num_atoms(&self) -> usize {
+        self.num_atoms
+    }
+
+    /// Number of patterns, or pieces of patterns, that must be verified
+    /// with a regular expression engine.
+    pub fn num_regexps(&self) -> usize {
+        self.num_regexps
+    }
+
+    /// Number of `for` loops in the rule's condition.
+    pub fn num_loops(&self) -> usize {
+        self.num_loops
+    }
+
+    /// Number of `for` loops iterating over a range whose bounds are not
+    /// known at compile time, like `(0..filesize)` or `(1..#a)`.
+    pub fn num_unbounded_loops(&self) -> usize {
+        self.num_unbounded_loops
+    }
+
+    /// Number of calls to functions implemented by modules.
+    pub fn num_func_calls(&self) -> usize {
+        self.num_func_calls
+    }
+
+    /// Returns a score that summarizes the cost of the rule.
+    ///
+    /// The score has no unit, and is useful only for comparing rules with
+    /// each other: the higher the score, the slower the rule. The formula
+    /// may change in future versions.
+    pub fn score(&self) -> u64 {
+        let atoms = self
+            .min_atom_quality
+            .map(|q| GOOD_ATOM_QUALITY.saturating_sub(q).max(0) as u64)
+            .unwrap_or(0);
+
+        atoms * 2
+            + self.num_regexps as u64 * 25
+            + self.num_loops as u64 * 10
+            + self.num_unbounded_loops as u64 * 100
+            + self.num_func_calls as u64 * 10
+    }
+
+    pub(in crate::compiler) fn loop_found(&mut self, unbounded: bool) {
+        self.num_loops += 1;
+        if unbounded {
+            self.num_unbounded_loops += 1;
+        }
+    }
+
+    pub(in crate::compiler) fn func_call_found(&mut self) {
+        self.num_func_calls += 1;
+    }
+
+    /// Adds the cost of a pattern used by the rule.
+    pub(in crate::compiler) fn add_pattern(&mut self, pattern: &PatternCost) {
+        self.num_atoms += pattern.num_atoms;
+        self.num_regexps += pattern.num_regexps;
+        self.min_atom_quality =
+            match (self.min_atom_quality, pattern.min_atom_quality) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            };
+    }
+}
+
+impl Display for RuleCost {
+    /// Describes the factors that contribute to the cost.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut factors = Vec::new();
+
+        if let Some(q) = self.min_atom_quality {
+            if q < GOOD_ATOM_QUALITY {
+                factors.push(format!("weak atoms (quality {q})"));
+            }
+        }
+        if self.num_regexps > 0 {
+            factors.push(format!("{} regexp(s)", self.num_regexps));
+        }
+        if self.num_unbounded_loops > 0 {
+            factors.push(format!(
+                "{} loop(s) with unknown bounds",
+                self.num_unbounded_loops
+            ));
+        }
+        if self.num_loops > self.num_unbounded_loops {
+            factors.push(format!(
+                "{} other loop(s)",
+                self.num_loops - self.num_unbounded_loops
+            ));
+        }
+        if self.num_func_calls > 0 {
+            factors.push(format!("{} function call(s)", self.num_func_calls));
+        }
+
+        write!(f, "{}", factors.join(", "))
+    }
+}
+
+/// Cost of a single pattern, computed once for each distinct pattern.
+#[derive(Clone, Default)]
+pub(in crate::compiler) struct PatternCost {
+    min_atom_quality: Option<i32>,
+    num_atoms: usize,
+    num_regexps: usize,
+}
+
+impl PatternCost {
+    /// Computes the cost of a pattern from the sub-patterns and atoms
+    /// produced for it.
+    pub fn new<'a, S, A>(sub_patterns: S, atoms: A) -> Self
+    where
+        S: IntoIterator<Item = &'a SubPattern>,
+        A: IntoIterator<Item = &'a SubPatternAtom>,
+    {
+        let mut cost = Self::default();
+
+        for atom in atoms {
+            // The quality of empty atoms is `i32::MIN`.
+            let quality = atom_quality(atom.as_slice()).max(0);
+            cost.num_atoms += 1;
+            cost.min_atom_quality = Some(
+                cost.min_atom_quality.map_or(quality, |q| q.min(quality)),
+            );
+        }
+
+        cost.num_regexps = sub_patterns
+            .into_iter()
+            .filter(|sub_pattern| {
+                matches!(
+                    sub_pattern,
+                    SubPattern::Regexp { .. }
+                        | SubPattern::RegexpChainHead { .. }
+                        | SubPattern::RegexpChainTail { .. }
+                )
+            })
+            .count();
+
+        cost
+    }
+}
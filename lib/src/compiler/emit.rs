@@ -197,6 +197,9 @@ pub(crate) struct EmitContext<'a> {
     /// Information about the rule whose condition is being emitted.
     pub current_rule: &'a RuleInfo,
 
+    /// ID of the rule whose condition is being emitted.
+    pub current_rule_id: RuleId,
+
     /// Table with all the symbols (functions, variables) used by WASM.
     pub wasm_symbols: &'a WasmSymbols,
 
@@ -692,8 +695,23 @@ fn emit_expr(
                 emit_expr(ctx, ir, obj, instr);
             }
 
-            for expr in func_call.args.iter() {
+            // For methods the first argument in the signature is the object
+            // itself, which is not included in `func_call.args`.
+            let params = func_call
+                .signature()
+                .args
+                .iter()
+                .skip(func_call.signature().method_of().is_some() as usize);
+
+            for (expr, param) in func_call.args.iter().zip(params) {
                 emit_expr(ctx, ir, *expr, instr);
+                // Integer arguments are promoted to float when the function
+                // expects a float.
+                if ir.get(*expr).ty() == Type::Integer
+                    && param.ty() == Type::Float
+                {
+                    instr.unop(UnaryOp::F64ConvertSI64);
+                }
             }
 
             if func_call.signature().result_may_be_undef() {
@@ -710,6 +728,11 @@ fn emit_expr(
         Expr::With(with) => {
             emit_with(ctx, ir, with.declarations.as_slice(), with.body, instr);
         }
+
+        Expr::BulkLookup(bulk) => {
+            emit_bulk_lookup(ctx, bulk.object, &bulk.lookups, instr);
+            emit_expr(ctx, ir, bulk.body, instr);
+        }
     }
 }
 
@@ -1756,6 +1779,12 @@ fn emit_for_in_range(
                 )
             });
 
+            // If the number of iterations is not known at compile time,
+            // limit it to the maximum allowed by the scanner.
+            if for_in.iterable.num_iterations(ir).is_none() {
+                emit_loop_limit(ctx, instr, n);
+            }
+
             // If n <= 0, exit from the loop.
             load_var(ctx, instr, n);
             instr.i64_const(0);
@@ -1846,6 +1875,8 @@ fn emit_for_in_array(
                 );
             });
 
+            emit_loop_limit(ctx, instr, n);
+
             // If n <= 0, exit from the loop.
             load_var(ctx, instr, n);
             instr.i64_const(0);
@@ -1917,6 +1948,8 @@ fn emit_for_in_map(
                     .call(ctx.function_id(wasm::export__map_len.mangled_name));
             });
 
+            emit_loop_limit(ctx, instr, n);
+
             // If n <= 0, exit from the loop.
             load_var(ctx, instr, n);
             instr.i64_const(0);
@@ -1995,6 +2028,13 @@ fn emit_for_in_expr_tuple(
                             |ctx, instr| match expressions.next() {
                                 Some(expr) => {
                                     emit_expr(ctx, ir, *expr, instr);
+                                    // In tuples that mix integers and
+                                    // floats, integers are promoted to float.
+                                    if next_item.ty() == Type::Float
+                                        && ir.get(*expr).ty() == Type::Integer
+                                    {
+                                        instr.unop(UnaryOp::F64ConvertSI64);
+                                    }
                                     true
                                 }
                                 None => false,
@@ -2017,6 +2057,43 @@ fn emit_for_in_expr_tuple(
     );
 }
 
+/// Emits the code that limits the number of iterations of a loop, stored
+/// in `n`, to the maximum set with [`crate::Scanner::max_loop_iterations`].
+///
+/// This is used in loops whose number of iterations is known only at scan
+/// time. The global `max_loop_iterations` is -1 when there's no limit, and
+/// `loop_iterations` is called only if the limit is exceeded.
+fn emit_loop_limit(
+    ctx: &mut EmitContext,
+    instr: &mut InstrSeqBuilder,
+    n: Var,
+) {
+    // Is max_loop_iterations >= 0 ?
+    instr.global_get(ctx.wasm_symbols.max_loop_iterations);
+    instr.i64_const(0);
+    instr.binop(BinaryOp::I64GeS);
+    // Is n > max_loop_iterations ?
+    load_var(ctx, instr, n);
+    instr.global_get(ctx.wasm_symbols.max_loop_iterations);
+    instr.binop(BinaryOp::I64GtS);
+    instr.binop(BinaryOp::I32And);
+    instr.if_else(
+        None,
+        |then_| {
+            set_var(ctx, then_, n, |ctx, instr| {
+                instr.i32_const(ctx.current_rule_id.into());
+                load_var(ctx, instr, n);
+                instr.call(
+                    ctx.function_id(
+                        wasm::export__loop_iterations.mangled_name,
+                    ),
+                );
+            });
+        },
+        |_| {},
+    );
+}
+
 /// Emits a `for` loop.
 ///
 /// This function allows creating different types of `for` loops by receiving
@@ -2324,6 +2401,52 @@ fn emit_with(
     emit_expr(ctx, ir, body, instr)
 }
 
+/// Emits the code that looks up multiple fields in the structure stored in
+/// `object`, storing their values in the corresponding variables.
+///
+/// For each field, the index of the variable, the number of fields in the
+/// path and the path itself are stored in WASM memory starting at offset
+/// [`LOOKUP_INDEXES_START`]. Then [`wasm::lookup_fields`] is called with
+/// the structure and the number of fields. See [`wasm::lookup_fields`] for
+/// details.
+fn emit_bulk_lookup(
+    ctx: &mut EmitContext,
+    object: Var,
+    lookups: &[(Var, Vec<i32>)],
+    instr: &mut InstrSeqBuilder,
+) {
+    let main_memory = ctx.wasm_symbols.main_memory;
+
+    let words = lookups.iter().flat_map(|(var, path)| {
+        [var.index(), path.len() as i32]
+            .into_iter()
+            .chain(path.iter().cloned())
+    });
+
+    for (i, word) in words.enumerate() {
+        let offset = (i * size_of::<i32>()) as i32;
+
+        assert!(
+            (0..LOOKUP_INDEXES_END - LOOKUP_INDEXES_START).contains(&offset)
+        );
+
+        instr.i32_const(offset);
+        instr.i32_const(word);
+        instr.store(
+            main_memory,
+            StoreKind::I32 { atomic: false },
+            MemArg {
+                align: size_of::<i32>() as u32,
+                offset: LOOKUP_INDEXES_START as u32,
+            },
+        );
+    }
+
+    load_var(ctx, instr, object);
+    instr.i32_const(lookups.len() as i32);
+    instr.call(ctx.function_id(wasm::export__lookup_fields.mangled_name));
+}
+
 /// Produces a switch statement by calling a `branch_generator` function
 /// multiple times.
 ///
@@ -63,6 +63,33 @@ pub enum SerializationError {
     /// Error occurred while deserializing WASM code.
     #[error("invalid YARA-X compiled rules file")]
     InvalidWASM(#[from] anyhow::Error),
+
+    /// The rules include native code for a target that is not the current
+    /// one.
+    #[error("rules were compiled for `{actual}`, expecting `{expected}`")]
+    TargetMismatch {
+        /// Current target.
+        expected: String,
+        /// Target for which the rules were compiled.
+        actual: String,
+    },
+
+    /// The rules use a module that is not supported by this build of
+    /// YARA-X.
+    #[error("rules use module `{module}`, which is not supported by this build of YARA-X")]
+    MissingModule {
+        /// Module name.
+        module: String,
+    },
+
+    /// The signature of the rules is missing or is not valid.
+    #[error("invalid signature in YARA-X compiled rules")]
+    InvalidSignature,
+
+    /// The rules couldn't be deserialized, and the source code embedded
+    /// in them failed to compile.
+    #[error("cannot recompile YARA-X rules from embedded source code")]
+    CompileError(#[from] Box<CompileError>),
 }
 
 /// Error returned when rule compilation fails.
@@ -75,6 +102,8 @@ pub enum CompileError {
     ArbitraryRegexpPrefix(Box<ArbitraryRegexpPrefix>),
     AssignmentMismatch(Box<AssignmentMismatch>),
     CircularIncludes(Box<CircularIncludes>),
+    ConflictingImport(Box<ConflictingImport>),
+    ConflictingModuleAlias(Box<ConflictingModuleAlias>),
     ConflictingRuleIdentifier(Box<ConflictingRuleIdentifier>),
     CustomError(Box<CustomError>),
     DuplicateModifier(Box<DuplicateModifier>),
@@ -103,9 +132,12 @@ pub enum CompileError {
     MethodNotAllowedInWith(Box<MethodNotAllowedInWith>),
     MismatchingTypes(Box<MismatchingTypes>),
     MissingMetadata(Box<MissingMetadata>),
+    MissingTag(Box<MissingTag>),
     MixedGreediness(Box<MixedGreediness>),
     NumberOutOfRange(Box<NumberOutOfRange>),
     PotentiallySlowLoop(Box<PotentiallySlowLoop>),
+    RuleConflictsWithImport(Box<RuleConflictsWithImport>),
+    RuleNameTooLong(Box<RuleNameTooLong>),
     SlowPattern(Box<SlowPattern>),
     SyntaxError(Box<SyntaxError>),
     TooManyPatterns(Box<TooManyPatterns>),
@@ -143,18 +175,30 @@ impl CompileError {
                     report_builder.span_to_code_loc(span),
                 )
             }
-            ast::Error::InvalidEscapeSequence { message, span } => {
-                InvalidEscapeSequence::build(
+            ast::Error::InvalidEscapeSequence { message, span, fix } => {
+                let mut err = InvalidEscapeSequence::build(
                     report_builder,
                     message,
                     report_builder.span_to_code_loc(span),
-                )
+                );
+                if let Some(fix) = fix {
+                    err.report_mut()
+                        .new_section(Level::HELP, "if you meant a literal backslash, escape it")
+                        .patch(report_builder.span_to_code_loc(fix.span), fix.replacement);
+                }
+                err
             }
             ast::Error::UnexpectedEscapeSequence(span) => {
                 UnexpectedEscapeSequence::build(report_builder, report_builder.span_to_code_loc(span))
             }
-            ast::Error::InvalidUTF8(span) => {
-                InvalidUTF8::build(report_builder, report_builder.span_to_code_loc(span))
+            ast::Error::InvalidUTF8 { span, fix } => {
+                let mut err = InvalidUTF8::build(report_builder, report_builder.span_to_code_loc(span));
+                if let Some(fix) = fix {
+                    err.report_mut()
+                        .new_section(Level::HELP, "use escape sequences for bytes that are not valid UTF-8")
+                        .patch(report_builder.span_to_code_loc(fix.span), fix.replacement);
+                }
+                err
             }
         }
     }
@@ -636,7 +680,9 @@ pub struct InvalidModifier {
 /// This error indicates that a rule contains a `for` loop that may be very
 /// slow because it iterates over a range with an upper bound that depends on
 /// `filesize`. For very large files this may mean hundreds of millions of
-/// iterations.
+/// iterations. The same happens with upper bounds that depend on the number
+/// of occurrences of some pattern (i.e: `#a`), the offset of some match
+/// (i.e: `@a[1]`), or integers read from the scanned data (i.e: `uint32(0)`).
 ///
 /// # Example
 ///
@@ -952,6 +998,175 @@ pub struct CircularIncludes {
     note: Option<String>,
 }
 
+/// The alias given to a module in an `import` statement is already in use.
+///
+/// ## Example
+///
+/// ```text
+/// error[E047]: module alias `elf` conflicts with an existing identifier
+///  --> line:1:16
+///   |
+/// 1 | import "pe" as elf
+///   |                ^^^ identifier already in use by a module, global variable or rule
+///   |
+/// ```
+#[derive(ErrorStruct, Clone, Debug, PartialEq, Eq)]
+#[associated_enum(CompileError)]
+#[error(
+    code = "E047",
+    title = "module alias `{alias}` conflicts with an existing identifier"
+)]
+#[label(
+    "identifier already in use by a module, global variable or rule",
+    error_loc
+)]
+pub struct ConflictingModuleAlias {
+    report: Report,
+    alias: String,
+    error_loc: CodeLoc,
+}
+
+/// Rule name is longer than allowed. This is only used if the compiler is
+/// configured to check for it (see: [`crate::linters::RuleNameLength`]).
+///
+/// ## Example
+///
+/// ```text
+/// error[E048]: rule name is too long
+///  --> test.yar:13:6
+///    |
+/// 13 | rule pants_with_a_very_long_name {
+///    |      ^^^^^^^^^^^^^^^^^^^^^^^^^^^ this rule name has 27 characters, the maximum is 16
+///    |
+/// ```
+#[derive(ErrorStruct, Clone, Debug, PartialEq, Eq)]
+#[associated_enum(CompileError)]
+#[error(
+    code = "E048",
+    title = "rule name is too long"
+)]
+#[label(
+    "this rule name has {len} characters, the maximum is {max_len}",
+    rule_loc
+)]
+pub struct RuleNameTooLong {
+    report: Report,
+    rule_loc: CodeLoc,
+    len: usize,
+    max_len: usize,
+}
+
+/// Missing tag. This is only used if the compiler is configured to check
+/// for required tags (see: [`crate::linters::RequiredTags`]).
+///
+/// ## Example
+///
+/// ```text
+/// error[E049]: required tag is missing
+///  --> rules/test.yara:1:6
+///   |
+/// 1 | rule a : foo {
+///   |      ^ required tag `bar` not found
+///   |
+/// ```
+#[derive(ErrorStruct, Clone, Debug, PartialEq, Eq)]
+#[associated_enum(CompileError)]
+#[error(
+    code = "E049",
+    title = "required tag is missing"
+)]
+#[label(
+    "required tag `{name}` not found",
+    rule_loc
+)]
+pub struct MissingTag {
+    report: Report,
+    rule_loc: CodeLoc,
+    name: String,
+}
+
+/// A module is imported with the same name as an existing rule.
+///
+/// The rule and the import statement can be in different source files, as
+/// long as they are in the same namespace.
+///
+/// ## Example
+///
+/// ```text
+/// error[E050]: module `pe` conflicts with an existing rule
+///  --> b.yar:1:1
+///   |
+/// 1 | import "pe"
+///   | ^^^^^^^^^^^ identifier already in use by a rule
+///   |
+///  ::: a.yar:1:6
+///   |
+/// 1 | rule pe { condition: true }
+///   |      -- rule `pe` declared here
+/// ```
+#[derive(ErrorStruct, Clone, Debug, PartialEq, Eq)]
+#[associated_enum(CompileError)]
+#[error(
+    code = "E050",
+    title = "module `{identifier}` conflicts with an existing rule"
+)]
+#[label(
+    "identifier already in use by a rule",
+    import_loc
+)]
+#[label(
+    "rule `{identifier}` declared here",
+    rule_loc,
+    Level::NOTE
+)]
+pub struct ConflictingImport {
+    report: Report,
+    identifier: String,
+    import_loc: CodeLoc,
+    rule_loc: CodeLoc,
+}
+
+/// A rule has the same name as a module imported in the same namespace.
+///
+/// The rule and the import statement can be in different source files, as
+/// long as they are in the same namespace.
+///
+/// ## Example
+///
+/// ```text
+/// error[E051]: rule `pe` conflicts with an imported module
+///  --> b.yar:1:6
+///   |
+/// 1 | rule pe { condition: true }
+///   |      ^^ identifier already in use by a module
+///   |
+///  ::: a.yar:1:1
+///   |
+/// 1 | import "pe"
+///   | ----------- `pe` imported here
+/// ```
+#[derive(ErrorStruct, Clone, Debug, PartialEq, Eq)]
+#[associated_enum(CompileError)]
+#[error(
+    code = "E051",
+    title = "rule `{identifier}` conflicts with an imported module"
+)]
+#[label(
+    "identifier already in use by a module",
+    rule_loc
+)]
+#[label(
+    "`{identifier}` imported here",
+    import_loc,
+    Level::NOTE
+)]
+pub struct RuleConflictsWithImport {
+    report: Report,
+    identifier: String,
+    rule_loc: CodeLoc,
+    import_loc: CodeLoc,
+}
+
 /// A custom error has occurred.
 #[derive(ErrorStruct, Clone, Debug, PartialEq, Eq)]
 #[associated_enum(CompileError)]
@@ -35,13 +35,15 @@ use crate::compiler::{
 };
 use crate::errors::CustomError;
 use crate::errors::{MethodNotAllowedInWith, PotentiallySlowLoop};
-use crate::re;
+use crate::re::parser::SlowConstruct;
 use crate::symbols::{Symbol, SymbolLookup, SymbolTable};
 use crate::types::Value::Const;
 use crate::types::{
-    IntegerConstraint, Map, Regexp, StringConstraint, Type, TypeValue,
+    FuncSignature, IntegerConstraint, Map, Regexp, StringConstraint, Type,
+    TypeValue,
 };
 use crate::warnings::UnsatisfiableExpression;
+use crate::{re, wasm};
 
 /// How many patterns a rule can have. If a rule has more than this number of
 /// patterns the [`TooManyPatterns`] error is returned.
@@ -244,6 +246,36 @@ pub(in crate::compiler) fn text_pattern_from_ast<'src>(
         ));
     }
 
+    // With Unicode case folding the pattern is converted into a regexp that
+    // contains all the case variants of each character. The ASCII and wide
+    // variants are both part of the regexp, the latter encoded as UTF-16LE.
+    if ctx.unicode_case_folding && nocase.is_some() {
+        if let Ok(text) = text.to_str() {
+            if re::hir::Hir::has_non_ascii_case_folding(text) {
+                let hir = re::hir::Hir::case_folded_text(
+                    text,
+                    flags.contains(PatternFlags::Ascii),
+                    flags.contains(PatternFlags::Wide),
+                );
+
+                flags.remove(PatternFlags::Nocase | PatternFlags::Wide);
+                flags.insert(PatternFlags::Ascii);
+
+                return Ok(PatternInRule {
+                    identifier: pattern.identifier.clone(),
+                    in_use: false,
+                    span: pattern.span(),
+                    pattern: Pattern::Regexp(RegexpPattern {
+                        flags,
+                        hir,
+                        anchored_at: None,
+                        filesize_bounds: FilesizeBounds::default(),
+                    }),
+                });
+            }
+        }
+    }
+
     Ok(PatternInRule {
         identifier: pattern.identifier.clone(),
         in_use: false,
@@ -420,17 +452,55 @@ pub(in crate::compiler) fn regexp_pattern_from_ast<'src>(
     // matches that start at the same offset are found while scanning backwards
     // (right-to-left). However, if the regexp contains a mix of greedy and
     // non-greedy repetitions the decision becomes impossible.
-    let hir = re::parser::Parser::new()
+    let (hir, slow_constructs) = re::parser::Parser::new()
         .force_case_insensitive(flags.contains(PatternFlags::Nocase))
         .allow_mixed_greediness(false)
         .relaxed_re_syntax(ctx.relaxed_re_syntax)
-        .parse(&pattern.regexp)
+        .parse_with_slow_constructs(&pattern.regexp)
         .map_err(|err| {
             re_error_to_compile_error(ctx.report_builder, &pattern.regexp, err)
         })?;
 
-    // TODO: raise warning when .* used, propose using the non-greedy
-    // variant .*?
+    for slow_construct in slow_constructs {
+        let (msg, note) = match &slow_construct {
+            SlowConstruct::LeadingWildcard { snippet, .. } => (
+                format!("leading `{snippet}` in regexp"),
+                format!(
+                    "patterns are searched at every offset of the scanned data, consider removing the leading `{snippet}`"
+                ),
+            ),
+            SlowConstruct::UnboundedWildcard { bounded, .. } => (
+                "unbounded repetition of `.`".to_string(),
+                format!(
+                    "consider using a bounded repetition like `{bounded}`, where N is the maximum expected length"
+                ),
+            ),
+            SlowConstruct::NestedQuantifier { snippet, .. } => (
+                "nested quantifiers".to_string(),
+                format!(
+                    "`{snippet}` contains an unbounded repetition inside another one, consider rewriting it without nested quantifiers"
+                ),
+            ),
+        };
+        let span = slow_construct.span();
+        ctx.warnings.add(|| {
+            warnings::SlowRegexp::build(
+                ctx.report_builder,
+                msg,
+                // Spans in regexps are relative to the start of the regexp,
+                // without the opening slash (/). See comment in
+                // `re_error_to_compile_error`.
+                ctx.report_builder.span_to_code_loc(
+                    pattern
+                        .regexp
+                        .span()
+                        .subspan(span.start.offset, span.end.offset)
+                        .offset(1),
+                ),
+                Some(note),
+            )
+        });
+    }
 
     Ok(PatternInRule {
         identifier: pattern.identifier.clone(),
@@ -465,7 +535,10 @@ fn expr_from_ast(
 
             return Err(err);
         }
-        ast::Expr::Filesize { .. } => ctx.ir.filesize(),
+        ast::Expr::Filesize { span } => {
+            ctx.check_banned_symbol("filesize", span.clone())?;
+            ctx.ir.filesize()
+        }
 
         ast::Expr::True { .. } => {
             ctx.ir.constant(TypeValue::const_bool_from(true))
@@ -614,6 +687,10 @@ fn expr_from_ast(
         ast::Expr::FuncCall(func_call) => func_call_from_ast(ctx, func_call)?,
 
         ast::Expr::FieldAccess(expr) => {
+            if let Some(path) = field_access_path(expr) {
+                ctx.check_banned_symbol(&path, expr.span())?;
+            }
+
             let mut operands = Vec::with_capacity(expr.operands.len());
             // Iterate over all operands except the last one. These operands
             // must be structures. For instance, in `foo.bar.baz`, `foo` and
@@ -633,12 +710,52 @@ fn expr_from_ast(
             }
 
             // Now process the last operand.
-            operands.push(expr_from_ast(ctx, expr.operands.last().unwrap())?);
+            let last = expr_from_ast(ctx, expr.operands.last().unwrap())?;
+
+            // If the last operand is a computed field, the field access is
+            // replaced with a call to the method that computes the field's
+            // value, where the object is the structure that contains the
+            // field. For instance, `foo.bar.baz` is equivalent to
+            // `foo.bar.baz()`.
+            if let Expr::Symbol(symbol) = ctx.ir.get(last) {
+                if let Symbol::Field {
+                    computed: true,
+                    type_value: TypeValue::Func(func),
+                    ..
+                } = symbol.as_ref()
+                {
+                    let signature = func
+                        .signatures()
+                        .iter()
+                        .find(|signature| signature.args.len() == 1)
+                        .cloned()
+                        .unwrap();
 
+                    let object = if operands.len() == 1 {
+                        operands.pop().unwrap()
+                    } else {
+                        ctx.ir.field_access(operands)
+                    };
+
+                    return Ok(ctx.ir.func_call(
+                        Some(object),
+                        vec![],
+                        signature,
+                    ));
+                }
+            }
+
+            operands.push(last);
             ctx.ir.field_access(operands)
         }
 
         ast::Expr::Ident(ident) => {
+            // Identifiers that are fields of some structure are checked when
+            // the whole field access expression is processed.
+            if ctx.one_shot_symbol_table.is_none() {
+                ctx.check_banned_symbol(ident.name, ident.span())?;
+            }
+
             let symbol = ctx.lookup(ident)?;
 
             // If the symbol is a structure field, and it has an ACL, check if
@@ -924,10 +1041,28 @@ fn expr_from_ast(
                     check_type(ctx, index, expr.index.span(), &[key_ty])?;
                     ctx.ir.lookup(deputy_value.clone(), primary, index)
                 }
+                // Indexing a string returns the byte at the given index,
+                // this is implemented by calling `str_index`.
+                TypeValue::String { .. } => {
+                    let index =
+                        non_negative_integer_from_ast(ctx, &expr.index)?;
+                    ctx.ir.func_call(
+                        None,
+                        vec![primary, index],
+                        Rc::new(FuncSignature::from(
+                            wasm::export__str_index.mangled_name,
+                        )),
+                    )
+                }
                 type_value => {
                     return Err(WrongType::build(
                         ctx.report_builder,
-                        format!("`{}` or `{}`", Type::Array, Type::Map),
+                        format!(
+                            "`{}`, `{}` or `{}`",
+                            Type::Array,
+                            Type::Map,
+                            Type::String
+                        ),
                         format!("`{}`", type_value.ty()),
                         ctx.report_builder
                             .span_to_code_loc(expr.primary.span()),
@@ -1285,15 +1420,32 @@ fn is_potentially_large_range(ctx: &CompileContext, range: &Range) -> bool {
     }
 
     // If the lower bound is constant, and the upper bound is some expression
-    // that depends on `filesize` or the number of occurrences of some pattern
-    // (i.e: #a), we consider it a potentially large range. The only exception
-    // is when `math.min` is used, like in `(0..math.min(filesize, 1000))`
+    // that depends on `filesize`, the number of occurrences of some pattern
+    // (i.e: #a), the offset of some match (i.e: @a[1]), or an integer read
+    // from the scanned data (i.e: uint32(0)), we consider it a potentially
+    // large range. The only exception is when `math.min` is used, like in
+    // `(0..math.min(filesize, 1000))`
     ctx.ir
         .dfs_find(
             range.upper_bound,
-            // Traverse the upper bound expression looking for the use of filesize
-            // or a pattern count.
-            |node| matches!(node, Expr::Filesize | Expr::PatternCount { .. }),
+            // Traverse the upper bound expression looking for the use of
+            // filesize, a pattern count, a pattern offset, or a function
+            // that can return huge values.
+            |node| match node {
+                Expr::Filesize
+                | Expr::PatternCount { .. }
+                | Expr::PatternOffset { .. } => true,
+                Expr::FuncCall(func) => matches!(
+                    &func.signature.result,
+                    TypeValue::Integer { constraints: Some(constraints), .. }
+                        if constraints.iter().any(|constraint| matches!(
+                            constraint,
+                            IntegerConstraint::Range(_, max)
+                                if *max > MAX_LOOP_ITERATIONS
+                        ))
+                ),
+                _ => false,
+            },
             // Don't traverse the arguments of `math.min`.
             |node| {
                 if let Expr::FuncCall(func) = node {
@@ -1361,15 +1513,22 @@ fn for_in_expr_from_ast(
             // instead of `clone`, because we want a TypeValue with the same
             // type as the first item in the tuple, but we don't want to
             // clone its actual value if known. The actual value for the
-            // loop variable is not known until the loop is executed.
-            (
-                vec![expressions
+            // loop variable is not known until the loop is executed. The
+            // only exception are tuples that mix integers and floats, where
+            // the loop variable is a float.
+            let var = if expressions
+                .iter()
+                .any(|expr| ctx.ir.get(*expr).ty() == Type::Float)
+            {
+                TypeValue::unknown_float()
+            } else {
+                expressions
                     .first()
                     .map(|node_idx| ctx.ir.get(*node_idx).type_value())
                     .unwrap()
-                    .clone_without_value()],
-                Type::Unknown,
-            )
+                    .clone_without_value()
+            };
+            (vec![var], Type::Unknown)
         }
         Iterable::Expr(expr) => match ctx.ir.get(*expr).type_value() {
             TypeValue::Array(array) => (vec![array.deputy()], Type::Array),
@@ -1548,7 +1707,12 @@ fn iterable_from_ast(
                 // type mismatch.
                 let ty = ctx.ir.get(expr).ty();
                 if let Some((prev_ty, prev_span)) = prev {
-                    if prev_ty != ty {
+                    // Integers and floats can be mixed, in which case all
+                    // items are promoted to float.
+                    let numeric =
+                        matches!(prev_ty, Type::Integer | Type::Float)
+                            && matches!(ty, Type::Integer | Type::Float);
+                    if prev_ty != ty && !numeric {
                         return Err(MismatchingTypes::build(
                             ctx.report_builder,
                             prev_ty.to_string(),
@@ -1763,6 +1927,10 @@ fn func_call_from_ast(
     ctx: &mut CompileContext,
     func_call: &ast::FuncCall,
 ) -> Result<ExprId, CompileError> {
+    if let Some(path) = func_call_path(func_call) {
+        ctx.check_banned_symbol(&path, func_call.span())?;
+    }
+
     let mut object = if let Some(obj) = &func_call.object {
         let expr = expr_from_ast(ctx, obj)?;
         // The one-shot symbol table is set according to the type of the object
@@ -1804,6 +1972,11 @@ fn func_call_from_ast(
     let mut expected_args = Vec::new();
     let mut matching_signature = None;
 
+    // Signature that accepts the provided arguments after promoting some
+    // integer arguments to float, together with the number of promoted
+    // arguments.
+    let mut promoting_signature: Option<(_, usize)> = None;
+
     // Determine if any of the signatures for the called function matches
     // the provided arguments.
     for signature in func.signatures().iter() {
@@ -1824,9 +1997,38 @@ fn func_call_from_ast(
             break;
         }
 
+        // Integer arguments are accepted where a float is expected. If
+        // more than one signature accepts the arguments in this way, the
+        // one that requires less promotions is used.
+        if arg_types.len() == expected_arg_types.len() {
+            let mut promotions = 0;
+            let accepted = arg_types
+                .iter()
+                .zip(expected_arg_types.iter())
+                .all(|(arg_ty, expected_ty)| match (arg_ty, expected_ty) {
+                    (Type::Integer, Type::Float) => {
+                        promotions += 1;
+                        true
+                    }
+                    _ => arg_ty == expected_ty,
+                });
+            if accepted
+                && promoting_signature.is_none_or(|(_, min_promotions)| {
+                    promotions < min_promotions
+                })
+            {
+                promoting_signature = Some((signature, promotions));
+            }
+        }
+
         expected_args.push(expected_arg_types);
     }
 
+    if matching_signature.is_none() {
+        matching_signature =
+            promoting_signature.map(|(signature, _)| signature);
+    }
+
     // No matching signature was found, that means that the arguments
     // provided were incorrect.
     if matching_signature.is_none() {
@@ -2018,6 +2220,40 @@ fn re_error_to_compile_error(
     }
 }
 
+/// Returns the path of the symbol referenced by an expression, like
+/// `pe.sections.name` for `pe.sections[0].name`. Array and map indexes are
+/// not included in the path. Returns `None` if the expression is not a
+/// reference to some symbol.
+fn symbol_path(expr: &ast::Expr) -> Option<String> {
+    match expr {
+        ast::Expr::Ident(ident) => Some(ident.name.to_string()),
+        ast::Expr::Lookup(lookup) => symbol_path(&lookup.primary),
+        ast::Expr::FieldAccess(expr) => field_access_path(expr),
+        ast::Expr::FuncCall(func_call) => func_call_path(func_call),
+        _ => None,
+    }
+}
+
+/// Returns the path of the symbol referenced by a field access expression.
+/// See [`symbol_path`].
+fn field_access_path(expr: &ast::NAryExpr) -> Option<String> {
+    expr.operands
+        .iter()
+        .map(symbol_path)
+        .collect::<Option<Vec<_>>>()
+        .map(|paths| paths.join("."))
+}
+
+/// Returns the path of the function called by a function call expression,
+/// like `math.entropy` for `math.entropy(0, filesize)`. See [`symbol_path`].
+fn func_call_path(func_call: &ast::FuncCall) -> Option<String> {
+    match &func_call.object {
+        Some(obj) => symbol_path(obj)
+            .map(|path| format!("{}.{}", path, func_call.identifier.name)),
+        None => Some(func_call.identifier.name.to_string()),
+    }
+}
+
 /// Produce a warning if the expression is not boolean.
 pub(in crate::compiler) fn warn_if_not_bool(
     ctx: &mut CompileContext,
@@ -2041,12 +2277,37 @@ pub(in crate::compiler) fn warn_if_not_bool(
                 ),
                 _ => None,
             };
-            warnings::NonBooleanAsBoolean::build(
+            // The expression can be converted into a boolean by comparing
+            // it with the zero value of its type. The comparison is appended
+            // at the end of the expression, as `!=` has lower precedence than
+            // any operator that can produce a non-boolean value.
+            let comparison = match ty {
+                Type::Integer => Some(" != 0"),
+                Type::Float => Some(" != 0.0"),
+                Type::String => Some(r#" != """#),
+                _ => None,
+            };
+
+            let mut warning = warnings::NonBooleanAsBoolean::build(
                 ctx.report_builder,
                 ty.to_string(),
-                ctx.report_builder.span_to_code_loc(span),
+                ctx.report_builder.span_to_code_loc(span.clone()),
                 note,
-            )
+            );
+
+            if let Some(comparison) = comparison {
+                warning
+                    .report_mut()
+                    .new_section(Level::HELP, "consider an explicit comparison")
+                    .patch(
+                        ctx.report_builder.span_to_code_loc(Span(
+                            span.end() as u32..span.end() as u32,
+                        )),
+                        comparison,
+                    );
+            }
+
+            warning
         });
     }
 }
@@ -2357,7 +2618,7 @@ gen_binary_op!(
     Type::Integer | Type::Float | Type::String,
     // Integers can be compared with floats.
     &[(Type::Integer, Type::Float)],
-    None
+    Some(ne_check)
 );
 
 gen_binary_op!(
@@ -2367,7 +2628,7 @@ gen_binary_op!(
     Type::Integer | Type::Float | Type::String,
     // Integers can be compared with floats.
     &[(Type::Integer, Type::Float)],
-    None
+    Some(gt_check)
 );
 
 gen_binary_op!(
@@ -2378,7 +2639,7 @@ gen_binary_op!(
     // Integers can be compared with floats, but strings can be
     // compared only with another string.
     &[(Type::Integer, Type::Float)],
-    None
+    Some(ge_check)
 );
 
 gen_binary_op!(
@@ -2388,7 +2649,7 @@ gen_binary_op!(
     Type::Integer | Type::Float | Type::String,
     // Integers can be compared with floats.
     &[(Type::Integer, Type::Float)],
-    None
+    Some(lt_check)
 );
 
 gen_binary_op!(
@@ -2398,7 +2659,7 @@ gen_binary_op!(
     Type::Integer | Type::Float | Type::String,
     // Integers can be compared with floats.
     &[(Type::Integer, Type::Float)],
-    None
+    Some(le_check)
 );
 
 gen_string_op!(contains_expr_from_ast, contains);
@@ -2417,6 +2678,26 @@ fn eq_check(
     lhs_span: Span,
     rhs_span: Span,
 ) -> Result<(), CompileError> {
+    let has_constraints = |expr: ExprId| {
+        matches!(
+            ctx.ir.get(expr).type_value(),
+            TypeValue::Integer { constraints: Some(_), .. }
+        )
+    };
+
+    // Integers with constraints are checked below, which produces a more
+    // specific warning.
+    if !has_constraints(lhs) && !has_constraints(rhs) {
+        invariant_comparison_check(
+            ctx,
+            lhs,
+            rhs,
+            lhs_span.clone(),
+            rhs_span.clone(),
+            |a, b| a == b,
+        );
+    }
+
     let lhs = ctx.ir.get(lhs).type_value();
     let rhs = ctx.ir.get(rhs).type_value();
 
@@ -2594,6 +2875,177 @@ fn eq_check(
     Ok(())
 }
 
+/// Checks the operands for the != operation.
+fn ne_check(
+    ctx: &mut CompileContext,
+    lhs: ExprId,
+    rhs: ExprId,
+    lhs_span: Span,
+    rhs_span: Span,
+) -> Result<(), CompileError> {
+    invariant_comparison_check(ctx, lhs, rhs, lhs_span, rhs_span, |a, b| {
+        a != b
+    });
+    Ok(())
+}
+
+/// Checks the operands for the > operation.
+fn gt_check(
+    ctx: &mut CompileContext,
+    lhs: ExprId,
+    rhs: ExprId,
+    lhs_span: Span,
+    rhs_span: Span,
+) -> Result<(), CompileError> {
+    invariant_comparison_check(ctx, lhs, rhs, lhs_span, rhs_span, |a, b| {
+        a > b
+    });
+    Ok(())
+}
+
+/// Checks the operands for the >= operation.
+fn ge_check(
+    ctx: &mut CompileContext,
+    lhs: ExprId,
+    rhs: ExprId,
+    lhs_span: Span,
+    rhs_span: Span,
+) -> Result<(), CompileError> {
+    invariant_comparison_check(ctx, lhs, rhs, lhs_span, rhs_span, |a, b| {
+        a >= b
+    });
+    Ok(())
+}
+
+/// Checks the operands for the < operation.
+fn lt_check(
+    ctx: &mut CompileContext,
+    lhs: ExprId,
+    rhs: ExprId,
+    lhs_span: Span,
+    rhs_span: Span,
+) -> Result<(), CompileError> {
+    invariant_comparison_check(ctx, lhs, rhs, lhs_span, rhs_span, |a, b| {
+        a < b
+    });
+    Ok(())
+}
+
+/// Checks the operands for the <= operation.
+fn le_check(
+    ctx: &mut CompileContext,
+    lhs: ExprId,
+    rhs: ExprId,
+    lhs_span: Span,
+    rhs_span: Span,
+) -> Result<(), CompileError> {
+    invariant_comparison_check(ctx, lhs, rhs, lhs_span, rhs_span, |a, b| {
+        a <= b
+    });
+    Ok(())
+}
+
+/// Returns the minimum and maximum values that an integer expression can
+/// take, if they are known at compile time.
+fn integer_bounds(ctx: &CompileContext, expr: ExprId) -> Option<(i64, i64)> {
+    match ctx.ir.get(expr) {
+        Expr::Filesize
+        | Expr::PatternCount { .. }
+        | Expr::PatternCountVar { .. }
+        | Expr::PatternOffset { .. }
+        | Expr::PatternOffsetVar { .. }
+        | Expr::PatternLength { .. }
+        | Expr::PatternLengthVar { .. } => Some((0, i64::MAX)),
+        expr => match expr.type_value() {
+            TypeValue::Integer { constraints: Some(constraints), .. } => {
+                constraints
+                    .iter()
+                    .map(|IntegerConstraint::Range(min, max)| (*min, *max))
+                    .next()
+            }
+            _ => None,
+        },
+    }
+}
+
+/// Checks if a comparison between an integer expression with known bounds
+/// and a constant always produces the same result, and raises a warning in
+/// that case. For instance, `filesize < 0` is always false.
+///
+/// `cmp` is the function that implements the comparison.
+fn invariant_comparison_check(
+    ctx: &mut CompileContext,
+    lhs: ExprId,
+    rhs: ExprId,
+    lhs_span: Span,
+    rhs_span: Span,
+    cmp: fn(i64, i64) -> bool,
+) {
+    let lhs_const = ctx.ir.get(lhs).try_as_const_integer();
+    let rhs_const = ctx.ir.get(rhs).try_as_const_integer();
+
+    // `bounded_expr` is the operand with known bounds, `f` computes the
+    // result of the comparison for a given value of that operand.
+    let (bounded_expr, bounded_span, constant, f): (
+        _,
+        _,
+        _,
+        &dyn Fn(i64) -> bool,
+    ) = match (lhs_const, rhs_const) {
+        (None, Some(c)) => (lhs, lhs_span.clone(), c, &move |x| cmp(x, c)),
+        (Some(c), None) => (rhs, rhs_span.clone(), c, &move |x| cmp(c, x)),
+        _ => return,
+    };
+
+    let Some((min, max)) = integer_bounds(ctx, bounded_expr) else {
+        return;
+    };
+
+    // The comparisons are monotonic, except for == and !=, which change
+    // their result only at the constant itself. Therefore, the result is
+    // the same for every value in [min, max] if it's the same for `min`,
+    // `max` and the constant (if it falls within the range).
+    let value = f(min);
+
+    if f(max) != value
+        || ((min..=max).contains(&constant) && f(constant) != value)
+    {
+        return;
+    }
+
+    // Expressions like `@a[1]` or `uint8(0)` can be undefined, and
+    // comparisons with undefined values are false. So, these comparisons can
+    // be guaranteed to be always false, but not always true. Only `filesize`
+    // and pattern counts are always defined.
+    if value
+        && !matches!(
+            ctx.ir.get(bounded_expr),
+            Expr::Filesize
+                | Expr::PatternCount { .. }
+                | Expr::PatternCountVar { .. }
+        )
+    {
+        return;
+    }
+
+    let snippet = ctx.report_builder.get_snippet(bounded_span.clone());
+
+    let note = if max == i64::MAX && min == 0 {
+        format!("`{snippet}` is never negative")
+    } else {
+        format!("`{snippet}` is always in the range [{min},{max}]")
+    };
+
+    ctx.warnings.add(|| {
+        warnings::InvariantBooleanExpression::build(
+            ctx.report_builder,
+            value,
+            ctx.report_builder.span_to_code_loc(lhs_span.combine(&rhs_span)),
+            Some(note),
+        )
+    });
+}
+
 /// Checks the operands for shift-left and shift-right operations.
 fn shx_check(
     ctx: &mut CompileContext,
@@ -402,6 +402,10 @@ pub(super) fn dfs_common(
                     .push(Event::Enter((*expr, EventContext::WithDeclaration)))
             }
         }
+
+        Expr::BulkLookup(bulk) => {
+            stack.push(Event::Enter((bulk.body, EventContext::Body)));
+        }
     }
 }
 
@@ -187,7 +187,7 @@ mod tests {
     use regex_syntax::hir::{
         Class, ClassBytes, ClassBytesRange, Dot, Hir, HirKind, Repetition,
     };
-    use rustc_hash::FxHashSet;
+    use rustc_hash::{FxHashMap, FxHashSet};
 
     use yara_x_parser::ast;
     use yara_x_parser::ast::{
@@ -229,9 +229,11 @@ mod tests {
         let mut ctx = CompileContext {
             ir: &mut ir,
             relaxed_re_syntax: false,
+            unicode_case_folding: false,
             error_on_slow_loop: false,
             one_shot_symbol_table: None,
             features: &FxHashSet::default(),
+            banned_symbols: &FxHashMap::default(),
             symbol_table: &mut symbol_table,
             report_builder: &mut report_builder,
             current_rule_patterns: &mut rule_patterns,
@@ -51,11 +51,11 @@ use crate::compiler::ir::dfs::{
     dfs_common, DFSIter, DFSWithScopeIter, Event, EventContext,
 };
 
-use crate::compiler::FilesizeBounds;
-use crate::re;
+use crate::compiler::{FilesizeBounds, RuleCost};
 use crate::symbols::Symbol;
 use crate::types::Value::Const;
 use crate::types::{FuncSignature, Type, TypeValue};
+use crate::{re, wasm};
 
 pub(in crate::compiler) use ast2ir::patterns_from_ast;
 pub(in crate::compiler) use ast2ir::rule_condition_from_ast;
@@ -253,10 +253,8 @@ impl Pattern {
                 *anchored_at = None;
                 self.flags_mut().insert(PatternFlags::NonAnchorable);
             }
-            None => {
-                if is_anchorable {
-                    *anchored_at = Some(offset);
-                }
+            None if is_anchorable => {
+                *anchored_at = Some(offset);
             }
             _ => {}
         }
@@ -708,6 +706,9 @@ impl IR {
                     Expr::With(with) => {
                         with.declarations.iter().any(|(v, _)| v.index() == var)
                     }
+                    Expr::BulkLookup(bulk) => {
+                        bulk.lookups.iter().any(|(v, _)| v.index() == var)
+                    }
                     Expr::ForIn(for_in) => {
                         for_in.variables.iter().any(|v| v.index() == var)
                     }
@@ -889,6 +890,214 @@ impl IR {
         self.root.unwrap()
     }
 
+    /// Optimizes the IR by fetching the fields of a structure that are used
+    /// in the body of a loop with a single call, instead of one call per
+    /// field access.
+    ///
+    /// For example, in the following loop:
+    ///
+    /// ```text
+    /// for any section in pe.sections : (
+    ///   section.name == ".text" and section.raw_data_size > 0
+    /// )
+    /// ```
+    ///
+    /// each field access (`section.name` and `section.raw_data_size`) is a
+    /// call from WASM code to the host. This pass wraps the body of the loop
+    /// in a [`Expr::BulkLookup`] that fetches the values of both fields
+    /// with a single call at the beginning of each iteration, storing them
+    /// in variables, and replaces the field accesses with those variables.
+    ///
+    /// This only applies to field accesses that start at a loop variable
+    /// of type struct, and that end in an integer, float, bool or string
+    /// field. Loops where such field accesses appear less than twice are
+    /// left untouched, as they wouldn't benefit from this optimization. The
+    /// root of the IR tree is never modified by this pass.
+    pub fn bulk_lookups(&mut self) {
+        let loops: Vec<ExprId> = self
+            .dfs_iter(self.root.unwrap())
+            .filter_map(|evt| match evt {
+                Event::Enter((expr_id, Expr::ForIn(_), _)) => Some(expr_id),
+                _ => None,
+            })
+            .collect();
+
+        for loop_expr_id in loops {
+            let (object, body) = match self.get(loop_expr_id) {
+                Expr::ForIn(for_in) => {
+                    match for_in
+                        .variables
+                        .iter()
+                        .find(|var| var.ty() == Type::Struct)
+                    {
+                        Some(var) => (*var, for_in.body),
+                        None => continue,
+                    }
+                }
+                _ => unreachable!(),
+            };
+
+            // Field accesses in the body that can be replaced by variables,
+            // grouped by the path to the field.
+            let mut field_accesses: Vec<(Vec<i32>, TypeValue, Vec<ExprId>)> =
+                Vec::new();
+
+            let mut num_field_accesses = 0;
+
+            // The variable index and path of each field are passed to the
+            // host in the memory area reserved for lookup indexes, which has
+            // a limited size. Fields that don't fit are looked up in the
+            // usual way.
+            let mut free_words = (wasm::LOOKUP_INDEXES_END
+                - wasm::LOOKUP_INDEXES_START)
+                as usize
+                / size_of::<i32>();
+
+            for evt in self.dfs_iter(body) {
+                let (expr_id, field_access) = match evt {
+                    Event::Enter((expr_id, Expr::FieldAccess(f), _)) => {
+                        (expr_id, f)
+                    }
+                    _ => continue,
+                };
+
+                let Some((path, type_value)) =
+                    self.field_path(object, &field_access.operands)
+                else {
+                    continue;
+                };
+
+                if let Some((_, _, expr_ids)) =
+                    field_accesses.iter_mut().find(|(p, _, _)| *p == path)
+                {
+                    expr_ids.push(expr_id);
+                    num_field_accesses += 1;
+                } else if let Some(remaining) =
+                    free_words.checked_sub(2 + path.len())
+                {
+                    free_words = remaining;
+                    field_accesses.push((path, type_value, vec![expr_id]));
+                    num_field_accesses += 1;
+                }
+            }
+
+            if num_field_accesses < 2 {
+                continue;
+            }
+
+            // The variables that receive the field values are placed at the
+            // beginning of the body's stack frame, variables declared inside
+            // the body are shifted to make room for them.
+            let var_index = self
+                .ancestors(body)
+                .map(|expr_id| self.get(expr_id).stack_frame_size())
+                .sum::<i32>();
+
+            self.shift_vars(body, var_index, field_accesses.len() as i32);
+
+            let mut lookups = Vec::with_capacity(field_accesses.len());
+
+            for (i, (path, type_value, expr_ids)) in
+                field_accesses.into_iter().enumerate()
+            {
+                let var = Var::new(0, type_value.ty(), var_index + i as i32);
+                for expr_id in expr_ids {
+                    self.replace(
+                        expr_id,
+                        Expr::Symbol(Box::new(Symbol::Var {
+                            var,
+                            type_value: type_value.clone(),
+                        })),
+                    );
+                }
+                lookups.push((var, path));
+            }
+
+            let bulk_lookup = self.bulk_lookup(object, lookups, body);
+
+            self.set_parent(bulk_lookup, loop_expr_id);
+            self.get_mut(loop_expr_id).replace_child(body, bulk_lookup);
+        }
+    }
+
+    /// If the operands of a field access start with the variable `object`,
+    /// followed by one or more fields, and the last field is an integer,
+    /// float, bool or string that is not a constant, returns the indexes of
+    /// the fields and the type of the last one.
+    fn field_path(
+        &self,
+        object: Var,
+        operands: &[ExprId],
+    ) -> Option<(Vec<i32>, TypeValue)> {
+        let (first, fields) = operands.split_first()?;
+
+        match self.get(*first) {
+            Expr::Symbol(symbol) => match symbol.as_ref() {
+                Symbol::Var { var, .. } if *var == object => {}
+                _ => return None,
+            },
+            _ => return None,
+        }
+
+        let mut path = Vec::with_capacity(fields.len());
+        let mut last_type_value = None;
+
+        for field in fields {
+            match self.get(*field) {
+                Expr::Symbol(symbol) => match symbol.as_ref() {
+                    Symbol::Field { index, type_value, .. } => {
+                        path.push(*index as i32);
+                        last_type_value = Some(type_value);
+                    }
+                    _ => return None,
+                },
+                _ => return None,
+            }
+        }
+
+        match last_type_value? {
+            type_value @ (TypeValue::Integer { .. }
+            | TypeValue::Float { .. }
+            | TypeValue::Bool { .. }
+            | TypeValue::String { .. })
+                if !type_value.is_const() =>
+            {
+                Some((path, type_value.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the cost of the condition, counting the loops and the calls
+    /// to module functions in it. The cost of the rule's patterns is not
+    /// included, as patterns are not part of the IR.
+    pub fn cost(&self) -> RuleCost {
+        let mut cost = RuleCost::default();
+
+        for evt in self.dfs_iter(self.root.unwrap()) {
+            let expr = match evt {
+                Event::Enter((_, expr, _)) => expr,
+                _ => continue,
+            };
+            match expr {
+                Expr::ForIn(for_in) => cost.loop_found(
+                    matches!(for_in.iterable, Iterable::Range(_))
+                        && for_in.iterable.num_iterations(self).is_none(),
+                ),
+                Expr::ForOf(_) => cost.loop_found(false),
+                // Module functions are the ones with a dot in their names,
+                // like `pe.imports`. The rest are built-in functions like
+                // `uint8`, which are cheap.
+                Expr::FuncCall(func) if func.mangled_name().contains('.') => {
+                    cost.func_call_found()
+                }
+                _ => {}
+            }
+        }
+
+        cost
+    }
+
     /// Determines the constraints on `filesize` imposed by a rule condition.
     ///
     /// This function analyzes the rule’s condition to determine whether it
@@ -1823,6 +2032,27 @@ impl IR {
         expr_id
     }
 
+    /// Creates a new [`Expr::BulkLookup`].
+    pub fn bulk_lookup(
+        &mut self,
+        object: Var,
+        lookups: Vec<(Var, Vec<i32>)>,
+        body: ExprId,
+    ) -> ExprId {
+        let type_value = self.get(body).type_value();
+        let expr_id = ExprId::from(self.nodes.len());
+        self.parents[body.0 as usize] = expr_id;
+        self.parents.push(ExprId::none());
+        self.nodes.push(Expr::BulkLookup(Box::new(BulkLookup {
+            type_value,
+            object,
+            lookups,
+            body,
+        })));
+        debug_assert_eq!(self.parents.len(), self.nodes.len());
+        expr_id
+    }
+
     /// Creates a new [`Expr::With`].
     pub fn with(
         &mut self,
@@ -1955,6 +2185,12 @@ impl Debug for IR {
                         Expr::Defined { .. } => write!(f, "DEFINED -- hash: {expr_hash:#08x}")?,
                         Expr::FieldAccess { .. } => write!(f, "FIELD_ACCESS -- hash: {expr_hash:#08x}")?,
                         Expr::With { .. } => write!(f, "WITH -- hash: {expr_hash:#08x}")?,
+                        Expr::BulkLookup(bulk) => write!(f,
+                            "BULK_LOOKUP {:?} {:?} -- hash: {:#08x}",
+                            bulk.object,
+                            bulk.lookups,
+                            expr_hash
+                        )?,
                         Expr::Symbol(symbol) => write!(f, "SYMBOL {symbol:?}")?,
                         Expr::OfExprTuple(_) => write!(f, "OF -- hash: {expr_hash:#08x}")?,
                         Expr::OfPatternSet(_) => write!(f, "OF -- hash: {expr_hash:#08x}")?,
@@ -2229,6 +2465,12 @@ pub(crate) enum Expr {
 
     /// Array or dictionary lookup expression (e.g. `array[1]`, `dict["key"]`)
     Lookup(Box<Lookup>),
+
+    /// Looks up multiple fields of a structure with a single call, storing
+    /// their values in variables that are used by the body. This expression
+    /// doesn't appear in the source code, it is introduced by
+    /// [`IR::bulk_lookups`].
+    BulkLookup(Box<BulkLookup>),
 }
 
 /// A lookup operation in an array or dictionary.
@@ -2345,6 +2587,20 @@ pub(crate) struct With {
     pub body: ExprId,
 }
 
+/// Multiple field lookups in the structure stored in `object`, performed
+/// with a single call before evaluating `body`.
+///
+/// Each item in `lookups` contains the variable where the value of a field
+/// is stored, and the path to the field, expressed as the indexes of the
+/// fields that must be traversed starting at `object`. The field must be
+/// an integer, float, bool or string.
+pub(crate) struct BulkLookup {
+    pub type_value: TypeValue,
+    pub object: Var,
+    pub lookups: Vec<(Var, Vec<i32>)>,
+    pub body: ExprId,
+}
+
 /// In expressions like `$a at 0` and `$b in (0..10)`, this type represents the
 /// anchor (e.g. `at <expr>`, `in <range>`).
 ///
@@ -2476,6 +2732,7 @@ impl Expr {
     pub fn stack_frame_size(&self) -> i32 {
         match self {
             Expr::With(with) => with.declarations.len() as i32,
+            Expr::BulkLookup(bulk) => bulk.lookups.len() as i32,
             Expr::ForOf(_) => VarStack::FOR_OF_FRAME_SIZE,
             Expr::ForIn(_) => VarStack::FOR_IN_FRAME_SIZE,
             Expr::OfExprTuple(_) => VarStack::OF_FRAME_SIZE,
@@ -2512,6 +2769,13 @@ impl Expr {
                 }
             }
 
+            Expr::BulkLookup(bulk) => {
+                bulk.object.shift(from_index, shift_amount);
+                for (v, _) in bulk.lookups.iter_mut() {
+                    v.shift(from_index, shift_amount)
+                }
+            }
+
             Expr::OfExprTuple(of) => {
                 of.next_expr_var.shift(from_index, shift_amount);
                 of.for_vars.shift(from_index, shift_amount);
@@ -2662,6 +2926,12 @@ impl Expr {
                 }
             }
 
+            Expr::BulkLookup(bulk) => {
+                if bulk.body == child {
+                    bulk.body = replacement
+                }
+            }
+
             Expr::FieldAccess(field_access) => {
                 replace_in_slice(field_access.operands.as_mut_slice());
             }
@@ -2778,6 +3048,7 @@ impl Expr {
             Expr::FuncCall(func_call) => func_call.signature.result.ty(),
             Expr::Lookup(lookup) => lookup.type_value.ty(),
             Expr::With(with) => with.type_value.ty(),
+            Expr::BulkLookup(bulk) => bulk.type_value.ty(),
         }
     }
 
@@ -2849,6 +3120,7 @@ impl Expr {
             Expr::FuncCall(func_call) => func_call.signature.result.clone(),
             Expr::Lookup(lookup) => lookup.type_value.clone(),
             Expr::With(with) => with.type_value.clone(),
+            Expr::BulkLookup(bulk) => bulk.type_value.clone(),
         }
     }
 
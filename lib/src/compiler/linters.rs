@@ -111,6 +111,78 @@ impl LinterInternal for RuleName {
     }
 }
 
+/// A linter that ensures that rule names are not longer than a given number
+/// of characters.
+///
+/// ```
+/// # use yara_x::Compiler;
+/// use yara_x::linters::rule_name_length;
+/// let mut compiler = Compiler::new();
+/// let warnings = compiler
+///     .add_linter(rule_name_length(8))
+///     // This produces a warning because the rule name is too long.
+///     .add_source(r#"rule foo_bar_baz { condition: true }"#)
+///     .unwrap()
+///     .warnings();
+///
+/// assert_eq!(
+///     warnings[0].to_string(),
+///     r#"warning[rule_name_too_long]: rule name is too long
+///  --> line:1:6
+///   |
+/// 1 | rule foo_bar_baz { condition: true }
+///   |      ----------- this rule name has 11 characters, the maximum is 8"#);
+/// ```
+pub struct RuleNameLength {
+    max_len: usize,
+    error: bool,
+}
+
+impl RuleNameLength {
+    fn new(max_len: usize) -> Self {
+        Self { max_len, error: false }
+    }
+
+    /// Specifies whether the linter should produce an error instead of a warning.
+    ///
+    /// By default, the linter raises warnings about rule names that are too
+    /// long. This setting allows turning such warnings into errors.
+    pub fn error(mut self, yes: bool) -> Self {
+        self.error = yes;
+        self
+    }
+}
+
+impl LinterInternal for RuleNameLength {
+    fn check(
+        &self,
+        report_builder: &ReportBuilder,
+        rule: &ast::Rule,
+    ) -> LinterResult {
+        let len = rule.identifier.name.len();
+
+        if len <= self.max_len {
+            return LinterResult::Ok;
+        }
+
+        if self.error {
+            LinterResult::Err(errors::RuleNameTooLong::build(
+                report_builder,
+                report_builder.span_to_code_loc(rule.identifier.span()),
+                len,
+                self.max_len,
+            ))
+        } else {
+            LinterResult::Warn(warnings::RuleNameTooLong::build(
+                report_builder,
+                report_builder.span_to_code_loc(rule.identifier.span()),
+                len,
+                self.max_len,
+            ))
+        }
+    }
+}
+
 type Predicate<'a> = dyn Fn(&Meta) -> bool + 'a;
 
 /// A linter that ensures tags meet specified requirements in either an allowed
@@ -247,6 +319,92 @@ impl LinterInternal for Tags {
     }
 }
 
+/// A linter that ensures that every rule has all the tags in a given list.
+///
+/// ```
+/// # use yara_x::Compiler;
+/// use yara_x::linters;
+/// let mut compiler = Compiler::new();
+/// let warnings = compiler
+///     .add_linter(linters::tags_required(vec!["foo".to_string(), "bar".to_string()]))
+///     // This produces a warning because the rule doesn't have the `bar`
+///     // tag.
+///     .add_source(r#"rule test : foo { condition: true }"#)
+///     .unwrap()
+///     .warnings();
+///
+/// assert_eq!(
+///     warnings[0].to_string(),
+///     r#"warning[missing_tag]: required tag is missing
+///  --> line:1:6
+///   |
+/// 1 | rule test : foo { condition: true }
+///   |      ---- required tag `bar` not found"#);
+/// ```
+pub struct RequiredTags {
+    required: Vec<String>,
+    error: bool,
+}
+
+impl RequiredTags {
+    fn new(required: Vec<String>) -> Self {
+        Self { required, error: false }
+    }
+
+    /// Specifies whether the linter should produce an error instead of a
+    /// warning.
+    ///
+    /// By default, the linter raises warnings about rules that don't have
+    /// some of the required tags. This setting allows turning such warnings
+    /// into errors.
+    pub fn error(mut self, yes: bool) -> Self {
+        self.error = yes;
+        self
+    }
+}
+
+impl LinterInternal for RequiredTags {
+    fn check(
+        &self,
+        report_builder: &ReportBuilder,
+        rule: &ast::Rule,
+    ) -> LinterResult {
+        let mut results: Vec<Warning> = Vec::new();
+
+        for required in self.required.iter() {
+            let found = rule
+                .tags
+                .iter()
+                .flatten()
+                .any(|tag| tag.name == required.as_str());
+
+            if found {
+                continue;
+            }
+
+            if self.error {
+                return LinterResult::Err(errors::MissingTag::build(
+                    report_builder,
+                    report_builder.span_to_code_loc(rule.identifier.span()),
+                    required.clone(),
+                ));
+            } else {
+                results.push(warnings::MissingTag::build(
+                    report_builder,
+                    report_builder.span_to_code_loc(rule.identifier.span()),
+                    required.clone(),
+                ));
+            }
+        }
+
+        if results.is_empty() {
+            LinterResult::Ok
+        } else {
+            LinterResult::Warns(results)
+        }
+    }
+}
+
 /// A linter that validates metadata entries.
 ///
 /// ```
@@ -430,6 +588,14 @@ pub fn tag_regex<R: Into<String>>(regex: R) -> Result<Tags, Error> {
     Tags::from_regex(regex)
 }
 
+/// Creates a tag linter that makes sure that each rule has all the tags in
+/// the given list.
+///
+/// See [`RequiredTags`] for details.
+pub fn tags_required(list: Vec<String>) -> RequiredTags {
+    RequiredTags::new(list)
+}
+
 /// Creates a linter that validates metadata entries.
 ///
 /// See [`Metadata`] for details.
@@ -444,3 +610,11 @@ pub fn metadata<'a, I: Into<String>>(identifier: I) -> Metadata<'a> {
 pub fn rule_name<R: Into<String>>(regex: R) -> Result<RuleName, Error> {
     RuleName::new(regex)
 }
+
+/// Creates a linter that makes sure that rule names are not longer than
+/// `max_len` characters.
+///
+/// See [`RuleNameLength`] for details.
+pub fn rule_name_length(max_len: usize) -> RuleNameLength {
+    RuleNameLength::new(max_len)
+}
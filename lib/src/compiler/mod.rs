@@ -32,27 +32,30 @@ use yara_x_parser::{Parser, Span};
 use crate::compiler::base64::base64_patterns;
 use crate::compiler::emit::{emit_rule_condition, EmitContext};
 use crate::compiler::errors::{
-    CompileError, ConflictingRuleIdentifier, CustomError, DuplicateRule,
-    DuplicateTag, EmitWasmError, InvalidRegexp, InvalidUTF8, UnknownModule,
-    UnusedPattern,
+    CompileError, ConflictingImport, ConflictingModuleAlias,
+    ConflictingRuleIdentifier, CustomError, DuplicateRule, DuplicateTag,
+    EmitWasmError, InvalidRegexp, InvalidUTF8, RuleConflictsWithImport,
+    UnknownModule, UnusedPattern,
 };
 use crate::compiler::report::ReportBuilder;
 use crate::compiler::{CompileContext, VarStack};
-use crate::modules::BUILTIN_MODULES;
+use crate::modules::protos::yara::exts::module_options;
+use crate::modules::{Module, BUILTIN_MODULES};
 use crate::re::hir::{ChainedPattern, ChainedPatternGap};
 use crate::string_pool::{BStringPool, StringPool};
 use crate::symbols::{StackedSymbolTable, Symbol, SymbolLookup, SymbolTable};
-use crate::types::{Func, Struct, TypeValue};
+use crate::types::{Struct, TypeValue};
 use crate::utils::cast;
 use crate::variables::{is_valid_identifier, Variable, VariableError};
 use crate::wasm::builder::WasmModuleBuilder;
-use crate::wasm::{wasm_exports, WasmSymbols};
+use crate::wasm::{WasmExport, WasmSymbols};
 use crate::{re, wasm};
 
 pub(crate) use crate::compiler::atoms::*;
 pub(crate) use crate::compiler::context::*;
 pub(crate) use crate::compiler::ir::*;
 
+use crate::compiler::cost::{PatternCost, WEAK_ATOM_QUALITY};
 use crate::compiler::wsh::WarningSuppressionHook;
 use crate::errors::{
     CircularIncludes, IncludeError, IncludeNotAllowed, IncludeNotFound,
@@ -61,19 +64,25 @@ use crate::errors::{
 use crate::linters::LinterResult;
 use crate::models::PatternKind;
 
+#[doc(inline)]
+pub use crate::compiler::cost::RuleCost;
 #[doc(inline)]
 pub use crate::compiler::report::Patch;
 #[doc(inline)]
 pub use crate::compiler::rules::*;
 #[doc(inline)]
+pub use crate::compiler::stats::RulesStats;
+#[doc(inline)]
 pub use crate::compiler::warnings::*;
 
 mod atoms;
 mod context;
+mod cost;
 mod emit;
 mod ir;
 mod report;
 mod rules;
+mod stats;
 
 #[cfg(test)]
 mod tests;
@@ -130,6 +139,45 @@ impl<'src> SourceCode<'src> {
         Self { raw: self.raw, valid: self.valid, origin: Some(origin.into()) }
     }
 
+    /// Returns the line and column where `span` starts.
+    ///
+    /// Spans in errors and warnings are byte offsets within the source code,
+    /// this function translates them to a (line, column) pair, both 1-based
+    /// and with the column counted in characters. Returns `None` if the span
+    /// is out of bounds or the source code is not valid UTF-8.
+    ///
+    /// ```
+    /// # use yara_x::{SourceCode, Span};
+    /// let src = SourceCode::from("rule test {\n  condition: foo\n}");
+    /// assert_eq!(src.line_col(&Span(25..28)), Some((2, 14)));
+    /// ```
+    pub fn line_col(&self, span: &Span) -> Option<(usize, usize)> {
+        span.start_line_col(self.valid_str()?)
+    }
+
+    /// Returns the fragment of source code covered by `span`.
+    ///
+    /// Returns `None` if the span is out of bounds or the source code is not
+    /// valid UTF-8.
+    ///
+    /// ```
+    /// # use yara_x::{SourceCode, Span};
+    /// let src = SourceCode::from("rule test {\n  condition: foo\n}");
+    /// assert_eq!(src.snippet(&Span(25..28)), Some("foo"));
+    /// ```
+    pub fn snippet(&self, span: &Span) -> Option<&'src str> {
+        span.snippet(self.valid_str()?)
+    }
+
+    /// Returns the source code as a `&str` without caching the result of
+    /// UTF-8 validation.
+    fn valid_str(&self) -> Option<&'src str> {
+        match self.valid {
+            Some(s) => Some(s),
+            None => self.raw.to_str().ok(),
+        }
+    }
+
     /// Returns the source code as a `&str`.
     ///
     /// If the source code is not valid UTF-8 it will return an error.
@@ -197,6 +245,32 @@ where
     Ok(compiler.build())
 }
 
+/// Backend used for executing rule conditions.
+///
+/// Rule conditions are compiled to WebAssembly, which is then executed by
+/// one of these backends. See [`Compiler::backend`].
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum Backend {
+    /// The WebAssembly code is translated to native code for the current
+    /// platform. This is the fastest backend, but requires generating
+    /// native code at runtime.
+    #[default]
+    Native,
+    /// The WebAssembly code is translated to bytecode for Pulley, the
+    /// portable interpreter included in `wasmtime`. The translation is
+    /// still done by `wasmtime` and Cranelift, but no native code is
+    /// generated or executed at runtime, which is not allowed in some
+    /// platforms, like iOS. This is slower than [`Backend::Native`].
+    ///
+    /// When the rules are serialized with the `native-code-serialization`
+    /// feature, the bytecode is included in the serialized rules and it's
+    /// not generated again while deserializing them.
+    #[cfg(feature = "pulley-backend")]
+    Pulley,
+}
+
 /// Structure that contains information about a rule namespace.
 ///
 /// Includes NamespaceId, the IdentId corresponding to the namespace's
@@ -206,6 +280,10 @@ struct Namespace {
     id: NamespaceId,
     ident_id: IdentId,
     symbols: Rc<RefCell<SymbolTable>>,
+    /// Location of the first import statement for each module imported in
+    /// this namespace. Keys are the identifiers used for referring to the
+    /// module, which is the alias if the module was imported with one.
+    imports: FxHashMap<String, CodeLoc>,
 }
 
 /// Compiles YARA source code producing a set of compiled [`Rules`].
@@ -238,11 +316,19 @@ struct Namespace {
 /// ```
 ///
 pub struct Compiler<'a> {
+    /// Backend used for executing the conditions of the compiled rules.
+    backend: Backend,
+
     /// Mimics YARA behaviour with respect to regular expressions, allowing
     /// some constructs that are invalid in YARA-X by default, like invalid
     /// escape sequences.
     relaxed_re_syntax: bool,
 
+    /// If true, text patterns with the `nocase` modifier are matched
+    /// according to Unicode simple case folding rules, instead of ASCII
+    /// case folding.
+    unicode_case_folding: bool,
+
     /// If true, the compiler hoists loop-invariant expressions (i.e: those
     /// that don't vary on each iteration of the loop), moving them outside
     /// the loop.
@@ -261,10 +347,24 @@ pub struct Compiler<'a> {
     /// Like for example: `for all x in (0..filesize) : (...)`
     error_on_slow_loop: bool,
 
+    /// If set, rules with an estimated cost higher than this value produce
+    /// a warning. See [`RuleCost::score`].
+    costly_rule_threshold: Option<u64>,
+
     /// If true, include statements are allowed. If false, include statements
     /// will produce a compile error.
     includes_enabled: bool,
 
+    /// Source code successfully compiled so far, together with the namespace
+    /// where it was compiled. This is `None` unless source embedding was
+    /// enabled with [`Compiler::embed_sources`].
+    embedded_sources: Option<Vec<(String, String)>>,
+
+    /// True if the compiler received input that is not embedded in the
+    /// rules together with their source code, like global variables, ASTs
+    /// or included files. See [`Compiler::embed_sources`].
+    non_embedded_input: bool,
+
     /// Tracks the paths of the files that have been included by nested
     /// includes. This is useful for detecting circular includes and resolving
     /// relative includes.
@@ -352,6 +452,16 @@ pub struct Compiler<'a> {
     /// by some other rule. If that's the case, that same pattern is re-used.
     patterns: FxHashMap<Pattern, PatternId>,
 
+    /// Patterns contributed by the imported modules. Keys are module names,
+    /// values are maps that associate the identifier of each pattern declared
+    /// by the module to its `PatternId`.
+    module_patterns: FxHashMap<String, FxHashMap<String, PatternId>>,
+
+    /// Cost of each pattern, which is computed when the pattern is
+    /// processed for the first time, and re-used by any other rule that
+    /// declares the same pattern.
+    pattern_costs: FxHashMap<PatternId, PatternCost>,
+
     /// A vector with all the sub-patterns from all the rules. A
     /// [`SubPatternId`] is an index in this vector.
     sub_patterns: Vec<(PatternId, SubPattern)>,
@@ -389,11 +499,28 @@ pub struct Compiler<'a> {
     /// if the banned module is imported.
     banned_modules: FxHashMap<String, (String, String)>,
 
+    /// Keys in this map are the symbols that are banned (e.g: `pe.imphash`),
+    /// and values are a pair of strings with the title and message for the
+    /// error that will be shown if the banned symbol is used.
+    banned_symbols: FxHashMap<String, (String, String)>,
+
     /// Keys in this map are the name of rules that will be ignored because they
     /// depend on unsupported modules, either directly or indirectly. Values are
     /// the names of the unsupported modules they depend on.
     ignored_rules: FxHashMap<String, String>,
 
+    /// Features used for selecting which rules are compiled. When this is
+    /// `Some`, rules that declare a set of features in their `features`
+    /// metadata are compiled only if some of their features is in this set.
+    /// See [`Compiler::with_features`] for details.
+    rule_features: Option<FxHashSet<String>>,
+
+    /// Names of the rules in the current namespace that were excluded from
+    /// compilation because their features don't match the ones passed to
+    /// [`Compiler::with_features`], or because they depend on some other
+    /// excluded rule.
+    excluded_rules: FxHashSet<String>,
+
     /// Structure where each field corresponds to a global identifier or a module
     /// imported by the rules. For fields corresponding to modules, the value is
     /// the structure that describes the module.
@@ -426,15 +553,15 @@ impl<'a> Compiler<'a> {
 
         let global_symbols = symbol_table.push_new();
 
-        // Add symbols for built-in functions like uint8, uint16, etc.
-        for export in wasm_exports()
-            // Get only the public exports not belonging to a YARA module.
-            .filter(|e| e.public && e.builtin())
+        // Add symbols for built-in functions like uint8, uint16, etc. Get
+        // only the public exports not belonging to a YARA module. Overloaded
+        // functions like `min` appear as a single function with multiple
+        // signatures.
+        for (name, func) in
+            WasmExport::get_functions(|e| e.public && e.builtin())
         {
-            let func = Rc::new(Func::from(export.mangled_name));
-            let symbol = Symbol::Func(func);
-
-            global_symbols.borrow_mut().insert(export.name, symbol);
+            let symbol = Symbol::Func(Rc::new(func));
+            global_symbols.borrow_mut().insert(name, symbol);
         }
 
         // Create the default namespace. Rule identifiers will be added to this
@@ -444,6 +571,7 @@ impl<'a> Compiler<'a> {
             id: NamespaceId(0),
             ident_id: ident_pool.get_or_intern("default"),
             symbols: symbol_table.push_new(),
+            imports: FxHashMap::default(),
         };
 
         // At this point the symbol table (which is a stacked symbol table) has
@@ -476,10 +604,15 @@ impl<'a> Compiler<'a> {
             wasm_mod,
             wasm_symbols,
             wasm_exports,
+            backend: Backend::default(),
             relaxed_re_syntax: false,
+            unicode_case_folding: false,
+            embedded_sources: None,
+            non_embedded_input: false,
             hoisting: false,
             error_on_slow_pattern: false,
             error_on_slow_loop: false,
+            costly_rule_threshold: None,
             next_pattern_id: PatternId(0),
             current_namespace: default_namespace,
             features: FxHashSet::default(),
@@ -493,13 +626,18 @@ impl<'a> Compiler<'a> {
             imported_modules: Vec::new(),
             ignored_modules: FxHashSet::default(),
             banned_modules: FxHashMap::default(),
+            banned_symbols: FxHashMap::default(),
             ignored_rules: FxHashMap::default(),
+            rule_features: None,
+            excluded_rules: FxHashSet::default(),
             filesize_bounds: FxHashMap::default(),
             root_struct: Struct::new().make_root(),
             report_builder: ReportBuilder::new(),
             lit_pool: BStringPool::new(),
             regexp_pool: StringPool::new(),
             patterns: FxHashMap::default(),
+            module_patterns: FxHashMap::default(),
+            pattern_costs: FxHashMap::default(),
             ir_writer: None,
             linters: Vec::new(),
             include_dirs: None,
@@ -552,6 +690,11 @@ impl<'a> Compiler<'a> {
     /// found during compilation are also recorded and can be retrieved using
     /// [`Compiler::errors`].
     ///
+    /// An error in one rule doesn't prevent the compiler from processing the
+    /// remaining rules in the source code, so a single call to this function
+    /// can produce multiple errors. These errors are recorded in the same order
+    /// in which they appear in the source code.
+    ///
     /// Even if previous calls to this function resulted in compilation errors,
     /// you may continue adding additional rules. Only successfully compiled rules
     /// will be included in the final rule set.
@@ -575,6 +718,17 @@ impl<'a> Compiler<'a> {
         // if otherwise.
         let ast = match src.as_str() {
             Ok(src) => {
+                if let Some(sources) = self.embedded_sources.as_mut() {
+                    let namespace = self
+                        .ident_pool
+                        .get(self.current_namespace.ident_id)
+                        .unwrap();
+                    sources.push((namespace.to_string(), src.to_string()));
+                }
+
+                #[cfg(feature = "tracing")]
+                let _span = tracing::info_span!("parse").entered();
+
                 // Parse the source code and build the Abstract Syntax Tree.
                 let cst = Parser::new(src.as_bytes());
                 let cst =
@@ -610,26 +764,77 @@ impl<'a> Compiler<'a> {
             }
         };
 
-        // Store the current length of the `errors` vector, so that we can
-        // know if more errors were added.
-        let existing_errors = self.errors.len();
-
-        self.c_items(ast.items());
-
-        self.warnings.clear_suppressed();
-
-        self.errors.extend(
-            ast.into_errors()
-                .into_iter()
-                .map(|err| CompileError::from(&self.report_builder, err)),
-        );
-
-        // More errors were added? Return the first error that was added.
-        if self.errors.len() > existing_errors {
-            return Err(self.errors[existing_errors].clone());
+        match self.c_ast(ast) {
+            Ok(_) => Ok(self),
+            Err(err) => {
+                // Source code that doesn't compile is not embedded in the
+                // rules, as the rules don't include it either.
+                if let Some(sources) = self.embedded_sources.as_mut() {
+                    sources.pop();
+                }
+                Err(err)
+            }
         }
+    }
 
-        Ok(self)
+    /// Adds the rules in an [`AST`] to be compiled.
+    ///
+    /// This allows tools that build or transform rules programmatically,
+    /// like rule generators or obfuscators, to feed them to the compiler
+    /// directly, without serializing them back to text and parsing them
+    /// again. The AST is usually obtained by parsing some source code with
+    /// [`AST::from`] and modifying it afterward.
+    ///
+    /// Errors and warnings are handled exactly as in [`Compiler::add_source`],
+    /// including the syntax errors already present in the AST. However, the
+    /// compiler doesn't have access to the source code the AST comes from,
+    /// so error reports can't show the code where the issue is located. Use
+    /// [`Compiler::add_ast_with_source`] if that source code is available.
+    ///
+    /// ```
+    /// # use yara_x::Compiler;
+    /// use yara_x::ast::{Item, AST};
+    ///
+    /// let mut ast = AST::from(r#"
+    /// rule foo { condition: true }
+    /// rule bar { condition: false }
+    /// "#);
+    ///
+    /// // Keep only the rule named `foo`.
+    /// ast.items.retain(|item| {
+    ///     matches!(item, Item::Rule(rule) if rule.identifier.name == "foo")
+    /// });
+    ///
+    /// let mut compiler = Compiler::new();
+    /// compiler.add_ast(ast)?;
+    ///
+    /// let rules = compiler.build();
+    /// assert_eq!(rules.iter().len(), 1);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn add_ast(
+        &mut self,
+        ast: AST<'_>,
+    ) -> Result<&mut Self, CompileError> {
+        self.add_ast_with_source(ast, "")
+    }
+
+    /// Like [`Compiler::add_ast`], but receives the source code that the
+    /// AST was created from.
+    ///
+    /// The source code is used only in error and warning reports. Spans in
+    /// the AST that fall outside the source code are reported at its end.
+    pub fn add_ast_with_source<'src, S>(
+        &mut self,
+        ast: AST<'_>,
+        src: S,
+    ) -> Result<&mut Self, CompileError>
+    where
+        S: Into<SourceCode<'src>>,
+    {
+        self.report_builder.register_source(&src.into());
+        self.non_embedded_input = true;
+        self.c_ast(ast)
     }
 
     /// Defines a global variable and sets its initial value.
@@ -684,6 +889,8 @@ impl<'a> Compiler<'a> {
             .borrow_mut()
             .insert(ident, self.root_struct.lookup(ident).unwrap());
 
+        self.non_embedded_input = true;
+
         Ok(self)
     }
 
@@ -740,8 +947,10 @@ impl<'a> Compiler<'a> {
             id: NamespaceId(self.current_namespace.id.0 + 1),
             ident_id: self.ident_pool.get_or_intern(namespace),
             symbols: self.symbol_table.push_new(),
+            imports: FxHashMap::default(),
         };
         self.ignored_rules.clear();
+        self.excluded_rules.clear();
         self.wasm_mod.new_namespace();
         self
     }
@@ -761,11 +970,16 @@ impl<'a> Compiler<'a> {
         // if the WASM code is invalid, which should not happen as the code is
         // emitted by YARA itself. If this ever happens is probably because
         // wrong WASM code is being emitted.
-        let compiled_wasm_mod = wasmtime::Module::from_binary(
-            wasm::get_engine(),
-            wasm_mod.as_slice(),
-        )
-        .expect("WASM module is not valid");
+        let compiled_wasm_mod = {
+            #[cfg(feature = "tracing")]
+            let _span = tracing::info_span!("wasm_compile").entered();
+
+            wasmtime::Module::from_binary(
+                wasm::get_engine_for(self.backend),
+                wasm_mod.as_slice(),
+            )
+            .expect("WASM module is not valid")
+        };
 
         #[cfg(feature = "logging")]
         info!("WASM module build time: {:?}", Instant::elapsed(&start));
@@ -787,10 +1001,22 @@ impl<'a> Compiler<'a> {
         )
         .expect("failed to serialize global variables");
 
+        // The rules can be compiled again from the embedded source code
+        // only if the result doesn't depend on anything else.
+        let recompilable = self.embedded_sources.is_some()
+            && !self.non_embedded_input
+            && self.rule_features.is_none()
+            && self.features.is_empty()
+            && self.ignored_modules.is_empty()
+            && !self.unicode_case_folding
+            && !self.hoisting
+            && self.backend == Backend::default();
+
         let mut rules = Rules {
             serialized_globals,
             wasm_mod,
             compiled_wasm_mod: Some(compiled_wasm_mod),
+            backend: self.backend,
             relaxed_re_syntax: self.relaxed_re_syntax,
             ac: None,
             num_patterns: self.next_pattern_id.0 as usize,
@@ -805,9 +1031,14 @@ impl<'a> Compiler<'a> {
             re_code: self.re_code,
             warnings: self.warnings.into(),
             filesize_bounds: self.filesize_bounds,
+            module_patterns: self.module_patterns,
+            regexps: Vec::new(),
+            embedded_sources: self.embedded_sources,
+            recompilable,
         };
 
         rules.build_ac_automaton();
+        rules.build_regexps();
         rules
     }
 
@@ -824,6 +1055,56 @@ impl<'a> Compiler<'a> {
         self
     }
 
+    /// Compiles only the rules that match the given features.
+    ///
+    /// Rules can declare the features they are intended for by using the
+    /// `features` metadata, which contains a comma-separated list of features.
+    /// When this function is called, a rule that declares some features is
+    /// compiled only if at least one of them is among the given features,
+    /// otherwise the rule is excluded as if it didn't exist. Rules that don't
+    /// declare any feature are always compiled. Rules that depend on excluded
+    /// rules are also excluded.
+    ///
+    /// This allows producing per-platform bundles from a single set of
+    /// rules. If this function is called multiple times, the features are
+    /// accumulated.
+    ///
+    /// ```
+    /// # use yara_x::Compiler;
+    /// let mut compiler = Compiler::new();
+    ///
+    /// compiler
+    ///     .with_features(["win"])
+    ///     .add_source(r#"
+    ///         rule for_windows {
+    ///           meta:
+    ///             features = "win"
+    ///           condition:
+    ///             true
+    ///         }
+    ///         rule for_linux_and_macos {
+    ///           meta:
+    ///             features = "elf, macho"
+    ///           condition:
+    ///             true
+    ///         }"#)?;
+    ///
+    /// let rules = compiler.build();
+    ///
+    /// assert_eq!(rules.iter().len(), 1);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_features<I, F>(&mut self, features: I) -> &mut Self
+    where
+        I: IntoIterator<Item = F>,
+        F: Into<String>,
+    {
+        self.rule_features
+            .get_or_insert_with(FxHashSet::default)
+            .extend(features.into_iter().map(|f| f.into()));
+        self
+    }
+
     /// Enables a feature on this compiler.
     ///
     /// When defining the structure of a module in a `.proto` file, you can
@@ -909,6 +1190,47 @@ impl<'a> Compiler<'a> {
         self
     }
 
+    /// Tell the compiler that a symbol can't be used in rule conditions.
+    ///
+    /// The symbol can be a module (e.g: `pe`), a field or function within
+    /// a module (e.g: `pe.imphash`, `math.entropy`), a global function (e.g:
+    /// `uint32`), or the `filesize` keyword. Using a banned symbol, or any
+    /// field or function inside a banned structure, causes an error with the
+    /// given title and message. Array and map
+    /// indexes are not part of the symbol's name, for instance, banning
+    /// `pe.sections.name` forbids the use of `pe.sections[0].name`.
+    ///
+    /// Unlike [`Compiler::ban_module`], banning a module with this function
+    /// doesn't prevent it from being imported, only its usage is forbidden.
+    ///
+    /// If this function is called multiple times with the same symbol, the
+    /// error title and message will be updated.
+    ///
+    /// ```
+    /// # use yara_x::Compiler;
+    /// let mut compiler = Compiler::new();
+    ///
+    /// compiler.ban_symbol(
+    ///     "filesize",
+    ///     "`filesize` can't be used",
+    ///     "`filesize` used here",
+    /// );
+    ///
+    /// assert!(compiler
+    ///     .add_source("rule test { condition: filesize < 100 }")
+    ///     .is_err());
+    /// ```
+    pub fn ban_symbol<S: Into<String>, T: Into<String>, E: Into<String>>(
+        &mut self,
+        symbol: S,
+        error_title: T,
+        error_message: E,
+    ) -> &mut Self {
+        self.banned_symbols
+            .insert(symbol.into(), (error_title.into(), error_message.into()));
+        self
+    }
+
     /// Specifies whether the compiler should produce colorful error messages.
     ///
     /// Colorized error messages contain ANSI escape sequences that make them
@@ -976,6 +1298,34 @@ impl<'a> Compiler<'a> {
         self
     }
 
+    /// Enables Unicode case folding for text patterns with the `nocase`
+    /// modifier.
+    ///
+    /// By default, `nocase` only folds ASCII characters, so `"árbol" nocase`
+    /// matches `"ÁRBOL"` only if the accented letters have the same case. When
+    /// this setting is enabled, text patterns that contain characters with
+    /// non-ASCII case variants are matched according to Unicode simple case
+    /// folding rules, and the `wide` modifier matches the UTF-16LE encoding
+    /// of the text, instead of interleaving zeroes between its UTF-8 bytes.
+    ///
+    /// Notice that `icontains`, `iequals`, `istartswith` and `iendswith`
+    /// always take Unicode into account when comparing valid UTF-8 strings.
+    ///
+    /// This is disabled by default.
+    pub fn unicode_case_folding(&mut self, yes: bool) -> &mut Self {
+        self.unicode_case_folding = yes;
+        self
+    }
+
+    /// Sets the backend used for executing the conditions of the compiled
+    /// rules.
+    ///
+    /// The default is [`Backend::Native`].
+    pub fn backend(&mut self, backend: Backend) -> &mut Self {
+        self.backend = backend;
+        self
+    }
+
     /// When enabled, slow patterns produce an error instead of a warning.
     ///
     /// This is disabled by default.
@@ -993,6 +1343,19 @@ impl<'a> Compiler<'a> {
         self
     }
 
+    /// Produces a [`CostlyRule`] warning for every rule with an estimated
+    /// cost higher than `threshold`.
+    ///
+    /// The cost of each rule is computed no matter this setting, and can be
+    /// obtained from the compiled rules with [`crate::Rule::cost`]. See
+    /// [`RuleCost::score`] for details about how the cost is estimated.
+    ///
+    /// This is disabled by default.
+    pub fn warn_on_costly_rules(&mut self, threshold: u64) -> &mut Self {
+        self.costly_rule_threshold = Some(threshold);
+        self
+    }
+
     /// Controls whether `include` statements are allowed.
     ///
     /// By default, the compiler allows the use of `include` statements, which
@@ -1009,6 +1372,27 @@ impl<'a> Compiler<'a> {
         self
     }
 
+    /// Controls whether the source code is embedded in the compiled rules.
+    ///
+    /// When enabled, the source code passed to [`Compiler::add_source`] is
+    /// stored in the resulting [`Rules`] and included in their serialized
+    /// form. This allows [`Rules::deserialize_or_recompile`] to compile the
+    /// rules again when they were serialized in a format that is not
+    /// compatible with the current version of YARA-X.
+    ///
+    /// Only the source code itself is embedded. Rules that also depend on
+    /// included files, global variables, ASTs added with
+    /// [`Compiler::add_ast`], or compiler settings other than
+    /// [`Compiler::relaxed_re_syntax`] are not recompiled, as the result
+    /// could differ from the original rules.
+    ///
+    /// This is disabled by default, and should be called before any source
+    /// code is added to the compiler.
+    pub fn embed_sources(&mut self, yes: bool) -> &mut Self {
+        self.embedded_sources = if yes { Some(Vec::new()) } else { None };
+        self
+    }
+
     /// When enabled, the compiler tries to optimize rule conditions.
     ///
     /// The optimizations usually reduce condition evaluation times, specially
@@ -1058,11 +1442,49 @@ impl<'a> Compiler<'a> {
         Ok(wasm_mod.emit_wasm_file(path)?)
     }
 
+    /// Emits a `.wat` file with the WASM module generated by the compiler,
+    /// in WebAssembly text format.
+    ///
+    /// This is similar to [`Compiler::emit_wasm_file`], but the output is
+    /// human-readable and doesn't require third-party tooling. Functions
+    /// containing the code for rule conditions are named after the rules
+    /// they contain, using the `namespace.rule` syntax.
+    #[cfg(feature = "emit-wat")]
+    pub fn emit_wat_file<P>(self, path: P) -> Result<(), EmitWasmError>
+    where
+        P: AsRef<Path>,
+    {
+        let rules = &self.rules;
+        let ident_pool = &self.ident_pool;
+
+        let mut wasm_mod = self.wasm_mod.build_with_names(|rule_id| {
+            let rule = &rules[usize::from(rule_id)];
+            format!(
+                "{}.{}",
+                ident_pool.get(rule.namespace_ident_id).unwrap(),
+                ident_pool.get(rule.ident_id).unwrap()
+            )
+        });
+
+        let wat = wasmprinter::print_bytes(wasm_mod.emit_wasm())?;
+        fs::write(path, wat).map_err(anyhow::Error::from)?;
+
+        Ok(())
+    }
+
     /// Sets a writer where the compiler will write the Intermediate
     /// Representation (IR) of compiled conditions.
     ///
-    /// This is used for testing and debugging purposes.
-    #[doc(hidden)]
+    /// The IR is the representation of rule conditions from which the WASM
+    /// code is emitted, after transformations like constant folding and
+    /// hoisting have been applied, so it shows what the scanner will
+    /// actually evaluate. This is useful for auditing rules and for
+    /// debugging purposes.
+    ///
+    /// For each rule the compiler writes a line with `RULE` followed by the
+    /// rule's identifier, and then the rule's condition as a tree where each
+    /// line is an expression, with its sub-expressions indented below it.
+    /// The format is meant for humans, and it may change between versions.
     pub fn set_ir_writer<W: Write + 'static>(&mut self, w: W) -> &mut Self {
         self.ir_writer = Some(Box::new(w));
         self
@@ -1116,6 +1538,25 @@ impl Compiler<'_> {
                         .ident_ref
                         .clone(),
                 )),
+                // Built-in functions like `min` or `max` can be shadowed by
+                // rules, so that existing rules using those names are still
+                // accepted.
+                Symbol::Func(_) => Ok(()),
+                // Found a module imported in this namespace, possibly in
+                // another source file.
+                Symbol::Field { .. }
+                    if self
+                        .current_namespace
+                        .imports
+                        .contains_key(ident.name) =>
+                {
+                    Err(RuleConflictsWithImport::build(
+                        &self.report_builder,
+                        ident.name.to_string(),
+                        self.report_builder.span_to_code_loc(ident.span()),
+                        self.current_namespace.imports[ident.name].clone(),
+                    ))
+                }
                 // Found another symbol that is not a rule, but has the same
                 // name.
                 _ => Err(ConflictingRuleIdentifier::build(
@@ -1128,6 +1569,34 @@ impl Compiler<'_> {
         Ok(())
     }
 
+    /// Returns true if the rule is enabled by [`Compiler::with_features`].
+    fn rule_matches_features(&self, rule: &ast::Rule) -> bool {
+        let Some(enabled_features) = &self.rule_features else {
+            return true;
+        };
+
+        let mut rule_features = rule
+            .meta
+            .iter()
+            .flatten()
+            .filter(|m| m.identifier.name == "features")
+            .filter_map(|m| match &m.value {
+                ast::MetaValue::String((s, _)) => Some(*s),
+                _ => None,
+            })
+            .flat_map(|s| s.split(','))
+            .map(|f| f.trim())
+            .filter(|f| !f.is_empty())
+            .peekable();
+
+        // Rules that don't declare any feature are always compiled.
+        if rule_features.peek().is_none() {
+            return true;
+        }
+
+        rule_features.any(|f| enabled_features.contains(f))
+    }
+
     /// Checks that tags are not duplicate.
     fn check_for_duplicate_tags(
         &self,
@@ -1203,6 +1672,56 @@ impl Compiler<'_> {
             .retain(|pattern_id, _| *pattern_id < snapshot.next_pattern_id);
     }
 
+    /// Emits a [`warnings::WeakAtom`] warning if the worst atom among the
+    /// ones in `self.atoms[atoms_start..]` has a low quality.
+    ///
+    /// Regexp and hex patterns with atoms shorter than 2 bytes are ignored,
+    /// as they already produce a [`warnings::SlowPattern`] warning. Regexp
+    /// and hex patterns that produce more than one atom are ignored too,
+    /// because for them [`warnings::SlowPattern`] takes into account the
+    /// number of atoms, not only their quality.
+    fn check_weak_atoms(
+        &mut self,
+        atoms_start: usize,
+        is_literal: bool,
+        pattern_span: Span,
+    ) {
+        let atoms = &self.atoms[atoms_start..];
+
+        if !is_literal && atoms.len() > 1 {
+            return;
+        }
+
+        let Some(atom) = atoms
+            .iter()
+            .map(|atom| atom.as_slice())
+            .min_by_key(|atom| atom_quality(*atom))
+        else {
+            return;
+        };
+
+        if atom_quality(atom) >= WEAK_ATOM_QUALITY
+            || (!is_literal && atom.len() < 2)
+        {
+            return;
+        }
+
+        let atom = if atom.iter().all(|b| b.is_ascii_graphic() || *b == b' ') {
+            format!("\"{}\"", atom.escape_ascii())
+        } else {
+            format!("{{ {:02X} }}", atom.iter().format(" "))
+        };
+
+        self.warnings.add(|| {
+            warnings::WeakAtom::build(
+                &self.report_builder,
+                atom,
+                self.report_builder.span_to_code_loc(pattern_span),
+                Some("short or repetitive atoms appear very often in the scanned data, which slows down the scan".to_string()),
+            )
+        });
+    }
+
     /// Returns true if the bytes in the slice are all 0x00, 0x90, or 0xff.
     fn common_byte_repetition(bytes: &[u8]) -> bool {
         let mut all_x00 = true;
@@ -1235,7 +1754,8 @@ impl Compiler<'_> {
 
     /// Reads the file specified by an `include` statement.
     ///
-    /// Tries to read the file in the include directories that were specified
+    /// Tries to read the file relative to the directory of the including
+    /// file first, and then in the include directories that were specified
     /// with [`Compiler::add_include_dir`], or in the current directory, if
     /// no include directories were specified.
     ///
@@ -1262,10 +1782,24 @@ impl Compiler<'_> {
                 Ok((content, path))
             };
 
-        // Look for the included file in the directory at the top of the
-        // include stack.
-        if let Some(dir) =
-            self.include_stack.last().and_then(|path| path.parent())
+        // Look for the included file in the directory of the including
+        // file, which is the one at the top of the include stack. If the
+        // stack is empty, the including file is the source code passed to
+        // `add_source`, and its origin is used as its path, if any.
+        let including_path =
+            self.include_stack.last().cloned().or_else(|| {
+                self.report_builder
+                    .get_current_source_id()
+                    .and_then(|source_id| {
+                        self.report_builder.get_origin(source_id)
+                    })
+                    .map(PathBuf::from)
+            });
+
+        if let Some(dir) = including_path
+            .as_deref()
+            .and_then(|path| path.parent())
+            .filter(|dir| !dir.as_os_str().is_empty())
         {
             if let Ok(result) = read_file(dir.join(include.file_name)) {
                 return Ok(result);
@@ -1309,21 +1843,63 @@ impl Compiler<'_> {
 }
 
 impl Compiler<'_> {
-    fn c_items<'a, I>(&mut self, items: I)
+    /// Compiles the items in an AST that was produced from the source code
+    /// most recently registered in the report builder.
+    fn c_ast(&mut self, ast: AST) -> Result<&mut Self, CompileError> {
+        // Store the current length of the `errors` vector, so that we can
+        // know if more errors were added.
+        let existing_errors = self.errors.len();
+
+        self.c_items(ast.items(), ast.errors());
+
+        self.warnings.clear_suppressed();
+
+        // More errors were added? Return the first error that was added.
+        if self.errors.len() > existing_errors {
+            return Err(self.errors[existing_errors].clone());
+        }
+
+        Ok(self)
+    }
+
+    /// Compiles the top-level items in a source file.
+    ///
+    /// The syntax errors found while parsing the source file are added to
+    /// `self.errors` interleaved with the errors produced while compiling
+    /// the items, so that errors are sorted in the same order in which they
+    /// appear in the source code.
+    fn c_items<'a, I>(&mut self, items: I, syntax_errors: &[ast::Error])
     where
         I: Iterator<Item = &'a ast::Item<'a>>,
     {
         let mut already_imported = FxHashMap::default();
+        let mut syntax_errors = syntax_errors.iter().peekable();
 
         for item in items {
+            let item_start = match item {
+                ast::Item::Import(import) => import.span().start(),
+                ast::Item::Include(include) => include.span().start(),
+                ast::Item::Rule(rule) => rule.identifier.span().start(),
+            };
+
+            while let Some(err) =
+                syntax_errors.next_if(|err| err.span().start() < item_start)
+            {
+                self.errors.push(CompileError::from(
+                    &self.report_builder,
+                    err.clone(),
+                ));
+            }
+
             match item {
                 ast::Item::Import(import) => {
                     // Checks that all imported modules actually exist, and
                     // raise warnings in case of duplicated imports within
                     // the same source file. For each module add a symbol to
-                    // the current namespace.
+                    // the current namespace. Importing the same module with
+                    // different aliases is not a duplicate import.
                     if let Some(existing_import) = already_imported.insert(
-                        &import.module_name,
+                        (import.module_name, import.ident()),
                         self.report_builder.span_to_code_loc(import.span()),
                     ) {
                         let duplicated_import = self
@@ -1405,6 +1981,7 @@ impl Compiler<'_> {
                         );
 
                     self.include_stack.push(included_path);
+                    self.non_embedded_input = true;
 
                     // Any error generated while processing the included source
                     // code will be added to `self.errors`. The error returned
@@ -1425,6 +2002,11 @@ impl Compiler<'_> {
                 }
             }
         }
+
+        for err in syntax_errors {
+            self.errors
+                .push(CompileError::from(&self.report_builder, err.clone()));
+        }
     }
 
     fn c_rule(&mut self, rule: &ast::Rule) -> Result<(), CompileError> {
@@ -1437,6 +2019,13 @@ impl Compiler<'_> {
             self.check_for_duplicate_tags(tags.as_slice())?;
         }
 
+        // Skip the rule if its features don't match the ones selected with
+        // `with_features`.
+        if !self.rule_matches_features(rule) {
+            self.excluded_rules.insert(rule.identifier.name.to_string());
+            return Ok(());
+        }
+
         // Check the rule with all the linters.
         for linter in self.linters.iter() {
             match linter.check(&self.report_builder, rule) {
@@ -1499,9 +2088,15 @@ impl Compiler<'_> {
 
         let mut rule_patterns = Vec::new();
 
+        #[cfg(feature = "tracing")]
+        let semcheck_span =
+            tracing::info_span!("semcheck", rule = rule.identifier.name)
+                .entered();
+
         let mut ctx = CompileContext {
             ir: &mut self.ir,
             relaxed_re_syntax: self.relaxed_re_syntax,
+            unicode_case_folding: self.unicode_case_folding,
             error_on_slow_loop: self.error_on_slow_loop,
             one_shot_symbol_table: None,
             symbol_table: &mut self.symbol_table,
@@ -1511,6 +2106,7 @@ impl Compiler<'_> {
             vars: VarStack::new(),
             for_of_depth: 0,
             features: &self.features,
+            banned_symbols: &self.banned_symbols,
             loop_iteration_multiplier: 1,
         };
 
@@ -1529,6 +2125,9 @@ impl Compiler<'_> {
 
         drop(ctx);
 
+        #[cfg(feature = "tracing")]
+        drop(semcheck_span);
+
         // Search for patterns that are very common byte repetitions like:
         //
         //   00 00 00 00 00 00 ....
@@ -1548,7 +2147,9 @@ impl Compiler<'_> {
         // Note: this can't be done before calling `rule_condition_from_ast`,
         // because we don't know which patterns are anchored until the condition
         // is processed.
-        for pat in rule_patterns.iter() {
+        let mut slow_patterns = HashSet::new();
+
+        for (i, pat) in rule_patterns.iter().enumerate() {
             if pat.anchored_at().is_none()
                 && !pat.pattern().flags().intersects(
                     PatternFlags::Xor
@@ -1564,6 +2165,7 @@ impl Compiler<'_> {
                 };
                 if let Some(literal_bytes) = literal_bytes {
                     if Self::common_byte_repetition(literal_bytes) {
+                        slow_patterns.insert(i);
                         self.warnings.add(|| {
                             warnings::SlowPattern::build(
                                 &self.report_builder,
@@ -1583,6 +2185,13 @@ impl Compiler<'_> {
         // the error is tolerated and a warning is issued instead.
         let mut condition = match condition {
             Ok(condition) => condition,
+            Err(CompileError::UnknownIdentifier(unknown))
+                if self.excluded_rules.contains(unknown.identifier()) =>
+            {
+                self.restore_snapshot(snapshot);
+                self.excluded_rules.insert(rule.identifier.name.to_string());
+                return Ok(());
+            }
             Err(CompileError::UnknownIdentifier(unknown))
                 if self.ignored_rules.contains_key(unknown.identifier())
                     || self.ignored_modules.contains(unknown.identifier()) =>
@@ -1634,10 +2243,16 @@ impl Compiler<'_> {
             condition = self.ir.hoisting();
         }
 
+        self.ir.bulk_lookups();
+
         // Analyze the condition and determine the bounds it imposes to
         // `filesize`, if any.
         let filesize_bounds = self.ir.filesize_bounds();
 
+        // Estimate the cost of the condition. The cost of the patterns is
+        // added later, once they have been processed.
+        let mut cost = self.ir.cost();
+
         // Set the bounds to all patterns in the rule. This must be done
         // before assigning the PatternId to each pattern, as the filesize
         // bounds are taken into account when determining if the pattern
@@ -1732,23 +2347,35 @@ impl Compiler<'_> {
             num_private_patterns,
             is_global: rule.flags.contains(RuleFlags::Global),
             is_private: rule.flags.contains(RuleFlags::Private),
+            cost: RuleCost::default(),
             namespace_id: self.current_namespace.id,
             namespace_ident_id: self.current_namespace.ident_id,
             ident_id: self.ident_pool.get_or_intern(rule.identifier.name),
             ident_ref: self
                 .report_builder
                 .span_to_code_loc(rule.identifier.span()),
+            origin: self
+                .report_builder
+                .get_current_source_id()
+                .and_then(|source_id| {
+                    self.report_builder.get_origin(source_id)
+                })
+                .map(|origin| self.lit_pool.get_or_intern(origin)),
         });
 
         // Process the patterns in the rule. This extracts the best atoms
         // from each pattern, adding them to the `self.atoms` vector, it
         // also creates one or more sub-patterns per pattern and adds them
         // to `self.sub_patterns`
-        for (pattern_id, pattern) in
-            izip!(pattern_ids.iter(), rule_patterns.into_iter())
+        for (i, (pattern_id, pattern)) in
+            izip!(pattern_ids.iter(), rule_patterns.into_iter()).enumerate()
         {
             if pending_patterns.contains(pattern_id) {
                 let pattern_span = pattern.span().clone();
+                let pattern_flags = *pattern.pattern().flags();
+                let is_literal = matches!(pattern.pattern(), Pattern::Text(_));
+                let atoms_start = self.atoms.len();
+                let sub_patterns_start = self.sub_patterns.len();
                 match pattern.into_pattern() {
                     Pattern::Text(pattern) => {
                         self.c_literal_pattern(*pattern_id, pattern);
@@ -1757,7 +2384,7 @@ impl Compiler<'_> {
                         if let Err(err) = self.c_regexp_pattern(
                             *pattern_id,
                             pattern,
-                            pattern_span,
+                            pattern_span.clone(),
                         ) {
                             self.restore_snapshot(snapshot);
                             return Err(err);
@@ -1773,10 +2400,61 @@ impl Compiler<'_> {
                     // This should not happen.
                     panic!("modifying the file size bounds of an existing pattern")
                 }
+                self.pattern_costs.insert(
+                    *pattern_id,
+                    PatternCost::new(
+                        self.sub_patterns[sub_patterns_start..]
+                            .iter()
+                            .map(|(_, sub_pattern)| sub_pattern),
+                        &self.atoms[atoms_start..],
+                    ),
+                );
+                // Atoms for xor and base64 patterns are derived from the
+                // original ones, they are not considered here. Patterns
+                // that already produced a slow pattern warning are ignored.
+                if !slow_patterns.contains(&i)
+                    && !pattern_flags.intersects(
+                        PatternFlags::Xor
+                            | PatternFlags::Base64
+                            | PatternFlags::Base64Wide,
+                    )
+                {
+                    self.check_weak_atoms(
+                        atoms_start,
+                        is_literal,
+                        pattern_span,
+                    );
+                }
                 pending_patterns.remove(pattern_id);
             }
         }
 
+        // Add the cost of the patterns to the cost of the rule. Patterns
+        // declared more than once in the same rule are counted only once.
+        for pattern_id in pattern_ids.iter().unique() {
+            if let Some(pattern_cost) = self.pattern_costs.get(pattern_id) {
+                cost.add_pattern(pattern_cost);
+            }
+        }
+
+        if let Some(threshold) = self.costly_rule_threshold {
+            let score = cost.score();
+            if score > threshold {
+                self.warnings.add(|| {
+                    warnings::CostlyRule::build(
+                        &self.report_builder,
+                        rule.identifier.name.to_string(),
+                        score,
+                        self.report_builder
+                            .span_to_code_loc(rule.identifier.span()),
+                        Some(format!("the cost is due to: {cost}")),
+                    )
+                });
+            }
+        }
+
+        self.rules.last_mut().unwrap().cost = cost;
+
         // Create a new symbol of bool type for the rule.
         let new_symbol = Symbol::Rule {
             rule_id,
@@ -1802,8 +2480,13 @@ impl Compiler<'_> {
         // because once the code is emitted it cannot be undone, which means
         // that if this function fails after emitting the code, some code debris
         // will remain in the WASM module.
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::info_span!("emit", rule = rule.identifier.name).entered();
+
         let mut ctx = EmitContext {
             current_rule: self.rules.last_mut().unwrap(),
+            current_rule_id: rule_id,
             lit_pool: &mut self.lit_pool,
             regexp_pool: &mut self.regexp_pool,
             wasm_symbols: &self.wasm_symbols,
@@ -1877,20 +2560,67 @@ impl Compiler<'_> {
             {
                 panic!("duplicate module `{module_name}`")
             }
+
+            self.c_module_patterns(module_name, module);
         }
 
         let mut symbol_table =
             self.current_namespace.symbols.as_ref().borrow_mut();
 
+        let ident = import.ident();
+
+        // The module can't be imported if a rule with the same name was
+        // already declared in this namespace, possibly in another source
+        // file.
+        if let Some(Symbol::Rule { rule_id, .. }) = symbol_table.get(ident) {
+            return Err(ConflictingImport::build(
+                &self.report_builder,
+                ident.to_string(),
+                self.report_builder.span_to_code_loc(import.span()),
+                self.rules.get(rule_id.0 as usize).unwrap().ident_ref.clone(),
+            ));
+        }
+
+        // If the module is imported with an alias, as in `import "pe" as p`,
+        // the alias can't be the name of another module, a global variable,
+        // or an identifier already defined in this namespace, except when
+        // the same alias was already used for the same module.
+        if let Some(alias) = &import.alias {
+            let conflicts = match symbol_table.get(alias.name) {
+                Some(Symbol::Field { index, .. }) => self
+                    .root_struct
+                    .field_and_index_by_name(module_name)
+                    .is_none_or(|(_, module_index)| module_index != *index),
+                Some(_) => true,
+                None => {
+                    BUILTIN_MODULES.contains_key(alias.name)
+                        || self.root_struct.has_field(alias.name)
+                }
+            };
+            if conflicts {
+                return Err(ConflictingModuleAlias::build(
+                    &self.report_builder,
+                    alias.name.to_string(),
+                    self.report_builder.span_to_code_loc(alias.span()),
+                ));
+            }
+        }
+
         // Create a symbol for the module and insert it in the symbol
-        // table for this namespace, if it doesn't exist.
-        if !symbol_table.contains(module_name) {
-            symbol_table.insert(
-                module_name,
-                self.root_struct.lookup(module_name).unwrap(),
-            );
+        // table for this namespace, if it doesn't exist. When the module
+        // has an alias, the symbol is inserted only with the alias.
+        if !symbol_table.contains(ident) {
+            symbol_table
+                .insert(ident, self.root_struct.lookup(module_name).unwrap());
         }
 
+        self.current_namespace
+            .imports
+            .entry(ident.to_string())
+            .or_insert_with(|| {
+                self.report_builder.span_to_code_loc(import.span())
+            });
+
         // Is the module banned? If yes, produce an error. Notice however that
         // this check is done after the module has been added to the symbol
         // table because we don't want additional errors due to undefined
@@ -1909,6 +2639,86 @@ impl Compiler<'_> {
         Ok(())
     }
 
+    /// Processes the patterns contributed by a module, if any.
+    ///
+    /// These patterns are declared in the `patterns` field of the module's
+    /// `yara.module_options`. They are searched for in the scanned data
+    /// together with the patterns declared in rules, and patterns that are
+    /// exactly equal to some pattern declared in a rule share the same
+    /// `PatternId`.
+    fn c_module_patterns(&mut self, module_name: &str, module: &Module) {
+        let file_descriptor = module.root_struct_descriptor.file_descriptor();
+
+        let options =
+            match module_options.get(&file_descriptor.proto().options) {
+                Some(options) => options,
+                None => return,
+            };
+
+        for module_pattern in options.patterns {
+            let mut flags = PatternFlags::empty();
+
+            if module_pattern.wide() {
+                flags.insert(PatternFlags::Wide);
+            } else {
+                flags.insert(PatternFlags::Ascii);
+            }
+
+            if module_pattern.nocase() {
+                flags.insert(PatternFlags::Nocase);
+            }
+
+            let pattern = LiteralPattern {
+                flags,
+                text: module_pattern.data().into(),
+                anchored_at: None,
+                xor_range: None,
+                base64_alphabet: None,
+                base64wide_alphabet: None,
+                filesize_bounds: FilesizeBounds::default(),
+            };
+
+            let pattern_id =
+                match self.patterns.entry(Pattern::Text(pattern.clone())) {
+                    Entry::Occupied(entry) => *entry.get(),
+                    Entry::Vacant(entry) => {
+                        let pattern_id = self.next_pattern_id;
+                        self.next_pattern_id.incr(1);
+                        entry.insert(pattern_id);
+
+                        let atoms_start = self.atoms.len();
+                        let sub_patterns_start = self.sub_patterns.len();
+
+                        self.c_literal_pattern(pattern_id, pattern);
+                        self.pattern_costs.insert(
+                            pattern_id,
+                            PatternCost::new(
+                                self.sub_patterns[sub_patterns_start..]
+                                    .iter()
+                                    .map(|(_, sub_pattern)| sub_pattern),
+                                &self.atoms[atoms_start..],
+                            ),
+                        );
+                        pattern_id
+                    }
+                };
+
+            if self
+                .module_patterns
+                .entry(module_name.to_string())
+                .or_default()
+                .insert(module_pattern.identifier().to_string(), pattern_id)
+                .is_some()
+            {
+                panic!(
+                    "duplicate pattern `{}` in module `{}`",
+                    module_pattern.identifier(),
+                    module_name
+                )
+            }
+        }
+    }
+
     fn c_literal_pattern(
         &mut self,
         pattern_id: PatternId,
@@ -2155,10 +2965,15 @@ impl Compiler<'_> {
             flags.insert(SubPatternFlags::FastRegexp);
         }
 
+        let pattern_lit_id = self.lit_pool.get_or_intern(head.to_string());
+
         if pattern.flags.contains(PatternFlags::Wide) {
             self.add_sub_pattern(
                 pattern_id,
-                SubPattern::Regexp { flags: flags | SubPatternFlags::Wide },
+                SubPattern::Regexp {
+                    pattern: pattern_lit_id,
+                    flags: flags | SubPatternFlags::Wide,
+                },
                 atoms.iter().cloned().map(|atom| atom.make_wide()),
                 SubPatternAtom::from_regexp_atom,
             );
@@ -2167,7 +2982,7 @@ impl Compiler<'_> {
         if pattern.flags.contains(PatternFlags::Ascii) {
             self.add_sub_pattern(
                 pattern_id,
-                SubPattern::Regexp { flags },
+                SubPattern::Regexp { pattern: pattern_lit_id, flags },
                 atoms.into_iter(),
                 SubPatternAtom::from_regexp_atom,
             );
@@ -2325,6 +3140,9 @@ impl Compiler<'_> {
                 flags.insert(SubPatternFlags::FastRegexp);
             }
 
+            let pattern_lit_id =
+                self.lit_pool.get_or_intern(leading.to_string());
+
             if full_word {
                 flags.insert(SubPatternFlags::FullwordLeft);
             }
@@ -2333,6 +3151,7 @@ impl Compiler<'_> {
                 prev_sub_pattern_wide = self.add_sub_pattern(
                     pattern_id,
                     SubPattern::RegexpChainHead {
+                        pattern: pattern_lit_id,
                         flags: flags | SubPatternFlags::Wide,
                     },
                     atoms.iter().cloned().map(|atom| atom.make_wide()),
@@ -2343,7 +3162,10 @@ impl Compiler<'_> {
             if ascii {
                 prev_sub_pattern_ascii = self.add_sub_pattern(
                     pattern_id,
-                    SubPattern::RegexpChainHead { flags },
+                    SubPattern::RegexpChainHead {
+                        pattern: pattern_lit_id,
+                        flags,
+                    },
                     atoms.into_iter(),
                     SubPatternAtom::from_regexp_atom,
                 );
@@ -2395,10 +3217,14 @@ impl Compiler<'_> {
                     flags.insert(SubPatternFlags::FastRegexp);
                 }
 
+                let pattern_lit_id =
+                    self.lit_pool.get_or_intern(p.hir.to_string());
+
                 if wide {
                     prev_sub_pattern_wide = self.add_sub_pattern(
                         pattern_id,
                         SubPattern::RegexpChainTail {
+                            pattern: pattern_lit_id,
                             chained_to: prev_sub_pattern_wide,
                             gap: p.gap.clone(),
                             flags: flags | SubPatternFlags::Wide,
@@ -2412,6 +3238,7 @@ impl Compiler<'_> {
                     prev_sub_pattern_ascii = self.add_sub_pattern(
                         pattern_id,
                         SubPattern::RegexpChainTail {
+                            pattern: pattern_lit_id,
                             chained_to: prev_sub_pattern_ascii,
                             gap: p.gap.clone(),
                             flags,
@@ -2800,6 +3627,13 @@ impl From<PatternId> for usize {
 #[serde(transparent)]
 pub(crate) struct SubPatternId(u32);
 
+impl From<usize> for SubPatternId {
+    #[inline]
+    fn from(value: usize) -> Self {
+        Self(value.try_into().unwrap())
+    }
+}
+
 /// Iterator that yields the names of the modules imported by the rules.
 pub struct Imports<'a> {
     iter: std::slice::Iter<'a, IdentId>,
@@ -2865,14 +3699,17 @@ pub(crate) enum SubPattern {
     },
 
     Regexp {
+        pattern: LiteralId,
         flags: SubPatternFlags,
     },
 
     RegexpChainHead {
+        pattern: LiteralId,
         flags: SubPatternFlags,
     },
 
     RegexpChainTail {
+        pattern: LiteralId,
         chained_to: SubPatternId,
         gap: ChainedPatternGap,
         flags: SubPatternFlags,
@@ -2907,6 +3744,42 @@ pub(crate) enum SubPattern {
 }
 
 impl SubPattern {
+    /// Returns the [`LiteralId`] of the sub-pattern's literal bytes, or of the
+    /// text of the regular expression for regexp sub-patterns.
+    pub fn pattern(&self) -> LiteralId {
+        match self {
+            SubPattern::Literal { pattern, .. }
+            | SubPattern::LiteralChainHead { pattern, .. }
+            | SubPattern::LiteralChainTail { pattern, .. }
+            | SubPattern::Regexp { pattern, .. }
+            | SubPattern::RegexpChainHead { pattern, .. }
+            | SubPattern::RegexpChainTail { pattern, .. }
+            | SubPattern::Xor { pattern, .. }
+            | SubPattern::Base64 { pattern, .. }
+            | SubPattern::Base64Wide { pattern, .. }
+            | SubPattern::CustomBase64 { pattern, .. }
+            | SubPattern::CustomBase64Wide { pattern, .. } => *pattern,
+        }
+    }
+
+    /// Returns the sub-pattern's flags. Base64 sub-patterns don't have
+    /// flags, an empty set is returned for them.
+    pub fn flags(&self) -> SubPatternFlags {
+        match self {
+            SubPattern::Literal { flags, .. }
+            | SubPattern::LiteralChainHead { flags, .. }
+            | SubPattern::LiteralChainTail { flags, .. }
+            | SubPattern::Regexp { flags, .. }
+            | SubPattern::RegexpChainHead { flags, .. }
+            | SubPattern::RegexpChainTail { flags, .. }
+            | SubPattern::Xor { flags, .. } => *flags,
+            SubPattern::Base64 { .. }
+            | SubPattern::Base64Wide { .. }
+            | SubPattern::CustomBase64 { .. }
+            | SubPattern::CustomBase64Wide { .. } => SubPatternFlags::empty(),
+        }
+    }
+
     /// If this sub-pattern is chained to another one, returns the
     /// [`SubPatternId`] associated to this other pattern.
     pub fn chained_to(&self) -> Option<SubPatternId> {
@@ -140,13 +140,12 @@ impl Report {
             // byte offset where each line begins. By doing a binary search
             // on that vector, we can locate the line number in O(log(N))
             // instead of O(N).
-            let (line, column) =
-                match byte_offset_to_line_col(code, span.start()) {
-                    Some((line, column)) => (line, column),
-                    None => panic!(
-                        "can't find line and column for span {span} in code:\n{code}",
-                    ),
-                };
+            let (line, column) = match span.start_line_col(code) {
+                Some((line, column)) => (line, column),
+                None => panic!(
+                    "can't find line and column for span {span} in code:\n{code}",
+                ),
+            };
 
             Label {
                 level: level_as_text(level),
@@ -361,6 +360,18 @@ impl Label<'_> {
         self.code_origin.as_deref()
     }
 
+    /// Line number where the label starts (1-based).
+    #[inline]
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Column number where the label starts (1-based, in characters).
+    #[inline]
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
     #[inline]
     pub fn span(&self) -> &Span {
         &self.span
@@ -474,8 +485,28 @@ impl ReportBuilder {
     ///
     /// This is a convenience method that creates a [`CodeLoc`] with the current
     /// source ID and the provided span.
+    ///
+    /// Spans that fall outside the current source code are clamped to its
+    /// end. This happens with ASTs passed to [`crate::Compiler::add_ast`],
+    /// which may not come from the registered source code.
     pub fn span_to_code_loc(&self, span: Span) -> CodeLoc {
-        CodeLoc::new(self.get_current_source_id(), span)
+        let source_id = self.get_current_source_id();
+
+        let code_len = source_id.and_then(|source_id| {
+            self.code_cache
+                .read()
+                .get(&source_id)
+                .map(|entry| entry.code.len())
+        });
+
+        let span = match code_len {
+            Some(len) if span.end() > len => {
+                Span(span.start().min(len) as u32..len as u32)
+            }
+            _ => span,
+        };
+
+        CodeLoc::new(source_id, span)
     }
 
     /// Returns the green style used in error/warning reports.
@@ -533,6 +564,16 @@ impl ReportBuilder {
         source_id
     }
 
+    /// Returns the origin of the source code identified by `source_id`, as
+    /// set with [`SourceCode::with_origin`]. Returns `None` if the source
+    /// code doesn't have an origin.
+    pub fn get_origin(&self, source_id: SourceId) -> Option<String> {
+        self.code_cache
+            .read()
+            .get(&source_id)
+            .and_then(|entry| entry.origin.clone())
+    }
+
     /// Returns the fragment from the current source code indicated by `span`.
     pub fn get_snippet(&self, span: Span) -> String {
         let source_id = self.get_current_source_id().unwrap();
@@ -540,7 +581,7 @@ impl ReportBuilder {
         let cache_entry = code_cache.get(&source_id).unwrap();
         let src = cache_entry.code.as_str();
 
-        src[span.range()].to_string()
+        src.get(span.range()).unwrap_or_default().to_string()
     }
 
     /// Creates a new error or warning report.
@@ -585,89 +626,3 @@ fn level_as_text(level: &Level) -> &'static str {
         _ => panic!("unsupported level {level:?}"),
     }
 }
-
-/// Given a text slice and a position indicated as a byte offset, returns
-/// the same position as a (line, column) pair.
-fn byte_offset_to_line_col(
-    text: &str,
-    byte_offset: usize,
-) -> Option<(usize, usize)> {
-    // Check if the byte_offset is valid
-    if byte_offset > text.len() {
-        return None; // Out of bounds
-    }
-
-    let mut line = 1;
-    let mut col = 1;
-
-    // Iterate through the characters (not bytes) in the string
-    for (i, c) in text.char_indices() {
-        if i == byte_offset {
-            return Some((line, col));
-        }
-        if c == '\n' {
-            line += 1;
-            col = 1; // Reset column to 1 after a newline
-        } else {
-            col += 1;
-        }
-    }
-
-    // If the byte_offset points to the last byte of the string, return the
-    // final position
-    if byte_offset == text.len() {
-        return Some((line, col));
-    }
-
-    None
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::compiler::report::byte_offset_to_line_col;
-
-    #[test]
-    fn byte_offset_to_line_col_single_line() {
-        let text = "Hello, World!";
-        assert_eq!(byte_offset_to_line_col(text, 0), Some((1, 1))); // Start of the string
-        assert_eq!(byte_offset_to_line_col(text, 7), Some((1, 8))); // Byte offset of 'W'
-        assert_eq!(byte_offset_to_line_col(text, 12), Some((1, 13))); // Byte offset of '!'
-    }
-
-    #[test]
-    fn byte_offset_to_line_col_multiline() {
-        let text = "Hello\nRust\nWorld!";
-        assert_eq!(byte_offset_to_line_col(text, 0), Some((1, 1))); // First character
-        assert_eq!(byte_offset_to_line_col(text, 5), Some((1, 6))); // End of first line (newline)
-        assert_eq!(byte_offset_to_line_col(text, 6), Some((2, 1))); // Start of second line ('R')
-        assert_eq!(byte_offset_to_line_col(text, 9), Some((2, 4))); // Byte offset of 't' in "Rust"
-        assert_eq!(byte_offset_to_line_col(text, 11), Some((3, 1))); // Start of third line ('W')
-    }
-
-    #[test]
-    fn byte_offset_to_line_col_empty_string() {
-        let text = "";
-        assert_eq!(byte_offset_to_line_col(text, 0), Some((1, 1)));
-    }
-
-    #[test]
-    fn byte_offset_to_line_col_out_of_bounds() {
-        let text = "Hello, World!";
-        assert_eq!(byte_offset_to_line_col(text, text.len() + 1), None);
-    }
-
-    #[test]
-    fn byte_offset_to_line_col_end_of_string() {
-        let text = "Hello, World!";
-        assert_eq!(byte_offset_to_line_col(text, text.len()), Some((1, 14))); // Last position after '!'
-    }
-
-    #[test]
-    fn byte_offset_to_line_col_multibyte_characters() {
-        let text = "Hello, 你好!";
-        assert_eq!(byte_offset_to_line_col(text, 7), Some((1, 8))); // Position of '你'
-        assert_eq!(byte_offset_to_line_col(text, 8), None); // Position in the middle of '你'
-        assert_eq!(byte_offset_to_line_col(text, 10), Some((1, 9))); // Position of '好'
-        assert_eq!(byte_offset_to_line_col(text, 13), Some((1, 10))); // Position of '!'
-    }
-}
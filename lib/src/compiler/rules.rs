@@ -17,13 +17,13 @@ use crate::compiler::errors::SerializationError;
 use crate::compiler::report::CodeLoc;
 use crate::compiler::warnings::Warning;
 use crate::compiler::{
-    IdentId, Imports, LiteralId, NamespaceId, PatternId, RegexpId, RuleId,
-    SubPattern, SubPatternId,
+    Backend, IdentId, Imports, LiteralId, NamespaceId, PatternId, RegexpId,
+    RuleCost, RuleId, RulesStats, SubPattern, SubPatternId,
 };
 use crate::models::PatternKind;
 use crate::re::{BckCodeLoc, FwdCodeLoc, RegexpAtom};
 use crate::string_pool::{BStringPool, StringPool};
-use crate::{re, types, wasm, Rule};
+use crate::{modules, re, types, wasm, Rule};
 
 /// Magic bytes prepended to any binary file generated by YARA-X.
 const MAGIC: &[u8] = b"YARA-X\0\0";
@@ -32,7 +32,56 @@ const MAGIC: &[u8] = b"YARA-X\0\0";
 ///
 /// This version is incremented every time a change is made to the binary
 /// format in a way that breaks backwards compatibility.
-const SERIALIZATION_VERSION: u32 = 1;
+const SERIALIZATION_VERSION: u32 = 7;
+
+/// Header that follows the magic bytes and the version number in serialized
+/// rules.
+///
+/// The header describes the build of YARA-X that produced the rules. Its
+/// format must not change across serialization versions, so that it can be
+/// decoded even when the rest of the data can't, see
+/// [`Rules::deserialize_or_recompile`]. New fields can be appended at the
+/// end, though.
+#[derive(Serialize, Deserialize)]
+struct Header {
+    /// Version of YARA-X that produced the rules.
+    yara_x_version: String,
+    /// Architecture and operating system for which YARA-X was built, like
+    /// `x86_64-linux`. Native code included in the rules is valid only for
+    /// this target.
+    target: String,
+    /// Modules imported by the rules.
+    modules: Vec<String>,
+    /// Whether the rules include native code.
+    native_code: bool,
+    /// Whether the rules were compiled with relaxed regular expression
+    /// syntax.
+    relaxed_re_syntax: bool,
+    /// Source code of the rules, with the namespace where each source was
+    /// compiled. Only present if the source code was embedded with
+    /// [`crate::Compiler::embed_sources`].
+    sources: Option<Vec<(String, String)>>,
+    /// Whether the rules can be compiled again from `sources` with the
+    /// same result. This is false if the rules depend on something that
+    /// is not embedded, like global variables or compiler settings.
+    recompilable: bool,
+}
+
+impl Header {
+    /// Decodes the header from `bytes`, which must start right after the
+    /// version number. Returns the header and its size in bytes.
+    fn decode(bytes: &[u8]) -> Result<(Self, usize), SerializationError> {
+        Ok(bincode::serde::decode_from_slice(
+            bytes,
+            bincode::config::standard(),
+        )?)
+    }
+}
+
+/// Returns the target for which YARA-X was built, like `x86_64-linux`.
+fn target() -> String {
+    format!("{}-{}", std::env::consts::ARCH, std::env::consts::OS)
+}
 
 /// A set of YARA rules in compiled form.
 ///
@@ -54,6 +103,9 @@ pub struct Rules {
     /// contain invalid escape sequences.
     pub(in crate::compiler) relaxed_re_syntax: bool,
 
+    /// Backend used for executing the rule conditions.
+    pub(in crate::compiler) backend: Backend,
+
     /// Pool with literal strings used in the rules. Each literal has its
     /// own [`LiteralId`], which can be used for retrieving the literal
     /// string as `&BStr`.
@@ -108,6 +160,12 @@ pub struct Rules {
     pub(in crate::compiler) filesize_bounds:
         FxHashMap<PatternId, FilesizeBounds>,
 
+    /// Patterns contributed by modules. Keys are module names, values are
+    /// maps that associate the identifier of each pattern declared by the
+    /// module to its [`PatternId`].
+    pub(in crate::compiler) module_patterns:
+        FxHashMap<String, FxHashMap<String, PatternId>>,
+
     /// Vector that contains the [`SubPatternId`] for sub-patterns that can
     /// match only at a fixed offset within the scanned data. These sub-patterns
     /// are not added to the Aho-Corasick automaton.
@@ -139,11 +197,31 @@ pub struct Rules {
     #[serde(skip)]
     pub(in crate::compiler) ac: Option<AhoCorasick>,
 
+    /// Compiled form of the regular expressions in `regexp_pool`, indexed
+    /// by [`RegexpId`]. These regular expressions are the ones used in
+    /// conditions like `some_var matches /foobar/`. They are compiled once
+    /// when the rules are built or deserialized, and shared by all the
+    /// scanners. Like the Aho-Corasick automaton, they are not serialized.
+    #[serde(skip)]
+    pub(in crate::compiler) regexps: Vec<Regex>,
+
     /// Warnings that were produced while compiling these rules. These warnings
     /// are not serialized, rules that are obtained by deserializing previously
     /// serialized rules won't have any warnings.
     #[serde(skip)]
     pub(in crate::compiler) warnings: Vec<Warning>,
+
+    /// Source code from which the rules were compiled, together with the
+    /// namespace where each source was compiled. Only present if the source
+    /// code was embedded with [`crate::Compiler::embed_sources`]. This is
+    /// serialized in the [`Header`], not with the rest of the fields.
+    #[serde(skip)]
+    pub(in crate::compiler) embedded_sources: Option<Vec<(String, String)>>,
+
+    /// True if the rules can be compiled again from `embedded_sources`.
+    /// This is serialized in the [`Header`] too.
+    #[serde(skip)]
+    pub(in crate::compiler) recompilable: bool,
 }
 
 impl Rules {
@@ -161,6 +239,26 @@ impl Rules {
         self.warnings.as_slice()
     }
 
+    /// Returns statistics about the rules, like the number of rules and
+    /// patterns, or the estimated memory footprint.
+    ///
+    /// ```
+    /// # use yara_x;
+    /// let rules = yara_x::compile(r#"
+    ///     rule foo { strings: $a = "foo" condition: $a }
+    ///     rule bar { strings: $a = { 62 61 72 } condition: $a }
+    /// "#).unwrap();
+    ///
+    /// let stats = rules.stats();
+    ///
+    /// assert_eq!(stats.num_rules(), 2);
+    /// assert_eq!(stats.num_text_patterns(), 1);
+    /// assert_eq!(stats.num_hex_patterns(), 1);
+    /// ```
+    pub fn stats(&self) -> RulesStats {
+        RulesStats::new(self)
+    }
+
     /// Serializes the rules as a sequence of bytes.
     ///
     /// The [`Rules`] can be restored back by passing the bytes to
@@ -171,23 +269,78 @@ impl Rules {
         Ok(bytes)
     }
 
+    /// Serializes the rules and signs them with an Ed25519 secret key.
+    ///
+    /// The signature is appended to the serialized rules, which can be
+    /// loaded with [`Rules::deserialize_verified`] using the public key
+    /// that corresponds to `secret_key`. The signed rules can be loaded with
+    /// [`Rules::deserialize`] too, but the signature is not verified in that
+    /// case.
+    #[cfg(feature = "rules-signing")]
+    pub fn serialize_signed(
+        &self,
+        secret_key: &[u8; 32],
+    ) -> Result<Vec<u8>, SerializationError> {
+        use ed25519_dalek::Signer;
+        let mut bytes = self.serialize()?;
+        let key = ed25519_dalek::SigningKey::from_bytes(secret_key);
+        let signature = key.sign(bytes.as_slice());
+        bytes.extend_from_slice(&signature.to_bytes());
+        Ok(bytes)
+    }
+
     /// Deserializes the rules from a sequence of bytes produced by
-    /// [`Rules::serialize`].
-    pub fn deserialize<B>(bytes: B) -> Result<Self, SerializationError>
+    /// [`Rules::serialize_signed`], verifying their signature with an
+    /// Ed25519 public key.
+    ///
+    /// Fails with [`SerializationError::InvalidSignature`] if the rules
+    /// are not signed, if they were signed with a key that doesn't
+    /// correspond to `public_key`, or if they were modified after being
+    /// signed.
+    #[cfg(feature = "rules-signing")]
+    pub fn deserialize_verified<B>(
+        bytes: B,
+        public_key: &[u8; 32],
+    ) -> Result<Self, SerializationError>
     where
         B: AsRef<[u8]>,
     {
         let bytes = bytes.as_ref();
-        let version_offset = MAGIC.len();
-        let data_offset = version_offset + size_of::<u32>();
 
-        if bytes.len() < data_offset || &bytes[0..version_offset] != MAGIC {
-            return Err(SerializationError::InvalidFormat);
-        }
+        let data_len = bytes
+            .len()
+            .checked_sub(ed25519_dalek::SIGNATURE_LENGTH)
+            .ok_or(SerializationError::InvalidSignature)?;
 
-        let version = u32::from_le_bytes(
-            bytes[version_offset..data_offset].try_into().unwrap(),
-        );
+        let (data, signature) = bytes.split_at(data_len);
+
+        let key = ed25519_dalek::VerifyingKey::from_bytes(public_key)
+            .map_err(|_| SerializationError::InvalidSignature)?;
+
+        let signature =
+            ed25519_dalek::Signature::from_slice(signature).unwrap();
+
+        key.verify_strict(data, &signature)
+            .map_err(|_| SerializationError::InvalidSignature)?;
+
+        Self::deserialize(data)
+    }
+
+    /// Deserializes the rules from a sequence of bytes produced by
+    /// [`Rules::serialize`].
+    ///
+    /// Fails with [`SerializationError::InvalidVersion`] if the rules were
+    /// serialized with a format that is not compatible with the current
+    /// version of YARA-X, with [`SerializationError::TargetMismatch`] if
+    /// they include native code for a different target, and with
+    /// [`SerializationError::MissingModule`] if the rules use some module
+    /// that is not supported by the current build of YARA-X.
+    pub fn deserialize<B>(bytes: B) -> Result<Self, SerializationError>
+    where
+        B: AsRef<[u8]>,
+    {
+        let bytes = bytes.as_ref();
+        let (version, header_offset) = Self::decode_version(bytes)?;
 
         if version != SERIALIZATION_VERSION {
             return Err(SerializationError::InvalidVersion {
@@ -196,6 +349,25 @@ impl Rules {
             });
         }
 
+        let (header, header_len) = Header::decode(&bytes[header_offset..])?;
+        let data_offset = header_offset + header_len;
+
+        if let Some(module) = header.modules.iter().find(|module| {
+            !modules::BUILTIN_MODULES.contains_key(module.as_str())
+        }) {
+            return Err(SerializationError::MissingModule {
+                module: module.to_string(),
+            });
+        }
+
+        // Native code can be used only in the target it was compiled for.
+        if header.native_code && header.target != target() {
+            return Err(SerializationError::TargetMismatch {
+                expected: target(),
+                actual: header.target,
+            });
+        }
+
         #[cfg(feature = "logging")]
         let start = Instant::now();
 
@@ -209,6 +381,9 @@ impl Rules {
         #[cfg(feature = "logging")]
         info!("Deserialization time: {:?}", Instant::elapsed(&start));
 
+        rules.embedded_sources = header.sources;
+        rules.recompilable = header.recompilable;
+
         // `rules.compiled_wasm_mod` can be `None` for two reasons:
         //
         //  1- The rules were serialized without compiled rules (i.e: the
@@ -220,13 +395,22 @@ impl Rules {
         //     returned `None`.
         //
         // In both cases we try to build the module again from the data in
-        // `rules.wasm_mode`.
-        if rules.compiled_wasm_mod.is_none() {
+        // `rules.wasm_mode`. The same happens if the compiled module is not
+        // for the engine used by the rules' backend.
+        if !rules.compiled_wasm_mod.as_ref().is_some_and(|module| {
+            wasmtime::Engine::same(
+                module.engine(),
+                wasm::get_engine_for(rules.backend),
+            )
+        }) {
             #[cfg(feature = "logging")]
             let start = Instant::now();
 
+            #[cfg(feature = "tracing")]
+            let _span = tracing::info_span!("wasm_compile").entered();
+
             rules.compiled_wasm_mod = Some(wasmtime::Module::from_binary(
-                wasm::get_engine(),
+                wasm::get_engine_for(rules.backend),
                 rules.wasm_mod.as_slice(),
             )?);
 
@@ -235,10 +419,87 @@ impl Rules {
         }
 
         rules.build_ac_automaton();
+        rules.build_regexps();
 
         Ok(rules)
     }
 
+    /// Like [`Rules::deserialize`], but compiles the rules again from the
+    /// source code embedded in them if they can't be deserialized.
+    ///
+    /// The source code is embedded in the rules only when they are compiled
+    /// with [`crate::Compiler::embed_sources`] enabled. This allows loading
+    /// rules serialized by other versions of YARA-X, at the cost of compiling
+    /// them. If the rules can't be deserialized and can't be compiled again
+    /// from their source code, either because it was not embedded or
+    /// because the rules depend on something else, like global variables,
+    /// the error returned by [`Rules::deserialize`] is returned. If the
+    /// source code doesn't compile, the function returns
+    /// [`SerializationError::CompileError`].
+    pub fn deserialize_or_recompile<B>(
+        bytes: B,
+    ) -> Result<Self, SerializationError>
+    where
+        B: AsRef<[u8]>,
+    {
+        let bytes = bytes.as_ref();
+
+        let err = match Self::deserialize(bytes) {
+            Ok(rules) => return Ok(rules),
+            Err(err) => err,
+        };
+
+        // The header is decoded no matter the version, as its format is
+        // the same in all versions where it exists.
+        let header = Self::decode_version(bytes)
+            .ok()
+            .and_then(|(_, offset)| Header::decode(&bytes[offset..]).ok())
+            .map(|(header, _len)| header);
+
+        let Some(sources) = header
+            .as_ref()
+            .filter(|h| h.recompilable)
+            .and_then(|h| h.sources.as_ref())
+        else {
+            return Err(err);
+        };
+
+        let mut compiler = crate::Compiler::new();
+
+        compiler
+            .relaxed_re_syntax(header.as_ref().unwrap().relaxed_re_syntax)
+            .embed_sources(true);
+
+        for (namespace, src) in sources {
+            compiler
+                .new_namespace(namespace)
+                .add_source(src.as_str())
+                .map_err(Box::new)?;
+        }
+
+        Ok(compiler.build())
+    }
+
+    /// Decodes the magic bytes and version number at the start of
+    /// serialized rules. Returns the version number and the offset where
+    /// the data that follows the version starts.
+    fn decode_version(
+        bytes: &[u8],
+    ) -> Result<(u32, usize), SerializationError> {
+        let version_offset = MAGIC.len();
+        let data_offset = version_offset + size_of::<u32>();
+
+        if bytes.len() < data_offset || &bytes[0..version_offset] != MAGIC {
+            return Err(SerializationError::InvalidFormat);
+        }
+
+        let version = u32::from_le_bytes(
+            bytes[version_offset..data_offset].try_into().unwrap(),
+        );
+
+        Ok((version, data_offset))
+    }
+
     /// Serializes the rules into a `writer`.
     pub fn serialize_into<W>(
         &self,
@@ -255,6 +516,25 @@ impl Rules {
         // Write version.
         writer.write_all(&SERIALIZATION_VERSION.to_le_bytes())?;
 
+        let modules =
+            self.imports().map(|module| module.to_string()).collect();
+
+        let header = Header {
+            yara_x_version: env!("CARGO_PKG_VERSION").to_string(),
+            target: target(),
+            modules,
+            native_code: cfg!(feature = "native-code-serialization"),
+            relaxed_re_syntax: self.relaxed_re_syntax,
+            sources: self.embedded_sources.clone(),
+            recompilable: self.recompilable,
+        };
+
+        bincode::serde::encode_into_std_write(
+            &header,
+            &mut writer,
+            bincode::config::standard(),
+        )?;
+
         bincode::serde::encode_into_std_write(
             self,
             &mut writer,
@@ -314,8 +594,33 @@ impl Rules {
     ///
     /// If no regular expression with such [`RegexpId`] exists.
     #[inline]
-    pub(crate) fn get_regexp(&self, regexp_id: RegexpId) -> Regex {
-        let re = types::Regexp::new(self.regexp_pool.get(regexp_id).unwrap());
+    pub(crate) fn get_regexp(&self, regexp_id: RegexpId) -> &Regex {
+        let regexp_id: u32 = regexp_id.into();
+        &self.regexps[regexp_id as usize]
+    }
+
+    /// Compiles the regular expressions in `regexp_pool`, if they were not
+    /// compiled yet.
+    pub(crate) fn build_regexps(&mut self) {
+        if !self.regexps.is_empty() {
+            return;
+        }
+
+        self.regexps = self
+            .regexp_pool
+            .iter()
+            .enumerate()
+            .map(|(i, (regexp_id, re))| {
+                // Regexps are interned with consecutive IDs starting at 0,
+                // so their IDs are also their indexes in `self.regexps`.
+                debug_assert_eq!(u32::from(regexp_id) as usize, i);
+                self.compile_regexp(re)
+            })
+            .collect();
+    }
+
+    fn compile_regexp(&self, re: &str) -> Regex {
+        let re = types::Regexp::new(re);
 
         let parser = re::parser::Parser::new()
             .relaxed_re_syntax(self.relaxed_re_syntax);
@@ -367,12 +672,16 @@ impl Rules {
         None
     }
 
-    #[cfg(feature = "rules-profiling")]
     #[inline]
     pub(crate) fn rules(&self) -> &[RuleInfo] {
         self.rules.as_slice()
     }
 
+    #[inline]
+    pub(crate) fn sub_patterns(&self) -> &[(PatternId, SubPattern)] {
+        self.sub_patterns.as_slice()
+    }
+
     #[inline]
     pub(crate) fn atoms(&self) -> &[SubPatternAtom] {
         self.atoms.as_slice()
@@ -502,6 +811,24 @@ impl Rules {
         self.compiled_wasm_mod.as_ref().unwrap()
     }
 
+    #[inline]
+    pub(crate) fn backend(&self) -> Backend {
+        self.backend
+    }
+
+    /// Returns the [`PatternId`] of a pattern contributed by a module.
+    ///
+    /// Returns `None` if the module was not imported by the rules or if it
+    /// doesn't declare a pattern with the given identifier.
+    #[inline]
+    pub(crate) fn module_pattern_id(
+        &self,
+        module: &str,
+        ident: &str,
+    ) -> Option<PatternId> {
+        self.module_patterns.get(module)?.get(ident).copied()
+    }
+
     #[inline]
     pub(crate) fn filesize_bounds(
         &self,
@@ -548,14 +875,29 @@ where
     D: Deserializer<'de>,
 {
     let bytes: Option<&[u8]> = Deserialize::deserialize(deserializer)?;
-    let module = if let Some(bytes) = bytes {
-        unsafe {
-            wasmtime::Module::deserialize(wasm::get_engine(), bytes).ok()
-        }
-    } else {
-        None
+
+    let Some(bytes) = bytes else {
+        return Ok(None);
+    };
+
+    // The backend used by the rules is not known yet. The code is
+    // deserialized with the engine for `Backend::Native`, and if it's not
+    // native code for the current platform, with the engine for
+    // `Backend::Pulley`. `Rules::deserialize` makes sure that the resulting
+    // module is for the right backend.
+    let module = unsafe {
+        wasmtime::Module::deserialize(wasm::get_engine(), bytes).ok()
     };
 
+    #[cfg(feature = "pulley-backend")]
+    let module = module.or_else(|| unsafe {
+        wasmtime::Module::deserialize(
+            wasm::get_engine_for(Backend::Pulley),
+            bytes,
+        )
+        .ok()
+    });
+
     Ok(module)
 }
 
@@ -643,10 +985,15 @@ pub(crate) struct RuleInfo {
     /// Number of private patterns in the rule. The number of non-private
     /// patterns can be computed as patterns.len - num_private_patterns.
     pub num_private_patterns: usize,
+    /// The origin of the source code where the rule was defined (usually
+    /// a file path), as set with [`crate::SourceCode::with_origin`].
+    pub origin: Option<LiteralId>,
     /// True if the rule is global.
     pub is_global: bool,
     /// True if the rule is private.
     pub is_private: bool,
+    /// Estimated cost of the rule.
+    pub cost: RuleCost,
 }
 
 /// Information about each of pattern in a rule.
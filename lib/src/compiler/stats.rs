@@ -0,0 +1,127 @@
+use std::mem::{size_of, size_of_val};
+
+use rustc_hash::FxHashSet;
+
+use crate::compiler::{PatternInfo, RuleInfo, Rules, SubPatternAtom};
+use crate::models::PatternKind;
+
+/// Statistics about a set of compiled rules.
+///
+/// This is returned by [`Rules::stats`], and allows imposing limits on the
+/// size and complexity of rules before using them for scanning.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RulesStats {
+    num_rules: usize,
+    num_text_patterns: usize,
+    num_hex_patterns: usize,
+    num_regexp_patterns: usize,
+    num_atoms: usize,
+    num_imports: usize,
+    wasm_code_size: usize,
+    memory_footprint: usize,
+}
+
+impl RulesStats {
+    pub(in crate::compiler) fn new(rules: &Rules) -> Self {
+        let mut stats = Self {
+            num_rules: rules.rules.len(),
+            num_atoms: rules.atoms.len(),
+            num_imports: rules.imported_modules.len(),
+            wasm_code_size: rules.wasm_mod.len(),
+            ..Default::default()
+        };
+
+        // Identical patterns are shared by multiple rules, each one must
+        // be counted only once.
+        let mut seen = FxHashSet::default();
+        let mut num_patterns_in_rules = 0;
+
+        for pattern in rules.rules.iter().flat_map(|rule| &rule.patterns) {
+            num_patterns_in_rules += 1;
+            if !seen.insert(pattern.pattern_id) {
+                continue;
+            }
+            match pattern.kind {
+                PatternKind::Text => stats.num_text_patterns += 1,
+                PatternKind::Hex => stats.num_hex_patterns += 1,
+                PatternKind::Regexp => stats.num_regexp_patterns += 1,
+            }
+        }
+
+        stats.memory_footprint = rules.ident_pool.size()
+            + rules.regexp_pool.size()
+            + rules.lit_pool.size()
+            + rules.wasm_mod.len()
+            + rules.re_code.len()
+            + rules.serialized_globals.len()
+            + rules.rules.len() * size_of::<RuleInfo>()
+            + num_patterns_in_rules * size_of::<PatternInfo>()
+            + size_of_val(rules.sub_patterns.as_slice())
+            + rules.atoms.len() * size_of::<SubPatternAtom>()
+            + rules.ac.as_ref().map_or(0, |ac| ac.memory_usage());
+
+        stats
+    }
+
+    /// Number of rules, including private and global ones.
+    pub fn num_rules(&self) -> usize {
+        self.num_rules
+    }
+
+    /// Number of text patterns (e.g: `"foo"`). Identical patterns declared
+    /// in different rules are counted once.
+    pub fn num_text_patterns(&self) -> usize {
+        self.num_text_patterns
+    }
+
+    /// Number of hex patterns (e.g: `{ 01 02 03 }`). Identical patterns
+    /// declared in different rules are counted once.
+    pub fn num_hex_patterns(&self) -> usize {
+        self.num_hex_patterns
+    }
+
+    /// Number of regular expression patterns (e.g: `/foo.*bar/`). Identical
+    /// patterns declared in different rules are counted once.
+    pub fn num_regexp_patterns(&self) -> usize {
+        self.num_regexp_patterns
+    }
+
+    /// Total number of patterns, regardless of their type.
+    pub fn num_patterns(&self) -> usize {
+        self.num_text_patterns
+            + self.num_hex_patterns
+            + self.num_regexp_patterns
+    }
+
+    /// Number of atoms extracted from the patterns.
+    ///
+    /// Atoms are the short sequences of bytes searched in the scanned data
+    /// for finding candidate matches for each pattern. See
+    /// [`crate::RuleCost::num_atoms`].
+    pub fn num_atoms(&self) -> usize {
+        self.num_atoms
+    }
+
+    /// Number of modules imported by the rules.
+    pub fn num_imports(&self) -> usize {
+        self.num_imports
+    }
+
+    /// Size in bytes of the WASM code generated for the rule conditions.
+    /// This doesn't include the native code produced from it.
+    pub fn wasm_code_size(&self) -> usize {
+        self.wasm_code_size
+    }
+
+    /// Estimated amount of memory in bytes used by the rules.
+    ///
+    /// This includes the WASM code, the Aho-Corasick automaton, the code of
+    /// regular expressions, and the data structures describing rules,
+    /// patterns and atoms. The native code produced from the WASM code is
+    /// not included. The estimate is useful for comparing rule sets with
+    /// each other, the method used for computing it may change in future
+    /// versions.
+    pub fn memory_footprint(&self) -> usize {
+        self.memory_footprint
+    }
+}
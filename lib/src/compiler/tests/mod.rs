@@ -28,7 +28,7 @@ fn serialization() {
     // `DecodeError`.
     let mut data = Vec::new();
     data.extend(b"YARA-X\0\0");
-    data.extend(1u32.to_le_bytes());
+    data.extend(7u32.to_le_bytes());
     data.extend(b"foo");
 
     assert!(matches!(
@@ -68,6 +68,155 @@ fn serialization() {
     assert_eq!(size_of::<SubPattern>(), 24);
 }
 
+#[test]
+fn serialization_is_deterministic() {
+    let src = r#"
+        import "test_proto2"
+        rule foo { strings: $a = "foo" condition: $a }
+        rule bar { strings: $a = /ba[rz]/ condition: $a and test_proto2.int32_one == 1 }
+    "#;
+
+    assert_eq!(
+        compile(src).unwrap().serialize().unwrap(),
+        compile(src).unwrap().serialize().unwrap()
+    );
+}
+
+#[test]
+fn serialization_recompile() {
+    let mut compiler = Compiler::new();
+
+    compiler
+        .embed_sources(true)
+        .add_source(r#"rule foo { strings: $a = "foo" condition: $a }"#)
+        .unwrap()
+        .new_namespace("bar")
+        .add_source(r#"rule bar { strings: $a = "bar" condition: $a }"#)
+        .unwrap();
+
+    // Sources with errors are not embedded.
+    assert!(compiler.add_source("rule baz { condition: qux }").is_err());
+
+    let mut data = compiler.build().serialize().unwrap();
+
+    // Simulate rules serialized by another version of YARA-X by changing
+    // the version number.
+    data[8..12].copy_from_slice(&0u32.to_le_bytes());
+
+    assert!(matches!(
+        Rules::deserialize(&data).err().unwrap(),
+        SerializationError::InvalidVersion { expected: _, actual: 0 }
+    ));
+
+    let rules = Rules::deserialize_or_recompile(&data).unwrap();
+    let mut scanner = Scanner::new(&rules);
+    let results = scanner.scan(b"foobar").unwrap();
+
+    assert_eq!(
+        results
+            .matching_rules()
+            .map(|rule| (rule.namespace(), rule.identifier()))
+            .collect::<Vec<_>>(),
+        [("default", "foo"), ("bar", "bar")]
+    );
+
+    // Without embedded sources the original error is returned.
+    let mut data =
+        compile("rule foo { condition: true }").unwrap().serialize().unwrap();
+
+    data[8..12].copy_from_slice(&0u32.to_le_bytes());
+
+    assert!(matches!(
+        Rules::deserialize_or_recompile(&data).err().unwrap(),
+        SerializationError::InvalidVersion { expected: _, actual: 0 }
+    ));
+
+    // Rules that use global variables are not recompiled, as the variables
+    // are not embedded.
+    let mut compiler = Compiler::new();
+
+    compiler
+        .embed_sources(true)
+        .define_global("bar", 1)
+        .unwrap()
+        .add_source("rule foo { condition: bar == 1 }")
+        .unwrap();
+
+    let mut data = compiler.build().serialize().unwrap();
+
+    data[8..12].copy_from_slice(&0u32.to_le_bytes());
+
+    assert!(matches!(
+        Rules::deserialize_or_recompile(&data).err().unwrap(),
+        SerializationError::InvalidVersion { expected: _, actual: 0 }
+    ));
+}
+
+#[test]
+fn serialization_missing_module() {
+    let mut data = compile(r#"import "math" rule foo { condition: true }"#)
+        .unwrap()
+        .serialize()
+        .unwrap();
+
+    // Simulate rules that import a module that doesn't exist in this build
+    // of YARA-X by renaming the module in the header.
+    let pos = data.windows(4).position(|w| w == b"math").unwrap();
+    data[pos..pos + 4].copy_from_slice(b"mxth");
+
+    assert!(matches!(
+        Rules::deserialize(&data).err().unwrap(),
+        SerializationError::MissingModule { module } if module == "mxth"
+    ));
+}
+
+#[cfg(feature = "rules-signing")]
+#[test]
+fn serialization_signed() {
+    let secret_key = [1; 32];
+    let public_key = ed25519_dalek::SigningKey::from_bytes(&secret_key)
+        .verifying_key()
+        .to_bytes();
+
+    let mut data =
+        compile(r#"rule test { strings: $a = "foo" condition: $a }"#)
+            .unwrap()
+            .serialize_signed(&secret_key)
+            .unwrap();
+
+    let rules = Rules::deserialize_verified(&data, &public_key).unwrap();
+    let mut scanner = Scanner::new(&rules);
+    assert_eq!(scanner.scan(b"foo").unwrap().matching_rules().len(), 1);
+
+    // Signed rules can be deserialized without verifying the signature.
+    assert!(Rules::deserialize(&data).is_ok());
+
+    // Wrong public key.
+    let other_key = ed25519_dalek::SigningKey::from_bytes(&[2; 32])
+        .verifying_key()
+        .to_bytes();
+
+    assert!(matches!(
+        Rules::deserialize_verified(&data, &other_key).err().unwrap(),
+        SerializationError::InvalidSignature
+    ));
+
+    // Tampered data.
+    let len = data.len();
+    data[len / 2] ^= 0xff;
+
+    assert!(matches!(
+        Rules::deserialize_verified(&data, &public_key).err().unwrap(),
+        SerializationError::InvalidSignature
+    ));
+
+    // Data without signature.
+    assert!(matches!(
+        Rules::deserialize_verified(b"YARA-X", &public_key).err().unwrap(),
+        SerializationError::InvalidSignature
+    ));
+}
+
 #[test]
 fn namespaces() {
     // `foo` and `bar` are both in the default namespace, this compiles
@@ -651,6 +800,134 @@ fn banned_modules() {
     assert_eq!(compiler.errors().len(), 1);
 }
 
+#[cfg(feature = "test_proto2-module")]
+#[test]
+fn banned_symbols() {
+    let mut compiler = Compiler::new();
+
+    compiler
+        .ban_symbol(
+            "test_proto2.int32_zero",
+            "`int32_zero` can't be used",
+            "`int32_zero` is used here",
+        )
+        .ban_symbol(
+            "test_proto2.nested",
+            "`nested` can't be used",
+            "`nested` is used here",
+        )
+        .ban_symbol("uint8", "`uint8` can't be used", "`uint8` is used here")
+        .ban_symbol(
+            "filesize",
+            "`filesize` can't be used",
+            "`filesize` is used here",
+        );
+
+    assert_eq!(
+        compiler
+            .add_source(
+                r#"
+            import "test_proto2"
+            rule test { condition: test_proto2.int32_zero == 0 }
+            "#,
+            )
+            .expect_err("expected error")
+            .to_string(),
+        r#"error[E100]: `int32_zero` can't be used
+ --> line:3:36
+  |
+3 |             rule test { condition: test_proto2.int32_zero == 0 }
+  |                                    ^^^^^^^^^^^^^^^^^^^^^^ `int32_zero` is used here"#
+    );
+
+    assert_eq!(
+        compiler
+            .add_source(
+                r#"rule test { condition: test_proto2.nested.nested_int32_zero == 0 }"#,
+            )
+            .expect_err("expected error")
+            .to_string(),
+        r#"error[E100]: `nested` can't be used
+ --> line:1:24
+  |
+1 | rule test { condition: test_proto2.nested.nested_int32_zero == 0 }
+  |                        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ `nested` is used here"#
+    );
+
+    assert_eq!(
+        compiler
+            .add_source(r#"rule test { condition: uint8(0) == 0 }"#)
+            .expect_err("expected error")
+            .to_string(),
+        r#"error[E100]: `uint8` can't be used
+ --> line:1:24
+  |
+1 | rule test { condition: uint8(0) == 0 }
+  |                        ^^^^^^^^ `uint8` is used here"#
+    );
+
+    assert_eq!(
+        compiler
+            .add_source(r#"rule test { condition: filesize > 0 }"#)
+            .expect_err("expected error")
+            .to_string(),
+        r#"error[E100]: `filesize` can't be used
+ --> line:1:24
+  |
+1 | rule test { condition: filesize > 0 }
+  |                        ^^^^^^^^ `filesize` is used here"#
+    );
+
+    // Symbols that are not banned can be used.
+    assert!(compiler
+        .add_source(
+            r#"rule test { condition: test_proto2.int64_zero == 0 and uint16(0) == 0 }"#
+        )
+        .is_ok());
+}
+
+#[test]
+fn rule_features() {
+    let src = r#"
+        rule generic { condition: filesize > 0 }
+        rule win { meta: features = "win" condition: filesize > 0 }
+        rule elf_or_macho {
+          meta:
+            features = "elf, macho"
+          condition:
+            filesize > 0
+        }
+        rule depends_on_win { condition: win }
+        "#;
+
+    let rule_names = |features: Option<&[&str]>| {
+        let mut compiler = Compiler::new();
+        if let Some(features) = features {
+            compiler.with_features(features.iter().copied());
+        }
+        compiler.add_source(src).unwrap();
+        assert!(compiler.warnings().is_empty());
+        compiler
+            .build()
+            .iter()
+            .map(|r| r.identifier().to_string())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        rule_names(None),
+        ["generic", "win", "elf_or_macho", "depends_on_win"]
+    );
+
+    assert_eq!(
+        rule_names(Some(&["win"])),
+        ["generic", "win", "depends_on_win"]
+    );
+
+    assert_eq!(rule_names(Some(&["macho"])), ["generic", "elf_or_macho"]);
+    assert_eq!(rule_names(Some(&[])), ["generic"]);
+}
+
 #[test]
 fn linter_tag_list() {
     assert!(Compiler::new()
@@ -816,6 +1093,93 @@ fn linter_rule_name() {
     assert!(linters::rule_name("(AXS|ERS").is_err());
 }
 
+#[test]
+fn linter_rule_name_length() {
+    assert!(Compiler::new()
+        .add_linter(linters::rule_name_length(3))
+        .add_source(r#"rule foo { condition: filesize > 0 }"#)
+        .unwrap()
+        .warnings()
+        .is_empty());
+
+    assert_eq!(
+        Compiler::new()
+            .add_linter(linters::rule_name_length(3))
+            .add_source(r#"rule foobar { condition: filesize > 0 }"#)
+            .unwrap()
+            .warnings()
+            .iter()
+            .map(|w| w.to_string())
+            .collect::<Vec<_>>(),
+        &[r#"warning[rule_name_too_long]: rule name is too long
+ --> line:1:6
+  |
+1 | rule foobar { condition: filesize > 0 }
+  |      ------ this rule name has 6 characters, the maximum is 3"#]
+    );
+
+    assert_eq!(
+        Compiler::new()
+            .add_linter(linters::rule_name_length(3).error(true))
+            .add_source(r#"rule foobar { condition: filesize > 0 }"#)
+            .expect_err("expected error")
+            .to_string(),
+        "error[E048]: rule name is too long
+ --> line:1:6
+  |
+1 | rule foobar { condition: filesize > 0 }
+  |      ^^^^^^ this rule name has 6 characters, the maximum is 3"
+    );
+}
+
+#[test]
+fn linter_tags_required() {
+    let required = vec!["foo".to_string(), "bar".to_string()];
+
+    assert!(Compiler::new()
+        .add_linter(linters::tags_required(required.clone()))
+        .add_source(r#"rule test : bar baz foo { condition: filesize > 0 }"#)
+        .unwrap()
+        .warnings()
+        .is_empty());
+
+    assert_eq!(
+        Compiler::new()
+            .add_linter(linters::tags_required(required.clone()))
+            .add_source(r#"rule test { condition: filesize > 0 }"#)
+            .unwrap()
+            .warnings()
+            .iter()
+            .map(|w| w.to_string())
+            .collect::<Vec<_>>(),
+        &[
+            r#"warning[missing_tag]: required tag is missing
+ --> line:1:6
+  |
+1 | rule test { condition: filesize > 0 }
+  |      ---- required tag `foo` not found"#,
+            r#"warning[missing_tag]: required tag is missing
+ --> line:1:6
+  |
+1 | rule test { condition: filesize > 0 }
+  |      ---- required tag `bar` not found"#
+        ]
+    );
+
+    assert_eq!(
+        Compiler::new()
+            .add_linter(linters::tags_required(required).error(true))
+            .add_source(r#"rule test : foo { condition: filesize > 0 }"#)
+            .expect_err("expected error")
+            .to_string(),
+        "error[E049]: required tag is missing
+ --> line:1:6
+  |
+1 | rule test : foo { condition: filesize > 0 }
+  |      ^^^^ required tag `bar` not found"
+    );
+}
+
 #[test]
 fn linter_required_metadata() {
     assert!(Compiler::new()
@@ -1060,6 +1424,68 @@ fn duplicate_rule_error() {
     );
 }
 
+#[cfg(feature = "test_proto2-module")]
+#[test]
+fn conflicting_import_error() {
+    assert_eq!(
+        Compiler::new()
+            .add_source(
+                SourceCode::from("rule test_proto2 {condition: true}")
+                    .with_origin("a.yar"),
+            )
+            .unwrap()
+            .add_source(
+                SourceCode::from(r#"import "test_proto2""#)
+                    .with_origin("b.yar"),
+            )
+            .unwrap_err()
+            .to_string(),
+        r#"error[E050]: module `test_proto2` conflicts with an existing rule
+ --> b.yar:1:1
+  |
+1 | import "test_proto2"
+  | ^^^^^^^^^^^^^^^^^^^^ identifier already in use by a rule
+  |
+ ::: a.yar:1:6
+  |
+1 | rule test_proto2 {condition: true}
+  |      ----------- rule `test_proto2` declared here"#
+    );
+
+    assert_eq!(
+        Compiler::new()
+            .add_source(
+                SourceCode::from(r#"import "test_proto2" as foo"#)
+                    .with_origin("a.yar"),
+            )
+            .unwrap()
+            .add_source(
+                SourceCode::from("rule foo {condition: true}")
+                    .with_origin("b.yar"),
+            )
+            .unwrap_err()
+            .to_string(),
+        r#"error[E051]: rule `foo` conflicts with an imported module
+ --> b.yar:1:6
+  |
+1 | rule foo {condition: true}
+  |      ^^^ identifier already in use by a module
+  |
+ ::: a.yar:1:1
+  |
+1 | import "test_proto2" as foo
+  | --------------------------- `foo` imported here"#
+    );
+
+    // Rules and modules with the same name don't conflict when they are in
+    // different namespaces.
+    let mut compiler = Compiler::new();
+
+    compiler.add_source(r#"import "test_proto2""#).unwrap();
+    compiler.new_namespace("foo");
+    compiler.add_source("rule test_proto2 {condition: true}").unwrap();
+}
+
 #[cfg(feature = "constant-folding")]
 #[test]
 fn number_out_of_range_error() {
@@ -1206,6 +1632,62 @@ fn test_relative_includes() {
     assert_eq!(scanner.scan(b"").unwrap().matching_rules().len(), 1);
 }
 
+#[test]
+fn test_includes_relative_to_origin() {
+    let mut compiler = Compiler::new();
+
+    // Includes in the source code are relative to the directory of its
+    // origin. No include directories are specified.
+    compiler
+        .add_source(
+            SourceCode::from(r#"include "a/a.yar""#)
+                .with_origin("src/compiler/tests/testdata/includes/main.yar"),
+        )
+        .unwrap();
+
+    compiler
+        .add_source(
+            SourceCode::from("rule foo { condition: true }")
+                .with_origin("foo.yar"),
+        )
+        .unwrap();
+
+    compiler.add_source("rule bar { condition: true }").unwrap();
+
+    let rules = compiler.build();
+
+    let origins: Vec<_> =
+        rules.iter().map(|rule| (rule.identifier(), rule.origin())).collect();
+
+    #[cfg(target_family = "unix")]
+    assert_eq!(
+        origins,
+        [
+            (
+                "included",
+                Some("src/compiler/tests/testdata/includes/included_ok.yar")
+            ),
+            ("foo", Some("foo.yar")),
+            ("bar", None),
+        ]
+    );
+
+    // Errors in included files are reported with the path of the included
+    // file.
+    let err = Compiler::new()
+        .add_source(
+            SourceCode::from(r#"include "included_error.yar""#)
+                .with_origin("src/compiler/tests/testdata/includes/main.yar"),
+        )
+        .unwrap_err();
+
+    #[cfg(target_family = "unix")]
+    assert_eq!(
+        err.labels().next().unwrap().origin(),
+        Some("src/compiler/tests/testdata/includes/included_error.yar")
+    );
+}
+
 #[test]
 fn test_disable_includes() {
     let mut compiler = Compiler::new();
@@ -1264,6 +1746,56 @@ fn test_switch_all_warnings() {
     assert_eq!(compiler.warnings().len(), 0);
 }
 
+#[test]
+fn multiple_errors() {
+    let mut compiler = Compiler::new();
+
+    // The first error is returned by `add_source`, but the remaining rules
+    // are still compiled and their errors collected.
+    assert!(compiler
+        .add_source(
+            r#"
+rule b { condition: }
+rule a { condition: foo }
+rule c { condition: true }
+rule d { strings: $a = "a" condition: }
+"#,
+        )
+        .is_err());
+
+    compiler.new_namespace("other");
+
+    assert!(compiler
+        .add_source(
+            r#"
+rule e { condition: bar }
+rule f { condition: true }
+"#,
+        )
+        .is_err());
+
+    // Errors are sorted in the same order they appear in the source code,
+    // no matter if they are syntax or semantic errors.
+    assert_eq!(
+        compiler
+            .errors()
+            .iter()
+            .map(|err| (
+                err.code(),
+                err.labels().next().unwrap().span().start()
+            ))
+            .collect::<Vec<_>>(),
+        vec![("E001", 21), ("E009", 43), ("E001", 114), ("E009", 21)]
+    );
+
+    let rules = compiler.build();
+
+    assert_eq!(
+        rules.iter().map(|rule| rule.identifier()).collect::<Vec<_>>(),
+        vec!["c", "f"]
+    );
+}
+
 #[test]
 fn test_errors() {
     let mut mint = goldenfile::Mint::new(".");
@@ -1391,3 +1923,246 @@ fn test_filesize_bounds() {
         FilesizeBounds::from((Bound::Excluded(1), Bound::Excluded(1000)))
     );
 }
+
+#[test]
+fn error_line_and_column() {
+    let src = SourceCode::from(
+        r#"rule test {
+  condition:
+    "你好" contains foo
+}"#,
+    );
+
+    let mut compiler = Compiler::new();
+    let err = compiler.add_source(src.clone()).unwrap_err();
+    let label = err.labels().next().unwrap();
+
+    assert_eq!((label.line(), label.column()), (3, 19));
+    assert_eq!(src.line_col(label.span()), Some((3, 19)));
+    assert_eq!(src.snippet(label.span()), Some("foo"));
+}
+
+#[test]
+fn add_ast() {
+    let src = r#"
+rule foo { condition: true }
+rule bar { condition: baz }
+"#;
+
+    // The compiler doesn't know the source code, the error is reported at
+    // the start of an empty source code.
+    let err = Compiler::new().add_ast(crate::ast::AST::from(src)).unwrap_err();
+
+    assert_eq!(err.code(), "E009");
+    assert_eq!(err.labels().next().unwrap().span(), &crate::Span(0..0));
+    assert!(err.to_string().starts_with("error[E009]: unknown identifier"));
+
+    // With the source code, the error is reported at the right place.
+    let err = Compiler::new()
+        .add_ast_with_source(crate::ast::AST::from(src), src)
+        .unwrap_err();
+
+    assert_eq!(err.labels().next().unwrap().span(), &crate::Span(52..55));
+
+    // Transform the AST by removing the rule `bar`.
+    let mut ast = crate::ast::AST::from(src);
+
+    ast.items.retain(|item| match item {
+        crate::ast::Item::Rule(rule) => rule.identifier.name != "bar",
+        _ => true,
+    });
+
+    let mut compiler = Compiler::new();
+    compiler.add_ast(ast).unwrap();
+
+    let rules = compiler.build();
+    let mut scanner = Scanner::new(&rules);
+    let results = scanner.scan(&[]).unwrap();
+
+    assert_eq!(results.matching_rules().len(), 1);
+}
+
+#[test]
+fn sub_patterns() {
+    use crate::SubPatternKind;
+
+    let rules = compile(
+        r#"
+rule test {
+  strings:
+    $a = "foobar" ascii wide
+    $b = /foo\d+bar/
+    $c = "xyz" xor(1-2)
+    $d = { 01 02 03 04 [-] 05 06 07 08 }
+  condition:
+    all of them
+}"#,
+    )
+    .unwrap();
+
+    let rule = rules.iter().next().unwrap();
+
+    let sub_patterns: Vec<_> = rule
+        .patterns()
+        .flat_map(|pattern| pattern.sub_patterns().collect::<Vec<_>>())
+        .map(|sub_pattern| {
+            (
+                sub_pattern.kind(),
+                sub_pattern.pattern().to_string(),
+                sub_pattern.is_wide(),
+                sub_pattern.is_chained(),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        sub_patterns,
+        [
+            (
+                SubPatternKind::Literal,
+                "f\0o\0o\0b\0a\0r\0".to_string(),
+                true,
+                false
+            ),
+            (SubPatternKind::Literal, "foobar".to_string(), false, false),
+            (
+                SubPatternKind::Regexp,
+                r"(?:(?:foo)(?-u:[0-9])+(?:bar))".to_string(),
+                false,
+                false
+            ),
+            (SubPatternKind::Xor, "xyz".to_string(), false, false),
+            (
+                SubPatternKind::Literal,
+                "\x01\x02\x03\x04".to_string(),
+                false,
+                true
+            ),
+            (
+                SubPatternKind::Literal,
+                "\x05\x06\x07\x08".to_string(),
+                false,
+                true
+            ),
+        ]
+    );
+
+    let pattern_a = rule.patterns().next().unwrap();
+    let ascii = pattern_a.sub_patterns().nth(1).unwrap();
+
+    assert_eq!(ascii.atoms().collect::<Vec<_>>(), [b"obar".as_slice()]);
+
+    // The `xor` modifier produces one atom per key.
+    let pattern_c = rule.patterns().nth(2).unwrap();
+    let xor = pattern_c.sub_patterns().next().unwrap();
+
+    assert_eq!(xor.atoms().count(), 2);
+}
+
+#[test]
+fn rule_cost() {
+    let mut compiler = Compiler::new();
+
+    compiler
+        .warn_on_costly_rules(100)
+        .add_source(
+            r#"
+            import "test_proto2"
+            rule cheap {
+              strings:
+                $a = "abcdefgh"
+              condition:
+                $a
+            }
+            rule expensive {
+              strings:
+                $a = { 00 00 ?? ?? }
+                $b = /ab[0-9]+cd/
+              condition:
+                $a and $b and
+                for any i in (0..filesize) : ( uint8(i) == 0 ) and
+                for any i in (0..10) : ( test_proto2.add(i, 1) == 2 )
+            }
+            "#,
+        )
+        .unwrap();
+
+    // Only the expensive rule produces a `costly_rule` warning.
+    let costly_rules = compiler
+        .warnings()
+        .iter()
+        .filter(|w| w.code() == "costly_rule")
+        .map(|w| w.to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(costly_rules.len(), 1);
+    assert!(costly_rules[0].contains("rule `expensive` is potentially slow"));
+
+    let rules = compiler.build();
+    let mut iter = rules.iter();
+
+    let cheap = iter.next().unwrap();
+    let cost = cheap.cost();
+
+    assert_eq!(cost.num_atoms(), 1);
+    assert_eq!(cost.num_regexps(), 0);
+    assert_eq!(cost.num_loops(), 0);
+    assert_eq!(cost.score(), 0);
+
+    let expensive = iter.next().unwrap();
+    let cost = expensive.cost();
+
+    assert!(cost.min_atom_quality().unwrap() < 80);
+    // Both the hex pattern and the regexp are verified with the regexp
+    // engine.
+    assert_eq!(cost.num_regexps(), 2);
+    assert_eq!(cost.num_loops(), 2);
+    assert_eq!(cost.num_unbounded_loops(), 1);
+    assert_eq!(cost.num_func_calls(), 1);
+    assert!(cost.score() > cheap.cost().score());
+}
+
+#[test]
+fn rules_stats() {
+    let rules = compile(
+        r#"
+        import "test_proto2"
+        rule foo {
+          strings:
+            $a = "foobar"
+            $b = { 01 02 03 04 }
+          condition:
+            $a and $b and test_proto2.int32_one == 1
+        }
+        rule bar {
+          strings:
+            $a = "foobar"
+            $b = /qux[0-9]+/
+          condition:
+            $a or $b
+        }
+        "#,
+    )
+    .unwrap();
+
+    let stats = rules.stats();
+
+    assert_eq!(stats.num_rules(), 2);
+    // The "foobar" pattern is shared by both rules.
+    assert_eq!(stats.num_text_patterns(), 1);
+    assert_eq!(stats.num_hex_patterns(), 1);
+    assert_eq!(stats.num_regexp_patterns(), 1);
+    assert_eq!(stats.num_patterns(), 3);
+    // Each pattern produces at least one atom.
+    assert!(stats.num_atoms() >= stats.num_patterns());
+    assert_eq!(stats.num_imports(), 1);
+    assert!(stats.wasm_code_size() > 0);
+    assert!(stats.memory_footprint() > stats.wasm_code_size());
+
+    let empty = compile("rule empty { condition: true }").unwrap().stats();
+
+    assert_eq!(empty.num_patterns(), 0);
+    assert_eq!(empty.num_atoms(), 0);
+    assert_eq!(empty.num_imports(), 0);
+    assert!(empty.memory_footprint() < stats.memory_footprint());
+}
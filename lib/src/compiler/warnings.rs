@@ -21,6 +21,7 @@ pub enum Warning {
     AmbiguousExpression(Box<AmbiguousExpression>),
     BooleanIntegerComparison(Box<BooleanIntegerComparison>),
     ConsecutiveJumps(Box<ConsecutiveJumps>),
+    CostlyRule(Box<CostlyRule>),
     DeprecatedField(Box<DeprecatedField>),
     DuplicateImport(Box<DuplicateImport>),
     GlobalRuleMisuse(Box<GlobalRuleMisuse>),
@@ -31,16 +32,20 @@ pub enum Warning {
     InvalidTag(Box<InvalidTag>),
     InvariantBooleanExpression(Box<InvariantBooleanExpression>),
     MissingMetadata(Box<MissingMetadata>),
+    MissingTag(Box<MissingTag>),
     NonBooleanAsBoolean(Box<NonBooleanAsBoolean>),
     PotentiallySlowLoop(Box<PotentiallySlowLoop>),
     PotentiallyUnsatisfiableExpression(Box<PotentiallyUnsatisfiableExpression>),
     RedundantCaseModifier(Box<RedundantCaseModifier>),
+    RuleNameTooLong(Box<RuleNameTooLong>),
     SlowPattern(Box<SlowPattern>),
+    SlowRegexp(Box<SlowRegexp>),
     TextPatternAsHex(Box<TextPatternAsHex>),
     TooManyIterations(Box<TooManyIterations>),
     UnknownTag(Box<UnknownTag>),
     UnsatisfiableExpression(Box<UnsatisfiableExpression>),
     UnusedIdentifier(Box<UnusedIdentifier>),
+    WeakAtom(Box<WeakAtom>),
 }
 
 /// A hex pattern contains two or more consecutive jumps.
@@ -84,12 +89,64 @@ impl ConsecutiveJumps {
     }
 }
 
+/// A rule has an estimated cost higher than the configured threshold.
+///
+/// This warning is produced only if the compiler was configured with
+/// [`crate::Compiler::warn_on_costly_rules`]. See [`crate::RuleCost`] for
+/// details about how the cost is estimated.
+///
+/// ## Example
+///
+/// ```text
+/// warning[costly_rule]: rule `test` is potentially slow
+///  --> line:1:6
+///   |
+/// 1 | rule test { condition: for any i in (0..filesize) : ( uint8(i) == 0 ) }
+///   |      ---- the estimated cost of this rule is 110
+///   |
+///   = note: the cost is due to: 1 loop(s) with unknown bounds
+/// ```
+#[derive(ErrorStruct, Debug, PartialEq, Eq)]
+#[associated_enum(Warning)]
+#[warning(
+    code = "costly_rule",
+    title = "rule `{rule_ident}` is potentially slow",
+)]
+#[label(
+    "the estimated cost of this rule is {cost}",
+    rule_loc
+)]
+#[footer(note)]
+pub struct CostlyRule {
+    report: Report,
+    rule_ident: String,
+    cost: u64,
+    rule_loc: CodeLoc,
+    note: Option<String>,
+}
+
+impl CostlyRule {
+    /// Identifier of the rule.
+    #[inline]
+    pub fn rule(&self) -> &str {
+        self.rule_ident.as_str()
+    }
+
+    /// Estimated cost of the rule. See [`crate::RuleCost::score`].
+    #[inline]
+    pub fn cost(&self) -> u64 {
+        self.cost
+    }
+}
+
 /// A rule contains a loop that could be very slow.
 ///
 /// This warning indicates that a rule contains a `for` loop that may be very
 /// slow because it iterates over a range with an upper bound that depends on
 /// `filesize`. For very large files this may mean hundreds of millions of
-/// iterations.
+/// iterations. The same happens with upper bounds that depend on the number
+/// of occurrences of some pattern (i.e: `#a`), the offset of some match
+/// (i.e: `@a[1]`), or integers read from the scanned data (i.e: `uint32(0)`).
 ///
 /// # Example
 ///
@@ -240,6 +297,10 @@ pub struct InvariantBooleanExpression {
 ///   |              - this expression is `integer` but is being used as `bool`
 ///   |
 ///   = note: non-zero integers are considered `true`, while zero is `false`
+/// help: consider an explicit comparison
+///   |
+/// 3 |   condition: 2 != 0 and 3
+///   |                ++++
 /// ```
 #[derive(ErrorStruct, Debug, PartialEq, Eq)]
 #[associated_enum(Warning)]
@@ -408,6 +469,42 @@ pub struct SlowPattern {
     note: Option<String>,
 }
 
+/// A regexp pattern contains constructs that may slow down the scan.
+///
+/// This warning is raised for unbounded repetitions of `.` (e.g: `.*`, `.+`
+/// or `.{2,}`), specially when they appear at the start of the regexp, and
+/// for unbounded repetitions nested inside other unbounded repetitions, like
+/// in `(a+)+`.
+///
+/// ## Example
+///
+/// ```text
+/// warning[slow_regexp]: slow regular expression
+///  --> line:3:14
+///   |
+/// 3 |     $a = /foo.*bar/
+///   |              -- unbounded repetition of `.`
+///   |
+///   = note: consider using a bounded repetition like `.{0,N}`, where N is the maximum expected length
+/// ```
+#[derive(ErrorStruct, Debug, PartialEq, Eq)]
+#[associated_enum(Warning)]
+#[warning(
+    code = "slow_regexp",
+    title = "slow regular expression"
+)]
+#[label(
+    "{msg}",
+    loc
+)]
+#[footer(note)]
+pub struct SlowRegexp {
+    report: Report,
+    msg: String,
+    loc: CodeLoc,
+    note: Option<String>,
+}
+
 /// An unsupported module has been used.
 ///
 /// If you use [`crate::Compiler::ignore_module`] for telling the compiler
@@ -596,6 +693,36 @@ pub struct InvalidRuleName {
     regex: String,
 }
 
+/// Rule name is longer than allowed. This is only used if the compiler is
+/// configured to check for it (see: [`crate::linters::RuleNameLength`]).
+///
+/// ## Example
+///
+/// ```text
+/// warning[rule_name_too_long]: rule name is too long
+///  --> test.yar:13:6
+///    |
+/// 13 | rule pants_with_a_very_long_name {
+///    |      --------------------------- this rule name has 27 characters, the maximum is 16
+///    |
+/// ```
+#[derive(ErrorStruct, Debug, PartialEq, Eq)]
+#[associated_enum(Warning)]
+#[warning(
+    code = "rule_name_too_long",
+    title = "rule name is too long"
+)]
+#[label(
+    "this rule name has {len} characters, the maximum is {max_len}",
+    rule_loc
+)]
+pub struct RuleNameTooLong {
+    report: Report,
+    rule_loc: CodeLoc,
+    len: usize,
+    max_len: usize,
+}
+
 /// A loop or nested loops have a total number of iterations exceeding a
 /// predefined threshold.
 ///
@@ -661,6 +788,35 @@ pub struct UnknownTag {
     note: Option<String>,
 }
 
+/// Missing tag. This is only used if the compiler is configured to check
+/// for required tags (see: [`crate::linters::RequiredTags`]).
+///
+/// ## Example
+///
+/// ```text
+/// warning[missing_tag]: required tag is missing
+///  --> rules/test.yara:1:6
+///   |
+/// 1 | rule a : foo {
+///   |      - required tag `bar` not found
+///   |
+/// ```
+#[derive(ErrorStruct, Debug, PartialEq, Eq)]
+#[associated_enum(Warning)]
+#[warning(
+    code = "missing_tag",
+    title = "required tag is missing"
+)]
+#[label(
+    "required tag `{name}` not found",
+    rule_loc
+)]
+pub struct MissingTag {
+    report: Report,
+    rule_loc: CodeLoc,
+    name: String,
+}
+
 /// Tag does not match regex. This is only used if the compiler is configured to
 /// check for it (see: [`crate::linters::Tags`]).
 ///
@@ -814,4 +970,55 @@ pub struct GlobalRuleMisuse {
     report: Report,
     loc: CodeLoc,
     note: Option<String>,
-}
\ No newline at end of file
+}
+
+/// A pattern produces weak atoms.
+///
+/// Atoms are short sequences of bytes extracted from each pattern. The
+/// scanner looks for the atoms in the data, and every time an atom is found
+/// it verifies whether the whole pattern matches. Atoms that are too short,
+/// or formed by bytes that are very common, like `00 00`, appear so often
+/// in the data that they degrade the performance of the whole scan, not
+/// only of the rule that contains the pattern.
+///
+/// The warning indicates the worst atom extracted from the pattern.
+///
+/// ## Example
+///
+/// ```text
+/// warning[weak_atom]: pattern with weak atoms
+///  --> line:3:5
+///   |
+/// 3 |     $a = { 00 00 ?? ?? }
+///   |     -------------------- the best atom for this pattern is `{ 00 00 }`
+///   |
+///   = note: short or repetitive atoms appear very often in the scanned data, which slows down the scan
+/// ```
+#[derive(ErrorStruct, Debug, PartialEq, Eq)]
+#[associated_enum(Warning)]
+#[warning(
+    code = "weak_atom",
+    title = "pattern with weak atoms",
+)]
+#[label(
+    "the best atom for this pattern is `{atom}`",
+    pattern_loc
+)]
+#[footer(note)]
+pub struct WeakAtom {
+    report: Report,
+    atom: String,
+    pattern_loc: CodeLoc,
+    note: Option<String>,
+}
+
+impl WeakAtom {
+    /// The weakest atom extracted from the pattern.
+    ///
+    /// Printable atoms are represented as a quoted string, like `"ab"`,
+    /// and the rest as a sequence of hex bytes, like `{ 00 00 }`.
+    #[inline]
+    pub fn atom(&self) -> &str {
+        self.atom.as_str()
+    }
+}
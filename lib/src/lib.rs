@@ -46,10 +46,13 @@ assert_eq!(results.matching_rules().len(), 1);
 extern crate core;
 
 pub use compiler::compile;
+pub use compiler::Backend;
 pub use compiler::Compiler;
 pub use compiler::Patch;
+pub use compiler::RuleCost;
 pub use compiler::Rules;
 pub use compiler::RulesIter;
+pub use compiler::RulesStats;
 pub use compiler::SourceCode;
 pub use models::Match;
 pub use models::Matches;
@@ -59,11 +62,19 @@ pub use models::Pattern;
 pub use models::PatternKind;
 pub use models::Patterns;
 pub use models::Rule;
+pub use models::SubPattern;
+pub use models::SubPatternKind;
 pub use modules::mods;
 pub use scanner::blocks;
+pub use scanner::input;
+pub use scanner::multi;
+pub use scanner::CancellationToken;
+pub use scanner::MatchOverlapPolicy;
 pub use scanner::MatchingRules;
+pub use scanner::ModuleNotice;
 pub use scanner::ModuleOutputs;
 pub use scanner::NonMatchingRules;
+pub use scanner::OversizedDataPolicy;
 #[cfg(feature = "rules-profiling")]
 pub use scanner::ProfilingData;
 pub use scanner::ScanError;
@@ -71,7 +82,11 @@ pub use scanner::ScanOptions;
 pub use scanner::ScanResults;
 pub use scanner::Scanner;
 pub use variables::Variable;
+pub use yara_x_parser::ast;
+pub use yara_x_parser::Span;
 
+#[cfg(feature = "libyara-compat")]
+pub mod compat;
 mod compiler;
 mod modules;
 mod re;
@@ -108,6 +123,13 @@ pub mod errors {
     pub use crate::variables::VariableError;
 }
 
+pub mod proto {
+    //! Protobuf messages that represent scan results.
+    //!
+    //! See [`crate::ScanResults::to_proto`].
+    pub use crate::modules::protos::scan::*;
+}
+
 pub mod warnings {
     //! Warnings returned while compiling rules.
     pub use crate::compiler::warnings::*;
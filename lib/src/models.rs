@@ -37,6 +37,19 @@ impl<'a, 'r> Rule<'a, 'r> {
         self.rules.ident_pool().get(self.rule_info.namespace_ident_id).unwrap()
     }
 
+    /// Returns the origin of the source code where the rule was defined.
+    ///
+    /// This is the origin set with [`crate::SourceCode::with_origin`] for the
+    /// source code passed to [`crate::Compiler::add_source`], usually a file
+    /// path. For rules defined in included files, it is the path of the
+    /// included file. Returns `None` if the source code doesn't have an
+    /// origin.
+    pub fn origin(&self) -> Option<&'r str> {
+        self.rule_info
+            .origin
+            .map(|origin| self.rules.lit_pool().get_str(origin).unwrap())
+    }
+
     /// Returns the metadata associated to this rule.
     pub fn metadata(&self) -> Metadata<'a, 'r> {
         Metadata {
@@ -46,6 +59,13 @@ impl<'a, 'r> Rule<'a, 'r> {
         }
     }
 
+    /// Returns the estimated cost of the rule.
+    ///
+    /// See [`crate::RuleCost`].
+    pub fn cost(&self) -> &'r crate::RuleCost {
+        &self.rule_info.cost
+    }
+
     /// Returns true if the rule is global.
     pub fn is_global(&self) -> bool {
         self.rule_info.is_global
@@ -81,6 +101,60 @@ impl<'a, 'r> Rule<'a, 'r> {
             len_private: self.rule_info.num_private_patterns,
         }
     }
+
+    /// Returns the rule as a protobuf message.
+    ///
+    /// The message includes the rule's public patterns, together with their
+    /// matches.
+    pub fn to_proto(&self) -> crate::proto::Rule {
+        use crate::proto::metadata::Value;
+
+        let mut rule = crate::proto::Rule::new();
+
+        rule.set_namespace(self.namespace().to_string());
+        rule.set_identifier(self.identifier().to_string());
+        rule.tags =
+            self.tags().map(|tag| tag.identifier().to_string()).collect();
+
+        rule.metadata = self
+            .metadata()
+            .map(|(identifier, value)| {
+                let mut metadata = crate::proto::Metadata::new();
+                metadata.set_identifier(identifier.to_string());
+                metadata.value = Some(match value {
+                    MetaValue::Integer(i) => Value::Integer(i),
+                    MetaValue::Float(f) => Value::Float(f),
+                    MetaValue::Bool(b) => Value::Bool(b),
+                    MetaValue::String(s) => Value::String(s.to_string()),
+                    MetaValue::Bytes(b) => Value::Bytes(b.to_vec()),
+                });
+                metadata
+            })
+            .collect();
+
+        rule.patterns = self
+            .patterns()
+            .map(|pattern| {
+                let mut p = crate::proto::Pattern::new();
+                p.set_identifier(pattern.identifier().to_string());
+                p.matches = pattern
+                    .matches()
+                    .map(|m| {
+                        let mut match_ = crate::proto::Match::new();
+                        match_.set_offset(m.range().start as u64);
+                        match_.set_length(m.range().len() as u64);
+                        if let Some(key) = m.xor_key() {
+                            match_.set_xor_key(key.into());
+                        }
+                        match_
+                    })
+                    .collect();
+                p
+            })
+            .collect();
+
+        rule
+    }
 }
 
 /// A metadata value.
@@ -338,6 +412,37 @@ impl<'a, 'r> Pattern<'a, 'r> {
         self.is_private
     }
 
+    /// Returns true if the pattern reached the maximum number of matches
+    /// during the scan.
+    ///
+    /// When this happens, further matches for the pattern are ignored, and
+    /// [`Pattern::matches`] returns only a subset of them. The limit is set
+    /// with [`crate::Scanner::max_matches_per_pattern`].
+    pub fn limit_reached(&self) -> bool {
+        self.ctx
+            .is_some_and(|ctx| ctx.limit_reached.contains(&self.pattern_id))
+    }
+
+    /// Returns the sub-patterns generated for this pattern.
+    ///
+    /// Each pattern is compiled into one or more sub-patterns, for instance,
+    /// a text pattern with both the `ascii` and `wide` modifiers produces a
+    /// sub-pattern for each variant, and patterns with large gaps are split
+    /// into a chain of sub-patterns. This is useful for understanding how a
+    /// pattern is searched for during a scan.
+    pub fn sub_patterns(&self) -> impl Iterator<Item = SubPattern<'r>> + '_ {
+        self.rules
+            .sub_patterns()
+            .iter()
+            .enumerate()
+            .filter(|(_, (pattern_id, _))| *pattern_id == self.pattern_id)
+            .map(|(id, (_, sub_pattern))| SubPattern {
+                rules: self.rules,
+                sub_pattern_id: compiler::SubPatternId::from(id),
+                sub_pattern,
+            })
+    }
+
     /// Returns the matches found for this pattern.
     pub fn matches(&self) -> Matches<'a, 'r> {
         Matches {
@@ -351,6 +456,127 @@ impl<'a, 'r> Pattern<'a, 'r> {
     }
 }
 
+/// Kinds of sub-patterns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubPatternKind {
+    /// The sub-pattern is a literal string.
+    Literal,
+    /// The sub-pattern is a regular expression. Hex patterns are compiled
+    /// to regular expressions too, except when they are plain literals.
+    Regexp,
+    /// The sub-pattern is a literal string that matches with any of the
+    /// keys specified with the `xor` modifier.
+    Xor,
+    /// The sub-pattern is a literal string that matches base64-encoded.
+    Base64,
+    /// The sub-pattern is a literal string that matches base64-encoded and
+    /// then interleaved with zeroes.
+    Base64Wide,
+}
+
+/// One of the sub-patterns a pattern is compiled into.
+///
+/// See [`Pattern::sub_patterns`].
+pub struct SubPattern<'r> {
+    rules: &'r Rules,
+    sub_pattern_id: compiler::SubPatternId,
+    sub_pattern: &'r compiler::SubPattern,
+}
+
+impl<'r> SubPattern<'r> {
+    /// Returns the kind of this sub-pattern.
+    pub fn kind(&self) -> SubPatternKind {
+        match self.sub_pattern {
+            compiler::SubPattern::Literal { .. }
+            | compiler::SubPattern::LiteralChainHead { .. }
+            | compiler::SubPattern::LiteralChainTail { .. } => {
+                SubPatternKind::Literal
+            }
+            compiler::SubPattern::Regexp { .. }
+            | compiler::SubPattern::RegexpChainHead { .. }
+            | compiler::SubPattern::RegexpChainTail { .. } => {
+                SubPatternKind::Regexp
+            }
+            compiler::SubPattern::Xor { .. } => SubPatternKind::Xor,
+            compiler::SubPattern::Base64 { .. }
+            | compiler::SubPattern::CustomBase64 { .. } => {
+                SubPatternKind::Base64
+            }
+            compiler::SubPattern::Base64Wide { .. }
+            | compiler::SubPattern::CustomBase64Wide { .. } => {
+                SubPatternKind::Base64Wide
+            }
+        }
+    }
+
+    /// Returns the bytes searched for by this sub-pattern, or the text of
+    /// the regular expression if this is a [`SubPatternKind::Regexp`].
+    ///
+    /// For literals, the bytes already reflect the `wide` modifier. For
+    /// regular expressions with the `wide` modifier, the text of the
+    /// regular expression doesn't reflect it, use [`SubPattern::is_wide`]
+    /// instead. For `xor`, `base64` and `base64wide` sub-patterns, these are
+    /// the bytes before applying the modifier.
+    pub fn pattern(&self) -> &'r BStr {
+        self.rules.lit_pool().get(self.sub_pattern.pattern()).unwrap()
+    }
+
+    /// Returns true if the sub-pattern corresponds to the `wide` variant of
+    /// the pattern.
+    pub fn is_wide(&self) -> bool {
+        matches!(
+            self.sub_pattern,
+            compiler::SubPattern::Base64Wide { .. }
+                | compiler::SubPattern::CustomBase64Wide { .. }
+        ) || self.sub_pattern.flags().contains(compiler::SubPatternFlags::Wide)
+    }
+
+    /// Returns true if the sub-pattern is case-insensitive.
+    pub fn is_nocase(&self) -> bool {
+        self.sub_pattern.flags().contains(compiler::SubPatternFlags::Nocase)
+    }
+
+    /// Returns true if this sub-pattern is part of a chain of sub-patterns,
+    /// which happens when a pattern contains large gaps.
+    pub fn is_chained(&self) -> bool {
+        matches!(
+            self.sub_pattern,
+            compiler::SubPattern::LiteralChainHead { .. }
+                | compiler::SubPattern::LiteralChainTail { .. }
+                | compiler::SubPattern::RegexpChainHead { .. }
+                | compiler::SubPattern::RegexpChainTail { .. }
+        )
+    }
+
+    /// If the sub-pattern can match only at a fixed offset in the scanned
+    /// data, returns that offset. Such sub-patterns are not searched for
+    /// using atoms.
+    pub fn anchored_at(&self) -> Option<usize> {
+        match self.sub_pattern {
+            compiler::SubPattern::Literal { anchored_at, .. } => *anchored_at,
+            _ => None,
+        }
+    }
+
+    /// Returns the atoms selected for this sub-pattern.
+    ///
+    /// Atoms are the short byte sequences that the scanner searches for in
+    /// the scanned data. When an atom is found, the scanner verifies whether
+    /// the sub-pattern actually matches. Short atoms, or atoms containing
+    /// common bytes like zeroes, produce lots of verifications and slow
+    /// down the scan.
+    ///
+    /// This operation is slow, as it iterates over the atoms for all the
+    /// sub-patterns in the rules.
+    pub fn atoms(&self) -> impl Iterator<Item = &'r [u8]> + '_ {
+        self.rules
+            .atoms()
+            .iter()
+            .filter(|atom| atom.sub_pattern_id() == self.sub_pattern_id)
+            .map(|atom| atom.as_slice())
+    }
+}
+
 /// Iterator that returns the matches for a pattern.
 pub struct Matches<'a, 'r> {
     ctx: Option<&'a ScanContext<'r, 'a>>,
@@ -1,39 +1,51 @@
 // File generated automatically by build.rs. Do not edit.
 {
 #[cfg(feature = "console-module")]
-add_module!(modules, "console", console, "console.Console", Some("console"), Some(console::__main__ as MainFn));
+add_module!(modules, "console", console, "console.Console", Some("console"), Some(console::__main__ as MainFn), false);
 #[cfg(feature = "crx-module")]
-add_module!(modules, "crx", crx, "crx.Crx", Some("crx"), Some(crx::__main__ as MainFn));
+add_module!(modules, "crx", crx, "crx.Crx", Some("crx"), Some(crx::__main__ as MainFn), false);
 #[cfg(feature = "cuckoo-module")]
-add_module!(modules, "cuckoo", cuckoo, "cuckoo.Cuckoo", Some("cuckoo"), Some(cuckoo::__main__ as MainFn));
+add_module!(modules, "cuckoo", cuckoo, "cuckoo.Cuckoo", Some("cuckoo"), Some(cuckoo::__main__ as MainFn), true);
 #[cfg(feature = "dex-module")]
-add_module!(modules, "dex", dex, "dex.Dex", Some("dex"), Some(dex::__main__ as MainFn));
+add_module!(modules, "dex", dex, "dex.Dex", Some("dex"), Some(dex::__main__ as MainFn), false);
 #[cfg(feature = "dotnet-module")]
-add_module!(modules, "dotnet", dotnet, "dotnet.Dotnet", Some("dotnet"), Some(dotnet::__main__ as MainFn));
+add_module!(modules, "dotnet", dotnet, "dotnet.Dotnet", Some("dotnet"), Some(dotnet::__main__ as MainFn), false);
 #[cfg(feature = "elf-module")]
-add_module!(modules, "elf", elf, "elf.ELF", Some("elf"), Some(elf::__main__ as MainFn));
+add_module!(modules, "elf", elf, "elf.ELF", Some("elf"), Some(elf::__main__ as MainFn), false);
+#[cfg(feature = "email-module")]
+add_module!(modules, "email", email, "email.Email", Some("email"), Some(email::__main__ as MainFn), false);
 #[cfg(feature = "hash-module")]
-add_module!(modules, "hash", hash, "hash.Hash", Some("hash"), Some(hash::__main__ as MainFn));
+add_module!(modules, "hash", hash, "hash.Hash", Some("hash"), Some(hash::__main__ as MainFn), false);
+#[cfg(feature = "iso9660-module")]
+add_module!(modules, "iso9660", iso9660, "iso9660.Iso9660", Some("iso9660"), Some(iso9660::__main__ as MainFn), false);
 #[cfg(feature = "lnk-module")]
-add_module!(modules, "lnk", lnk, "lnk.Lnk", Some("lnk"), Some(lnk::__main__ as MainFn));
+add_module!(modules, "lnk", lnk, "lnk.Lnk", Some("lnk"), Some(lnk::__main__ as MainFn), false);
 #[cfg(feature = "macho-module")]
-add_module!(modules, "macho", macho, "macho.Macho", Some("macho"), Some(macho::__main__ as MainFn));
+add_module!(modules, "macho", macho, "macho.Macho", Some("macho"), Some(macho::__main__ as MainFn), false);
 #[cfg(feature = "magic-module")]
-add_module!(modules, "magic", magic, "magic.Magic", Some("magic"), Some(magic::__main__ as MainFn));
+add_module!(modules, "magic", magic, "magic.Magic", Some("magic"), Some(magic::__main__ as MainFn), false);
 #[cfg(feature = "math-module")]
-add_module!(modules, "math", math, "math.Math", Some("math"), Some(math::__main__ as MainFn));
+add_module!(modules, "math", math, "math.Math", Some("math"), Some(math::__main__ as MainFn), false);
+#[cfg(feature = "pcap-module")]
+add_module!(modules, "pcap", pcap, "pcap.Pcap", Some("pcap"), Some(pcap::__main__ as MainFn), false);
 #[cfg(feature = "pe-module")]
-add_module!(modules, "pe", pe, "pe.PE", Some("pe"), Some(pe::__main__ as MainFn));
+add_module!(modules, "pe", pe, "pe.PE", Some("pe"), Some(pe::__main__ as MainFn), false);
+#[cfg(feature = "regf-module")]
+add_module!(modules, "regf", regf, "regf.Regf", Some("regf"), Some(regf::__main__ as MainFn), false);
 #[cfg(feature = "string-module")]
-add_module!(modules, "string", string, "string.String", Some("string"), Some(string::__main__ as MainFn));
+add_module!(modules, "string", string, "string.String", Some("string"), Some(string::__main__ as MainFn), false);
 #[cfg(feature = "test_proto2-module")]
-add_module!(modules, "test_proto2", test_proto2, "test_proto2.TestProto2", Some("test_proto2"), Some(test_proto2::__main__ as MainFn));
+add_module!(modules, "test_proto2", test_proto2, "test_proto2.TestProto2", Some("test_proto2"), Some(test_proto2::__main__ as MainFn), false);
 #[cfg(feature = "test_proto3-module")]
-add_module!(modules, "test_proto3", test_proto3, "test_proto3.TestProto3", Some("test_proto3"), Some(test_proto3::__main__ as MainFn));
+add_module!(modules, "test_proto3", test_proto3, "test_proto3.TestProto3", Some("test_proto3"), Some(test_proto3::__main__ as MainFn), false);
 #[cfg(feature = "text-module")]
-add_module!(modules, "text", text, "text.Text", Some("text"), Some(text::__main__ as MainFn));
+add_module!(modules, "text", text, "text.Text", Some("text"), Some(text::__main__ as MainFn), false);
 #[cfg(feature = "time-module")]
-add_module!(modules, "time", time, "time.Time", Some("time"), Some(time::__main__ as MainFn));
+add_module!(modules, "time", time, "time.Time", Some("time"), Some(time::__main__ as MainFn), false);
 #[cfg(feature = "vt-module")]
-add_module!(modules, "vt", titan, "vt.titan.LiveHuntData", Some("vt"), Some(vt::__main__ as MainFn));
+add_module!(modules, "vt", titan, "vt.titan.LiveHuntData", Some("vt"), Some(vt::__main__ as MainFn), false);
+#[cfg(feature = "wasm-module")]
+add_module!(modules, "wasm", wasm, "wasm.Wasm", Some("wasm"), Some(wasm::__main__ as MainFn), false);
+#[cfg(feature = "x509-module")]
+add_module!(modules, "x509", x509, "x509.X509", Some("x509"), Some(x509::__main__ as MainFn), false);
 }
\ No newline at end of file
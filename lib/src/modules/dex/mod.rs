@@ -5,7 +5,6 @@ This allows creating YARA rules based on metadata extracted from those files.
 
 use sha1::{Digest, Sha1};
 use simd_adler32::Adler32;
-use std::cell::RefCell;
 
 use crate::modules::prelude::*;
 use crate::modules::protos::dex::*;
@@ -15,12 +14,6 @@ pub mod parser;
 #[cfg(test)]
 mod tests;
 
-thread_local!(
-    static CHECKSUM_CACHE: RefCell<Option<i64>> = const { RefCell::new(None) };
-    static SIGNATURE_CACHE: RefCell<Option<String>> =
-        const { RefCell::new(None) };
-);
-
 #[module_main]
 fn main(data: &[u8], _meta: Option<&[u8]>) -> Result<Dex, ModuleError> {
     match parser::Dex::parse(data) {
@@ -40,13 +33,10 @@ fn main(data: &[u8], _meta: Option<&[u8]>) -> Result<Dex, ModuleError> {
 /// the one in the header.
 #[module_export]
 fn checksum(ctx: &mut ScanContext) -> Option<i64> {
-    let cached: Option<i64> =
-        CHECKSUM_CACHE.with(|cache| -> Option<i64> { *cache.borrow() });
-
-    if cached.is_some() {
-        return cached;
-    }
+    ctx.computed_value("dex.checksum", checksum_impl)
+}
 
+fn checksum_impl(ctx: &mut ScanContext) -> Option<i64> {
     let dex = ctx.module_output::<Dex>()?;
     if !dex.is_dex() {
         return None;
@@ -59,13 +49,8 @@ fn checksum(ctx: &mut ScanContext) -> Option<i64> {
 
     let mut adler = Adler32::new();
     adler.write(data);
-    let hash = adler.finish();
-
-    CHECKSUM_CACHE.with(|cache| {
-        *cache.borrow_mut() = Some(hash.into());
-    });
 
-    Some(hash.into())
+    Some(adler.finish().into())
 }
 
 /// Function that return the sha1 signature for the current DEX file.
@@ -75,18 +60,11 @@ fn checksum(ctx: &mut ScanContext) -> Option<i64> {
 /// the on in the header.
 #[module_export]
 fn signature(ctx: &mut ScanContext) -> Option<Lowercase<FixedLenString<40>>> {
-    let cached = SIGNATURE_CACHE.with(
-        |cache| -> Option<Lowercase<FixedLenString<40>>> {
-            cache.borrow().as_deref().map(|s| {
-                Lowercase::<FixedLenString<40>>::from_slice(ctx, s.as_bytes())
-            })
-        },
-    );
-
-    if cached.is_some() {
-        return cached;
-    }
+    let digest = ctx.computed_value("dex.signature", signature_impl)?;
+    Some(Lowercase::<FixedLenString<40>>::new(digest))
+}
 
+fn signature_impl(ctx: &mut ScanContext) -> Option<String> {
     let dex = ctx.module_output::<Dex>()?;
     if !dex.is_dex() {
         return None;
@@ -99,13 +77,8 @@ fn signature(ctx: &mut ScanContext) -> Option<Lowercase<FixedLenString<40>>> {
 
     let mut hasher = Sha1::new();
     hasher.update(data);
-    let digest = format!("{:x}", hasher.finalize());
 
-    SIGNATURE_CACHE.with(|cache| {
-        *cache.borrow_mut() = Some(digest.clone());
-    });
-
-    Some(Lowercase::<FixedLenString<40>>::new(digest))
+    Some(format!("{:x}", hasher.finalize()))
 }
 
 /// Function that checks whether the DEX file contains the specified string
@@ -4,7 +4,6 @@ This allows creating YARA rules based on ELF metadata, including segments
 and sections information, exported symbols, target platform, etc.
  */
 
-use std::cell::RefCell;
 use std::sync::LazyLock;
 
 use itertools::Itertools;
@@ -20,17 +19,8 @@ pub mod parser;
 mod tests;
 mod tlsh;
 
-thread_local!(
-    static IMPORT_MD5_CACHE: RefCell<Option<String>> =
-        const { RefCell::new(None) };
-    static TLSH_CACHE: RefCell<Option<String>> = const { RefCell::new(None) };
-);
-
 #[module_main]
 fn main(data: &[u8], _meta: Option<&[u8]>) -> Result<ELF, ModuleError> {
-    IMPORT_MD5_CACHE.with(|cache| *cache.borrow_mut() = None);
-    TLSH_CACHE.with(|cache| *cache.borrow_mut() = None);
-
     match parser::ElfParser::new().parse(data) {
         Ok(elf) => Ok(elf),
         Err(_) => Ok(ELF::new()),
@@ -39,18 +29,11 @@ fn main(data: &[u8], _meta: Option<&[u8]>) -> Result<ELF, ModuleError> {
 
 #[module_export]
 fn import_md5(ctx: &mut ScanContext) -> Option<Lowercase<FixedLenString<32>>> {
-    let cached = IMPORT_MD5_CACHE.with(
-        |cache| -> Option<Lowercase<FixedLenString<32>>> {
-            cache.borrow().as_deref().map(|s| {
-                Lowercase::<FixedLenString<32>>::from_slice(ctx, s.as_bytes())
-            })
-        },
-    );
-
-    if cached.is_some() {
-        return cached;
-    }
+    let digest = ctx.computed_value("elf.import_md5", import_md5_impl)?;
+    Some(Lowercase::<FixedLenString<32>>::new(digest))
+}
 
+fn import_md5_impl(ctx: &mut ScanContext) -> Option<String> {
     let elf = ctx.module_output::<ELF>()?;
 
     let symbols = if elf.dynsym.is_empty() {
@@ -72,13 +55,7 @@ fn import_md5(ctx: &mut ScanContext) -> Option<Lowercase<FixedLenString<32>>> {
     let mut hasher = Md5::new();
     hasher.update(comma_separated_names.as_bytes());
 
-    let digest = format!("{:x}", hasher.finalize());
-
-    IMPORT_MD5_CACHE.with(|cache| {
-        *cache.borrow_mut() = Some(digest.clone());
-    });
-
-    Some(Lowercase::<FixedLenString<32>>::new(digest))
+    Some(format!("{:x}", hasher.finalize()))
 }
 
 /// Function names excluded while computing the telfhash. These exclusions
@@ -107,17 +84,11 @@ pub(crate) static TELFHASH_EXCLUSIONS: LazyLock<FxHashSet<&'static str>> =
 /// [1]: https://github.com/trendmicro/telfhash
 #[module_export]
 fn telfhash(ctx: &mut ScanContext) -> Option<Uppercase<FixedLenString<72>>> {
-    let cached =
-        TLSH_CACHE.with(|cache| -> Option<Uppercase<FixedLenString<72>>> {
-            cache.borrow().as_deref().map(|s| {
-                Uppercase::<FixedLenString<72>>::from_slice(ctx, s.as_bytes())
-            })
-        });
-
-    if cached.is_some() {
-        return cached;
-    }
+    let digest = ctx.computed_value("elf.telfhash", telfhash_impl)?;
+    Some(Uppercase::<FixedLenString<72>>::new(digest))
+}
 
+fn telfhash_impl(ctx: &mut ScanContext) -> Option<String> {
     let elf = ctx.module_output::<ELF>()?;
 
     // Prefer dynsym over symbtab.
@@ -167,11 +138,5 @@ fn telfhash(ctx: &mut ScanContext) -> Option<Uppercase<FixedLenString<72>>> {
 
     builder.update(comma_separated_names.as_bytes());
 
-    let digest = builder.build().ok()?.hash();
-
-    IMPORT_MD5_CACHE.with(|cache| {
-        *cache.borrow_mut() = Some(digest.clone());
-    });
-
-    Some(Uppercase::<FixedLenString<72>>::new(digest))
+    Some(builder.build().ok()?.hash())
 }
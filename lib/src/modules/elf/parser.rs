@@ -3,7 +3,7 @@ use std::ops::Range;
 
 use nom::bytes::complete::{take, take_till};
 use nom::combinator::{map, map_res, verify};
-use nom::multi::{count, many0};
+use nom::multi::{count, many0, many_m_n};
 use nom::number::complete::{le_u32, u16, u32, u64, u8};
 use nom::number::Endianness;
 use nom::{Err, IResult, Parser};
@@ -12,6 +12,7 @@ use num_traits::FromPrimitive;
 use protobuf::EnumOrUnknown;
 
 use crate::modules::protos::elf;
+use crate::modules::utils::limits::ParseLimits;
 
 #[repr(u8)]
 #[derive(FromPrimitive)]
@@ -214,6 +215,8 @@ impl ElfParser {
     const ELF_SHT_NOBITS: u32 = 8;
     const ELF_SHT_DYNSYM: u32 = 11;
 
+    const LIMITS: ParseLimits = ParseLimits::DEFAULT;
+
     /// Parses an offset or address.
     ///
     /// The size of an offset or address in an ELF file depends on the class
@@ -265,7 +268,9 @@ impl ElfParser {
 
     fn parse_segments(&self, ehdr: &Ehdr, input: &[u8]) -> Option<Vec<Phdr>> {
         input.get(ehdr.ph_offset as usize..).and_then(|segments| {
-            count(self.parse_phdr(), ehdr.ph_entry_count as usize)
+            let n =
+                (ehdr.ph_entry_count as usize).min(Self::LIMITS.max_sections);
+            count(self.parse_phdr(), n)
                 .parse(segments)
                 .map(|(_, segments)| segments)
                 .ok()
@@ -274,7 +279,9 @@ impl ElfParser {
 
     fn parse_sections(&self, ehdr: &Ehdr, input: &[u8]) -> Option<Vec<Shdr>> {
         input.get(ehdr.sh_offset as usize..).and_then(|sections| {
-            count(self.parse_shdr(), ehdr.sh_entry_count as usize)
+            let n =
+                (ehdr.sh_entry_count as usize).min(Self::LIMITS.max_sections);
+            count(self.parse_shdr(), n)
                 .parse(sections)
                 .map(|(_, sections)| sections)
                 .ok()
@@ -451,10 +458,14 @@ impl ElfParser {
         if let Some(symtab) = sections.iter().find(predicate) {
             if let Some(range) = symtab.offset_range() {
                 if let Some(data) = elf.get(range) {
-                    let syms = many0(self.parse_sym())
-                        .parse(data)
-                        .map(|(_, syms)| syms)
-                        .ok();
+                    let syms = many_m_n(
+                        0,
+                        Self::LIMITS.max_symbols,
+                        self.parse_sym(),
+                    )
+                    .parse(data)
+                    .map(|(_, syms)| syms)
+                    .ok();
 
                     let symtabstr = sections.get(symtab.link as usize);
 
@@ -295,9 +295,9 @@ impl TlshBuilder {
             (j0 + WINDOW_SIZE - 4) % WINDOW_SIZE,
         );
 
-        let mut fed_len = self.data_len;
-
-        for item in data.iter().skip(offset).take(len) {
+        for (fed_len, item) in
+            (self.data_len..).zip(data.iter().skip(offset).take(len))
+        {
             self.slide_window[j0] = *item;
 
             if fed_len >= 4 {
@@ -375,8 +375,6 @@ impl TlshBuilder {
                 self.buckets[r as usize] += 1;
             }
 
-            fed_len += 1;
-
             let tmp = j4;
 
             j4 = j3;
@@ -0,0 +1,27 @@
+/*! YARA module that parses email messages.
+
+This module parses messages in RFC 822 format, including MIME messages, like
+the ones stored in `.eml` files. It exposes the message headers, the
+attachments, and the URLs found in the text parts of the message, which
+allows creating anti-phishing rules without preprocessing the messages.
+ */
+
+use crate::modules::prelude::*;
+use crate::modules::protos::email::*;
+
+mod parser;
+
+#[cfg(test)]
+mod tests;
+
+#[module_main]
+fn main(data: &[u8], _meta: Option<&[u8]>) -> Result<Email, ModuleError> {
+    match parser::EmailParser::new().parse(data) {
+        Some(email) => Ok(email),
+        None => {
+            let mut email = Email::new();
+            email.set_is_email(false);
+            Ok(email)
+        }
+    }
+}
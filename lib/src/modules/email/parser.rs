@@ -0,0 +1,559 @@
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+use base64::alphabet;
+use base64::engine::general_purpose::{GeneralPurpose, GeneralPurposeConfig};
+use base64::engine::DecodePaddingMode;
+use base64::Engine;
+use memchr::memmem;
+use regex::bytes::Regex;
+
+use crate::modules::protos::email::{Attachment, Email};
+
+/// Base64 engine used for decoding email content, which is tolerant with
+/// missing padding and trailing bits.
+const BASE64_ENGINE: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::STANDARD,
+    GeneralPurposeConfig::new()
+        .with_decode_padding_mode(DecodePaddingMode::Indifferent)
+        .with_decode_allow_trailing_bits(true),
+);
+
+/// List of `(name, value)` pairs, used for headers and header parameters.
+type Params = Vec<(String, String)>;
+
+/// Regular expression that matches URLs in text parts.
+static URL_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?i)\b(?:https?|ftp)://[^\s"'<>()\[\]{}\\^`|]+"#).unwrap()
+});
+
+/// Regular expression that matches MIME encoded-words in headers
+/// (RFC 2047), like `=?utf-8?B?SGVsbG8=?=`.
+static ENCODED_WORD_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"=\?([^?\s]+)\?([bBqQ])\?([^?\s]*)\?=").unwrap()
+});
+
+/// Headers that, besides `From`, must appear in a message. At least one of
+/// them is required for considering that the file is an email.
+const EXPECTED_HEADERS: &[&str] =
+    &["date", "message-id", "mime-version", "received", "subject", "to"];
+
+/// A parser for email messages in RFC 822 format, including MIME messages.
+pub struct EmailParser {
+    result: Email,
+    urls: HashSet<String>,
+    depth: usize,
+}
+
+impl EmailParser {
+    /// Maximum nesting level for multipart messages.
+    const MAX_DEPTH: usize = 16;
+
+    /// Maximum number of MIME parts processed.
+    const MAX_PARTS: u64 = 1024;
+
+    /// Maximum number of distinct URLs included in the result.
+    const MAX_URLS: usize = 1024;
+
+    /// Maximum length of a header line.
+    const MAX_LINE_LENGTH: usize = 8192;
+
+    /// Creates a new parser for email messages.
+    pub fn new() -> Self {
+        Self { result: Email::new(), urls: HashSet::new(), depth: 0 }
+    }
+
+    /// Parses an email message. Returns `None` if the data doesn't look like
+    /// an email.
+    pub fn parse(mut self, data: &[u8]) -> Option<Email> {
+        // Messages in mbox files start with a "From " line that is not an
+        // actual header.
+        let data = if data.starts_with(b"From ") {
+            let eol = memchr::memchr(b'\n', data)?;
+            &data[eol + 1..]
+        } else {
+            data
+        };
+
+        let (headers, body) = parse_headers(data, true)?;
+
+        let has_header =
+            |name: &str| headers.iter().any(|(n, _)| n.as_str() == name);
+
+        if !has_header("from")
+            || !EXPECTED_HEADERS.iter().any(|h| has_header(h))
+        {
+            return None;
+        }
+
+        self.result.set_is_email(true);
+
+        for (name, value) in &headers {
+            if !self.result.headers.contains_key(name) {
+                self.result
+                    .headers
+                    .insert(name.clone(), decode_encoded_words(value));
+            }
+        }
+
+        let content_type = header_value(&headers, "content-type")
+            .map(parse_header_value)
+            .map(|(value, _)| value)
+            .unwrap_or_else(|| "text/plain".to_string());
+
+        self.result.set_content_type(content_type);
+        self.parse_part(&headers, body);
+
+        // Make sure that `number_of_urls` is defined even if the message
+        // doesn't contain any URL.
+        self.result.set_number_of_urls(self.result.number_of_urls());
+
+        Some(self.result)
+    }
+
+    /// Process a MIME part, given its headers and body.
+    fn parse_part(&mut self, headers: &[(String, String)], body: &[u8]) {
+        let (content_type, content_type_params) =
+            header_value(headers, "content-type")
+                .map(parse_header_value)
+                .unwrap_or_else(|| ("text/plain".to_string(), Vec::new()));
+
+        if content_type.starts_with("multipart/") {
+            let boundary = param(&content_type_params, "boundary");
+            if let Some(boundary) = boundary {
+                if self.depth < Self::MAX_DEPTH {
+                    self.depth += 1;
+                    for part in split_multipart(body, boundary.as_bytes()) {
+                        if let Some((headers, body)) =
+                            parse_headers(part, false)
+                        {
+                            self.parse_part(&headers, body);
+                        }
+                    }
+                    self.depth -= 1;
+                }
+                return;
+            }
+        }
+
+        if self.result.number_of_parts() >= Self::MAX_PARTS {
+            return;
+        }
+
+        self.result.set_number_of_parts(self.result.number_of_parts() + 1);
+
+        let (disposition, disposition_params) =
+            header_value(headers, "content-disposition")
+                .map(parse_header_value)
+                .unwrap_or_default();
+
+        let name = param(&disposition_params, "filename")
+            .or_else(|| param(&content_type_params, "name"));
+
+        let encoding = header_value(headers, "content-transfer-encoding")
+            .map(|encoding| encoding.trim().to_lowercase());
+
+        let body = match encoding.as_deref() {
+            Some("base64") => decode_base64(body),
+            Some("quoted-printable") => decode_quoted_printable(body, false),
+            _ => body.to_vec(),
+        };
+
+        if name.is_some() || disposition == "attachment" {
+            let mut attachment = Attachment::new();
+            attachment.name = name.map(|name| decode_encoded_words(&name));
+            attachment.set_content_type(content_type);
+            attachment.set_size(body.len() as u64);
+            self.result.attachments.push(attachment);
+        } else if content_type.starts_with("text/") {
+            self.find_urls(body.as_slice());
+        }
+    }
+
+    /// Finds the URLs in a text part.
+    fn find_urls(&mut self, text: &[u8]) {
+        for m in URL_REGEX.find_iter(text) {
+            // Punctuation at the end of the URL is most likely part of the
+            // text that contains the URL.
+            let url = m.as_bytes().trim_ascii_end();
+            let url = match url.iter().rposition(|c| !b".,;:!?".contains(c)) {
+                Some(end) => &url[..=end],
+                None => continue,
+            };
+
+            self.result.set_number_of_urls(self.result.number_of_urls() + 1);
+
+            let url = String::from_utf8_lossy(url).to_string();
+
+            if self.urls.len() < Self::MAX_URLS
+                && self.urls.insert(url.clone())
+            {
+                self.result.urls.push(url);
+            }
+        }
+    }
+}
+
+/// Parses the headers at the start of `input`. Returns the headers, with
+/// lowercase names and unfolded values, and the remaining data after the
+/// empty line that separates headers from the body.
+///
+/// In `strict` mode the function returns `None` if the first line is not a
+/// valid header, or if some line in the header block is not a valid header.
+/// In non-strict mode, an invalid line is considered the start of the body.
+fn parse_headers(input: &[u8], strict: bool) -> Option<(Params, &[u8])> {
+    let mut headers = Params::new();
+    let mut remainder = input;
+
+    while !remainder.is_empty() {
+        let (line, next) = match memchr::memchr(b'\n', remainder) {
+            Some(eol) => (&remainder[..eol], &remainder[eol + 1..]),
+            None => (remainder, &remainder[remainder.len()..]),
+        };
+
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+
+        // An empty line marks the end of the headers.
+        if line.is_empty() {
+            return Some((headers, next));
+        }
+
+        if strict && line.len() > EmailParser::MAX_LINE_LENGTH {
+            return None;
+        }
+
+        // Lines starting with whitespaces are continuations of the
+        // previous header.
+        if line[0] == b' ' || line[0] == b'\t' {
+            match headers.last_mut() {
+                Some((_, value)) => {
+                    value.push(' ');
+                    value.push_str(
+                        String::from_utf8_lossy(line.trim_ascii()).as_ref(),
+                    );
+                    remainder = next;
+                    continue;
+                }
+                None if strict => return None,
+                None => return Some((headers, remainder)),
+            }
+        }
+
+        let header = memchr::memchr(b':', line).and_then(|colon| {
+            let name = &line[..colon];
+            let valid_name = !name.is_empty()
+                && name.iter().all(|c| c.is_ascii_graphic() && *c != b':');
+            valid_name.then(|| (name, &line[colon + 1..]))
+        });
+
+        match header {
+            Some((name, value)) => {
+                headers.push((
+                    String::from_utf8_lossy(name).to_lowercase(),
+                    String::from_utf8_lossy(value.trim_ascii()).to_string(),
+                ));
+            }
+            None if strict => return None,
+            None => return Some((headers, remainder)),
+        }
+
+        remainder = next;
+    }
+
+    if strict && headers.is_empty() {
+        return None;
+    }
+
+    Some((headers, remainder))
+}
+
+/// Returns the value of the first header with the given name.
+fn header_value<'a>(
+    headers: &'a [(String, String)],
+    name: &str,
+) -> Option<&'a str> {
+    headers.iter().find(|(n, _)| n == name).map(|(_, v)| v.as_str())
+}
+
+/// Returns the value of the parameter with the given name.
+fn param(params: &[(String, String)], name: &str) -> Option<String> {
+    params.iter().find(|(n, _)| n == name).map(|(_, v)| v.clone())
+}
+
+/// Parses header values with parameters, like the ones in `Content-Type`
+/// and `Content-Disposition`.
+///
+/// For instance, for `text/plain; charset="utf-8"` the result is the main
+/// value in lowercase (`text/plain`), and the list of parameters, with
+/// lowercase names and unquoted values (`[("charset", "utf-8")]`).
+///
+/// Parameters split in multiple sections, or with charset and percent
+/// encoding, as described in RFC 2231, are joined and decoded.
+fn parse_header_value(value: &str) -> (String, Params) {
+    let mut items = split_unquoted(value, ';').into_iter();
+    let main_value = items.next().unwrap_or_default().trim().to_lowercase();
+    let mut params = Params::new();
+
+    for item in items {
+        let Some((name, value)) = item.split_once('=') else {
+            continue;
+        };
+
+        let name = name.trim().to_lowercase();
+        let value = unquote(value.trim());
+
+        // In RFC 2231, `name*` indicates that the value is percent-encoded
+        // and prefixed by a charset and language, while `name*0`, `name*1`,
+        // etc, are sections of the value that must be concatenated.
+        let (name, extended) = match name.strip_suffix('*') {
+            Some(name) => (name, true),
+            None => (name.as_str(), false),
+        };
+
+        let (name, section) = match name.split_once('*') {
+            Some((name, section)) => (name, section.parse::<u32>().ok()),
+            None => (name, None),
+        };
+
+        let value = if extended {
+            // The charset and language are present only in the first
+            // section, delimited by single quotes.
+            let value = match section {
+                None | Some(0) => {
+                    value.splitn(3, '\'').nth(2).unwrap_or(value.as_str())
+                }
+                _ => value.as_str(),
+            };
+            percent_decode(value)
+        } else {
+            value
+        };
+
+        match params.iter_mut().find(|(n, _)| n == name) {
+            Some((_, v)) if section.is_some_and(|s| s > 0) => {
+                v.push_str(value.as_str())
+            }
+            Some(_) => {}
+            None => params.push((name.to_string(), value)),
+        }
+    }
+
+    (main_value, params)
+}
+
+/// Splits a string by the given separator, ignoring separators enclosed in
+/// double quotes.
+fn split_unquoted(s: &str, separator: char) -> Vec<&str> {
+    let mut result = Vec::new();
+    let mut quoted = false;
+    let mut escaped = false;
+    let mut start = 0;
+
+    for (i, c) in s.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            c if c == separator && !quoted => {
+                result.push(&s[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+
+    result.push(&s[start..]);
+    result
+}
+
+/// Removes the double quotes around a string, if any, and resolves escaped
+/// characters within the quotes.
+fn unquote(s: &str) -> String {
+    match s.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        Some(s) => {
+            let mut result = String::with_capacity(s.len());
+            let mut chars = s.chars();
+            while let Some(c) = chars.next() {
+                match c {
+                    '\\' => result.extend(chars.next()),
+                    c => result.push(c),
+                }
+            }
+            result
+        }
+        None => s.to_string(),
+    }
+}
+
+/// Decodes percent-encoded strings, like `invoice%202024.pdf`.
+fn percent_decode(s: &str) -> String {
+    let mut result = Vec::with_capacity(s.len());
+    let mut bytes = s.as_bytes().iter();
+
+    while let Some(b) = bytes.next() {
+        match b {
+            b'%' => {
+                let hex = bytes.as_slice().get(..2).and_then(|hex| {
+                    u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok()
+                });
+                match hex {
+                    Some(byte) => {
+                        result.push(byte);
+                        bytes.nth(1);
+                    }
+                    None => result.push(*b),
+                }
+            }
+            b => result.push(*b),
+        }
+    }
+
+    String::from_utf8_lossy(result.as_slice()).to_string()
+}
+
+/// Splits the body of a multipart message into its parts.
+///
+/// Parts are delimited by lines starting with `--` followed by the
+/// boundary. The line with the boundary followed by `--` marks the end of
+/// the last part. Anything before the first delimiter or after the last one
+/// is ignored.
+fn split_multipart<'a>(body: &'a [u8], boundary: &[u8]) -> Vec<&'a [u8]> {
+    let mut delimiter = b"--".to_vec();
+    delimiter.extend_from_slice(boundary);
+
+    // Start and end offset for each delimiter line, excluding the line
+    // break that precedes the delimiter.
+    let mut delimiters = Vec::new();
+
+    for start in memmem::find_iter(body, delimiter.as_slice()) {
+        // Delimiters must be at the start of a line.
+        if start > 0 && body[start - 1] != b'\n' {
+            continue;
+        }
+
+        let line_end = memchr::memchr(b'\n', &body[start..])
+            .map(|eol| start + eol + 1)
+            .unwrap_or(body.len());
+
+        let closing = body[start + delimiter.len()..].starts_with(b"--");
+
+        // The line break before the delimiter belongs to the delimiter.
+        let mut part_end = start;
+        if body[..part_end].ends_with(b"\n") {
+            part_end -= 1;
+        }
+        if body[..part_end].ends_with(b"\r") {
+            part_end -= 1;
+        }
+
+        delimiters.push((part_end, line_end));
+
+        if closing {
+            break;
+        }
+    }
+
+    delimiters.windows(2).filter_map(|w| body.get(w[0].1..w[1].0)).collect()
+}
+
+/// Decodes base64 content, ignoring any character outside the base64
+/// alphabet, like line breaks.
+fn decode_base64(input: &[u8]) -> Vec<u8> {
+    let mut clean: Vec<u8> = input
+        .iter()
+        .filter(|c| c.is_ascii_alphanumeric() || **c == b'+' || **c == b'/')
+        .copied()
+        .collect();
+
+    // A single character in the last block can't be decoded.
+    if clean.len() % 4 == 1 {
+        clean.pop();
+    }
+
+    BASE64_ENGINE.decode(clean).unwrap_or_default()
+}
+
+/// Decodes quoted-printable content.
+///
+/// If `header` is true, underscores are decoded as spaces, as in the
+/// "Q" encoding used in MIME encoded-words.
+fn decode_quoted_printable(input: &[u8], header: bool) -> Vec<u8> {
+    let mut result = Vec::with_capacity(input.len());
+    let mut i = 0;
+
+    while i < input.len() {
+        match input[i] {
+            b'=' => {
+                let next = &input[i + 1..];
+                // Soft line break.
+                if next.starts_with(b"\r\n") {
+                    i += 3;
+                } else if next.starts_with(b"\n") {
+                    i += 2;
+                } else if let Some(byte) = next
+                    .get(..2)
+                    .and_then(|hex| std::str::from_utf8(hex).ok())
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                {
+                    result.push(byte);
+                    i += 3;
+                } else {
+                    result.push(b'=');
+                    i += 1;
+                }
+            }
+            b'_' if header => {
+                result.push(b' ');
+                i += 1;
+            }
+            c => {
+                result.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    result
+}
+
+/// Decodes MIME encoded-words (RFC 2047) in a header value.
+///
+/// UTF-8 and ASCII are decoded as UTF-8, while ISO-8859-1 and Windows-1252
+/// are decoded as Latin-1. Other charsets are decoded as UTF-8, with invalid
+/// sequences replaced by the replacement character.
+fn decode_encoded_words(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut last_end = 0;
+    let mut prev_was_word = false;
+
+    for caps in ENCODED_WORD_REGEX.captures_iter(value.as_bytes()) {
+        let m = caps.get(0).unwrap();
+        let gap = &value[last_end..m.start()];
+
+        // Whitespace between adjacent encoded-words is ignored.
+        if !(prev_was_word && gap.trim().is_empty()) {
+            result.push_str(gap);
+        }
+
+        let charset = String::from_utf8_lossy(&caps[1]).to_lowercase();
+        let text = &caps[3];
+
+        let decoded = match &caps[2] {
+            b"b" | b"B" => decode_base64(text),
+            _ => decode_quoted_printable(text, true),
+        };
+
+        match charset.as_str() {
+            "iso-8859-1" | "latin1" | "windows-1252" => {
+                result.extend(decoded.iter().map(|c| *c as char))
+            }
+            _ => result.push_str(String::from_utf8_lossy(&decoded).as_ref()),
+        }
+
+        last_end = m.end();
+        prev_was_word = true;
+    }
+
+    result.push_str(&value[last_end..]);
+    result
+}
@@ -0,0 +1,75 @@
+use crate::modules::tests::create_binary_from_zipped_ihex;
+use crate::tests::rule_false;
+use crate::tests::rule_true;
+use crate::tests::test_rule;
+
+#[test]
+fn multipart() {
+    let email = create_binary_from_zipped_ihex(
+        "src/modules/email/tests/testdata/email-multipart.in.zip",
+    );
+
+    rule_true!(
+        r#"
+        import "email"
+        rule test {
+          condition:
+            email.is_email and
+            email.content_type == "multipart/mixed" and
+            email.number_of_parts == 5 and
+            email.headers["subject"] == "Acción requerida: verifique su cuenta" and
+            email.headers["reply-to"] == "attacker@example.org" and
+            not defined email.headers["cc"]
+        }
+        "#,
+        &email
+    );
+
+    rule_true!(
+        r#"
+        import "email"
+        rule test {
+          condition:
+            email.attachments[0].name == "invoice.pdf.exe" and
+            email.attachments[0].content_type == "application/octet-stream" and
+            email.attachments[1].name == "factura ñ.zip" and
+            for any attachment in email.attachments : (
+              attachment.name endswith ".exe"
+            )
+        }
+        "#,
+        &email
+    );
+
+    rule_true!(
+        r#"
+        import "email"
+        rule test {
+          condition:
+            email.number_of_urls == 5 and
+            email.urls.len() == 2 and
+            for any url in email.urls : (
+              url == "https://login.examp1e-bank.com/verify?id=42"
+            ) and
+            for all url in email.urls : (
+              not url endswith "." and not url endswith ","
+            )
+        }
+        "#,
+        &email
+    );
+}
+
+#[test]
+fn not_email() {
+    rule_false!(
+        r#"
+        import "email"
+        rule test {
+          condition:
+            email.is_email
+        }
+        "#,
+        b"Subject: not an email\r\n\r\nHello"
+    );
+}
@@ -1,5 +1,3 @@
-use std::cell::RefCell;
-
 use md5::Md5;
 use rustc_hash::FxHashMap;
 use sha1::Sha1;
@@ -11,63 +9,53 @@ use crate::modules::protos::hash::*;
 #[cfg(test)]
 mod tests;
 
-thread_local!(
-    static SHA256_CACHE: RefCell<FxHashMap<(i64, i64), String>> =
-        RefCell::new(FxHashMap::default());
-
-    static SHA1_CACHE: RefCell<FxHashMap<(i64, i64), String>> =
-        RefCell::new(FxHashMap::default());
-
-    static MD5_CACHE: RefCell<FxHashMap<(i64, i64), String>> =
-        RefCell::new(FxHashMap::default());
-
-    static CRC32_CACHE: RefCell<FxHashMap<(i64, i64), i64>> =
-        RefCell::new(FxHashMap::default());
-
-    static CHECKSUM32_CACHE: RefCell<FxHashMap<(i64, i64), i64>> =
-        RefCell::new(FxHashMap::default());
-);
-
 #[module_main]
 fn main(_data: &[u8], _meta: Option<&[u8]>) -> Result<Hash, ModuleError> {
-    // With every scanned file the cache must be cleared.
-    SHA256_CACHE.with(|cache| cache.borrow_mut().clear());
-    SHA1_CACHE.with(|cache| cache.borrow_mut().clear());
-    MD5_CACHE.with(|cache| cache.borrow_mut().clear());
-    CRC32_CACHE.with(|cache| cache.borrow_mut().clear());
-    CHECKSUM32_CACHE.with(|cache| cache.borrow_mut().clear());
-
     Ok(Hash::new())
 }
 
-#[module_export(name = "md5")]
-fn md5_data(
+/// Returns the value that `f` computes for the scanned data in the range
+/// given by `offset` and `size`.
+///
+/// Values are cached during the current scan in the cache identified by
+/// `name`, so that `f` is called only once for each range.
+fn cached<T, F>(
     ctx: &mut ScanContext,
+    name: &'static str,
     offset: i64,
     size: i64,
-) -> Option<Lowercase<FixedLenString<32>>> {
-    let cached = MD5_CACHE.with(|cache| {
-        Some(Lowercase::<FixedLenString<32>>::from_slice(
-            ctx,
-            cache.borrow().get(&(offset, size))?.as_bytes(),
-        ))
-    });
-
-    if cached.is_some() {
-        return cached;
+    f: F,
+) -> Option<T>
+where
+    T: Clone + 'static,
+    F: FnOnce(&[u8]) -> T,
+{
+    if let Some(value) =
+        ctx.scan_local::<FxHashMap<(i64, i64), T>>(name).get(&(offset, size))
+    {
+        return Some(value.clone());
     }
 
     let range = offset.try_into().ok()?..(offset + size).try_into().ok()?;
-    let data = ctx.scanned_data()?.get(range)?;
-    let mut hasher = Md5::new();
+    let value = f(ctx.scanned_data()?.get(range)?);
 
-    hasher.update(data);
+    ctx.scan_local::<FxHashMap<(i64, i64), T>>(name)
+        .insert((offset, size), value.clone());
 
-    let digest = format!("{:x}", hasher.finalize());
+    Some(value)
+}
 
-    MD5_CACHE.with(|cache| {
-        cache.borrow_mut().insert((offset, size), digest.clone());
-    });
+#[module_export(name = "md5")]
+fn md5_data(
+    ctx: &mut ScanContext,
+    offset: i64,
+    size: i64,
+) -> Option<Lowercase<FixedLenString<32>>> {
+    let digest = cached(ctx, "hash.md5", offset, size, |data| {
+        let mut hasher = Md5::new();
+        hasher.update(data);
+        format!("{:x}", hasher.finalize())
+    })?;
 
     Some(Lowercase::<FixedLenString<32>>::new(digest))
 }
@@ -92,28 +80,11 @@ fn sha1_data(
     offset: i64,
     size: i64,
 ) -> Option<Lowercase<FixedLenString<40>>> {
-    let cached = SHA1_CACHE.with(|cache| {
-        Some(Lowercase::<FixedLenString<40>>::from_slice(
-            ctx,
-            cache.borrow().get(&(offset, size))?.as_bytes(),
-        ))
-    });
-
-    if cached.is_some() {
-        return cached;
-    }
-
-    let range = offset.try_into().ok()?..(offset + size).try_into().ok()?;
-    let data = ctx.scanned_data()?.get(range)?;
-    let mut hasher = Sha1::new();
-
-    hasher.update(data);
-
-    let digest = format!("{:x}", hasher.finalize());
-
-    SHA1_CACHE.with(|cache| {
-        cache.borrow_mut().insert((offset, size), digest.clone());
-    });
+    let digest = cached(ctx, "hash.sha1", offset, size, |data| {
+        let mut hasher = Sha1::new();
+        hasher.update(data);
+        format!("{:x}", hasher.finalize())
+    })?;
 
     Some(Lowercase::<FixedLenString<40>>::new(digest))
 }
@@ -138,28 +109,11 @@ fn sha256_data(
     offset: i64,
     size: i64,
 ) -> Option<Lowercase<FixedLenString<64>>> {
-    let cached = SHA256_CACHE.with(|cache| {
-        Some(Lowercase::<FixedLenString<64>>::from_slice(
-            ctx,
-            cache.borrow().get(&(offset, size))?.as_bytes(),
-        ))
-    });
-
-    if cached.is_some() {
-        return cached;
-    }
-
-    let range = offset.try_into().ok()?..(offset + size).try_into().ok()?;
-    let data = ctx.scanned_data()?.get(range)?;
-    let mut hasher = Sha256::new();
-
-    hasher.update(data);
-
-    let digest = format!("{:x}", hasher.finalize());
-
-    SHA256_CACHE.with(|cache| {
-        cache.borrow_mut().insert((offset, size), digest.clone());
-    });
+    let digest = cached(ctx, "hash.sha256", offset, size, |data| {
+        let mut hasher = Sha256::new();
+        hasher.update(data);
+        format!("{:x}", hasher.finalize())
+    })?;
 
     Some(Lowercase::<FixedLenString<64>>::new(digest))
 }
@@ -179,24 +133,8 @@ fn sha256_str(
 }
 
 #[module_export(name = "crc32")]
-fn crc_data(ctx: &ScanContext, offset: i64, size: i64) -> Option<i64> {
-    let cached = CRC32_CACHE.with(|cache| -> Option<i64> {
-        Some(*cache.borrow().get(&(offset, size))?)
-    });
-
-    if cached.is_some() {
-        return cached;
-    }
-
-    let range = offset.try_into().ok()?..(offset + size).try_into().ok()?;
-    let data = ctx.scanned_data()?.get(range)?;
-    let crc = crc32fast::hash(data);
-
-    CRC32_CACHE.with(|cache| {
-        cache.borrow_mut().insert((offset, size), crc.into());
-    });
-
-    Some(crc.into())
+fn crc_data(ctx: &mut ScanContext, offset: i64, size: i64) -> Option<i64> {
+    cached(ctx, "hash.crc32", offset, size, |data| crc32fast::hash(data).into())
 }
 
 #[module_export(name = "crc32")]
@@ -206,28 +144,18 @@ fn crc_str(ctx: &ScanContext, s: RuntimeString) -> Option<i64> {
 }
 
 #[module_export(name = "checksum32")]
-fn checksum_data(ctx: &ScanContext, offset: i64, size: i64) -> Option<i64> {
-    let cached = CHECKSUM32_CACHE.with(|cache| -> Option<i64> {
-        Some(*cache.borrow().get(&(offset, size))?)
-    });
-
-    if cached.is_some() {
-        return cached;
-    }
-
-    let range = offset.try_into().ok()?..(offset + size).try_into().ok()?;
-    let data = ctx.scanned_data()?.get(range)?;
-    let mut checksum = 0_u32;
-
-    for byte in data {
-        checksum = checksum.wrapping_add(*byte as u32)
-    }
-
-    CHECKSUM32_CACHE.with(|cache| {
-        cache.borrow_mut().insert((offset, size), checksum.into());
-    });
-
-    Some(checksum.into())
+fn checksum_data(
+    ctx: &mut ScanContext,
+    offset: i64,
+    size: i64,
+) -> Option<i64> {
+    cached(ctx, "hash.checksum32", offset, size, |data| {
+        let mut checksum = 0_u32;
+        for byte in data {
+            checksum = checksum.wrapping_add(*byte as u32)
+        }
+        checksum.into()
+    })
 }
 
 #[module_export(name = "checksum32")]
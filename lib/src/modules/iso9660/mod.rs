@@ -0,0 +1,28 @@
+/*! YARA module that parses ISO 9660 disk images.
+
+ISO images are commonly used for delivering malware, as they are opened
+transparently by Windows and their contents bypass some of the protections
+applied to downloaded files. This module exposes the volume labels and the
+files contained in the image, which allows creating rules for this kind of
+files without extracting them first.
+ */
+
+use crate::modules::prelude::*;
+use crate::modules::protos::iso9660::*;
+
+mod parser;
+
+#[cfg(test)]
+mod tests;
+
+#[module_main]
+fn main(data: &[u8], _meta: Option<&[u8]>) -> Result<Iso9660, ModuleError> {
+    match parser::Iso9660Parser::new().parse(data) {
+        Some(iso) => Ok(iso),
+        None => {
+            let mut iso = Iso9660::new();
+            iso.set_is_iso9660(false);
+            Ok(iso)
+        }
+    }
+}
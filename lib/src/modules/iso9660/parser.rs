@@ -0,0 +1,400 @@
+use std::collections::{HashSet, VecDeque};
+
+use chrono::NaiveDate;
+use nom::bytes::complete::take;
+use nom::number::complete::{be_u16, be_u32, le_u16, le_u32, le_u8};
+use nom::Parser;
+
+use crate::modules::protos::iso9660;
+
+type NomError<'a> = nom::error::Error<&'a [u8]>;
+
+/// An ISO 9660 disk image parser.
+///
+/// The first 16 sectors of an ISO 9660 image are unused by the file system,
+/// they are followed by a sequence of volume descriptors, each one occupying
+/// a whole sector. The primary volume descriptor contains the volume label
+/// and the location of the root directory. Supplementary volume descriptors,
+/// like the one used by the Joliet extension, contain alternative directory
+/// hierarchies that use Unicode names. The sequence ends with a terminator,
+/// and in images that also contain an UDF file system, the terminator is
+/// followed by the UDF volume recognition sequence.
+///
+/// See: https://wiki.osdev.org/ISO_9660
+pub struct Iso9660Parser {
+    result: iso9660::Iso9660,
+}
+
+/// A directory record, which describes a file or directory.
+struct DirRecord<'a> {
+    extent: u32,
+    size: u32,
+    recording_time: Option<i64>,
+    flags: u8,
+    name: &'a [u8],
+}
+
+impl DirRecord<'_> {
+    const HIDDEN: u8 = 0x01;
+    const DIRECTORY: u8 = 0x02;
+    const MULTI_EXTENT: u8 = 0x80;
+}
+
+impl Iso9660Parser {
+    /// Size of a sector. Volume descriptors occupy a whole sector, and
+    /// directory records can't cross sector boundaries.
+    const SECTOR_SIZE: usize = 2048;
+
+    /// Sector where the first volume descriptor is located.
+    const FIRST_DESCRIPTOR: usize = 16;
+
+    /// Maximum number of volume descriptors processed.
+    const MAX_DESCRIPTORS: usize = 64;
+
+    /// Maximum number of files and directories included in the result.
+    const MAX_FILES: usize = 10_000;
+
+    /// Maximum depth of the directory hierarchy.
+    const MAX_DEPTH: usize = 32;
+
+    /// Escape sequences that identify a supplementary volume descriptor
+    /// as a Joliet one, for each of the three levels of Joliet.
+    const JOLIET_ESCAPE_SEQUENCES: [&'static [u8]; 3] =
+        [b"%/@", b"%/C", b"%/E"];
+
+    pub fn new() -> Self {
+        Self { result: iso9660::Iso9660::new() }
+    }
+
+    pub fn parse(mut self, data: &[u8]) -> Option<iso9660::Iso9660> {
+        let mut primary = None;
+        let mut joliet = None;
+        let mut is_bootable = false;
+        let mut is_udf = false;
+
+        let descriptors = data
+            .get(Self::FIRST_DESCRIPTOR * Self::SECTOR_SIZE..)?
+            .chunks_exact(Self::SECTOR_SIZE)
+            .take(Self::MAX_DESCRIPTORS);
+
+        for descriptor in descriptors {
+            match (&descriptor[1..6], descriptor[0]) {
+                // Boot record.
+                (b"CD001", 0) => {
+                    if descriptor[7..].starts_with(b"EL TORITO SPECIFICATION")
+                    {
+                        is_bootable = true;
+                    }
+                }
+                // Primary volume descriptor.
+                (b"CD001", 1) => {
+                    primary.get_or_insert(descriptor);
+                }
+                // Supplementary volume descriptor.
+                (b"CD001", 2) => {
+                    if Self::JOLIET_ESCAPE_SEQUENCES
+                        .contains(&&descriptor[88..91])
+                    {
+                        joliet.get_or_insert(descriptor);
+                    }
+                }
+                // Volume partition descriptors and terminator.
+                (b"CD001", _) => {}
+                // UDF volume recognition sequence.
+                (b"NSR02" | b"NSR03", _) => {
+                    is_udf = true;
+                }
+                (b"BEA01" | b"TEA01" | b"BOOT2", _) => {}
+                _ => break,
+            }
+        }
+
+        self.result.set_is_udf(is_udf);
+        self.result.set_is_bootable(is_bootable);
+
+        let Some(primary) = primary else {
+            if is_udf {
+                self.result.set_is_iso9660(false);
+                return Some(self.result);
+            }
+            return None;
+        };
+
+        self.result.set_is_iso9660(true);
+        self.result.set_has_joliet(joliet.is_some());
+
+        self.result.system_identifier = identifier(&primary[8..40]);
+        self.result.volume_identifier = identifier(&primary[40..72]);
+        self.result.volume_set_identifier = identifier(&primary[190..318]);
+        self.result.publisher_identifier = identifier(&primary[318..446]);
+        self.result.data_preparer_identifier = identifier(&primary[446..574]);
+        self.result.application_identifier = identifier(&primary[574..702]);
+        self.result.creation_time = volume_time(&primary[813..830]);
+        self.result.modification_time = volume_time(&primary[830..847]);
+
+        let volume_space_size =
+            u32::from_le_bytes(primary[80..84].try_into().unwrap());
+
+        let logical_block_size =
+            u16::from_le_bytes(primary[128..130].try_into().unwrap());
+
+        self.result.set_volume_space_size(volume_space_size);
+        self.result.set_logical_block_size(logical_block_size.into());
+
+        // When the Joliet hierarchy is present use it instead of the primary
+        // one, as it has the long file names.
+        let (descriptor, is_joliet) = match joliet {
+            Some(joliet) => (joliet, true),
+            None => (primary, false),
+        };
+
+        if let Some(root) = dir_record(&descriptor[156..190]) {
+            self.walk(data, logical_block_size as usize, root, is_joliet);
+        }
+
+        let number_of_files =
+            self.result.files.iter().filter(|f| !f.is_directory()).count();
+
+        self.result.set_number_of_files(number_of_files as u64);
+
+        Some(self.result)
+    }
+
+    /// Walks the directory hierarchy starting at the given root directory,
+    /// adding the files and directories found to the result.
+    fn walk(
+        &mut self,
+        data: &[u8],
+        block_size: usize,
+        root: DirRecord,
+        is_joliet: bool,
+    ) {
+        if block_size == 0 {
+            return;
+        }
+
+        // Extents of the directories already visited. Malformed images may
+        // contain directories that point to their ancestors.
+        let mut visited = HashSet::new();
+        let mut pending = VecDeque::new();
+
+        visited.insert(root.extent);
+        pending.push_back((root.extent, root.size, String::new(), 0));
+
+        while let Some((extent, size, path, depth)) = pending.pop_front() {
+            let Some(dir) = (extent as usize)
+                .checked_mul(block_size)
+                .and_then(|start| data.get(start..))
+            else {
+                continue;
+            };
+
+            let dir = &dir[..dir.len().min(size as usize)];
+            let mut offset = 0;
+            let mut multi_extent = false;
+
+            while offset < dir.len() {
+                let len = dir[offset] as usize;
+
+                // Records can't cross sector boundaries, a zero length
+                // indicates that the next record is in the next sector.
+                if len == 0 {
+                    offset =
+                        (offset / Self::SECTOR_SIZE + 1) * Self::SECTOR_SIZE;
+                    continue;
+                }
+
+                let Some(record) =
+                    dir.get(offset..offset + len).and_then(dir_record)
+                else {
+                    break;
+                };
+
+                offset += len;
+
+                // Skip the records for the directory itself and its parent.
+                if record.name == [0] || record.name == [1] {
+                    continue;
+                }
+
+                let name =
+                    format!("{}/{}", path, file_name(record.name, is_joliet));
+
+                // Files that span multiple extents have one record per
+                // extent, all of them with the same name. Every record
+                // except the last one has the multi-extent flag.
+                if multi_extent {
+                    if let Some(last) = self.result.files.last_mut() {
+                        if last.name() == name {
+                            last.set_size(last.size() + record.size as u64);
+                            multi_extent =
+                                record.flags & DirRecord::MULTI_EXTENT != 0;
+                            continue;
+                        }
+                    }
+                }
+
+                if self.result.files.len() >= Self::MAX_FILES {
+                    return;
+                }
+
+                multi_extent = record.flags & DirRecord::MULTI_EXTENT != 0;
+
+                let is_directory = record.flags & DirRecord::DIRECTORY != 0;
+
+                let mut file = iso9660::File::new();
+                file.set_name(name.clone());
+                file.set_size(record.size.into());
+                file.set_is_directory(is_directory);
+                file.set_is_hidden(record.flags & DirRecord::HIDDEN != 0);
+                file.recording_time = record.recording_time;
+
+                self.result.files.push(file);
+
+                if is_directory
+                    && depth < Self::MAX_DEPTH
+                    && visited.insert(record.extent)
+                {
+                    pending.push_back((
+                        record.extent,
+                        record.size,
+                        name,
+                        depth + 1,
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Parses a directory record.
+fn dir_record(input: &[u8]) -> Option<DirRecord<'_>> {
+    let (
+        remainder,
+        (
+            _len,
+            _ext_attr_len,
+            extent,
+            _extent_be,
+            size,
+            _size_be,
+            recording_time,
+            flags,
+            _unit_size,
+            _gap_size,
+            _volume_seq_number,
+            _volume_seq_number_be,
+            name_len,
+        ),
+    ) = (
+        le_u8::<_, NomError>, // length
+        le_u8,                // extended attribute record length
+        le_u32,               // extent
+        be_u32,               // extent (big-endian)
+        le_u32,               // size
+        be_u32,               // size (big-endian)
+        take(7_u8),           // recording date and time
+        le_u8,                // flags
+        le_u8,                // file unit size
+        le_u8,                // interleave gap size
+        le_u16,               // volume sequence number
+        be_u16,               // volume sequence number (big-endian)
+        le_u8,                // name length
+    )
+        .parse(input)
+        .ok()?;
+
+    let (_, name) = take::<_, _, NomError>(name_len).parse(remainder).ok()?;
+
+    Some(DirRecord {
+        extent,
+        size,
+        recording_time: recording_time_to_unix_timestamp(recording_time),
+        flags,
+        name,
+    })
+}
+
+/// Returns the name of a file, given the name stored in its directory
+/// record.
+///
+/// Names in the primary directory hierarchy are ASCII strings, while names
+/// in the Joliet hierarchy are UTF-16 BE strings. In both cases the file
+/// version (e.g: ";1") is removed, as well as the trailing dot that appears
+/// in names without extension (e.g: "README.;1").
+fn file_name(name: &[u8], is_joliet: bool) -> String {
+    let name = if is_joliet {
+        let chars: Vec<u16> = name
+            .chunks_exact(2)
+            .map(|c| u16::from_be_bytes([c[0], c[1]]))
+            .collect();
+        String::from_utf16_lossy(chars.as_slice())
+    } else {
+        String::from_utf8_lossy(name).into_owned()
+    };
+
+    let name = match name.rsplit_once(';') {
+        Some((name, _version)) => name,
+        None => name.as_str(),
+    };
+
+    name.strip_suffix('.').unwrap_or(name).to_string()
+}
+
+/// Returns the value of an identifier in a volume descriptor.
+///
+/// Identifiers are padded with spaces, which are removed. Returns `None` if
+/// the identifier is empty.
+fn identifier(input: &[u8]) -> Option<String> {
+    let identifier = String::from_utf8_lossy(input);
+    let identifier = identifier.trim_end_matches([' ', '\0']);
+
+    if identifier.is_empty() {
+        None
+    } else {
+        Some(identifier.to_string())
+    }
+}
+
+/// Converts the date and time in directory records to a UNIX timestamp.
+///
+/// The date and time is stored in 7 bytes: years since 1900, month, day,
+/// hour, minute, second, and offset from GMT in 15 minutes intervals.
+fn recording_time_to_unix_timestamp(input: &[u8]) -> Option<i64> {
+    let [year, month, day, hour, minute, second, offset] = *input else {
+        return None;
+    };
+
+    let timestamp =
+        NaiveDate::from_ymd_opt(1900 + year as i32, month.into(), day.into())?
+            .and_hms_opt(hour.into(), minute.into(), second.into())?
+            .and_utc()
+            .timestamp();
+
+    Some(timestamp - offset as i8 as i64 * 15 * 60)
+}
+
+/// Converts the date and time in volume descriptors to a UNIX timestamp.
+///
+/// The date and time is stored as 16 ASCII digits (YYYYMMDDHHMMSScc)
+/// followed by the offset from GMT in 15 minutes intervals. Returns `None`
+/// if the date is not set, which is indicated by all digits being zero.
+fn volume_time(input: &[u8]) -> Option<i64> {
+    let digits = std::str::from_utf8(input.get(..16)?).ok()?;
+    let offset = *input.get(16)? as i8 as i64;
+
+    let field = |range: std::ops::Range<usize>| -> Option<u32> {
+        digits.get(range)?.parse().ok()
+    };
+
+    let timestamp = NaiveDate::from_ymd_opt(
+        field(0..4)? as i32,
+        field(4..6)?,
+        field(6..8)?,
+    )?
+    .and_hms_opt(field(8..10)?, field(10..12)?, field(12..14)?)?
+    .and_utc()
+    .timestamp();
+
+    Some(timestamp - offset * 15 * 60)
+}
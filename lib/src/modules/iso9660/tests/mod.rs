@@ -0,0 +1,67 @@
+use crate::modules::tests::create_binary_from_zipped_ihex;
+use crate::tests::rule_false;
+use crate::tests::rule_true;
+use crate::tests::test_rule;
+
+#[test]
+fn joliet() {
+    let iso = create_binary_from_zipped_ihex(
+        "src/modules/iso9660/tests/testdata/iso9660-joliet.in.zip",
+    );
+
+    rule_true!(
+        r#"
+        import "iso9660"
+        rule test {
+          condition:
+            iso9660.is_iso9660 and
+            iso9660.is_udf and
+            iso9660.is_bootable and
+            iso9660.has_joliet and
+            iso9660.volume_identifier == "INVOICE_2024" and
+            iso9660.number_of_files == 3 and
+            for any file in iso9660.files : (
+              file.name == "/Documents/Invoice_2024.pdf.lnk" and
+              not file.is_hidden
+            ) and
+            for any file in iso9660.files : (
+              file.name == "/Documents/payload.dll" and file.is_hidden
+            )
+        }
+        "#,
+        &iso
+    );
+
+    let iso = create_binary_from_zipped_ihex(
+        "src/modules/iso9660/tests/testdata/iso9660-plain.in.zip",
+    );
+
+    rule_true!(
+        r#"
+        import "iso9660"
+        rule test {
+          condition:
+            iso9660.is_iso9660 and
+            not iso9660.is_udf and
+            not iso9660.is_bootable and
+            not iso9660.has_joliet and
+            iso9660.files[0].name == "/DOCS" and
+            iso9660.files[0].is_directory and
+            iso9660.files[1].name == "/README.TXT" and
+            iso9660.files[1].size == 18
+        }
+        "#,
+        &iso
+    );
+
+    rule_false!(
+        r#"
+        import "iso9660"
+        rule test {
+          condition:
+            iso9660.is_iso9660
+        }
+        "#,
+        b"CD001"
+    );
+}
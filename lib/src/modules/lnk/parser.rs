@@ -5,7 +5,7 @@ use std::num::NonZeroUsize;
 use nom::bytes::complete::{take, take_while};
 use nom::combinator::{cond, map_res, verify};
 use nom::multi::{fold_many0, length_value, many_till};
-use nom::number::complete::{le_u128, le_u16, le_u32, le_u64};
+use nom::number::complete::{le_u128, le_u16, le_u32, le_u64, u8};
 use nom::{Err, Input, ToUsize};
 use nom::{IResult, Needed, Parser};
 use protobuf::EnumOrUnknown;
@@ -13,7 +13,9 @@ use uuid::Uuid;
 
 type NomError<'a> = nom::error::Error<&'a [u8]>;
 
-use crate::modules::protos::lnk::{DriveType, Lnk, ShowCommand, TrackerData};
+use crate::modules::protos::lnk::{
+    DriveType, ItemId, ItemType, Lnk, ShowCommand, TrackerData,
+};
 
 /// A Windows LNK file parser.
 pub struct LnkParser {
@@ -188,6 +190,12 @@ impl LnkParser {
     const VOLUME_ID_AND_LOCAL_BASE_PATH: u32 = 0x00000001;
     const COMMON_NETWORK_RELATIVE_LINK_AND_PATH_SUFFIX: u32 = 0x00000002;
 
+    const ENVIRONMENT_VARIABLES_DATA_BLOCK: u32 = 0xA0000001;
+    const TRACKER_DATA_BLOCK: u32 = 0xA0000003;
+    const SPECIAL_FOLDER_DATA_BLOCK: u32 = 0xA0000005;
+    const ICON_ENVIRONMENT_DATA_BLOCK: u32 = 0xA0000007;
+    const KNOWN_FOLDER_DATA_BLOCK: u32 = 0xA000000B;
+
     fn parse_link_target_id_list(
         &mut self,
     ) -> impl FnMut(&[u8]) -> IResult<&[u8], ()> + '_ {
@@ -212,15 +220,125 @@ impl LnkParser {
         move |input: &[u8]| {
             // Each item ID starts with a 2-bytes length that includes
             // the length itself its data.
-            let (remainder, _data) = Self::length_data(le_u16).parse(input)?;
-            // TODO(vmalvarez): Implement the parsing of link targets if
-            // there's enough demand for it.
-            // A possible reference implementation is:
-            // https://github.com/Matmaus/LnkParse3/blob/master/LnkParse3/target_factory.py#L1
+            let (remainder, data) = Self::length_data(le_u16).parse(input)?;
+            // Items that can't be parsed are ignored, they don't prevent
+            // the parsing of the remaining ones.
+            if let Ok((_, item)) = Self::parse_item_id(data) {
+                self.result.link_target_id_list.push(item);
+            }
             Ok((remainder, ()))
         }
     }
 
+    /// Parses the data in an item ID, excluding the size.
+    ///
+    /// The format of each item depends on its class type indicator, which
+    /// is the first byte in the item. A reference implementation is:
+    /// https://github.com/Matmaus/LnkParse3/blob/master/LnkParse3/target_factory.py#L1
+    fn parse_item_id(input: &[u8]) -> IResult<&[u8], ItemId> {
+        let (data, class_type) = u8(input)?;
+
+        let item_type = match class_type {
+            0x1f => ItemType::ROOT_FOLDER,
+            0x20..=0x2f => ItemType::VOLUME,
+            0x30..=0x3f => ItemType::FILE_ENTRY,
+            0x40..=0x4f => ItemType::NETWORK_LOCATION,
+            0x52 => ItemType::COMPRESSED_FOLDER,
+            0x61 => ItemType::URI,
+            0x70 | 0x71 => ItemType::CONTROL_PANEL,
+            _ => ItemType::UNKNOWN_ITEM,
+        };
+
+        let mut item = ItemId::new();
+
+        item.class_type = Some(class_type.into());
+        item.item_type = Some(item_type.into());
+
+        match item_type {
+            ItemType::ROOT_FOLDER => {
+                let (_, (_sort_index, clsid)) =
+                    (u8, map_res(take(16_u8), Uuid::from_slice_le))
+                        .parse(data)?;
+                item.clsid = Some(clsid.to_string());
+            }
+            // The lowest bit in the class type indicates that the volume
+            // has a name.
+            ItemType::VOLUME if class_type & 0x01 != 0 => {
+                let (_, name) = Self::parse_string(data)?;
+                item.name = Some(name);
+            }
+            ItemType::FILE_ENTRY => {
+                let (data, (_, file_size, _modification_time, attributes)) =
+                    (u8, le_u32, le_u32, le_u16).parse(data)?;
+
+                // The short name is a UTF-16 string if the class type has
+                // the 0x04 bit set, or an ASCII string if otherwise.
+                let (data, short_name) = if class_type & 0x04 != 0 {
+                    Self::parse_utf16_string(data)?
+                } else {
+                    Self::parse_string(data)?
+                };
+
+                // The long name is in an extension block that follows
+                // the short name, after some padding. The block is located
+                // by its signature, which comes 4 bytes after the start of
+                // the block.
+                let long_name = data
+                    .windows(4)
+                    .position(|w| w == b"\x04\x00\xef\xbe")
+                    .and_then(|pos| pos.checked_sub(4))
+                    .and_then(|start| {
+                        Self::parse_file_entry_extension(&data[start..]).ok()
+                    })
+                    .map(|(_, long_name)| long_name);
+
+                item.file_size = Some(file_size);
+                item.file_attributes = Some(attributes.into());
+                item.name = long_name.or_else(|| Some(short_name.clone()));
+                item.short_name = Some(short_name);
+            }
+            ItemType::NETWORK_LOCATION => {
+                let (_, (_, _flags, location)) =
+                    (u8, u8, Self::parse_string).parse(data)?;
+                item.name = Some(location);
+            }
+            _ => {}
+        }
+
+        Ok((&[], item))
+    }
+
+    /// Parses the extension block with signature 0xbeef0004 that appears
+    /// in file entry items, and returns the long name of the file.
+    fn parse_file_entry_extension(input: &[u8]) -> IResult<&[u8], String> {
+        let (_, block) = Self::length_data(le_u16).parse(input)?;
+        let (_, (version, _signature)) = (le_u16, le_u32).parse(block)?;
+
+        // The offset of the long name depends on the version of the
+        // block. Offsets are relative to the start of the block, including
+        // the size field.
+        let offset: usize = match version {
+            3..=6 => 20,
+            7 => 38,
+            8 => 42,
+            9.. => 46,
+            _ => {
+                return Err(Err::Error(nom::error::Error::new(
+                    input,
+                    nom::error::ErrorKind::Verify,
+                )))
+            }
+        };
+
+        match input.get(offset..) {
+            Some(long_name) => Self::parse_utf16_string(long_name),
+            None => Err(Err::Error(nom::error::Error::new(
+                input,
+                nom::error::ErrorKind::Eof,
+            ))),
+        }
+    }
+
     fn parse_link_info(
         &mut self,
     ) -> impl FnMut(&[u8]) -> IResult<&[u8], ()> + '_ {
@@ -384,15 +502,66 @@ impl LnkParser {
         move |input: &[u8]| {
             let (remainder, block) = Self::length_data(le_u32).parse(input)?;
             // The first 4 bytes in each block indicates its type.
-            if let Ok((block_data, 0xA0000003)) =
-                le_u32::<&[u8], nom::error::Error<&[u8]>>(block)
-            {
-                let _ = self.parse_tracker_data_block()(block_data);
+            let (block_data, signature) =
+                match le_u32::<&[u8], NomError>(block) {
+                    Ok(result) => result,
+                    Err(_) => return Ok((remainder, ())),
+                };
+            match signature {
+                Self::TRACKER_DATA_BLOCK => {
+                    let _ = self.parse_tracker_data_block()(block_data);
+                }
+                Self::ENVIRONMENT_VARIABLES_DATA_BLOCK => {
+                    self.result.environment_target =
+                        Self::parse_environment_data(block_data)
+                            .map(|(_, target)| Some(target))
+                            .unwrap_or(None);
+                }
+                Self::ICON_ENVIRONMENT_DATA_BLOCK => {
+                    self.result.environment_icon_location =
+                        Self::parse_environment_data(block_data)
+                            .map(|(_, location)| Some(location))
+                            .unwrap_or(None);
+                }
+                Self::KNOWN_FOLDER_DATA_BLOCK => {
+                    self.result.known_folder_id = block_data
+                        .get(..16)
+                        .and_then(|id| Uuid::from_slice_le(id).ok())
+                        .map(|id| id.to_string());
+                }
+                Self::SPECIAL_FOLDER_DATA_BLOCK => {
+                    self.result.special_folder_id =
+                        le_u32::<&[u8], NomError>(block_data)
+                            .map(|(_, id)| Some(id))
+                            .unwrap_or(None);
+                }
+                _ => {}
             }
             Ok((remainder, ()))
         }
     }
 
+    /// Parses the data in environment variables data blocks and icon
+    /// environment data blocks.
+    ///
+    /// Both blocks contain the same path twice, first as a 260-bytes ANSI
+    /// string, and then as a 520-bytes UTF-16 string. The UTF-16 string is
+    /// preferred, the ANSI one is used only if the former is empty.
+    fn parse_environment_data(input: &[u8]) -> IResult<&[u8], String> {
+        let (remainder, (ansi, unicode)) =
+            (take(260_u16), take(520_u16)).parse(input)?;
+
+        let (_, unicode) = Self::parse_utf16_string(unicode)?;
+
+        if !unicode.is_empty() {
+            return Ok((remainder, unicode));
+        }
+
+        let (_, ansi) = Self::parse_string(ansi)?;
+
+        Ok((remainder, ansi))
+    }
+
     fn parse_tracker_data_block(
         &mut self,
     ) -> impl FnMut(&[u8]) -> IResult<&[u8], ()> + '_ {
@@ -437,6 +606,14 @@ impl LnkParser {
             tracker_data.droid_birth_file_id =
                 Some(droid_birth_file_id.to_string());
 
+            // File IDs are version 1 UUIDs, which contain the MAC address
+            // of the machine that generated them.
+            tracker_data.droid_file_mac =
+                droid_file_id.get_node_id().map(format_mac);
+
+            tracker_data.droid_birth_file_mac =
+                droid_birth_file_id.get_node_id().map(format_mac);
+
             self.result.tracker_data = Some(tracker_data).into();
 
             Ok((remainder, ()))
@@ -573,3 +750,9 @@ impl LnkParser {
 fn filetime_to_unix_timestamp(filetime: u64) -> Option<u64> {
     (filetime / 10000000).checked_sub(11644473600)
 }
+
+/// Formats a MAC address as six groups of two hex digits separated by
+/// colons.
+fn format_mac(mac: [u8; 6]) -> String {
+    mac.iter().map(|b| format!("{b:02x}")).collect::<Vec<_>>().join(":")
+}
@@ -5,8 +5,6 @@
 //! both protobuf structure fields and constants. This together with
 //! also exported functions can be later used in YARA rules.
 
-use std::cell::RefCell;
-
 use crate::modules::macho::parser::{N_EXT, N_STAB, N_TYPE};
 use crate::modules::prelude::*;
 use crate::modules::protos::macho::*;
@@ -18,19 +16,6 @@ mod parser;
 #[cfg(test)]
 mod tests;
 
-thread_local!(
-    static DYLIB_MD5_CACHE: RefCell<Option<String>> =
-        const { RefCell::new(None) };
-    static ENTITLEMENT_MD5_CACHE: RefCell<Option<String>> =
-        const { RefCell::new(None) };
-    static EXPORT_MD5_CACHE: RefCell<Option<String>> =
-        const { RefCell::new(None) };
-    static IMPORT_MD5_CACHE: RefCell<Option<String>> =
-        const { RefCell::new(None) };
-    static SYM_MD5_CACHE: RefCell<Option<String>> =
-        const { RefCell::new(None) };
-);
-
 /// Get the index of a Mach-O file within a fat binary based on CPU type.
 ///
 /// This function iterates through the architecture types contained in a
@@ -336,18 +321,11 @@ fn has_export(ctx: &ScanContext, export: RuntimeString) -> Option<bool> {
 /// Returns a md5 hash of the dylibs designated in the mach-o binary
 #[module_export]
 fn dylib_hash(ctx: &mut ScanContext) -> Option<Lowercase<FixedLenString<32>>> {
-    let cached = DYLIB_MD5_CACHE.with(
-        |cache| -> Option<Lowercase<FixedLenString<32>>> {
-            cache.borrow().as_deref().map(|s| {
-                Lowercase::<FixedLenString<32>>::from_slice(ctx, s.as_bytes())
-            })
-        },
-    );
-
-    if cached.is_some() {
-        return cached;
-    }
+    let digest = ctx.computed_value("macho.dylib_hash", dylib_hash_impl)?;
+    Some(Lowercase::<FixedLenString<32>>::new(digest))
+}
 
+fn dylib_hash_impl(ctx: &mut ScanContext) -> Option<String> {
     let macho = ctx.module_output::<Macho>()?;
     let mut dylibs_to_hash = &macho.dylibs;
 
@@ -378,13 +356,7 @@ fn dylib_hash(ctx: &mut ScanContext) -> Option<Lowercase<FixedLenString<32>>> {
 
     md5_hash.update(dylibs_to_hash.as_bytes());
 
-    let digest = format!("{:x}", md5_hash.finalize());
-
-    DYLIB_MD5_CACHE.with(|cache| {
-        *cache.borrow_mut() = Some(digest.clone());
-    });
-
-    Some(Lowercase::<FixedLenString<32>>::new(digest))
+    Some(format!("{:x}", md5_hash.finalize()))
 }
 
 /// Returns a md5 hash of the entitlements designated in the mach-o binary
@@ -392,18 +364,11 @@ fn dylib_hash(ctx: &mut ScanContext) -> Option<Lowercase<FixedLenString<32>>> {
 fn entitlement_hash(
     ctx: &mut ScanContext,
 ) -> Option<Lowercase<FixedLenString<32>>> {
-    let cached = ENTITLEMENT_MD5_CACHE.with(
-        |cache| -> Option<Lowercase<FixedLenString<32>>> {
-            cache.borrow().as_deref().map(|s| {
-                Lowercase::<FixedLenString<32>>::from_slice(ctx, s.as_bytes())
-            })
-        },
-    );
-
-    if cached.is_some() {
-        return cached;
-    }
+    let digest = ctx.computed_value("macho.entitlement_hash", entitlement_hash_impl)?;
+    Some(Lowercase::<FixedLenString<32>>::new(digest))
+}
 
+fn entitlement_hash_impl(ctx: &mut ScanContext) -> Option<String> {
     let macho = ctx.module_output::<Macho>()?;
     let mut entitlements_to_hash = &macho.entitlements;
 
@@ -429,13 +394,7 @@ fn entitlement_hash(
 
     md5_hash.update(entitlements_str.as_bytes());
 
-    let digest = format!("{:x}", md5_hash.finalize());
-
-    ENTITLEMENT_MD5_CACHE.with(|cache| {
-        *cache.borrow_mut() = Some(digest.clone());
-    });
-
-    Some(Lowercase::<FixedLenString<32>>::new(digest))
+    Some(format!("{:x}", md5_hash.finalize()))
 }
 
 /// Returns a md5 hash of the export symbols in the mach-o binary
@@ -443,18 +402,11 @@ fn entitlement_hash(
 fn export_hash(
     ctx: &mut ScanContext,
 ) -> Option<Lowercase<FixedLenString<32>>> {
-    let cached = EXPORT_MD5_CACHE.with(
-        |cache| -> Option<Lowercase<FixedLenString<32>>> {
-            cache.borrow().as_deref().map(|s| {
-                Lowercase::<FixedLenString<32>>::from_slice(ctx, s.as_bytes())
-            })
-        },
-    );
-
-    if cached.is_some() {
-        return cached;
-    }
+    let digest = ctx.computed_value("macho.export_hash", export_hash_impl)?;
+    Some(Lowercase::<FixedLenString<32>>::new(digest))
+}
 
+fn export_hash_impl(ctx: &mut ScanContext) -> Option<String> {
     let macho = ctx.module_output::<Macho>()?;
     let mut exports_to_hash = &macho.exports;
 
@@ -480,13 +432,7 @@ fn export_hash(
 
     md5_hash.update(exports_str.as_bytes());
 
-    let digest = format!("{:x}", md5_hash.finalize());
-
-    EXPORT_MD5_CACHE.with(|cache| {
-        *cache.borrow_mut() = Some(digest.clone());
-    });
-
-    Some(Lowercase::<FixedLenString<32>>::new(digest))
+    Some(format!("{:x}", md5_hash.finalize()))
 }
 
 /// Returns a md5 hash of the imported symbols in the mach-o binary
@@ -494,18 +440,11 @@ fn export_hash(
 fn import_hash(
     ctx: &mut ScanContext,
 ) -> Option<Lowercase<FixedLenString<32>>> {
-    let cached = IMPORT_MD5_CACHE.with(
-        |cache| -> Option<Lowercase<FixedLenString<32>>> {
-            cache.borrow().as_deref().map(|s| {
-                Lowercase::<FixedLenString<32>>::from_slice(ctx, s.as_bytes())
-            })
-        },
-    );
-
-    if cached.is_some() {
-        return cached;
-    }
+    let digest = ctx.computed_value("macho.import_hash", import_hash_impl)?;
+    Some(Lowercase::<FixedLenString<32>>::new(digest))
+}
 
+fn import_hash_impl(ctx: &mut ScanContext) -> Option<String> {
     let macho = ctx.module_output::<Macho>()?;
     let mut imports_to_hash = &macho.imports;
 
@@ -531,30 +470,18 @@ fn import_hash(
 
     md5_hash.update(imports_str.as_bytes());
 
-    let digest = format!("{:x}", md5_hash.finalize());
-
-    IMPORT_MD5_CACHE.with(|cache| {
-        *cache.borrow_mut() = Some(digest.clone());
-    });
-
-    Some(Lowercase::<FixedLenString<32>>::new(digest))
+    Some(format!("{:x}", md5_hash.finalize()))
 }
 
 /// Returns a md5 hash of specific parts of the symbol table
 /// as defined by http://github.com/threatstream/symhash
 #[module_export]
 fn symhash(ctx: &mut ScanContext) -> Option<Lowercase<FixedLenString<32>>> {
-    let cached =
-        SYM_MD5_CACHE.with(|cache| -> Option<Lowercase<FixedLenString<32>>> {
-            cache.borrow().as_deref().map(|s| {
-                Lowercase::<FixedLenString<32>>::from_slice(ctx, s.as_bytes())
-            })
-        });
-
-    if cached.is_some() {
-        return cached;
-    }
+    let digest = ctx.computed_value("macho.symhash", symhash_impl)?;
+    Some(Lowercase::<FixedLenString<32>>::new(digest))
+}
 
+fn symhash_impl(ctx: &mut ScanContext) -> Option<String> {
     let macho = ctx.module_output::<Macho>()?;
     let mut symtab_to_hash = &macho.symtab.entries;
     let mut nlists = &macho.symtab.nlists;
@@ -595,22 +522,11 @@ fn symhash(ctx: &mut ScanContext) -> Option<Lowercase<FixedLenString<32>>> {
 
     md5_hash.update(symtab_hash_entries);
 
-    let digest = format!("{:x}", md5_hash.finalize());
-
-    SYM_MD5_CACHE.with(|cache| {
-        *cache.borrow_mut() = Some(digest.clone());
-    });
-
-    Some(Lowercase::<FixedLenString<32>>::new(digest))
+    Some(format!("{:x}", md5_hash.finalize()))
 }
 
 #[module_main]
 fn main(data: &[u8], _meta: Option<&[u8]>) -> Result<Macho, ModuleError> {
-    DYLIB_MD5_CACHE.with(|cache| *cache.borrow_mut() = None);
-    ENTITLEMENT_MD5_CACHE.with(|cache| *cache.borrow_mut() = None);
-    EXPORT_MD5_CACHE.with(|cache| *cache.borrow_mut() = None);
-    IMPORT_MD5_CACHE.with(|cache| *cache.borrow_mut() = None);
-    SYM_MD5_CACHE.with(|cache| *cache.borrow_mut() = None);
 
     match parser::MachO::parse(data) {
         Ok(macho) => Ok(macho.into()),
@@ -25,9 +25,12 @@ use x509_parser::x509::AlgorithmIdentifier;
 use crate::modules::protos;
 use crate::modules::utils::asn1::SignedData;
 use crate::modules::utils::leb128::{sleb128, uleb128};
+use crate::modules::utils::limits::ParseLimits;
 
 type NomError<'a> = nom::error::Error<&'a [u8]>;
 
+const LIMITS: ParseLimits = ParseLimits::DEFAULT;
+
 /// Mach-O magic constants
 const MH_MAGIC: u32 = 0xfeedface;
 const MH_CIGAM: u32 = 0xcefaedfe;
@@ -1158,7 +1161,7 @@ impl<'a> MachOFile<'a> {
         let mut data = symbol_table;
         let mut n;
 
-        for _ in 0..count {
+        for _ in 0..(count as usize).min(LIMITS.max_symbols) {
             (data, n) = self.nlist().parse(data)?;
             if let Some(symtab) = self.symtab.as_mut() {
                 if let Some(string_data) =
@@ -1190,6 +1193,10 @@ impl<'a> MachOFile<'a> {
         stack.push(ExportNode { offset: 0, prefix: "".to_string() });
 
         while !stack.is_empty() && !data.is_empty() {
+            if self.exports.len() >= LIMITS.max_exports {
+                break;
+            }
+
             let export_node = stack.pop().unwrap();
 
             // If node was already visited, continue without processing it.
@@ -1240,6 +1247,16 @@ impl<'a> MachOFile<'a> {
 
                 let (remainder, edge_offset) = uleb128(remainder)?;
 
+                // The prefix of each node is the concatenation of the
+                // labels in the path from the root. Hostile files can make
+                // it arbitrarily long, nodes with longer names are ignored.
+                let prefix_len = export_node.prefix.len() + edge_label.len();
+
+                if prefix_len > LIMITS.max_name_length {
+                    edge_remainder = remainder;
+                    continue;
+                }
+
                 if let Ok(edge_label_str) = edge_label.to_str() {
                     stack.push(ExportNode {
                         offset: edge_offset as usize,
@@ -7,7 +7,6 @@ This allows creating YARA rules that use the file type provided by [libmagic][1]
 
 use crate::modules::prelude::*;
 use crate::modules::protos::magic::*;
-use std::cell::RefCell;
 
 #[cfg(feature = "logging")]
 use log::*;
@@ -22,62 +21,45 @@ thread_local! {
             .load(&Default::default())
             .expect("loaded libmagic database")
     };
-
-    static TYPE_CACHE: RefCell<Option<String>> = const { RefCell::new(None) };
-    static MIME_TYPE_CACHE: RefCell<Option<String>> = const { RefCell::new(None) };
 }
 
 #[module_main]
 fn main(_data: &[u8], _meta: Option<&[u8]>) -> Result<Magic, ModuleError> {
-    // With every scanned file the cache must be cleared.
-    TYPE_CACHE.set(None);
-    MIME_TYPE_CACHE.set(None);
-
     Ok(Magic::new())
 }
 
 #[module_export(name = "type")]
 fn file_type(ctx: &mut ScanContext) -> Option<RuntimeString> {
-    let cached = TYPE_CACHE.with(|cache| cache.borrow().clone());
-
-    if let Some(cached) = cached {
-        return Some(RuntimeString::new(cached));
-    }
-
-    match get_type(ctx.scanned_data()?) {
-        Ok(type_) => {
-            TYPE_CACHE.replace(Some(type_.clone()));
-            Some(RuntimeString::new(type_))
-        }
-        #[allow(unused_variables)]
-        Err(err) => {
-            #[cfg(feature = "logging")]
-            error!("libmagic error: {}", err);
-            None
+    let type_ = ctx.computed_value("magic.type", |ctx| {
+        match get_type(ctx.scanned_data()?) {
+            Ok(type_) => Some(type_),
+            #[allow(unused_variables)]
+            Err(err) => {
+                #[cfg(feature = "logging")]
+                error!("libmagic error: {}", err);
+                None
+            }
         }
-    }
+    })?;
+
+    Some(RuntimeString::new(type_))
 }
 
 #[module_export(name = "mime_type")]
 fn mime_type(ctx: &mut ScanContext) -> Option<RuntimeString> {
-    let cached = MIME_TYPE_CACHE.with(|cache| cache.borrow().clone());
-
-    if let Some(cached) = cached {
-        return Some(RuntimeString::new(cached));
-    }
-
-    match get_mime_type(ctx.scanned_data()?) {
-        Ok(type_) => {
-            MIME_TYPE_CACHE.replace(Some(type_.clone()));
-            Some(RuntimeString::new(type_))
+    let type_ = ctx.computed_value("magic.mime_type", |ctx| {
+        match get_mime_type(ctx.scanned_data()?) {
+            Ok(type_) => Some(type_),
+            #[allow(unused_variables)]
+            Err(err) => {
+                #[cfg(feature = "logging")]
+                error!("libmagic error: {}", err);
+                None
+            }
         }
-        #[allow(unused_variables)]
-        Err(err) => {
-            #[cfg(feature = "logging")]
-            error!("libmagic error: {}", err);
-            None
-        }
-    }
+    })?;
+
+    Some(RuntimeString::new(type_))
 }
 
 fn get_type(data: &[u8]) -> Result<String, magic::cookie::Error> {
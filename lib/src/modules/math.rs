@@ -6,6 +6,7 @@ use num_traits::Pow;
 
 use crate::modules::prelude::*;
 use crate::modules::protos::math::*;
+use crate::modules::utils::stats::entropy;
 
 #[module_main]
 fn main(_data: &[u8], _meta: Option<&[u8]>) -> Result<Math, ModuleError> {
@@ -223,27 +224,6 @@ fn monte_carlo_pi_string(ctx: &ScanContext, s: RuntimeString) -> Option<f64> {
     monte_carlo_pi(s.as_bstr(ctx).as_bytes())
 }
 
-fn entropy(data: &[u8]) -> f64 {
-    if data.is_empty() {
-        return 0.0;
-    }
-
-    let mut distribution = [0u64; 256];
-    for byte in data {
-        distribution[*byte as usize] += 1;
-    }
-
-    let mut entropy: f64 = 0.0;
-    for value in &distribution {
-        if *value != 0 {
-            let x = *value as f64 / data.len() as f64;
-            entropy -= x * f64::log2(x);
-        }
-    }
-
-    entropy
-}
-
 fn deviation(data: &[u8], mean: f64) -> Option<f64> {
     if data.is_empty() {
         return None;
@@ -1,8 +1,13 @@
-use std::sync::LazyLock;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, LazyLock};
+use std::thread;
+use std::time::Duration;
 
 use protobuf::reflect::MessageDescriptor;
 use protobuf::MessageDyn;
-use rustc_hash::FxHashMap;
 
 use thiserror::Error;
 
@@ -56,6 +61,152 @@ pub enum ModuleError {
 type MainFn =
     fn(&[u8], Option<&[u8]>) -> Result<Box<dyn MessageDyn>, ModuleError>;
 
+thread_local! {
+    /// Notices emitted by the module whose main function is being executed.
+    /// Each notice is a tuple with the notice's code and message. See
+    /// [`emit_notice`].
+    static NOTICES: RefCell<Vec<(&'static str, String)>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+/// Emits a notice about some recoverable anomaly found by a module while
+/// parsing the scanned data (e.g. a truncated directory or a corrupt section
+/// table).
+///
+/// This is intended to be called from the module's main function. `code` is
+/// a short identifier for the kind of anomaly (e.g. `truncated_directory`),
+/// and `message` is a human-readable description. Notices are available in
+/// [`crate::ScanResults::module_notices`].
+#[allow(dead_code)]
+pub(crate) fn emit_notice<M: Into<String>>(code: &'static str, message: M) {
+    NOTICES.with(|notices| notices.borrow_mut().push((code, message.into())));
+}
+
+/// Returns the notices emitted with [`emit_notice`] since the last call to
+/// this function.
+pub(crate) fn take_notices() -> Vec<(&'static str, String)> {
+    NOTICES.with(|notices| notices.take())
+}
+
+/// Output of a module's main function, together with the notices emitted
+/// by it.
+type MainFnOutput =
+    (Result<Box<dyn MessageDyn>, ModuleError>, Vec<(&'static str, String)>);
+
+/// Maximum number of threads running main functions that didn't finish
+/// before their timeout, per scanner. While this number is reached,
+/// [`invoke_main_fn_with_timeout`] refuses to run more main functions.
+pub(crate) const MAX_ABANDONED_THREADS: usize = 8;
+
+/// States of a thread started by [`invoke_main_fn_with_timeout`].
+const THREAD_RUNNING: u8 = 0;
+const THREAD_DONE: u8 = 1;
+const THREAD_ABANDONED: u8 = 2;
+
+/// Marks a thread started by [`invoke_main_fn_with_timeout`] as done when
+/// dropped, even if the main function panics. If the thread was abandoned,
+/// it's removed from the counter of abandoned threads.
+struct ThreadGuard {
+    state: Arc<AtomicU8>,
+    abandoned_threads: Arc<AtomicUsize>,
+}
+
+impl Drop for ThreadGuard {
+    fn drop(&mut self) {
+        if self.state.swap(THREAD_DONE, Ordering::AcqRel) == THREAD_ABANDONED {
+            self.abandoned_threads.fetch_sub(1, Ordering::AcqRel);
+        }
+    }
+}
+
+/// Invokes a module's main function in a separate thread, waiting at most
+/// `timeout` for it to finish.
+///
+/// `abandoned_threads` counts the threads started by previous calls that
+/// didn't finish in time and are still running. Each scanner has its own
+/// counter.
+///
+/// Returns the notice that must be reported if the main function doesn't
+/// produce any output, either because the timeout expires or because
+/// [`MAX_ABANDONED_THREADS`] threads that timed out in previous calls are
+/// still running. Threads can't be stopped, when the timeout expires the
+/// thread keeps running in the background, together with its copy of the
+/// scanned data, until the main function returns, and its output is
+/// discarded. If the main function panics, the panic is reported as a
+/// [`ModuleError::InternalError`].
+pub(crate) fn invoke_main_fn_with_timeout(
+    main_fn: MainFn,
+    data: Arc<[u8]>,
+    meta: Option<Vec<u8>>,
+    timeout: Duration,
+    abandoned_threads: &Arc<AtomicUsize>,
+) -> Result<MainFnOutput, (&'static str, String)> {
+    if abandoned_threads.load(Ordering::Acquire) >= MAX_ABANDONED_THREADS {
+        return Err((
+            "skipped",
+            format!(
+                "{MAX_ABANDONED_THREADS} main functions that didn't finish in time are still running"
+            ),
+        ));
+    }
+
+    let (sender, receiver) = mpsc::channel();
+    let state = Arc::new(AtomicU8::new(THREAD_RUNNING));
+    let guard = ThreadGuard {
+        state: state.clone(),
+        abandoned_threads: abandoned_threads.clone(),
+    };
+
+    thread::spawn(move || {
+        let _guard = guard;
+        let output = main_fn(&data, meta.as_deref());
+        // Notices are stored in a thread-local variable, they must be
+        // sent back to the scanning thread together with the output.
+        // Sending fails if the receiver stopped waiting, which is fine.
+        let _ = sender.send((output, take_notices()));
+    });
+
+    let panicked = || {
+        Ok((
+            Err(ModuleError::InternalError {
+                err: "main function panicked".to_string(),
+            }),
+            Vec::new(),
+        ))
+    };
+
+    match receiver.recv_timeout(timeout) {
+        Ok(output) => Ok(output),
+        Err(RecvTimeoutError::Disconnected) => panicked(),
+        Err(RecvTimeoutError::Timeout) => {
+            // The counter is incremented before changing the state, so
+            // that the thread never decrements it before it's incremented.
+            abandoned_threads.fetch_add(1, Ordering::AcqRel);
+            if state
+                .compare_exchange(
+                    THREAD_RUNNING,
+                    THREAD_ABANDONED,
+                    Ordering::AcqRel,
+                    Ordering::Acquire,
+                )
+                .is_ok()
+            {
+                return Err((
+                    "timeout",
+                    format!("main function didn't finish in {timeout:?}"),
+                ));
+            }
+            // The thread finished right after the timeout expired, its
+            // output is used anyway.
+            abandoned_threads.fetch_sub(1, Ordering::AcqRel);
+            match receiver.try_recv() {
+                Ok(output) => Ok(output),
+                Err(_) => panicked(),
+            }
+        }
+    }
+}
+
 /// A structure describing a YARA module.
 pub(crate) struct Module {
     /// Pointer to the module's main function.
@@ -68,6 +219,11 @@ pub(crate) struct Module {
     /// for the YARA module. It allows iterating the fields declared by the
     /// module and obtaining their names and types.
     pub root_struct_descriptor: MessageDescriptor,
+    /// If true, the main function always runs in the scanning thread, even
+    /// when a timeout is set with [`crate::Scanner::module_timeout`]. This
+    /// corresponds to the "main_fn_in_scan_thread" option for the YARA
+    /// module.
+    pub main_fn_in_scan_thread: bool,
 }
 
 /// Macro that adds a module to the `BUILTIN_MODULES` map.
@@ -79,9 +235,9 @@ pub(crate) struct Module {
 /// # Example
 ///
 /// add_module!(modules, "test", test, "Test", test_mod, Some(test::main as
-/// MainFn));
+/// MainFn), false);
 macro_rules! add_module {
-    ($modules:expr, $name:literal, $proto:ident, $root_message:literal, $rust_module_name:expr, $main_fn:expr) => {{
+    ($modules:expr, $name:literal, $proto:ident, $root_message:literal, $rust_module_name:expr, $main_fn:expr, $main_fn_in_scan_thread:literal) => {{
         use std::stringify;
         let root_struct_descriptor = protos::$proto::file_descriptor()
             // message_by_full_name expects a dot (.) at the beginning
@@ -99,6 +255,7 @@ macro_rules! add_module {
                 main_fn: $main_fn,
                 rust_module_name: $rust_module_name,
                 root_struct_descriptor,
+                main_fn_in_scan_thread: $main_fn_in_scan_thread,
             },
         );
     }};
@@ -127,9 +284,13 @@ macro_rules! add_module {
 /// `rust_module` is the name of the Rust module where functions exported
 /// by the YARA module are defined. This field is optional, if not provided
 /// the module is considered a data-only module.
-pub(crate) static BUILTIN_MODULES: LazyLock<FxHashMap<&'static str, Module>> =
+///
+/// A `BTreeMap` is used instead of a `HashMap` because iterating over the
+/// modules must produce the same results across builds. Modules are sorted
+/// by name.
+pub(crate) static BUILTIN_MODULES: LazyLock<BTreeMap<&'static str, Module>> =
     LazyLock::new(|| {
-        let mut modules = FxHashMap::default();
+        let mut modules = BTreeMap::new();
         // The `add_modules.rs` file is automatically generated at compile time
         // by `build.rs`. This is an example of how `add_modules.rs` looks like:
         //
@@ -229,6 +390,60 @@ pub mod mods {
     /// Data structure returned by the `pe` module.
     pub use super::protos::pe::PE;
 
+    /// Data structures defined by the `regf` module.
+    ///
+    /// The main structure produced by the module is [`regf::Regf`]. The rest
+    /// of them are used by one or more fields in the main structure.
+    ///
+    pub use super::protos::regf;
+    /// Data structure returned by the `regf` module.
+    pub use super::protos::regf::Regf;
+
+    /// Data structures defined by the `email` module.
+    ///
+    /// The main structure produced by the module is [`email::Email`]. The rest
+    /// of them are used by one or more fields in the main structure.
+    ///
+    pub use super::protos::email;
+    /// Data structure returned by the `email` module.
+    pub use super::protos::email::Email;
+
+    /// Data structures defined by the `x509` module.
+    ///
+    /// The main structure produced by the module is [`x509::X509`]. The rest
+    /// of them are used by one or more fields in the main structure.
+    ///
+    pub use super::protos::x509;
+    /// Data structure returned by the `x509` module.
+    pub use super::protos::x509::X509;
+
+    /// Data structures defined by the `iso9660` module.
+    ///
+    /// The main structure produced by the module is [`iso9660::Iso9660`]. The rest
+    /// of them are used by one or more fields in the main structure.
+    ///
+    pub use super::protos::iso9660;
+    /// Data structure returned by the `iso9660` module.
+    pub use super::protos::iso9660::Iso9660;
+
+    /// Data structures defined by the `wasm` module.
+    ///
+    /// The main structure produced by the module is [`wasm::Wasm`]. The rest
+    /// of them are used by one or more fields in the main structure.
+    ///
+    pub use super::protos::wasm;
+    /// Data structure returned by the `wasm` module.
+    pub use super::protos::wasm::Wasm;
+
+    /// Data structures defined by the `pcap` module.
+    ///
+    /// The main structure produced by the module is [`pcap::Pcap`]. The rest
+    /// of them are used by one or more fields in the main structure.
+    ///
+    pub use super::protos::pcap;
+    /// Data structure returned by the `pcap` module.
+    pub use super::protos::pcap::Pcap;
+
     /// A data structure containing the data returned by all modules.
     pub use super::protos::mods::Modules;
 
@@ -294,7 +509,12 @@ pub mod mods {
                 module.root_struct_descriptor.full_name() == proto_name
             })?;
 
-        module.main_fn?(data, meta).ok()
+        let output = module.main_fn?(data, meta).ok();
+
+        // Notices are reported only while scanning, discard them.
+        super::take_notices();
+
+        output
     }
 
     /// Invokes all YARA modules and returns the data produced by them.
@@ -316,15 +536,22 @@ pub mod mods {
         info.lnk = protobuf::MessageField(invoke::<Lnk>(data));
         info.crx = protobuf::MessageField(invoke::<Crx>(data));
         info.dex = protobuf::MessageField(invoke::<Dex>(data));
+        info.regf = protobuf::MessageField(invoke::<Regf>(data));
+        info.email = protobuf::MessageField(invoke::<Email>(data));
+        info.x509 = protobuf::MessageField(invoke::<X509>(data));
+        info.iso9660 = protobuf::MessageField(invoke::<Iso9660>(data));
+        info.wasm = protobuf::MessageField(invoke::<Wasm>(data));
+        info.pcap = protobuf::MessageField(invoke::<Pcap>(data));
         info
     }
 
     /// Iterator over built-in module names.
     ///
     /// See the "debug modules" command.
+    ///
+    /// Names are sorted alphabetically.
     pub fn module_names() -> impl Iterator<Item = &'static str> {
-        use itertools::Itertools;
-        super::BUILTIN_MODULES.keys().sorted_by_key(|k| **k).copied()
+        super::BUILTIN_MODULES.keys().copied()
     }
 
     /// Returns the definition of the module with the given name.
@@ -336,6 +563,59 @@ pub mod mods {
             .map(|m| reflect::Struct::new(Rc::<types::Struct>::from(m)))
     }
 
+    /// Returns the functions that can be called from YARA rules.
+    ///
+    /// This includes built-in functions like `uint8`, `uint16be`, etc, and
+    /// the functions exported by every module included in the current build.
+    /// Functions are sorted by their fully qualified name, and overloaded
+    /// functions appear only once, with all their signatures.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use yara_x;
+    /// for function in yara_x::mods::functions() {
+    ///     println!("{}", function.name);
+    /// }
+    /// ```
+    pub fn functions() -> impl Iterator<Item = reflect::Function> {
+        use crate::types;
+        use crate::wasm;
+        use std::collections::BTreeMap;
+        use std::rc::Rc;
+
+        let mut functions: BTreeMap<
+            String,
+            (Option<&'static str>, types::Func),
+        > = BTreeMap::new();
+
+        // Methods are not included, they are described by the types they
+        // belong to.
+        for export in wasm::wasm_exports()
+            .filter(|export| export.public && export.method_of.is_none())
+        {
+            let mangled_name = export.fully_qualified_mangled_name();
+            let name = mangled_name
+                .split_once('@')
+                .map_or(mangled_name.as_str(), |(name, _)| name)
+                .to_owned();
+
+            if let Some((_, func)) = functions.get_mut(&name) {
+                func.add_signature(types::FuncSignature::from(mangled_name))
+            } else {
+                functions.insert(
+                    name,
+                    (export.module_name(), types::Func::from(mangled_name)),
+                );
+            }
+        }
+
+        functions.into_iter().map(|(name, (module, func))| reflect::Function {
+            name,
+            module,
+            func: reflect::Func::from(Rc::new(func)),
+        })
+    }
+
     /// Types that allow for module introspection.
     ///
     /// This API is unstable and not ready for public use.
@@ -392,6 +672,21 @@ pub mod mods {
             }
         }
 
+        /// Describes a function that can be called from YARA rules.
+        ///
+        /// See [`super::functions`].
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct Function {
+            /// Fully qualified name of the function (e.g: `uint8`,
+            /// `pe.imphash`, `cuckoo.network.dns_lookup`).
+            pub name: String,
+            /// Name of the module that exports the function, or `None`
+            /// for built-in functions.
+            pub module: Option<&'static str>,
+            /// The function's signatures.
+            pub func: Func,
+        }
+
         /// Describes a function signature.
         #[derive(Clone, Debug, PartialEq)]
         pub struct FuncSignature {
@@ -423,7 +718,19 @@ pub mod mods {
 
             /// Returns the type of the field.
             pub fn ty(&self) -> Type {
-                Type::from(&self.struct_field.type_value)
+                // The type of a computed field is the type returned by the
+                // method that computes its value.
+                match &self.struct_field.type_value {
+                    TypeValue::Func(func) if self.struct_field.computed => {
+                        let signature = func
+                            .signatures()
+                            .iter()
+                            .find(|signature| signature.args.len() == 1)
+                            .unwrap();
+                        Type::from(&signature.result)
+                    }
+                    type_value => Type::from(type_value),
+                }
             }
         }
 
@@ -482,5 +789,11 @@ pub mod mods {
     }
 }
 
-#[cfg(feature = "crypto")]
+#[cfg(any(
+    feature = "crypto",
+    feature = "dex-module",
+    feature = "elf-module",
+    feature = "math-module",
+    feature = "wasm-module"
+))]
 pub(crate) mod utils;
@@ -11,8 +11,12 @@ mod dex;
 mod dotnet;
 #[cfg(feature = "elf-module")]
 mod elf;
+#[cfg(feature = "email-module")]
+mod email;
 #[cfg(feature = "hash-module")]
 mod hash;
+#[cfg(feature = "iso9660-module")]
+mod iso9660;
 #[cfg(feature = "lnk-module")]
 mod lnk;
 #[cfg(feature = "macho-module")]
@@ -21,8 +25,12 @@ mod macho;
 mod magic;
 #[cfg(feature = "math-module")]
 mod math;
+#[cfg(feature = "pcap-module")]
+mod pcap;
 #[cfg(feature = "pe-module")]
 mod pe;
+#[cfg(feature = "regf-module")]
+mod regf;
 #[cfg(feature = "string-module")]
 mod string;
 #[cfg(feature = "test_proto2-module")]
@@ -34,4 +42,8 @@ mod text;
 #[cfg(feature = "time-module")]
 mod time;
 #[cfg(feature = "vt-module")]
-mod vt;
\ No newline at end of file
+mod vt;
+#[cfg(feature = "wasm-module")]
+mod wasm;
+#[cfg(feature = "x509-module")]
+mod x509;
\ No newline at end of file
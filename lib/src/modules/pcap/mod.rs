@@ -0,0 +1,28 @@
+/*! YARA module that parses network captures.
+
+This module parses network captures in pcap and pcapng formats, like the
+ones produced by `tcpdump` and Wireshark. It exposes metadata about the
+capture, the hosts that sent more traffic, and the names in DNS queries,
+which allows triaging network captures with the same rules used for
+files.
+ */
+
+use crate::modules::prelude::*;
+use crate::modules::protos::pcap::*;
+
+mod parser;
+
+#[cfg(test)]
+mod tests;
+
+#[module_main]
+fn main(data: &[u8], _meta: Option<&[u8]>) -> Result<Pcap, ModuleError> {
+    match parser::PcapParser::new().parse(data) {
+        Some(pcap) => Ok(pcap),
+        None => {
+            let mut pcap = Pcap::new();
+            pcap.set_is_pcap(false);
+            Ok(pcap)
+        }
+    }
+}
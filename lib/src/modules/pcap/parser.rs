@@ -0,0 +1,581 @@
+use std::collections::{HashMap, HashSet};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use itertools::Itertools;
+use nom::number::complete::{u16, u32};
+use nom::number::Endianness;
+use nom::Parser;
+use protobuf::EnumOrUnknown;
+
+use crate::modules::protos::pcap;
+
+type NomError<'a> = nom::error::Error<&'a [u8]>;
+
+/// A parser for network captures in pcap and pcapng formats.
+///
+/// Both formats contain a sequence of packets, each one with a timestamp
+/// and the data captured from the wire. The parser decodes the link layer
+/// and the IP layer of each packet for computing statistics about the
+/// hosts, and decodes DNS queries sent over UDP.
+///
+/// See:
+/// https://www.ietf.org/archive/id/draft-ietf-opsawg-pcap-04.html
+/// https://www.ietf.org/archive/id/draft-ietf-opsawg-pcapng-02.html
+pub struct PcapParser {
+    result: pcap::Pcap,
+    /// Number of packets and bytes sent by each host.
+    hosts: HashMap<IpAddr, (u64, u64)>,
+    /// Distinct names in DNS queries.
+    dns_queries: HashSet<String>,
+}
+
+/// An interface in a pcapng file.
+struct Interface {
+    link_type: u32,
+    /// Number of timestamp units per second.
+    units_per_second: u64,
+}
+
+impl PcapParser {
+    /// Maximum number of distinct hosts tracked.
+    const MAX_HOSTS: usize = 65_536;
+
+    /// Maximum number of hosts included in `top_talkers`.
+    const MAX_TOP_TALKERS: usize = 10;
+
+    /// Maximum number of distinct DNS query names included in the result.
+    const MAX_DNS_QUERIES: usize = 10_000;
+
+    /// Maximum number of questions processed in a DNS message.
+    const MAX_DNS_QUESTIONS: usize = 16;
+
+    const LINKTYPE_NULL: u32 = 0;
+    const LINKTYPE_ETHERNET: u32 = 1;
+    const LINKTYPE_RAW: u32 = 101;
+    const LINKTYPE_LOOP: u32 = 108;
+    const LINKTYPE_LINUX_SLL: u32 = 113;
+    const LINKTYPE_IPV4: u32 = 228;
+    const LINKTYPE_IPV6: u32 = 229;
+    const LINKTYPE_LINUX_SLL2: u32 = 276;
+
+    const BLOCK_SECTION_HEADER: u32 = 0x0a0d0d0a;
+    const BLOCK_INTERFACE_DESCRIPTION: u32 = 0x00000001;
+    const BLOCK_PACKET: u32 = 0x00000002;
+    const BLOCK_SIMPLE_PACKET: u32 = 0x00000003;
+    const BLOCK_ENHANCED_PACKET: u32 = 0x00000006;
+
+    pub fn new() -> Self {
+        Self {
+            result: pcap::Pcap::new(),
+            hosts: HashMap::new(),
+            dns_queries: HashSet::new(),
+        }
+    }
+
+    pub fn parse(mut self, data: &[u8]) -> Option<pcap::Pcap> {
+        if data.starts_with(&[0x0a, 0x0d, 0x0d, 0x0a]) {
+            self.parse_pcapng(data)?;
+            self.result.set_format(pcap::Format::FORMAT_PCAPNG);
+        } else {
+            self.parse_pcap(data)?;
+            self.result.set_format(pcap::Format::FORMAT_PCAP);
+        }
+
+        self.result.set_is_pcap(true);
+
+        self.result.top_talkers = self
+            .hosts
+            .iter()
+            .sorted_by(|(addr_a, (_, bytes_a)), (addr_b, (_, bytes_b))| {
+                bytes_b.cmp(bytes_a).then(addr_a.cmp(addr_b))
+            })
+            .take(Self::MAX_TOP_TALKERS)
+            .map(|(addr, (packets, bytes))| {
+                let mut host = pcap::Host::new();
+                host.set_address(addr.to_string());
+                host.set_packets(*packets);
+                host.set_bytes(*bytes);
+                host
+            })
+            .collect();
+
+        Some(self.result)
+    }
+
+    /// Parses a capture in pcap format.
+    fn parse_pcap(&mut self, data: &[u8]) -> Option<()> {
+        // The magic number is 0xa1b2c3d4 for captures with timestamps in
+        // microseconds, and 0xa1b23c4d for captures with timestamps in
+        // nanoseconds. It also tells the endianness of the file.
+        let endianness = match data.get(..4)? {
+            [0xd4, 0xc3, 0xb2, 0xa1] | [0x4d, 0x3c, 0xb2, 0xa1] => {
+                Endianness::Little
+            }
+            [0xa1, 0xb2, 0xc3, 0xd4] | [0xa1, 0xb2, 0x3c, 0x4d] => {
+                Endianness::Big
+            }
+            _ => return None,
+        };
+
+        let (
+            mut remainder,
+            (
+                _magic,
+                major_version,
+                minor_version,
+                _reserved1,
+                _reserved2,
+                _snap_len,
+                link_type,
+            ),
+        ) = (
+            u32::<_, NomError>(endianness),
+            u16(endianness),
+            u16(endianness),
+            u32(endianness),
+            u32(endianness),
+            u32(endianness),
+            u32(endianness),
+        )
+            .parse(data)
+            .ok()?;
+
+        // The upper bits of the link type field contain other information,
+        // like the length of the frame check sequence.
+        let link_type = link_type & 0xffff;
+
+        self.result.set_major_version(major_version.into());
+        self.result.set_minor_version(minor_version.into());
+        self.result.link_type =
+            Some(EnumOrUnknown::from_i32(link_type as i32));
+        self.result.set_number_of_packets(0);
+
+        while let Ok((rest, (ts_sec, _ts_frac, captured_len, original_len))) =
+            (
+                u32::<_, NomError>(endianness),
+                u32(endianness),
+                u32(endianness),
+                u32(endianness),
+            )
+                .parse(remainder)
+        {
+            // The last packet may be truncated.
+            let (packet, rest) =
+                rest.split_at((captured_len as usize).min(rest.len()));
+
+            self.packet(link_type, packet, original_len, Some(ts_sec.into()));
+
+            remainder = rest;
+        }
+
+        Some(())
+    }
+
+    /// Parses a capture in pcapng format.
+    ///
+    /// A pcapng file is a sequence of blocks. Each section starts with a
+    /// section header block, which determines the endianness of the blocks
+    /// in the section, and is followed by interface description blocks
+    /// and packet blocks.
+    fn parse_pcapng(&mut self, data: &[u8]) -> Option<()> {
+        let mut remainder = data;
+        let mut endianness = Endianness::Little;
+        let mut interfaces = Vec::new();
+
+        self.result.set_number_of_packets(0);
+
+        while remainder.len() >= 12 {
+            if remainder.starts_with(&[0x0a, 0x0d, 0x0d, 0x0a]) {
+                endianness = match &remainder[8..12] {
+                    [0x4d, 0x3c, 0x2b, 0x1a] => Endianness::Little,
+                    [0x1a, 0x2b, 0x3c, 0x4d] => Endianness::Big,
+                    _ => break,
+                };
+            }
+
+            let Ok((_, (block_type, block_len))) =
+                (u32::<_, NomError>(endianness), u32(endianness))
+                    .parse(remainder)
+            else {
+                break;
+            };
+
+            let block_len = block_len as usize;
+
+            if block_len < 12 || block_len > remainder.len() {
+                break;
+            }
+
+            let body = &remainder[8..block_len - 4];
+            remainder = &remainder[block_len..];
+
+            match block_type {
+                Self::BLOCK_SECTION_HEADER => {
+                    // Interface IDs are relative to the current section.
+                    interfaces.clear();
+                    if self.result.major_version.is_none() {
+                        if let Ok((_, (_magic, major, minor))) = (
+                            u32::<_, NomError>(endianness),
+                            u16(endianness),
+                            u16(endianness),
+                        )
+                            .parse(body)
+                        {
+                            self.result.set_major_version(major.into());
+                            self.result.set_minor_version(minor.into());
+                        }
+                    }
+                }
+                Self::BLOCK_INTERFACE_DESCRIPTION => {
+                    if let Some(interface) = interface(body, endianness) {
+                        if self.result.link_type.is_none() {
+                            self.result.link_type =
+                                Some(EnumOrUnknown::from_i32(
+                                    interface.link_type as i32,
+                                ));
+                        }
+                        interfaces.push(interface);
+                    }
+                }
+                Self::BLOCK_ENHANCED_PACKET | Self::BLOCK_PACKET => {
+                    let header = if block_type == Self::BLOCK_PACKET {
+                        (
+                            u16::<_, NomError>(endianness).map(u32::from),
+                            u16(endianness),
+                        )
+                            .map(|(interface_id, _drops)| interface_id)
+                            .parse(body)
+                    } else {
+                        u32(endianness).parse(body)
+                    };
+
+                    let Ok((
+                        rest,
+                        (
+                            interface_id,
+                            (ts_high, ts_low, captured_len, original_len),
+                        ),
+                    )) = header.and_then(|(rest, interface_id)| {
+                        (
+                            u32(endianness),
+                            u32(endianness),
+                            u32(endianness),
+                            u32(endianness),
+                        )
+                            .parse(rest)
+                            .map(|(rest, fields)| {
+                                (rest, (interface_id, fields))
+                            })
+                    })
+                    else {
+                        continue;
+                    };
+
+                    let Some(interface) =
+                        interfaces.get(interface_id as usize)
+                    else {
+                        continue;
+                    };
+
+                    let packet =
+                        &rest[..(captured_len as usize).min(rest.len())];
+
+                    let timestamp = ((ts_high as u64) << 32) | ts_low as u64;
+                    let timestamp = timestamp
+                        .checked_div(interface.units_per_second)
+                        .and_then(|ts| i64::try_from(ts).ok());
+
+                    self.packet(
+                        interface.link_type,
+                        packet,
+                        original_len,
+                        timestamp,
+                    );
+                }
+                Self::BLOCK_SIMPLE_PACKET => {
+                    // Simple packet blocks don't have timestamps, and are
+                    // always captured from the first interface.
+                    let Ok((rest, original_len)) =
+                        u32::<_, NomError>(endianness).parse(body)
+                    else {
+                        continue;
+                    };
+
+                    let Some(interface) = interfaces.first() else {
+                        continue;
+                    };
+
+                    let packet =
+                        &rest[..(original_len as usize).min(rest.len())];
+
+                    self.packet(
+                        interface.link_type,
+                        packet,
+                        original_len,
+                        None,
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        Some(())
+    }
+
+    /// Processes a packet captured from an interface with the given link
+    /// type.
+    fn packet(
+        &mut self,
+        link_type: u32,
+        packet: &[u8],
+        original_len: u32,
+        timestamp: Option<i64>,
+    ) {
+        self.result.set_number_of_packets(self.result.number_of_packets() + 1);
+
+        if let Some(timestamp) = timestamp {
+            let first = self.result.first_packet_time.get_or_insert(timestamp);
+            *first = (*first).min(timestamp);
+            let last = self.result.last_packet_time.get_or_insert(timestamp);
+            *last = (*last).max(timestamp);
+        }
+
+        if let Some(ip_packet) = ip_packet(link_type, packet) {
+            self.ip_packet(ip_packet, original_len);
+        }
+    }
+
+    /// Processes an IPv4 or IPv6 packet.
+    fn ip_packet(&mut self, packet: &[u8], original_len: u32) {
+        let Some((src, protocol, payload)) = (match packet[0] >> 4 {
+            4 => ipv4(packet),
+            6 => ipv6(packet),
+            _ => None,
+        }) else {
+            return;
+        };
+
+        self.result
+            .set_number_of_ip_packets(self.result.number_of_ip_packets() + 1);
+
+        if self.hosts.len() < Self::MAX_HOSTS || self.hosts.contains_key(&src)
+        {
+            let (packets, bytes) = self.hosts.entry(src).or_default();
+            *packets += 1;
+            *bytes += original_len as u64;
+        }
+
+        match protocol {
+            // TCP
+            6 => {
+                self.result.set_number_of_tcp_packets(
+                    self.result.number_of_tcp_packets() + 1,
+                );
+            }
+            // UDP
+            17 => {
+                self.result.set_number_of_udp_packets(
+                    self.result.number_of_udp_packets() + 1,
+                );
+                if let Some(header) = payload.get(..8) {
+                    let src_port = u16::from_be_bytes([header[0], header[1]]);
+                    let dst_port = u16::from_be_bytes([header[2], header[3]]);
+                    if src_port == 53 || dst_port == 53 {
+                        self.dns_message(&payload[8..]);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Processes a DNS message, adding the names in the questions to the
+    /// result if the message is a query.
+    fn dns_message(&mut self, msg: &[u8]) {
+        let Some(header) = msg.get(..12) else {
+            return;
+        };
+
+        // Ignore responses, which repeat the questions in the query.
+        if header[2] & 0x80 != 0 {
+            return;
+        }
+
+        let questions = u16::from_be_bytes([header[4], header[5]]);
+        let mut offset = 12;
+
+        for _ in 0..(questions as usize).min(Self::MAX_DNS_QUESTIONS) {
+            let Some((name, end)) = dns_name(msg, offset) else {
+                return;
+            };
+
+            self.result.set_number_of_dns_queries(
+                self.result.number_of_dns_queries() + 1,
+            );
+
+            if self.dns_queries.len() < Self::MAX_DNS_QUERIES
+                && self.dns_queries.insert(name.clone())
+            {
+                self.result.dns_queries.push(name);
+            }
+
+            // Skip the name, type and class.
+            offset = end + 4;
+        }
+    }
+}
+
+/// Parses the body of an interface description block.
+fn interface(body: &[u8], endianness: Endianness) -> Option<Interface> {
+    let (mut options, (link_type, _reserved, _snap_len)) =
+        (u16::<_, NomError>(endianness), u16(endianness), u32(endianness))
+            .parse(body)
+            .ok()?;
+
+    let mut interface =
+        Interface { link_type: link_type.into(), units_per_second: 1_000_000 };
+
+    // Look for the `if_tsresol` option, which indicates the resolution of
+    // the timestamps. When the most significant bit is zero, the resolution
+    // is a negative power of 10, otherwise it's a negative power of 2.
+    while let Ok((rest, (code, len))) =
+        (u16::<_, NomError>(endianness), u16(endianness)).parse(options)
+    {
+        if code == 0 {
+            break;
+        }
+        if code == 9 && len == 1 {
+            let resolution = *rest.first()?;
+            interface.units_per_second = if resolution & 0x80 == 0 {
+                10_u64.checked_pow(resolution.into())?
+            } else {
+                1_u64.checked_shl((resolution & 0x7f).into())?
+            };
+        }
+        // Option values are padded to 32 bits.
+        let padded_len = (len as usize).next_multiple_of(4);
+        options = rest.get(padded_len..)?;
+    }
+
+    Some(interface)
+}
+
+/// Returns the IP packet contained in a packet captured from an interface
+/// with the given link type, if any.
+fn ip_packet(link_type: u32, packet: &[u8]) -> Option<&[u8]> {
+    let packet = match link_type {
+        PcapParser::LINKTYPE_ETHERNET => {
+            let mut ether_type =
+                u16::from_be_bytes(packet.get(12..14)?.try_into().unwrap());
+            let mut offset = 14;
+            // Skip 802.1Q and 802.1ad VLAN tags.
+            while ether_type == 0x8100 || ether_type == 0x88a8 {
+                ether_type = u16::from_be_bytes(
+                    packet.get(offset + 2..offset + 4)?.try_into().unwrap(),
+                );
+                offset += 4;
+            }
+            if ether_type != 0x0800 && ether_type != 0x86dd {
+                return None;
+            }
+            packet.get(offset..)?
+        }
+        PcapParser::LINKTYPE_LINUX_SLL => packet.get(16..)?,
+        PcapParser::LINKTYPE_LINUX_SLL2 => packet.get(20..)?,
+        // The link layer header contains the address family.
+        PcapParser::LINKTYPE_NULL | PcapParser::LINKTYPE_LOOP => {
+            packet.get(4..)?
+        }
+        PcapParser::LINKTYPE_RAW
+        | PcapParser::LINKTYPE_IPV4
+        | PcapParser::LINKTYPE_IPV6 => packet,
+        _ => return None,
+    };
+
+    if packet.is_empty() {
+        None
+    } else {
+        Some(packet)
+    }
+}
+
+/// Parses an IPv4 packet, returning the source address, the protocol, and
+/// the payload. Fragments other than the first one have no payload, as it
+/// doesn't start with a transport layer header.
+fn ipv4(packet: &[u8]) -> Option<(IpAddr, u8, &[u8])> {
+    let header_len = (packet[0] & 0x0f) as usize * 4;
+    let total_len =
+        u16::from_be_bytes(packet.get(2..4)?.try_into().unwrap()) as usize;
+    let fragment_offset =
+        u16::from_be_bytes(packet.get(6..8)?.try_into().unwrap()) & 0x1fff;
+    let protocol = *packet.get(9)?;
+    let src: [u8; 4] = packet.get(12..16)?.try_into().unwrap();
+
+    let payload = if fragment_offset == 0 {
+        packet.get(header_len..total_len.min(packet.len())).unwrap_or_default()
+    } else {
+        &[]
+    };
+
+    Some((IpAddr::V4(Ipv4Addr::from(src)), protocol, payload))
+}
+
+/// Parses an IPv6 packet, returning the source address, the next header,
+/// and the payload. Extension headers are not decoded.
+fn ipv6(packet: &[u8]) -> Option<(IpAddr, u8, &[u8])> {
+    let next_header = *packet.get(6)?;
+    let src: [u8; 16] = packet.get(8..24)?.try_into().unwrap();
+    let payload = packet.get(40..)?;
+
+    Some((IpAddr::V6(Ipv6Addr::from(src)), next_header, payload))
+}
+
+/// Parses a name in a DNS message, starting at the given offset.
+///
+/// Returns the name, in lowercase and without the trailing dot, and the
+/// offset where the name ends. Names can be compressed by replacing their
+/// last labels with a pointer to another name in the same message.
+fn dns_name(msg: &[u8], mut offset: usize) -> Option<(String, usize)> {
+    // Maximum number of pointers followed, for avoiding infinite loops.
+    const MAX_POINTERS: usize = 16;
+    // Maximum length of a name, as defined in RFC 1035.
+    const MAX_NAME_LEN: usize = 255;
+
+    let mut name = String::new();
+    let mut end = None;
+    let mut pointers = 0;
+
+    loop {
+        let len = *msg.get(offset)? as usize;
+        match len & 0xc0 {
+            0x00 if len == 0 => {
+                end.get_or_insert(offset + 1);
+                break;
+            }
+            0x00 => {
+                let label = msg.get(offset + 1..offset + 1 + len)?;
+                if !name.is_empty() {
+                    name.push('.');
+                }
+                name.push_str(
+                    &String::from_utf8_lossy(label).to_ascii_lowercase(),
+                );
+                if name.len() > MAX_NAME_LEN {
+                    return None;
+                }
+                offset += 1 + len;
+            }
+            0xc0 => {
+                pointers += 1;
+                if pointers > MAX_POINTERS {
+                    return None;
+                }
+                end.get_or_insert(offset + 2);
+                offset = ((len & 0x3f) << 8) | *msg.get(offset + 1)? as usize;
+            }
+            _ => return None,
+        }
+    }
+
+    Some((name, end?))
+}
@@ -0,0 +1,100 @@
+use crate::modules::tests::create_binary_from_zipped_ihex;
+use crate::tests::rule_false;
+use crate::tests::rule_true;
+use crate::tests::test_rule;
+
+#[test]
+fn ethernet() {
+    let pcap = create_binary_from_zipped_ihex(
+        "src/modules/pcap/tests/testdata/pcap-ethernet.in.zip",
+    );
+
+    rule_true!(
+        r#"
+        import "pcap"
+        rule test {
+          condition:
+            pcap.is_pcap and
+            pcap.format == pcap.FORMAT_PCAP and
+            pcap.link_type == pcap.LINKTYPE_ETHERNET and
+            pcap.number_of_packets == 7 and
+            pcap.number_of_ip_packets == 6 and
+            pcap.number_of_tcp_packets == 2 and
+            pcap.number_of_udp_packets == 4 and
+            pcap.first_packet_time == 1700000000 and
+            pcap.last_packet_time == 1700000010
+        }
+        "#,
+        &pcap
+    );
+
+    rule_true!(
+        r#"
+        import "pcap"
+        rule test {
+          condition:
+            pcap.number_of_dns_queries == 3 and
+            for any q in pcap.dns_queries : (q == "evil.example.net") and
+            for all q in pcap.dns_queries : (q endswith "example.com" or q endswith "example.net")
+        }
+        "#,
+        &pcap
+    );
+
+    rule_true!(
+        r#"
+        import "pcap"
+        rule test {
+          condition:
+            pcap.top_talkers[0].address == "93.184.216.34" and
+            pcap.top_talkers[0].packets == 1 and
+            for any host in pcap.top_talkers : (
+              host.address == "2001:db8::1"
+            )
+        }
+        "#,
+        &pcap
+    );
+}
+
+#[test]
+fn pcapng() {
+    let pcap = create_binary_from_zipped_ihex(
+        "src/modules/pcap/tests/testdata/pcap-pcapng.in.zip",
+    );
+
+    rule_true!(
+        r#"
+        import "pcap"
+        rule test {
+          condition:
+            pcap.format == pcap.FORMAT_PCAPNG and
+            pcap.link_type == pcap.LINKTYPE_RAW and
+            pcap.number_of_packets == 4 and
+            pcap.number_of_tcp_packets == 1 and
+            pcap.first_packet_time == 1710000000 and
+            pcap.last_packet_time == 1710000060 and
+            pcap.dns_queries[0] == "www.virustotal.com"
+        }
+        "#,
+        &pcap
+    );
+}
+
+#[test]
+fn not_a_capture() {
+    let data = create_binary_from_zipped_ihex(
+        "src/modules/pcap/tests/testdata/pcap-not-a-capture.in.zip",
+    );
+
+    rule_false!(
+        r#"
+        import "pcap"
+        rule test {
+          condition:
+            pcap.is_pcap
+        }
+        "#,
+        &data
+    );
+}
@@ -1,10 +1,9 @@
 use std::borrow::Cow;
 use std::collections::HashSet;
-use std::fmt::Write;
 
 use bstr::ByteSlice;
 use const_oid::db::{rfc5911, rfc5912, rfc6268};
-use der_parser::asn1_rs::{Set, Tag, ToDer, UtcTime};
+use der_parser::asn1_rs::{Set, ToDer, UtcTime};
 use digest::Digest;
 use itertools::Itertools;
 use md2::Md2;
@@ -22,9 +21,10 @@ use log::error;
 
 use crate::modules::protos;
 use crate::modules::utils::asn1::{
-    oid, oid_to_object_identifier, oid_to_str, Attribute, Certificate,
-    ContentInfo, DigestInfo, SignedData, SignerInfo, SpcIndirectDataContent,
-    SpcSpOpusInfo, TstInfo,
+    algorithm_to_str, extended_key_usage, format_general_name, format_name,
+    oid, oid_to_object_identifier, Attribute, Certificate, ContentInfo,
+    DigestInfo, SignedData, SignerInfo, SpcIndirectDataContent, SpcSpOpusInfo,
+    TstInfo,
 };
 use crate::modules::utils::crypto::PublicKey;
 
@@ -239,44 +239,46 @@ impl AuthenticodeParser {
         }
 
         // Compute the Authenticode hash by ourselves. This hash will be
-        // compared later with the one included in the PE file.
+        // compared later with the one included in the PE file. With unknown
+        // digest algorithms the hash is not computed, and the signature is
+        // reported as not verified.
         let computed_authenticode_hash = match digest_algorithm {
             rfc5912::ID_MD_2 | rfc5912::MD_2_WITH_RSA_ENCRYPTION => {
                 let mut md2 = Md2::default();
                 authenticode_hasher.hash(&mut md2);
-                md2.finalize().to_vec()
+                Some(md2.finalize().to_vec())
             }
             rfc5912::ID_MD_5 | rfc5912::MD_5_WITH_RSA_ENCRYPTION => {
                 let mut md5 = Md5::default();
                 authenticode_hasher.hash(&mut md5);
-                md5.finalize().to_vec()
+                Some(md5.finalize().to_vec())
             }
             rfc5912::ID_SHA_1
             | rfc5912::SHA_1_WITH_RSA_ENCRYPTION
             | oid::SHA1_WITH_RSA_ENCRYPTION_OBSOLETE => {
                 let mut sha1 = Sha1::default();
                 authenticode_hasher.hash(&mut sha1);
-                sha1.finalize().to_vec()
+                Some(sha1.finalize().to_vec())
             }
             rfc5912::ID_SHA_256 | rfc5912::SHA_256_WITH_RSA_ENCRYPTION => {
                 let mut sha256 = Sha256::default();
                 authenticode_hasher.hash(&mut sha256);
-                sha256.finalize().to_vec()
+                Some(sha256.finalize().to_vec())
             }
             rfc5912::ID_SHA_384 | rfc5912::SHA_384_WITH_RSA_ENCRYPTION => {
                 let mut sha384 = Sha384::default();
                 authenticode_hasher.hash(&mut sha384);
-                sha384.finalize().to_vec()
+                Some(sha384.finalize().to_vec())
             }
             rfc5912::ID_SHA_512 | rfc5912::SHA_512_WITH_RSA_ENCRYPTION => {
                 let mut sha512 = Sha512::default();
                 authenticode_hasher.hash(&mut sha512);
-                sha512.finalize().to_vec()
+                Some(sha512.finalize().to_vec())
             }
             _ => {
                 #[cfg(feature = "logging")]
                 error!("unknown digest algorithm: {:?}", digest_algorithm);
-                return Err(ParseError::InvalidDigestAlgorithm);
+                None
             }
         };
 
@@ -293,8 +295,9 @@ impl AuthenticodeParser {
         // * The `SignerInfo` struct has not been tampered, which is verified
         //   by `verify_signer_info`.
         //
-        let verified = authenticode_digest.digest
-            == computed_authenticode_hash.as_slice()
+        let verified = computed_authenticode_hash
+            .as_deref()
+            .is_some_and(|hash| hash == authenticode_digest.digest)
             && verify_message_digest(
                 &signer_info.digest_algorithm,
                 signed_data_raw,
@@ -360,7 +363,8 @@ impl AuthenticodeParser {
                     Err(_) => continue,
                 };
 
-            countersignature.digest_alg = oid_to_str(tst.hash_algorithm.oid());
+            countersignature.digest_alg =
+                algorithm_to_str(tst.hash_algorithm.oid());
             countersignature.digest = tst.hashed_message;
 
             let cs_si_digest = match cs_si
@@ -443,7 +447,7 @@ impl AuthenticodeParser {
 
         Ok(AuthenticodeCountersign {
             signer: si.serial_number.clone(),
-            digest_alg: oid_to_str(si.digest_algorithm.oid()),
+            digest_alg: algorithm_to_str(si.digest_algorithm.oid()),
             digest,
             signing_time,
             verified: false,
@@ -467,7 +471,7 @@ pub struct AuthenticodeSignature<'a> {
     countersignatures: Vec<AuthenticodeCountersign<'a>>,
     program_name: Option<String>,
     more_info: Option<String>,
-    computed_authenticode_hash: Vec<u8>,
+    computed_authenticode_hash: Option<Vec<u8>>,
     verified: bool,
 }
 
@@ -478,20 +482,21 @@ impl<'a> AuthenticodeSignature<'a> {
         self.authenticode_digest.digest
     }
 
-    /// Get the Authenticode hash computed by ourselves.
+    /// Get the Authenticode hash computed by ourselves, or `None` if the
+    /// digest algorithm is not supported.
     #[inline]
-    pub fn computed_authenticode_hash(&self) -> &[u8] {
-        self.computed_authenticode_hash.as_slice()
+    pub fn computed_authenticode_hash(&self) -> Option<&[u8]> {
+        self.computed_authenticode_hash.as_deref()
     }
 
     /// Get the name of the Authenticode hash algorithm.
     pub fn authenticode_hash_algorithm(&self) -> Cow<'static, str> {
-        oid_to_str(self.authenticode_digest.algorithm.oid())
+        algorithm_to_str(self.authenticode_digest.algorithm.oid())
     }
 
     #[inline]
     pub fn signer_info_digest_alg(&self) -> Cow<'static, str> {
-        oid_to_str(self.signer_info.digest_algorithm.oid())
+        algorithm_to_str(self.signer_info.digest_algorithm.oid())
     }
 
     #[inline]
@@ -560,7 +565,7 @@ impl From<&AuthenticodeSignature<'_>> for protos::pe::Signature {
 
         sig.set_digest(hex::encode(value.stored_authenticode_hash()));
         sig.set_digest_alg(value.authenticode_hash_algorithm().into_owned());
-        sig.set_file_digest(hex::encode(value.computed_authenticode_hash()));
+        sig.file_digest = value.computed_authenticode_hash().map(hex::encode);
         sig.set_verified(value.verified());
 
         sig.certificates.extend(
@@ -577,6 +582,16 @@ impl From<&AuthenticodeSignature<'_>> for protos::pe::Signature {
             sig.countersignatures.push(pbcs);
         }
 
+        // The time when the file was signed is taken from the verified
+        // countersignatures. If there are several of them, the earliest
+        // one is used.
+        sig.sign_time = sig
+            .countersignatures
+            .iter()
+            .filter(|cs| cs.verified())
+            .filter_map(|cs| cs.sign_time)
+            .min();
+
         sig.set_number_of_certificates(
             sig.certificates.len().try_into().unwrap(),
         );
@@ -657,7 +672,8 @@ impl From<&Certificate<'_>> for protos::pe::Certificate {
         ));
 
         cert.set_algorithm(
-            oid_to_str(&value.x509.signature_algorithm.algorithm).into_owned(),
+            algorithm_to_str(&value.x509.signature_algorithm.algorithm)
+                .into_owned(),
         );
 
         cert.set_thumbprint(value.thumbprint.clone());
@@ -670,69 +686,26 @@ impl From<&Certificate<'_>> for protos::pe::Certificate {
             value.x509.tbs_certificate.validity.not_after.timestamp(),
         );
 
-        cert
-    }
-}
+        if let Ok(Some(key_usage)) = value.x509.key_usage() {
+            cert.set_key_usage(key_usage.value.flags.into());
+        }
 
-/// Produces a printable string for a x509 name.
-///
-/// The [`X509Name`] type implements the [`std::fmt::Display`] trait, but the
-/// resulting string follows the [RFC 4514], resulting in something like:
-///
-/// ```text
-/// CN=Thawte Timestamping CA,OU=Thawte Certification,O=Thawte,L=Durbanville,ST=Western Cape,C=ZA
-/// ```
-///
-/// However, the format traditionally used by YARA is inherited from OpenSSL
-/// and looks like:
-///
-/// ```text
-/// /C=ZA/ST=Western Cape/L=Durbanville/O=Thawte/OU=Thawte Certification/CN=Thawte Timestamping CA
-/// ```
-///
-/// [RFC 4514]: https://datatracker.ietf.org/doc/html/rfc4514
-fn format_name(name: &X509Name) -> String {
-    let mut n = String::new();
-    for rdn in name.iter_rdn() {
-        write!(n, "/").unwrap();
-        for atv in rdn.iter() {
-            let key = oid_to_str(atv.attr_type());
-            let attr_val = atv.attr_value();
-            // Not using `atv.as_str()` because it doesn't take into account
-            // the `Tag::TeletexString` case.
-            let val = match attr_val.tag() {
-                Tag::PrintableString => {
-                    attr_val.as_printablestring().ok().map(|s| s.string())
-                }
-                Tag::Utf8String => {
-                    attr_val.as_utf8string().ok().map(|s| s.string())
-                }
-                Tag::Ia5String => {
-                    attr_val.as_ia5string().ok().map(|s| s.string())
-                }
-                Tag::TeletexString => {
-                    attr_val.as_teletexstring().ok().map(|s| s.string())
-                }
-                _ => None,
-            };
-            match (key, val) {
-                (key, Some(val)) => {
-                    write!(n, "{key}=").unwrap();
-                    for char in val.chars() {
-                        n.write_char(char).unwrap();
-                    }
-                }
-                (key, None) => {
-                    write!(n, "{key}=#").unwrap();
-                    for c in attr_val.data {
-                        write!(n, "{c:02x}").unwrap();
-                    }
-                }
-            }
+        if let Some((flags, oids)) = extended_key_usage(&value.x509) {
+            cert.set_extended_key_usage(flags);
+            cert.extended_key_usage_oids = oids;
+        }
+
+        if let Ok(Some(san)) = value.x509.subject_alternative_name() {
+            cert.subject_alternative_names = san
+                .value
+                .general_names
+                .iter()
+                .filter_map(format_general_name)
+                .collect();
         }
-    }
 
-    n
+        cert
+    }
 }
 
 /// Given a hashing algorithm and a message, compute the message's hash
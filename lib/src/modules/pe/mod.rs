@@ -4,7 +4,7 @@ This allows creating YARA rules based on PE metadata, including sections,
 imports and exports, resources, etc.
  */
 
-use std::cell::RefCell;
+use std::cmp::min;
 use std::rc::Rc;
 use std::slice::Iter;
 
@@ -19,6 +19,7 @@ use nom::number::complete::{le_u16, le_u32};
 use crate::compiler::RegexpId;
 use crate::modules::prelude::*;
 use crate::modules::protos::pe::*;
+use crate::modules::utils::stats::entropy;
 use crate::types::Struct;
 
 #[cfg(test)]
@@ -28,18 +29,8 @@ mod authenticode;
 pub mod parser;
 mod rva2off;
 
-thread_local!(
-    static IMPHASH_CACHE: RefCell<Option<String>> =
-        const { RefCell::new(None) };
-
-    static CHECKSUM_CACHE: RefCell<Option<i64>> = const { RefCell::new(None) };
-);
-
 #[module_main]
 fn main(data: &[u8], _meta: Option<&[u8]>) -> Result<PE, ModuleError> {
-    IMPHASH_CACHE.with(|cache| *cache.borrow_mut() = None);
-    CHECKSUM_CACHE.with(|cache| *cache.borrow_mut() = None);
-
     match parser::PE::parse(data) {
         Ok(pe) => Ok(pe.into()),
         Err(_) => {
@@ -91,6 +82,10 @@ fn rva_to_offset(ctx: &ScanContext, rva: i64) -> Option<i64> {
 /// in the header.
 #[module_export]
 fn calculate_checksum(ctx: &mut ScanContext) -> Option<i64> {
+    ctx.computed_value("pe.calculate_checksum", calculate_checksum_impl)
+}
+
+fn calculate_checksum_impl(ctx: &mut ScanContext) -> Option<i64> {
     // In essence, the checksum algorithm goes as follows:
     //
     // - Read the file as a series of 16-bit little-endian unsigned integers.
@@ -123,13 +118,6 @@ fn calculate_checksum(ctx: &mut ScanContext) -> Option<i64> {
     // in the header is not aligned to a 4-bytes boundary. Such files are not
     // very common, but they do exist. Example:
     // af3f20a9272489cbef4281c8c86ad42ccfb04ccedd3ada1e8c26939c726a4c8e
-    let cached: Option<i64> =
-        CHECKSUM_CACHE.with(|cache| -> Option<i64> { *cache.borrow() });
-
-    if cached.is_some() {
-        return cached;
-    }
-
     let pe = ctx.module_output::<PE>()?;
     let data = ctx.scanned_data()?;
     let mut sum: u32 = 0;
@@ -175,10 +163,6 @@ fn calculate_checksum(ctx: &mut ScanContext) -> Option<i64> {
     sum &= 0xffff;
     sum += data.len() as u32;
 
-    CHECKSUM_CACHE.with(|cache| {
-        *cache.borrow_mut() = Some(sum.into());
-    });
-
     Some(sum.into())
 }
 
@@ -229,17 +213,11 @@ fn section_index_offset(ctx: &ScanContext, offset: i64) -> Option<i64> {
 /// The resulting hash string is consistently in lowercase.
 #[module_export]
 fn imphash(ctx: &mut ScanContext) -> Option<Lowercase<FixedLenString<32>>> {
-    let cached =
-        IMPHASH_CACHE.with(|cache| -> Option<Lowercase<FixedLenString<32>>> {
-            cache.borrow().as_deref().map(|s| {
-                Lowercase::<FixedLenString<32>>::from_slice(ctx, s.as_bytes())
-            })
-        });
-
-    if cached.is_some() {
-        return cached;
-    }
+    let digest = ctx.computed_value("pe.imphash", imphash_impl)?;
+    Some(Lowercase::<FixedLenString<32>>::new(digest))
+}
 
+fn imphash_impl(ctx: &mut ScanContext) -> Option<String> {
     let pe = ctx.module_output::<PE>()?;
 
     if !pe.is_pe() {
@@ -271,13 +249,7 @@ fn imphash(ctx: &mut ScanContext) -> Option<Lowercase<FixedLenString<32>>> {
         }
     }
 
-    let digest = format!("{:x}", md5_hash.finalize());
-
-    IMPHASH_CACHE.with(|cache| {
-        *cache.borrow_mut() = Some(digest.clone());
-    });
-
-    Some(Lowercase::<FixedLenString<32>>::new(digest))
+    Some(format!("{:x}", md5_hash.finalize()))
 }
 
 #[module_export(name = "rich_signature.toolid")]
@@ -682,6 +654,52 @@ fn valid_on(
     Some(timestamp >= not_before && timestamp <= not_after)
 }
 
+/// Returns true if the signature was valid at the time the file was signed.
+///
+/// The signing time is the one recorded by the earliest verified
+/// countersignature. The result is undefined if the signature doesn't have
+/// any verified countersignature with a signing time.
+#[module_export(method_of = "pe.Signature")]
+fn signed_while_valid(
+    ctx: &ScanContext,
+    signature: Rc<Struct>,
+) -> Option<bool> {
+    let sign_time = signature
+        .field_by_name("sign_time")
+        .unwrap()
+        .type_value
+        .try_as_integer()?;
+
+    valid_on(ctx, signature, sign_time)
+}
+
+/// Computes the entropy of the section's raw data.
+#[module_export(name = "entropy", method_of = "pe.Section")]
+fn section_entropy(ctx: &mut ScanContext, section: Rc<Struct>) -> Option<f64> {
+    ctx.computed_field(&section, "entropy", |ctx| {
+        let offset: usize = section
+            .field_by_name("raw_data_offset")
+            .unwrap()
+            .type_value
+            .try_as_integer()?
+            .try_into()
+            .ok()?;
+
+        let size: usize = section
+            .field_by_name("raw_data_size")
+            .unwrap()
+            .type_value
+            .try_as_integer()?
+            .try_into()
+            .ok()?;
+
+        let data = ctx.scanned_data()?;
+        let end = min(data.len(), offset.saturating_add(size));
+
+        Some(entropy(data.get(offset..end)?))
+    })
+}
+
 enum MatchCriteria<'a> {
     Any,
     Regexp(RegexpId),
@@ -29,6 +29,7 @@ use crate::modules::pe::authenticode::{
 };
 use crate::modules::pe::rva2off;
 use crate::modules::protos;
+use crate::modules::utils::limits::ParseLimits;
 
 type Error<'a> = nom::error::Error<&'a [u8]>;
 
@@ -232,10 +233,10 @@ impl<'a> PE<'a> {
             many_m_n(
                 // Parse at least one section.
                 1,
-                // The number of sections is capped to MAX_PE_SECTIONS.
+                // The number of sections is capped to `LIMITS.max_sections`.
                 usize::min(
                     pe_hdr.number_of_sections as usize,
-                    Self::MAX_PE_SECTIONS,
+                    Self::LIMITS.max_sections,
                 ),
                 // The section parser needs the string table for resolving
                 // some section names.
@@ -534,12 +535,15 @@ impl<'a> PE<'a> {
     const SIZE_OF_DIR_ENTRY: usize = 8;
     const SIZE_OF_SYMBOL: u32 = 18;
 
-    const MAX_PE_SECTIONS: usize = 96;
-    const MAX_PE_IMPORTS: usize = 16384;
-    const MAX_PE_EXPORTS: usize = 16384;
-    const MAX_PE_RESOURCES: usize = 65536;
+    /// The number of sections is capped to 96, like in YARA, and the
+    /// number of exports to the same number as imports.
+    const LIMITS: ParseLimits = ParseLimits {
+        max_sections: 96,
+        max_exports: 16_384,
+        ..ParseLimits::DEFAULT
+    };
+
     const MAX_DIR_ENTRIES: usize = 16;
-    const MAX_FUNC_NAME_LENGTH: usize = 1024;
     const MAX_DLL_NAME_LENGTH: usize = 512;
 
     fn parse_dos_header(input: &[u8]) -> IResult<&[u8], DOSHeader> {
@@ -1491,7 +1495,7 @@ impl<'a> PE<'a> {
                                 length: rsrc_entry.size,
                             });
 
-                            if resources.len() == Self::MAX_PE_RESOURCES {
+                            if resources.len() == Self::LIMITS.max_resources {
                                 return Some((resources_info, resources));
                             }
                         }
@@ -1803,7 +1807,7 @@ impl<'a> PE<'a> {
 
         // Parse import descriptors until finding one that is empty (filled
         // with null values), which indicates the end of the directory table;
-        // or until `MAX_PE_IMPORTS` is reached.
+        // or until `LIMITS.max_imports` is reached.
         let import_descriptors = iterator(
             input,
             verify(descriptor_parser, |d| {
@@ -1815,7 +1819,8 @@ impl<'a> PE<'a> {
 
         let mut num_imported_funcs = 0;
 
-        for mut descriptor in import_descriptors.take(Self::MAX_PE_IMPORTS) {
+        for mut descriptor in import_descriptors.take(Self::LIMITS.max_imports)
+        {
             // If the values in the descriptor are virtual addresses, convert
             // them to relative virtual addresses (RVAs) by subtracting the
             // image base. This only happens with 32-bits PE files, in 64-bits
@@ -1871,7 +1876,7 @@ impl<'a> PE<'a> {
             let mut funcs = Vec::new();
 
             for (i, mut thunk) in
-                &mut thunks.take(Self::MAX_PE_IMPORTS).enumerate()
+                &mut thunks.take(Self::LIMITS.max_imports).enumerate()
             {
                 // If the most significant bit is set, this is an import by
                 // ordinal. The most significant bit depends on whether this
@@ -1929,7 +1934,7 @@ impl<'a> PE<'a> {
                 imported_funcs.push((dll_name, funcs));
             }
 
-            if num_imported_funcs >= Self::MAX_PE_IMPORTS {
+            if num_imported_funcs >= Self::LIMITS.max_imports {
                 break;
             }
         }
@@ -2023,12 +2028,14 @@ impl<'a> PE<'a> {
                 // name
                 verify(
                     // As a sanity check, function names are limited to
-                    // MAX_FUNC_NAME_LENGTH bytes. Some corrupted files can
+                    // `LIMITS.max_name_length` bytes. Some corrupted files can
                     // produce larger names. Example:
                     // 0a88c56ab8abf7955138f5ecc81a635d8fca70865f5f763fd07d9fb3d1381585
-                    take_while_m_n(0, Self::MAX_FUNC_NAME_LENGTH, |c: u8| {
-                        c != 0_u8
-                    }),
+                    take_while_m_n(
+                        0,
+                        Self::LIMITS.max_name_length,
+                        |c: u8| c != 0_u8,
+                    ),
                     |name: &[u8]| {
                         !name.is_empty()
                             && name.iter().all(|c| c.is_ascii_graphic())
@@ -2064,11 +2071,13 @@ impl<'a> PE<'a> {
             });
         }
 
-        let num_exports =
-            min(exports.number_of_functions as usize, Self::MAX_PE_EXPORTS);
+        let num_exports = min(
+            exports.number_of_functions as usize,
+            Self::LIMITS.max_exports,
+        );
 
         let num_names =
-            min(exports.number_of_names as usize, Self::MAX_PE_EXPORTS);
+            min(exports.number_of_names as usize, Self::LIMITS.max_exports);
 
         // The IMAGE_EXPORT_DIRECTORY structure points to three arrays. The
         // only required array is the Export Address Table (EAT), which is an
@@ -2147,8 +2156,8 @@ impl<'a> PE<'a> {
                     })
             {
                 if let Some(name_rva) = names.get(idx) {
-                    f.name =
-                        self.str_at_rva(*name_rva, Self::MAX_FUNC_NAME_LENGTH);
+                    f.name = self
+                        .str_at_rva(*name_rva, Self::LIMITS.max_name_length);
                 }
             }
 
@@ -2159,7 +2168,7 @@ impl<'a> PE<'a> {
             // contains the DLL and function to which this export is forwarded.
             if exports_section.contains(&f.rva) {
                 f.forward_name =
-                    self.str_at_rva(f.rva, Self::MAX_FUNC_NAME_LENGTH);
+                    self.str_at_rva(f.rva, Self::LIMITS.max_name_length);
             } else {
                 f.offset = self.rva_to_offset(f.rva);
             }
@@ -28,6 +28,83 @@ fn rich_signature() {
     );
 }
 
+#[test]
+fn section_entropy() {
+    let pe = create_binary_from_zipped_ihex(
+        "src/modules/pe/tests/testdata/2775d97f8bdb3311ace960a42eee35dbec84b9d71a6abbacb26c14e83f5897e4.in.zip",
+    );
+
+    rule_true!(
+        r#"
+        import "pe"
+        import "math"
+        rule test {
+          condition:
+            for all section in pe.sections : (
+              section.entropy == math.entropy(
+                section.raw_data_offset, section.raw_data_size)
+            ) and
+            pe.sections[0].entropy > 5.0
+        }
+        "#,
+        &pe
+    );
+}
+
+#[test]
+fn sections() {
+    let pe = create_binary_from_zipped_ihex(
+        "src/modules/pe/tests/testdata/2775d97f8bdb3311ace960a42eee35dbec84b9d71a6abbacb26c14e83f5897e4.in.zip",
+    );
+
+    rule_true!(
+        r#"
+        import "pe"
+        rule test {
+          condition:
+            for 1 section in pe.sections : (
+              section.name == ".text" and
+              section.full_name == ".text" and
+              section.raw_data_size == 0x600 and
+              section.raw_data_offset == 0x400 and
+              section.virtual_size == 0x58b
+            )
+        }
+        "#,
+        &pe
+    );
+}
+
+#[test]
+fn patterns_at_module_offsets() {
+    let pe = create_binary_from_zipped_ihex(
+        "src/modules/pe/tests/testdata/2775d97f8bdb3311ace960a42eee35dbec84b9d71a6abbacb26c14e83f5897e4.in.zip",
+    );
+
+    // The entry point is at offset 0x400, at the start of the first section.
+    let ep_bytes: Vec<String> =
+        pe[0x400..0x408].iter().map(|b| format!("{b:02X}")).collect();
+
+    rule_true!(
+        format!(
+            r#"
+            import "pe"
+            rule test {{
+              strings:
+                $ep = {{ {} }}
+              condition:
+                $ep at pe.entry_point and
+                $ep in (pe.sections[0].raw_data_offset..pe.sections[0].raw_data_offset + 100) and
+                not $ep in (pe.sections[1].raw_data_offset..filesize)
+            }}
+            "#,
+            ep_bytes.join(" ")
+        )
+        .as_str(),
+        &pe
+    );
+}
+
 #[test]
 fn imports() {
     let pe = create_binary_from_zipped_ihex(
@@ -523,3 +600,48 @@ fn valid_on() {
         &pe
     );
 }
+
+#[test]
+fn certificate_extensions() {
+    let pe = create_binary_from_zipped_ihex(
+        "src/modules/pe/tests/testdata/00a1067fc96eb2c1d440bb5b44b32f43b9900fdd3a65c985d65a63b8f1535ef5.in.zip",
+    );
+
+    rule_true!(
+        r#"
+        import "pe"
+        rule test {
+          condition:
+            pe.signatures[0].certificates[0].extended_key_usage == pe.EKU_TIME_STAMPING and
+            pe.signatures[0].certificates[2].extended_key_usage & pe.EKU_CODE_SIGNING != 0 and
+            pe.signatures[0].certificates[2].key_usage == pe.KEY_USAGE_DIGITAL_SIGNATURE and
+            pe.signatures[0].certificates[2].extended_key_usage_oids[0] == "1.3.6.1.5.5.7.3.3" and
+            pe.signatures[0].certificates[3].subject_alternative_names[0] == "DirName:/CN=SymantecPKI-1-567" and
+            not defined pe.signatures[0].certificates[2].subject_alternative_names[0]
+        }
+        "#,
+        &pe
+    );
+}
+
+#[test]
+fn signed_while_valid() {
+    let pe = create_binary_from_zipped_ihex(
+        "src/modules/pe/tests/testdata/9d8196aa4afce055daa9edb3130efa9e3ddb8b19f6d664e95f8d0e103866ea22.in.zip",
+    );
+
+    rule_true!(
+        r#"
+        import "pe"
+        rule test {
+          condition:
+            pe.signatures[0].sign_time == 1700015367 and
+            not pe.signatures[0].signed_while_valid() and
+            pe.signatures[1].signed_while_valid() and
+            not defined pe.signatures[2].sign_time and
+            not defined pe.signatures[2].signed_while_valid()
+        }
+        "#,
+        &pe
+    );
+}
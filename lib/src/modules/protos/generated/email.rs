@@ -0,0 +1,657 @@
+// This file is generated by rust-protobuf 3.7.2. Do not edit
+// .proto file is parsed by pure
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `email.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_7_2;
+
+// @@protoc_insertion_point(message:email.Email)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Email {
+    // message fields
+    // @@protoc_insertion_point(field:email.Email.is_email)
+    pub is_email: ::std::option::Option<bool>,
+    // @@protoc_insertion_point(field:email.Email.headers)
+    pub headers: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    // @@protoc_insertion_point(field:email.Email.content_type)
+    pub content_type: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:email.Email.number_of_parts)
+    pub number_of_parts: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:email.Email.attachments)
+    pub attachments: ::std::vec::Vec<Attachment>,
+    // @@protoc_insertion_point(field:email.Email.number_of_urls)
+    pub number_of_urls: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:email.Email.urls)
+    pub urls: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:email.Email.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Email {
+    fn default() -> &'a Email {
+        <Email as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Email {
+    pub fn new() -> Email {
+        ::std::default::Default::default()
+    }
+
+    // optional bool is_email = 1;
+
+    pub fn is_email(&self) -> bool {
+        self.is_email.unwrap_or(false)
+    }
+
+    pub fn clear_is_email(&mut self) {
+        self.is_email = ::std::option::Option::None;
+    }
+
+    pub fn has_is_email(&self) -> bool {
+        self.is_email.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_email(&mut self, v: bool) {
+        self.is_email = ::std::option::Option::Some(v);
+    }
+
+    // optional string content_type = 3;
+
+    pub fn content_type(&self) -> &str {
+        match self.content_type.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_content_type(&mut self) {
+        self.content_type = ::std::option::Option::None;
+    }
+
+    pub fn has_content_type(&self) -> bool {
+        self.content_type.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_content_type(&mut self, v: ::std::string::String) {
+        self.content_type = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_content_type(&mut self) -> &mut ::std::string::String {
+        if self.content_type.is_none() {
+            self.content_type = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.content_type.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_content_type(&mut self) -> ::std::string::String {
+        self.content_type.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional uint64 number_of_parts = 4;
+
+    pub fn number_of_parts(&self) -> u64 {
+        self.number_of_parts.unwrap_or(0)
+    }
+
+    pub fn clear_number_of_parts(&mut self) {
+        self.number_of_parts = ::std::option::Option::None;
+    }
+
+    pub fn has_number_of_parts(&self) -> bool {
+        self.number_of_parts.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_number_of_parts(&mut self, v: u64) {
+        self.number_of_parts = ::std::option::Option::Some(v);
+    }
+
+    // optional uint64 number_of_urls = 6;
+
+    pub fn number_of_urls(&self) -> u64 {
+        self.number_of_urls.unwrap_or(0)
+    }
+
+    pub fn clear_number_of_urls(&mut self) {
+        self.number_of_urls = ::std::option::Option::None;
+    }
+
+    pub fn has_number_of_urls(&self) -> bool {
+        self.number_of_urls.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_number_of_urls(&mut self, v: u64) {
+        self.number_of_urls = ::std::option::Option::Some(v);
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(7);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "is_email",
+            |m: &Email| { &m.is_email },
+            |m: &mut Email| { &mut m.is_email },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor_new::<_, _>(
+            "headers",
+            |m: &Email| { &m.headers },
+            |m: &mut Email| { &mut m.headers },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "content_type",
+            |m: &Email| { &m.content_type },
+            |m: &mut Email| { &mut m.content_type },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "number_of_parts",
+            |m: &Email| { &m.number_of_parts },
+            |m: &mut Email| { &mut m.number_of_parts },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "attachments",
+            |m: &Email| { &m.attachments },
+            |m: &mut Email| { &mut m.attachments },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "number_of_urls",
+            |m: &Email| { &m.number_of_urls },
+            |m: &mut Email| { &mut m.number_of_urls },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "urls",
+            |m: &Email| { &m.urls },
+            |m: &mut Email| { &mut m.urls },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Email>(
+            "Email",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Email {
+    const NAME: &'static str = "Email";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.is_email = ::std::option::Option::Some(is.read_bool()?);
+                },
+                18 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            10 => key = is.read_string()?,
+                            18 => value = is.read_string()?,
+                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.headers.insert(key, value);
+                },
+                26 => {
+                    self.content_type = ::std::option::Option::Some(is.read_string()?);
+                },
+                32 => {
+                    self.number_of_parts = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                42 => {
+                    self.attachments.push(is.read_message()?);
+                },
+                48 => {
+                    self.number_of_urls = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                58 => {
+                    self.urls.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.is_email {
+            my_size += 1 + 1;
+        }
+        for (k, v) in &self.headers {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
+        if let Some(v) = self.content_type.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        if let Some(v) = self.number_of_parts {
+            my_size += ::protobuf::rt::uint64_size(4, v);
+        }
+        for value in &self.attachments {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if let Some(v) = self.number_of_urls {
+            my_size += ::protobuf::rt::uint64_size(6, v);
+        }
+        for value in &self.urls {
+            my_size += ::protobuf::rt::string_size(7, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.is_email {
+            os.write_bool(1, v)?;
+        }
+        for (k, v) in &self.headers {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            os.write_raw_varint32(18)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_string(1, &k)?;
+            os.write_string(2, &v)?;
+        };
+        if let Some(v) = self.content_type.as_ref() {
+            os.write_string(3, v)?;
+        }
+        if let Some(v) = self.number_of_parts {
+            os.write_uint64(4, v)?;
+        }
+        for v in &self.attachments {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        };
+        if let Some(v) = self.number_of_urls {
+            os.write_uint64(6, v)?;
+        }
+        for v in &self.urls {
+            os.write_string(7, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Email {
+        Email::new()
+    }
+
+    fn clear(&mut self) {
+        self.is_email = ::std::option::Option::None;
+        self.headers.clear();
+        self.content_type = ::std::option::Option::None;
+        self.number_of_parts = ::std::option::Option::None;
+        self.attachments.clear();
+        self.number_of_urls = ::std::option::Option::None;
+        self.urls.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Email {
+        static instance: ::protobuf::rt::Lazy<Email> = ::protobuf::rt::Lazy::new();
+        instance.get(Email::new)
+    }
+}
+
+impl ::protobuf::MessageFull for Email {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Email").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Email {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Email {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:email.Attachment)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Attachment {
+    // message fields
+    // @@protoc_insertion_point(field:email.Attachment.name)
+    pub name: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:email.Attachment.content_type)
+    pub content_type: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:email.Attachment.size)
+    pub size: ::std::option::Option<u64>,
+    // special fields
+    // @@protoc_insertion_point(special_field:email.Attachment.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Attachment {
+    fn default() -> &'a Attachment {
+        <Attachment as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Attachment {
+    pub fn new() -> Attachment {
+        ::std::default::Default::default()
+    }
+
+    // optional string name = 1;
+
+    pub fn name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_name(&mut self) {
+        self.name = ::std::option::Option::None;
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional string content_type = 2;
+
+    pub fn content_type(&self) -> &str {
+        match self.content_type.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_content_type(&mut self) {
+        self.content_type = ::std::option::Option::None;
+    }
+
+    pub fn has_content_type(&self) -> bool {
+        self.content_type.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_content_type(&mut self, v: ::std::string::String) {
+        self.content_type = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_content_type(&mut self) -> &mut ::std::string::String {
+        if self.content_type.is_none() {
+            self.content_type = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.content_type.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_content_type(&mut self) -> ::std::string::String {
+        self.content_type.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional uint64 size = 3;
+
+    pub fn size(&self) -> u64 {
+        self.size.unwrap_or(0)
+    }
+
+    pub fn clear_size(&mut self) {
+        self.size = ::std::option::Option::None;
+    }
+
+    pub fn has_size(&self) -> bool {
+        self.size.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_size(&mut self, v: u64) {
+        self.size = ::std::option::Option::Some(v);
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "name",
+            |m: &Attachment| { &m.name },
+            |m: &mut Attachment| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "content_type",
+            |m: &Attachment| { &m.content_type },
+            |m: &mut Attachment| { &mut m.content_type },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "size",
+            |m: &Attachment| { &m.size },
+            |m: &mut Attachment| { &mut m.size },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Attachment>(
+            "Attachment",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Attachment {
+    const NAME: &'static str = "Attachment";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = ::std::option::Option::Some(is.read_string()?);
+                },
+                18 => {
+                    self.content_type = ::std::option::Option::Some(is.read_string()?);
+                },
+                24 => {
+                    self.size = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.content_type.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(v) = self.size {
+            my_size += ::protobuf::rt::uint64_size(3, v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.name.as_ref() {
+            os.write_string(1, v)?;
+        }
+        if let Some(v) = self.content_type.as_ref() {
+            os.write_string(2, v)?;
+        }
+        if let Some(v) = self.size {
+            os.write_uint64(3, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Attachment {
+        Attachment::new()
+    }
+
+    fn clear(&mut self) {
+        self.name = ::std::option::Option::None;
+        self.content_type = ::std::option::Option::None;
+        self.size = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Attachment {
+        static instance: Attachment = Attachment {
+            name: ::std::option::Option::None,
+            content_type: ::std::option::Option::None,
+            size: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Attachment {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Attachment").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Attachment {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Attachment {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0bemail.proto\x12\x05email\x1a\nyara.proto\"\xcd\x02\n\x05Email\x12\
+    \x19\n\x08is_email\x18\x01\x20\x01(\x08R\x07isEmail\x123\n\x07headers\
+    \x18\x02\x20\x03(\x0b2\x19.email.Email.HeadersEntryR\x07headers\x12!\n\
+    \x0ccontent_type\x18\x03\x20\x01(\tR\x0bcontentType\x12&\n\x0fnumber_of_\
+    parts\x18\x04\x20\x01(\x04R\rnumberOfParts\x123\n\x0battachments\x18\x05\
+    \x20\x03(\x0b2\x11.email.AttachmentR\x0battachments\x12$\n\x0enumber_of_\
+    urls\x18\x06\x20\x01(\x04R\x0cnumberOfUrls\x12\x12\n\x04urls\x18\x07\x20\
+    \x03(\tR\x04urls\x1a:\n\x0cHeadersEntry\x12\x10\n\x03key\x18\x01\x20\x01\
+    (\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x028\x01\"W\
+    \n\nAttachment\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12!\n\x0cc\
+    ontent_type\x18\x02\x20\x01(\tR\x0bcontentType\x12\x12\n\x04size\x18\x03\
+    \x20\x01(\x04R\x04sizeB-\xfa\x92\x19)\n\x05email\x12\x0bemail.Email\x1a\
+    \x05email\"\x0cemail-moduleb\x06proto2\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobuf::rt::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobuf::rt::Lazy<::protobuf::reflect::GeneratedFileDescriptor> = ::protobuf::rt::Lazy::new();
+    static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(1);
+            deps.push(super::yara::file_descriptor().clone());
+            let mut messages = ::std::vec::Vec::with_capacity(2);
+            messages.push(Email::generated_message_descriptor_data());
+            messages.push(Attachment::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(0);
+            ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobuf::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
@@ -0,0 +1,1168 @@
+// This file is generated by rust-protobuf 3.7.2. Do not edit
+// .proto file is parsed by pure
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `iso9660.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_7_2;
+
+// @@protoc_insertion_point(message:iso9660.Iso9660)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Iso9660 {
+    // message fields
+    // @@protoc_insertion_point(field:iso9660.Iso9660.is_iso9660)
+    pub is_iso9660: ::std::option::Option<bool>,
+    // @@protoc_insertion_point(field:iso9660.Iso9660.is_udf)
+    pub is_udf: ::std::option::Option<bool>,
+    // @@protoc_insertion_point(field:iso9660.Iso9660.is_bootable)
+    pub is_bootable: ::std::option::Option<bool>,
+    // @@protoc_insertion_point(field:iso9660.Iso9660.has_joliet)
+    pub has_joliet: ::std::option::Option<bool>,
+    // @@protoc_insertion_point(field:iso9660.Iso9660.system_identifier)
+    pub system_identifier: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:iso9660.Iso9660.volume_identifier)
+    pub volume_identifier: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:iso9660.Iso9660.volume_set_identifier)
+    pub volume_set_identifier: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:iso9660.Iso9660.publisher_identifier)
+    pub publisher_identifier: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:iso9660.Iso9660.data_preparer_identifier)
+    pub data_preparer_identifier: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:iso9660.Iso9660.application_identifier)
+    pub application_identifier: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:iso9660.Iso9660.volume_space_size)
+    pub volume_space_size: ::std::option::Option<u32>,
+    // @@protoc_insertion_point(field:iso9660.Iso9660.logical_block_size)
+    pub logical_block_size: ::std::option::Option<u32>,
+    // @@protoc_insertion_point(field:iso9660.Iso9660.creation_time)
+    pub creation_time: ::std::option::Option<i64>,
+    // @@protoc_insertion_point(field:iso9660.Iso9660.modification_time)
+    pub modification_time: ::std::option::Option<i64>,
+    // @@protoc_insertion_point(field:iso9660.Iso9660.number_of_files)
+    pub number_of_files: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:iso9660.Iso9660.files)
+    pub files: ::std::vec::Vec<File>,
+    // special fields
+    // @@protoc_insertion_point(special_field:iso9660.Iso9660.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Iso9660 {
+    fn default() -> &'a Iso9660 {
+        <Iso9660 as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Iso9660 {
+    pub fn new() -> Iso9660 {
+        ::std::default::Default::default()
+    }
+
+    // optional bool is_iso9660 = 1;
+
+    pub fn is_iso9660(&self) -> bool {
+        self.is_iso9660.unwrap_or(false)
+    }
+
+    pub fn clear_is_iso9660(&mut self) {
+        self.is_iso9660 = ::std::option::Option::None;
+    }
+
+    pub fn has_is_iso9660(&self) -> bool {
+        self.is_iso9660.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_iso9660(&mut self, v: bool) {
+        self.is_iso9660 = ::std::option::Option::Some(v);
+    }
+
+    // optional bool is_udf = 2;
+
+    pub fn is_udf(&self) -> bool {
+        self.is_udf.unwrap_or(false)
+    }
+
+    pub fn clear_is_udf(&mut self) {
+        self.is_udf = ::std::option::Option::None;
+    }
+
+    pub fn has_is_udf(&self) -> bool {
+        self.is_udf.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_udf(&mut self, v: bool) {
+        self.is_udf = ::std::option::Option::Some(v);
+    }
+
+    // optional bool is_bootable = 3;
+
+    pub fn is_bootable(&self) -> bool {
+        self.is_bootable.unwrap_or(false)
+    }
+
+    pub fn clear_is_bootable(&mut self) {
+        self.is_bootable = ::std::option::Option::None;
+    }
+
+    pub fn has_is_bootable(&self) -> bool {
+        self.is_bootable.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_bootable(&mut self, v: bool) {
+        self.is_bootable = ::std::option::Option::Some(v);
+    }
+
+    // optional bool has_joliet = 4;
+
+    pub fn has_joliet(&self) -> bool {
+        self.has_joliet.unwrap_or(false)
+    }
+
+    pub fn clear_has_joliet(&mut self) {
+        self.has_joliet = ::std::option::Option::None;
+    }
+
+    pub fn has_has_joliet(&self) -> bool {
+        self.has_joliet.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_has_joliet(&mut self, v: bool) {
+        self.has_joliet = ::std::option::Option::Some(v);
+    }
+
+    // optional string system_identifier = 5;
+
+    pub fn system_identifier(&self) -> &str {
+        match self.system_identifier.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_system_identifier(&mut self) {
+        self.system_identifier = ::std::option::Option::None;
+    }
+
+    pub fn has_system_identifier(&self) -> bool {
+        self.system_identifier.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_system_identifier(&mut self, v: ::std::string::String) {
+        self.system_identifier = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_system_identifier(&mut self) -> &mut ::std::string::String {
+        if self.system_identifier.is_none() {
+            self.system_identifier = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.system_identifier.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_system_identifier(&mut self) -> ::std::string::String {
+        self.system_identifier.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional string volume_identifier = 6;
+
+    pub fn volume_identifier(&self) -> &str {
+        match self.volume_identifier.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_volume_identifier(&mut self) {
+        self.volume_identifier = ::std::option::Option::None;
+    }
+
+    pub fn has_volume_identifier(&self) -> bool {
+        self.volume_identifier.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_volume_identifier(&mut self, v: ::std::string::String) {
+        self.volume_identifier = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_volume_identifier(&mut self) -> &mut ::std::string::String {
+        if self.volume_identifier.is_none() {
+            self.volume_identifier = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.volume_identifier.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_volume_identifier(&mut self) -> ::std::string::String {
+        self.volume_identifier.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional string volume_set_identifier = 7;
+
+    pub fn volume_set_identifier(&self) -> &str {
+        match self.volume_set_identifier.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_volume_set_identifier(&mut self) {
+        self.volume_set_identifier = ::std::option::Option::None;
+    }
+
+    pub fn has_volume_set_identifier(&self) -> bool {
+        self.volume_set_identifier.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_volume_set_identifier(&mut self, v: ::std::string::String) {
+        self.volume_set_identifier = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_volume_set_identifier(&mut self) -> &mut ::std::string::String {
+        if self.volume_set_identifier.is_none() {
+            self.volume_set_identifier = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.volume_set_identifier.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_volume_set_identifier(&mut self) -> ::std::string::String {
+        self.volume_set_identifier.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional string publisher_identifier = 8;
+
+    pub fn publisher_identifier(&self) -> &str {
+        match self.publisher_identifier.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_publisher_identifier(&mut self) {
+        self.publisher_identifier = ::std::option::Option::None;
+    }
+
+    pub fn has_publisher_identifier(&self) -> bool {
+        self.publisher_identifier.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_publisher_identifier(&mut self, v: ::std::string::String) {
+        self.publisher_identifier = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_publisher_identifier(&mut self) -> &mut ::std::string::String {
+        if self.publisher_identifier.is_none() {
+            self.publisher_identifier = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.publisher_identifier.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_publisher_identifier(&mut self) -> ::std::string::String {
+        self.publisher_identifier.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional string data_preparer_identifier = 9;
+
+    pub fn data_preparer_identifier(&self) -> &str {
+        match self.data_preparer_identifier.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_data_preparer_identifier(&mut self) {
+        self.data_preparer_identifier = ::std::option::Option::None;
+    }
+
+    pub fn has_data_preparer_identifier(&self) -> bool {
+        self.data_preparer_identifier.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data_preparer_identifier(&mut self, v: ::std::string::String) {
+        self.data_preparer_identifier = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data_preparer_identifier(&mut self) -> &mut ::std::string::String {
+        if self.data_preparer_identifier.is_none() {
+            self.data_preparer_identifier = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.data_preparer_identifier.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_data_preparer_identifier(&mut self) -> ::std::string::String {
+        self.data_preparer_identifier.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional string application_identifier = 10;
+
+    pub fn application_identifier(&self) -> &str {
+        match self.application_identifier.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_application_identifier(&mut self) {
+        self.application_identifier = ::std::option::Option::None;
+    }
+
+    pub fn has_application_identifier(&self) -> bool {
+        self.application_identifier.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_application_identifier(&mut self, v: ::std::string::String) {
+        self.application_identifier = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_application_identifier(&mut self) -> &mut ::std::string::String {
+        if self.application_identifier.is_none() {
+            self.application_identifier = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.application_identifier.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_application_identifier(&mut self) -> ::std::string::String {
+        self.application_identifier.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional uint32 volume_space_size = 11;
+
+    pub fn volume_space_size(&self) -> u32 {
+        self.volume_space_size.unwrap_or(0)
+    }
+
+    pub fn clear_volume_space_size(&mut self) {
+        self.volume_space_size = ::std::option::Option::None;
+    }
+
+    pub fn has_volume_space_size(&self) -> bool {
+        self.volume_space_size.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_volume_space_size(&mut self, v: u32) {
+        self.volume_space_size = ::std::option::Option::Some(v);
+    }
+
+    // optional uint32 logical_block_size = 12;
+
+    pub fn logical_block_size(&self) -> u32 {
+        self.logical_block_size.unwrap_or(0)
+    }
+
+    pub fn clear_logical_block_size(&mut self) {
+        self.logical_block_size = ::std::option::Option::None;
+    }
+
+    pub fn has_logical_block_size(&self) -> bool {
+        self.logical_block_size.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_logical_block_size(&mut self, v: u32) {
+        self.logical_block_size = ::std::option::Option::Some(v);
+    }
+
+    // optional int64 creation_time = 13;
+
+    pub fn creation_time(&self) -> i64 {
+        self.creation_time.unwrap_or(0)
+    }
+
+    pub fn clear_creation_time(&mut self) {
+        self.creation_time = ::std::option::Option::None;
+    }
+
+    pub fn has_creation_time(&self) -> bool {
+        self.creation_time.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_creation_time(&mut self, v: i64) {
+        self.creation_time = ::std::option::Option::Some(v);
+    }
+
+    // optional int64 modification_time = 14;
+
+    pub fn modification_time(&self) -> i64 {
+        self.modification_time.unwrap_or(0)
+    }
+
+    pub fn clear_modification_time(&mut self) {
+        self.modification_time = ::std::option::Option::None;
+    }
+
+    pub fn has_modification_time(&self) -> bool {
+        self.modification_time.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_modification_time(&mut self, v: i64) {
+        self.modification_time = ::std::option::Option::Some(v);
+    }
+
+    // optional uint64 number_of_files = 15;
+
+    pub fn number_of_files(&self) -> u64 {
+        self.number_of_files.unwrap_or(0)
+    }
+
+    pub fn clear_number_of_files(&mut self) {
+        self.number_of_files = ::std::option::Option::None;
+    }
+
+    pub fn has_number_of_files(&self) -> bool {
+        self.number_of_files.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_number_of_files(&mut self, v: u64) {
+        self.number_of_files = ::std::option::Option::Some(v);
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(16);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "is_iso9660",
+            |m: &Iso9660| { &m.is_iso9660 },
+            |m: &mut Iso9660| { &mut m.is_iso9660 },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "is_udf",
+            |m: &Iso9660| { &m.is_udf },
+            |m: &mut Iso9660| { &mut m.is_udf },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "is_bootable",
+            |m: &Iso9660| { &m.is_bootable },
+            |m: &mut Iso9660| { &mut m.is_bootable },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "has_joliet",
+            |m: &Iso9660| { &m.has_joliet },
+            |m: &mut Iso9660| { &mut m.has_joliet },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "system_identifier",
+            |m: &Iso9660| { &m.system_identifier },
+            |m: &mut Iso9660| { &mut m.system_identifier },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "volume_identifier",
+            |m: &Iso9660| { &m.volume_identifier },
+            |m: &mut Iso9660| { &mut m.volume_identifier },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "volume_set_identifier",
+            |m: &Iso9660| { &m.volume_set_identifier },
+            |m: &mut Iso9660| { &mut m.volume_set_identifier },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "publisher_identifier",
+            |m: &Iso9660| { &m.publisher_identifier },
+            |m: &mut Iso9660| { &mut m.publisher_identifier },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "data_preparer_identifier",
+            |m: &Iso9660| { &m.data_preparer_identifier },
+            |m: &mut Iso9660| { &mut m.data_preparer_identifier },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "application_identifier",
+            |m: &Iso9660| { &m.application_identifier },
+            |m: &mut Iso9660| { &mut m.application_identifier },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "volume_space_size",
+            |m: &Iso9660| { &m.volume_space_size },
+            |m: &mut Iso9660| { &mut m.volume_space_size },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "logical_block_size",
+            |m: &Iso9660| { &m.logical_block_size },
+            |m: &mut Iso9660| { &mut m.logical_block_size },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "creation_time",
+            |m: &Iso9660| { &m.creation_time },
+            |m: &mut Iso9660| { &mut m.creation_time },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "modification_time",
+            |m: &Iso9660| { &m.modification_time },
+            |m: &mut Iso9660| { &mut m.modification_time },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "number_of_files",
+            |m: &Iso9660| { &m.number_of_files },
+            |m: &mut Iso9660| { &mut m.number_of_files },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "files",
+            |m: &Iso9660| { &m.files },
+            |m: &mut Iso9660| { &mut m.files },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Iso9660>(
+            "Iso9660",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Iso9660 {
+    const NAME: &'static str = "Iso9660";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.is_iso9660 = ::std::option::Option::Some(is.read_bool()?);
+                },
+                16 => {
+                    self.is_udf = ::std::option::Option::Some(is.read_bool()?);
+                },
+                24 => {
+                    self.is_bootable = ::std::option::Option::Some(is.read_bool()?);
+                },
+                32 => {
+                    self.has_joliet = ::std::option::Option::Some(is.read_bool()?);
+                },
+                42 => {
+                    self.system_identifier = ::std::option::Option::Some(is.read_string()?);
+                },
+                50 => {
+                    self.volume_identifier = ::std::option::Option::Some(is.read_string()?);
+                },
+                58 => {
+                    self.volume_set_identifier = ::std::option::Option::Some(is.read_string()?);
+                },
+                66 => {
+                    self.publisher_identifier = ::std::option::Option::Some(is.read_string()?);
+                },
+                74 => {
+                    self.data_preparer_identifier = ::std::option::Option::Some(is.read_string()?);
+                },
+                82 => {
+                    self.application_identifier = ::std::option::Option::Some(is.read_string()?);
+                },
+                88 => {
+                    self.volume_space_size = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                96 => {
+                    self.logical_block_size = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                104 => {
+                    self.creation_time = ::std::option::Option::Some(is.read_int64()?);
+                },
+                112 => {
+                    self.modification_time = ::std::option::Option::Some(is.read_int64()?);
+                },
+                120 => {
+                    self.number_of_files = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                130 => {
+                    self.files.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.is_iso9660 {
+            my_size += 1 + 1;
+        }
+        if let Some(v) = self.is_udf {
+            my_size += 1 + 1;
+        }
+        if let Some(v) = self.is_bootable {
+            my_size += 1 + 1;
+        }
+        if let Some(v) = self.has_joliet {
+            my_size += 1 + 1;
+        }
+        if let Some(v) = self.system_identifier.as_ref() {
+            my_size += ::protobuf::rt::string_size(5, &v);
+        }
+        if let Some(v) = self.volume_identifier.as_ref() {
+            my_size += ::protobuf::rt::string_size(6, &v);
+        }
+        if let Some(v) = self.volume_set_identifier.as_ref() {
+            my_size += ::protobuf::rt::string_size(7, &v);
+        }
+        if let Some(v) = self.publisher_identifier.as_ref() {
+            my_size += ::protobuf::rt::string_size(8, &v);
+        }
+        if let Some(v) = self.data_preparer_identifier.as_ref() {
+            my_size += ::protobuf::rt::string_size(9, &v);
+        }
+        if let Some(v) = self.application_identifier.as_ref() {
+            my_size += ::protobuf::rt::string_size(10, &v);
+        }
+        if let Some(v) = self.volume_space_size {
+            my_size += ::protobuf::rt::uint32_size(11, v);
+        }
+        if let Some(v) = self.logical_block_size {
+            my_size += ::protobuf::rt::uint32_size(12, v);
+        }
+        if let Some(v) = self.creation_time {
+            my_size += ::protobuf::rt::int64_size(13, v);
+        }
+        if let Some(v) = self.modification_time {
+            my_size += ::protobuf::rt::int64_size(14, v);
+        }
+        if let Some(v) = self.number_of_files {
+            my_size += ::protobuf::rt::uint64_size(15, v);
+        }
+        for value in &self.files {
+            let len = value.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.is_iso9660 {
+            os.write_bool(1, v)?;
+        }
+        if let Some(v) = self.is_udf {
+            os.write_bool(2, v)?;
+        }
+        if let Some(v) = self.is_bootable {
+            os.write_bool(3, v)?;
+        }
+        if let Some(v) = self.has_joliet {
+            os.write_bool(4, v)?;
+        }
+        if let Some(v) = self.system_identifier.as_ref() {
+            os.write_string(5, v)?;
+        }
+        if let Some(v) = self.volume_identifier.as_ref() {
+            os.write_string(6, v)?;
+        }
+        if let Some(v) = self.volume_set_identifier.as_ref() {
+            os.write_string(7, v)?;
+        }
+        if let Some(v) = self.publisher_identifier.as_ref() {
+            os.write_string(8, v)?;
+        }
+        if let Some(v) = self.data_preparer_identifier.as_ref() {
+            os.write_string(9, v)?;
+        }
+        if let Some(v) = self.application_identifier.as_ref() {
+            os.write_string(10, v)?;
+        }
+        if let Some(v) = self.volume_space_size {
+            os.write_uint32(11, v)?;
+        }
+        if let Some(v) = self.logical_block_size {
+            os.write_uint32(12, v)?;
+        }
+        if let Some(v) = self.creation_time {
+            os.write_int64(13, v)?;
+        }
+        if let Some(v) = self.modification_time {
+            os.write_int64(14, v)?;
+        }
+        if let Some(v) = self.number_of_files {
+            os.write_uint64(15, v)?;
+        }
+        for v in &self.files {
+            ::protobuf::rt::write_message_field_with_cached_size(16, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Iso9660 {
+        Iso9660::new()
+    }
+
+    fn clear(&mut self) {
+        self.is_iso9660 = ::std::option::Option::None;
+        self.is_udf = ::std::option::Option::None;
+        self.is_bootable = ::std::option::Option::None;
+        self.has_joliet = ::std::option::Option::None;
+        self.system_identifier = ::std::option::Option::None;
+        self.volume_identifier = ::std::option::Option::None;
+        self.volume_set_identifier = ::std::option::Option::None;
+        self.publisher_identifier = ::std::option::Option::None;
+        self.data_preparer_identifier = ::std::option::Option::None;
+        self.application_identifier = ::std::option::Option::None;
+        self.volume_space_size = ::std::option::Option::None;
+        self.logical_block_size = ::std::option::Option::None;
+        self.creation_time = ::std::option::Option::None;
+        self.modification_time = ::std::option::Option::None;
+        self.number_of_files = ::std::option::Option::None;
+        self.files.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Iso9660 {
+        static instance: Iso9660 = Iso9660 {
+            is_iso9660: ::std::option::Option::None,
+            is_udf: ::std::option::Option::None,
+            is_bootable: ::std::option::Option::None,
+            has_joliet: ::std::option::Option::None,
+            system_identifier: ::std::option::Option::None,
+            volume_identifier: ::std::option::Option::None,
+            volume_set_identifier: ::std::option::Option::None,
+            publisher_identifier: ::std::option::Option::None,
+            data_preparer_identifier: ::std::option::Option::None,
+            application_identifier: ::std::option::Option::None,
+            volume_space_size: ::std::option::Option::None,
+            logical_block_size: ::std::option::Option::None,
+            creation_time: ::std::option::Option::None,
+            modification_time: ::std::option::Option::None,
+            number_of_files: ::std::option::Option::None,
+            files: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Iso9660 {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Iso9660").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Iso9660 {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Iso9660 {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:iso9660.File)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct File {
+    // message fields
+    // @@protoc_insertion_point(field:iso9660.File.name)
+    pub name: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:iso9660.File.size)
+    pub size: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:iso9660.File.recording_time)
+    pub recording_time: ::std::option::Option<i64>,
+    // @@protoc_insertion_point(field:iso9660.File.is_directory)
+    pub is_directory: ::std::option::Option<bool>,
+    // @@protoc_insertion_point(field:iso9660.File.is_hidden)
+    pub is_hidden: ::std::option::Option<bool>,
+    // special fields
+    // @@protoc_insertion_point(special_field:iso9660.File.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a File {
+    fn default() -> &'a File {
+        <File as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl File {
+    pub fn new() -> File {
+        ::std::default::Default::default()
+    }
+
+    // optional string name = 1;
+
+    pub fn name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_name(&mut self) {
+        self.name = ::std::option::Option::None;
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional uint64 size = 2;
+
+    pub fn size(&self) -> u64 {
+        self.size.unwrap_or(0)
+    }
+
+    pub fn clear_size(&mut self) {
+        self.size = ::std::option::Option::None;
+    }
+
+    pub fn has_size(&self) -> bool {
+        self.size.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_size(&mut self, v: u64) {
+        self.size = ::std::option::Option::Some(v);
+    }
+
+    // optional int64 recording_time = 3;
+
+    pub fn recording_time(&self) -> i64 {
+        self.recording_time.unwrap_or(0)
+    }
+
+    pub fn clear_recording_time(&mut self) {
+        self.recording_time = ::std::option::Option::None;
+    }
+
+    pub fn has_recording_time(&self) -> bool {
+        self.recording_time.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_recording_time(&mut self, v: i64) {
+        self.recording_time = ::std::option::Option::Some(v);
+    }
+
+    // optional bool is_directory = 4;
+
+    pub fn is_directory(&self) -> bool {
+        self.is_directory.unwrap_or(false)
+    }
+
+    pub fn clear_is_directory(&mut self) {
+        self.is_directory = ::std::option::Option::None;
+    }
+
+    pub fn has_is_directory(&self) -> bool {
+        self.is_directory.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_directory(&mut self, v: bool) {
+        self.is_directory = ::std::option::Option::Some(v);
+    }
+
+    // optional bool is_hidden = 5;
+
+    pub fn is_hidden(&self) -> bool {
+        self.is_hidden.unwrap_or(false)
+    }
+
+    pub fn clear_is_hidden(&mut self) {
+        self.is_hidden = ::std::option::Option::None;
+    }
+
+    pub fn has_is_hidden(&self) -> bool {
+        self.is_hidden.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_hidden(&mut self, v: bool) {
+        self.is_hidden = ::std::option::Option::Some(v);
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "name",
+            |m: &File| { &m.name },
+            |m: &mut File| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "size",
+            |m: &File| { &m.size },
+            |m: &mut File| { &mut m.size },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "recording_time",
+            |m: &File| { &m.recording_time },
+            |m: &mut File| { &mut m.recording_time },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "is_directory",
+            |m: &File| { &m.is_directory },
+            |m: &mut File| { &mut m.is_directory },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "is_hidden",
+            |m: &File| { &m.is_hidden },
+            |m: &mut File| { &mut m.is_hidden },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<File>(
+            "File",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for File {
+    const NAME: &'static str = "File";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = ::std::option::Option::Some(is.read_string()?);
+                },
+                16 => {
+                    self.size = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                24 => {
+                    self.recording_time = ::std::option::Option::Some(is.read_int64()?);
+                },
+                32 => {
+                    self.is_directory = ::std::option::Option::Some(is.read_bool()?);
+                },
+                40 => {
+                    self.is_hidden = ::std::option::Option::Some(is.read_bool()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.size {
+            my_size += ::protobuf::rt::uint64_size(2, v);
+        }
+        if let Some(v) = self.recording_time {
+            my_size += ::protobuf::rt::int64_size(3, v);
+        }
+        if let Some(v) = self.is_directory {
+            my_size += 1 + 1;
+        }
+        if let Some(v) = self.is_hidden {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.name.as_ref() {
+            os.write_string(1, v)?;
+        }
+        if let Some(v) = self.size {
+            os.write_uint64(2, v)?;
+        }
+        if let Some(v) = self.recording_time {
+            os.write_int64(3, v)?;
+        }
+        if let Some(v) = self.is_directory {
+            os.write_bool(4, v)?;
+        }
+        if let Some(v) = self.is_hidden {
+            os.write_bool(5, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> File {
+        File::new()
+    }
+
+    fn clear(&mut self) {
+        self.name = ::std::option::Option::None;
+        self.size = ::std::option::Option::None;
+        self.recording_time = ::std::option::Option::None;
+        self.is_directory = ::std::option::Option::None;
+        self.is_hidden = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static File {
+        static instance: File = File {
+            name: ::std::option::Option::None,
+            size: ::std::option::Option::None,
+            recording_time: ::std::option::Option::None,
+            is_directory: ::std::option::Option::None,
+            is_hidden: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for File {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("File").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for File {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for File {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\riso9660.proto\x12\x07iso9660\x1a\nyara.proto\"\xbc\x05\n\x07Iso9660\
+    \x12\x1d\n\nis_iso9660\x18\x01\x20\x01(\x08R\tisIso9660\x12\x15\n\x06is_\
+    udf\x18\x02\x20\x01(\x08R\x05isUdf\x12\x1f\n\x0bis_bootable\x18\x03\x20\
+    \x01(\x08R\nisBootable\x12\x1d\n\nhas_joliet\x18\x04\x20\x01(\x08R\thasJ\
+    oliet\x12+\n\x11system_identifier\x18\x05\x20\x01(\tR\x10systemIdentifie\
+    r\x12+\n\x11volume_identifier\x18\x06\x20\x01(\tR\x10volumeIdentifier\
+    \x122\n\x15volume_set_identifier\x18\x07\x20\x01(\tR\x13volumeSetIdentif\
+    ier\x121\n\x14publisher_identifier\x18\x08\x20\x01(\tR\x13publisherIdent\
+    ifier\x128\n\x18data_preparer_identifier\x18\t\x20\x01(\tR\x16dataPrepar\
+    erIdentifier\x125\n\x16application_identifier\x18\n\x20\x01(\tR\x15appli\
+    cationIdentifier\x12*\n\x11volume_space_size\x18\x0b\x20\x01(\rR\x0fvolu\
+    meSpaceSize\x12,\n\x12logical_block_size\x18\x0c\x20\x01(\rR\x10logicalB\
+    lockSize\x12,\n\rcreation_time\x18\r\x20\x01(\x03R\x0ccreationTimeB\x07\
+    \x82\x93\x19\x03*\x01t\x124\n\x11modification_time\x18\x0e\x20\x01(\x03R\
+    \x10modificationTimeB\x07\x82\x93\x19\x03*\x01t\x12&\n\x0fnumber_of_file\
+    s\x18\x0f\x20\x01(\x04R\rnumberOfFiles\x12#\n\x05files\x18\x10\x20\x03(\
+    \x0b2\r.iso9660.FileR\x05files\"\x9e\x01\n\x04File\x12\x12\n\x04name\x18\
+    \x01\x20\x01(\tR\x04name\x12\x12\n\x04size\x18\x02\x20\x01(\x04R\x04size\
+    \x12.\n\x0erecording_time\x18\x03\x20\x01(\x03R\rrecordingTimeB\x07\x82\
+    \x93\x19\x03*\x01t\x12!\n\x0cis_directory\x18\x04\x20\x01(\x08R\x0bisDir\
+    ectory\x12\x1b\n\tis_hidden\x18\x05\x20\x01(\x08R\x08isHiddenB7\xfa\x92\
+    \x193\n\x07iso9660\x12\x0fiso9660.Iso9660\x1a\x07iso9660\"\x0eiso9660-mo\
+    duleb\x06proto2\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobuf::rt::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobuf::rt::Lazy<::protobuf::reflect::GeneratedFileDescriptor> = ::protobuf::rt::Lazy::new();
+    static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(1);
+            deps.push(super::yara::file_descriptor().clone());
+            let mut messages = ::std::vec::Vec::with_capacity(2);
+            messages.push(Iso9660::generated_message_descriptor_data());
+            messages.push(File::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(0);
+            ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobuf::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
@@ -70,6 +70,16 @@ pub struct Lnk {
     pub overlay_offset: ::std::option::Option<u64>,
     // @@protoc_insertion_point(field:lnk.Lnk.tracker_data)
     pub tracker_data: ::protobuf::MessageField<TrackerData>,
+    // @@protoc_insertion_point(field:lnk.Lnk.link_target_id_list)
+    pub link_target_id_list: ::std::vec::Vec<ItemId>,
+    // @@protoc_insertion_point(field:lnk.Lnk.environment_target)
+    pub environment_target: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:lnk.Lnk.environment_icon_location)
+    pub environment_icon_location: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:lnk.Lnk.known_folder_id)
+    pub known_folder_id: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:lnk.Lnk.special_folder_id)
+    pub special_folder_id: ::std::option::Option<u32>,
     // special fields
     // @@protoc_insertion_point(special_field:lnk.Lnk.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -608,8 +618,135 @@ impl Lnk {
         self.overlay_offset = ::std::option::Option::Some(v);
     }
 
+    // optional string environment_target = 23;
+
+    pub fn environment_target(&self) -> &str {
+        match self.environment_target.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_environment_target(&mut self) {
+        self.environment_target = ::std::option::Option::None;
+    }
+
+    pub fn has_environment_target(&self) -> bool {
+        self.environment_target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_environment_target(&mut self, v: ::std::string::String) {
+        self.environment_target = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_environment_target(&mut self) -> &mut ::std::string::String {
+        if self.environment_target.is_none() {
+            self.environment_target = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.environment_target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_environment_target(&mut self) -> ::std::string::String {
+        self.environment_target.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional string environment_icon_location = 24;
+
+    pub fn environment_icon_location(&self) -> &str {
+        match self.environment_icon_location.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_environment_icon_location(&mut self) {
+        self.environment_icon_location = ::std::option::Option::None;
+    }
+
+    pub fn has_environment_icon_location(&self) -> bool {
+        self.environment_icon_location.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_environment_icon_location(&mut self, v: ::std::string::String) {
+        self.environment_icon_location = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_environment_icon_location(&mut self) -> &mut ::std::string::String {
+        if self.environment_icon_location.is_none() {
+            self.environment_icon_location = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.environment_icon_location.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_environment_icon_location(&mut self) -> ::std::string::String {
+        self.environment_icon_location.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional string known_folder_id = 25;
+
+    pub fn known_folder_id(&self) -> &str {
+        match self.known_folder_id.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_known_folder_id(&mut self) {
+        self.known_folder_id = ::std::option::Option::None;
+    }
+
+    pub fn has_known_folder_id(&self) -> bool {
+        self.known_folder_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_known_folder_id(&mut self, v: ::std::string::String) {
+        self.known_folder_id = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_known_folder_id(&mut self) -> &mut ::std::string::String {
+        if self.known_folder_id.is_none() {
+            self.known_folder_id = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.known_folder_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_known_folder_id(&mut self) -> ::std::string::String {
+        self.known_folder_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional uint32 special_folder_id = 26;
+
+    pub fn special_folder_id(&self) -> u32 {
+        self.special_folder_id.unwrap_or(0)
+    }
+
+    pub fn clear_special_folder_id(&mut self) {
+        self.special_folder_id = ::std::option::Option::None;
+    }
+
+    pub fn has_special_folder_id(&self) -> bool {
+        self.special_folder_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_special_folder_id(&mut self, v: u32) {
+        self.special_folder_id = ::std::option::Option::Some(v);
+    }
+
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(21);
+        let mut fields = ::std::vec::Vec::with_capacity(26);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
             "is_lnk",
@@ -716,6 +853,31 @@ impl Lnk {
             |m: &Lnk| { &m.tracker_data },
             |m: &mut Lnk| { &mut m.tracker_data },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "link_target_id_list",
+            |m: &Lnk| { &m.link_target_id_list },
+            |m: &mut Lnk| { &mut m.link_target_id_list },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "environment_target",
+            |m: &Lnk| { &m.environment_target },
+            |m: &mut Lnk| { &mut m.environment_target },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "environment_icon_location",
+            |m: &Lnk| { &m.environment_icon_location },
+            |m: &mut Lnk| { &mut m.environment_icon_location },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "known_folder_id",
+            |m: &Lnk| { &m.known_folder_id },
+            |m: &mut Lnk| { &mut m.known_folder_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "special_folder_id",
+            |m: &Lnk| { &m.special_folder_id },
+            |m: &mut Lnk| { &mut m.special_folder_id },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Lnk>(
             "Lnk",
             fields,
@@ -736,6 +898,11 @@ impl ::protobuf::Message for Lnk {
                 return false;
             }
         };
+        for v in &self.link_target_id_list {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -805,6 +972,21 @@ impl ::protobuf::Message for Lnk {
                 170 => {
                     ::protobuf::rt::read_singular_message_into_field(is, &mut self.tracker_data)?;
                 },
+                178 => {
+                    self.link_target_id_list.push(is.read_message()?);
+                },
+                186 => {
+                    self.environment_target = ::std::option::Option::Some(is.read_string()?);
+                },
+                194 => {
+                    self.environment_icon_location = ::std::option::Option::Some(is.read_string()?);
+                },
+                202 => {
+                    self.known_folder_id = ::std::option::Option::Some(is.read_string()?);
+                },
+                208 => {
+                    self.special_folder_id = ::std::option::Option::Some(is.read_uint32()?);
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -881,6 +1063,22 @@ impl ::protobuf::Message for Lnk {
             let len = v.compute_size();
             my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
+        for value in &self.link_target_id_list {
+            let len = value.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if let Some(v) = self.environment_target.as_ref() {
+            my_size += ::protobuf::rt::string_size(23, &v);
+        }
+        if let Some(v) = self.environment_icon_location.as_ref() {
+            my_size += ::protobuf::rt::string_size(24, &v);
+        }
+        if let Some(v) = self.known_folder_id.as_ref() {
+            my_size += ::protobuf::rt::string_size(25, &v);
+        }
+        if let Some(v) = self.special_folder_id {
+            my_size += ::protobuf::rt::uint32_size(26, v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -950,6 +1148,21 @@ impl ::protobuf::Message for Lnk {
         if let Some(v) = self.tracker_data.as_ref() {
             ::protobuf::rt::write_message_field_with_cached_size(21, v, os)?;
         }
+        for v in &self.link_target_id_list {
+            ::protobuf::rt::write_message_field_with_cached_size(22, v, os)?;
+        };
+        if let Some(v) = self.environment_target.as_ref() {
+            os.write_string(23, v)?;
+        }
+        if let Some(v) = self.environment_icon_location.as_ref() {
+            os.write_string(24, v)?;
+        }
+        if let Some(v) = self.known_folder_id.as_ref() {
+            os.write_string(25, v)?;
+        }
+        if let Some(v) = self.special_folder_id {
+            os.write_uint32(26, v)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -988,6 +1201,11 @@ impl ::protobuf::Message for Lnk {
         self.overlay_size = ::std::option::Option::None;
         self.overlay_offset = ::std::option::Option::None;
         self.tracker_data.clear();
+        self.link_target_id_list.clear();
+        self.environment_target = ::std::option::Option::None;
+        self.environment_icon_location = ::std::option::Option::None;
+        self.known_folder_id = ::std::option::Option::None;
+        self.special_folder_id = ::std::option::Option::None;
         self.special_fields.clear();
     }
 
@@ -1014,6 +1232,11 @@ impl ::protobuf::Message for Lnk {
             overlay_size: ::std::option::Option::None,
             overlay_offset: ::std::option::Option::None,
             tracker_data: ::protobuf::MessageField::none(),
+            link_target_id_list: ::std::vec::Vec::new(),
+            environment_target: ::std::option::Option::None,
+            environment_icon_location: ::std::option::Option::None,
+            known_folder_id: ::std::option::Option::None,
+            special_folder_id: ::std::option::Option::None,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -1037,6 +1260,423 @@ impl ::protobuf::reflect::ProtobufValue for Lnk {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+// @@protoc_insertion_point(message:lnk.ItemId)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ItemId {
+    // message fields
+    // @@protoc_insertion_point(field:lnk.ItemId.item_type)
+    pub item_type: ::std::option::Option<::protobuf::EnumOrUnknown<ItemType>>,
+    // @@protoc_insertion_point(field:lnk.ItemId.class_type)
+    pub class_type: ::std::option::Option<u32>,
+    // @@protoc_insertion_point(field:lnk.ItemId.name)
+    pub name: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:lnk.ItemId.short_name)
+    pub short_name: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:lnk.ItemId.clsid)
+    pub clsid: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:lnk.ItemId.file_size)
+    pub file_size: ::std::option::Option<u32>,
+    // @@protoc_insertion_point(field:lnk.ItemId.file_attributes)
+    pub file_attributes: ::std::option::Option<u32>,
+    // special fields
+    // @@protoc_insertion_point(special_field:lnk.ItemId.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ItemId {
+    fn default() -> &'a ItemId {
+        <ItemId as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ItemId {
+    pub fn new() -> ItemId {
+        ::std::default::Default::default()
+    }
+
+    // optional .lnk.ItemType item_type = 1;
+
+    pub fn item_type(&self) -> ItemType {
+        match self.item_type {
+            Some(e) => e.enum_value_or(ItemType::UNKNOWN_ITEM),
+            None => ItemType::UNKNOWN_ITEM,
+        }
+    }
+
+    pub fn clear_item_type(&mut self) {
+        self.item_type = ::std::option::Option::None;
+    }
+
+    pub fn has_item_type(&self) -> bool {
+        self.item_type.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_item_type(&mut self, v: ItemType) {
+        self.item_type = ::std::option::Option::Some(::protobuf::EnumOrUnknown::new(v));
+    }
+
+    // optional uint32 class_type = 2;
+
+    pub fn class_type(&self) -> u32 {
+        self.class_type.unwrap_or(0)
+    }
+
+    pub fn clear_class_type(&mut self) {
+        self.class_type = ::std::option::Option::None;
+    }
+
+    pub fn has_class_type(&self) -> bool {
+        self.class_type.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_class_type(&mut self, v: u32) {
+        self.class_type = ::std::option::Option::Some(v);
+    }
+
+    // optional string name = 3;
+
+    pub fn name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_name(&mut self) {
+        self.name = ::std::option::Option::None;
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional string short_name = 4;
+
+    pub fn short_name(&self) -> &str {
+        match self.short_name.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_short_name(&mut self) {
+        self.short_name = ::std::option::Option::None;
+    }
+
+    pub fn has_short_name(&self) -> bool {
+        self.short_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_short_name(&mut self, v: ::std::string::String) {
+        self.short_name = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_short_name(&mut self) -> &mut ::std::string::String {
+        if self.short_name.is_none() {
+            self.short_name = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.short_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_short_name(&mut self) -> ::std::string::String {
+        self.short_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional string clsid = 5;
+
+    pub fn clsid(&self) -> &str {
+        match self.clsid.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_clsid(&mut self) {
+        self.clsid = ::std::option::Option::None;
+    }
+
+    pub fn has_clsid(&self) -> bool {
+        self.clsid.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_clsid(&mut self, v: ::std::string::String) {
+        self.clsid = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_clsid(&mut self) -> &mut ::std::string::String {
+        if self.clsid.is_none() {
+            self.clsid = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.clsid.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_clsid(&mut self) -> ::std::string::String {
+        self.clsid.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional uint32 file_size = 6;
+
+    pub fn file_size(&self) -> u32 {
+        self.file_size.unwrap_or(0)
+    }
+
+    pub fn clear_file_size(&mut self) {
+        self.file_size = ::std::option::Option::None;
+    }
+
+    pub fn has_file_size(&self) -> bool {
+        self.file_size.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_size(&mut self, v: u32) {
+        self.file_size = ::std::option::Option::Some(v);
+    }
+
+    // optional uint32 file_attributes = 7;
+
+    pub fn file_attributes(&self) -> u32 {
+        self.file_attributes.unwrap_or(0)
+    }
+
+    pub fn clear_file_attributes(&mut self) {
+        self.file_attributes = ::std::option::Option::None;
+    }
+
+    pub fn has_file_attributes(&self) -> bool {
+        self.file_attributes.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_attributes(&mut self, v: u32) {
+        self.file_attributes = ::std::option::Option::Some(v);
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(7);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "item_type",
+            |m: &ItemId| { &m.item_type },
+            |m: &mut ItemId| { &mut m.item_type },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "class_type",
+            |m: &ItemId| { &m.class_type },
+            |m: &mut ItemId| { &mut m.class_type },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "name",
+            |m: &ItemId| { &m.name },
+            |m: &mut ItemId| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "short_name",
+            |m: &ItemId| { &m.short_name },
+            |m: &mut ItemId| { &mut m.short_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "clsid",
+            |m: &ItemId| { &m.clsid },
+            |m: &mut ItemId| { &mut m.clsid },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "file_size",
+            |m: &ItemId| { &m.file_size },
+            |m: &mut ItemId| { &mut m.file_size },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "file_attributes",
+            |m: &ItemId| { &m.file_attributes },
+            |m: &mut ItemId| { &mut m.file_attributes },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ItemId>(
+            "ItemId",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ItemId {
+    const NAME: &'static str = "ItemId";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.item_type = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                },
+                16 => {
+                    self.class_type = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                26 => {
+                    self.name = ::std::option::Option::Some(is.read_string()?);
+                },
+                34 => {
+                    self.short_name = ::std::option::Option::Some(is.read_string()?);
+                },
+                42 => {
+                    self.clsid = ::std::option::Option::Some(is.read_string()?);
+                },
+                48 => {
+                    self.file_size = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                56 => {
+                    self.file_attributes = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.item_type {
+            my_size += ::protobuf::rt::int32_size(1, v.value());
+        }
+        if let Some(v) = self.class_type {
+            my_size += ::protobuf::rt::uint32_size(2, v);
+        }
+        if let Some(v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        if let Some(v) = self.short_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
+        }
+        if let Some(v) = self.clsid.as_ref() {
+            my_size += ::protobuf::rt::string_size(5, &v);
+        }
+        if let Some(v) = self.file_size {
+            my_size += ::protobuf::rt::uint32_size(6, v);
+        }
+        if let Some(v) = self.file_attributes {
+            my_size += ::protobuf::rt::uint32_size(7, v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.item_type {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&v))?;
+        }
+        if let Some(v) = self.class_type {
+            os.write_uint32(2, v)?;
+        }
+        if let Some(v) = self.name.as_ref() {
+            os.write_string(3, v)?;
+        }
+        if let Some(v) = self.short_name.as_ref() {
+            os.write_string(4, v)?;
+        }
+        if let Some(v) = self.clsid.as_ref() {
+            os.write_string(5, v)?;
+        }
+        if let Some(v) = self.file_size {
+            os.write_uint32(6, v)?;
+        }
+        if let Some(v) = self.file_attributes {
+            os.write_uint32(7, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ItemId {
+        ItemId::new()
+    }
+
+    fn clear(&mut self) {
+        self.item_type = ::std::option::Option::None;
+        self.class_type = ::std::option::Option::None;
+        self.name = ::std::option::Option::None;
+        self.short_name = ::std::option::Option::None;
+        self.clsid = ::std::option::Option::None;
+        self.file_size = ::std::option::Option::None;
+        self.file_attributes = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ItemId {
+        static instance: ItemId = ItemId {
+            item_type: ::std::option::Option::None,
+            class_type: ::std::option::Option::None,
+            name: ::std::option::Option::None,
+            short_name: ::std::option::Option::None,
+            clsid: ::std::option::Option::None,
+            file_size: ::std::option::Option::None,
+            file_attributes: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ItemId {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ItemId").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ItemId {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ItemId {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
 // @@protoc_insertion_point(message:lnk.TrackerData)
 #[derive(PartialEq,Clone,Default,Debug)]
 pub struct TrackerData {
@@ -1053,6 +1693,10 @@ pub struct TrackerData {
     pub droid_birth_volume_id: ::std::option::Option<::std::string::String>,
     // @@protoc_insertion_point(field:lnk.TrackerData.droid_birth_file_id)
     pub droid_birth_file_id: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:lnk.TrackerData.droid_file_mac)
+    pub droid_file_mac: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:lnk.TrackerData.droid_birth_file_mac)
+    pub droid_birth_file_mac: ::std::option::Option<::std::string::String>,
     // special fields
     // @@protoc_insertion_point(special_field:lnk.TrackerData.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -1268,8 +1912,80 @@ impl TrackerData {
         self.droid_birth_file_id.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
+    // optional string droid_file_mac = 7;
+
+    pub fn droid_file_mac(&self) -> &str {
+        match self.droid_file_mac.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_droid_file_mac(&mut self) {
+        self.droid_file_mac = ::std::option::Option::None;
+    }
+
+    pub fn has_droid_file_mac(&self) -> bool {
+        self.droid_file_mac.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_droid_file_mac(&mut self, v: ::std::string::String) {
+        self.droid_file_mac = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_droid_file_mac(&mut self) -> &mut ::std::string::String {
+        if self.droid_file_mac.is_none() {
+            self.droid_file_mac = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.droid_file_mac.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_droid_file_mac(&mut self) -> ::std::string::String {
+        self.droid_file_mac.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional string droid_birth_file_mac = 8;
+
+    pub fn droid_birth_file_mac(&self) -> &str {
+        match self.droid_birth_file_mac.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_droid_birth_file_mac(&mut self) {
+        self.droid_birth_file_mac = ::std::option::Option::None;
+    }
+
+    pub fn has_droid_birth_file_mac(&self) -> bool {
+        self.droid_birth_file_mac.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_droid_birth_file_mac(&mut self, v: ::std::string::String) {
+        self.droid_birth_file_mac = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_droid_birth_file_mac(&mut self) -> &mut ::std::string::String {
+        if self.droid_birth_file_mac.is_none() {
+            self.droid_birth_file_mac = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.droid_birth_file_mac.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_droid_birth_file_mac(&mut self) -> ::std::string::String {
+        self.droid_birth_file_mac.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut fields = ::std::vec::Vec::with_capacity(8);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
             "version",
@@ -1301,6 +2017,16 @@ impl TrackerData {
             |m: &TrackerData| { &m.droid_birth_file_id },
             |m: &mut TrackerData| { &mut m.droid_birth_file_id },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "droid_file_mac",
+            |m: &TrackerData| { &m.droid_file_mac },
+            |m: &mut TrackerData| { &mut m.droid_file_mac },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "droid_birth_file_mac",
+            |m: &TrackerData| { &m.droid_birth_file_mac },
+            |m: &mut TrackerData| { &mut m.droid_birth_file_mac },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TrackerData>(
             "TrackerData",
             fields,
@@ -1337,6 +2063,12 @@ impl ::protobuf::Message for TrackerData {
                 50 => {
                     self.droid_birth_file_id = ::std::option::Option::Some(is.read_string()?);
                 },
+                58 => {
+                    self.droid_file_mac = ::std::option::Option::Some(is.read_string()?);
+                },
+                66 => {
+                    self.droid_birth_file_mac = ::std::option::Option::Some(is.read_string()?);
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -1367,6 +2099,12 @@ impl ::protobuf::Message for TrackerData {
         if let Some(v) = self.droid_birth_file_id.as_ref() {
             my_size += ::protobuf::rt::string_size(6, &v);
         }
+        if let Some(v) = self.droid_file_mac.as_ref() {
+            my_size += ::protobuf::rt::string_size(7, &v);
+        }
+        if let Some(v) = self.droid_birth_file_mac.as_ref() {
+            my_size += ::protobuf::rt::string_size(8, &v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -1391,6 +2129,12 @@ impl ::protobuf::Message for TrackerData {
         if let Some(v) = self.droid_birth_file_id.as_ref() {
             os.write_string(6, v)?;
         }
+        if let Some(v) = self.droid_file_mac.as_ref() {
+            os.write_string(7, v)?;
+        }
+        if let Some(v) = self.droid_birth_file_mac.as_ref() {
+            os.write_string(8, v)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1414,6 +2158,8 @@ impl ::protobuf::Message for TrackerData {
         self.droid_file_id = ::std::option::Option::None;
         self.droid_birth_volume_id = ::std::option::Option::None;
         self.droid_birth_file_id = ::std::option::Option::None;
+        self.droid_file_mac = ::std::option::Option::None;
+        self.droid_birth_file_mac = ::std::option::Option::None;
         self.special_fields.clear();
     }
 
@@ -1425,6 +2171,8 @@ impl ::protobuf::Message for TrackerData {
             droid_file_id: ::std::option::Option::None,
             droid_birth_volume_id: ::std::option::Option::None,
             droid_birth_file_id: ::std::option::Option::None,
+            droid_file_mac: ::std::option::Option::None,
+            droid_birth_file_mac: ::std::option::Option::None,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -1751,8 +2499,100 @@ impl DriveType {
     }
 }
 
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:lnk.ItemType)
+pub enum ItemType {
+    // @@protoc_insertion_point(enum_value:lnk.ItemType.UNKNOWN_ITEM)
+    UNKNOWN_ITEM = 0,
+    // @@protoc_insertion_point(enum_value:lnk.ItemType.ROOT_FOLDER)
+    ROOT_FOLDER = 1,
+    // @@protoc_insertion_point(enum_value:lnk.ItemType.VOLUME)
+    VOLUME = 2,
+    // @@protoc_insertion_point(enum_value:lnk.ItemType.FILE_ENTRY)
+    FILE_ENTRY = 3,
+    // @@protoc_insertion_point(enum_value:lnk.ItemType.NETWORK_LOCATION)
+    NETWORK_LOCATION = 4,
+    // @@protoc_insertion_point(enum_value:lnk.ItemType.COMPRESSED_FOLDER)
+    COMPRESSED_FOLDER = 5,
+    // @@protoc_insertion_point(enum_value:lnk.ItemType.URI)
+    URI = 6,
+    // @@protoc_insertion_point(enum_value:lnk.ItemType.CONTROL_PANEL)
+    CONTROL_PANEL = 7,
+}
+
+impl ::protobuf::Enum for ItemType {
+    const NAME: &'static str = "ItemType";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<ItemType> {
+        match value {
+            0 => ::std::option::Option::Some(ItemType::UNKNOWN_ITEM),
+            1 => ::std::option::Option::Some(ItemType::ROOT_FOLDER),
+            2 => ::std::option::Option::Some(ItemType::VOLUME),
+            3 => ::std::option::Option::Some(ItemType::FILE_ENTRY),
+            4 => ::std::option::Option::Some(ItemType::NETWORK_LOCATION),
+            5 => ::std::option::Option::Some(ItemType::COMPRESSED_FOLDER),
+            6 => ::std::option::Option::Some(ItemType::URI),
+            7 => ::std::option::Option::Some(ItemType::CONTROL_PANEL),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<ItemType> {
+        match str {
+            "UNKNOWN_ITEM" => ::std::option::Option::Some(ItemType::UNKNOWN_ITEM),
+            "ROOT_FOLDER" => ::std::option::Option::Some(ItemType::ROOT_FOLDER),
+            "VOLUME" => ::std::option::Option::Some(ItemType::VOLUME),
+            "FILE_ENTRY" => ::std::option::Option::Some(ItemType::FILE_ENTRY),
+            "NETWORK_LOCATION" => ::std::option::Option::Some(ItemType::NETWORK_LOCATION),
+            "COMPRESSED_FOLDER" => ::std::option::Option::Some(ItemType::COMPRESSED_FOLDER),
+            "URI" => ::std::option::Option::Some(ItemType::URI),
+            "CONTROL_PANEL" => ::std::option::Option::Some(ItemType::CONTROL_PANEL),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [ItemType] = &[
+        ItemType::UNKNOWN_ITEM,
+        ItemType::ROOT_FOLDER,
+        ItemType::VOLUME,
+        ItemType::FILE_ENTRY,
+        ItemType::NETWORK_LOCATION,
+        ItemType::COMPRESSED_FOLDER,
+        ItemType::URI,
+        ItemType::CONTROL_PANEL,
+    ];
+}
+
+impl ::protobuf::EnumFull for ItemType {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("ItemType").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for ItemType {
+    fn default() -> Self {
+        ItemType::UNKNOWN_ITEM
+    }
+}
+
+impl ItemType {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<ItemType>("ItemType")
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\tlnk.proto\x12\x03lnk\x1a\nyara.proto\"\x95\x06\n\x03Lnk\x12\x15\n\
+    \n\tlnk.proto\x12\x03lnk\x1a\nyara.proto\"\x90\x08\n\x03Lnk\x12\x15\n\
     \x06is_lnk\x18\x01\x20\x02(\x08R\x05isLnk\x12\x12\n\x04name\x18\x02\x20\
     \x01(\tR\x04name\x12#\n\rcreation_time\x18\x03\x20\x01(\x04R\x0ccreation\
     Time\x12\x1f\n\x0baccess_time\x18\x04\x20\x01(\x04R\naccessTime\x12\x1d\
@@ -1770,29 +2610,46 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     orkingDir\x12\"\n\rcmd_line_args\x18\x12\x20\x01(\tR\x0bcmdLineArgs\x12!\
     \n\x0coverlay_size\x18\x13\x20\x01(\x04R\x0boverlaySize\x12%\n\x0eoverla\
     y_offset\x18\x14\x20\x01(\x04R\roverlayOffset\x123\n\x0ctracker_data\x18\
-    \x15\x20\x01(\x0b2\x10.lnk.TrackerDataR\x0btrackerData\"\xf4\x01\n\x0bTr\
-    ackerData\x12\x18\n\x07version\x18\x01\x20\x01(\rR\x07version\x12\x1d\n\
-    \nmachine_id\x18\x02\x20\x01(\tR\tmachineId\x12&\n\x0fdroid_volume_id\
-    \x18\x03\x20\x01(\tR\rdroidVolumeId\x12\"\n\rdroid_file_id\x18\x04\x20\
-    \x01(\tR\x0bdroidFileId\x121\n\x15droid_birth_volume_id\x18\x05\x20\x01(\
-    \tR\x12droidBirthVolumeId\x12-\n\x13droid_birth_file_id\x18\x06\x20\x01(\
-    \tR\x10droidBirthFileId*\xca\x03\n\x0eFileAttributes\x12\x1b\n\x17FILE_A\
-    TTRIBUTE_READONLY\x10\x01\x12\x19\n\x15FILE_ATTRIBUTE_HIDDEN\x10\x02\x12\
-    \x19\n\x15FILE_ATTRIBUTE_SYSTEM\x10\x04\x12\x0e\n\nRESERVED_1\x10\x08\
-    \x12\x1c\n\x18FILE_ATTRIBUTE_DIRECTORY\x10\x10\x12\x1a\n\x16FILE_ATTRIBU\
-    TE_ARCHIVE\x10\x20\x12\x0e\n\nRESERVED_2\x10@\x12\x1a\n\x15FILE_ATTRIBUT\
-    E_NORMAL\x10\x80\x01\x12\x1d\n\x18FILE_ATTRIBUTE_TEMPORARY\x10\x80\x02\
-    \x12\x1f\n\x1aFILE_ATTRIBUTE_SPARSE_FILE\x10\x80\x04\x12!\n\x1cFILE_ATTR\
-    IBUTE_REPARSE_POINT\x10\x80\x08\x12\x1e\n\x19FILE_ATTRIBUTE_COMPRESSED\
-    \x10\x80\x10\x12\x1b\n\x16FILE_ATTRIBUTE_OFFLINE\x10\x80\x20\x12'\n\"FIL\
-    E_ATTRIBUTE_NOT_CONTENT_INDEXED\x10\x80@\x12\x1e\n\x18FILE_ATTRIBUTE_ENC\
-    RYPTED\x10\x80\x80\x01\x1a\x06\x92\x93\x19\x02\x10\x01*;\n\x0bShowComman\
-    d\x12\n\n\x06NORMAL\x10\x01\x12\r\n\tMAXIMIZED\x10\x03\x12\x11\n\rMIN_NO\
-    _ACTIVE\x10\x07*g\n\tDriveType\x12\x0b\n\x07UNKNOWN\x10\0\x12\x0f\n\x0bN\
-    O_ROOT_DIR\x10\x01\x12\r\n\tREMOVABLE\x10\x02\x12\t\n\x05FIXED\x10\x03\
-    \x12\n\n\x06REMOTE\x10\x04\x12\t\n\x05CDROM\x10\x05\x12\x0b\n\x07RAMDISK\
-    \x10\x06B#\xfa\x92\x19\x1f\n\x03lnk\x12\x07lnk.Lnk\x1a\x03lnk\"\nlnk-mod\
-    uleb\x06proto2\
+    \x15\x20\x01(\x0b2\x10.lnk.TrackerDataR\x0btrackerData\x12:\n\x13link_ta\
+    rget_id_list\x18\x16\x20\x03(\x0b2\x0b.lnk.ItemIdR\x10linkTargetIdList\
+    \x12-\n\x12environment_target\x18\x17\x20\x01(\tR\x11environmentTarget\
+    \x12:\n\x19environment_icon_location\x18\x18\x20\x01(\tR\x17environmentI\
+    conLocation\x12&\n\x0fknown_folder_id\x18\x19\x20\x01(\tR\rknownFolderId\
+    \x12*\n\x11special_folder_id\x18\x1a\x20\x01(\rR\x0fspecialFolderId\"\
+    \xeb\x01\n\x06ItemId\x12*\n\titem_type\x18\x01\x20\x01(\x0e2\r.lnk.ItemT\
+    ypeR\x08itemType\x12&\n\nclass_type\x18\x02\x20\x01(\rR\tclassTypeB\x07\
+    \x82\x93\x19\x03*\x01x\x12\x12\n\x04name\x18\x03\x20\x01(\tR\x04name\x12\
+    \x1d\n\nshort_name\x18\x04\x20\x01(\tR\tshortName\x12\x14\n\x05clsid\x18\
+    \x05\x20\x01(\tR\x05clsid\x12\x1b\n\tfile_size\x18\x06\x20\x01(\rR\x08fi\
+    leSize\x12'\n\x0ffile_attributes\x18\x07\x20\x01(\rR\x0efileAttributes\"\
+    \xcb\x02\n\x0bTrackerData\x12\x18\n\x07version\x18\x01\x20\x01(\rR\x07ve\
+    rsion\x12\x1d\n\nmachine_id\x18\x02\x20\x01(\tR\tmachineId\x12&\n\x0fdro\
+    id_volume_id\x18\x03\x20\x01(\tR\rdroidVolumeId\x12\"\n\rdroid_file_id\
+    \x18\x04\x20\x01(\tR\x0bdroidFileId\x121\n\x15droid_birth_volume_id\x18\
+    \x05\x20\x01(\tR\x12droidBirthVolumeId\x12-\n\x13droid_birth_file_id\x18\
+    \x06\x20\x01(\tR\x10droidBirthFileId\x12$\n\x0edroid_file_mac\x18\x07\
+    \x20\x01(\tR\x0cdroidFileMac\x12/\n\x14droid_birth_file_mac\x18\x08\x20\
+    \x01(\tR\x11droidBirthFileMac*\xca\x03\n\x0eFileAttributes\x12\x1b\n\x17\
+    FILE_ATTRIBUTE_READONLY\x10\x01\x12\x19\n\x15FILE_ATTRIBUTE_HIDDEN\x10\
+    \x02\x12\x19\n\x15FILE_ATTRIBUTE_SYSTEM\x10\x04\x12\x0e\n\nRESERVED_1\
+    \x10\x08\x12\x1c\n\x18FILE_ATTRIBUTE_DIRECTORY\x10\x10\x12\x1a\n\x16FILE\
+    _ATTRIBUTE_ARCHIVE\x10\x20\x12\x0e\n\nRESERVED_2\x10@\x12\x1a\n\x15FILE_\
+    ATTRIBUTE_NORMAL\x10\x80\x01\x12\x1d\n\x18FILE_ATTRIBUTE_TEMPORARY\x10\
+    \x80\x02\x12\x1f\n\x1aFILE_ATTRIBUTE_SPARSE_FILE\x10\x80\x04\x12!\n\x1cF\
+    ILE_ATTRIBUTE_REPARSE_POINT\x10\x80\x08\x12\x1e\n\x19FILE_ATTRIBUTE_COMP\
+    RESSED\x10\x80\x10\x12\x1b\n\x16FILE_ATTRIBUTE_OFFLINE\x10\x80\x20\x12'\
+    \n\"FILE_ATTRIBUTE_NOT_CONTENT_INDEXED\x10\x80@\x12\x1e\n\x18FILE_ATTRIB\
+    UTE_ENCRYPTED\x10\x80\x80\x01\x1a\x06\x92\x93\x19\x02\x10\x01*;\n\x0bSho\
+    wCommand\x12\n\n\x06NORMAL\x10\x01\x12\r\n\tMAXIMIZED\x10\x03\x12\x11\n\
+    \rMIN_NO_ACTIVE\x10\x07*g\n\tDriveType\x12\x0b\n\x07UNKNOWN\x10\0\x12\
+    \x0f\n\x0bNO_ROOT_DIR\x10\x01\x12\r\n\tREMOVABLE\x10\x02\x12\t\n\x05FIXE\
+    D\x10\x03\x12\n\n\x06REMOTE\x10\x04\x12\t\n\x05CDROM\x10\x05\x12\x0b\n\
+    \x07RAMDISK\x10\x06*\x92\x01\n\x08ItemType\x12\x10\n\x0cUNKNOWN_ITEM\x10\
+    \0\x12\x0f\n\x0bROOT_FOLDER\x10\x01\x12\n\n\x06VOLUME\x10\x02\x12\x0e\n\
+    \nFILE_ENTRY\x10\x03\x12\x14\n\x10NETWORK_LOCATION\x10\x04\x12\x15\n\x11\
+    COMPRESSED_FOLDER\x10\x05\x12\x07\n\x03URI\x10\x06\x12\x11\n\rCONTROL_PA\
+    NEL\x10\x07B#\xfa\x92\x19\x1f\n\x03lnk\x12\x07lnk.Lnk\x1a\x03lnk\"\nlnk-\
+    moduleb\x06proto2\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -1811,13 +2668,15 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
         let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
             let mut deps = ::std::vec::Vec::with_capacity(1);
             deps.push(super::yara::file_descriptor().clone());
-            let mut messages = ::std::vec::Vec::with_capacity(2);
+            let mut messages = ::std::vec::Vec::with_capacity(3);
             messages.push(Lnk::generated_message_descriptor_data());
+            messages.push(ItemId::generated_message_descriptor_data());
             messages.push(TrackerData::generated_message_descriptor_data());
-            let mut enums = ::std::vec::Vec::with_capacity(3);
+            let mut enums = ::std::vec::Vec::with_capacity(4);
             enums.push(FileAttributes::generated_enum_descriptor_data());
             enums.push(ShowCommand::generated_enum_descriptor_data());
             enums.push(DriveType::generated_enum_descriptor_data());
+            enums.push(ItemType::generated_enum_descriptor_data());
             ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
                 file_descriptor_proto(),
                 deps,
@@ -7,18 +7,23 @@ pub mod cuckoo;
 pub mod dex;
 pub mod dotnet;
 pub mod elf;
+pub mod email;
 pub mod filetypes;
 pub mod gti_score;
 pub mod hash;
 pub mod hunting_gti_score;
+pub mod iso9660;
 pub mod lnk;
 pub mod macho;
 pub mod magic;
 pub mod math;
 pub mod mods;
 pub mod net_analysis;
+pub mod pcap;
 pub mod pe;
+pub mod regf;
 pub mod sandbox;
+pub mod scan;
 pub mod sigma;
 pub mod string;
 pub mod submitter;
@@ -28,4 +33,6 @@ pub mod text;
 pub mod time;
 pub mod titan;
 pub mod vtnet;
+pub mod wasm;
+pub mod x509;
 pub mod yara;
@@ -42,6 +42,18 @@ pub struct Modules {
     pub crx: ::protobuf::MessageField<super::crx::Crx>,
     // @@protoc_insertion_point(field:mods.Modules.dex)
     pub dex: ::protobuf::MessageField<super::dex::Dex>,
+    // @@protoc_insertion_point(field:mods.Modules.regf)
+    pub regf: ::protobuf::MessageField<super::regf::Regf>,
+    // @@protoc_insertion_point(field:mods.Modules.email)
+    pub email: ::protobuf::MessageField<super::email::Email>,
+    // @@protoc_insertion_point(field:mods.Modules.x509)
+    pub x509: ::protobuf::MessageField<super::x509::X509>,
+    // @@protoc_insertion_point(field:mods.Modules.iso9660)
+    pub iso9660: ::protobuf::MessageField<super::iso9660::Iso9660>,
+    // @@protoc_insertion_point(field:mods.Modules.wasm)
+    pub wasm: ::protobuf::MessageField<super::wasm::Wasm>,
+    // @@protoc_insertion_point(field:mods.Modules.pcap)
+    pub pcap: ::protobuf::MessageField<super::pcap::Pcap>,
     // special fields
     // @@protoc_insertion_point(special_field:mods.Modules.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -59,7 +71,7 @@ impl Modules {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(7);
+        let mut fields = ::std::vec::Vec::with_capacity(13);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::pe::PE>(
             "pe",
@@ -96,6 +108,36 @@ impl Modules {
             |m: &Modules| { &m.dex },
             |m: &mut Modules| { &mut m.dex },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::regf::Regf>(
+            "regf",
+            |m: &Modules| { &m.regf },
+            |m: &mut Modules| { &mut m.regf },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::email::Email>(
+            "email",
+            |m: &Modules| { &m.email },
+            |m: &mut Modules| { &mut m.email },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::x509::X509>(
+            "x509",
+            |m: &Modules| { &m.x509 },
+            |m: &mut Modules| { &mut m.x509 },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::iso9660::Iso9660>(
+            "iso9660",
+            |m: &Modules| { &m.iso9660 },
+            |m: &mut Modules| { &mut m.iso9660 },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::wasm::Wasm>(
+            "wasm",
+            |m: &Modules| { &m.wasm },
+            |m: &mut Modules| { &mut m.wasm },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::pcap::Pcap>(
+            "pcap",
+            |m: &Modules| { &m.pcap },
+            |m: &mut Modules| { &mut m.pcap },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Modules>(
             "Modules",
             fields,
@@ -143,6 +185,36 @@ impl ::protobuf::Message for Modules {
                 return false;
             }
         };
+        for v in &self.regf {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.email {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.x509 {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.iso9660 {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.wasm {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.pcap {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -170,6 +242,24 @@ impl ::protobuf::Message for Modules {
                 58 => {
                     ::protobuf::rt::read_singular_message_into_field(is, &mut self.dex)?;
                 },
+                66 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.regf)?;
+                },
+                74 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.email)?;
+                },
+                82 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.x509)?;
+                },
+                90 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.iso9660)?;
+                },
+                98 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.wasm)?;
+                },
+                106 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.pcap)?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -210,6 +300,30 @@ impl ::protobuf::Message for Modules {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
+        if let Some(v) = self.regf.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.email.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.x509.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.iso9660.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.wasm.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.pcap.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -237,6 +351,24 @@ impl ::protobuf::Message for Modules {
         if let Some(v) = self.dex.as_ref() {
             ::protobuf::rt::write_message_field_with_cached_size(7, v, os)?;
         }
+        if let Some(v) = self.regf.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(8, v, os)?;
+        }
+        if let Some(v) = self.email.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(9, v, os)?;
+        }
+        if let Some(v) = self.x509.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(10, v, os)?;
+        }
+        if let Some(v) = self.iso9660.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(11, v, os)?;
+        }
+        if let Some(v) = self.wasm.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(12, v, os)?;
+        }
+        if let Some(v) = self.pcap.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(13, v, os)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -261,6 +393,12 @@ impl ::protobuf::Message for Modules {
         self.lnk.clear();
         self.crx.clear();
         self.dex.clear();
+        self.regf.clear();
+        self.email.clear();
+        self.x509.clear();
+        self.iso9660.clear();
+        self.wasm.clear();
+        self.pcap.clear();
         self.special_fields.clear();
     }
 
@@ -273,6 +411,12 @@ impl ::protobuf::Message for Modules {
             lnk: ::protobuf::MessageField::none(),
             crx: ::protobuf::MessageField::none(),
             dex: ::protobuf::MessageField::none(),
+            regf: ::protobuf::MessageField::none(),
+            email: ::protobuf::MessageField::none(),
+            x509: ::protobuf::MessageField::none(),
+            iso9660: ::protobuf::MessageField::none(),
+            wasm: ::protobuf::MessageField::none(),
+            pcap: ::protobuf::MessageField::none(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -299,13 +443,20 @@ impl ::protobuf::reflect::ProtobufValue for Modules {
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\nmods.proto\x12\x04mods\x1a\nyara.proto\x1a\tcrx.proto\x1a\tdex.proto\
     \x1a\x0cdotnet.proto\x1a\telf.proto\x1a\x08pe.proto\x1a\tlnk.proto\x1a\
-    \x0bmacho.proto\"\xdd\x01\n\x07Modules\x12\x16\n\x02pe\x18\x01\x20\x01(\
-    \x0b2\x06.pe.PER\x02pe\x12\x1a\n\x03elf\x18\x02\x20\x01(\x0b2\x08.elf.EL\
-    FR\x03elf\x12&\n\x06dotnet\x18\x03\x20\x01(\x0b2\x0e.dotnet.DotnetR\x06d\
-    otnet\x12\"\n\x05macho\x18\x04\x20\x01(\x0b2\x0c.macho.MachoR\x05macho\
-    \x12\x1a\n\x03lnk\x18\x05\x20\x01(\x0b2\x08.lnk.LnkR\x03lnk\x12\x1a\n\
-    \x03crx\x18\x06\x20\x01(\x0b2\x08.crx.CrxR\x03crx\x12\x1a\n\x03dex\x18\
-    \x07\x20\x01(\x0b2\x08.dex.DexR\x03dexb\x06proto2\
+    \x0bmacho.proto\x1a\nregf.proto\x1a\x0bemail.proto\x1a\nx509.proto\x1a\r\
+    iso9660.proto\x1a\nwasm.proto\x1a\npcap.proto\"\xad\x03\n\x07Modules\x12\
+    \x16\n\x02pe\x18\x01\x20\x01(\x0b2\x06.pe.PER\x02pe\x12\x1a\n\x03elf\x18\
+    \x02\x20\x01(\x0b2\x08.elf.ELFR\x03elf\x12&\n\x06dotnet\x18\x03\x20\x01(\
+    \x0b2\x0e.dotnet.DotnetR\x06dotnet\x12\"\n\x05macho\x18\x04\x20\x01(\x0b\
+    2\x0c.macho.MachoR\x05macho\x12\x1a\n\x03lnk\x18\x05\x20\x01(\x0b2\x08.l\
+    nk.LnkR\x03lnk\x12\x1a\n\x03crx\x18\x06\x20\x01(\x0b2\x08.crx.CrxR\x03cr\
+    x\x12\x1a\n\x03dex\x18\x07\x20\x01(\x0b2\x08.dex.DexR\x03dex\x12\x1e\n\
+    \x04regf\x18\x08\x20\x01(\x0b2\n.regf.RegfR\x04regf\x12\"\n\x05email\x18\
+    \t\x20\x01(\x0b2\x0c.email.EmailR\x05email\x12\x1e\n\x04x509\x18\n\x20\
+    \x01(\x0b2\n.x509.X509R\x04x509\x12*\n\x07iso9660\x18\x0b\x20\x01(\x0b2\
+    \x10.iso9660.Iso9660R\x07iso9660\x12\x1e\n\x04wasm\x18\x0c\x20\x01(\x0b2\
+    \n.wasm.WasmR\x04wasm\x12\x1e\n\x04pcap\x18\r\x20\x01(\x0b2\n.pcap.PcapR\
+    \x04pcapb\x06proto2\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -322,7 +473,7 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
     static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
     file_descriptor.get(|| {
         let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
-            let mut deps = ::std::vec::Vec::with_capacity(8);
+            let mut deps = ::std::vec::Vec::with_capacity(14);
             deps.push(super::yara::file_descriptor().clone());
             deps.push(super::crx::file_descriptor().clone());
             deps.push(super::dex::file_descriptor().clone());
@@ -331,6 +482,12 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             deps.push(super::pe::file_descriptor().clone());
             deps.push(super::lnk::file_descriptor().clone());
             deps.push(super::macho::file_descriptor().clone());
+            deps.push(super::regf::file_descriptor().clone());
+            deps.push(super::email::file_descriptor().clone());
+            deps.push(super::x509::file_descriptor().clone());
+            deps.push(super::iso9660::file_descriptor().clone());
+            deps.push(super::wasm::file_descriptor().clone());
+            deps.push(super::pcap::file_descriptor().clone());
             let mut messages = ::std::vec::Vec::with_capacity(1);
             messages.push(Modules::generated_message_descriptor_data());
             let mut enums = ::std::vec::Vec::with_capacity(0);
@@ -0,0 +1,1089 @@
+// This file is generated by rust-protobuf 3.7.2. Do not edit
+// .proto file is parsed by pure
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `pcap.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_7_2;
+
+// @@protoc_insertion_point(message:pcap.Pcap)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Pcap {
+    // message fields
+    // @@protoc_insertion_point(field:pcap.Pcap.is_pcap)
+    pub is_pcap: ::std::option::Option<bool>,
+    // @@protoc_insertion_point(field:pcap.Pcap.format)
+    pub format: ::std::option::Option<::protobuf::EnumOrUnknown<Format>>,
+    // @@protoc_insertion_point(field:pcap.Pcap.major_version)
+    pub major_version: ::std::option::Option<u32>,
+    // @@protoc_insertion_point(field:pcap.Pcap.minor_version)
+    pub minor_version: ::std::option::Option<u32>,
+    // @@protoc_insertion_point(field:pcap.Pcap.link_type)
+    pub link_type: ::std::option::Option<::protobuf::EnumOrUnknown<LinkType>>,
+    // @@protoc_insertion_point(field:pcap.Pcap.number_of_packets)
+    pub number_of_packets: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:pcap.Pcap.number_of_ip_packets)
+    pub number_of_ip_packets: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:pcap.Pcap.number_of_tcp_packets)
+    pub number_of_tcp_packets: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:pcap.Pcap.number_of_udp_packets)
+    pub number_of_udp_packets: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:pcap.Pcap.first_packet_time)
+    pub first_packet_time: ::std::option::Option<i64>,
+    // @@protoc_insertion_point(field:pcap.Pcap.last_packet_time)
+    pub last_packet_time: ::std::option::Option<i64>,
+    // @@protoc_insertion_point(field:pcap.Pcap.top_talkers)
+    pub top_talkers: ::std::vec::Vec<Host>,
+    // @@protoc_insertion_point(field:pcap.Pcap.number_of_dns_queries)
+    pub number_of_dns_queries: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:pcap.Pcap.dns_queries)
+    pub dns_queries: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:pcap.Pcap.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Pcap {
+    fn default() -> &'a Pcap {
+        <Pcap as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Pcap {
+    pub fn new() -> Pcap {
+        ::std::default::Default::default()
+    }
+
+    // optional bool is_pcap = 1;
+
+    pub fn is_pcap(&self) -> bool {
+        self.is_pcap.unwrap_or(false)
+    }
+
+    pub fn clear_is_pcap(&mut self) {
+        self.is_pcap = ::std::option::Option::None;
+    }
+
+    pub fn has_is_pcap(&self) -> bool {
+        self.is_pcap.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_pcap(&mut self, v: bool) {
+        self.is_pcap = ::std::option::Option::Some(v);
+    }
+
+    // optional .pcap.Format format = 2;
+
+    pub fn format(&self) -> Format {
+        match self.format {
+            Some(e) => e.enum_value_or(Format::FORMAT_PCAP),
+            None => Format::FORMAT_PCAP,
+        }
+    }
+
+    pub fn clear_format(&mut self) {
+        self.format = ::std::option::Option::None;
+    }
+
+    pub fn has_format(&self) -> bool {
+        self.format.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_format(&mut self, v: Format) {
+        self.format = ::std::option::Option::Some(::protobuf::EnumOrUnknown::new(v));
+    }
+
+    // optional uint32 major_version = 3;
+
+    pub fn major_version(&self) -> u32 {
+        self.major_version.unwrap_or(0)
+    }
+
+    pub fn clear_major_version(&mut self) {
+        self.major_version = ::std::option::Option::None;
+    }
+
+    pub fn has_major_version(&self) -> bool {
+        self.major_version.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_major_version(&mut self, v: u32) {
+        self.major_version = ::std::option::Option::Some(v);
+    }
+
+    // optional uint32 minor_version = 4;
+
+    pub fn minor_version(&self) -> u32 {
+        self.minor_version.unwrap_or(0)
+    }
+
+    pub fn clear_minor_version(&mut self) {
+        self.minor_version = ::std::option::Option::None;
+    }
+
+    pub fn has_minor_version(&self) -> bool {
+        self.minor_version.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_minor_version(&mut self, v: u32) {
+        self.minor_version = ::std::option::Option::Some(v);
+    }
+
+    // optional .pcap.LinkType link_type = 5;
+
+    pub fn link_type(&self) -> LinkType {
+        match self.link_type {
+            Some(e) => e.enum_value_or(LinkType::LINKTYPE_NULL),
+            None => LinkType::LINKTYPE_NULL,
+        }
+    }
+
+    pub fn clear_link_type(&mut self) {
+        self.link_type = ::std::option::Option::None;
+    }
+
+    pub fn has_link_type(&self) -> bool {
+        self.link_type.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_link_type(&mut self, v: LinkType) {
+        self.link_type = ::std::option::Option::Some(::protobuf::EnumOrUnknown::new(v));
+    }
+
+    // optional uint64 number_of_packets = 6;
+
+    pub fn number_of_packets(&self) -> u64 {
+        self.number_of_packets.unwrap_or(0)
+    }
+
+    pub fn clear_number_of_packets(&mut self) {
+        self.number_of_packets = ::std::option::Option::None;
+    }
+
+    pub fn has_number_of_packets(&self) -> bool {
+        self.number_of_packets.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_number_of_packets(&mut self, v: u64) {
+        self.number_of_packets = ::std::option::Option::Some(v);
+    }
+
+    // optional uint64 number_of_ip_packets = 7;
+
+    pub fn number_of_ip_packets(&self) -> u64 {
+        self.number_of_ip_packets.unwrap_or(0)
+    }
+
+    pub fn clear_number_of_ip_packets(&mut self) {
+        self.number_of_ip_packets = ::std::option::Option::None;
+    }
+
+    pub fn has_number_of_ip_packets(&self) -> bool {
+        self.number_of_ip_packets.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_number_of_ip_packets(&mut self, v: u64) {
+        self.number_of_ip_packets = ::std::option::Option::Some(v);
+    }
+
+    // optional uint64 number_of_tcp_packets = 8;
+
+    pub fn number_of_tcp_packets(&self) -> u64 {
+        self.number_of_tcp_packets.unwrap_or(0)
+    }
+
+    pub fn clear_number_of_tcp_packets(&mut self) {
+        self.number_of_tcp_packets = ::std::option::Option::None;
+    }
+
+    pub fn has_number_of_tcp_packets(&self) -> bool {
+        self.number_of_tcp_packets.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_number_of_tcp_packets(&mut self, v: u64) {
+        self.number_of_tcp_packets = ::std::option::Option::Some(v);
+    }
+
+    // optional uint64 number_of_udp_packets = 9;
+
+    pub fn number_of_udp_packets(&self) -> u64 {
+        self.number_of_udp_packets.unwrap_or(0)
+    }
+
+    pub fn clear_number_of_udp_packets(&mut self) {
+        self.number_of_udp_packets = ::std::option::Option::None;
+    }
+
+    pub fn has_number_of_udp_packets(&self) -> bool {
+        self.number_of_udp_packets.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_number_of_udp_packets(&mut self, v: u64) {
+        self.number_of_udp_packets = ::std::option::Option::Some(v);
+    }
+
+    // optional int64 first_packet_time = 10;
+
+    pub fn first_packet_time(&self) -> i64 {
+        self.first_packet_time.unwrap_or(0)
+    }
+
+    pub fn clear_first_packet_time(&mut self) {
+        self.first_packet_time = ::std::option::Option::None;
+    }
+
+    pub fn has_first_packet_time(&self) -> bool {
+        self.first_packet_time.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_first_packet_time(&mut self, v: i64) {
+        self.first_packet_time = ::std::option::Option::Some(v);
+    }
+
+    // optional int64 last_packet_time = 11;
+
+    pub fn last_packet_time(&self) -> i64 {
+        self.last_packet_time.unwrap_or(0)
+    }
+
+    pub fn clear_last_packet_time(&mut self) {
+        self.last_packet_time = ::std::option::Option::None;
+    }
+
+    pub fn has_last_packet_time(&self) -> bool {
+        self.last_packet_time.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_packet_time(&mut self, v: i64) {
+        self.last_packet_time = ::std::option::Option::Some(v);
+    }
+
+    // optional uint64 number_of_dns_queries = 13;
+
+    pub fn number_of_dns_queries(&self) -> u64 {
+        self.number_of_dns_queries.unwrap_or(0)
+    }
+
+    pub fn clear_number_of_dns_queries(&mut self) {
+        self.number_of_dns_queries = ::std::option::Option::None;
+    }
+
+    pub fn has_number_of_dns_queries(&self) -> bool {
+        self.number_of_dns_queries.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_number_of_dns_queries(&mut self, v: u64) {
+        self.number_of_dns_queries = ::std::option::Option::Some(v);
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(14);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "is_pcap",
+            |m: &Pcap| { &m.is_pcap },
+            |m: &mut Pcap| { &mut m.is_pcap },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "format",
+            |m: &Pcap| { &m.format },
+            |m: &mut Pcap| { &mut m.format },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "major_version",
+            |m: &Pcap| { &m.major_version },
+            |m: &mut Pcap| { &mut m.major_version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "minor_version",
+            |m: &Pcap| { &m.minor_version },
+            |m: &mut Pcap| { &mut m.minor_version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "link_type",
+            |m: &Pcap| { &m.link_type },
+            |m: &mut Pcap| { &mut m.link_type },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "number_of_packets",
+            |m: &Pcap| { &m.number_of_packets },
+            |m: &mut Pcap| { &mut m.number_of_packets },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "number_of_ip_packets",
+            |m: &Pcap| { &m.number_of_ip_packets },
+            |m: &mut Pcap| { &mut m.number_of_ip_packets },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "number_of_tcp_packets",
+            |m: &Pcap| { &m.number_of_tcp_packets },
+            |m: &mut Pcap| { &mut m.number_of_tcp_packets },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "number_of_udp_packets",
+            |m: &Pcap| { &m.number_of_udp_packets },
+            |m: &mut Pcap| { &mut m.number_of_udp_packets },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "first_packet_time",
+            |m: &Pcap| { &m.first_packet_time },
+            |m: &mut Pcap| { &mut m.first_packet_time },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "last_packet_time",
+            |m: &Pcap| { &m.last_packet_time },
+            |m: &mut Pcap| { &mut m.last_packet_time },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "top_talkers",
+            |m: &Pcap| { &m.top_talkers },
+            |m: &mut Pcap| { &mut m.top_talkers },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "number_of_dns_queries",
+            |m: &Pcap| { &m.number_of_dns_queries },
+            |m: &mut Pcap| { &mut m.number_of_dns_queries },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "dns_queries",
+            |m: &Pcap| { &m.dns_queries },
+            |m: &mut Pcap| { &mut m.dns_queries },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Pcap>(
+            "Pcap",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Pcap {
+    const NAME: &'static str = "Pcap";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.is_pcap = ::std::option::Option::Some(is.read_bool()?);
+                },
+                16 => {
+                    self.format = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                },
+                24 => {
+                    self.major_version = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                32 => {
+                    self.minor_version = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                40 => {
+                    self.link_type = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                },
+                48 => {
+                    self.number_of_packets = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                56 => {
+                    self.number_of_ip_packets = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                64 => {
+                    self.number_of_tcp_packets = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                72 => {
+                    self.number_of_udp_packets = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                80 => {
+                    self.first_packet_time = ::std::option::Option::Some(is.read_int64()?);
+                },
+                88 => {
+                    self.last_packet_time = ::std::option::Option::Some(is.read_int64()?);
+                },
+                98 => {
+                    self.top_talkers.push(is.read_message()?);
+                },
+                104 => {
+                    self.number_of_dns_queries = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                114 => {
+                    self.dns_queries.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.is_pcap {
+            my_size += 1 + 1;
+        }
+        if let Some(v) = self.format {
+            my_size += ::protobuf::rt::int32_size(2, v.value());
+        }
+        if let Some(v) = self.major_version {
+            my_size += ::protobuf::rt::uint32_size(3, v);
+        }
+        if let Some(v) = self.minor_version {
+            my_size += ::protobuf::rt::uint32_size(4, v);
+        }
+        if let Some(v) = self.link_type {
+            my_size += ::protobuf::rt::int32_size(5, v.value());
+        }
+        if let Some(v) = self.number_of_packets {
+            my_size += ::protobuf::rt::uint64_size(6, v);
+        }
+        if let Some(v) = self.number_of_ip_packets {
+            my_size += ::protobuf::rt::uint64_size(7, v);
+        }
+        if let Some(v) = self.number_of_tcp_packets {
+            my_size += ::protobuf::rt::uint64_size(8, v);
+        }
+        if let Some(v) = self.number_of_udp_packets {
+            my_size += ::protobuf::rt::uint64_size(9, v);
+        }
+        if let Some(v) = self.first_packet_time {
+            my_size += ::protobuf::rt::int64_size(10, v);
+        }
+        if let Some(v) = self.last_packet_time {
+            my_size += ::protobuf::rt::int64_size(11, v);
+        }
+        for value in &self.top_talkers {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if let Some(v) = self.number_of_dns_queries {
+            my_size += ::protobuf::rt::uint64_size(13, v);
+        }
+        for value in &self.dns_queries {
+            my_size += ::protobuf::rt::string_size(14, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.is_pcap {
+            os.write_bool(1, v)?;
+        }
+        if let Some(v) = self.format {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&v))?;
+        }
+        if let Some(v) = self.major_version {
+            os.write_uint32(3, v)?;
+        }
+        if let Some(v) = self.minor_version {
+            os.write_uint32(4, v)?;
+        }
+        if let Some(v) = self.link_type {
+            os.write_enum(5, ::protobuf::EnumOrUnknown::value(&v))?;
+        }
+        if let Some(v) = self.number_of_packets {
+            os.write_uint64(6, v)?;
+        }
+        if let Some(v) = self.number_of_ip_packets {
+            os.write_uint64(7, v)?;
+        }
+        if let Some(v) = self.number_of_tcp_packets {
+            os.write_uint64(8, v)?;
+        }
+        if let Some(v) = self.number_of_udp_packets {
+            os.write_uint64(9, v)?;
+        }
+        if let Some(v) = self.first_packet_time {
+            os.write_int64(10, v)?;
+        }
+        if let Some(v) = self.last_packet_time {
+            os.write_int64(11, v)?;
+        }
+        for v in &self.top_talkers {
+            ::protobuf::rt::write_message_field_with_cached_size(12, v, os)?;
+        };
+        if let Some(v) = self.number_of_dns_queries {
+            os.write_uint64(13, v)?;
+        }
+        for v in &self.dns_queries {
+            os.write_string(14, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Pcap {
+        Pcap::new()
+    }
+
+    fn clear(&mut self) {
+        self.is_pcap = ::std::option::Option::None;
+        self.format = ::std::option::Option::None;
+        self.major_version = ::std::option::Option::None;
+        self.minor_version = ::std::option::Option::None;
+        self.link_type = ::std::option::Option::None;
+        self.number_of_packets = ::std::option::Option::None;
+        self.number_of_ip_packets = ::std::option::Option::None;
+        self.number_of_tcp_packets = ::std::option::Option::None;
+        self.number_of_udp_packets = ::std::option::Option::None;
+        self.first_packet_time = ::std::option::Option::None;
+        self.last_packet_time = ::std::option::Option::None;
+        self.top_talkers.clear();
+        self.number_of_dns_queries = ::std::option::Option::None;
+        self.dns_queries.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Pcap {
+        static instance: Pcap = Pcap {
+            is_pcap: ::std::option::Option::None,
+            format: ::std::option::Option::None,
+            major_version: ::std::option::Option::None,
+            minor_version: ::std::option::Option::None,
+            link_type: ::std::option::Option::None,
+            number_of_packets: ::std::option::Option::None,
+            number_of_ip_packets: ::std::option::Option::None,
+            number_of_tcp_packets: ::std::option::Option::None,
+            number_of_udp_packets: ::std::option::Option::None,
+            first_packet_time: ::std::option::Option::None,
+            last_packet_time: ::std::option::Option::None,
+            top_talkers: ::std::vec::Vec::new(),
+            number_of_dns_queries: ::std::option::Option::None,
+            dns_queries: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Pcap {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Pcap").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Pcap {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Pcap {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:pcap.Host)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Host {
+    // message fields
+    // @@protoc_insertion_point(field:pcap.Host.address)
+    pub address: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:pcap.Host.packets)
+    pub packets: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:pcap.Host.bytes)
+    pub bytes: ::std::option::Option<u64>,
+    // special fields
+    // @@protoc_insertion_point(special_field:pcap.Host.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Host {
+    fn default() -> &'a Host {
+        <Host as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Host {
+    pub fn new() -> Host {
+        ::std::default::Default::default()
+    }
+
+    // optional string address = 1;
+
+    pub fn address(&self) -> &str {
+        match self.address.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_address(&mut self) {
+        self.address = ::std::option::Option::None;
+    }
+
+    pub fn has_address(&self) -> bool {
+        self.address.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_address(&mut self, v: ::std::string::String) {
+        self.address = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_address(&mut self) -> &mut ::std::string::String {
+        if self.address.is_none() {
+            self.address = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.address.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_address(&mut self) -> ::std::string::String {
+        self.address.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional uint64 packets = 2;
+
+    pub fn packets(&self) -> u64 {
+        self.packets.unwrap_or(0)
+    }
+
+    pub fn clear_packets(&mut self) {
+        self.packets = ::std::option::Option::None;
+    }
+
+    pub fn has_packets(&self) -> bool {
+        self.packets.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_packets(&mut self, v: u64) {
+        self.packets = ::std::option::Option::Some(v);
+    }
+
+    // optional uint64 bytes = 3;
+
+    pub fn bytes(&self) -> u64 {
+        self.bytes.unwrap_or(0)
+    }
+
+    pub fn clear_bytes(&mut self) {
+        self.bytes = ::std::option::Option::None;
+    }
+
+    pub fn has_bytes(&self) -> bool {
+        self.bytes.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bytes(&mut self, v: u64) {
+        self.bytes = ::std::option::Option::Some(v);
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "address",
+            |m: &Host| { &m.address },
+            |m: &mut Host| { &mut m.address },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "packets",
+            |m: &Host| { &m.packets },
+            |m: &mut Host| { &mut m.packets },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "bytes",
+            |m: &Host| { &m.bytes },
+            |m: &mut Host| { &mut m.bytes },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Host>(
+            "Host",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Host {
+    const NAME: &'static str = "Host";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.address = ::std::option::Option::Some(is.read_string()?);
+                },
+                16 => {
+                    self.packets = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                24 => {
+                    self.bytes = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.address.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.packets {
+            my_size += ::protobuf::rt::uint64_size(2, v);
+        }
+        if let Some(v) = self.bytes {
+            my_size += ::protobuf::rt::uint64_size(3, v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.address.as_ref() {
+            os.write_string(1, v)?;
+        }
+        if let Some(v) = self.packets {
+            os.write_uint64(2, v)?;
+        }
+        if let Some(v) = self.bytes {
+            os.write_uint64(3, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Host {
+        Host::new()
+    }
+
+    fn clear(&mut self) {
+        self.address = ::std::option::Option::None;
+        self.packets = ::std::option::Option::None;
+        self.bytes = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Host {
+        static instance: Host = Host {
+            address: ::std::option::Option::None,
+            packets: ::std::option::Option::None,
+            bytes: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Host {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Host").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Host {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Host {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:pcap.Format)
+pub enum Format {
+    // @@protoc_insertion_point(enum_value:pcap.Format.FORMAT_PCAP)
+    FORMAT_PCAP = 1,
+    // @@protoc_insertion_point(enum_value:pcap.Format.FORMAT_PCAPNG)
+    FORMAT_PCAPNG = 2,
+}
+
+impl ::protobuf::Enum for Format {
+    const NAME: &'static str = "Format";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Format> {
+        match value {
+            1 => ::std::option::Option::Some(Format::FORMAT_PCAP),
+            2 => ::std::option::Option::Some(Format::FORMAT_PCAPNG),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<Format> {
+        match str {
+            "FORMAT_PCAP" => ::std::option::Option::Some(Format::FORMAT_PCAP),
+            "FORMAT_PCAPNG" => ::std::option::Option::Some(Format::FORMAT_PCAPNG),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [Format] = &[
+        Format::FORMAT_PCAP,
+        Format::FORMAT_PCAPNG,
+    ];
+}
+
+impl ::protobuf::EnumFull for Format {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("Format").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = match self {
+            Format::FORMAT_PCAP => 0,
+            Format::FORMAT_PCAPNG => 1,
+        };
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+// Note, `Default` is implemented although default value is not 0
+impl ::std::default::Default for Format {
+    fn default() -> Self {
+        Format::FORMAT_PCAP
+    }
+}
+
+impl Format {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Format>("Format")
+    }
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:pcap.LinkType)
+pub enum LinkType {
+    // @@protoc_insertion_point(enum_value:pcap.LinkType.LINKTYPE_NULL)
+    LINKTYPE_NULL = 0,
+    // @@protoc_insertion_point(enum_value:pcap.LinkType.LINKTYPE_ETHERNET)
+    LINKTYPE_ETHERNET = 1,
+    // @@protoc_insertion_point(enum_value:pcap.LinkType.LINKTYPE_RAW)
+    LINKTYPE_RAW = 101,
+    // @@protoc_insertion_point(enum_value:pcap.LinkType.LINKTYPE_IEEE802_11)
+    LINKTYPE_IEEE802_11 = 105,
+    // @@protoc_insertion_point(enum_value:pcap.LinkType.LINKTYPE_LOOP)
+    LINKTYPE_LOOP = 108,
+    // @@protoc_insertion_point(enum_value:pcap.LinkType.LINKTYPE_LINUX_SLL)
+    LINKTYPE_LINUX_SLL = 113,
+    // @@protoc_insertion_point(enum_value:pcap.LinkType.LINKTYPE_IEEE802_11_RADIOTAP)
+    LINKTYPE_IEEE802_11_RADIOTAP = 127,
+    // @@protoc_insertion_point(enum_value:pcap.LinkType.LINKTYPE_IPV4)
+    LINKTYPE_IPV4 = 228,
+    // @@protoc_insertion_point(enum_value:pcap.LinkType.LINKTYPE_IPV6)
+    LINKTYPE_IPV6 = 229,
+    // @@protoc_insertion_point(enum_value:pcap.LinkType.LINKTYPE_LINUX_SLL2)
+    LINKTYPE_LINUX_SLL2 = 276,
+}
+
+impl ::protobuf::Enum for LinkType {
+    const NAME: &'static str = "LinkType";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<LinkType> {
+        match value {
+            0 => ::std::option::Option::Some(LinkType::LINKTYPE_NULL),
+            1 => ::std::option::Option::Some(LinkType::LINKTYPE_ETHERNET),
+            101 => ::std::option::Option::Some(LinkType::LINKTYPE_RAW),
+            105 => ::std::option::Option::Some(LinkType::LINKTYPE_IEEE802_11),
+            108 => ::std::option::Option::Some(LinkType::LINKTYPE_LOOP),
+            113 => ::std::option::Option::Some(LinkType::LINKTYPE_LINUX_SLL),
+            127 => ::std::option::Option::Some(LinkType::LINKTYPE_IEEE802_11_RADIOTAP),
+            228 => ::std::option::Option::Some(LinkType::LINKTYPE_IPV4),
+            229 => ::std::option::Option::Some(LinkType::LINKTYPE_IPV6),
+            276 => ::std::option::Option::Some(LinkType::LINKTYPE_LINUX_SLL2),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<LinkType> {
+        match str {
+            "LINKTYPE_NULL" => ::std::option::Option::Some(LinkType::LINKTYPE_NULL),
+            "LINKTYPE_ETHERNET" => ::std::option::Option::Some(LinkType::LINKTYPE_ETHERNET),
+            "LINKTYPE_RAW" => ::std::option::Option::Some(LinkType::LINKTYPE_RAW),
+            "LINKTYPE_IEEE802_11" => ::std::option::Option::Some(LinkType::LINKTYPE_IEEE802_11),
+            "LINKTYPE_LOOP" => ::std::option::Option::Some(LinkType::LINKTYPE_LOOP),
+            "LINKTYPE_LINUX_SLL" => ::std::option::Option::Some(LinkType::LINKTYPE_LINUX_SLL),
+            "LINKTYPE_IEEE802_11_RADIOTAP" => ::std::option::Option::Some(LinkType::LINKTYPE_IEEE802_11_RADIOTAP),
+            "LINKTYPE_IPV4" => ::std::option::Option::Some(LinkType::LINKTYPE_IPV4),
+            "LINKTYPE_IPV6" => ::std::option::Option::Some(LinkType::LINKTYPE_IPV6),
+            "LINKTYPE_LINUX_SLL2" => ::std::option::Option::Some(LinkType::LINKTYPE_LINUX_SLL2),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [LinkType] = &[
+        LinkType::LINKTYPE_NULL,
+        LinkType::LINKTYPE_ETHERNET,
+        LinkType::LINKTYPE_RAW,
+        LinkType::LINKTYPE_IEEE802_11,
+        LinkType::LINKTYPE_LOOP,
+        LinkType::LINKTYPE_LINUX_SLL,
+        LinkType::LINKTYPE_IEEE802_11_RADIOTAP,
+        LinkType::LINKTYPE_IPV4,
+        LinkType::LINKTYPE_IPV6,
+        LinkType::LINKTYPE_LINUX_SLL2,
+    ];
+}
+
+impl ::protobuf::EnumFull for LinkType {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("LinkType").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = match self {
+            LinkType::LINKTYPE_NULL => 0,
+            LinkType::LINKTYPE_ETHERNET => 1,
+            LinkType::LINKTYPE_RAW => 2,
+            LinkType::LINKTYPE_IEEE802_11 => 3,
+            LinkType::LINKTYPE_LOOP => 4,
+            LinkType::LINKTYPE_LINUX_SLL => 5,
+            LinkType::LINKTYPE_IEEE802_11_RADIOTAP => 6,
+            LinkType::LINKTYPE_IPV4 => 7,
+            LinkType::LINKTYPE_IPV6 => 8,
+            LinkType::LINKTYPE_LINUX_SLL2 => 9,
+        };
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for LinkType {
+    fn default() -> Self {
+        LinkType::LINKTYPE_NULL
+    }
+}
+
+impl LinkType {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<LinkType>("LinkType")
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\npcap.proto\x12\x04pcap\x1a\nyara.proto\"\xe8\x04\n\x04Pcap\x12\x17\n\
+    \x07is_pcap\x18\x01\x20\x01(\x08R\x06isPcap\x12$\n\x06format\x18\x02\x20\
+    \x01(\x0e2\x0c.pcap.FormatR\x06format\x12#\n\rmajor_version\x18\x03\x20\
+    \x01(\rR\x0cmajorVersion\x12#\n\rminor_version\x18\x04\x20\x01(\rR\x0cmi\
+    norVersion\x12+\n\tlink_type\x18\x05\x20\x01(\x0e2\x0e.pcap.LinkTypeR\
+    \x08linkType\x12*\n\x11number_of_packets\x18\x06\x20\x01(\x04R\x0fnumber\
+    OfPackets\x12/\n\x14number_of_ip_packets\x18\x07\x20\x01(\x04R\x11number\
+    OfIpPackets\x121\n\x15number_of_tcp_packets\x18\x08\x20\x01(\x04R\x12num\
+    berOfTcpPackets\x121\n\x15number_of_udp_packets\x18\t\x20\x01(\x04R\x12n\
+    umberOfUdpPackets\x123\n\x11first_packet_time\x18\n\x20\x01(\x03R\x0ffir\
+    stPacketTimeB\x07\x82\x93\x19\x03*\x01t\x121\n\x10last_packet_time\x18\
+    \x0b\x20\x01(\x03R\x0elastPacketTimeB\x07\x82\x93\x19\x03*\x01t\x12+\n\
+    \x0btop_talkers\x18\x0c\x20\x03(\x0b2\n.pcap.HostR\ntopTalkers\x121\n\
+    \x15number_of_dns_queries\x18\r\x20\x01(\x04R\x12numberOfDnsQueries\x12\
+    \x1f\n\x0bdns_queries\x18\x0e\x20\x03(\tR\ndnsQueries\"P\n\x04Host\x12\
+    \x18\n\x07address\x18\x01\x20\x01(\tR\x07address\x12\x18\n\x07packets\
+    \x18\x02\x20\x01(\x04R\x07packets\x12\x14\n\x05bytes\x18\x03\x20\x01(\
+    \x04R\x05bytes*4\n\x06Format\x12\x0f\n\x0bFORMAT_PCAP\x10\x01\x12\x11\n\
+    \rFORMAT_PCAPNG\x10\x02\x1a\x06\x92\x93\x19\x02\x10\x01*\xf6\x01\n\x08Li\
+    nkType\x12\x11\n\rLINKTYPE_NULL\x10\0\x12\x15\n\x11LINKTYPE_ETHERNET\x10\
+    \x01\x12\x10\n\x0cLINKTYPE_RAW\x10e\x12\x17\n\x13LINKTYPE_IEEE802_11\x10\
+    i\x12\x11\n\rLINKTYPE_LOOP\x10l\x12\x16\n\x12LINKTYPE_LINUX_SLL\x10q\x12\
+    \x20\n\x1cLINKTYPE_IEEE802_11_RADIOTAP\x10\x7f\x12\x12\n\rLINKTYPE_IPV4\
+    \x10\xe4\x01\x12\x12\n\rLINKTYPE_IPV6\x10\xe5\x01\x12\x18\n\x13LINKTYPE_\
+    LINUX_SLL2\x10\x94\x02\x1a\x06\x92\x93\x19\x02\x10\x01B(\xfa\x92\x19$\n\
+    \x04pcap\x12\tpcap.Pcap\x1a\x04pcap\"\x0bpcap-moduleb\x06proto2\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobuf::rt::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobuf::rt::Lazy<::protobuf::reflect::GeneratedFileDescriptor> = ::protobuf::rt::Lazy::new();
+    static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(1);
+            deps.push(super::yara::file_descriptor().clone());
+            let mut messages = ::std::vec::Vec::with_capacity(2);
+            messages.push(Pcap::generated_message_descriptor_data());
+            messages.push(Host::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(2);
+            enums.push(Format::generated_enum_descriptor_data());
+            enums.push(LinkType::generated_enum_descriptor_data());
+            ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobuf::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
@@ -4052,6 +4052,8 @@ pub struct Signature {
     pub certificates: ::std::vec::Vec<Certificate>,
     // @@protoc_insertion_point(field:pe.Signature.countersignatures)
     pub countersignatures: ::std::vec::Vec<CounterSignature>,
+    // @@protoc_insertion_point(field:pe.Signature.sign_time)
+    pub sign_time: ::std::option::Option<i64>,
     // special fields
     // @@protoc_insertion_point(special_field:pe.Signature.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -4506,8 +4508,27 @@ impl Signature {
         self.number_of_countersignatures = ::std::option::Option::Some(v);
     }
 
+    // optional int64 sign_time = 19;
+
+    pub fn sign_time(&self) -> i64 {
+        self.sign_time.unwrap_or(0)
+    }
+
+    pub fn clear_sign_time(&mut self) {
+        self.sign_time = ::std::option::Option::None;
+    }
+
+    pub fn has_sign_time(&self) -> bool {
+        self.sign_time.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sign_time(&mut self, v: i64) {
+        self.sign_time = ::std::option::Option::Some(v);
+    }
+
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(18);
+        let mut fields = ::std::vec::Vec::with_capacity(19);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
             "subject",
@@ -4599,6 +4620,11 @@ impl Signature {
             |m: &Signature| { &m.countersignatures },
             |m: &mut Signature| { &mut m.countersignatures },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "sign_time",
+            |m: &Signature| { &m.sign_time },
+            |m: &mut Signature| { &mut m.sign_time },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Signature>(
             "Signature",
             fields,
@@ -4671,6 +4697,9 @@ impl ::protobuf::Message for Signature {
                 146 => {
                     self.countersignatures.push(is.read_message()?);
                 },
+                152 => {
+                    self.sign_time = ::std::option::Option::Some(is.read_int64()?);
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -4740,6 +4769,9 @@ impl ::protobuf::Message for Signature {
             let len = value.compute_size();
             my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         };
+        if let Some(v) = self.sign_time {
+            my_size += ::protobuf::rt::int64_size(19, v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -4800,6 +4832,9 @@ impl ::protobuf::Message for Signature {
         for v in &self.countersignatures {
             ::protobuf::rt::write_message_field_with_cached_size(18, v, os)?;
         };
+        if let Some(v) = self.sign_time {
+            os.write_int64(19, v)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -4835,6 +4870,7 @@ impl ::protobuf::Message for Signature {
         self.signer_info.clear();
         self.certificates.clear();
         self.countersignatures.clear();
+        self.sign_time = ::std::option::Option::None;
         self.special_fields.clear();
     }
 
@@ -4858,6 +4894,7 @@ impl ::protobuf::Message for Signature {
             signer_info: ::protobuf::MessageField::none(),
             certificates: ::std::vec::Vec::new(),
             countersignatures: ::std::vec::Vec::new(),
+            sign_time: ::std::option::Option::None,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -5242,6 +5279,14 @@ pub struct Certificate {
     pub not_before: ::std::option::Option<i64>,
     // @@protoc_insertion_point(field:pe.Certificate.not_after)
     pub not_after: ::std::option::Option<i64>,
+    // @@protoc_insertion_point(field:pe.Certificate.key_usage)
+    pub key_usage: ::std::option::Option<u32>,
+    // @@protoc_insertion_point(field:pe.Certificate.extended_key_usage)
+    pub extended_key_usage: ::std::option::Option<u32>,
+    // @@protoc_insertion_point(field:pe.Certificate.extended_key_usage_oids)
+    pub extended_key_usage_oids: ::std::vec::Vec<::std::string::String>,
+    // @@protoc_insertion_point(field:pe.Certificate.subject_alternative_names)
+    pub subject_alternative_names: ::std::vec::Vec<::std::string::String>,
     // special fields
     // @@protoc_insertion_point(special_field:pe.Certificate.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -5531,8 +5576,46 @@ impl Certificate {
         self.not_after = ::std::option::Option::Some(v);
     }
 
+    // optional uint32 key_usage = 10;
+
+    pub fn key_usage(&self) -> u32 {
+        self.key_usage.unwrap_or(0)
+    }
+
+    pub fn clear_key_usage(&mut self) {
+        self.key_usage = ::std::option::Option::None;
+    }
+
+    pub fn has_key_usage(&self) -> bool {
+        self.key_usage.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_usage(&mut self, v: u32) {
+        self.key_usage = ::std::option::Option::Some(v);
+    }
+
+    // optional uint32 extended_key_usage = 11;
+
+    pub fn extended_key_usage(&self) -> u32 {
+        self.extended_key_usage.unwrap_or(0)
+    }
+
+    pub fn clear_extended_key_usage(&mut self) {
+        self.extended_key_usage = ::std::option::Option::None;
+    }
+
+    pub fn has_extended_key_usage(&self) -> bool {
+        self.extended_key_usage.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_extended_key_usage(&mut self, v: u32) {
+        self.extended_key_usage = ::std::option::Option::Some(v);
+    }
+
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(9);
+        let mut fields = ::std::vec::Vec::with_capacity(13);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
             "issuer",
@@ -5579,6 +5662,26 @@ impl Certificate {
             |m: &Certificate| { &m.not_after },
             |m: &mut Certificate| { &mut m.not_after },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "key_usage",
+            |m: &Certificate| { &m.key_usage },
+            |m: &mut Certificate| { &mut m.key_usage },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "extended_key_usage",
+            |m: &Certificate| { &m.extended_key_usage },
+            |m: &mut Certificate| { &mut m.extended_key_usage },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "extended_key_usage_oids",
+            |m: &Certificate| { &m.extended_key_usage_oids },
+            |m: &mut Certificate| { &mut m.extended_key_usage_oids },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "subject_alternative_names",
+            |m: &Certificate| { &m.subject_alternative_names },
+            |m: &mut Certificate| { &mut m.subject_alternative_names },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Certificate>(
             "Certificate",
             fields,
@@ -5624,6 +5727,18 @@ impl ::protobuf::Message for Certificate {
                 72 => {
                     self.not_after = ::std::option::Option::Some(is.read_int64()?);
                 },
+                80 => {
+                    self.key_usage = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                88 => {
+                    self.extended_key_usage = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                98 => {
+                    self.extended_key_usage_oids.push(is.read_string()?);
+                },
+                106 => {
+                    self.subject_alternative_names.push(is.read_string()?);
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -5663,6 +5778,18 @@ impl ::protobuf::Message for Certificate {
         if let Some(v) = self.not_after {
             my_size += ::protobuf::rt::int64_size(9, v);
         }
+        if let Some(v) = self.key_usage {
+            my_size += ::protobuf::rt::uint32_size(10, v);
+        }
+        if let Some(v) = self.extended_key_usage {
+            my_size += ::protobuf::rt::uint32_size(11, v);
+        }
+        for value in &self.extended_key_usage_oids {
+            my_size += ::protobuf::rt::string_size(12, &value);
+        };
+        for value in &self.subject_alternative_names {
+            my_size += ::protobuf::rt::string_size(13, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -5696,6 +5823,18 @@ impl ::protobuf::Message for Certificate {
         if let Some(v) = self.not_after {
             os.write_int64(9, v)?;
         }
+        if let Some(v) = self.key_usage {
+            os.write_uint32(10, v)?;
+        }
+        if let Some(v) = self.extended_key_usage {
+            os.write_uint32(11, v)?;
+        }
+        for v in &self.extended_key_usage_oids {
+            os.write_string(12, &v)?;
+        };
+        for v in &self.subject_alternative_names {
+            os.write_string(13, &v)?;
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -5722,6 +5861,10 @@ impl ::protobuf::Message for Certificate {
         self.serial = ::std::option::Option::None;
         self.not_before = ::std::option::Option::None;
         self.not_after = ::std::option::Option::None;
+        self.key_usage = ::std::option::Option::None;
+        self.extended_key_usage = ::std::option::Option::None;
+        self.extended_key_usage_oids.clear();
+        self.subject_alternative_names.clear();
         self.special_fields.clear();
     }
 
@@ -5736,6 +5879,10 @@ impl ::protobuf::Message for Certificate {
             serial: ::std::option::Option::None,
             not_before: ::std::option::Option::None,
             not_after: ::std::option::Option::None,
+            key_usage: ::std::option::Option::None,
+            extended_key_usage: ::std::option::Option::None,
+            extended_key_usage_oids: ::std::vec::Vec::new(),
+            subject_alternative_names: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -6090,6 +6237,8 @@ pub struct Section {
     pub number_of_relocations: ::std::option::Option<u32>,
     // @@protoc_insertion_point(field:pe.Section.number_of_line_numbers)
     pub number_of_line_numbers: ::std::option::Option<u32>,
+    // @@protoc_insertion_point(field:pe.Section.entropy)
+    pub entropy: ::std::option::Option<f64>,
     // special fields
     // @@protoc_insertion_point(special_field:pe.Section.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -6349,8 +6498,27 @@ impl Section {
         self.number_of_line_numbers = ::std::option::Option::Some(v);
     }
 
+    // optional double entropy = 12;
+
+    pub fn entropy(&self) -> f64 {
+        self.entropy.unwrap_or(0.)
+    }
+
+    pub fn clear_entropy(&mut self) {
+        self.entropy = ::std::option::Option::None;
+    }
+
+    pub fn has_entropy(&self) -> bool {
+        self.entropy.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_entropy(&mut self, v: f64) {
+        self.entropy = ::std::option::Option::Some(v);
+    }
+
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(11);
+        let mut fields = ::std::vec::Vec::with_capacity(12);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
             "name",
@@ -6407,6 +6575,11 @@ impl Section {
             |m: &Section| { &m.number_of_line_numbers },
             |m: &mut Section| { &mut m.number_of_line_numbers },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "entropy",
+            |m: &Section| { &m.entropy },
+            |m: &mut Section| { &mut m.entropy },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Section>(
             "Section",
             fields,
@@ -6491,6 +6664,9 @@ impl ::protobuf::Message for Section {
                 88 => {
                     self.number_of_line_numbers = ::std::option::Option::Some(is.read_uint32()?);
                 },
+                97 => {
+                    self.entropy = ::std::option::Option::Some(is.read_double()?);
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -6536,6 +6712,9 @@ impl ::protobuf::Message for Section {
         if let Some(v) = self.number_of_line_numbers {
             my_size += ::protobuf::rt::uint32_size(11, v);
         }
+        if let Some(v) = self.entropy {
+            my_size += 1 + 8;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -6575,6 +6754,9 @@ impl ::protobuf::Message for Section {
         if let Some(v) = self.number_of_line_numbers {
             os.write_uint32(11, v)?;
         }
+        if let Some(v) = self.entropy {
+            os.write_double(12, v)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -6603,6 +6785,7 @@ impl ::protobuf::Message for Section {
         self.pointer_to_line_numbers = ::std::option::Option::None;
         self.number_of_relocations = ::std::option::Option::None;
         self.number_of_line_numbers = ::std::option::Option::None;
+        self.entropy = ::std::option::Option::None;
         self.special_fields.clear();
     }
 
@@ -6619,6 +6802,7 @@ impl ::protobuf::Message for Section {
             pointer_to_line_numbers: ::std::option::Option::None,
             number_of_relocations: ::std::option::Option::None,
             number_of_line_numbers: ::std::option::Option::None,
+            entropy: ::std::option::Option::None,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -8712,6 +8896,234 @@ impl DllCharacteristics {
     }
 }
 
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:pe.KeyUsage)
+pub enum KeyUsage {
+    // @@protoc_insertion_point(enum_value:pe.KeyUsage.KEY_USAGE_DIGITAL_SIGNATURE)
+    KEY_USAGE_DIGITAL_SIGNATURE = 1,
+    // @@protoc_insertion_point(enum_value:pe.KeyUsage.KEY_USAGE_NON_REPUDIATION)
+    KEY_USAGE_NON_REPUDIATION = 2,
+    // @@protoc_insertion_point(enum_value:pe.KeyUsage.KEY_USAGE_KEY_ENCIPHERMENT)
+    KEY_USAGE_KEY_ENCIPHERMENT = 4,
+    // @@protoc_insertion_point(enum_value:pe.KeyUsage.KEY_USAGE_DATA_ENCIPHERMENT)
+    KEY_USAGE_DATA_ENCIPHERMENT = 8,
+    // @@protoc_insertion_point(enum_value:pe.KeyUsage.KEY_USAGE_KEY_AGREEMENT)
+    KEY_USAGE_KEY_AGREEMENT = 16,
+    // @@protoc_insertion_point(enum_value:pe.KeyUsage.KEY_USAGE_KEY_CERT_SIGN)
+    KEY_USAGE_KEY_CERT_SIGN = 32,
+    // @@protoc_insertion_point(enum_value:pe.KeyUsage.KEY_USAGE_CRL_SIGN)
+    KEY_USAGE_CRL_SIGN = 64,
+    // @@protoc_insertion_point(enum_value:pe.KeyUsage.KEY_USAGE_ENCIPHER_ONLY)
+    KEY_USAGE_ENCIPHER_ONLY = 128,
+    // @@protoc_insertion_point(enum_value:pe.KeyUsage.KEY_USAGE_DECIPHER_ONLY)
+    KEY_USAGE_DECIPHER_ONLY = 256,
+}
+
+impl ::protobuf::Enum for KeyUsage {
+    const NAME: &'static str = "KeyUsage";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<KeyUsage> {
+        match value {
+            1 => ::std::option::Option::Some(KeyUsage::KEY_USAGE_DIGITAL_SIGNATURE),
+            2 => ::std::option::Option::Some(KeyUsage::KEY_USAGE_NON_REPUDIATION),
+            4 => ::std::option::Option::Some(KeyUsage::KEY_USAGE_KEY_ENCIPHERMENT),
+            8 => ::std::option::Option::Some(KeyUsage::KEY_USAGE_DATA_ENCIPHERMENT),
+            16 => ::std::option::Option::Some(KeyUsage::KEY_USAGE_KEY_AGREEMENT),
+            32 => ::std::option::Option::Some(KeyUsage::KEY_USAGE_KEY_CERT_SIGN),
+            64 => ::std::option::Option::Some(KeyUsage::KEY_USAGE_CRL_SIGN),
+            128 => ::std::option::Option::Some(KeyUsage::KEY_USAGE_ENCIPHER_ONLY),
+            256 => ::std::option::Option::Some(KeyUsage::KEY_USAGE_DECIPHER_ONLY),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<KeyUsage> {
+        match str {
+            "KEY_USAGE_DIGITAL_SIGNATURE" => ::std::option::Option::Some(KeyUsage::KEY_USAGE_DIGITAL_SIGNATURE),
+            "KEY_USAGE_NON_REPUDIATION" => ::std::option::Option::Some(KeyUsage::KEY_USAGE_NON_REPUDIATION),
+            "KEY_USAGE_KEY_ENCIPHERMENT" => ::std::option::Option::Some(KeyUsage::KEY_USAGE_KEY_ENCIPHERMENT),
+            "KEY_USAGE_DATA_ENCIPHERMENT" => ::std::option::Option::Some(KeyUsage::KEY_USAGE_DATA_ENCIPHERMENT),
+            "KEY_USAGE_KEY_AGREEMENT" => ::std::option::Option::Some(KeyUsage::KEY_USAGE_KEY_AGREEMENT),
+            "KEY_USAGE_KEY_CERT_SIGN" => ::std::option::Option::Some(KeyUsage::KEY_USAGE_KEY_CERT_SIGN),
+            "KEY_USAGE_CRL_SIGN" => ::std::option::Option::Some(KeyUsage::KEY_USAGE_CRL_SIGN),
+            "KEY_USAGE_ENCIPHER_ONLY" => ::std::option::Option::Some(KeyUsage::KEY_USAGE_ENCIPHER_ONLY),
+            "KEY_USAGE_DECIPHER_ONLY" => ::std::option::Option::Some(KeyUsage::KEY_USAGE_DECIPHER_ONLY),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [KeyUsage] = &[
+        KeyUsage::KEY_USAGE_DIGITAL_SIGNATURE,
+        KeyUsage::KEY_USAGE_NON_REPUDIATION,
+        KeyUsage::KEY_USAGE_KEY_ENCIPHERMENT,
+        KeyUsage::KEY_USAGE_DATA_ENCIPHERMENT,
+        KeyUsage::KEY_USAGE_KEY_AGREEMENT,
+        KeyUsage::KEY_USAGE_KEY_CERT_SIGN,
+        KeyUsage::KEY_USAGE_CRL_SIGN,
+        KeyUsage::KEY_USAGE_ENCIPHER_ONLY,
+        KeyUsage::KEY_USAGE_DECIPHER_ONLY,
+    ];
+}
+
+impl ::protobuf::EnumFull for KeyUsage {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("KeyUsage").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = match self {
+            KeyUsage::KEY_USAGE_DIGITAL_SIGNATURE => 0,
+            KeyUsage::KEY_USAGE_NON_REPUDIATION => 1,
+            KeyUsage::KEY_USAGE_KEY_ENCIPHERMENT => 2,
+            KeyUsage::KEY_USAGE_DATA_ENCIPHERMENT => 3,
+            KeyUsage::KEY_USAGE_KEY_AGREEMENT => 4,
+            KeyUsage::KEY_USAGE_KEY_CERT_SIGN => 5,
+            KeyUsage::KEY_USAGE_CRL_SIGN => 6,
+            KeyUsage::KEY_USAGE_ENCIPHER_ONLY => 7,
+            KeyUsage::KEY_USAGE_DECIPHER_ONLY => 8,
+        };
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+// Note, `Default` is implemented although default value is not 0
+impl ::std::default::Default for KeyUsage {
+    fn default() -> Self {
+        KeyUsage::KEY_USAGE_DIGITAL_SIGNATURE
+    }
+}
+
+impl KeyUsage {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<KeyUsage>("KeyUsage")
+    }
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:pe.ExtendedKeyUsage)
+pub enum ExtendedKeyUsage {
+    // @@protoc_insertion_point(enum_value:pe.ExtendedKeyUsage.EKU_ANY)
+    EKU_ANY = 1,
+    // @@protoc_insertion_point(enum_value:pe.ExtendedKeyUsage.EKU_SERVER_AUTH)
+    EKU_SERVER_AUTH = 2,
+    // @@protoc_insertion_point(enum_value:pe.ExtendedKeyUsage.EKU_CLIENT_AUTH)
+    EKU_CLIENT_AUTH = 4,
+    // @@protoc_insertion_point(enum_value:pe.ExtendedKeyUsage.EKU_CODE_SIGNING)
+    EKU_CODE_SIGNING = 8,
+    // @@protoc_insertion_point(enum_value:pe.ExtendedKeyUsage.EKU_EMAIL_PROTECTION)
+    EKU_EMAIL_PROTECTION = 16,
+    // @@protoc_insertion_point(enum_value:pe.ExtendedKeyUsage.EKU_TIME_STAMPING)
+    EKU_TIME_STAMPING = 32,
+    // @@protoc_insertion_point(enum_value:pe.ExtendedKeyUsage.EKU_OCSP_SIGNING)
+    EKU_OCSP_SIGNING = 64,
+    // @@protoc_insertion_point(enum_value:pe.ExtendedKeyUsage.EKU_SPC_INDIVIDUAL_SP_KEY_PURPOSE)
+    EKU_SPC_INDIVIDUAL_SP_KEY_PURPOSE = 128,
+    // @@protoc_insertion_point(enum_value:pe.ExtendedKeyUsage.EKU_SPC_COMMERCIAL_SP_KEY_PURPOSE)
+    EKU_SPC_COMMERCIAL_SP_KEY_PURPOSE = 256,
+    // @@protoc_insertion_point(enum_value:pe.ExtendedKeyUsage.EKU_LIFETIME_SIGNING)
+    EKU_LIFETIME_SIGNING = 512,
+    // @@protoc_insertion_point(enum_value:pe.ExtendedKeyUsage.EKU_KERNEL_MODE_CODE_SIGNING)
+    EKU_KERNEL_MODE_CODE_SIGNING = 1024,
+}
+
+impl ::protobuf::Enum for ExtendedKeyUsage {
+    const NAME: &'static str = "ExtendedKeyUsage";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<ExtendedKeyUsage> {
+        match value {
+            1 => ::std::option::Option::Some(ExtendedKeyUsage::EKU_ANY),
+            2 => ::std::option::Option::Some(ExtendedKeyUsage::EKU_SERVER_AUTH),
+            4 => ::std::option::Option::Some(ExtendedKeyUsage::EKU_CLIENT_AUTH),
+            8 => ::std::option::Option::Some(ExtendedKeyUsage::EKU_CODE_SIGNING),
+            16 => ::std::option::Option::Some(ExtendedKeyUsage::EKU_EMAIL_PROTECTION),
+            32 => ::std::option::Option::Some(ExtendedKeyUsage::EKU_TIME_STAMPING),
+            64 => ::std::option::Option::Some(ExtendedKeyUsage::EKU_OCSP_SIGNING),
+            128 => ::std::option::Option::Some(ExtendedKeyUsage::EKU_SPC_INDIVIDUAL_SP_KEY_PURPOSE),
+            256 => ::std::option::Option::Some(ExtendedKeyUsage::EKU_SPC_COMMERCIAL_SP_KEY_PURPOSE),
+            512 => ::std::option::Option::Some(ExtendedKeyUsage::EKU_LIFETIME_SIGNING),
+            1024 => ::std::option::Option::Some(ExtendedKeyUsage::EKU_KERNEL_MODE_CODE_SIGNING),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<ExtendedKeyUsage> {
+        match str {
+            "EKU_ANY" => ::std::option::Option::Some(ExtendedKeyUsage::EKU_ANY),
+            "EKU_SERVER_AUTH" => ::std::option::Option::Some(ExtendedKeyUsage::EKU_SERVER_AUTH),
+            "EKU_CLIENT_AUTH" => ::std::option::Option::Some(ExtendedKeyUsage::EKU_CLIENT_AUTH),
+            "EKU_CODE_SIGNING" => ::std::option::Option::Some(ExtendedKeyUsage::EKU_CODE_SIGNING),
+            "EKU_EMAIL_PROTECTION" => ::std::option::Option::Some(ExtendedKeyUsage::EKU_EMAIL_PROTECTION),
+            "EKU_TIME_STAMPING" => ::std::option::Option::Some(ExtendedKeyUsage::EKU_TIME_STAMPING),
+            "EKU_OCSP_SIGNING" => ::std::option::Option::Some(ExtendedKeyUsage::EKU_OCSP_SIGNING),
+            "EKU_SPC_INDIVIDUAL_SP_KEY_PURPOSE" => ::std::option::Option::Some(ExtendedKeyUsage::EKU_SPC_INDIVIDUAL_SP_KEY_PURPOSE),
+            "EKU_SPC_COMMERCIAL_SP_KEY_PURPOSE" => ::std::option::Option::Some(ExtendedKeyUsage::EKU_SPC_COMMERCIAL_SP_KEY_PURPOSE),
+            "EKU_LIFETIME_SIGNING" => ::std::option::Option::Some(ExtendedKeyUsage::EKU_LIFETIME_SIGNING),
+            "EKU_KERNEL_MODE_CODE_SIGNING" => ::std::option::Option::Some(ExtendedKeyUsage::EKU_KERNEL_MODE_CODE_SIGNING),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [ExtendedKeyUsage] = &[
+        ExtendedKeyUsage::EKU_ANY,
+        ExtendedKeyUsage::EKU_SERVER_AUTH,
+        ExtendedKeyUsage::EKU_CLIENT_AUTH,
+        ExtendedKeyUsage::EKU_CODE_SIGNING,
+        ExtendedKeyUsage::EKU_EMAIL_PROTECTION,
+        ExtendedKeyUsage::EKU_TIME_STAMPING,
+        ExtendedKeyUsage::EKU_OCSP_SIGNING,
+        ExtendedKeyUsage::EKU_SPC_INDIVIDUAL_SP_KEY_PURPOSE,
+        ExtendedKeyUsage::EKU_SPC_COMMERCIAL_SP_KEY_PURPOSE,
+        ExtendedKeyUsage::EKU_LIFETIME_SIGNING,
+        ExtendedKeyUsage::EKU_KERNEL_MODE_CODE_SIGNING,
+    ];
+}
+
+impl ::protobuf::EnumFull for ExtendedKeyUsage {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("ExtendedKeyUsage").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = match self {
+            ExtendedKeyUsage::EKU_ANY => 0,
+            ExtendedKeyUsage::EKU_SERVER_AUTH => 1,
+            ExtendedKeyUsage::EKU_CLIENT_AUTH => 2,
+            ExtendedKeyUsage::EKU_CODE_SIGNING => 3,
+            ExtendedKeyUsage::EKU_EMAIL_PROTECTION => 4,
+            ExtendedKeyUsage::EKU_TIME_STAMPING => 5,
+            ExtendedKeyUsage::EKU_OCSP_SIGNING => 6,
+            ExtendedKeyUsage::EKU_SPC_INDIVIDUAL_SP_KEY_PURPOSE => 7,
+            ExtendedKeyUsage::EKU_SPC_COMMERCIAL_SP_KEY_PURPOSE => 8,
+            ExtendedKeyUsage::EKU_LIFETIME_SIGNING => 9,
+            ExtendedKeyUsage::EKU_KERNEL_MODE_CODE_SIGNING => 10,
+        };
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+// Note, `Default` is implemented although default value is not 0
+impl ::std::default::Default for ExtendedKeyUsage {
+    fn default() -> Self {
+        ExtendedKeyUsage::EKU_ANY
+    }
+}
+
+impl ExtendedKeyUsage {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<ExtendedKeyUsage>("ExtendedKeyUsage")
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x08pe.proto\x12\x02pe\x1a\nyara.proto\"\x9f\x1a\n\x02PE\x12\x13\n\x05\
     is_pe\x18\x01\x20\x02(\x08R\x04isPe\x12%\n\x07machine\x18\x02\x20\x01(\
@@ -8813,7 +9225,7 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     ard_name\x18\x05\x20\x01(\tR\x0bforwardName\"S\n\x08Function\x12\x12\n\
     \x04name\x18\x01\x20\x01(\tR\x04name\x12\x18\n\x07ordinal\x18\x02\x20\
     \x01(\rR\x07ordinal\x12\x19\n\x03rva\x18\x03\x20\x02(\rR\x03rvaB\x07\x82\
-    \x93\x19\x03*\x01x\"\xb4\x05\n\tSignature\x12\x18\n\x07subject\x18\x01\
+    \x93\x19\x03*\x01x\"\xda\x05\n\tSignature\x12\x18\n\x07subject\x18\x01\
     \x20\x01(\tR\x07subject\x12\x16\n\x06issuer\x18\x02\x20\x01(\tR\x06issue\
     r\x12\x1e\n\nthumbprint\x18\x03\x20\x01(\tR\nthumbprint\x12\x18\n\x07ver\
     sion\x18\x04\x20\x01(\x03R\x07version\x12\x1c\n\talgorithm\x18\x05\x20\
@@ -8829,25 +9241,31 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     Countersignatures\x12/\n\x0bsigner_info\x18\x10\x20\x01(\x0b2\x0e.pe.Sig\
     nerInfoR\nsignerInfo\x123\n\x0ccertificates\x18\x11\x20\x03(\x0b2\x0f.pe\
     .CertificateR\x0ccertificates\x12B\n\x11countersignatures\x18\x12\x20\
-    \x03(\x0b2\x14.pe.CounterSignatureR\x11countersignatures\"\xaa\x01\n\nSi\
-    gnerInfo\x12!\n\x0cprogram_name\x18\x01\x20\x01(\tR\x0bprogramName\x12\
-    \x1b\n\tmore_info\x18\x02\x20\x01(\tR\x08moreInfo\x12\x16\n\x06digest\
-    \x18\x03\x20\x01(\tR\x06digest\x12\x1d\n\ndigest_alg\x18\x04\x20\x01(\tR\
-    \tdigestAlg\x12%\n\x05chain\x18\x05\x20\x03(\x0b2\x0f.pe.CertificateR\
-    \x05chain\"\xa2\x02\n\x0bCertificate\x12\x16\n\x06issuer\x18\x01\x20\x01\
-    (\tR\x06issuer\x12\x18\n\x07subject\x18\x02\x20\x01(\tR\x07subject\x12\
-    \x1e\n\nthumbprint\x18\x03\x20\x01(\tR\nthumbprint\x12\x18\n\x07version\
-    \x18\x04\x20\x01(\x03R\x07version\x12\x1c\n\talgorithm\x18\x05\x20\x01(\
-    \tR\talgorithm\x12#\n\ralgorithm_oid\x18\x06\x20\x01(\tR\x0calgorithmOid\
-    \x12\x16\n\x06serial\x18\x07\x20\x01(\tR\x06serial\x12&\n\nnot_before\
-    \x18\x08\x20\x01(\x03R\tnotBeforeB\x07\x82\x93\x19\x03*\x01t\x12$\n\tnot\
-    _after\x18\t\x20\x01(\x03R\x08notAfterB\x07\x82\x93\x19\x03*\x01t\"\xb2\
-    \x01\n\x10CounterSignature\x12\x1a\n\x08verified\x18\x01\x20\x01(\x08R\
-    \x08verified\x12$\n\tsign_time\x18\x02\x20\x01(\x03R\x08signTimeB\x07\
-    \x82\x93\x19\x03*\x01t\x12\x16\n\x06digest\x18\x0c\x20\x01(\tR\x06digest\
-    \x12\x1d\n\ndigest_alg\x18\x03\x20\x01(\tR\tdigestAlg\x12%\n\x05chain\
-    \x18\x04\x20\x03(\x0b2\x0f.pe.CertificateR\x05chain\"\xac\x04\n\x07Secti\
-    on\x12\x12\n\x04name\x18\x01\x20\x02(\x0cR\x04name\x12\x1b\n\tfull_name\
+    \x03(\x0b2\x14.pe.CounterSignatureR\x11countersignatures\x12$\n\tsign_ti\
+    me\x18\x13\x20\x01(\x03R\x08signTimeB\x07\x82\x93\x19\x03*\x01t\"\xaa\
+    \x01\n\nSignerInfo\x12!\n\x0cprogram_name\x18\x01\x20\x01(\tR\x0bprogram\
+    Name\x12\x1b\n\tmore_info\x18\x02\x20\x01(\tR\x08moreInfo\x12\x16\n\x06d\
+    igest\x18\x03\x20\x01(\tR\x06digest\x12\x1d\n\ndigest_alg\x18\x04\x20\
+    \x01(\tR\tdigestAlg\x12%\n\x05chain\x18\x05\x20\x03(\x0b2\x0f.pe.Certifi\
+    cateR\x05chain\"\x94\x04\n\x0bCertificate\x12\x16\n\x06issuer\x18\x01\
+    \x20\x01(\tR\x06issuer\x12\x18\n\x07subject\x18\x02\x20\x01(\tR\x07subje\
+    ct\x12\x1e\n\nthumbprint\x18\x03\x20\x01(\tR\nthumbprint\x12\x18\n\x07ve\
+    rsion\x18\x04\x20\x01(\x03R\x07version\x12\x1c\n\talgorithm\x18\x05\x20\
+    \x01(\tR\talgorithm\x12#\n\ralgorithm_oid\x18\x06\x20\x01(\tR\x0calgorit\
+    hmOid\x12\x16\n\x06serial\x18\x07\x20\x01(\tR\x06serial\x12&\n\nnot_befo\
+    re\x18\x08\x20\x01(\x03R\tnotBeforeB\x07\x82\x93\x19\x03*\x01t\x12$\n\tn\
+    ot_after\x18\t\x20\x01(\x03R\x08notAfterB\x07\x82\x93\x19\x03*\x01t\x121\
+    \n\tkey_usage\x18\n\x20\x01(\rR\x08keyUsageB\x14\x82\x93\x19\x10*\x0efla\
+    gs:KeyUsage\x12J\n\x12extended_key_usage\x18\x0b\x20\x01(\rR\x10extended\
+    KeyUsageB\x1c\x82\x93\x19\x18*\x16flags:ExtendedKeyUsage\x125\n\x17exten\
+    ded_key_usage_oids\x18\x0c\x20\x03(\tR\x14extendedKeyUsageOids\x12:\n\
+    \x19subject_alternative_names\x18\r\x20\x03(\tR\x17subjectAlternativeNam\
+    es\"\xb2\x01\n\x10CounterSignature\x12\x1a\n\x08verified\x18\x01\x20\x01\
+    (\x08R\x08verified\x12$\n\tsign_time\x18\x02\x20\x01(\x03R\x08signTimeB\
+    \x07\x82\x93\x19\x03*\x01t\x12\x16\n\x06digest\x18\x0c\x20\x01(\tR\x06di\
+    gest\x12\x1d\n\ndigest_alg\x18\x03\x20\x01(\tR\tdigestAlg\x12%\n\x05chai\
+    n\x18\x04\x20\x03(\x0b2\x0f.pe.CertificateR\x05chain\"\xce\x04\n\x07Sect\
+    ion\x12\x12\n\x04name\x18\x01\x20\x02(\x0cR\x04name\x12\x1b\n\tfull_name\
     \x18\x02\x20\x02(\x0cR\x08fullName\x12L\n\x0fcharacteristics\x18\x03\x20\
     \x02(\rR\x0fcharacteristicsB\"\x82\x93\x19\x1e*\x1cflags:SectionCharacte\
     ristics\x12+\n\rraw_data_size\x18\x04\x20\x02(\rR\x0brawDataSizeB\x07\
@@ -8860,133 +9278,148 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x20\x02(\rR\x14pointerToLineNumbersB\x07\x82\x93\x19\x03*\x01x\x122\n\
     \x15number_of_relocations\x18\n\x20\x02(\rR\x13numberOfRelocations\x123\
     \n\x16number_of_line_numbers\x18\x0b\x20\x02(\rR\x13numberOfLineNumbers\
-    \"\xc1\x01\n\rRichSignature\x12\x1f\n\x06offset\x18\x01\x20\x02(\rR\x06o\
-    ffsetB\x07\x82\x93\x19\x03*\x01x\x12\x1f\n\x06length\x18\x02\x20\x02(\rR\
-    \x06lengthB\x07\x82\x93\x19\x03*\x01x\x12\x10\n\x03key\x18\x03\x20\x02(\
-    \rR\x03key\x12\x19\n\x08raw_data\x18\x04\x20\x02(\x0cR\x07rawData\x12\
-    \x1d\n\nclear_data\x18\x05\x20\x02(\x0cR\tclearData\x12\"\n\x05tools\x18\
-    \x06\x20\x03(\x0b2\x0c.pe.RichToolR\x05tools\"R\n\x08RichTool\x12\x16\n\
-    \x06toolid\x18\x01\x20\x02(\rR\x06toolid\x12\x18\n\x07version\x18\x02\
-    \x20\x02(\rR\x07version\x12\x14\n\x05times\x18\x03\x20\x02(\rR\x05times\
-    \"G\n\x07Overlay\x12\x1f\n\x06offset\x18\x01\x20\x02(\x04R\x06offsetB\
-    \x07\x82\x93\x19\x03*\x01x\x12\x1b\n\x04size\x18\x02\x20\x02(\x04R\x04si\
-    zeB\x07\x82\x93\x19\x03*\x01x*\xd0\x04\n\x0cResourceType\x12\x18\n\x14RE\
-    SOURCE_TYPE_CURSOR\x10\x01\x12\x18\n\x14RESOURCE_TYPE_BITMAP\x10\x02\x12\
-    \x16\n\x12RESOURCE_TYPE_ICON\x10\x03\x12\x16\n\x12RESOURCE_TYPE_MENU\x10\
-    \x04\x12\x18\n\x14RESOURCE_TYPE_DIALOG\x10\x05\x12\x18\n\x14RESOURCE_TYP\
-    E_STRING\x10\x06\x12\x19\n\x15RESOURCE_TYPE_FONTDIR\x10\x07\x12\x16\n\
-    \x12RESOURCE_TYPE_FONT\x10\x08\x12\x1d\n\x19RESOURCE_TYPE_ACCELERATOR\
-    \x10\t\x12\x18\n\x14RESOURCE_TYPE_RCDATA\x10\n\x12\x1e\n\x1aRESOURCE_TYP\
-    E_MESSAGETABLE\x10\x0b\x12\x1e\n\x1aRESOURCE_TYPE_GROUP_CURSOR\x10\x0c\
-    \x12\x1c\n\x18RESOURCE_TYPE_GROUP_ICON\x10\x0e\x12\x19\n\x15RESOURCE_TYP\
-    E_VERSION\x10\x10\x12\x1c\n\x18RESOURCE_TYPE_DLGINCLUDE\x10\x11\x12\x1a\
-    \n\x16RESOURCE_TYPE_PLUGPLAY\x10\x13\x12\x15\n\x11RESOURCE_TYPE_VXD\x10\
-    \x14\x12\x1b\n\x17RESOURCE_TYPE_ANICURSOR\x10\x15\x12\x19\n\x15RESOURCE_\
-    TYPE_ANIICON\x10\x16\x12\x16\n\x12RESOURCE_TYPE_HTML\x10\x17\x12\x1a\n\
-    \x16RESOURCE_TYPE_MANIFEST\x10\x18\x1a\x06\x92\x93\x19\x02\x10\x01*\xd1\
-    \x03\n\x07Machine\x12\x13\n\x0fMACHINE_UNKNOWN\x10\0\x12\x11\n\x0cMACHIN\
-    E_AM33\x10\xd3\x03\x12\x13\n\rMACHINE_AMD64\x10\xe4\x8c\x02\x12\x10\n\
-    \x0bMACHINE_ARM\x10\xc0\x03\x12\x12\n\rMACHINE_ARMNT\x10\xc4\x03\x12\x13\
-    \n\rMACHINE_ARM64\x10\xe4\xd4\x02\x12\x10\n\x0bMACHINE_EBC\x10\xbc\x1d\
-    \x12\x11\n\x0cMACHINE_I386\x10\xcc\x02\x12\x11\n\x0cMACHINE_IA64\x10\x80\
-    \x04\x12\x12\n\x0cMACHINE_M32R\x10\xc1\xa0\x02\x12\x13\n\x0eMACHINE_MIPS\
-    16\x10\xe6\x04\x12\x14\n\x0fMACHINE_MIPSFPU\x10\xe6\x06\x12\x16\n\x11MAC\
-    HINE_MIPSFPU16\x10\xe6\x08\x12\x14\n\x0fMACHINE_POWERPC\x10\xf0\x03\x12\
-    \x16\n\x11MACHINE_POWERPCFP\x10\xf1\x03\x12\x12\n\rMACHINE_R4000\x10\xe6\
-    \x02\x12\x10\n\x0bMACHINE_SH3\x10\xa2\x03\x12\x13\n\x0eMACHINE_SH3DSP\
-    \x10\xa3\x03\x12\x10\n\x0bMACHINE_SH4\x10\xa6\x03\x12\x10\n\x0bMACHINE_S\
-    H5\x10\xa8\x03\x12\x12\n\rMACHINE_THUMB\x10\xc2\x03\x12\x16\n\x11MACHINE\
-    _WCEMIPSV2\x10\xe9\x02\x1a\x06\x92\x93\x19\x02\x10\x01*\xa3\x03\n\tSubsy\
-    stem\x12\x15\n\x11SUBSYSTEM_UNKNOWN\x10\0\x12\x14\n\x10SUBSYSTEM_NATIVE\
-    \x10\x01\x12\x19\n\x15SUBSYSTEM_WINDOWS_GUI\x10\x02\x12\x19\n\x15SUBSYST\
-    EM_WINDOWS_CUI\x10\x03\x12\x15\n\x11SUBSYSTEM_OS2_CUI\x10\x05\x12\x17\n\
-    \x13SUBSYSTEM_POSIX_CUI\x10\x07\x12\x1c\n\x18SUBSYSTEM_NATIVE_WINDOWS\
-    \x10\x08\x12\x1c\n\x18SUBSYSTEM_WINDOWS_CE_GUI\x10\t\x12\x1d\n\x19SUBSYS\
-    TEM_EFI_APPLICATION\x10\n\x12%\n!SUBSYSTEM_EFI_BOOT_SERVICE_DRIVER\x10\
-    \x0b\x12\x20\n\x1cSUBSYSTEM_EFI_RUNTIME_DRIVER\x10\x0c\x12\x1b\n\x17SUBS\
-    YSTEM_EFI_ROM_IMAGE\x10\r\x12\x12\n\x0eSUBSYSTEM_XBOX\x10\x0e\x12&\n\"SU\
-    BSYSTEM_WINDOWS_BOOT_APPLICATION\x10\x10\x1a\x06\x92\x93\x19\x02\x10\x01\
-    *N\n\x0bImportFlags\x12\x13\n\x0fIMPORT_STANDARD\x10\x01\x12\x12\n\x0eIM\
-    PORT_DELAYED\x10\x02\x12\x0e\n\nIMPORT_ANY\x10\x03\x1a\x06\x92\x93\x19\
-    \x02\x10\x01*\xe2\x02\n\x0fCharacteristics\x12\x13\n\x0fRELOCS_STRIPPED\
-    \x10\x01\x12\x14\n\x10EXECUTABLE_IMAGE\x10\x02\x12\x16\n\x12LINE_NUMS_ST\
-    RIPPED\x10\x04\x12\x17\n\x13LOCAL_SYMS_STRIPPED\x10\x08\x12\x15\n\x11AGG\
-    RESIVE_WS_TRIM\x10\x10\x12\x17\n\x13LARGE_ADDRESS_AWARE\x10\x20\x12\x16\
-    \n\x11BYTES_REVERSED_LO\x10\x80\x01\x12\x12\n\rMACHINE_32BIT\x10\x80\x02\
-    \x12\x13\n\x0eDEBUG_STRIPPED\x10\x80\x04\x12\x1c\n\x17REMOVABLE_RUN_FROM\
-    _SWAP\x10\x80\x08\x12\x16\n\x11NET_RUN_FROM_SWAP\x10\x80\x10\x12\x0b\n\
-    \x06SYSTEM\x10\x80\x20\x12\x08\n\x03DLL\x10\x80@\x12\x14\n\x0eUP_SYSTEM_\
-    ONLY\x10\x80\x80\x01\x12\x17\n\x11BYTES_REVERSED_HI\x10\x80\x80\x02\x1a\
-    \x06\x92\x93\x19\x02\x10\x01*\x82\x01\n\rOptionalMagic\x12\"\n\x1dIMAGE_\
-    NT_OPTIONAL_HDR32_MAGIC\x10\x8b\x02\x12\"\n\x1dIMAGE_NT_OPTIONAL_HDR64_M\
-    AGIC\x10\x8b\x04\x12!\n\x1cIMAGE_ROM_OPTIONAL_HDR_MAGIC\x10\x87\x02\x1a\
-    \x06\x92\x93\x19\x02\x10\x01*\xe0\x05\n\x0eDirectoryEntry\x12(\n\x1cIMAG\
-    E_DIRECTORY_ENTRY_EXPORT\x10\0\x1a\x06\x9a\x93\x19\x02\x08\0\x12(\n\x1cI\
-    MAGE_DIRECTORY_ENTRY_IMPORT\x10\x01\x1a\x06\x9a\x93\x19\x02\x08\x01\x12*\
-    \n\x1eIMAGE_DIRECTORY_ENTRY_RESOURCE\x10\x02\x1a\x06\x9a\x93\x19\x02\x08\
-    \x02\x12+\n\x1fIMAGE_DIRECTORY_ENTRY_EXCEPTION\x10\x03\x1a\x06\x9a\x93\
-    \x19\x02\x08\x03\x12*\n\x1eIMAGE_DIRECTORY_ENTRY_SECURITY\x10\x04\x1a\
-    \x06\x9a\x93\x19\x02\x08\x04\x12+\n\x1fIMAGE_DIRECTORY_ENTRY_BASERELOC\
-    \x10\x05\x1a\x06\x9a\x93\x19\x02\x08\x05\x12'\n\x1bIMAGE_DIRECTORY_ENTRY\
-    _DEBUG\x10\x06\x1a\x06\x9a\x93\x19\x02\x08\x06\x12+\n\x1fIMAGE_DIRECTORY\
-    _ENTRY_COPYRIGHT\x10\x07\x1a\x06\x9a\x93\x19\x02\x08\x07\x12.\n\"IMAGE_D\
-    IRECTORY_ENTRY_ARCHITECTURE\x10\x08\x1a\x06\x9a\x93\x19\x02\x08\x07\x12+\
-    \n\x1fIMAGE_DIRECTORY_ENTRY_GLOBALPTR\x10\t\x1a\x06\x9a\x93\x19\x02\x08\
-    \x08\x12%\n\x19IMAGE_DIRECTORY_ENTRY_TLS\x10\n\x1a\x06\x9a\x93\x19\x02\
-    \x08\t\x12-\n!IMAGE_DIRECTORY_ENTRY_LOAD_CONFIG\x10\x0b\x1a\x06\x9a\x93\
-    \x19\x02\x08\n\x12.\n\"IMAGE_DIRECTORY_ENTRY_BOUND_IMPORT\x10\x0c\x1a\
-    \x06\x9a\x93\x19\x02\x08\x0b\x12%\n\x19IMAGE_DIRECTORY_ENTRY_IAT\x10\r\
-    \x1a\x06\x9a\x93\x19\x02\x08\x0c\x12.\n\"IMAGE_DIRECTORY_ENTRY_DELAY_IMP\
-    ORT\x10\x0e\x1a\x06\x9a\x93\x19\x02\x08\r\x120\n$IMAGE_DIRECTORY_ENTRY_C\
-    OM_DESCRIPTOR\x10\x0f\x1a\x06\x9a\x93\x19\x02\x08\x0e\x1a\x06\x92\x93\
-    \x19\x02\x10\x01*\x81\n\n\x16SectionCharacteristics\x12\x1a\n\x0eSECTION\
-    _NO_PAD\x10\x01\x1a\x06\x9a\x93\x19\x02\x08\x08\x12\x1c\n\x10SECTION_CNT\
-    _CODE\x10\x02\x1a\x06\x9a\x93\x19\x02\x08\x20\x12(\n\x1cSECTION_CNT_INIT\
-    IALIZED_DATA\x10\x03\x1a\x06\x9a\x93\x19\x02\x08@\x12+\n\x1eSECTION_CNT_\
-    UNINITIALIZED_DATA\x10\x04\x1a\x07\x9a\x93\x19\x03\x08\x80\x01\x12\x1e\n\
-    \x11SECTION_LNK_OTHER\x10\x05\x1a\x07\x9a\x93\x19\x03\x08\x80\x02\x12\
-    \x1d\n\x10SECTION_LNK_INFO\x10\x06\x1a\x07\x9a\x93\x19\x03\x08\x80\x04\
-    \x12\x1f\n\x12SECTION_LNK_REMOVE\x10\x07\x1a\x07\x9a\x93\x19\x03\x08\x80\
-    \x10\x12\x1f\n\x12SECTION_LNK_COMDAT\x10\x08\x1a\x07\x9a\x93\x19\x03\x08\
-    \x80\x20\x12'\n\x19SECTION_NO_DEFER_SPEC_EXC\x10\t\x1a\x08\x9a\x93\x19\
-    \x04\x08\x80\x80\x01\x12\x1b\n\rSECTION_GPREL\x10\n\x1a\x08\x9a\x93\x19\
-    \x04\x08\x80\x80\x02\x12\"\n\x14SECTION_ALIGN_1BYTES\x10\x0b\x1a\x08\x9a\
-    \x93\x19\x04\x08\x80\x80@\x12#\n\x14SECTION_ALIGN_2BYTES\x10\x0c\x1a\t\
-    \x9a\x93\x19\x05\x08\x80\x80\x80\x01\x12#\n\x14SECTION_ALIGN_4BYTES\x10\
-    \r\x1a\t\x9a\x93\x19\x05\x08\x80\x80\xc0\x01\x12#\n\x14SECTION_ALIGN_8BY\
-    TES\x10\x0e\x1a\t\x9a\x93\x19\x05\x08\x80\x80\x80\x02\x12$\n\x15SECTION_\
-    ALIGN_16BYTES\x10\x0f\x1a\t\x9a\x93\x19\x05\x08\x80\x80\xc0\x02\x12$\n\
-    \x15SECTION_ALIGN_32BYTES\x10\x10\x1a\t\x9a\x93\x19\x05\x08\x80\x80\x80\
-    \x03\x12$\n\x15SECTION_ALIGN_64BYTES\x10\x11\x1a\t\x9a\x93\x19\x05\x08\
-    \x80\x80\xc0\x03\x12%\n\x16SECTION_ALIGN_128BYTES\x10\x12\x1a\t\x9a\x93\
-    \x19\x05\x08\x80\x80\x80\x04\x12%\n\x16SECTION_ALIGN_256BYTES\x10\x13\
-    \x1a\t\x9a\x93\x19\x05\x08\x80\x80\xc0\x04\x12%\n\x16SECTION_ALIGN_512BY\
-    TES\x10\x14\x1a\t\x9a\x93\x19\x05\x08\x80\x80\x80\x05\x12&\n\x17SECTION_\
-    ALIGN_1024BYTES\x10\x15\x1a\t\x9a\x93\x19\x05\x08\x80\x80\xc0\x05\x12&\n\
-    \x17SECTION_ALIGN_2048BYTES\x10\x16\x1a\t\x9a\x93\x19\x05\x08\x80\x80\
-    \x80\x06\x12&\n\x17SECTION_ALIGN_4096BYTES\x10\x17\x1a\t\x9a\x93\x19\x05\
-    \x08\x80\x80\xc0\x06\x12&\n\x17SECTION_ALIGN_8192BYTES\x10\x18\x1a\t\x9a\
-    \x93\x19\x05\x08\x80\x80\x80\x07\x12!\n\x12SECTION_ALIGN_MASK\x10\x19\
-    \x1a\t\x9a\x93\x19\x05\x08\x80\x80\xc0\x07\x12&\n\x17SECTION_LNK_NRELOC_\
-    OVFL\x10\x1a\x1a\t\x9a\x93\x19\x05\x08\x80\x80\x80\x08\x12&\n\x17SECTION\
-    _MEM_DISCARDABLE\x10\x1b\x1a\t\x9a\x93\x19\x05\x08\x80\x80\x80\x10\x12%\
-    \n\x16SECTION_MEM_NOT_CACHED\x10\x1c\x1a\t\x9a\x93\x19\x05\x08\x80\x80\
-    \x80\x20\x12$\n\x15SECTION_MEM_NOT_PAGED\x10\x1d\x1a\t\x9a\x93\x19\x05\
-    \x08\x80\x80\x80@\x12\"\n\x12SECTION_MEM_SHARED\x10\x1e\x1a\n\x9a\x93\
-    \x19\x06\x08\x80\x80\x80\x80\x01\x12#\n\x13SECTION_MEM_EXECUTE\x10\x1f\
-    \x1a\n\x9a\x93\x19\x06\x08\x80\x80\x80\x80\x02\x12\x20\n\x10SECTION_MEM_\
-    READ\x10\x20\x1a\n\x9a\x93\x19\x06\x08\x80\x80\x80\x80\x04\x12!\n\x11SEC\
-    TION_MEM_WRITE\x10!\x1a\n\x9a\x93\x19\x06\x08\x80\x80\x80\x80\x08\x12\
-    \x1f\n\x13SECTION_SCALE_INDEX\x10\"\x1a\x06\x9a\x93\x19\x02\x08\x01\x1a\
-    \x06\x92\x93\x19\x02\x10\x01*\xe8\x01\n\x12DllCharacteristics\x12\x13\n\
-    \x0fHIGH_ENTROPY_VA\x10\x20\x12\x10\n\x0cDYNAMIC_BASE\x10@\x12\x14\n\x0f\
-    FORCE_INTEGRITY\x10\x80\x01\x12\x0e\n\tNX_COMPAT\x10\x80\x02\x12\x11\n\
-    \x0cNO_ISOLATION\x10\x80\x04\x12\x0b\n\x06NO_SEH\x10\x80\x08\x12\x0c\n\
-    \x07NO_BIND\x10\x80\x10\x12\x11\n\x0cAPPCONTAINER\x10\x80\x20\x12\x0f\n\
-    \nWDM_DRIVER\x10\x80@\x12\x0e\n\x08GUARD_CF\x10\x80\x80\x01\x12\x1b\n\
-    \x15TERMINAL_SERVER_AWARE\x10\x80\x80\x02\x1a\x06\x92\x93\x19\x02\x10\
-    \x01B\x1e\xfa\x92\x19\x1a\n\x02pe\x12\x05pe.PE\x1a\x02pe\"\tpe-moduleb\
-    \x06proto2\
+    \x12\x20\n\x07entropy\x18\x0c\x20\x01(\x01R\x07entropyB\x06\x82\x93\x19\
+    \x028\x01\"\xc1\x01\n\rRichSignature\x12\x1f\n\x06offset\x18\x01\x20\x02\
+    (\rR\x06offsetB\x07\x82\x93\x19\x03*\x01x\x12\x1f\n\x06length\x18\x02\
+    \x20\x02(\rR\x06lengthB\x07\x82\x93\x19\x03*\x01x\x12\x10\n\x03key\x18\
+    \x03\x20\x02(\rR\x03key\x12\x19\n\x08raw_data\x18\x04\x20\x02(\x0cR\x07r\
+    awData\x12\x1d\n\nclear_data\x18\x05\x20\x02(\x0cR\tclearData\x12\"\n\
+    \x05tools\x18\x06\x20\x03(\x0b2\x0c.pe.RichToolR\x05tools\"R\n\x08RichTo\
+    ol\x12\x16\n\x06toolid\x18\x01\x20\x02(\rR\x06toolid\x12\x18\n\x07versio\
+    n\x18\x02\x20\x02(\rR\x07version\x12\x14\n\x05times\x18\x03\x20\x02(\rR\
+    \x05times\"G\n\x07Overlay\x12\x1f\n\x06offset\x18\x01\x20\x02(\x04R\x06o\
+    ffsetB\x07\x82\x93\x19\x03*\x01x\x12\x1b\n\x04size\x18\x02\x20\x02(\x04R\
+    \x04sizeB\x07\x82\x93\x19\x03*\x01x*\xd0\x04\n\x0cResourceType\x12\x18\n\
+    \x14RESOURCE_TYPE_CURSOR\x10\x01\x12\x18\n\x14RESOURCE_TYPE_BITMAP\x10\
+    \x02\x12\x16\n\x12RESOURCE_TYPE_ICON\x10\x03\x12\x16\n\x12RESOURCE_TYPE_\
+    MENU\x10\x04\x12\x18\n\x14RESOURCE_TYPE_DIALOG\x10\x05\x12\x18\n\x14RESO\
+    URCE_TYPE_STRING\x10\x06\x12\x19\n\x15RESOURCE_TYPE_FONTDIR\x10\x07\x12\
+    \x16\n\x12RESOURCE_TYPE_FONT\x10\x08\x12\x1d\n\x19RESOURCE_TYPE_ACCELERA\
+    TOR\x10\t\x12\x18\n\x14RESOURCE_TYPE_RCDATA\x10\n\x12\x1e\n\x1aRESOURCE_\
+    TYPE_MESSAGETABLE\x10\x0b\x12\x1e\n\x1aRESOURCE_TYPE_GROUP_CURSOR\x10\
+    \x0c\x12\x1c\n\x18RESOURCE_TYPE_GROUP_ICON\x10\x0e\x12\x19\n\x15RESOURCE\
+    _TYPE_VERSION\x10\x10\x12\x1c\n\x18RESOURCE_TYPE_DLGINCLUDE\x10\x11\x12\
+    \x1a\n\x16RESOURCE_TYPE_PLUGPLAY\x10\x13\x12\x15\n\x11RESOURCE_TYPE_VXD\
+    \x10\x14\x12\x1b\n\x17RESOURCE_TYPE_ANICURSOR\x10\x15\x12\x19\n\x15RESOU\
+    RCE_TYPE_ANIICON\x10\x16\x12\x16\n\x12RESOURCE_TYPE_HTML\x10\x17\x12\x1a\
+    \n\x16RESOURCE_TYPE_MANIFEST\x10\x18\x1a\x06\x92\x93\x19\x02\x10\x01*\
+    \xd1\x03\n\x07Machine\x12\x13\n\x0fMACHINE_UNKNOWN\x10\0\x12\x11\n\x0cMA\
+    CHINE_AM33\x10\xd3\x03\x12\x13\n\rMACHINE_AMD64\x10\xe4\x8c\x02\x12\x10\
+    \n\x0bMACHINE_ARM\x10\xc0\x03\x12\x12\n\rMACHINE_ARMNT\x10\xc4\x03\x12\
+    \x13\n\rMACHINE_ARM64\x10\xe4\xd4\x02\x12\x10\n\x0bMACHINE_EBC\x10\xbc\
+    \x1d\x12\x11\n\x0cMACHINE_I386\x10\xcc\x02\x12\x11\n\x0cMACHINE_IA64\x10\
+    \x80\x04\x12\x12\n\x0cMACHINE_M32R\x10\xc1\xa0\x02\x12\x13\n\x0eMACHINE_\
+    MIPS16\x10\xe6\x04\x12\x14\n\x0fMACHINE_MIPSFPU\x10\xe6\x06\x12\x16\n\
+    \x11MACHINE_MIPSFPU16\x10\xe6\x08\x12\x14\n\x0fMACHINE_POWERPC\x10\xf0\
+    \x03\x12\x16\n\x11MACHINE_POWERPCFP\x10\xf1\x03\x12\x12\n\rMACHINE_R4000\
+    \x10\xe6\x02\x12\x10\n\x0bMACHINE_SH3\x10\xa2\x03\x12\x13\n\x0eMACHINE_S\
+    H3DSP\x10\xa3\x03\x12\x10\n\x0bMACHINE_SH4\x10\xa6\x03\x12\x10\n\x0bMACH\
+    INE_SH5\x10\xa8\x03\x12\x12\n\rMACHINE_THUMB\x10\xc2\x03\x12\x16\n\x11MA\
+    CHINE_WCEMIPSV2\x10\xe9\x02\x1a\x06\x92\x93\x19\x02\x10\x01*\xa3\x03\n\t\
+    Subsystem\x12\x15\n\x11SUBSYSTEM_UNKNOWN\x10\0\x12\x14\n\x10SUBSYSTEM_NA\
+    TIVE\x10\x01\x12\x19\n\x15SUBSYSTEM_WINDOWS_GUI\x10\x02\x12\x19\n\x15SUB\
+    SYSTEM_WINDOWS_CUI\x10\x03\x12\x15\n\x11SUBSYSTEM_OS2_CUI\x10\x05\x12\
+    \x17\n\x13SUBSYSTEM_POSIX_CUI\x10\x07\x12\x1c\n\x18SUBSYSTEM_NATIVE_WIND\
+    OWS\x10\x08\x12\x1c\n\x18SUBSYSTEM_WINDOWS_CE_GUI\x10\t\x12\x1d\n\x19SUB\
+    SYSTEM_EFI_APPLICATION\x10\n\x12%\n!SUBSYSTEM_EFI_BOOT_SERVICE_DRIVER\
+    \x10\x0b\x12\x20\n\x1cSUBSYSTEM_EFI_RUNTIME_DRIVER\x10\x0c\x12\x1b\n\x17\
+    SUBSYSTEM_EFI_ROM_IMAGE\x10\r\x12\x12\n\x0eSUBSYSTEM_XBOX\x10\x0e\x12&\n\
+    \"SUBSYSTEM_WINDOWS_BOOT_APPLICATION\x10\x10\x1a\x06\x92\x93\x19\x02\x10\
+    \x01*N\n\x0bImportFlags\x12\x13\n\x0fIMPORT_STANDARD\x10\x01\x12\x12\n\
+    \x0eIMPORT_DELAYED\x10\x02\x12\x0e\n\nIMPORT_ANY\x10\x03\x1a\x06\x92\x93\
+    \x19\x02\x10\x01*\xe2\x02\n\x0fCharacteristics\x12\x13\n\x0fRELOCS_STRIP\
+    PED\x10\x01\x12\x14\n\x10EXECUTABLE_IMAGE\x10\x02\x12\x16\n\x12LINE_NUMS\
+    _STRIPPED\x10\x04\x12\x17\n\x13LOCAL_SYMS_STRIPPED\x10\x08\x12\x15\n\x11\
+    AGGRESIVE_WS_TRIM\x10\x10\x12\x17\n\x13LARGE_ADDRESS_AWARE\x10\x20\x12\
+    \x16\n\x11BYTES_REVERSED_LO\x10\x80\x01\x12\x12\n\rMACHINE_32BIT\x10\x80\
+    \x02\x12\x13\n\x0eDEBUG_STRIPPED\x10\x80\x04\x12\x1c\n\x17REMOVABLE_RUN_\
+    FROM_SWAP\x10\x80\x08\x12\x16\n\x11NET_RUN_FROM_SWAP\x10\x80\x10\x12\x0b\
+    \n\x06SYSTEM\x10\x80\x20\x12\x08\n\x03DLL\x10\x80@\x12\x14\n\x0eUP_SYSTE\
+    M_ONLY\x10\x80\x80\x01\x12\x17\n\x11BYTES_REVERSED_HI\x10\x80\x80\x02\
+    \x1a\x06\x92\x93\x19\x02\x10\x01*\x82\x01\n\rOptionalMagic\x12\"\n\x1dIM\
+    AGE_NT_OPTIONAL_HDR32_MAGIC\x10\x8b\x02\x12\"\n\x1dIMAGE_NT_OPTIONAL_HDR\
+    64_MAGIC\x10\x8b\x04\x12!\n\x1cIMAGE_ROM_OPTIONAL_HDR_MAGIC\x10\x87\x02\
+    \x1a\x06\x92\x93\x19\x02\x10\x01*\xe0\x05\n\x0eDirectoryEntry\x12(\n\x1c\
+    IMAGE_DIRECTORY_ENTRY_EXPORT\x10\0\x1a\x06\x9a\x93\x19\x02\x08\0\x12(\n\
+    \x1cIMAGE_DIRECTORY_ENTRY_IMPORT\x10\x01\x1a\x06\x9a\x93\x19\x02\x08\x01\
+    \x12*\n\x1eIMAGE_DIRECTORY_ENTRY_RESOURCE\x10\x02\x1a\x06\x9a\x93\x19\
+    \x02\x08\x02\x12+\n\x1fIMAGE_DIRECTORY_ENTRY_EXCEPTION\x10\x03\x1a\x06\
+    \x9a\x93\x19\x02\x08\x03\x12*\n\x1eIMAGE_DIRECTORY_ENTRY_SECURITY\x10\
+    \x04\x1a\x06\x9a\x93\x19\x02\x08\x04\x12+\n\x1fIMAGE_DIRECTORY_ENTRY_BAS\
+    ERELOC\x10\x05\x1a\x06\x9a\x93\x19\x02\x08\x05\x12'\n\x1bIMAGE_DIRECTORY\
+    _ENTRY_DEBUG\x10\x06\x1a\x06\x9a\x93\x19\x02\x08\x06\x12+\n\x1fIMAGE_DIR\
+    ECTORY_ENTRY_COPYRIGHT\x10\x07\x1a\x06\x9a\x93\x19\x02\x08\x07\x12.\n\"I\
+    MAGE_DIRECTORY_ENTRY_ARCHITECTURE\x10\x08\x1a\x06\x9a\x93\x19\x02\x08\
+    \x07\x12+\n\x1fIMAGE_DIRECTORY_ENTRY_GLOBALPTR\x10\t\x1a\x06\x9a\x93\x19\
+    \x02\x08\x08\x12%\n\x19IMAGE_DIRECTORY_ENTRY_TLS\x10\n\x1a\x06\x9a\x93\
+    \x19\x02\x08\t\x12-\n!IMAGE_DIRECTORY_ENTRY_LOAD_CONFIG\x10\x0b\x1a\x06\
+    \x9a\x93\x19\x02\x08\n\x12.\n\"IMAGE_DIRECTORY_ENTRY_BOUND_IMPORT\x10\
+    \x0c\x1a\x06\x9a\x93\x19\x02\x08\x0b\x12%\n\x19IMAGE_DIRECTORY_ENTRY_IAT\
+    \x10\r\x1a\x06\x9a\x93\x19\x02\x08\x0c\x12.\n\"IMAGE_DIRECTORY_ENTRY_DEL\
+    AY_IMPORT\x10\x0e\x1a\x06\x9a\x93\x19\x02\x08\r\x120\n$IMAGE_DIRECTORY_E\
+    NTRY_COM_DESCRIPTOR\x10\x0f\x1a\x06\x9a\x93\x19\x02\x08\x0e\x1a\x06\x92\
+    \x93\x19\x02\x10\x01*\x81\n\n\x16SectionCharacteristics\x12\x1a\n\x0eSEC\
+    TION_NO_PAD\x10\x01\x1a\x06\x9a\x93\x19\x02\x08\x08\x12\x1c\n\x10SECTION\
+    _CNT_CODE\x10\x02\x1a\x06\x9a\x93\x19\x02\x08\x20\x12(\n\x1cSECTION_CNT_\
+    INITIALIZED_DATA\x10\x03\x1a\x06\x9a\x93\x19\x02\x08@\x12+\n\x1eSECTION_\
+    CNT_UNINITIALIZED_DATA\x10\x04\x1a\x07\x9a\x93\x19\x03\x08\x80\x01\x12\
+    \x1e\n\x11SECTION_LNK_OTHER\x10\x05\x1a\x07\x9a\x93\x19\x03\x08\x80\x02\
+    \x12\x1d\n\x10SECTION_LNK_INFO\x10\x06\x1a\x07\x9a\x93\x19\x03\x08\x80\
+    \x04\x12\x1f\n\x12SECTION_LNK_REMOVE\x10\x07\x1a\x07\x9a\x93\x19\x03\x08\
+    \x80\x10\x12\x1f\n\x12SECTION_LNK_COMDAT\x10\x08\x1a\x07\x9a\x93\x19\x03\
+    \x08\x80\x20\x12'\n\x19SECTION_NO_DEFER_SPEC_EXC\x10\t\x1a\x08\x9a\x93\
+    \x19\x04\x08\x80\x80\x01\x12\x1b\n\rSECTION_GPREL\x10\n\x1a\x08\x9a\x93\
+    \x19\x04\x08\x80\x80\x02\x12\"\n\x14SECTION_ALIGN_1BYTES\x10\x0b\x1a\x08\
+    \x9a\x93\x19\x04\x08\x80\x80@\x12#\n\x14SECTION_ALIGN_2BYTES\x10\x0c\x1a\
+    \t\x9a\x93\x19\x05\x08\x80\x80\x80\x01\x12#\n\x14SECTION_ALIGN_4BYTES\
+    \x10\r\x1a\t\x9a\x93\x19\x05\x08\x80\x80\xc0\x01\x12#\n\x14SECTION_ALIGN\
+    _8BYTES\x10\x0e\x1a\t\x9a\x93\x19\x05\x08\x80\x80\x80\x02\x12$\n\x15SECT\
+    ION_ALIGN_16BYTES\x10\x0f\x1a\t\x9a\x93\x19\x05\x08\x80\x80\xc0\x02\x12$\
+    \n\x15SECTION_ALIGN_32BYTES\x10\x10\x1a\t\x9a\x93\x19\x05\x08\x80\x80\
+    \x80\x03\x12$\n\x15SECTION_ALIGN_64BYTES\x10\x11\x1a\t\x9a\x93\x19\x05\
+    \x08\x80\x80\xc0\x03\x12%\n\x16SECTION_ALIGN_128BYTES\x10\x12\x1a\t\x9a\
+    \x93\x19\x05\x08\x80\x80\x80\x04\x12%\n\x16SECTION_ALIGN_256BYTES\x10\
+    \x13\x1a\t\x9a\x93\x19\x05\x08\x80\x80\xc0\x04\x12%\n\x16SECTION_ALIGN_5\
+    12BYTES\x10\x14\x1a\t\x9a\x93\x19\x05\x08\x80\x80\x80\x05\x12&\n\x17SECT\
+    ION_ALIGN_1024BYTES\x10\x15\x1a\t\x9a\x93\x19\x05\x08\x80\x80\xc0\x05\
+    \x12&\n\x17SECTION_ALIGN_2048BYTES\x10\x16\x1a\t\x9a\x93\x19\x05\x08\x80\
+    \x80\x80\x06\x12&\n\x17SECTION_ALIGN_4096BYTES\x10\x17\x1a\t\x9a\x93\x19\
+    \x05\x08\x80\x80\xc0\x06\x12&\n\x17SECTION_ALIGN_8192BYTES\x10\x18\x1a\t\
+    \x9a\x93\x19\x05\x08\x80\x80\x80\x07\x12!\n\x12SECTION_ALIGN_MASK\x10\
+    \x19\x1a\t\x9a\x93\x19\x05\x08\x80\x80\xc0\x07\x12&\n\x17SECTION_LNK_NRE\
+    LOC_OVFL\x10\x1a\x1a\t\x9a\x93\x19\x05\x08\x80\x80\x80\x08\x12&\n\x17SEC\
+    TION_MEM_DISCARDABLE\x10\x1b\x1a\t\x9a\x93\x19\x05\x08\x80\x80\x80\x10\
+    \x12%\n\x16SECTION_MEM_NOT_CACHED\x10\x1c\x1a\t\x9a\x93\x19\x05\x08\x80\
+    \x80\x80\x20\x12$\n\x15SECTION_MEM_NOT_PAGED\x10\x1d\x1a\t\x9a\x93\x19\
+    \x05\x08\x80\x80\x80@\x12\"\n\x12SECTION_MEM_SHARED\x10\x1e\x1a\n\x9a\
+    \x93\x19\x06\x08\x80\x80\x80\x80\x01\x12#\n\x13SECTION_MEM_EXECUTE\x10\
+    \x1f\x1a\n\x9a\x93\x19\x06\x08\x80\x80\x80\x80\x02\x12\x20\n\x10SECTION_\
+    MEM_READ\x10\x20\x1a\n\x9a\x93\x19\x06\x08\x80\x80\x80\x80\x04\x12!\n\
+    \x11SECTION_MEM_WRITE\x10!\x1a\n\x9a\x93\x19\x06\x08\x80\x80\x80\x80\x08\
+    \x12\x1f\n\x13SECTION_SCALE_INDEX\x10\"\x1a\x06\x9a\x93\x19\x02\x08\x01\
+    \x1a\x06\x92\x93\x19\x02\x10\x01*\xe8\x01\n\x12DllCharacteristics\x12\
+    \x13\n\x0fHIGH_ENTROPY_VA\x10\x20\x12\x10\n\x0cDYNAMIC_BASE\x10@\x12\x14\
+    \n\x0fFORCE_INTEGRITY\x10\x80\x01\x12\x0e\n\tNX_COMPAT\x10\x80\x02\x12\
+    \x11\n\x0cNO_ISOLATION\x10\x80\x04\x12\x0b\n\x06NO_SEH\x10\x80\x08\x12\
+    \x0c\n\x07NO_BIND\x10\x80\x10\x12\x11\n\x0cAPPCONTAINER\x10\x80\x20\x12\
+    \x0f\n\nWDM_DRIVER\x10\x80@\x12\x0e\n\x08GUARD_CF\x10\x80\x80\x01\x12\
+    \x1b\n\x15TERMINAL_SERVER_AWARE\x10\x80\x80\x02\x1a\x06\x92\x93\x19\x02\
+    \x10\x01*\xa1\x02\n\x08KeyUsage\x12\x1f\n\x1bKEY_USAGE_DIGITAL_SIGNATURE\
+    \x10\x01\x12\x1d\n\x19KEY_USAGE_NON_REPUDIATION\x10\x02\x12\x1e\n\x1aKEY\
+    _USAGE_KEY_ENCIPHERMENT\x10\x04\x12\x1f\n\x1bKEY_USAGE_DATA_ENCIPHERMENT\
+    \x10\x08\x12\x1b\n\x17KEY_USAGE_KEY_AGREEMENT\x10\x10\x12\x1b\n\x17KEY_U\
+    SAGE_KEY_CERT_SIGN\x10\x20\x12\x16\n\x12KEY_USAGE_CRL_SIGN\x10@\x12\x1c\
+    \n\x17KEY_USAGE_ENCIPHER_ONLY\x10\x80\x01\x12\x1c\n\x17KEY_USAGE_DECIPHE\
+    R_ONLY\x10\x80\x02\x1a\x06\x92\x93\x19\x02\x10\x01*\xbc\x02\n\x10Extende\
+    dKeyUsage\x12\x0b\n\x07EKU_ANY\x10\x01\x12\x13\n\x0fEKU_SERVER_AUTH\x10\
+    \x02\x12\x13\n\x0fEKU_CLIENT_AUTH\x10\x04\x12\x14\n\x10EKU_CODE_SIGNING\
+    \x10\x08\x12\x18\n\x14EKU_EMAIL_PROTECTION\x10\x10\x12\x15\n\x11EKU_TIME\
+    _STAMPING\x10\x20\x12\x14\n\x10EKU_OCSP_SIGNING\x10@\x12&\n!EKU_SPC_INDI\
+    VIDUAL_SP_KEY_PURPOSE\x10\x80\x01\x12&\n!EKU_SPC_COMMERCIAL_SP_KEY_PURPO\
+    SE\x10\x80\x02\x12\x19\n\x14EKU_LIFETIME_SIGNING\x10\x80\x04\x12!\n\x1cE\
+    KU_KERNEL_MODE_CODE_SIGNING\x10\x80\x08\x1a\x06\x92\x93\x19\x02\x10\x01B\
+    \x1e\xfa\x92\x19\x1a\n\x02pe\x12\x05pe.PE\x1a\x02pe\"\tpe-moduleb\x06pro\
+    to2\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -9022,7 +9455,7 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             messages.push(RichSignature::generated_message_descriptor_data());
             messages.push(RichTool::generated_message_descriptor_data());
             messages.push(Overlay::generated_message_descriptor_data());
-            let mut enums = ::std::vec::Vec::with_capacity(9);
+            let mut enums = ::std::vec::Vec::with_capacity(11);
             enums.push(ResourceType::generated_enum_descriptor_data());
             enums.push(Machine::generated_enum_descriptor_data());
             enums.push(Subsystem::generated_enum_descriptor_data());
@@ -9032,6 +9465,8 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             enums.push(DirectoryEntry::generated_enum_descriptor_data());
             enums.push(SectionCharacteristics::generated_enum_descriptor_data());
             enums.push(DllCharacteristics::generated_enum_descriptor_data());
+            enums.push(KeyUsage::generated_enum_descriptor_data());
+            enums.push(ExtendedKeyUsage::generated_enum_descriptor_data());
             ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
                 file_descriptor_proto(),
                 deps,
@@ -0,0 +1,741 @@
+// This file is generated by rust-protobuf 3.7.2. Do not edit
+// .proto file is parsed by pure
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `regf.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_7_2;
+
+// @@protoc_insertion_point(message:regf.Regf)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Regf {
+    // message fields
+    // @@protoc_insertion_point(field:regf.Regf.is_regf)
+    pub is_regf: ::std::option::Option<bool>,
+    // @@protoc_insertion_point(field:regf.Regf.major_version)
+    pub major_version: ::std::option::Option<u32>,
+    // @@protoc_insertion_point(field:regf.Regf.minor_version)
+    pub minor_version: ::std::option::Option<u32>,
+    // @@protoc_insertion_point(field:regf.Regf.last_written)
+    pub last_written: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:regf.Regf.file_name)
+    pub file_name: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:regf.Regf.root_key)
+    pub root_key: ::protobuf::MessageField<Key>,
+    // @@protoc_insertion_point(field:regf.Regf.number_of_keys)
+    pub number_of_keys: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:regf.Regf.number_of_values)
+    pub number_of_values: ::std::option::Option<u64>,
+    // special fields
+    // @@protoc_insertion_point(special_field:regf.Regf.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Regf {
+    fn default() -> &'a Regf {
+        <Regf as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Regf {
+    pub fn new() -> Regf {
+        ::std::default::Default::default()
+    }
+
+    // optional bool is_regf = 1;
+
+    pub fn is_regf(&self) -> bool {
+        self.is_regf.unwrap_or(false)
+    }
+
+    pub fn clear_is_regf(&mut self) {
+        self.is_regf = ::std::option::Option::None;
+    }
+
+    pub fn has_is_regf(&self) -> bool {
+        self.is_regf.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_regf(&mut self, v: bool) {
+        self.is_regf = ::std::option::Option::Some(v);
+    }
+
+    // optional uint32 major_version = 2;
+
+    pub fn major_version(&self) -> u32 {
+        self.major_version.unwrap_or(0)
+    }
+
+    pub fn clear_major_version(&mut self) {
+        self.major_version = ::std::option::Option::None;
+    }
+
+    pub fn has_major_version(&self) -> bool {
+        self.major_version.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_major_version(&mut self, v: u32) {
+        self.major_version = ::std::option::Option::Some(v);
+    }
+
+    // optional uint32 minor_version = 3;
+
+    pub fn minor_version(&self) -> u32 {
+        self.minor_version.unwrap_or(0)
+    }
+
+    pub fn clear_minor_version(&mut self) {
+        self.minor_version = ::std::option::Option::None;
+    }
+
+    pub fn has_minor_version(&self) -> bool {
+        self.minor_version.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_minor_version(&mut self, v: u32) {
+        self.minor_version = ::std::option::Option::Some(v);
+    }
+
+    // optional uint64 last_written = 4;
+
+    pub fn last_written(&self) -> u64 {
+        self.last_written.unwrap_or(0)
+    }
+
+    pub fn clear_last_written(&mut self) {
+        self.last_written = ::std::option::Option::None;
+    }
+
+    pub fn has_last_written(&self) -> bool {
+        self.last_written.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_written(&mut self, v: u64) {
+        self.last_written = ::std::option::Option::Some(v);
+    }
+
+    // optional string file_name = 5;
+
+    pub fn file_name(&self) -> &str {
+        match self.file_name.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_file_name(&mut self) {
+        self.file_name = ::std::option::Option::None;
+    }
+
+    pub fn has_file_name(&self) -> bool {
+        self.file_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_name(&mut self, v: ::std::string::String) {
+        self.file_name = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_file_name(&mut self) -> &mut ::std::string::String {
+        if self.file_name.is_none() {
+            self.file_name = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.file_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_file_name(&mut self) -> ::std::string::String {
+        self.file_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional uint64 number_of_keys = 7;
+
+    pub fn number_of_keys(&self) -> u64 {
+        self.number_of_keys.unwrap_or(0)
+    }
+
+    pub fn clear_number_of_keys(&mut self) {
+        self.number_of_keys = ::std::option::Option::None;
+    }
+
+    pub fn has_number_of_keys(&self) -> bool {
+        self.number_of_keys.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_number_of_keys(&mut self, v: u64) {
+        self.number_of_keys = ::std::option::Option::Some(v);
+    }
+
+    // optional uint64 number_of_values = 8;
+
+    pub fn number_of_values(&self) -> u64 {
+        self.number_of_values.unwrap_or(0)
+    }
+
+    pub fn clear_number_of_values(&mut self) {
+        self.number_of_values = ::std::option::Option::None;
+    }
+
+    pub fn has_number_of_values(&self) -> bool {
+        self.number_of_values.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_number_of_values(&mut self, v: u64) {
+        self.number_of_values = ::std::option::Option::Some(v);
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(8);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "is_regf",
+            |m: &Regf| { &m.is_regf },
+            |m: &mut Regf| { &mut m.is_regf },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "major_version",
+            |m: &Regf| { &m.major_version },
+            |m: &mut Regf| { &mut m.major_version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "minor_version",
+            |m: &Regf| { &m.minor_version },
+            |m: &mut Regf| { &mut m.minor_version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "last_written",
+            |m: &Regf| { &m.last_written },
+            |m: &mut Regf| { &mut m.last_written },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "file_name",
+            |m: &Regf| { &m.file_name },
+            |m: &mut Regf| { &mut m.file_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Key>(
+            "root_key",
+            |m: &Regf| { &m.root_key },
+            |m: &mut Regf| { &mut m.root_key },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "number_of_keys",
+            |m: &Regf| { &m.number_of_keys },
+            |m: &mut Regf| { &mut m.number_of_keys },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "number_of_values",
+            |m: &Regf| { &m.number_of_values },
+            |m: &mut Regf| { &mut m.number_of_values },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Regf>(
+            "Regf",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Regf {
+    const NAME: &'static str = "Regf";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.is_regf = ::std::option::Option::Some(is.read_bool()?);
+                },
+                16 => {
+                    self.major_version = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                24 => {
+                    self.minor_version = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                32 => {
+                    self.last_written = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                42 => {
+                    self.file_name = ::std::option::Option::Some(is.read_string()?);
+                },
+                50 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.root_key)?;
+                },
+                56 => {
+                    self.number_of_keys = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                64 => {
+                    self.number_of_values = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.is_regf {
+            my_size += 1 + 1;
+        }
+        if let Some(v) = self.major_version {
+            my_size += ::protobuf::rt::uint32_size(2, v);
+        }
+        if let Some(v) = self.minor_version {
+            my_size += ::protobuf::rt::uint32_size(3, v);
+        }
+        if let Some(v) = self.last_written {
+            my_size += ::protobuf::rt::uint64_size(4, v);
+        }
+        if let Some(v) = self.file_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(5, &v);
+        }
+        if let Some(v) = self.root_key.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.number_of_keys {
+            my_size += ::protobuf::rt::uint64_size(7, v);
+        }
+        if let Some(v) = self.number_of_values {
+            my_size += ::protobuf::rt::uint64_size(8, v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.is_regf {
+            os.write_bool(1, v)?;
+        }
+        if let Some(v) = self.major_version {
+            os.write_uint32(2, v)?;
+        }
+        if let Some(v) = self.minor_version {
+            os.write_uint32(3, v)?;
+        }
+        if let Some(v) = self.last_written {
+            os.write_uint64(4, v)?;
+        }
+        if let Some(v) = self.file_name.as_ref() {
+            os.write_string(5, v)?;
+        }
+        if let Some(v) = self.root_key.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
+        }
+        if let Some(v) = self.number_of_keys {
+            os.write_uint64(7, v)?;
+        }
+        if let Some(v) = self.number_of_values {
+            os.write_uint64(8, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Regf {
+        Regf::new()
+    }
+
+    fn clear(&mut self) {
+        self.is_regf = ::std::option::Option::None;
+        self.major_version = ::std::option::Option::None;
+        self.minor_version = ::std::option::Option::None;
+        self.last_written = ::std::option::Option::None;
+        self.file_name = ::std::option::Option::None;
+        self.root_key.clear();
+        self.number_of_keys = ::std::option::Option::None;
+        self.number_of_values = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Regf {
+        static instance: Regf = Regf {
+            is_regf: ::std::option::Option::None,
+            major_version: ::std::option::Option::None,
+            minor_version: ::std::option::Option::None,
+            last_written: ::std::option::Option::None,
+            file_name: ::std::option::Option::None,
+            root_key: ::protobuf::MessageField::none(),
+            number_of_keys: ::std::option::Option::None,
+            number_of_values: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Regf {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Regf").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Regf {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Regf {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:regf.Key)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Key {
+    // message fields
+    // @@protoc_insertion_point(field:regf.Key.name)
+    pub name: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:regf.Key.last_written)
+    pub last_written: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:regf.Key.number_of_subkeys)
+    pub number_of_subkeys: ::std::option::Option<u32>,
+    // @@protoc_insertion_point(field:regf.Key.number_of_values)
+    pub number_of_values: ::std::option::Option<u32>,
+    // special fields
+    // @@protoc_insertion_point(special_field:regf.Key.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Key {
+    fn default() -> &'a Key {
+        <Key as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Key {
+    pub fn new() -> Key {
+        ::std::default::Default::default()
+    }
+
+    // optional string name = 1;
+
+    pub fn name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_name(&mut self) {
+        self.name = ::std::option::Option::None;
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional uint64 last_written = 2;
+
+    pub fn last_written(&self) -> u64 {
+        self.last_written.unwrap_or(0)
+    }
+
+    pub fn clear_last_written(&mut self) {
+        self.last_written = ::std::option::Option::None;
+    }
+
+    pub fn has_last_written(&self) -> bool {
+        self.last_written.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_written(&mut self, v: u64) {
+        self.last_written = ::std::option::Option::Some(v);
+    }
+
+    // optional uint32 number_of_subkeys = 3;
+
+    pub fn number_of_subkeys(&self) -> u32 {
+        self.number_of_subkeys.unwrap_or(0)
+    }
+
+    pub fn clear_number_of_subkeys(&mut self) {
+        self.number_of_subkeys = ::std::option::Option::None;
+    }
+
+    pub fn has_number_of_subkeys(&self) -> bool {
+        self.number_of_subkeys.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_number_of_subkeys(&mut self, v: u32) {
+        self.number_of_subkeys = ::std::option::Option::Some(v);
+    }
+
+    // optional uint32 number_of_values = 4;
+
+    pub fn number_of_values(&self) -> u32 {
+        self.number_of_values.unwrap_or(0)
+    }
+
+    pub fn clear_number_of_values(&mut self) {
+        self.number_of_values = ::std::option::Option::None;
+    }
+
+    pub fn has_number_of_values(&self) -> bool {
+        self.number_of_values.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_number_of_values(&mut self, v: u32) {
+        self.number_of_values = ::std::option::Option::Some(v);
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "name",
+            |m: &Key| { &m.name },
+            |m: &mut Key| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "last_written",
+            |m: &Key| { &m.last_written },
+            |m: &mut Key| { &mut m.last_written },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "number_of_subkeys",
+            |m: &Key| { &m.number_of_subkeys },
+            |m: &mut Key| { &mut m.number_of_subkeys },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "number_of_values",
+            |m: &Key| { &m.number_of_values },
+            |m: &mut Key| { &mut m.number_of_values },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Key>(
+            "Key",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Key {
+    const NAME: &'static str = "Key";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = ::std::option::Option::Some(is.read_string()?);
+                },
+                16 => {
+                    self.last_written = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                24 => {
+                    self.number_of_subkeys = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                32 => {
+                    self.number_of_values = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.last_written {
+            my_size += ::protobuf::rt::uint64_size(2, v);
+        }
+        if let Some(v) = self.number_of_subkeys {
+            my_size += ::protobuf::rt::uint32_size(3, v);
+        }
+        if let Some(v) = self.number_of_values {
+            my_size += ::protobuf::rt::uint32_size(4, v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.name.as_ref() {
+            os.write_string(1, v)?;
+        }
+        if let Some(v) = self.last_written {
+            os.write_uint64(2, v)?;
+        }
+        if let Some(v) = self.number_of_subkeys {
+            os.write_uint32(3, v)?;
+        }
+        if let Some(v) = self.number_of_values {
+            os.write_uint32(4, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Key {
+        Key::new()
+    }
+
+    fn clear(&mut self) {
+        self.name = ::std::option::Option::None;
+        self.last_written = ::std::option::Option::None;
+        self.number_of_subkeys = ::std::option::Option::None;
+        self.number_of_values = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Key {
+        static instance: Key = Key {
+            name: ::std::option::Option::None,
+            last_written: ::std::option::Option::None,
+            number_of_subkeys: ::std::option::Option::None,
+            number_of_values: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Key {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Key").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Key {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Key {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\nregf.proto\x12\x04regf\x1a\nyara.proto\"\xa8\x02\n\x04Regf\x12\x17\n\
+    \x07is_regf\x18\x01\x20\x01(\x08R\x06isRegf\x12#\n\rmajor_version\x18\
+    \x02\x20\x01(\rR\x0cmajorVersion\x12#\n\rminor_version\x18\x03\x20\x01(\
+    \rR\x0cminorVersion\x12*\n\x0clast_written\x18\x04\x20\x01(\x04R\x0blast\
+    WrittenB\x07\x82\x93\x19\x03*\x01t\x12\x1b\n\tfile_name\x18\x05\x20\x01(\
+    \tR\x08fileName\x12$\n\x08root_key\x18\x06\x20\x01(\x0b2\t.regf.KeyR\x07\
+    rootKey\x12$\n\x0enumber_of_keys\x18\x07\x20\x01(\x04R\x0cnumberOfKeys\
+    \x12(\n\x10number_of_values\x18\x08\x20\x01(\x04R\x0enumberOfValues\"\
+    \x9b\x01\n\x03Key\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12*\n\
+    \x0clast_written\x18\x02\x20\x01(\x04R\x0blastWrittenB\x07\x82\x93\x19\
+    \x03*\x01t\x12*\n\x11number_of_subkeys\x18\x03\x20\x01(\rR\x0fnumberOfSu\
+    bkeys\x12(\n\x10number_of_values\x18\x04\x20\x01(\rR\x0enumberOfValuesB(\
+    \xfa\x92\x19$\n\x04regf\x12\tregf.Regf\x1a\x04regf\"\x0bregf-moduleb\x06\
+    proto2\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobuf::rt::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobuf::rt::Lazy<::protobuf::reflect::GeneratedFileDescriptor> = ::protobuf::rt::Lazy::new();
+    static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(1);
+            deps.push(super::yara::file_descriptor().clone());
+            let mut messages = ::std::vec::Vec::with_capacity(2);
+            messages.push(Regf::generated_message_descriptor_data());
+            messages.push(Key::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(0);
+            ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobuf::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
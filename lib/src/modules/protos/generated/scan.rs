@@ -0,0 +1,1405 @@
+// This file is generated by rust-protobuf 3.7.2. Do not edit
+// .proto file is parsed by pure
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `scan.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_7_2;
+
+// @@protoc_insertion_point(message:scan.ScanResults)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ScanResults {
+    // message fields
+    // @@protoc_insertion_point(field:scan.ScanResults.path)
+    pub path: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:scan.ScanResults.matching_rules)
+    pub matching_rules: ::std::vec::Vec<Rule>,
+    // @@protoc_insertion_point(field:scan.ScanResults.modules)
+    pub modules: ::protobuf::MessageField<super::mods::Modules>,
+    // special fields
+    // @@protoc_insertion_point(special_field:scan.ScanResults.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ScanResults {
+    fn default() -> &'a ScanResults {
+        <ScanResults as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ScanResults {
+    pub fn new() -> ScanResults {
+        ::std::default::Default::default()
+    }
+
+    // optional string path = 1;
+
+    pub fn path(&self) -> &str {
+        match self.path.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_path(&mut self) {
+        self.path = ::std::option::Option::None;
+    }
+
+    pub fn has_path(&self) -> bool {
+        self.path.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        if self.path.is_none() {
+            self.path = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.path.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        self.path.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "path",
+            |m: &ScanResults| { &m.path },
+            |m: &mut ScanResults| { &mut m.path },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "matching_rules",
+            |m: &ScanResults| { &m.matching_rules },
+            |m: &mut ScanResults| { &mut m.matching_rules },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::mods::Modules>(
+            "modules",
+            |m: &ScanResults| { &m.modules },
+            |m: &mut ScanResults| { &mut m.modules },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ScanResults>(
+            "ScanResults",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ScanResults {
+    const NAME: &'static str = "ScanResults";
+
+    fn is_initialized(&self) -> bool {
+        for v in &self.matching_rules {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.modules {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.path = ::std::option::Option::Some(is.read_string()?);
+                },
+                18 => {
+                    self.matching_rules.push(is.read_message()?);
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.modules)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.path.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        for value in &self.matching_rules {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if let Some(v) = self.modules.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.path.as_ref() {
+            os.write_string(1, v)?;
+        }
+        for v in &self.matching_rules {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
+        if let Some(v) = self.modules.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ScanResults {
+        ScanResults::new()
+    }
+
+    fn clear(&mut self) {
+        self.path = ::std::option::Option::None;
+        self.matching_rules.clear();
+        self.modules.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ScanResults {
+        static instance: ScanResults = ScanResults {
+            path: ::std::option::Option::None,
+            matching_rules: ::std::vec::Vec::new(),
+            modules: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ScanResults {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ScanResults").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ScanResults {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ScanResults {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:scan.Rule)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Rule {
+    // message fields
+    // @@protoc_insertion_point(field:scan.Rule.namespace)
+    pub namespace: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:scan.Rule.identifier)
+    pub identifier: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:scan.Rule.tags)
+    pub tags: ::std::vec::Vec<::std::string::String>,
+    // @@protoc_insertion_point(field:scan.Rule.metadata)
+    pub metadata: ::std::vec::Vec<Metadata>,
+    // @@protoc_insertion_point(field:scan.Rule.patterns)
+    pub patterns: ::std::vec::Vec<Pattern>,
+    // special fields
+    // @@protoc_insertion_point(special_field:scan.Rule.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Rule {
+    fn default() -> &'a Rule {
+        <Rule as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Rule {
+    pub fn new() -> Rule {
+        ::std::default::Default::default()
+    }
+
+    // optional string namespace = 1;
+
+    pub fn namespace(&self) -> &str {
+        match self.namespace.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_namespace(&mut self) {
+        self.namespace = ::std::option::Option::None;
+    }
+
+    pub fn has_namespace(&self) -> bool {
+        self.namespace.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_namespace(&mut self, v: ::std::string::String) {
+        self.namespace = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_namespace(&mut self) -> &mut ::std::string::String {
+        if self.namespace.is_none() {
+            self.namespace = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.namespace.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_namespace(&mut self) -> ::std::string::String {
+        self.namespace.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional string identifier = 2;
+
+    pub fn identifier(&self) -> &str {
+        match self.identifier.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_identifier(&mut self) {
+        self.identifier = ::std::option::Option::None;
+    }
+
+    pub fn has_identifier(&self) -> bool {
+        self.identifier.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_identifier(&mut self, v: ::std::string::String) {
+        self.identifier = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_identifier(&mut self) -> &mut ::std::string::String {
+        if self.identifier.is_none() {
+            self.identifier = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.identifier.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_identifier(&mut self) -> ::std::string::String {
+        self.identifier.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "namespace",
+            |m: &Rule| { &m.namespace },
+            |m: &mut Rule| { &mut m.namespace },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "identifier",
+            |m: &Rule| { &m.identifier },
+            |m: &mut Rule| { &mut m.identifier },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "tags",
+            |m: &Rule| { &m.tags },
+            |m: &mut Rule| { &mut m.tags },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "metadata",
+            |m: &Rule| { &m.metadata },
+            |m: &mut Rule| { &mut m.metadata },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "patterns",
+            |m: &Rule| { &m.patterns },
+            |m: &mut Rule| { &mut m.patterns },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Rule>(
+            "Rule",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Rule {
+    const NAME: &'static str = "Rule";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.namespace = ::std::option::Option::Some(is.read_string()?);
+                },
+                18 => {
+                    self.identifier = ::std::option::Option::Some(is.read_string()?);
+                },
+                26 => {
+                    self.tags.push(is.read_string()?);
+                },
+                34 => {
+                    self.metadata.push(is.read_message()?);
+                },
+                42 => {
+                    self.patterns.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.namespace.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.identifier.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        for value in &self.tags {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in &self.metadata {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.patterns {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.namespace.as_ref() {
+            os.write_string(1, v)?;
+        }
+        if let Some(v) = self.identifier.as_ref() {
+            os.write_string(2, v)?;
+        }
+        for v in &self.tags {
+            os.write_string(3, &v)?;
+        };
+        for v in &self.metadata {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        };
+        for v in &self.patterns {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Rule {
+        Rule::new()
+    }
+
+    fn clear(&mut self) {
+        self.namespace = ::std::option::Option::None;
+        self.identifier = ::std::option::Option::None;
+        self.tags.clear();
+        self.metadata.clear();
+        self.patterns.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Rule {
+        static instance: Rule = Rule {
+            namespace: ::std::option::Option::None,
+            identifier: ::std::option::Option::None,
+            tags: ::std::vec::Vec::new(),
+            metadata: ::std::vec::Vec::new(),
+            patterns: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Rule {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Rule").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Rule {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Rule {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:scan.Metadata)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Metadata {
+    // message fields
+    // @@protoc_insertion_point(field:scan.Metadata.identifier)
+    pub identifier: ::std::option::Option<::std::string::String>,
+    // message oneof groups
+    pub value: ::std::option::Option<metadata::Value>,
+    // special fields
+    // @@protoc_insertion_point(special_field:scan.Metadata.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Metadata {
+    fn default() -> &'a Metadata {
+        <Metadata as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Metadata {
+    pub fn new() -> Metadata {
+        ::std::default::Default::default()
+    }
+
+    // optional string identifier = 1;
+
+    pub fn identifier(&self) -> &str {
+        match self.identifier.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_identifier(&mut self) {
+        self.identifier = ::std::option::Option::None;
+    }
+
+    pub fn has_identifier(&self) -> bool {
+        self.identifier.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_identifier(&mut self, v: ::std::string::String) {
+        self.identifier = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_identifier(&mut self) -> &mut ::std::string::String {
+        if self.identifier.is_none() {
+            self.identifier = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.identifier.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_identifier(&mut self) -> ::std::string::String {
+        self.identifier.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional int64 integer = 2;
+
+    pub fn integer(&self) -> i64 {
+        match self.value {
+            ::std::option::Option::Some(metadata::Value::Integer(v)) => v,
+            _ => 0,
+        }
+    }
+
+    pub fn clear_integer(&mut self) {
+        self.value = ::std::option::Option::None;
+    }
+
+    pub fn has_integer(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(metadata::Value::Integer(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_integer(&mut self, v: i64) {
+        self.value = ::std::option::Option::Some(metadata::Value::Integer(v))
+    }
+
+    // optional double float = 3;
+
+    pub fn float(&self) -> f64 {
+        match self.value {
+            ::std::option::Option::Some(metadata::Value::Float(v)) => v,
+            _ => 0.,
+        }
+    }
+
+    pub fn clear_float(&mut self) {
+        self.value = ::std::option::Option::None;
+    }
+
+    pub fn has_float(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(metadata::Value::Float(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_float(&mut self, v: f64) {
+        self.value = ::std::option::Option::Some(metadata::Value::Float(v))
+    }
+
+    // optional bool bool = 4;
+
+    pub fn bool(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(metadata::Value::Bool(v)) => v,
+            _ => false,
+        }
+    }
+
+    pub fn clear_bool(&mut self) {
+        self.value = ::std::option::Option::None;
+    }
+
+    pub fn has_bool(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(metadata::Value::Bool(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bool(&mut self, v: bool) {
+        self.value = ::std::option::Option::Some(metadata::Value::Bool(v))
+    }
+
+    // optional string string = 5;
+
+    pub fn string(&self) -> &str {
+        match self.value {
+            ::std::option::Option::Some(metadata::Value::String(ref v)) => v,
+            _ => "",
+        }
+    }
+
+    pub fn clear_string(&mut self) {
+        self.value = ::std::option::Option::None;
+    }
+
+    pub fn has_string(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(metadata::Value::String(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_string(&mut self, v: ::std::string::String) {
+        self.value = ::std::option::Option::Some(metadata::Value::String(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_string(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(metadata::Value::String(_)) = self.value {
+        } else {
+            self.value = ::std::option::Option::Some(metadata::Value::String(::std::string::String::new()));
+        }
+        match self.value {
+            ::std::option::Option::Some(metadata::Value::String(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_string(&mut self) -> ::std::string::String {
+        if self.has_string() {
+            match self.value.take() {
+                ::std::option::Option::Some(metadata::Value::String(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // optional bytes bytes = 6;
+
+    pub fn bytes(&self) -> &[u8] {
+        match self.value {
+            ::std::option::Option::Some(metadata::Value::Bytes(ref v)) => v,
+            _ => &[],
+        }
+    }
+
+    pub fn clear_bytes(&mut self) {
+        self.value = ::std::option::Option::None;
+    }
+
+    pub fn has_bytes(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(metadata::Value::Bytes(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bytes(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = ::std::option::Option::Some(metadata::Value::Bytes(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_bytes(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if let ::std::option::Option::Some(metadata::Value::Bytes(_)) = self.value {
+        } else {
+            self.value = ::std::option::Option::Some(metadata::Value::Bytes(::std::vec::Vec::new()));
+        }
+        match self.value {
+            ::std::option::Option::Some(metadata::Value::Bytes(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_bytes(&mut self) -> ::std::vec::Vec<u8> {
+        if self.has_bytes() {
+            match self.value.take() {
+                ::std::option::Option::Some(metadata::Value::Bytes(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::vec::Vec::new()
+        }
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(1);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "identifier",
+            |m: &Metadata| { &m.identifier },
+            |m: &mut Metadata| { &mut m.identifier },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_copy_has_get_set_simpler_accessors::<_, _>(
+            "integer",
+            Metadata::has_integer,
+            Metadata::integer,
+            Metadata::set_integer,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_copy_has_get_set_simpler_accessors::<_, _>(
+            "float",
+            Metadata::has_float,
+            Metadata::float,
+            Metadata::set_float,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_copy_has_get_set_simpler_accessors::<_, _>(
+            "bool",
+            Metadata::has_bool,
+            Metadata::bool,
+            Metadata::set_bool,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_deref_has_get_set_simpler_accessor::<_, _>(
+            "string",
+            Metadata::has_string,
+            Metadata::string,
+            Metadata::set_string,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_deref_has_get_set_simpler_accessor::<_, _>(
+            "bytes",
+            Metadata::has_bytes,
+            Metadata::bytes,
+            Metadata::set_bytes,
+        ));
+        oneofs.push(metadata::Value::generated_oneof_descriptor_data());
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Metadata>(
+            "Metadata",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Metadata {
+    const NAME: &'static str = "Metadata";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.identifier = ::std::option::Option::Some(is.read_string()?);
+                },
+                16 => {
+                    self.value = ::std::option::Option::Some(metadata::Value::Integer(is.read_int64()?));
+                },
+                25 => {
+                    self.value = ::std::option::Option::Some(metadata::Value::Float(is.read_double()?));
+                },
+                32 => {
+                    self.value = ::std::option::Option::Some(metadata::Value::Bool(is.read_bool()?));
+                },
+                42 => {
+                    self.value = ::std::option::Option::Some(metadata::Value::String(is.read_string()?));
+                },
+                50 => {
+                    self.value = ::std::option::Option::Some(metadata::Value::Bytes(is.read_bytes()?));
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.identifier.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let ::std::option::Option::Some(ref v) = self.value {
+            match v {
+                &metadata::Value::Integer(v) => {
+                    my_size += ::protobuf::rt::int64_size(2, v);
+                },
+                &metadata::Value::Float(v) => {
+                    my_size += 1 + 8;
+                },
+                &metadata::Value::Bool(v) => {
+                    my_size += 1 + 1;
+                },
+                &metadata::Value::String(ref v) => {
+                    my_size += ::protobuf::rt::string_size(5, &v);
+                },
+                &metadata::Value::Bytes(ref v) => {
+                    my_size += ::protobuf::rt::bytes_size(6, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.identifier.as_ref() {
+            os.write_string(1, v)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.value {
+            match v {
+                &metadata::Value::Integer(v) => {
+                    os.write_int64(2, v)?;
+                },
+                &metadata::Value::Float(v) => {
+                    os.write_double(3, v)?;
+                },
+                &metadata::Value::Bool(v) => {
+                    os.write_bool(4, v)?;
+                },
+                &metadata::Value::String(ref v) => {
+                    os.write_string(5, v)?;
+                },
+                &metadata::Value::Bytes(ref v) => {
+                    os.write_bytes(6, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Metadata {
+        Metadata::new()
+    }
+
+    fn clear(&mut self) {
+        self.identifier = ::std::option::Option::None;
+        self.value = ::std::option::Option::None;
+        self.value = ::std::option::Option::None;
+        self.value = ::std::option::Option::None;
+        self.value = ::std::option::Option::None;
+        self.value = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Metadata {
+        static instance: Metadata = Metadata {
+            identifier: ::std::option::Option::None,
+            value: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Metadata {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Metadata").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Metadata {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Metadata {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `Metadata`
+pub mod metadata {
+
+    #[derive(Clone,PartialEq,Debug)]
+    #[non_exhaustive]
+    // @@protoc_insertion_point(oneof:scan.Metadata.value)
+    pub enum Value {
+        // @@protoc_insertion_point(oneof_field:scan.Metadata.integer)
+        Integer(i64),
+        // @@protoc_insertion_point(oneof_field:scan.Metadata.float)
+        Float(f64),
+        // @@protoc_insertion_point(oneof_field:scan.Metadata.bool)
+        Bool(bool),
+        // @@protoc_insertion_point(oneof_field:scan.Metadata.string)
+        String(::std::string::String),
+        // @@protoc_insertion_point(oneof_field:scan.Metadata.bytes)
+        Bytes(::std::vec::Vec<u8>),
+    }
+
+    impl ::protobuf::Oneof for Value {
+    }
+
+    impl ::protobuf::OneofFull for Value {
+        fn descriptor() -> ::protobuf::reflect::OneofDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::OneofDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| <super::Metadata as ::protobuf::MessageFull>::descriptor().oneof_by_name("value").unwrap()).clone()
+        }
+    }
+
+    impl Value {
+        pub(in super) fn generated_oneof_descriptor_data() -> ::protobuf::reflect::GeneratedOneofDescriptorData {
+            ::protobuf::reflect::GeneratedOneofDescriptorData::new::<Value>("value")
+        }
+    }
+}
+
+// @@protoc_insertion_point(message:scan.Pattern)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Pattern {
+    // message fields
+    // @@protoc_insertion_point(field:scan.Pattern.identifier)
+    pub identifier: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:scan.Pattern.matches)
+    pub matches: ::std::vec::Vec<Match>,
+    // special fields
+    // @@protoc_insertion_point(special_field:scan.Pattern.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Pattern {
+    fn default() -> &'a Pattern {
+        <Pattern as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Pattern {
+    pub fn new() -> Pattern {
+        ::std::default::Default::default()
+    }
+
+    // optional string identifier = 1;
+
+    pub fn identifier(&self) -> &str {
+        match self.identifier.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_identifier(&mut self) {
+        self.identifier = ::std::option::Option::None;
+    }
+
+    pub fn has_identifier(&self) -> bool {
+        self.identifier.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_identifier(&mut self, v: ::std::string::String) {
+        self.identifier = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_identifier(&mut self) -> &mut ::std::string::String {
+        if self.identifier.is_none() {
+            self.identifier = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.identifier.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_identifier(&mut self) -> ::std::string::String {
+        self.identifier.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "identifier",
+            |m: &Pattern| { &m.identifier },
+            |m: &mut Pattern| { &mut m.identifier },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "matches",
+            |m: &Pattern| { &m.matches },
+            |m: &mut Pattern| { &mut m.matches },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Pattern>(
+            "Pattern",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Pattern {
+    const NAME: &'static str = "Pattern";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.identifier = ::std::option::Option::Some(is.read_string()?);
+                },
+                18 => {
+                    self.matches.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.identifier.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        for value in &self.matches {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.identifier.as_ref() {
+            os.write_string(1, v)?;
+        }
+        for v in &self.matches {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Pattern {
+        Pattern::new()
+    }
+
+    fn clear(&mut self) {
+        self.identifier = ::std::option::Option::None;
+        self.matches.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Pattern {
+        static instance: Pattern = Pattern {
+            identifier: ::std::option::Option::None,
+            matches: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Pattern {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Pattern").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Pattern {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Pattern {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:scan.Match)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Match {
+    // message fields
+    // @@protoc_insertion_point(field:scan.Match.offset)
+    pub offset: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:scan.Match.length)
+    pub length: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:scan.Match.xor_key)
+    pub xor_key: ::std::option::Option<u32>,
+    // special fields
+    // @@protoc_insertion_point(special_field:scan.Match.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Match {
+    fn default() -> &'a Match {
+        <Match as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Match {
+    pub fn new() -> Match {
+        ::std::default::Default::default()
+    }
+
+    // optional uint64 offset = 1;
+
+    pub fn offset(&self) -> u64 {
+        self.offset.unwrap_or(0)
+    }
+
+    pub fn clear_offset(&mut self) {
+        self.offset = ::std::option::Option::None;
+    }
+
+    pub fn has_offset(&self) -> bool {
+        self.offset.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_offset(&mut self, v: u64) {
+        self.offset = ::std::option::Option::Some(v);
+    }
+
+    // optional uint64 length = 2;
+
+    pub fn length(&self) -> u64 {
+        self.length.unwrap_or(0)
+    }
+
+    pub fn clear_length(&mut self) {
+        self.length = ::std::option::Option::None;
+    }
+
+    pub fn has_length(&self) -> bool {
+        self.length.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_length(&mut self, v: u64) {
+        self.length = ::std::option::Option::Some(v);
+    }
+
+    // optional uint32 xor_key = 3;
+
+    pub fn xor_key(&self) -> u32 {
+        self.xor_key.unwrap_or(0)
+    }
+
+    pub fn clear_xor_key(&mut self) {
+        self.xor_key = ::std::option::Option::None;
+    }
+
+    pub fn has_xor_key(&self) -> bool {
+        self.xor_key.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_xor_key(&mut self, v: u32) {
+        self.xor_key = ::std::option::Option::Some(v);
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "offset",
+            |m: &Match| { &m.offset },
+            |m: &mut Match| { &mut m.offset },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "length",
+            |m: &Match| { &m.length },
+            |m: &mut Match| { &mut m.length },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "xor_key",
+            |m: &Match| { &m.xor_key },
+            |m: &mut Match| { &mut m.xor_key },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Match>(
+            "Match",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Match {
+    const NAME: &'static str = "Match";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.offset = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                16 => {
+                    self.length = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                24 => {
+                    self.xor_key = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.offset {
+            my_size += ::protobuf::rt::uint64_size(1, v);
+        }
+        if let Some(v) = self.length {
+            my_size += ::protobuf::rt::uint64_size(2, v);
+        }
+        if let Some(v) = self.xor_key {
+            my_size += ::protobuf::rt::uint32_size(3, v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.offset {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(v) = self.length {
+            os.write_uint64(2, v)?;
+        }
+        if let Some(v) = self.xor_key {
+            os.write_uint32(3, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Match {
+        Match::new()
+    }
+
+    fn clear(&mut self) {
+        self.offset = ::std::option::Option::None;
+        self.length = ::std::option::Option::None;
+        self.xor_key = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Match {
+        static instance: Match = Match {
+            offset: ::std::option::Option::None,
+            length: ::std::option::Option::None,
+            xor_key: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Match {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Match").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Match {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Match {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\nscan.proto\x12\x04scan\x1a\nmods.proto\"}\n\x0bScanResults\x12\x12\n\
+    \x04path\x18\x01\x20\x01(\tR\x04path\x121\n\x0ematching_rules\x18\x02\
+    \x20\x03(\x0b2\n.scan.RuleR\rmatchingRules\x12'\n\x07modules\x18\x03\x20\
+    \x01(\x0b2\r.mods.ModulesR\x07modules\"\xaf\x01\n\x04Rule\x12\x1c\n\tnam\
+    espace\x18\x01\x20\x01(\tR\tnamespace\x12\x1e\n\nidentifier\x18\x02\x20\
+    \x01(\tR\nidentifier\x12\x12\n\x04tags\x18\x03\x20\x03(\tR\x04tags\x12*\
+    \n\x08metadata\x18\x04\x20\x03(\x0b2\x0e.scan.MetadataR\x08metadata\x12)\
+    \n\x08patterns\x18\x05\x20\x03(\x0b2\r.scan.PatternR\x08patterns\"\xaf\
+    \x01\n\x08Metadata\x12\x1e\n\nidentifier\x18\x01\x20\x01(\tR\nidentifier\
+    \x12\x1a\n\x07integer\x18\x02\x20\x01(\x03H\0R\x07integer\x12\x16\n\x05f\
+    loat\x18\x03\x20\x01(\x01H\0R\x05float\x12\x14\n\x04bool\x18\x04\x20\x01\
+    (\x08H\0R\x04bool\x12\x18\n\x06string\x18\x05\x20\x01(\tH\0R\x06string\
+    \x12\x16\n\x05bytes\x18\x06\x20\x01(\x0cH\0R\x05bytesB\x07\n\x05value\"P\
+    \n\x07Pattern\x12\x1e\n\nidentifier\x18\x01\x20\x01(\tR\nidentifier\x12%\
+    \n\x07matches\x18\x02\x20\x03(\x0b2\x0b.scan.MatchR\x07matches\"P\n\x05M\
+    atch\x12\x16\n\x06offset\x18\x01\x20\x01(\x04R\x06offset\x12\x16\n\x06le\
+    ngth\x18\x02\x20\x01(\x04R\x06length\x12\x17\n\x07xor_key\x18\x03\x20\
+    \x01(\rR\x06xorKeyb\x06proto2\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobuf::rt::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobuf::rt::Lazy<::protobuf::reflect::GeneratedFileDescriptor> = ::protobuf::rt::Lazy::new();
+    static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(1);
+            deps.push(super::mods::file_descriptor().clone());
+            let mut messages = ::std::vec::Vec::with_capacity(5);
+            messages.push(ScanResults::generated_message_descriptor_data());
+            messages.push(Rule::generated_message_descriptor_data());
+            messages.push(Metadata::generated_message_descriptor_data());
+            messages.push(Pattern::generated_message_descriptor_data());
+            messages.push(Match::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(0);
+            ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobuf::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
@@ -156,6 +156,12 @@ pub struct TestProto2 {
     pub ignored: ::std::option::Option<bool>,
     // @@protoc_insertion_point(field:test_proto2.TestProto2.file_size)
     pub file_size: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:test_proto2.TestProto2.int64_with_default)
+    pub int64_with_default: ::std::option::Option<i64>,
+    // @@protoc_insertion_point(field:test_proto2.TestProto2.string_with_default)
+    pub string_with_default: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:test_proto2.TestProto2.bool_with_default)
+    pub bool_with_default: ::std::option::Option<bool>,
     // @@protoc_insertion_point(field:test_proto2.TestProto2.requires_foo_and_bar)
     pub requires_foo_and_bar: ::std::option::Option<u64>,
     // @@protoc_insertion_point(field:test_proto2.TestProto2.deprecated)
@@ -1212,6 +1218,80 @@ impl TestProto2 {
         self.file_size = ::std::option::Option::Some(v);
     }
 
+    // optional int64 int64_with_default = 401;
+
+    pub fn int64_with_default(&self) -> i64 {
+        self.int64_with_default.unwrap_or(42i64)
+    }
+
+    pub fn clear_int64_with_default(&mut self) {
+        self.int64_with_default = ::std::option::Option::None;
+    }
+
+    pub fn has_int64_with_default(&self) -> bool {
+        self.int64_with_default.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_int64_with_default(&mut self, v: i64) {
+        self.int64_with_default = ::std::option::Option::Some(v);
+    }
+
+    // optional string string_with_default = 402;
+
+    pub fn string_with_default(&self) -> &str {
+        match self.string_with_default.as_ref() {
+            Some(v) => v,
+            None => "default",
+        }
+    }
+
+    pub fn clear_string_with_default(&mut self) {
+        self.string_with_default = ::std::option::Option::None;
+    }
+
+    pub fn has_string_with_default(&self) -> bool {
+        self.string_with_default.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_string_with_default(&mut self, v: ::std::string::String) {
+        self.string_with_default = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_string_with_default(&mut self) -> &mut ::std::string::String {
+        if self.string_with_default.is_none() {
+            self.string_with_default = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.string_with_default.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_string_with_default(&mut self) -> ::std::string::String {
+        self.string_with_default.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional bool bool_with_default = 403;
+
+    pub fn bool_with_default(&self) -> bool {
+        self.bool_with_default.unwrap_or(true)
+    }
+
+    pub fn clear_bool_with_default(&mut self) {
+        self.bool_with_default = ::std::option::Option::None;
+    }
+
+    pub fn has_bool_with_default(&self) -> bool {
+        self.bool_with_default.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bool_with_default(&mut self, v: bool) {
+        self.bool_with_default = ::std::option::Option::Some(v);
+    }
+
     // optional uint64 requires_foo_and_bar = 500;
 
     pub fn requires_foo_and_bar(&self) -> u64 {
@@ -1304,7 +1384,7 @@ impl TestProto2 {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(67);
+        let mut fields = ::std::vec::Vec::with_capacity(70);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
             "int32_zero",
@@ -1626,6 +1706,21 @@ impl TestProto2 {
             |m: &TestProto2| { &m.file_size },
             |m: &mut TestProto2| { &mut m.file_size },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "int64_with_default",
+            |m: &TestProto2| { &m.int64_with_default },
+            |m: &mut TestProto2| { &mut m.int64_with_default },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "string_with_default",
+            |m: &TestProto2| { &m.string_with_default },
+            |m: &mut TestProto2| { &mut m.string_with_default },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "bool_with_default",
+            |m: &TestProto2| { &m.bool_with_default },
+            |m: &mut TestProto2| { &mut m.bool_with_default },
+        ));
         fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
             "requires_foo_and_bar",
             |m: &TestProto2| { &m.requires_foo_and_bar },
@@ -2087,6 +2182,15 @@ impl ::protobuf::Message for TestProto2 {
                 3200 => {
                     self.file_size = ::std::option::Option::Some(is.read_uint64()?);
                 },
+                3208 => {
+                    self.int64_with_default = ::std::option::Option::Some(is.read_int64()?);
+                },
+                3218 => {
+                    self.string_with_default = ::std::option::Option::Some(is.read_string()?);
+                },
+                3224 => {
+                    self.bool_with_default = ::std::option::Option::Some(is.read_bool()?);
+                },
                 4000 => {
                     self.requires_foo_and_bar = ::std::option::Option::Some(is.read_uint64()?);
                 },
@@ -2330,6 +2434,15 @@ impl ::protobuf::Message for TestProto2 {
         if let Some(v) = self.file_size {
             my_size += ::protobuf::rt::uint64_size(400, v);
         }
+        if let Some(v) = self.int64_with_default {
+            my_size += ::protobuf::rt::int64_size(401, v);
+        }
+        if let Some(v) = self.string_with_default.as_ref() {
+            my_size += ::protobuf::rt::string_size(402, &v);
+        }
+        if let Some(v) = self.bool_with_default {
+            my_size += 2 + 1;
+        }
         if let Some(v) = self.requires_foo_and_bar {
             my_size += ::protobuf::rt::uint64_size(500, v);
         }
@@ -2599,6 +2712,15 @@ impl ::protobuf::Message for TestProto2 {
         if let Some(v) = self.file_size {
             os.write_uint64(400, v)?;
         }
+        if let Some(v) = self.int64_with_default {
+            os.write_int64(401, v)?;
+        }
+        if let Some(v) = self.string_with_default.as_ref() {
+            os.write_string(402, v)?;
+        }
+        if let Some(v) = self.bool_with_default {
+            os.write_bool(403, v)?;
+        }
         if let Some(v) = self.requires_foo_and_bar {
             os.write_uint64(500, v)?;
         }
@@ -2689,6 +2811,9 @@ impl ::protobuf::Message for TestProto2 {
         self.bool_proto = ::std::option::Option::None;
         self.ignored = ::std::option::Option::None;
         self.file_size = ::std::option::Option::None;
+        self.int64_with_default = ::std::option::Option::None;
+        self.string_with_default = ::std::option::Option::None;
+        self.bool_with_default = ::std::option::Option::None;
         self.requires_foo_and_bar = ::std::option::Option::None;
         self.deprecated = ::std::option::Option::None;
         self.metadata = ::std::option::Option::None;
@@ -2873,6 +2998,10 @@ pub struct NestedProto2 {
     pub nested_string: ::std::option::Option<::std::string::String>,
     // @@protoc_insertion_point(field:test_proto2.NestedProto2.nested_array_int64)
     pub nested_array_int64: ::std::vec::Vec<i64>,
+    // @@protoc_insertion_point(field:test_proto2.NestedProto2.nested_computed)
+    pub nested_computed: ::std::option::Option<i64>,
+    // @@protoc_insertion_point(field:test_proto2.NestedProto2.nested_undef)
+    pub nested_undef: ::std::option::Option<i64>,
     // special fields
     // @@protoc_insertion_point(special_field:test_proto2.NestedProto2.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -3020,8 +3149,46 @@ impl NestedProto2 {
         self.nested_string.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
+    // optional int64 nested_computed = 8;
+
+    pub fn nested_computed(&self) -> i64 {
+        self.nested_computed.unwrap_or(0)
+    }
+
+    pub fn clear_nested_computed(&mut self) {
+        self.nested_computed = ::std::option::Option::None;
+    }
+
+    pub fn has_nested_computed(&self) -> bool {
+        self.nested_computed.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nested_computed(&mut self, v: i64) {
+        self.nested_computed = ::std::option::Option::Some(v);
+    }
+
+    // optional int64 nested_undef = 9;
+
+    pub fn nested_undef(&self) -> i64 {
+        self.nested_undef.unwrap_or(0)
+    }
+
+    pub fn clear_nested_undef(&mut self) {
+        self.nested_undef = ::std::option::Option::None;
+    }
+
+    pub fn has_nested_undef(&self) -> bool {
+        self.nested_undef.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nested_undef(&mut self, v: i64) {
+        self.nested_undef = ::std::option::Option::Some(v);
+    }
+
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(7);
+        let mut fields = ::std::vec::Vec::with_capacity(9);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
             "nested_int32_zero",
@@ -3058,6 +3225,16 @@ impl NestedProto2 {
             |m: &NestedProto2| { &m.nested_array_int64 },
             |m: &mut NestedProto2| { &mut m.nested_array_int64 },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "nested_computed",
+            |m: &NestedProto2| { &m.nested_computed },
+            |m: &mut NestedProto2| { &mut m.nested_computed },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "nested_undef",
+            |m: &NestedProto2| { &m.nested_undef },
+            |m: &mut NestedProto2| { &mut m.nested_undef },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<NestedProto2>(
             "NestedProto2",
             fields,
@@ -3100,6 +3277,12 @@ impl ::protobuf::Message for NestedProto2 {
                 56 => {
                     self.nested_array_int64.push(is.read_int64()?);
                 },
+                64 => {
+                    self.nested_computed = ::std::option::Option::Some(is.read_int64()?);
+                },
+                72 => {
+                    self.nested_undef = ::std::option::Option::Some(is.read_int64()?);
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -3133,6 +3316,12 @@ impl ::protobuf::Message for NestedProto2 {
         for value in &self.nested_array_int64 {
             my_size += ::protobuf::rt::int64_size(7, *value);
         };
+        if let Some(v) = self.nested_computed {
+            my_size += ::protobuf::rt::int64_size(8, v);
+        }
+        if let Some(v) = self.nested_undef {
+            my_size += ::protobuf::rt::int64_size(9, v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -3160,6 +3349,12 @@ impl ::protobuf::Message for NestedProto2 {
         for v in &self.nested_array_int64 {
             os.write_int64(7, *v)?;
         };
+        if let Some(v) = self.nested_computed {
+            os.write_int64(8, v)?;
+        }
+        if let Some(v) = self.nested_undef {
+            os.write_int64(9, v)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3184,6 +3379,8 @@ impl ::protobuf::Message for NestedProto2 {
         self.nested_bool = ::std::option::Option::None;
         self.nested_string = ::std::option::Option::None;
         self.nested_array_int64.clear();
+        self.nested_computed = ::std::option::Option::None;
+        self.nested_undef = ::std::option::Option::None;
         self.special_fields.clear();
     }
 
@@ -3196,6 +3393,8 @@ impl ::protobuf::Message for NestedProto2 {
             nested_bool: ::std::option::Option::None,
             nested_string: ::std::option::Option::None,
             nested_array_int64: ::std::vec::Vec::new(),
+            nested_computed: ::std::option::Option::None,
+            nested_undef: ::std::option::Option::None,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -3417,7 +3616,7 @@ impl InlineEnumeration {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x11test_proto2.proto\x12\x0btest_proto2\x1a\nyara.proto\"\x9b\x1f\n\n\
+    \n\x11test_proto2.proto\x12\x0btest_proto2\x1a\nyara.proto\"\xc1\x20\n\n\
     TestProto2\x12\x1d\n\nint32_zero\x18\x01\x20\x02(\x05R\tint32Zero\x12\
     \x1d\n\nint64_zero\x18\x02\x20\x02(\x03R\tint64Zero\x12\x1f\n\x0bsint32_\
     zero\x18\x03\x20\x02(\x11R\nsint32Zero\x12\x1f\n\x0bsint64_zero\x18\x04\
@@ -3480,52 +3679,59 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     /\n\nbool_proto\x18\xde\x02\x20\x01(\x08R\tboolProtoB\x0f\x82\x93\x19\
     \x0b\n\tbool_yara\x12!\n\x07ignored\x18\xdf\x02\x20\x01(\x08R\x07ignored\
     B\x06\x82\x93\x19\x02\x10\x01\x12\x1c\n\tfile_size\x18\x90\x03\x20\x01(\
-    \x04R\x08fileSize\x12\xe6\x01\n\x14requires_foo_and_bar\x18\xf4\x03\x20\
-    \x01(\x04R\x11requiresFooAndBarB\xb3\x01\x82\x93\x19\xae\x01\x1a<\n\x0ff\
-    oo\x20is\x20required\x12\x1fthis\x20field\x20was\x20used\x20without\x20f\
-    oo\x1a\x03foo\x1a\x03FOO\x1a7\n\x0fbar\x20is\x20required\x12\x1fthis\x20\
-    field\x20was\x20used\x20without\x20bar\x1a\x03bar\x1a5\n\x10baz\x20is\
-    \x20forbidden\x12\x1cthis\x20field\x20was\x20used\x20with\x20baz\"\x03ba\
-    z\x12J\n\ndeprecated\x18\xf5\x03\x20\x01(\tR\ndeprecatedB)\x82\x93\x19%2\
-    #\n\tdon't\x20use\x12\x11use\x20`foo`\x20instead\x1a\x03foo\x12\x1b\n\
-    \x08metadata\x18\xf6\x03\x20\x01(\x0cR\x08metadata\x1a]\n\x14MapStringSt\
-    ructEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12/\n\x05value\
-    \x18\x02\x20\x01(\x0b2\x19.test_proto2.NestedProto2R\x05value:\x028\x01\
-    \x1aA\n\x13MapStringInt64Entry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03k\
-    ey\x12\x14\n\x05value\x18\x02\x20\x01(\x03R\x05value:\x028\x01\x1aB\n\
-    \x14MapStringStringEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\
-    \x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x028\x01\x1a@\n\x12MapStri\
-    ngBoolEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05val\
-    ue\x18\x02\x20\x01(\x08R\x05value:\x028\x01\x1aA\n\x13MapStringFloatEntr\
-    y\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\
-    \x20\x01(\x02R\x05value:\x028\x01\x1a\\\n\x13MapInt64StructEntry\x12\x10\
-    \n\x03key\x18\x01\x20\x01(\x03R\x03key\x12/\n\x05value\x18\x02\x20\x01(\
-    \x0b2\x19.test_proto2.NestedProto2R\x05value:\x028\x01\x1a@\n\x12MapInt6\
-    4Int64Entry\x12\x10\n\x03key\x18\x01\x20\x01(\x03R\x03key\x12\x14\n\x05v\
-    alue\x18\x02\x20\x01(\x03R\x05value:\x028\x01\x1aA\n\x13MapInt64StringEn\
-    try\x12\x10\n\x03key\x18\x01\x20\x01(\x03R\x03key\x12\x14\n\x05value\x18\
-    \x02\x20\x01(\tR\x05value:\x028\x01\x1a?\n\x11MapInt64BoolEntry\x12\x10\
-    \n\x03key\x18\x01\x20\x01(\x03R\x03key\x12\x14\n\x05value\x18\x02\x20\
-    \x01(\x08R\x05value:\x028\x01\x1a@\n\x12MapInt64FloatEntry\x12\x10\n\x03\
-    key\x18\x01\x20\x01(\x03R\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\x02\
-    R\x05value:\x028\x01\"\\\n\x0bEnumeration\x12\n\n\x06ITEM_0\x10\0\x12\n\
-    \n\x06ITEM_1\x10\x01\x12\x18\n\x06ITEM_2\x10\x02\x1a\x0c\x9a\x93\x19\x08\
-    \x08\xff\xff\xff\xff\xff\xff\x1f\x12\x1b\n\x06ITEM_3\x10\x03\x1a\x0f\x9a\
-    \x93\x19\x0b\x08\xff\xff\xff\xff\xff\xff\xff\xff\xff\x01\"3\n\x0cEnumera\
-    tion2\x12\n\n\x06ITEM_4\x10\0\x12\n\n\x06ITEM_5\x10\x01\x1a\x0b\x92\x93\
-    \x19\x07\n\x05items\"\xdb\x02\n\x0cNestedProto2\x12*\n\x11nested_int32_z\
-    ero\x18\x01\x20\x01(\x05R\x0fnestedInt32Zero\x12*\n\x11nested_int64_zero\
-    \x18\x02\x20\x01(\x03R\x0fnestedInt64Zero\x12(\n\x10nested_int32_one\x18\
-    \x03\x20\x01(\x05R\x0enestedInt32One\x12(\n\x10nested_int64_one\x18\x04\
-    \x20\x01(\x03R\x0enestedInt64One\x12\x1f\n\x0bnested_bool\x18\x05\x20\
-    \x01(\x08R\nnestedBool\x12#\n\rnested_string\x18\x06\x20\x01(\tR\x0cnest\
-    edString\x12,\n\x12nested_array_int64\x18\x07\x20\x03(\x03R\x10nestedArr\
-    ayInt64\"+\n\x11NestedEnumeration\x12\n\n\x06ITEM_0\x10\0\x12\n\n\x06ITE\
-    M_1\x10\x01*9\n\x13TopLevelEnumeration\x12\x10\n\x0bITEM_0x1000\x10\x80\
-    \x20\x12\x10\n\x0bITEM_0x2000\x10\x80@*C\n\x11InlineEnumeration\x12\x12\
-    \n\rINLINE_0x1000\x10\x80\x20\x12\x12\n\rINLINE_0x2000\x10\x80@\x1a\x06\
-    \x92\x93\x19\x02\x10\x01BJ\xfa\x92\x19F\n\x0btest_proto2\x12\x16test_pro\
-    to2.TestProto2\x1a\x0btest_proto2\"\x12test_proto2-moduleb\x06proto2\
+    \x04R\x08fileSize\x123\n\x12int64_with_default\x18\x91\x03\x20\x01(\x03:\
+    \x0242R\x10int64WithDefaultB\0\x12:\n\x13string_with_default\x18\x92\x03\
+    \x20\x01(\t:\x07defaultR\x11stringWithDefaultB\0\x123\n\x11bool_with_def\
+    ault\x18\x93\x03\x20\x01(\x08:\x04trueR\x0fboolWithDefaultB\0\x12\xe6\
+    \x01\n\x14requires_foo_and_bar\x18\xf4\x03\x20\x01(\x04R\x11requiresFooA\
+    ndBarB\xb3\x01\x82\x93\x19\xae\x01\x1a<\n\x0ffoo\x20is\x20required\x12\
+    \x1fthis\x20field\x20was\x20used\x20without\x20foo\x1a\x03foo\x1a\x03FOO\
+    \x1a7\n\x0fbar\x20is\x20required\x12\x1fthis\x20field\x20was\x20used\x20\
+    without\x20bar\x1a\x03bar\x1a5\n\x10baz\x20is\x20forbidden\x12\x1cthis\
+    \x20field\x20was\x20used\x20with\x20baz\"\x03baz\x12J\n\ndeprecated\x18\
+    \xf5\x03\x20\x01(\tR\ndeprecatedB)\x82\x93\x19%2#\n\tdon't\x20use\x12\
+    \x11use\x20`foo`\x20instead\x1a\x03foo\x12\x1b\n\x08metadata\x18\xf6\x03\
+    \x20\x01(\x0cR\x08metadata\x1a]\n\x14MapStringStructEntry\x12\x10\n\x03k\
+    ey\x18\x01\x20\x01(\tR\x03key\x12/\n\x05value\x18\x02\x20\x01(\x0b2\x19.\
+    test_proto2.NestedProto2R\x05value:\x028\x01\x1aA\n\x13MapStringInt64Ent\
+    ry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\
+    \x02\x20\x01(\x03R\x05value:\x028\x01\x1aB\n\x14MapStringStringEntry\x12\
+    \x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\
+    \x01(\tR\x05value:\x028\x01\x1a@\n\x12MapStringBoolEntry\x12\x10\n\x03ke\
+    y\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\x08R\
+    \x05value:\x028\x01\x1aA\n\x13MapStringFloatEntry\x12\x10\n\x03key\x18\
+    \x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\x02R\x05valu\
+    e:\x028\x01\x1a\\\n\x13MapInt64StructEntry\x12\x10\n\x03key\x18\x01\x20\
+    \x01(\x03R\x03key\x12/\n\x05value\x18\x02\x20\x01(\x0b2\x19.test_proto2.\
+    NestedProto2R\x05value:\x028\x01\x1a@\n\x12MapInt64Int64Entry\x12\x10\n\
+    \x03key\x18\x01\x20\x01(\x03R\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\
+    \x03R\x05value:\x028\x01\x1aA\n\x13MapInt64StringEntry\x12\x10\n\x03key\
+    \x18\x01\x20\x01(\x03R\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05\
+    value:\x028\x01\x1a?\n\x11MapInt64BoolEntry\x12\x10\n\x03key\x18\x01\x20\
+    \x01(\x03R\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\x08R\x05value:\x02\
+    8\x01\x1a@\n\x12MapInt64FloatEntry\x12\x10\n\x03key\x18\x01\x20\x01(\x03\
+    R\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\x02R\x05value:\x028\x01\"\\\
+    \n\x0bEnumeration\x12\n\n\x06ITEM_0\x10\0\x12\n\n\x06ITEM_1\x10\x01\x12\
+    \x18\n\x06ITEM_2\x10\x02\x1a\x0c\x9a\x93\x19\x08\x08\xff\xff\xff\xff\xff\
+    \xff\x1f\x12\x1b\n\x06ITEM_3\x10\x03\x1a\x0f\x9a\x93\x19\x0b\x08\xff\xff\
+    \xff\xff\xff\xff\xff\xff\xff\x01\"3\n\x0cEnumeration2\x12\n\n\x06ITEM_4\
+    \x10\0\x12\n\n\x06ITEM_5\x10\x01\x1a\x0b\x92\x93\x19\x07\n\x05items\"\
+    \xaf\x03\n\x0cNestedProto2\x12*\n\x11nested_int32_zero\x18\x01\x20\x01(\
+    \x05R\x0fnestedInt32Zero\x12*\n\x11nested_int64_zero\x18\x02\x20\x01(\
+    \x03R\x0fnestedInt64Zero\x12(\n\x10nested_int32_one\x18\x03\x20\x01(\x05\
+    R\x0enestedInt32One\x12(\n\x10nested_int64_one\x18\x04\x20\x01(\x03R\x0e\
+    nestedInt64One\x12\x1f\n\x0bnested_bool\x18\x05\x20\x01(\x08R\nnestedBoo\
+    l\x12#\n\rnested_string\x18\x06\x20\x01(\tR\x0cnestedString\x12,\n\x12ne\
+    sted_array_int64\x18\x07\x20\x03(\x03R\x10nestedArrayInt64\x12/\n\x0fnes\
+    ted_computed\x18\x08\x20\x01(\x03R\x0enestedComputedB\x06\x82\x93\x19\
+    \x028\x01\x12!\n\x0cnested_undef\x18\t\x20\x01(\x03R\x0bnestedUndef\"+\n\
+    \x11NestedEnumeration\x12\n\n\x06ITEM_0\x10\0\x12\n\n\x06ITEM_1\x10\x01*\
+    9\n\x13TopLevelEnumeration\x12\x10\n\x0bITEM_0x1000\x10\x80\x20\x12\x10\
+    \n\x0bITEM_0x2000\x10\x80@*C\n\x11InlineEnumeration\x12\x12\n\rINLINE_0x\
+    1000\x10\x80\x20\x12\x12\n\rINLINE_0x2000\x10\x80@\x1a\x06\x92\x93\x19\
+    \x02\x10\x01By\xfa\x92\x19u\n\x0btest_proto2\x12\x16test_proto2.TestProt\
+    o2\x1a\x0btest_proto2\"\x12test_proto2-module*\x11\n\tupx_magic\x12\x04U\
+    PX!*\x1a\n\x0ffoo_wide_nocase\x12\x03foo\x18\x01\x20\x01b\x06proto2\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -0,0 +1,1377 @@
+// This file is generated by rust-protobuf 3.7.2. Do not edit
+// .proto file is parsed by pure
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `wasm.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_7_2;
+
+// @@protoc_insertion_point(message:wasm.Wasm)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Wasm {
+    // message fields
+    // @@protoc_insertion_point(field:wasm.Wasm.is_wasm)
+    pub is_wasm: ::std::option::Option<bool>,
+    // @@protoc_insertion_point(field:wasm.Wasm.version)
+    pub version: ::std::option::Option<u32>,
+    // @@protoc_insertion_point(field:wasm.Wasm.number_of_sections)
+    pub number_of_sections: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:wasm.Wasm.sections)
+    pub sections: ::std::vec::Vec<Section>,
+    // @@protoc_insertion_point(field:wasm.Wasm.number_of_functions)
+    pub number_of_functions: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:wasm.Wasm.imports)
+    pub imports: ::std::vec::Vec<Import>,
+    // @@protoc_insertion_point(field:wasm.Wasm.exports)
+    pub exports: ::std::vec::Vec<Export>,
+    // @@protoc_insertion_point(field:wasm.Wasm.custom_sections)
+    pub custom_sections: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:wasm.Wasm.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Wasm {
+    fn default() -> &'a Wasm {
+        <Wasm as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Wasm {
+    pub fn new() -> Wasm {
+        ::std::default::Default::default()
+    }
+
+    // optional bool is_wasm = 1;
+
+    pub fn is_wasm(&self) -> bool {
+        self.is_wasm.unwrap_or(false)
+    }
+
+    pub fn clear_is_wasm(&mut self) {
+        self.is_wasm = ::std::option::Option::None;
+    }
+
+    pub fn has_is_wasm(&self) -> bool {
+        self.is_wasm.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_wasm(&mut self, v: bool) {
+        self.is_wasm = ::std::option::Option::Some(v);
+    }
+
+    // optional uint32 version = 2;
+
+    pub fn version(&self) -> u32 {
+        self.version.unwrap_or(0)
+    }
+
+    pub fn clear_version(&mut self) {
+        self.version = ::std::option::Option::None;
+    }
+
+    pub fn has_version(&self) -> bool {
+        self.version.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: u32) {
+        self.version = ::std::option::Option::Some(v);
+    }
+
+    // optional uint64 number_of_sections = 3;
+
+    pub fn number_of_sections(&self) -> u64 {
+        self.number_of_sections.unwrap_or(0)
+    }
+
+    pub fn clear_number_of_sections(&mut self) {
+        self.number_of_sections = ::std::option::Option::None;
+    }
+
+    pub fn has_number_of_sections(&self) -> bool {
+        self.number_of_sections.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_number_of_sections(&mut self, v: u64) {
+        self.number_of_sections = ::std::option::Option::Some(v);
+    }
+
+    // optional uint64 number_of_functions = 5;
+
+    pub fn number_of_functions(&self) -> u64 {
+        self.number_of_functions.unwrap_or(0)
+    }
+
+    pub fn clear_number_of_functions(&mut self) {
+        self.number_of_functions = ::std::option::Option::None;
+    }
+
+    pub fn has_number_of_functions(&self) -> bool {
+        self.number_of_functions.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_number_of_functions(&mut self, v: u64) {
+        self.number_of_functions = ::std::option::Option::Some(v);
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(8);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "is_wasm",
+            |m: &Wasm| { &m.is_wasm },
+            |m: &mut Wasm| { &mut m.is_wasm },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "version",
+            |m: &Wasm| { &m.version },
+            |m: &mut Wasm| { &mut m.version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "number_of_sections",
+            |m: &Wasm| { &m.number_of_sections },
+            |m: &mut Wasm| { &mut m.number_of_sections },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "sections",
+            |m: &Wasm| { &m.sections },
+            |m: &mut Wasm| { &mut m.sections },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "number_of_functions",
+            |m: &Wasm| { &m.number_of_functions },
+            |m: &mut Wasm| { &mut m.number_of_functions },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "imports",
+            |m: &Wasm| { &m.imports },
+            |m: &mut Wasm| { &mut m.imports },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "exports",
+            |m: &Wasm| { &m.exports },
+            |m: &mut Wasm| { &mut m.exports },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "custom_sections",
+            |m: &Wasm| { &m.custom_sections },
+            |m: &mut Wasm| { &mut m.custom_sections },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Wasm>(
+            "Wasm",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Wasm {
+    const NAME: &'static str = "Wasm";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.is_wasm = ::std::option::Option::Some(is.read_bool()?);
+                },
+                16 => {
+                    self.version = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                24 => {
+                    self.number_of_sections = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                34 => {
+                    self.sections.push(is.read_message()?);
+                },
+                40 => {
+                    self.number_of_functions = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                50 => {
+                    self.imports.push(is.read_message()?);
+                },
+                58 => {
+                    self.exports.push(is.read_message()?);
+                },
+                66 => {
+                    self.custom_sections.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.is_wasm {
+            my_size += 1 + 1;
+        }
+        if let Some(v) = self.version {
+            my_size += ::protobuf::rt::uint32_size(2, v);
+        }
+        if let Some(v) = self.number_of_sections {
+            my_size += ::protobuf::rt::uint64_size(3, v);
+        }
+        for value in &self.sections {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if let Some(v) = self.number_of_functions {
+            my_size += ::protobuf::rt::uint64_size(5, v);
+        }
+        for value in &self.imports {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.exports {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.custom_sections {
+            my_size += ::protobuf::rt::string_size(8, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.is_wasm {
+            os.write_bool(1, v)?;
+        }
+        if let Some(v) = self.version {
+            os.write_uint32(2, v)?;
+        }
+        if let Some(v) = self.number_of_sections {
+            os.write_uint64(3, v)?;
+        }
+        for v in &self.sections {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        };
+        if let Some(v) = self.number_of_functions {
+            os.write_uint64(5, v)?;
+        }
+        for v in &self.imports {
+            ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
+        };
+        for v in &self.exports {
+            ::protobuf::rt::write_message_field_with_cached_size(7, v, os)?;
+        };
+        for v in &self.custom_sections {
+            os.write_string(8, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Wasm {
+        Wasm::new()
+    }
+
+    fn clear(&mut self) {
+        self.is_wasm = ::std::option::Option::None;
+        self.version = ::std::option::Option::None;
+        self.number_of_sections = ::std::option::Option::None;
+        self.sections.clear();
+        self.number_of_functions = ::std::option::Option::None;
+        self.imports.clear();
+        self.exports.clear();
+        self.custom_sections.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Wasm {
+        static instance: Wasm = Wasm {
+            is_wasm: ::std::option::Option::None,
+            version: ::std::option::Option::None,
+            number_of_sections: ::std::option::Option::None,
+            sections: ::std::vec::Vec::new(),
+            number_of_functions: ::std::option::Option::None,
+            imports: ::std::vec::Vec::new(),
+            exports: ::std::vec::Vec::new(),
+            custom_sections: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Wasm {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Wasm").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Wasm {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Wasm {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:wasm.Section)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Section {
+    // message fields
+    // @@protoc_insertion_point(field:wasm.Section.type)
+    pub type_: ::std::option::Option<::protobuf::EnumOrUnknown<SectionType>>,
+    // @@protoc_insertion_point(field:wasm.Section.offset)
+    pub offset: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:wasm.Section.size)
+    pub size: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:wasm.Section.name)
+    pub name: ::std::option::Option<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:wasm.Section.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Section {
+    fn default() -> &'a Section {
+        <Section as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Section {
+    pub fn new() -> Section {
+        ::std::default::Default::default()
+    }
+
+    // optional .wasm.SectionType type = 1;
+
+    pub fn type_(&self) -> SectionType {
+        match self.type_ {
+            Some(e) => e.enum_value_or(SectionType::SECTION_CUSTOM),
+            None => SectionType::SECTION_CUSTOM,
+        }
+    }
+
+    pub fn clear_type_(&mut self) {
+        self.type_ = ::std::option::Option::None;
+    }
+
+    pub fn has_type(&self) -> bool {
+        self.type_.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_type(&mut self, v: SectionType) {
+        self.type_ = ::std::option::Option::Some(::protobuf::EnumOrUnknown::new(v));
+    }
+
+    // optional uint64 offset = 2;
+
+    pub fn offset(&self) -> u64 {
+        self.offset.unwrap_or(0)
+    }
+
+    pub fn clear_offset(&mut self) {
+        self.offset = ::std::option::Option::None;
+    }
+
+    pub fn has_offset(&self) -> bool {
+        self.offset.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_offset(&mut self, v: u64) {
+        self.offset = ::std::option::Option::Some(v);
+    }
+
+    // optional uint64 size = 3;
+
+    pub fn size(&self) -> u64 {
+        self.size.unwrap_or(0)
+    }
+
+    pub fn clear_size(&mut self) {
+        self.size = ::std::option::Option::None;
+    }
+
+    pub fn has_size(&self) -> bool {
+        self.size.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_size(&mut self, v: u64) {
+        self.size = ::std::option::Option::Some(v);
+    }
+
+    // optional string name = 4;
+
+    pub fn name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_name(&mut self) {
+        self.name = ::std::option::Option::None;
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "type",
+            |m: &Section| { &m.type_ },
+            |m: &mut Section| { &mut m.type_ },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "offset",
+            |m: &Section| { &m.offset },
+            |m: &mut Section| { &mut m.offset },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "size",
+            |m: &Section| { &m.size },
+            |m: &mut Section| { &mut m.size },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "name",
+            |m: &Section| { &m.name },
+            |m: &mut Section| { &mut m.name },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Section>(
+            "Section",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Section {
+    const NAME: &'static str = "Section";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.type_ = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                },
+                16 => {
+                    self.offset = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                24 => {
+                    self.size = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                34 => {
+                    self.name = ::std::option::Option::Some(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.type_ {
+            my_size += ::protobuf::rt::int32_size(1, v.value());
+        }
+        if let Some(v) = self.offset {
+            my_size += ::protobuf::rt::uint64_size(2, v);
+        }
+        if let Some(v) = self.size {
+            my_size += ::protobuf::rt::uint64_size(3, v);
+        }
+        if let Some(v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.type_ {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&v))?;
+        }
+        if let Some(v) = self.offset {
+            os.write_uint64(2, v)?;
+        }
+        if let Some(v) = self.size {
+            os.write_uint64(3, v)?;
+        }
+        if let Some(v) = self.name.as_ref() {
+            os.write_string(4, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Section {
+        Section::new()
+    }
+
+    fn clear(&mut self) {
+        self.type_ = ::std::option::Option::None;
+        self.offset = ::std::option::Option::None;
+        self.size = ::std::option::Option::None;
+        self.name = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Section {
+        static instance: Section = Section {
+            type_: ::std::option::Option::None,
+            offset: ::std::option::Option::None,
+            size: ::std::option::Option::None,
+            name: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Section {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Section").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Section {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Section {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:wasm.Import)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Import {
+    // message fields
+    // @@protoc_insertion_point(field:wasm.Import.module)
+    pub module: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:wasm.Import.name)
+    pub name: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:wasm.Import.kind)
+    pub kind: ::std::option::Option<::protobuf::EnumOrUnknown<ExternalKind>>,
+    // special fields
+    // @@protoc_insertion_point(special_field:wasm.Import.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Import {
+    fn default() -> &'a Import {
+        <Import as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Import {
+    pub fn new() -> Import {
+        ::std::default::Default::default()
+    }
+
+    // optional string module = 1;
+
+    pub fn module(&self) -> &str {
+        match self.module.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_module(&mut self) {
+        self.module = ::std::option::Option::None;
+    }
+
+    pub fn has_module(&self) -> bool {
+        self.module.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_module(&mut self, v: ::std::string::String) {
+        self.module = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_module(&mut self) -> &mut ::std::string::String {
+        if self.module.is_none() {
+            self.module = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.module.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_module(&mut self) -> ::std::string::String {
+        self.module.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional string name = 2;
+
+    pub fn name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_name(&mut self) {
+        self.name = ::std::option::Option::None;
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional .wasm.ExternalKind kind = 3;
+
+    pub fn kind(&self) -> ExternalKind {
+        match self.kind {
+            Some(e) => e.enum_value_or(ExternalKind::EXTERNAL_FUNCTION),
+            None => ExternalKind::EXTERNAL_FUNCTION,
+        }
+    }
+
+    pub fn clear_kind(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_kind(&self) -> bool {
+        self.kind.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: ExternalKind) {
+        self.kind = ::std::option::Option::Some(::protobuf::EnumOrUnknown::new(v));
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "module",
+            |m: &Import| { &m.module },
+            |m: &mut Import| { &mut m.module },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "name",
+            |m: &Import| { &m.name },
+            |m: &mut Import| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "kind",
+            |m: &Import| { &m.kind },
+            |m: &mut Import| { &mut m.kind },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Import>(
+            "Import",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Import {
+    const NAME: &'static str = "Import";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.module = ::std::option::Option::Some(is.read_string()?);
+                },
+                18 => {
+                    self.name = ::std::option::Option::Some(is.read_string()?);
+                },
+                24 => {
+                    self.kind = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.module.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(v) = self.kind {
+            my_size += ::protobuf::rt::int32_size(3, v.value());
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.module.as_ref() {
+            os.write_string(1, v)?;
+        }
+        if let Some(v) = self.name.as_ref() {
+            os.write_string(2, v)?;
+        }
+        if let Some(v) = self.kind {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(&v))?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Import {
+        Import::new()
+    }
+
+    fn clear(&mut self) {
+        self.module = ::std::option::Option::None;
+        self.name = ::std::option::Option::None;
+        self.kind = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Import {
+        static instance: Import = Import {
+            module: ::std::option::Option::None,
+            name: ::std::option::Option::None,
+            kind: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Import {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Import").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Import {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Import {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:wasm.Export)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Export {
+    // message fields
+    // @@protoc_insertion_point(field:wasm.Export.name)
+    pub name: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:wasm.Export.kind)
+    pub kind: ::std::option::Option<::protobuf::EnumOrUnknown<ExternalKind>>,
+    // @@protoc_insertion_point(field:wasm.Export.index)
+    pub index: ::std::option::Option<u32>,
+    // special fields
+    // @@protoc_insertion_point(special_field:wasm.Export.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Export {
+    fn default() -> &'a Export {
+        <Export as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Export {
+    pub fn new() -> Export {
+        ::std::default::Default::default()
+    }
+
+    // optional string name = 1;
+
+    pub fn name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_name(&mut self) {
+        self.name = ::std::option::Option::None;
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional .wasm.ExternalKind kind = 2;
+
+    pub fn kind(&self) -> ExternalKind {
+        match self.kind {
+            Some(e) => e.enum_value_or(ExternalKind::EXTERNAL_FUNCTION),
+            None => ExternalKind::EXTERNAL_FUNCTION,
+        }
+    }
+
+    pub fn clear_kind(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_kind(&self) -> bool {
+        self.kind.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: ExternalKind) {
+        self.kind = ::std::option::Option::Some(::protobuf::EnumOrUnknown::new(v));
+    }
+
+    // optional uint32 index = 3;
+
+    pub fn index(&self) -> u32 {
+        self.index.unwrap_or(0)
+    }
+
+    pub fn clear_index(&mut self) {
+        self.index = ::std::option::Option::None;
+    }
+
+    pub fn has_index(&self) -> bool {
+        self.index.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: u32) {
+        self.index = ::std::option::Option::Some(v);
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "name",
+            |m: &Export| { &m.name },
+            |m: &mut Export| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "kind",
+            |m: &Export| { &m.kind },
+            |m: &mut Export| { &mut m.kind },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "index",
+            |m: &Export| { &m.index },
+            |m: &mut Export| { &mut m.index },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Export>(
+            "Export",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Export {
+    const NAME: &'static str = "Export";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = ::std::option::Option::Some(is.read_string()?);
+                },
+                16 => {
+                    self.kind = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                },
+                24 => {
+                    self.index = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.kind {
+            my_size += ::protobuf::rt::int32_size(2, v.value());
+        }
+        if let Some(v) = self.index {
+            my_size += ::protobuf::rt::uint32_size(3, v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.name.as_ref() {
+            os.write_string(1, v)?;
+        }
+        if let Some(v) = self.kind {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&v))?;
+        }
+        if let Some(v) = self.index {
+            os.write_uint32(3, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Export {
+        Export::new()
+    }
+
+    fn clear(&mut self) {
+        self.name = ::std::option::Option::None;
+        self.kind = ::std::option::Option::None;
+        self.index = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Export {
+        static instance: Export = Export {
+            name: ::std::option::Option::None,
+            kind: ::std::option::Option::None,
+            index: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Export {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Export").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Export {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Export {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:wasm.SectionType)
+pub enum SectionType {
+    // @@protoc_insertion_point(enum_value:wasm.SectionType.SECTION_CUSTOM)
+    SECTION_CUSTOM = 0,
+    // @@protoc_insertion_point(enum_value:wasm.SectionType.SECTION_TYPE)
+    SECTION_TYPE = 1,
+    // @@protoc_insertion_point(enum_value:wasm.SectionType.SECTION_IMPORT)
+    SECTION_IMPORT = 2,
+    // @@protoc_insertion_point(enum_value:wasm.SectionType.SECTION_FUNCTION)
+    SECTION_FUNCTION = 3,
+    // @@protoc_insertion_point(enum_value:wasm.SectionType.SECTION_TABLE)
+    SECTION_TABLE = 4,
+    // @@protoc_insertion_point(enum_value:wasm.SectionType.SECTION_MEMORY)
+    SECTION_MEMORY = 5,
+    // @@protoc_insertion_point(enum_value:wasm.SectionType.SECTION_GLOBAL)
+    SECTION_GLOBAL = 6,
+    // @@protoc_insertion_point(enum_value:wasm.SectionType.SECTION_EXPORT)
+    SECTION_EXPORT = 7,
+    // @@protoc_insertion_point(enum_value:wasm.SectionType.SECTION_START)
+    SECTION_START = 8,
+    // @@protoc_insertion_point(enum_value:wasm.SectionType.SECTION_ELEMENT)
+    SECTION_ELEMENT = 9,
+    // @@protoc_insertion_point(enum_value:wasm.SectionType.SECTION_CODE)
+    SECTION_CODE = 10,
+    // @@protoc_insertion_point(enum_value:wasm.SectionType.SECTION_DATA)
+    SECTION_DATA = 11,
+    // @@protoc_insertion_point(enum_value:wasm.SectionType.SECTION_DATA_COUNT)
+    SECTION_DATA_COUNT = 12,
+    // @@protoc_insertion_point(enum_value:wasm.SectionType.SECTION_TAG)
+    SECTION_TAG = 13,
+}
+
+impl ::protobuf::Enum for SectionType {
+    const NAME: &'static str = "SectionType";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<SectionType> {
+        match value {
+            0 => ::std::option::Option::Some(SectionType::SECTION_CUSTOM),
+            1 => ::std::option::Option::Some(SectionType::SECTION_TYPE),
+            2 => ::std::option::Option::Some(SectionType::SECTION_IMPORT),
+            3 => ::std::option::Option::Some(SectionType::SECTION_FUNCTION),
+            4 => ::std::option::Option::Some(SectionType::SECTION_TABLE),
+            5 => ::std::option::Option::Some(SectionType::SECTION_MEMORY),
+            6 => ::std::option::Option::Some(SectionType::SECTION_GLOBAL),
+            7 => ::std::option::Option::Some(SectionType::SECTION_EXPORT),
+            8 => ::std::option::Option::Some(SectionType::SECTION_START),
+            9 => ::std::option::Option::Some(SectionType::SECTION_ELEMENT),
+            10 => ::std::option::Option::Some(SectionType::SECTION_CODE),
+            11 => ::std::option::Option::Some(SectionType::SECTION_DATA),
+            12 => ::std::option::Option::Some(SectionType::SECTION_DATA_COUNT),
+            13 => ::std::option::Option::Some(SectionType::SECTION_TAG),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<SectionType> {
+        match str {
+            "SECTION_CUSTOM" => ::std::option::Option::Some(SectionType::SECTION_CUSTOM),
+            "SECTION_TYPE" => ::std::option::Option::Some(SectionType::SECTION_TYPE),
+            "SECTION_IMPORT" => ::std::option::Option::Some(SectionType::SECTION_IMPORT),
+            "SECTION_FUNCTION" => ::std::option::Option::Some(SectionType::SECTION_FUNCTION),
+            "SECTION_TABLE" => ::std::option::Option::Some(SectionType::SECTION_TABLE),
+            "SECTION_MEMORY" => ::std::option::Option::Some(SectionType::SECTION_MEMORY),
+            "SECTION_GLOBAL" => ::std::option::Option::Some(SectionType::SECTION_GLOBAL),
+            "SECTION_EXPORT" => ::std::option::Option::Some(SectionType::SECTION_EXPORT),
+            "SECTION_START" => ::std::option::Option::Some(SectionType::SECTION_START),
+            "SECTION_ELEMENT" => ::std::option::Option::Some(SectionType::SECTION_ELEMENT),
+            "SECTION_CODE" => ::std::option::Option::Some(SectionType::SECTION_CODE),
+            "SECTION_DATA" => ::std::option::Option::Some(SectionType::SECTION_DATA),
+            "SECTION_DATA_COUNT" => ::std::option::Option::Some(SectionType::SECTION_DATA_COUNT),
+            "SECTION_TAG" => ::std::option::Option::Some(SectionType::SECTION_TAG),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [SectionType] = &[
+        SectionType::SECTION_CUSTOM,
+        SectionType::SECTION_TYPE,
+        SectionType::SECTION_IMPORT,
+        SectionType::SECTION_FUNCTION,
+        SectionType::SECTION_TABLE,
+        SectionType::SECTION_MEMORY,
+        SectionType::SECTION_GLOBAL,
+        SectionType::SECTION_EXPORT,
+        SectionType::SECTION_START,
+        SectionType::SECTION_ELEMENT,
+        SectionType::SECTION_CODE,
+        SectionType::SECTION_DATA,
+        SectionType::SECTION_DATA_COUNT,
+        SectionType::SECTION_TAG,
+    ];
+}
+
+impl ::protobuf::EnumFull for SectionType {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("SectionType").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for SectionType {
+    fn default() -> Self {
+        SectionType::SECTION_CUSTOM
+    }
+}
+
+impl SectionType {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<SectionType>("SectionType")
+    }
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:wasm.ExternalKind)
+pub enum ExternalKind {
+    // @@protoc_insertion_point(enum_value:wasm.ExternalKind.EXTERNAL_FUNCTION)
+    EXTERNAL_FUNCTION = 0,
+    // @@protoc_insertion_point(enum_value:wasm.ExternalKind.EXTERNAL_TABLE)
+    EXTERNAL_TABLE = 1,
+    // @@protoc_insertion_point(enum_value:wasm.ExternalKind.EXTERNAL_MEMORY)
+    EXTERNAL_MEMORY = 2,
+    // @@protoc_insertion_point(enum_value:wasm.ExternalKind.EXTERNAL_GLOBAL)
+    EXTERNAL_GLOBAL = 3,
+    // @@protoc_insertion_point(enum_value:wasm.ExternalKind.EXTERNAL_TAG)
+    EXTERNAL_TAG = 4,
+}
+
+impl ::protobuf::Enum for ExternalKind {
+    const NAME: &'static str = "ExternalKind";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<ExternalKind> {
+        match value {
+            0 => ::std::option::Option::Some(ExternalKind::EXTERNAL_FUNCTION),
+            1 => ::std::option::Option::Some(ExternalKind::EXTERNAL_TABLE),
+            2 => ::std::option::Option::Some(ExternalKind::EXTERNAL_MEMORY),
+            3 => ::std::option::Option::Some(ExternalKind::EXTERNAL_GLOBAL),
+            4 => ::std::option::Option::Some(ExternalKind::EXTERNAL_TAG),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<ExternalKind> {
+        match str {
+            "EXTERNAL_FUNCTION" => ::std::option::Option::Some(ExternalKind::EXTERNAL_FUNCTION),
+            "EXTERNAL_TABLE" => ::std::option::Option::Some(ExternalKind::EXTERNAL_TABLE),
+            "EXTERNAL_MEMORY" => ::std::option::Option::Some(ExternalKind::EXTERNAL_MEMORY),
+            "EXTERNAL_GLOBAL" => ::std::option::Option::Some(ExternalKind::EXTERNAL_GLOBAL),
+            "EXTERNAL_TAG" => ::std::option::Option::Some(ExternalKind::EXTERNAL_TAG),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [ExternalKind] = &[
+        ExternalKind::EXTERNAL_FUNCTION,
+        ExternalKind::EXTERNAL_TABLE,
+        ExternalKind::EXTERNAL_MEMORY,
+        ExternalKind::EXTERNAL_GLOBAL,
+        ExternalKind::EXTERNAL_TAG,
+    ];
+}
+
+impl ::protobuf::EnumFull for ExternalKind {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("ExternalKind").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for ExternalKind {
+    fn default() -> Self {
+        ExternalKind::EXTERNAL_FUNCTION
+    }
+}
+
+impl ExternalKind {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<ExternalKind>("ExternalKind")
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\nwasm.proto\x12\x04wasm\x1a\nyara.proto\"\xbb\x02\n\x04Wasm\x12\x17\n\
+    \x07is_wasm\x18\x01\x20\x01(\x08R\x06isWasm\x12\x18\n\x07version\x18\x02\
+    \x20\x01(\rR\x07version\x12,\n\x12number_of_sections\x18\x03\x20\x01(\
+    \x04R\x10numberOfSections\x12)\n\x08sections\x18\x04\x20\x03(\x0b2\r.was\
+    m.SectionR\x08sections\x12.\n\x13number_of_functions\x18\x05\x20\x01(\
+    \x04R\x11numberOfFunctions\x12&\n\x07imports\x18\x06\x20\x03(\x0b2\x0c.w\
+    asm.ImportR\x07imports\x12&\n\x07exports\x18\x07\x20\x03(\x0b2\x0c.wasm.\
+    ExportR\x07exports\x12'\n\x0fcustom_sections\x18\x08\x20\x03(\tR\x0ecust\
+    omSections\"y\n\x07Section\x12%\n\x04type\x18\x01\x20\x01(\x0e2\x11.wasm\
+    .SectionTypeR\x04type\x12\x1f\n\x06offset\x18\x02\x20\x01(\x04R\x06offse\
+    tB\x07\x82\x93\x19\x03*\x01x\x12\x12\n\x04size\x18\x03\x20\x01(\x04R\x04\
+    size\x12\x12\n\x04name\x18\x04\x20\x01(\tR\x04name\"\\\n\x06Import\x12\
+    \x16\n\x06module\x18\x01\x20\x01(\tR\x06module\x12\x12\n\x04name\x18\x02\
+    \x20\x01(\tR\x04name\x12&\n\x04kind\x18\x03\x20\x01(\x0e2\x12.wasm.Exter\
+    nalKindR\x04kind\"Z\n\x06Export\x12\x12\n\x04name\x18\x01\x20\x01(\tR\
+    \x04name\x12&\n\x04kind\x18\x02\x20\x01(\x0e2\x12.wasm.ExternalKindR\x04\
+    kind\x12\x14\n\x05index\x18\x03\x20\x01(\rR\x05index*\xa9\x02\n\x0bSecti\
+    onType\x12\x12\n\x0eSECTION_CUSTOM\x10\0\x12\x10\n\x0cSECTION_TYPE\x10\
+    \x01\x12\x12\n\x0eSECTION_IMPORT\x10\x02\x12\x14\n\x10SECTION_FUNCTION\
+    \x10\x03\x12\x11\n\rSECTION_TABLE\x10\x04\x12\x12\n\x0eSECTION_MEMORY\
+    \x10\x05\x12\x12\n\x0eSECTION_GLOBAL\x10\x06\x12\x12\n\x0eSECTION_EXPORT\
+    \x10\x07\x12\x11\n\rSECTION_START\x10\x08\x12\x13\n\x0fSECTION_ELEMENT\
+    \x10\t\x12\x10\n\x0cSECTION_CODE\x10\n\x12\x10\n\x0cSECTION_DATA\x10\x0b\
+    \x12\x16\n\x12SECTION_DATA_COUNT\x10\x0c\x12\x0f\n\x0bSECTION_TAG\x10\r\
+    \x1a\x06\x92\x93\x19\x02\x10\x01*}\n\x0cExternalKind\x12\x15\n\x11EXTERN\
+    AL_FUNCTION\x10\0\x12\x12\n\x0eEXTERNAL_TABLE\x10\x01\x12\x13\n\x0fEXTER\
+    NAL_MEMORY\x10\x02\x12\x13\n\x0fEXTERNAL_GLOBAL\x10\x03\x12\x10\n\x0cEXT\
+    ERNAL_TAG\x10\x04\x1a\x06\x92\x93\x19\x02\x10\x01B(\xfa\x92\x19$\n\x04wa\
+    sm\x12\twasm.Wasm\x1a\x04wasm\"\x0bwasm-moduleb\x06proto2\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobuf::rt::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobuf::rt::Lazy<::protobuf::reflect::GeneratedFileDescriptor> = ::protobuf::rt::Lazy::new();
+    static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(1);
+            deps.push(super::yara::file_descriptor().clone());
+            let mut messages = ::std::vec::Vec::with_capacity(4);
+            messages.push(Wasm::generated_message_descriptor_data());
+            messages.push(Section::generated_message_descriptor_data());
+            messages.push(Import::generated_message_descriptor_data());
+            messages.push(Export::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(2);
+            enums.push(SectionType::generated_enum_descriptor_data());
+            enums.push(ExternalKind::generated_enum_descriptor_data());
+            ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobuf::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
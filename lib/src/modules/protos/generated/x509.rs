@@ -0,0 +1,1944 @@
+// This file is generated by rust-protobuf 3.7.2. Do not edit
+// .proto file is parsed by pure
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `x509.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_7_2;
+
+// @@protoc_insertion_point(message:x509.X509)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct X509 {
+    // message fields
+    // @@protoc_insertion_point(field:x509.X509.is_x509)
+    pub is_x509: ::std::option::Option<bool>,
+    // @@protoc_insertion_point(field:x509.X509.format)
+    pub format: ::std::option::Option<::protobuf::EnumOrUnknown<Format>>,
+    // @@protoc_insertion_point(field:x509.X509.number_of_certificates)
+    pub number_of_certificates: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:x509.X509.certificates)
+    pub certificates: ::std::vec::Vec<Certificate>,
+    // special fields
+    // @@protoc_insertion_point(special_field:x509.X509.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a X509 {
+    fn default() -> &'a X509 {
+        <X509 as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl X509 {
+    pub fn new() -> X509 {
+        ::std::default::Default::default()
+    }
+
+    // optional bool is_x509 = 1;
+
+    pub fn is_x509(&self) -> bool {
+        self.is_x509.unwrap_or(false)
+    }
+
+    pub fn clear_is_x509(&mut self) {
+        self.is_x509 = ::std::option::Option::None;
+    }
+
+    pub fn has_is_x509(&self) -> bool {
+        self.is_x509.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_x509(&mut self, v: bool) {
+        self.is_x509 = ::std::option::Option::Some(v);
+    }
+
+    // optional .x509.Format format = 2;
+
+    pub fn format(&self) -> Format {
+        match self.format {
+            Some(e) => e.enum_value_or(Format::FORMAT_DER),
+            None => Format::FORMAT_DER,
+        }
+    }
+
+    pub fn clear_format(&mut self) {
+        self.format = ::std::option::Option::None;
+    }
+
+    pub fn has_format(&self) -> bool {
+        self.format.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_format(&mut self, v: Format) {
+        self.format = ::std::option::Option::Some(::protobuf::EnumOrUnknown::new(v));
+    }
+
+    // optional uint64 number_of_certificates = 3;
+
+    pub fn number_of_certificates(&self) -> u64 {
+        self.number_of_certificates.unwrap_or(0)
+    }
+
+    pub fn clear_number_of_certificates(&mut self) {
+        self.number_of_certificates = ::std::option::Option::None;
+    }
+
+    pub fn has_number_of_certificates(&self) -> bool {
+        self.number_of_certificates.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_number_of_certificates(&mut self, v: u64) {
+        self.number_of_certificates = ::std::option::Option::Some(v);
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "is_x509",
+            |m: &X509| { &m.is_x509 },
+            |m: &mut X509| { &mut m.is_x509 },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "format",
+            |m: &X509| { &m.format },
+            |m: &mut X509| { &mut m.format },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "number_of_certificates",
+            |m: &X509| { &m.number_of_certificates },
+            |m: &mut X509| { &mut m.number_of_certificates },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "certificates",
+            |m: &X509| { &m.certificates },
+            |m: &mut X509| { &mut m.certificates },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<X509>(
+            "X509",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for X509 {
+    const NAME: &'static str = "X509";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.is_x509 = ::std::option::Option::Some(is.read_bool()?);
+                },
+                16 => {
+                    self.format = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                },
+                24 => {
+                    self.number_of_certificates = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                34 => {
+                    self.certificates.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.is_x509 {
+            my_size += 1 + 1;
+        }
+        if let Some(v) = self.format {
+            my_size += ::protobuf::rt::int32_size(2, v.value());
+        }
+        if let Some(v) = self.number_of_certificates {
+            my_size += ::protobuf::rt::uint64_size(3, v);
+        }
+        for value in &self.certificates {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.is_x509 {
+            os.write_bool(1, v)?;
+        }
+        if let Some(v) = self.format {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&v))?;
+        }
+        if let Some(v) = self.number_of_certificates {
+            os.write_uint64(3, v)?;
+        }
+        for v in &self.certificates {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> X509 {
+        X509::new()
+    }
+
+    fn clear(&mut self) {
+        self.is_x509 = ::std::option::Option::None;
+        self.format = ::std::option::Option::None;
+        self.number_of_certificates = ::std::option::Option::None;
+        self.certificates.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static X509 {
+        static instance: X509 = X509 {
+            is_x509: ::std::option::Option::None,
+            format: ::std::option::Option::None,
+            number_of_certificates: ::std::option::Option::None,
+            certificates: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for X509 {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("X509").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for X509 {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for X509 {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:x509.Certificate)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Certificate {
+    // message fields
+    // @@protoc_insertion_point(field:x509.Certificate.issuer)
+    pub issuer: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:x509.Certificate.subject)
+    pub subject: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:x509.Certificate.thumbprint)
+    pub thumbprint: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:x509.Certificate.version)
+    pub version: ::std::option::Option<i64>,
+    // @@protoc_insertion_point(field:x509.Certificate.algorithm)
+    pub algorithm: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:x509.Certificate.algorithm_oid)
+    pub algorithm_oid: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:x509.Certificate.serial)
+    pub serial: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:x509.Certificate.not_before)
+    pub not_before: ::std::option::Option<i64>,
+    // @@protoc_insertion_point(field:x509.Certificate.not_after)
+    pub not_after: ::std::option::Option<i64>,
+    // @@protoc_insertion_point(field:x509.Certificate.self_signed)
+    pub self_signed: ::std::option::Option<bool>,
+    // @@protoc_insertion_point(field:x509.Certificate.key_algorithm)
+    pub key_algorithm: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:x509.Certificate.key_algorithm_oid)
+    pub key_algorithm_oid: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:x509.Certificate.key_size)
+    pub key_size: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:x509.Certificate.key_curve)
+    pub key_curve: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:x509.Certificate.is_ca)
+    pub is_ca: ::std::option::Option<bool>,
+    // @@protoc_insertion_point(field:x509.Certificate.key_usage)
+    pub key_usage: ::std::option::Option<u32>,
+    // @@protoc_insertion_point(field:x509.Certificate.extended_key_usage)
+    pub extended_key_usage: ::std::option::Option<u32>,
+    // @@protoc_insertion_point(field:x509.Certificate.extended_key_usage_oids)
+    pub extended_key_usage_oids: ::std::vec::Vec<::std::string::String>,
+    // @@protoc_insertion_point(field:x509.Certificate.subject_alternative_names)
+    pub subject_alternative_names: ::std::vec::Vec<::std::string::String>,
+    // @@protoc_insertion_point(field:x509.Certificate.subject_key_identifier)
+    pub subject_key_identifier: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:x509.Certificate.authority_key_identifier)
+    pub authority_key_identifier: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:x509.Certificate.extensions)
+    pub extensions: ::std::vec::Vec<Extension>,
+    // special fields
+    // @@protoc_insertion_point(special_field:x509.Certificate.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Certificate {
+    fn default() -> &'a Certificate {
+        <Certificate as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Certificate {
+    pub fn new() -> Certificate {
+        ::std::default::Default::default()
+    }
+
+    // optional string issuer = 1;
+
+    pub fn issuer(&self) -> &str {
+        match self.issuer.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_issuer(&mut self) {
+        self.issuer = ::std::option::Option::None;
+    }
+
+    pub fn has_issuer(&self) -> bool {
+        self.issuer.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_issuer(&mut self, v: ::std::string::String) {
+        self.issuer = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_issuer(&mut self) -> &mut ::std::string::String {
+        if self.issuer.is_none() {
+            self.issuer = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.issuer.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_issuer(&mut self) -> ::std::string::String {
+        self.issuer.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional string subject = 2;
+
+    pub fn subject(&self) -> &str {
+        match self.subject.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_subject(&mut self) {
+        self.subject = ::std::option::Option::None;
+    }
+
+    pub fn has_subject(&self) -> bool {
+        self.subject.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_subject(&mut self, v: ::std::string::String) {
+        self.subject = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_subject(&mut self) -> &mut ::std::string::String {
+        if self.subject.is_none() {
+            self.subject = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.subject.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_subject(&mut self) -> ::std::string::String {
+        self.subject.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional string thumbprint = 3;
+
+    pub fn thumbprint(&self) -> &str {
+        match self.thumbprint.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_thumbprint(&mut self) {
+        self.thumbprint = ::std::option::Option::None;
+    }
+
+    pub fn has_thumbprint(&self) -> bool {
+        self.thumbprint.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_thumbprint(&mut self, v: ::std::string::String) {
+        self.thumbprint = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_thumbprint(&mut self) -> &mut ::std::string::String {
+        if self.thumbprint.is_none() {
+            self.thumbprint = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.thumbprint.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_thumbprint(&mut self) -> ::std::string::String {
+        self.thumbprint.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional int64 version = 4;
+
+    pub fn version(&self) -> i64 {
+        self.version.unwrap_or(0)
+    }
+
+    pub fn clear_version(&mut self) {
+        self.version = ::std::option::Option::None;
+    }
+
+    pub fn has_version(&self) -> bool {
+        self.version.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: i64) {
+        self.version = ::std::option::Option::Some(v);
+    }
+
+    // optional string algorithm = 5;
+
+    pub fn algorithm(&self) -> &str {
+        match self.algorithm.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_algorithm(&mut self) {
+        self.algorithm = ::std::option::Option::None;
+    }
+
+    pub fn has_algorithm(&self) -> bool {
+        self.algorithm.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_algorithm(&mut self, v: ::std::string::String) {
+        self.algorithm = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_algorithm(&mut self) -> &mut ::std::string::String {
+        if self.algorithm.is_none() {
+            self.algorithm = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.algorithm.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_algorithm(&mut self) -> ::std::string::String {
+        self.algorithm.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional string algorithm_oid = 6;
+
+    pub fn algorithm_oid(&self) -> &str {
+        match self.algorithm_oid.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_algorithm_oid(&mut self) {
+        self.algorithm_oid = ::std::option::Option::None;
+    }
+
+    pub fn has_algorithm_oid(&self) -> bool {
+        self.algorithm_oid.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_algorithm_oid(&mut self, v: ::std::string::String) {
+        self.algorithm_oid = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_algorithm_oid(&mut self) -> &mut ::std::string::String {
+        if self.algorithm_oid.is_none() {
+            self.algorithm_oid = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.algorithm_oid.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_algorithm_oid(&mut self) -> ::std::string::String {
+        self.algorithm_oid.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional string serial = 7;
+
+    pub fn serial(&self) -> &str {
+        match self.serial.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_serial(&mut self) {
+        self.serial = ::std::option::Option::None;
+    }
+
+    pub fn has_serial(&self) -> bool {
+        self.serial.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_serial(&mut self, v: ::std::string::String) {
+        self.serial = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_serial(&mut self) -> &mut ::std::string::String {
+        if self.serial.is_none() {
+            self.serial = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.serial.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_serial(&mut self) -> ::std::string::String {
+        self.serial.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional int64 not_before = 8;
+
+    pub fn not_before(&self) -> i64 {
+        self.not_before.unwrap_or(0)
+    }
+
+    pub fn clear_not_before(&mut self) {
+        self.not_before = ::std::option::Option::None;
+    }
+
+    pub fn has_not_before(&self) -> bool {
+        self.not_before.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_not_before(&mut self, v: i64) {
+        self.not_before = ::std::option::Option::Some(v);
+    }
+
+    // optional int64 not_after = 9;
+
+    pub fn not_after(&self) -> i64 {
+        self.not_after.unwrap_or(0)
+    }
+
+    pub fn clear_not_after(&mut self) {
+        self.not_after = ::std::option::Option::None;
+    }
+
+    pub fn has_not_after(&self) -> bool {
+        self.not_after.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_not_after(&mut self, v: i64) {
+        self.not_after = ::std::option::Option::Some(v);
+    }
+
+    // optional bool self_signed = 10;
+
+    pub fn self_signed(&self) -> bool {
+        self.self_signed.unwrap_or(false)
+    }
+
+    pub fn clear_self_signed(&mut self) {
+        self.self_signed = ::std::option::Option::None;
+    }
+
+    pub fn has_self_signed(&self) -> bool {
+        self.self_signed.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_self_signed(&mut self, v: bool) {
+        self.self_signed = ::std::option::Option::Some(v);
+    }
+
+    // optional string key_algorithm = 11;
+
+    pub fn key_algorithm(&self) -> &str {
+        match self.key_algorithm.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_key_algorithm(&mut self) {
+        self.key_algorithm = ::std::option::Option::None;
+    }
+
+    pub fn has_key_algorithm(&self) -> bool {
+        self.key_algorithm.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_algorithm(&mut self, v: ::std::string::String) {
+        self.key_algorithm = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key_algorithm(&mut self) -> &mut ::std::string::String {
+        if self.key_algorithm.is_none() {
+            self.key_algorithm = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.key_algorithm.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_key_algorithm(&mut self) -> ::std::string::String {
+        self.key_algorithm.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional string key_algorithm_oid = 12;
+
+    pub fn key_algorithm_oid(&self) -> &str {
+        match self.key_algorithm_oid.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_key_algorithm_oid(&mut self) {
+        self.key_algorithm_oid = ::std::option::Option::None;
+    }
+
+    pub fn has_key_algorithm_oid(&self) -> bool {
+        self.key_algorithm_oid.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_algorithm_oid(&mut self, v: ::std::string::String) {
+        self.key_algorithm_oid = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key_algorithm_oid(&mut self) -> &mut ::std::string::String {
+        if self.key_algorithm_oid.is_none() {
+            self.key_algorithm_oid = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.key_algorithm_oid.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_key_algorithm_oid(&mut self) -> ::std::string::String {
+        self.key_algorithm_oid.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional uint64 key_size = 13;
+
+    pub fn key_size(&self) -> u64 {
+        self.key_size.unwrap_or(0)
+    }
+
+    pub fn clear_key_size(&mut self) {
+        self.key_size = ::std::option::Option::None;
+    }
+
+    pub fn has_key_size(&self) -> bool {
+        self.key_size.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_size(&mut self, v: u64) {
+        self.key_size = ::std::option::Option::Some(v);
+    }
+
+    // optional string key_curve = 14;
+
+    pub fn key_curve(&self) -> &str {
+        match self.key_curve.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_key_curve(&mut self) {
+        self.key_curve = ::std::option::Option::None;
+    }
+
+    pub fn has_key_curve(&self) -> bool {
+        self.key_curve.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_curve(&mut self, v: ::std::string::String) {
+        self.key_curve = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key_curve(&mut self) -> &mut ::std::string::String {
+        if self.key_curve.is_none() {
+            self.key_curve = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.key_curve.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_key_curve(&mut self) -> ::std::string::String {
+        self.key_curve.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional bool is_ca = 15;
+
+    pub fn is_ca(&self) -> bool {
+        self.is_ca.unwrap_or(false)
+    }
+
+    pub fn clear_is_ca(&mut self) {
+        self.is_ca = ::std::option::Option::None;
+    }
+
+    pub fn has_is_ca(&self) -> bool {
+        self.is_ca.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_ca(&mut self, v: bool) {
+        self.is_ca = ::std::option::Option::Some(v);
+    }
+
+    // optional uint32 key_usage = 16;
+
+    pub fn key_usage(&self) -> u32 {
+        self.key_usage.unwrap_or(0)
+    }
+
+    pub fn clear_key_usage(&mut self) {
+        self.key_usage = ::std::option::Option::None;
+    }
+
+    pub fn has_key_usage(&self) -> bool {
+        self.key_usage.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_usage(&mut self, v: u32) {
+        self.key_usage = ::std::option::Option::Some(v);
+    }
+
+    // optional uint32 extended_key_usage = 17;
+
+    pub fn extended_key_usage(&self) -> u32 {
+        self.extended_key_usage.unwrap_or(0)
+    }
+
+    pub fn clear_extended_key_usage(&mut self) {
+        self.extended_key_usage = ::std::option::Option::None;
+    }
+
+    pub fn has_extended_key_usage(&self) -> bool {
+        self.extended_key_usage.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_extended_key_usage(&mut self, v: u32) {
+        self.extended_key_usage = ::std::option::Option::Some(v);
+    }
+
+    // optional string subject_key_identifier = 20;
+
+    pub fn subject_key_identifier(&self) -> &str {
+        match self.subject_key_identifier.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_subject_key_identifier(&mut self) {
+        self.subject_key_identifier = ::std::option::Option::None;
+    }
+
+    pub fn has_subject_key_identifier(&self) -> bool {
+        self.subject_key_identifier.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_subject_key_identifier(&mut self, v: ::std::string::String) {
+        self.subject_key_identifier = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_subject_key_identifier(&mut self) -> &mut ::std::string::String {
+        if self.subject_key_identifier.is_none() {
+            self.subject_key_identifier = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.subject_key_identifier.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_subject_key_identifier(&mut self) -> ::std::string::String {
+        self.subject_key_identifier.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional string authority_key_identifier = 21;
+
+    pub fn authority_key_identifier(&self) -> &str {
+        match self.authority_key_identifier.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_authority_key_identifier(&mut self) {
+        self.authority_key_identifier = ::std::option::Option::None;
+    }
+
+    pub fn has_authority_key_identifier(&self) -> bool {
+        self.authority_key_identifier.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_authority_key_identifier(&mut self, v: ::std::string::String) {
+        self.authority_key_identifier = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_authority_key_identifier(&mut self) -> &mut ::std::string::String {
+        if self.authority_key_identifier.is_none() {
+            self.authority_key_identifier = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.authority_key_identifier.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_authority_key_identifier(&mut self) -> ::std::string::String {
+        self.authority_key_identifier.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(22);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "issuer",
+            |m: &Certificate| { &m.issuer },
+            |m: &mut Certificate| { &mut m.issuer },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "subject",
+            |m: &Certificate| { &m.subject },
+            |m: &mut Certificate| { &mut m.subject },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "thumbprint",
+            |m: &Certificate| { &m.thumbprint },
+            |m: &mut Certificate| { &mut m.thumbprint },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "version",
+            |m: &Certificate| { &m.version },
+            |m: &mut Certificate| { &mut m.version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "algorithm",
+            |m: &Certificate| { &m.algorithm },
+            |m: &mut Certificate| { &mut m.algorithm },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "algorithm_oid",
+            |m: &Certificate| { &m.algorithm_oid },
+            |m: &mut Certificate| { &mut m.algorithm_oid },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "serial",
+            |m: &Certificate| { &m.serial },
+            |m: &mut Certificate| { &mut m.serial },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "not_before",
+            |m: &Certificate| { &m.not_before },
+            |m: &mut Certificate| { &mut m.not_before },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "not_after",
+            |m: &Certificate| { &m.not_after },
+            |m: &mut Certificate| { &mut m.not_after },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "self_signed",
+            |m: &Certificate| { &m.self_signed },
+            |m: &mut Certificate| { &mut m.self_signed },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "key_algorithm",
+            |m: &Certificate| { &m.key_algorithm },
+            |m: &mut Certificate| { &mut m.key_algorithm },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "key_algorithm_oid",
+            |m: &Certificate| { &m.key_algorithm_oid },
+            |m: &mut Certificate| { &mut m.key_algorithm_oid },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "key_size",
+            |m: &Certificate| { &m.key_size },
+            |m: &mut Certificate| { &mut m.key_size },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "key_curve",
+            |m: &Certificate| { &m.key_curve },
+            |m: &mut Certificate| { &mut m.key_curve },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "is_ca",
+            |m: &Certificate| { &m.is_ca },
+            |m: &mut Certificate| { &mut m.is_ca },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "key_usage",
+            |m: &Certificate| { &m.key_usage },
+            |m: &mut Certificate| { &mut m.key_usage },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "extended_key_usage",
+            |m: &Certificate| { &m.extended_key_usage },
+            |m: &mut Certificate| { &mut m.extended_key_usage },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "extended_key_usage_oids",
+            |m: &Certificate| { &m.extended_key_usage_oids },
+            |m: &mut Certificate| { &mut m.extended_key_usage_oids },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "subject_alternative_names",
+            |m: &Certificate| { &m.subject_alternative_names },
+            |m: &mut Certificate| { &mut m.subject_alternative_names },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "subject_key_identifier",
+            |m: &Certificate| { &m.subject_key_identifier },
+            |m: &mut Certificate| { &mut m.subject_key_identifier },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "authority_key_identifier",
+            |m: &Certificate| { &m.authority_key_identifier },
+            |m: &mut Certificate| { &mut m.authority_key_identifier },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "extensions",
+            |m: &Certificate| { &m.extensions },
+            |m: &mut Certificate| { &mut m.extensions },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Certificate>(
+            "Certificate",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Certificate {
+    const NAME: &'static str = "Certificate";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.issuer = ::std::option::Option::Some(is.read_string()?);
+                },
+                18 => {
+                    self.subject = ::std::option::Option::Some(is.read_string()?);
+                },
+                26 => {
+                    self.thumbprint = ::std::option::Option::Some(is.read_string()?);
+                },
+                32 => {
+                    self.version = ::std::option::Option::Some(is.read_int64()?);
+                },
+                42 => {
+                    self.algorithm = ::std::option::Option::Some(is.read_string()?);
+                },
+                50 => {
+                    self.algorithm_oid = ::std::option::Option::Some(is.read_string()?);
+                },
+                58 => {
+                    self.serial = ::std::option::Option::Some(is.read_string()?);
+                },
+                64 => {
+                    self.not_before = ::std::option::Option::Some(is.read_int64()?);
+                },
+                72 => {
+                    self.not_after = ::std::option::Option::Some(is.read_int64()?);
+                },
+                80 => {
+                    self.self_signed = ::std::option::Option::Some(is.read_bool()?);
+                },
+                90 => {
+                    self.key_algorithm = ::std::option::Option::Some(is.read_string()?);
+                },
+                98 => {
+                    self.key_algorithm_oid = ::std::option::Option::Some(is.read_string()?);
+                },
+                104 => {
+                    self.key_size = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                114 => {
+                    self.key_curve = ::std::option::Option::Some(is.read_string()?);
+                },
+                120 => {
+                    self.is_ca = ::std::option::Option::Some(is.read_bool()?);
+                },
+                128 => {
+                    self.key_usage = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                136 => {
+                    self.extended_key_usage = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                146 => {
+                    self.extended_key_usage_oids.push(is.read_string()?);
+                },
+                154 => {
+                    self.subject_alternative_names.push(is.read_string()?);
+                },
+                162 => {
+                    self.subject_key_identifier = ::std::option::Option::Some(is.read_string()?);
+                },
+                170 => {
+                    self.authority_key_identifier = ::std::option::Option::Some(is.read_string()?);
+                },
+                178 => {
+                    self.extensions.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.issuer.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.subject.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(v) = self.thumbprint.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        if let Some(v) = self.version {
+            my_size += ::protobuf::rt::int64_size(4, v);
+        }
+        if let Some(v) = self.algorithm.as_ref() {
+            my_size += ::protobuf::rt::string_size(5, &v);
+        }
+        if let Some(v) = self.algorithm_oid.as_ref() {
+            my_size += ::protobuf::rt::string_size(6, &v);
+        }
+        if let Some(v) = self.serial.as_ref() {
+            my_size += ::protobuf::rt::string_size(7, &v);
+        }
+        if let Some(v) = self.not_before {
+            my_size += ::protobuf::rt::int64_size(8, v);
+        }
+        if let Some(v) = self.not_after {
+            my_size += ::protobuf::rt::int64_size(9, v);
+        }
+        if let Some(v) = self.self_signed {
+            my_size += 1 + 1;
+        }
+        if let Some(v) = self.key_algorithm.as_ref() {
+            my_size += ::protobuf::rt::string_size(11, &v);
+        }
+        if let Some(v) = self.key_algorithm_oid.as_ref() {
+            my_size += ::protobuf::rt::string_size(12, &v);
+        }
+        if let Some(v) = self.key_size {
+            my_size += ::protobuf::rt::uint64_size(13, v);
+        }
+        if let Some(v) = self.key_curve.as_ref() {
+            my_size += ::protobuf::rt::string_size(14, &v);
+        }
+        if let Some(v) = self.is_ca {
+            my_size += 1 + 1;
+        }
+        if let Some(v) = self.key_usage {
+            my_size += ::protobuf::rt::uint32_size(16, v);
+        }
+        if let Some(v) = self.extended_key_usage {
+            my_size += ::protobuf::rt::uint32_size(17, v);
+        }
+        for value in &self.extended_key_usage_oids {
+            my_size += ::protobuf::rt::string_size(18, &value);
+        };
+        for value in &self.subject_alternative_names {
+            my_size += ::protobuf::rt::string_size(19, &value);
+        };
+        if let Some(v) = self.subject_key_identifier.as_ref() {
+            my_size += ::protobuf::rt::string_size(20, &v);
+        }
+        if let Some(v) = self.authority_key_identifier.as_ref() {
+            my_size += ::protobuf::rt::string_size(21, &v);
+        }
+        for value in &self.extensions {
+            let len = value.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.issuer.as_ref() {
+            os.write_string(1, v)?;
+        }
+        if let Some(v) = self.subject.as_ref() {
+            os.write_string(2, v)?;
+        }
+        if let Some(v) = self.thumbprint.as_ref() {
+            os.write_string(3, v)?;
+        }
+        if let Some(v) = self.version {
+            os.write_int64(4, v)?;
+        }
+        if let Some(v) = self.algorithm.as_ref() {
+            os.write_string(5, v)?;
+        }
+        if let Some(v) = self.algorithm_oid.as_ref() {
+            os.write_string(6, v)?;
+        }
+        if let Some(v) = self.serial.as_ref() {
+            os.write_string(7, v)?;
+        }
+        if let Some(v) = self.not_before {
+            os.write_int64(8, v)?;
+        }
+        if let Some(v) = self.not_after {
+            os.write_int64(9, v)?;
+        }
+        if let Some(v) = self.self_signed {
+            os.write_bool(10, v)?;
+        }
+        if let Some(v) = self.key_algorithm.as_ref() {
+            os.write_string(11, v)?;
+        }
+        if let Some(v) = self.key_algorithm_oid.as_ref() {
+            os.write_string(12, v)?;
+        }
+        if let Some(v) = self.key_size {
+            os.write_uint64(13, v)?;
+        }
+        if let Some(v) = self.key_curve.as_ref() {
+            os.write_string(14, v)?;
+        }
+        if let Some(v) = self.is_ca {
+            os.write_bool(15, v)?;
+        }
+        if let Some(v) = self.key_usage {
+            os.write_uint32(16, v)?;
+        }
+        if let Some(v) = self.extended_key_usage {
+            os.write_uint32(17, v)?;
+        }
+        for v in &self.extended_key_usage_oids {
+            os.write_string(18, &v)?;
+        };
+        for v in &self.subject_alternative_names {
+            os.write_string(19, &v)?;
+        };
+        if let Some(v) = self.subject_key_identifier.as_ref() {
+            os.write_string(20, v)?;
+        }
+        if let Some(v) = self.authority_key_identifier.as_ref() {
+            os.write_string(21, v)?;
+        }
+        for v in &self.extensions {
+            ::protobuf::rt::write_message_field_with_cached_size(22, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Certificate {
+        Certificate::new()
+    }
+
+    fn clear(&mut self) {
+        self.issuer = ::std::option::Option::None;
+        self.subject = ::std::option::Option::None;
+        self.thumbprint = ::std::option::Option::None;
+        self.version = ::std::option::Option::None;
+        self.algorithm = ::std::option::Option::None;
+        self.algorithm_oid = ::std::option::Option::None;
+        self.serial = ::std::option::Option::None;
+        self.not_before = ::std::option::Option::None;
+        self.not_after = ::std::option::Option::None;
+        self.self_signed = ::std::option::Option::None;
+        self.key_algorithm = ::std::option::Option::None;
+        self.key_algorithm_oid = ::std::option::Option::None;
+        self.key_size = ::std::option::Option::None;
+        self.key_curve = ::std::option::Option::None;
+        self.is_ca = ::std::option::Option::None;
+        self.key_usage = ::std::option::Option::None;
+        self.extended_key_usage = ::std::option::Option::None;
+        self.extended_key_usage_oids.clear();
+        self.subject_alternative_names.clear();
+        self.subject_key_identifier = ::std::option::Option::None;
+        self.authority_key_identifier = ::std::option::Option::None;
+        self.extensions.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Certificate {
+        static instance: Certificate = Certificate {
+            issuer: ::std::option::Option::None,
+            subject: ::std::option::Option::None,
+            thumbprint: ::std::option::Option::None,
+            version: ::std::option::Option::None,
+            algorithm: ::std::option::Option::None,
+            algorithm_oid: ::std::option::Option::None,
+            serial: ::std::option::Option::None,
+            not_before: ::std::option::Option::None,
+            not_after: ::std::option::Option::None,
+            self_signed: ::std::option::Option::None,
+            key_algorithm: ::std::option::Option::None,
+            key_algorithm_oid: ::std::option::Option::None,
+            key_size: ::std::option::Option::None,
+            key_curve: ::std::option::Option::None,
+            is_ca: ::std::option::Option::None,
+            key_usage: ::std::option::Option::None,
+            extended_key_usage: ::std::option::Option::None,
+            extended_key_usage_oids: ::std::vec::Vec::new(),
+            subject_alternative_names: ::std::vec::Vec::new(),
+            subject_key_identifier: ::std::option::Option::None,
+            authority_key_identifier: ::std::option::Option::None,
+            extensions: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Certificate {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Certificate").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Certificate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Certificate {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:x509.Extension)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Extension {
+    // message fields
+    // @@protoc_insertion_point(field:x509.Extension.oid)
+    pub oid: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:x509.Extension.name)
+    pub name: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:x509.Extension.critical)
+    pub critical: ::std::option::Option<bool>,
+    // special fields
+    // @@protoc_insertion_point(special_field:x509.Extension.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Extension {
+    fn default() -> &'a Extension {
+        <Extension as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Extension {
+    pub fn new() -> Extension {
+        ::std::default::Default::default()
+    }
+
+    // optional string oid = 1;
+
+    pub fn oid(&self) -> &str {
+        match self.oid.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_oid(&mut self) {
+        self.oid = ::std::option::Option::None;
+    }
+
+    pub fn has_oid(&self) -> bool {
+        self.oid.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_oid(&mut self, v: ::std::string::String) {
+        self.oid = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_oid(&mut self) -> &mut ::std::string::String {
+        if self.oid.is_none() {
+            self.oid = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.oid.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_oid(&mut self) -> ::std::string::String {
+        self.oid.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional string name = 2;
+
+    pub fn name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_name(&mut self) {
+        self.name = ::std::option::Option::None;
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional bool critical = 3;
+
+    pub fn critical(&self) -> bool {
+        self.critical.unwrap_or(false)
+    }
+
+    pub fn clear_critical(&mut self) {
+        self.critical = ::std::option::Option::None;
+    }
+
+    pub fn has_critical(&self) -> bool {
+        self.critical.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_critical(&mut self, v: bool) {
+        self.critical = ::std::option::Option::Some(v);
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "oid",
+            |m: &Extension| { &m.oid },
+            |m: &mut Extension| { &mut m.oid },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "name",
+            |m: &Extension| { &m.name },
+            |m: &mut Extension| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "critical",
+            |m: &Extension| { &m.critical },
+            |m: &mut Extension| { &mut m.critical },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Extension>(
+            "Extension",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Extension {
+    const NAME: &'static str = "Extension";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.oid = ::std::option::Option::Some(is.read_string()?);
+                },
+                18 => {
+                    self.name = ::std::option::Option::Some(is.read_string()?);
+                },
+                24 => {
+                    self.critical = ::std::option::Option::Some(is.read_bool()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.oid.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(v) = self.critical {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.oid.as_ref() {
+            os.write_string(1, v)?;
+        }
+        if let Some(v) = self.name.as_ref() {
+            os.write_string(2, v)?;
+        }
+        if let Some(v) = self.critical {
+            os.write_bool(3, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Extension {
+        Extension::new()
+    }
+
+    fn clear(&mut self) {
+        self.oid = ::std::option::Option::None;
+        self.name = ::std::option::Option::None;
+        self.critical = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Extension {
+        static instance: Extension = Extension {
+            oid: ::std::option::Option::None,
+            name: ::std::option::Option::None,
+            critical: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Extension {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Extension").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Extension {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Extension {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:x509.Format)
+pub enum Format {
+    // @@protoc_insertion_point(enum_value:x509.Format.FORMAT_DER)
+    FORMAT_DER = 1,
+    // @@protoc_insertion_point(enum_value:x509.Format.FORMAT_PEM)
+    FORMAT_PEM = 2,
+    // @@protoc_insertion_point(enum_value:x509.Format.FORMAT_PKCS7)
+    FORMAT_PKCS7 = 3,
+}
+
+impl ::protobuf::Enum for Format {
+    const NAME: &'static str = "Format";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Format> {
+        match value {
+            1 => ::std::option::Option::Some(Format::FORMAT_DER),
+            2 => ::std::option::Option::Some(Format::FORMAT_PEM),
+            3 => ::std::option::Option::Some(Format::FORMAT_PKCS7),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<Format> {
+        match str {
+            "FORMAT_DER" => ::std::option::Option::Some(Format::FORMAT_DER),
+            "FORMAT_PEM" => ::std::option::Option::Some(Format::FORMAT_PEM),
+            "FORMAT_PKCS7" => ::std::option::Option::Some(Format::FORMAT_PKCS7),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [Format] = &[
+        Format::FORMAT_DER,
+        Format::FORMAT_PEM,
+        Format::FORMAT_PKCS7,
+    ];
+}
+
+impl ::protobuf::EnumFull for Format {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("Format").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = match self {
+            Format::FORMAT_DER => 0,
+            Format::FORMAT_PEM => 1,
+            Format::FORMAT_PKCS7 => 2,
+        };
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+// Note, `Default` is implemented although default value is not 0
+impl ::std::default::Default for Format {
+    fn default() -> Self {
+        Format::FORMAT_DER
+    }
+}
+
+impl Format {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Format>("Format")
+    }
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:x509.KeyUsage)
+pub enum KeyUsage {
+    // @@protoc_insertion_point(enum_value:x509.KeyUsage.KEY_USAGE_DIGITAL_SIGNATURE)
+    KEY_USAGE_DIGITAL_SIGNATURE = 1,
+    // @@protoc_insertion_point(enum_value:x509.KeyUsage.KEY_USAGE_NON_REPUDIATION)
+    KEY_USAGE_NON_REPUDIATION = 2,
+    // @@protoc_insertion_point(enum_value:x509.KeyUsage.KEY_USAGE_KEY_ENCIPHERMENT)
+    KEY_USAGE_KEY_ENCIPHERMENT = 4,
+    // @@protoc_insertion_point(enum_value:x509.KeyUsage.KEY_USAGE_DATA_ENCIPHERMENT)
+    KEY_USAGE_DATA_ENCIPHERMENT = 8,
+    // @@protoc_insertion_point(enum_value:x509.KeyUsage.KEY_USAGE_KEY_AGREEMENT)
+    KEY_USAGE_KEY_AGREEMENT = 16,
+    // @@protoc_insertion_point(enum_value:x509.KeyUsage.KEY_USAGE_KEY_CERT_SIGN)
+    KEY_USAGE_KEY_CERT_SIGN = 32,
+    // @@protoc_insertion_point(enum_value:x509.KeyUsage.KEY_USAGE_CRL_SIGN)
+    KEY_USAGE_CRL_SIGN = 64,
+    // @@protoc_insertion_point(enum_value:x509.KeyUsage.KEY_USAGE_ENCIPHER_ONLY)
+    KEY_USAGE_ENCIPHER_ONLY = 128,
+    // @@protoc_insertion_point(enum_value:x509.KeyUsage.KEY_USAGE_DECIPHER_ONLY)
+    KEY_USAGE_DECIPHER_ONLY = 256,
+}
+
+impl ::protobuf::Enum for KeyUsage {
+    const NAME: &'static str = "KeyUsage";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<KeyUsage> {
+        match value {
+            1 => ::std::option::Option::Some(KeyUsage::KEY_USAGE_DIGITAL_SIGNATURE),
+            2 => ::std::option::Option::Some(KeyUsage::KEY_USAGE_NON_REPUDIATION),
+            4 => ::std::option::Option::Some(KeyUsage::KEY_USAGE_KEY_ENCIPHERMENT),
+            8 => ::std::option::Option::Some(KeyUsage::KEY_USAGE_DATA_ENCIPHERMENT),
+            16 => ::std::option::Option::Some(KeyUsage::KEY_USAGE_KEY_AGREEMENT),
+            32 => ::std::option::Option::Some(KeyUsage::KEY_USAGE_KEY_CERT_SIGN),
+            64 => ::std::option::Option::Some(KeyUsage::KEY_USAGE_CRL_SIGN),
+            128 => ::std::option::Option::Some(KeyUsage::KEY_USAGE_ENCIPHER_ONLY),
+            256 => ::std::option::Option::Some(KeyUsage::KEY_USAGE_DECIPHER_ONLY),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<KeyUsage> {
+        match str {
+            "KEY_USAGE_DIGITAL_SIGNATURE" => ::std::option::Option::Some(KeyUsage::KEY_USAGE_DIGITAL_SIGNATURE),
+            "KEY_USAGE_NON_REPUDIATION" => ::std::option::Option::Some(KeyUsage::KEY_USAGE_NON_REPUDIATION),
+            "KEY_USAGE_KEY_ENCIPHERMENT" => ::std::option::Option::Some(KeyUsage::KEY_USAGE_KEY_ENCIPHERMENT),
+            "KEY_USAGE_DATA_ENCIPHERMENT" => ::std::option::Option::Some(KeyUsage::KEY_USAGE_DATA_ENCIPHERMENT),
+            "KEY_USAGE_KEY_AGREEMENT" => ::std::option::Option::Some(KeyUsage::KEY_USAGE_KEY_AGREEMENT),
+            "KEY_USAGE_KEY_CERT_SIGN" => ::std::option::Option::Some(KeyUsage::KEY_USAGE_KEY_CERT_SIGN),
+            "KEY_USAGE_CRL_SIGN" => ::std::option::Option::Some(KeyUsage::KEY_USAGE_CRL_SIGN),
+            "KEY_USAGE_ENCIPHER_ONLY" => ::std::option::Option::Some(KeyUsage::KEY_USAGE_ENCIPHER_ONLY),
+            "KEY_USAGE_DECIPHER_ONLY" => ::std::option::Option::Some(KeyUsage::KEY_USAGE_DECIPHER_ONLY),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [KeyUsage] = &[
+        KeyUsage::KEY_USAGE_DIGITAL_SIGNATURE,
+        KeyUsage::KEY_USAGE_NON_REPUDIATION,
+        KeyUsage::KEY_USAGE_KEY_ENCIPHERMENT,
+        KeyUsage::KEY_USAGE_DATA_ENCIPHERMENT,
+        KeyUsage::KEY_USAGE_KEY_AGREEMENT,
+        KeyUsage::KEY_USAGE_KEY_CERT_SIGN,
+        KeyUsage::KEY_USAGE_CRL_SIGN,
+        KeyUsage::KEY_USAGE_ENCIPHER_ONLY,
+        KeyUsage::KEY_USAGE_DECIPHER_ONLY,
+    ];
+}
+
+impl ::protobuf::EnumFull for KeyUsage {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("KeyUsage").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = match self {
+            KeyUsage::KEY_USAGE_DIGITAL_SIGNATURE => 0,
+            KeyUsage::KEY_USAGE_NON_REPUDIATION => 1,
+            KeyUsage::KEY_USAGE_KEY_ENCIPHERMENT => 2,
+            KeyUsage::KEY_USAGE_DATA_ENCIPHERMENT => 3,
+            KeyUsage::KEY_USAGE_KEY_AGREEMENT => 4,
+            KeyUsage::KEY_USAGE_KEY_CERT_SIGN => 5,
+            KeyUsage::KEY_USAGE_CRL_SIGN => 6,
+            KeyUsage::KEY_USAGE_ENCIPHER_ONLY => 7,
+            KeyUsage::KEY_USAGE_DECIPHER_ONLY => 8,
+        };
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+// Note, `Default` is implemented although default value is not 0
+impl ::std::default::Default for KeyUsage {
+    fn default() -> Self {
+        KeyUsage::KEY_USAGE_DIGITAL_SIGNATURE
+    }
+}
+
+impl KeyUsage {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<KeyUsage>("KeyUsage")
+    }
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:x509.ExtendedKeyUsage)
+pub enum ExtendedKeyUsage {
+    // @@protoc_insertion_point(enum_value:x509.ExtendedKeyUsage.EKU_ANY)
+    EKU_ANY = 1,
+    // @@protoc_insertion_point(enum_value:x509.ExtendedKeyUsage.EKU_SERVER_AUTH)
+    EKU_SERVER_AUTH = 2,
+    // @@protoc_insertion_point(enum_value:x509.ExtendedKeyUsage.EKU_CLIENT_AUTH)
+    EKU_CLIENT_AUTH = 4,
+    // @@protoc_insertion_point(enum_value:x509.ExtendedKeyUsage.EKU_CODE_SIGNING)
+    EKU_CODE_SIGNING = 8,
+    // @@protoc_insertion_point(enum_value:x509.ExtendedKeyUsage.EKU_EMAIL_PROTECTION)
+    EKU_EMAIL_PROTECTION = 16,
+    // @@protoc_insertion_point(enum_value:x509.ExtendedKeyUsage.EKU_TIME_STAMPING)
+    EKU_TIME_STAMPING = 32,
+    // @@protoc_insertion_point(enum_value:x509.ExtendedKeyUsage.EKU_OCSP_SIGNING)
+    EKU_OCSP_SIGNING = 64,
+    // @@protoc_insertion_point(enum_value:x509.ExtendedKeyUsage.EKU_SPC_INDIVIDUAL_SP_KEY_PURPOSE)
+    EKU_SPC_INDIVIDUAL_SP_KEY_PURPOSE = 128,
+    // @@protoc_insertion_point(enum_value:x509.ExtendedKeyUsage.EKU_SPC_COMMERCIAL_SP_KEY_PURPOSE)
+    EKU_SPC_COMMERCIAL_SP_KEY_PURPOSE = 256,
+    // @@protoc_insertion_point(enum_value:x509.ExtendedKeyUsage.EKU_LIFETIME_SIGNING)
+    EKU_LIFETIME_SIGNING = 512,
+    // @@protoc_insertion_point(enum_value:x509.ExtendedKeyUsage.EKU_KERNEL_MODE_CODE_SIGNING)
+    EKU_KERNEL_MODE_CODE_SIGNING = 1024,
+}
+
+impl ::protobuf::Enum for ExtendedKeyUsage {
+    const NAME: &'static str = "ExtendedKeyUsage";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<ExtendedKeyUsage> {
+        match value {
+            1 => ::std::option::Option::Some(ExtendedKeyUsage::EKU_ANY),
+            2 => ::std::option::Option::Some(ExtendedKeyUsage::EKU_SERVER_AUTH),
+            4 => ::std::option::Option::Some(ExtendedKeyUsage::EKU_CLIENT_AUTH),
+            8 => ::std::option::Option::Some(ExtendedKeyUsage::EKU_CODE_SIGNING),
+            16 => ::std::option::Option::Some(ExtendedKeyUsage::EKU_EMAIL_PROTECTION),
+            32 => ::std::option::Option::Some(ExtendedKeyUsage::EKU_TIME_STAMPING),
+            64 => ::std::option::Option::Some(ExtendedKeyUsage::EKU_OCSP_SIGNING),
+            128 => ::std::option::Option::Some(ExtendedKeyUsage::EKU_SPC_INDIVIDUAL_SP_KEY_PURPOSE),
+            256 => ::std::option::Option::Some(ExtendedKeyUsage::EKU_SPC_COMMERCIAL_SP_KEY_PURPOSE),
+            512 => ::std::option::Option::Some(ExtendedKeyUsage::EKU_LIFETIME_SIGNING),
+            1024 => ::std::option::Option::Some(ExtendedKeyUsage::EKU_KERNEL_MODE_CODE_SIGNING),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<ExtendedKeyUsage> {
+        match str {
+            "EKU_ANY" => ::std::option::Option::Some(ExtendedKeyUsage::EKU_ANY),
+            "EKU_SERVER_AUTH" => ::std::option::Option::Some(ExtendedKeyUsage::EKU_SERVER_AUTH),
+            "EKU_CLIENT_AUTH" => ::std::option::Option::Some(ExtendedKeyUsage::EKU_CLIENT_AUTH),
+            "EKU_CODE_SIGNING" => ::std::option::Option::Some(ExtendedKeyUsage::EKU_CODE_SIGNING),
+            "EKU_EMAIL_PROTECTION" => ::std::option::Option::Some(ExtendedKeyUsage::EKU_EMAIL_PROTECTION),
+            "EKU_TIME_STAMPING" => ::std::option::Option::Some(ExtendedKeyUsage::EKU_TIME_STAMPING),
+            "EKU_OCSP_SIGNING" => ::std::option::Option::Some(ExtendedKeyUsage::EKU_OCSP_SIGNING),
+            "EKU_SPC_INDIVIDUAL_SP_KEY_PURPOSE" => ::std::option::Option::Some(ExtendedKeyUsage::EKU_SPC_INDIVIDUAL_SP_KEY_PURPOSE),
+            "EKU_SPC_COMMERCIAL_SP_KEY_PURPOSE" => ::std::option::Option::Some(ExtendedKeyUsage::EKU_SPC_COMMERCIAL_SP_KEY_PURPOSE),
+            "EKU_LIFETIME_SIGNING" => ::std::option::Option::Some(ExtendedKeyUsage::EKU_LIFETIME_SIGNING),
+            "EKU_KERNEL_MODE_CODE_SIGNING" => ::std::option::Option::Some(ExtendedKeyUsage::EKU_KERNEL_MODE_CODE_SIGNING),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [ExtendedKeyUsage] = &[
+        ExtendedKeyUsage::EKU_ANY,
+        ExtendedKeyUsage::EKU_SERVER_AUTH,
+        ExtendedKeyUsage::EKU_CLIENT_AUTH,
+        ExtendedKeyUsage::EKU_CODE_SIGNING,
+        ExtendedKeyUsage::EKU_EMAIL_PROTECTION,
+        ExtendedKeyUsage::EKU_TIME_STAMPING,
+        ExtendedKeyUsage::EKU_OCSP_SIGNING,
+        ExtendedKeyUsage::EKU_SPC_INDIVIDUAL_SP_KEY_PURPOSE,
+        ExtendedKeyUsage::EKU_SPC_COMMERCIAL_SP_KEY_PURPOSE,
+        ExtendedKeyUsage::EKU_LIFETIME_SIGNING,
+        ExtendedKeyUsage::EKU_KERNEL_MODE_CODE_SIGNING,
+    ];
+}
+
+impl ::protobuf::EnumFull for ExtendedKeyUsage {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("ExtendedKeyUsage").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = match self {
+            ExtendedKeyUsage::EKU_ANY => 0,
+            ExtendedKeyUsage::EKU_SERVER_AUTH => 1,
+            ExtendedKeyUsage::EKU_CLIENT_AUTH => 2,
+            ExtendedKeyUsage::EKU_CODE_SIGNING => 3,
+            ExtendedKeyUsage::EKU_EMAIL_PROTECTION => 4,
+            ExtendedKeyUsage::EKU_TIME_STAMPING => 5,
+            ExtendedKeyUsage::EKU_OCSP_SIGNING => 6,
+            ExtendedKeyUsage::EKU_SPC_INDIVIDUAL_SP_KEY_PURPOSE => 7,
+            ExtendedKeyUsage::EKU_SPC_COMMERCIAL_SP_KEY_PURPOSE => 8,
+            ExtendedKeyUsage::EKU_LIFETIME_SIGNING => 9,
+            ExtendedKeyUsage::EKU_KERNEL_MODE_CODE_SIGNING => 10,
+        };
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+// Note, `Default` is implemented although default value is not 0
+impl ::std::default::Default for ExtendedKeyUsage {
+    fn default() -> Self {
+        ExtendedKeyUsage::EKU_ANY
+    }
+}
+
+impl ExtendedKeyUsage {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<ExtendedKeyUsage>("ExtendedKeyUsage")
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\nx509.proto\x12\x04x509\x1a\nyara.proto\"\xb2\x01\n\x04X509\x12\x17\n\
+    \x07is_x509\x18\x01\x20\x01(\x08R\x06isX509\x12$\n\x06format\x18\x02\x20\
+    \x01(\x0e2\x0c.x509.FormatR\x06format\x124\n\x16number_of_certificates\
+    \x18\x03\x20\x01(\x04R\x14numberOfCertificates\x125\n\x0ccertificates\
+    \x18\x04\x20\x03(\x0b2\x11.x509.CertificateR\x0ccertificates\"\xf4\x06\n\
+    \x0bCertificate\x12\x16\n\x06issuer\x18\x01\x20\x01(\tR\x06issuer\x12\
+    \x18\n\x07subject\x18\x02\x20\x01(\tR\x07subject\x12\x1e\n\nthumbprint\
+    \x18\x03\x20\x01(\tR\nthumbprint\x12\x18\n\x07version\x18\x04\x20\x01(\
+    \x03R\x07version\x12\x1c\n\talgorithm\x18\x05\x20\x01(\tR\talgorithm\x12\
+    #\n\ralgorithm_oid\x18\x06\x20\x01(\tR\x0calgorithmOid\x12\x16\n\x06seri\
+    al\x18\x07\x20\x01(\tR\x06serial\x12&\n\nnot_before\x18\x08\x20\x01(\x03\
+    R\tnotBeforeB\x07\x82\x93\x19\x03*\x01t\x12$\n\tnot_after\x18\t\x20\x01(\
+    \x03R\x08notAfterB\x07\x82\x93\x19\x03*\x01t\x12\x1f\n\x0bself_signed\
+    \x18\n\x20\x01(\x08R\nselfSigned\x12#\n\rkey_algorithm\x18\x0b\x20\x01(\
+    \tR\x0ckeyAlgorithm\x12*\n\x11key_algorithm_oid\x18\x0c\x20\x01(\tR\x0fk\
+    eyAlgorithmOid\x12\x19\n\x08key_size\x18\r\x20\x01(\x04R\x07keySize\x12\
+    \x1b\n\tkey_curve\x18\x0e\x20\x01(\tR\x08keyCurve\x12\x13\n\x05is_ca\x18\
+    \x0f\x20\x01(\x08R\x04isCa\x121\n\tkey_usage\x18\x10\x20\x01(\rR\x08keyU\
+    sageB\x14\x82\x93\x19\x10*\x0eflags:KeyUsage\x12J\n\x12extended_key_usag\
+    e\x18\x11\x20\x01(\rR\x10extendedKeyUsageB\x1c\x82\x93\x19\x18*\x16flags\
+    :ExtendedKeyUsage\x125\n\x17extended_key_usage_oids\x18\x12\x20\x03(\tR\
+    \x14extendedKeyUsageOids\x12:\n\x19subject_alternative_names\x18\x13\x20\
+    \x03(\tR\x17subjectAlternativeNames\x124\n\x16subject_key_identifier\x18\
+    \x14\x20\x01(\tR\x14subjectKeyIdentifier\x128\n\x18authority_key_identif\
+    ier\x18\x15\x20\x01(\tR\x16authorityKeyIdentifier\x12/\n\nextensions\x18\
+    \x16\x20\x03(\x0b2\x0f.x509.ExtensionR\nextensions\"M\n\tExtension\x12\
+    \x10\n\x03oid\x18\x01\x20\x01(\tR\x03oid\x12\x12\n\x04name\x18\x02\x20\
+    \x01(\tR\x04name\x12\x1a\n\x08critical\x18\x03\x20\x01(\x08R\x08critical\
+    *B\n\x06Format\x12\x0e\n\nFORMAT_DER\x10\x01\x12\x0e\n\nFORMAT_PEM\x10\
+    \x02\x12\x10\n\x0cFORMAT_PKCS7\x10\x03\x1a\x06\x92\x93\x19\x02\x10\x01*\
+    \xa1\x02\n\x08KeyUsage\x12\x1f\n\x1bKEY_USAGE_DIGITAL_SIGNATURE\x10\x01\
+    \x12\x1d\n\x19KEY_USAGE_NON_REPUDIATION\x10\x02\x12\x1e\n\x1aKEY_USAGE_K\
+    EY_ENCIPHERMENT\x10\x04\x12\x1f\n\x1bKEY_USAGE_DATA_ENCIPHERMENT\x10\x08\
+    \x12\x1b\n\x17KEY_USAGE_KEY_AGREEMENT\x10\x10\x12\x1b\n\x17KEY_USAGE_KEY\
+    _CERT_SIGN\x10\x20\x12\x16\n\x12KEY_USAGE_CRL_SIGN\x10@\x12\x1c\n\x17KEY\
+    _USAGE_ENCIPHER_ONLY\x10\x80\x01\x12\x1c\n\x17KEY_USAGE_DECIPHER_ONLY\
+    \x10\x80\x02\x1a\x06\x92\x93\x19\x02\x10\x01*\xbc\x02\n\x10ExtendedKeyUs\
+    age\x12\x0b\n\x07EKU_ANY\x10\x01\x12\x13\n\x0fEKU_SERVER_AUTH\x10\x02\
+    \x12\x13\n\x0fEKU_CLIENT_AUTH\x10\x04\x12\x14\n\x10EKU_CODE_SIGNING\x10\
+    \x08\x12\x18\n\x14EKU_EMAIL_PROTECTION\x10\x10\x12\x15\n\x11EKU_TIME_STA\
+    MPING\x10\x20\x12\x14\n\x10EKU_OCSP_SIGNING\x10@\x12&\n!EKU_SPC_INDIVIDU\
+    AL_SP_KEY_PURPOSE\x10\x80\x01\x12&\n!EKU_SPC_COMMERCIAL_SP_KEY_PURPOSE\
+    \x10\x80\x02\x12\x19\n\x14EKU_LIFETIME_SIGNING\x10\x80\x04\x12!\n\x1cEKU\
+    _KERNEL_MODE_CODE_SIGNING\x10\x80\x08\x1a\x06\x92\x93\x19\x02\x10\x01B(\
+    \xfa\x92\x19$\n\x04x509\x12\tx509.X509\x1a\x04x509\"\x0bx509-moduleb\x06\
+    proto2\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobuf::rt::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobuf::rt::Lazy<::protobuf::reflect::GeneratedFileDescriptor> = ::protobuf::rt::Lazy::new();
+    static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(1);
+            deps.push(super::yara::file_descriptor().clone());
+            let mut messages = ::std::vec::Vec::with_capacity(3);
+            messages.push(X509::generated_message_descriptor_data());
+            messages.push(Certificate::generated_message_descriptor_data());
+            messages.push(Extension::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(3);
+            enums.push(Format::generated_enum_descriptor_data());
+            enums.push(KeyUsage::generated_enum_descriptor_data());
+            enums.push(ExtendedKeyUsage::generated_enum_descriptor_data());
+            ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobuf::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
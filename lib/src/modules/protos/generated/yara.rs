@@ -36,6 +36,10 @@ pub struct ModuleOptions {
     pub rust_module: ::std::option::Option<::std::string::String>,
     // @@protoc_insertion_point(field:yara.ModuleOptions.cargo_feature)
     pub cargo_feature: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:yara.ModuleOptions.patterns)
+    pub patterns: ::std::vec::Vec<ModulePattern>,
+    // @@protoc_insertion_point(field:yara.ModuleOptions.main_fn_in_scan_thread)
+    pub main_fn_in_scan_thread: ::std::option::Option<bool>,
     // special fields
     // @@protoc_insertion_point(special_field:yara.ModuleOptions.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -196,8 +200,27 @@ impl ModuleOptions {
         self.cargo_feature.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
+    // optional bool main_fn_in_scan_thread = 6;
+
+    pub fn main_fn_in_scan_thread(&self) -> bool {
+        self.main_fn_in_scan_thread.unwrap_or(false)
+    }
+
+    pub fn clear_main_fn_in_scan_thread(&mut self) {
+        self.main_fn_in_scan_thread = ::std::option::Option::None;
+    }
+
+    pub fn has_main_fn_in_scan_thread(&self) -> bool {
+        self.main_fn_in_scan_thread.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_main_fn_in_scan_thread(&mut self, v: bool) {
+        self.main_fn_in_scan_thread = ::std::option::Option::Some(v);
+    }
+
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut fields = ::std::vec::Vec::with_capacity(6);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
             "name",
@@ -219,6 +242,16 @@ impl ModuleOptions {
             |m: &ModuleOptions| { &m.cargo_feature },
             |m: &mut ModuleOptions| { &mut m.cargo_feature },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "patterns",
+            |m: &ModuleOptions| { &m.patterns },
+            |m: &mut ModuleOptions| { &mut m.patterns },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "main_fn_in_scan_thread",
+            |m: &ModuleOptions| { &m.main_fn_in_scan_thread },
+            |m: &mut ModuleOptions| { &mut m.main_fn_in_scan_thread },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ModuleOptions>(
             "ModuleOptions",
             fields,
@@ -237,6 +270,11 @@ impl ::protobuf::Message for ModuleOptions {
         if self.root_message.is_none() {
             return false;
         }
+        for v in &self.patterns {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -255,6 +293,12 @@ impl ::protobuf::Message for ModuleOptions {
                 34 => {
                     self.cargo_feature = ::std::option::Option::Some(is.read_string()?);
                 },
+                42 => {
+                    self.patterns.push(is.read_message()?);
+                },
+                48 => {
+                    self.main_fn_in_scan_thread = ::std::option::Option::Some(is.read_bool()?);
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -279,6 +323,13 @@ impl ::protobuf::Message for ModuleOptions {
         if let Some(v) = self.cargo_feature.as_ref() {
             my_size += ::protobuf::rt::string_size(4, &v);
         }
+        for value in &self.patterns {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if let Some(v) = self.main_fn_in_scan_thread {
+            my_size += 1 + 1;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -297,6 +348,12 @@ impl ::protobuf::Message for ModuleOptions {
         if let Some(v) = self.cargo_feature.as_ref() {
             os.write_string(4, v)?;
         }
+        for v in &self.patterns {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        };
+        if let Some(v) = self.main_fn_in_scan_thread {
+            os.write_bool(6, v)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -318,6 +375,8 @@ impl ::protobuf::Message for ModuleOptions {
         self.root_message = ::std::option::Option::None;
         self.rust_module = ::std::option::Option::None;
         self.cargo_feature = ::std::option::Option::None;
+        self.patterns.clear();
+        self.main_fn_in_scan_thread = ::std::option::Option::None;
         self.special_fields.clear();
     }
 
@@ -327,6 +386,8 @@ impl ::protobuf::Message for ModuleOptions {
             root_message: ::std::option::Option::None,
             rust_module: ::std::option::Option::None,
             cargo_feature: ::std::option::Option::None,
+            patterns: ::std::vec::Vec::new(),
+            main_fn_in_scan_thread: ::std::option::Option::None,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -350,6 +411,298 @@ impl ::protobuf::reflect::ProtobufValue for ModuleOptions {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+// @@protoc_insertion_point(message:yara.ModulePattern)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ModulePattern {
+    // message fields
+    // @@protoc_insertion_point(field:yara.ModulePattern.identifier)
+    pub identifier: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:yara.ModulePattern.data)
+    pub data: ::std::option::Option<::std::vec::Vec<u8>>,
+    // @@protoc_insertion_point(field:yara.ModulePattern.nocase)
+    pub nocase: ::std::option::Option<bool>,
+    // @@protoc_insertion_point(field:yara.ModulePattern.wide)
+    pub wide: ::std::option::Option<bool>,
+    // special fields
+    // @@protoc_insertion_point(special_field:yara.ModulePattern.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ModulePattern {
+    fn default() -> &'a ModulePattern {
+        <ModulePattern as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ModulePattern {
+    pub fn new() -> ModulePattern {
+        ::std::default::Default::default()
+    }
+
+    // required string identifier = 1;
+
+    pub fn identifier(&self) -> &str {
+        match self.identifier.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_identifier(&mut self) {
+        self.identifier = ::std::option::Option::None;
+    }
+
+    pub fn has_identifier(&self) -> bool {
+        self.identifier.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_identifier(&mut self, v: ::std::string::String) {
+        self.identifier = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_identifier(&mut self) -> &mut ::std::string::String {
+        if self.identifier.is_none() {
+            self.identifier = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.identifier.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_identifier(&mut self) -> ::std::string::String {
+        self.identifier.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // required bytes data = 2;
+
+    pub fn data(&self) -> &[u8] {
+        match self.data.as_ref() {
+            Some(v) => v,
+            None => &[],
+        }
+    }
+
+    pub fn clear_data(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_data(&self) -> bool {
+        self.data.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.data = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.data.is_none() {
+            self.data = ::std::option::Option::Some(::std::vec::Vec::new());
+        }
+        self.data.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
+        self.data.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    // optional bool nocase = 3;
+
+    pub fn nocase(&self) -> bool {
+        self.nocase.unwrap_or(false)
+    }
+
+    pub fn clear_nocase(&mut self) {
+        self.nocase = ::std::option::Option::None;
+    }
+
+    pub fn has_nocase(&self) -> bool {
+        self.nocase.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nocase(&mut self, v: bool) {
+        self.nocase = ::std::option::Option::Some(v);
+    }
+
+    // optional bool wide = 4;
+
+    pub fn wide(&self) -> bool {
+        self.wide.unwrap_or(false)
+    }
+
+    pub fn clear_wide(&mut self) {
+        self.wide = ::std::option::Option::None;
+    }
+
+    pub fn has_wide(&self) -> bool {
+        self.wide.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_wide(&mut self, v: bool) {
+        self.wide = ::std::option::Option::Some(v);
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "identifier",
+            |m: &ModulePattern| { &m.identifier },
+            |m: &mut ModulePattern| { &mut m.identifier },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "data",
+            |m: &ModulePattern| { &m.data },
+            |m: &mut ModulePattern| { &mut m.data },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "nocase",
+            |m: &ModulePattern| { &m.nocase },
+            |m: &mut ModulePattern| { &mut m.nocase },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "wide",
+            |m: &ModulePattern| { &m.wide },
+            |m: &mut ModulePattern| { &mut m.wide },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ModulePattern>(
+            "ModulePattern",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ModulePattern {
+    const NAME: &'static str = "ModulePattern";
+
+    fn is_initialized(&self) -> bool {
+        if self.identifier.is_none() {
+            return false;
+        }
+        if self.data.is_none() {
+            return false;
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.identifier = ::std::option::Option::Some(is.read_string()?);
+                },
+                18 => {
+                    self.data = ::std::option::Option::Some(is.read_bytes()?);
+                },
+                24 => {
+                    self.nocase = ::std::option::Option::Some(is.read_bool()?);
+                },
+                32 => {
+                    self.wide = ::std::option::Option::Some(is.read_bool()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.identifier.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.data.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(2, &v);
+        }
+        if let Some(v) = self.nocase {
+            my_size += 1 + 1;
+        }
+        if let Some(v) = self.wide {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.identifier.as_ref() {
+            os.write_string(1, v)?;
+        }
+        if let Some(v) = self.data.as_ref() {
+            os.write_bytes(2, v)?;
+        }
+        if let Some(v) = self.nocase {
+            os.write_bool(3, v)?;
+        }
+        if let Some(v) = self.wide {
+            os.write_bool(4, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ModulePattern {
+        ModulePattern::new()
+    }
+
+    fn clear(&mut self) {
+        self.identifier = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.nocase = ::std::option::Option::None;
+        self.wide = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ModulePattern {
+        static instance: ModulePattern = ModulePattern {
+            identifier: ::std::option::Option::None,
+            data: ::std::option::Option::None,
+            nocase: ::std::option::Option::None,
+            wide: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ModulePattern {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ModulePattern").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ModulePattern {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ModulePattern {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
 // @@protoc_insertion_point(message:yara.FieldOptions)
 #[derive(PartialEq,Clone,Default,Debug)]
 pub struct FieldOptions {
@@ -366,6 +719,8 @@ pub struct FieldOptions {
     pub fmt: ::std::option::Option<::std::string::String>,
     // @@protoc_insertion_point(field:yara.FieldOptions.deprecation_notice)
     pub deprecation_notice: ::protobuf::MessageField<DeprecationNotice>,
+    // @@protoc_insertion_point(field:yara.FieldOptions.computed)
+    pub computed: ::std::option::Option<bool>,
     // special fields
     // @@protoc_insertion_point(special_field:yara.FieldOptions.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -492,8 +847,27 @@ impl FieldOptions {
         self.fmt.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
+    // optional bool computed = 7;
+
+    pub fn computed(&self) -> bool {
+        self.computed.unwrap_or(false)
+    }
+
+    pub fn clear_computed(&mut self) {
+        self.computed = ::std::option::Option::None;
+    }
+
+    pub fn has_computed(&self) -> bool {
+        self.computed.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_computed(&mut self, v: bool) {
+        self.computed = ::std::option::Option::Some(v);
+    }
+
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut fields = ::std::vec::Vec::with_capacity(7);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
             "name",
@@ -525,6 +899,11 @@ impl FieldOptions {
             |m: &FieldOptions| { &m.deprecation_notice },
             |m: &mut FieldOptions| { &mut m.deprecation_notice },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "computed",
+            |m: &FieldOptions| { &m.computed },
+            |m: &mut FieldOptions| { &mut m.computed },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<FieldOptions>(
             "FieldOptions",
             fields,
@@ -571,6 +950,9 @@ impl ::protobuf::Message for FieldOptions {
                 50 => {
                     ::protobuf::rt::read_singular_message_into_field(is, &mut self.deprecation_notice)?;
                 },
+                56 => {
+                    self.computed = ::std::option::Option::Some(is.read_bool()?);
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -603,6 +985,9 @@ impl ::protobuf::Message for FieldOptions {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
+        if let Some(v) = self.computed {
+            my_size += 1 + 1;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -627,6 +1012,9 @@ impl ::protobuf::Message for FieldOptions {
         if let Some(v) = self.deprecation_notice.as_ref() {
             ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
         }
+        if let Some(v) = self.computed {
+            os.write_bool(7, v)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -650,6 +1038,7 @@ impl ::protobuf::Message for FieldOptions {
         self.lowercase = ::std::option::Option::None;
         self.fmt = ::std::option::Option::None;
         self.deprecation_notice.clear();
+        self.computed = ::std::option::Option::None;
         self.special_fields.clear();
     }
 
@@ -661,6 +1050,7 @@ impl ::protobuf::Message for FieldOptions {
             lowercase: ::std::option::Option::None,
             fmt: ::std::option::Option::None,
             deprecation_notice: ::protobuf::MessageField::none(),
+            computed: ::std::option::Option::None,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -1802,16 +2192,22 @@ pub mod exts {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\nyara.proto\x12\x04yara\x1a\x20google/protobuf/descriptor.proto\"\x8c\
+    \n\nyara.proto\x12\x04yara\x1a\x20google/protobuf/descriptor.proto\"\xf1\
     \x01\n\rModuleOptions\x12\x12\n\x04name\x18\x01\x20\x02(\tR\x04name\x12!\
     \n\x0croot_message\x18\x02\x20\x02(\tR\x0brootMessage\x12\x1f\n\x0brust_\
     module\x18\x03\x20\x01(\tR\nrustModule\x12#\n\rcargo_feature\x18\x04\x20\
-    \x01(\tR\x0ccargoFeature\"\xd4\x01\n\x0cFieldOptions\x12\x12\n\x04name\
-    \x18\x01\x20\x01(\tR\x04name\x12\x16\n\x06ignore\x18\x02\x20\x01(\x08R\
-    \x06ignore\x12\x20\n\x03acl\x18\x03\x20\x03(\x0b2\x0e.yara.AclEntryR\x03\
-    acl\x12\x1c\n\tlowercase\x18\x04\x20\x01(\x08R\tlowercase\x12\x10\n\x03f\
-    mt\x18\x05\x20\x01(\tR\x03fmt\x12F\n\x12deprecation_notice\x18\x06\x20\
-    \x01(\x0b2\x17.yara.DeprecationNoticeR\x11deprecationNotice\"\x86\x01\n\
+    \x01(\tR\x0ccargoFeature\x12/\n\x08patterns\x18\x05\x20\x03(\x0b2\x13.ya\
+    ra.ModulePatternR\x08patterns\x122\n\x16main_fn_in_scan_thread\x18\x06\
+    \x20\x01(\x08R\x12mainFnInScanThread\"o\n\rModulePattern\x12\x1e\n\niden\
+    tifier\x18\x01\x20\x02(\tR\nidentifier\x12\x12\n\x04data\x18\x02\x20\x02\
+    (\x0cR\x04data\x12\x16\n\x06nocase\x18\x03\x20\x01(\x08R\x06nocase\x12\
+    \x12\n\x04wide\x18\x04\x20\x01(\x08R\x04wide\"\xf0\x01\n\x0cFieldOptions\
+    \x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x16\n\x06ignore\x18\
+    \x02\x20\x01(\x08R\x06ignore\x12\x20\n\x03acl\x18\x03\x20\x03(\x0b2\x0e.\
+    yara.AclEntryR\x03acl\x12\x1c\n\tlowercase\x18\x04\x20\x01(\x08R\tlowerc\
+    ase\x12\x10\n\x03fmt\x18\x05\x20\x01(\tR\x03fmt\x12F\n\x12deprecation_no\
+    tice\x18\x06\x20\x01(\x0b2\x17.yara.DeprecationNoticeR\x11deprecationNot\
+    ice\x12\x1a\n\x08computed\x18\x07\x20\x01(\x08R\x08computed\"\x86\x01\n\
     \x08AclEntry\x12\x1f\n\x0berror_title\x18\x01\x20\x02(\tR\nerrorTitle\
     \x12\x1f\n\x0berror_label\x18\x02\x20\x02(\tR\nerrorLabel\x12\x1b\n\tacc\
     ept_if\x18\x03\x20\x03(\tR\x08acceptIf\x12\x1b\n\treject_if\x18\x04\x20\
@@ -1850,8 +2246,9 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
         let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
             let mut deps = ::std::vec::Vec::with_capacity(1);
             deps.push(::protobuf::descriptor::file_descriptor().clone());
-            let mut messages = ::std::vec::Vec::with_capacity(7);
+            let mut messages = ::std::vec::Vec::with_capacity(8);
             messages.push(ModuleOptions::generated_message_descriptor_data());
+            messages.push(ModulePattern::generated_message_descriptor_data());
             messages.push(FieldOptions::generated_message_descriptor_data());
             messages.push(AclEntry::generated_message_descriptor_data());
             messages.push(DeprecationNotice::generated_message_descriptor_data());
@@ -0,0 +1,53 @@
+/*! YARA module that parses Windows registry hives.
+
+A registry hive is a file containing a subtree of the Windows registry, like
+the ones found in `C:\Windows\System32\config` or the `NTUSER.DAT` files in
+user profiles. Hives are also produced when the registry is exported with
+`reg save`. This module allows creating YARA rules based on the keys that
+exist in the hive, which is useful for finding persistence artifacts.
+ */
+
+use bstr::ByteSlice;
+
+use crate::modules::prelude::*;
+use crate::modules::protos::regf::*;
+
+mod parser;
+
+#[cfg(test)]
+mod tests;
+
+#[module_main]
+fn main(data: &[u8], _meta: Option<&[u8]>) -> Result<Regf, ModuleError> {
+    match parser::Hive::parse(data) {
+        Ok(hive) => Ok(hive.into()),
+        Err(_) => {
+            let mut regf = Regf::new();
+            regf.set_is_regf(false);
+            Ok(regf)
+        }
+    }
+}
+
+/// Returns true if the hive contains a key with the given path.
+///
+/// The path is relative to the root key, with components separated by
+/// backslashes, like in `Software\Microsoft\Windows\CurrentVersion\Run`.
+/// Key names are case-insensitive.
+#[module_export]
+fn key_exists(ctx: &ScanContext, path: RuntimeString) -> Option<bool> {
+    let hive = parser::Hive::parse(ctx.scanned_data()?).ok()?;
+    let path = path.as_bstr(ctx).to_str().ok()?;
+    Some(hive.find_key(path).is_some())
+}
+
+/// Returns the time when the key with the given path was last written.
+///
+/// The result is undefined if the key doesn't exist. See [`key_exists`] for
+/// details about the path.
+#[module_export]
+fn key_last_written(ctx: &ScanContext, path: RuntimeString) -> Option<i64> {
+    let hive = parser::Hive::parse(ctx.scanned_data()?).ok()?;
+    let path = path.as_bstr(ctx).to_str().ok()?;
+    hive.find_key(path)?.last_written()?.try_into().ok()
+}
@@ -0,0 +1,332 @@
+use std::collections::HashSet;
+
+use nom::bytes::complete::{tag, take};
+use nom::number::complete::{le_u16, le_u32, le_u64};
+use nom::Parser;
+
+use crate::modules::protos::regf;
+
+type NomError<'a> = nom::error::Error<&'a [u8]>;
+
+/// A Windows registry hive (regf) parser.
+///
+/// A hive starts with a 4KB base block that contains the signature ("regf"),
+/// the version of the format and the offset of the root key, among other
+/// fields. The base block is followed by hive bins, which are containers of
+/// cells. Each cell contains a single structure: a key node ("nk"), a value
+/// ("vk"), a list of subkeys ("lf", "lh", "li", "ri"), etc.
+///
+/// Cells reference each other by their offsets, which are relative to the
+/// start of the first hive bin. This parser doesn't care about hive bins
+/// themselves, it simply follows the offsets from the root key.
+///
+/// See: https://github.com/msuhanov/regf/blob/master/Windows%20registry%20file%20format%20specification.md
+pub struct Hive<'a> {
+    major_version: u32,
+    minor_version: u32,
+    last_written: u64,
+    file_name: String,
+    root_key_offset: u32,
+    bins: &'a [u8],
+}
+
+/// A key node in the hive.
+pub struct Key {
+    name: String,
+    last_written: u64,
+    number_of_subkeys: u32,
+    number_of_values: u32,
+    subkeys_list_offset: u32,
+}
+
+impl Key {
+    /// Time when the key was last written, as a UNIX timestamp.
+    pub fn last_written(&self) -> Option<u64> {
+        filetime_to_unix_timestamp(self.last_written)
+    }
+}
+
+impl<'a> Hive<'a> {
+    /// Size of the base block, which is followed by the hive bins.
+    const BASE_BLOCK_SIZE: usize = 4096;
+
+    /// Maximum number of keys visited while walking the hive.
+    const MAX_KEYS: usize = 1_000_000;
+
+    /// Maximum nesting level for index roots ("ri" lists). According to the
+    /// specification index roots can't be nested, but a small margin is
+    /// tolerated.
+    const MAX_INDEX_DEPTH: usize = 2;
+
+    /// Flag in key nodes indicating that the key name is an ASCII string,
+    /// instead of a UTF-16 one.
+    const KEY_COMP_NAME: u16 = 0x0020;
+
+    /// Parses the base block of a registry hive.
+    pub fn parse(data: &'a [u8]) -> Result<Self, nom::Err<NomError<'a>>> {
+        let (
+            _,
+            (
+                _signature,
+                _primary_seq_num,
+                _secondary_seq_num,
+                last_written,
+                major_version,
+                minor_version,
+                _file_type,
+                _file_format,
+                root_key_offset,
+                _hive_bins_data_size,
+                _clustering_factor,
+                file_name,
+            ),
+        ) = (
+            tag(b"regf".as_slice()),
+            le_u32,      // primary_seq_num
+            le_u32,      // secondary_seq_num
+            le_u64,      // last_written
+            le_u32,      // major_version
+            le_u32,      // minor_version
+            le_u32,      // file_type
+            le_u32,      // file_format
+            le_u32,      // root_key_offset
+            le_u32,      // hive_bins_data_size
+            le_u32,      // clustering_factor
+            take(64_u8), // file_name
+        )
+            .parse(data)?;
+
+        Ok(Self {
+            major_version,
+            minor_version,
+            last_written,
+            file_name: utf16_string(file_name),
+            root_key_offset,
+            bins: data.get(Self::BASE_BLOCK_SIZE..).unwrap_or_default(),
+        })
+    }
+
+    /// Returns the root key of the hive.
+    pub fn root_key(&self) -> Option<Key> {
+        self.key(self.root_key_offset)
+    }
+
+    /// Finds a key by its path.
+    ///
+    /// The path is relative to the root key, and its components are
+    /// separated by backslashes. Leading and trailing backslashes are
+    /// ignored. Like in the Windows registry, key names are case-insensitive.
+    pub fn find_key(&self, path: &str) -> Option<Key> {
+        let mut key = self.root_key()?;
+
+        for component in path.split('\\').filter(|c| !c.is_empty()) {
+            let component = component.to_lowercase();
+            key = self
+                .subkeys(&key)
+                .into_iter()
+                .filter_map(|offset| self.key(offset))
+                .find(|subkey| subkey.name.to_lowercase() == component)?;
+        }
+
+        Some(key)
+    }
+
+    /// Walks the whole hive starting at the root key, and returns the total
+    /// number of keys and values.
+    pub fn count_keys_and_values(&self) -> (u64, u64) {
+        let mut num_keys = 0;
+        let mut num_values = 0;
+
+        // Offsets of keys already visited. Subkey lists in malformed hives
+        // may contain cycles.
+        let mut visited = HashSet::new();
+        let mut pending = vec![self.root_key_offset];
+
+        while let Some(offset) = pending.pop() {
+            if visited.len() >= Self::MAX_KEYS {
+                break;
+            }
+            if !visited.insert(offset) {
+                continue;
+            }
+            if let Some(key) = self.key(offset) {
+                num_keys += 1;
+                num_values += key.number_of_values as u64;
+                pending.extend(self.subkeys(&key));
+            }
+        }
+
+        (num_keys, num_values)
+    }
+
+    /// Returns the data in the cell at the given offset.
+    ///
+    /// Each cell starts with its size as a 32-bits signed integer, which
+    /// includes the size field itself. The size is negative for allocated
+    /// cells, and positive for unallocated ones. This function returns
+    /// `None` for unallocated cells.
+    fn cell(&self, offset: u32) -> Option<&'a [u8]> {
+        let start = offset as usize;
+        let size = self.bins.get(start..start.checked_add(4)?)?;
+        let size = i32::from_le_bytes(size.try_into().unwrap());
+
+        if size >= 0 {
+            return None;
+        }
+
+        self.bins
+            .get(start + 4..start.checked_add(size.unsigned_abs() as usize)?)
+    }
+
+    /// Parses the key node in the cell at the given offset.
+    fn key(&self, offset: u32) -> Option<Key> {
+        let cell = self.cell(offset)?;
+
+        let (
+            _,
+            (
+                _signature,
+                flags,
+                last_written,
+                _access_bits,
+                _parent,
+                number_of_subkeys,
+                _number_of_volatile_subkeys,
+                subkeys_list_offset,
+                _volatile_subkeys_list_offset,
+                number_of_values,
+            ),
+        ) = (
+            tag::<_, _, NomError>(b"nk".as_slice()),
+            le_u16, // flags
+            le_u64, // last_written
+            le_u32, // access_bits
+            le_u32, // parent
+            le_u32, // number_of_subkeys
+            le_u32, // number_of_volatile_subkeys
+            le_u32, // subkeys_list_offset
+            le_u32, // volatile_subkeys_list_offset
+            le_u32, // number_of_values
+        )
+            .parse(cell)
+            .ok()?;
+
+        let name_len =
+            u16::from_le_bytes(cell.get(72..74)?.try_into().unwrap());
+        let name = cell.get(76..76 + name_len as usize)?;
+
+        let name = if flags & Self::KEY_COMP_NAME != 0 {
+            // Compressed names are actually Latin-1 strings.
+            name.iter().map(|c| *c as char).collect()
+        } else {
+            utf16_string(name)
+        };
+
+        Some(Key {
+            name,
+            last_written,
+            number_of_subkeys,
+            number_of_values,
+            subkeys_list_offset,
+        })
+    }
+
+    /// Returns the offsets of the subkeys of the given key.
+    fn subkeys(&self, key: &Key) -> Vec<u32> {
+        let mut offsets = Vec::new();
+        if key.number_of_subkeys > 0 {
+            self.subkeys_list(key.subkeys_list_offset, 0, &mut offsets);
+        }
+        offsets
+    }
+
+    /// Parses the subkeys list at the given offset, and appends the offsets
+    /// of the subkeys to `offsets`.
+    ///
+    /// There are four types of subkeys lists. Index leaves ("li") contain
+    /// the offsets of the subkeys. Fast leaves ("lf") and hash leaves ("lh")
+    /// contain the offsets of the subkeys, each followed by a hint or hash
+    /// of the key name. Finally, index roots ("ri") contain the offsets of
+    /// other subkeys lists.
+    fn subkeys_list(&self, offset: u32, depth: usize, offsets: &mut Vec<u32>) {
+        let Some(cell) = self.cell(offset) else {
+            return;
+        };
+
+        let Ok((entries, (signature, count))) =
+            (take::<_, _, NomError>(2_u8), le_u16).parse(cell)
+        else {
+            return;
+        };
+
+        let entry_size = match signature {
+            b"li" | b"ri" => 4,
+            b"lf" | b"lh" => 8,
+            _ => return,
+        };
+
+        let entries = entries
+            .chunks_exact(entry_size)
+            .take(count as usize)
+            .map(|entry| u32::from_le_bytes(entry[..4].try_into().unwrap()));
+
+        if signature == b"ri" {
+            if depth < Self::MAX_INDEX_DEPTH {
+                for list_offset in entries {
+                    self.subkeys_list(list_offset, depth + 1, offsets);
+                }
+            }
+        } else {
+            offsets.extend(entries);
+        }
+    }
+}
+
+impl From<Hive<'_>> for regf::Regf {
+    fn from(hive: Hive<'_>) -> Self {
+        let mut regf = regf::Regf::new();
+
+        regf.set_is_regf(true);
+        regf.set_major_version(hive.major_version);
+        regf.set_minor_version(hive.minor_version);
+        regf.last_written = filetime_to_unix_timestamp(hive.last_written);
+        regf.set_file_name(hive.file_name.clone());
+
+        if let Some(root_key) = hive.root_key() {
+            let mut key = regf::Key::new();
+            key.last_written = root_key.last_written();
+            key.set_name(root_key.name);
+            key.set_number_of_subkeys(root_key.number_of_subkeys);
+            key.set_number_of_values(root_key.number_of_values);
+            regf.root_key = Some(key).into();
+        }
+
+        let (num_keys, num_values) = hive.count_keys_and_values();
+
+        regf.set_number_of_keys(num_keys);
+        regf.set_number_of_values(num_values);
+
+        regf
+    }
+}
+
+/// Decodes a UTF-16 LE string, which ends at the first null character or
+/// at the end of the input.
+fn utf16_string(input: &[u8]) -> String {
+    let chars: Vec<u16> = input
+        .chunks_exact(2)
+        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+        .take_while(|c| *c != 0)
+        .collect();
+
+    String::from_utf16_lossy(chars.as_slice())
+}
+
+/// Converts from Window's FILETIME to UNIX timestamp.
+///
+/// Returns None if the given FILETIME is zero or outside the range
+/// representable by a UNIX timestamp.
+#[inline]
+fn filetime_to_unix_timestamp(filetime: u64) -> Option<u64> {
+    (filetime / 10000000).checked_sub(11644473600)
+}
@@ -0,0 +1,52 @@
+use crate::modules::tests::create_binary_from_zipped_ihex;
+use crate::tests::rule_false;
+use crate::tests::rule_true;
+use crate::tests::test_rule;
+
+#[test]
+fn key_exists() {
+    let hive = create_binary_from_zipped_ihex(
+        "src/modules/regf/tests/testdata/regf-software.in.zip",
+    );
+
+    rule_true!(
+        r#"
+        import "regf"
+        rule test {
+          condition:
+            regf.key_exists("") and
+            regf.key_exists("Software") and
+            regf.key_exists("Software\\Microsoft\\Windows\\CurrentVersion\\Run") and
+            regf.key_exists("\\software\\microsoft\\windows\\currentversion\\run\\") and
+            regf.key_exists("Software\\Policies") and
+            regf.key_exists("Software\\Ünicode Ключ") and
+            not regf.key_exists("Software\\Microsoft\\Windows\\CurrentVersion\\RunServices") and
+            not regf.key_exists("Run")
+        }
+        "#,
+        &hive
+    );
+
+    rule_true!(
+        r#"
+        import "regf"
+        rule test {
+          condition:
+            regf.key_last_written("Software\\Microsoft\\Windows\\CurrentVersion\\Run") == 1700000000 and
+            not defined regf.key_last_written("Foo")
+        }
+        "#,
+        &hive
+    );
+
+    rule_false!(
+        r#"
+        import "regf"
+        rule test {
+          condition:
+            regf.key_exists("Software")
+        }
+        "#,
+        b"foo"
+    );
+}
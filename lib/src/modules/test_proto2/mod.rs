@@ -1,5 +1,7 @@
+use std::cell::Cell;
 use std::rc::Rc;
 
+use crate::modules::emit_notice;
 use crate::modules::prelude::*;
 use crate::modules::protos::test_proto2::NestedProto2;
 use crate::modules::protos::test_proto2::TestProto2;
@@ -61,6 +63,58 @@ pub(crate) fn nested_method_with_arg(
     arg.eq(field.as_bstr())
 }
 
+thread_local! {
+    /// Number of times that the value of `nested_computed` was actually
+    /// computed. Used in tests for making sure that computed fields are
+    /// memoized.
+    pub(crate) static NESTED_COMPUTED_CALLS: Cell<usize> =
+        const { Cell::new(0) };
+}
+
+/// Computes the value of the `nested_computed` field, which is the sum of
+/// the items in `nested_array_int64`.
+#[module_export(
+    name = "nested_computed",
+    method_of = "test_proto2.NestedProto2"
+)]
+pub(crate) fn nested_computed(
+    ctx: &mut ScanContext,
+    structure: Rc<Struct>,
+) -> i64 {
+    ctx.computed_field(&structure, "nested_computed", |_| {
+        NESTED_COMPUTED_CALLS.set(NESTED_COMPUTED_CALLS.get() + 1);
+        structure
+            .field_by_name("nested_array_int64")
+            .unwrap()
+            .type_value
+            .as_array()
+            .as_integer_array()
+            .iter()
+            .sum()
+    })
+}
+
+/// Returns the number of occurrences of the `upx_magic` pattern, which is
+/// contributed by this module to the search phase.
+#[module_export]
+fn upx_magic_count(ctx: &mut ScanContext) -> i64 {
+    ctx.module_pattern_matches("test_proto2", "upx_magic").count() as i64
+}
+
+/// Returns the offset of the n-th occurrence of the `upx_magic` pattern.
+#[module_export]
+fn upx_magic_offset(ctx: &mut ScanContext, n: i64) -> Option<i64> {
+    let n = usize::try_from(n).ok()?;
+    let m = ctx.module_pattern_matches("test_proto2", "upx_magic").nth(n)?;
+    Some(m.start as i64)
+}
+
+/// Returns the number of occurrences of the `foo_wide_nocase` pattern.
+#[module_export]
+fn foo_wide_nocase_count(ctx: &mut ScanContext) -> i64 {
+    ctx.module_pattern_matches("test_proto2", "foo_wide_nocase").count() as i64
+}
+
 #[module_export]
 pub(crate) fn undef_i64(_ctx: &mut ScanContext) -> Option<i64> {
     None
@@ -127,6 +181,10 @@ fn main(data: &[u8], meta: Option<&[u8]>) -> Result<TestProto2, ModuleError> {
     test.set_bool_proto(true);
     test.set_file_size(data.len() as u64);
 
+    if data.is_empty() {
+        emit_notice("empty_data", "the scanned data is empty");
+    }
+
     test.array_int64.push(1);
     test.array_int64.push(10);
     test.array_int64.push(100);
@@ -180,5 +238,11 @@ fn main(data: &[u8], meta: Option<&[u8]>) -> Result<TestProto2, ModuleError> {
 
     test.metadata = meta.map(Vec::from);
 
+    // Makes the main function slow, for testing `Scanner::module_timeout`.
+    #[cfg(test)]
+    if meta == Some(b"sleep") {
+        std::thread::sleep(std::time::Duration::from_secs(1));
+    }
+
     Ok(test)
 }
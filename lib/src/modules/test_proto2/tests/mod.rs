@@ -251,6 +251,103 @@ fn test_proto2_module() {
            )"#
     );
 
+    // Nested loops over arrays of structures. The structure bound to the
+    // variable of the outer loop must not be affected by the inner loop.
+    condition_true!(
+        r#"for 2 a in test_proto2.array_struct : (
+             for 1 b in test_proto2.array_struct : (
+               a.nested_bool == b.nested_bool
+             )
+           )"#
+    );
+
+    condition_true!(
+        r#"for any a in test_proto2.array_struct : (
+             for any b in test_proto2.array_struct : (not b.nested_bool)
+             and a.nested_bool
+             and a.nested_method()
+           )"#
+    );
+
+    condition_true!(
+        r#"for all a in test_proto2.array_struct : (
+             for all b in test_proto2.array_struct : (
+               for any x in b.nested_array_int64 : (x == 100)
+             )
+             and a.nested_int64_one == 1
+             and a.nested_computed() == 111
+           )"#
+    );
+
+    condition_false!(
+        r#"for any a in test_proto2.array_struct : (
+             for all b in test_proto2.array_struct : (b.nested_bool)
+             or (not a.nested_bool and a.nested_bool)
+           )"#
+    );
+
+    // Loops that access multiple fields of the same structure, which are
+    // fetched with a single lookup.
+    condition_true!(
+        r#"for all s in test_proto2.array_struct : (
+             s.nested_int64_one == 1 and
+             s.nested_int32_zero == 0 and
+             s.nested_string == "foo" and
+             not defined s.nested_undef
+           )"#
+    );
+
+    condition_true!(
+        r#"for 1 s in test_proto2.array_struct : (
+             s.nested_bool and s.nested_string == "foo"
+           )"#
+    );
+
+    condition_true!(
+        r#"for none s in test_proto2.array_struct : (
+             s.nested_undef == 0 or s.nested_undef != 0
+           )"#
+    );
+
+    condition_true!(
+        r#"for all s in test_proto2.array_struct : (
+             s.nested_undef == 0 or s.nested_int64_one == 1
+           )"#
+    );
+
+    condition_true!(
+        r#"for any key, value in test_proto2.map_int64_float : (
+             key == 100 and value == 1000.0
+           )"#
+    );
+
+    condition_true!(
+        r#"for all key, value in test_proto2.map_string_float : (
+             key == "one" and value == 1.0
+           )"#
+    );
+
+    condition_true!(
+        r#"for none key, value in test_proto2.map_string_int64 : (
+             value == 2
+           )"#
+    );
+
+    condition_true!(
+        r#"for 100% key, value in test_proto2.map_string_int64 : (
+             value == 1
+           )"#
+    );
+
+    condition_true!(
+        r#"for any key, value in test_proto2.map_string_struct : (
+             for any k, v in test_proto2.map_int64_int64 : (
+               key == "foo" and k == 100 and v == 1000
+               and for any x in value.nested_array_int64 : (x == v \ 10)
+             )
+           )"#
+    );
+
     condition_true!(r#"test_proto2.get_foo() == "foo""#);
     condition_true!(r#"test_proto2.to_int("123") == 123"#);
 
@@ -292,6 +389,36 @@ fn test_proto2_module() {
         "#
     );
 
+    condition_true!(
+        r#"
+        test_proto2.map_string_struct["foo"].nested_method_with_arg("foo") and
+        not test_proto2.map_int64_struct[100].nested_method()
+        "#
+    );
+
+    // The value of `nested_computed` is computed by a method with the same
+    // name, which is declared with:
+    //
+    //   [(yara.field_options).computed = true];
+    //
+    condition_true!(
+        r#"
+        test_proto2.nested.nested_computed == 111 and
+        test_proto2.array_struct[1].nested_computed == 111 and
+        test_proto2.map_string_struct["foo"].nested_computed == 111 and
+        for all s in test_proto2.array_struct : (s.nested_computed == 111)
+        "#
+    );
+
+    // Fields with default values that are not set by the module.
+    condition_true!(
+        r#"
+        test_proto2.int64_with_default == 42 and
+        test_proto2.string_with_default == "default" and
+        test_proto2.bool_with_default
+        "#
+    );
+
     condition_true!(
         r#"
         test_proto2.NestedProto2.NestedEnumeration.ITEM_1 == 1
@@ -355,3 +482,62 @@ fn test_acl() {
   |                             ^^^^^^^^^^^^^^^^^^^^ this field was used with baz"#
     );
 }
+
+#[test]
+fn test_computed_fields_are_memoized() {
+    use crate::modules::test_proto2::NESTED_COMPUTED_CALLS;
+
+    let rules = crate::compile(
+        r#"
+        import "test_proto2"
+        rule test_1 { condition: test_proto2.nested.nested_computed == 111 }
+        rule test_2 { condition: test_proto2.nested.nested_computed > 100 }
+        rule test_3 { condition: test_proto2.nested.nested_computed != 0 }
+        "#,
+    )
+    .unwrap();
+
+    let mut scanner = crate::Scanner::new(&rules);
+
+    NESTED_COMPUTED_CALLS.set(0);
+
+    let scan_results = scanner.scan(&[]).unwrap();
+    assert_eq!(scan_results.matching_rules().len(), 3);
+    assert_eq!(NESTED_COMPUTED_CALLS.get(), 1);
+
+    // The value is computed again in the next scan.
+    scanner.scan(&[]).unwrap();
+    assert_eq!(NESTED_COMPUTED_CALLS.get(), 2);
+}
+
+#[test]
+fn test_module_patterns() {
+    condition_true!(r#"test_proto2.upx_magic_count() == 0"#, b"foo");
+    condition_true!(r#"test_proto2.upx_magic_count() == 2"#, b"UPX!..UPX!");
+    condition_true!(r#"test_proto2.upx_magic_offset(1) == 6"#, b"UPX!..UPX!");
+    condition_false!(r#"defined test_proto2.upx_magic_offset(2)"#, b"UPX!");
+    condition_true!(
+        r#"test_proto2.foo_wide_nocase_count() == 2"#,
+        b"f\x00o\x00o\x00..F\x00O\x00o\x00..foo"
+    );
+
+    // A rule declaring the same pattern shares it with the module, and
+    // both see the same matches.
+    let rules = crate::compile(
+        r#"
+        import "test_proto2"
+        rule test {
+          strings:
+            $a = "UPX!"
+          condition:
+            #a == 1 and test_proto2.upx_magic_offset(0) == @a[1]
+        }
+        "#,
+    )
+    .unwrap();
+
+    let mut scanner = crate::Scanner::new(&rules);
+    let scan_results = scanner.scan(b"...UPX!").unwrap();
+
+    assert_eq!(scan_results.matching_rules().len(), 1);
+}
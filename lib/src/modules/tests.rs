@@ -391,7 +391,13 @@ fn test_reflect() {
 
     let field = fields.next().unwrap();
     assert_eq!(field.name(), "nested");
-    assert!(matches!(field.ty(), Type::Struct(_)));
+
+    // The type of a computed field is the type returned by the method that
+    // computes it.
+    let Type::Struct(nested) = field.ty() else { panic!() };
+    let computed =
+        nested.fields().find(|f| f.name() == "nested_computed").unwrap();
+    assert_eq!(computed.ty(), Type::Integer);
 
     let field = fields.next().unwrap();
     assert_eq!(field.name(), "array_int64");
@@ -465,6 +471,18 @@ fn test_reflect() {
     assert_eq!(field.name(), "file_size");
     assert_eq!(field.ty(), Type::Integer);
 
+    let field = fields.next().unwrap();
+    assert_eq!(field.name(), "int64_with_default");
+    assert_eq!(field.ty(), Type::Integer);
+
+    let field = fields.next().unwrap();
+    assert_eq!(field.name(), "string_with_default");
+    assert_eq!(field.ty(), Type::String);
+
+    let field = fields.next().unwrap();
+    assert_eq!(field.name(), "bool_with_default");
+    assert_eq!(field.ty(), Type::Bool);
+
     let field = fields.next().unwrap();
     assert_eq!(field.name(), "requires_foo_and_bar");
     assert_eq!(field.ty(), Type::Integer);
@@ -505,6 +523,10 @@ fn test_reflect() {
     assert_eq!(field.name(), "add");
     assert!(matches!(field.ty(), Type::Func(_)));
 
+    let field = fields.next().unwrap();
+    assert_eq!(field.name(), "foo_wide_nocase_count");
+    assert!(matches!(field.ty(), Type::Func(_)));
+
     let field = fields.next().unwrap();
     assert_eq!(field.name(), "get_foo");
     assert!(matches!(field.ty(), Type::Func(_)));
@@ -525,5 +547,39 @@ fn test_reflect() {
     assert_eq!(field.name(), "uppercase");
     assert!(matches!(field.ty(), Type::Func(_)));
 
+    let field = fields.next().unwrap();
+    assert_eq!(field.name(), "upx_magic_count");
+    assert!(matches!(field.ty(), Type::Func(_)));
+
+    let field = fields.next().unwrap();
+    assert_eq!(field.name(), "upx_magic_offset");
+    assert!(matches!(field.ty(), Type::Func(_)));
+
     assert!(fields.next().is_none());
 }
+
+#[cfg(feature = "test_proto2-module")]
+#[test]
+fn test_functions() {
+    let functions: Vec<_> = mods::functions().collect();
+
+    let uint8 = functions.iter().find(|f| f.name == "uint8").unwrap();
+    assert_eq!(uint8.module, None);
+    assert_eq!(uint8.func.signatures.len(), 1);
+    assert_eq!(uint8.func.signatures[0].args, vec![Type::Integer]);
+    assert_eq!(uint8.func.signatures[0].ret, Type::Integer);
+
+    let add = functions.iter().find(|f| f.name == "test_proto2.add").unwrap();
+    assert_eq!(add.module, Some("test_proto2"));
+    assert_eq!(add.func.signatures.len(), 2);
+
+    assert!(functions
+        .iter()
+        .any(|f| f.name == "test_proto2.nested.nested_func"));
+
+    // Methods are not listed.
+    assert!(!functions.iter().any(|f| f.name.contains("nested_method")));
+
+    // Functions are sorted by name.
+    assert!(functions.windows(2).all(|w| w[0].name < w[1].name));
+}
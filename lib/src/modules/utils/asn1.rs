@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::fmt::Write;
 
 use const_oid::db::{rfc4519, rfc5912};
 use const_oid::ObjectIdentifier;
@@ -21,6 +22,7 @@ use digest::Digest;
 use sha1::Sha1;
 
 use x509_parser::certificate::X509Certificate;
+use x509_parser::extensions::GeneralName;
 use x509_parser::prelude::{AlgorithmIdentifier, X509CertificateParser};
 use x509_parser::x509::X509Name;
 
@@ -50,6 +52,18 @@ pub mod oid {
     pub const MS_COUNTERSIGN: ObjectIdentifier =
         ObjectIdentifier::new_unwrap("1.3.6.1.4.1.311.3.3.1");
 
+    pub const MS_SPC_INDIVIDUAL_SP_KEY_PURPOSE: ObjectIdentifier =
+        ObjectIdentifier::new_unwrap("1.3.6.1.4.1.311.2.1.21");
+
+    pub const MS_SPC_COMMERCIAL_SP_KEY_PURPOSE: ObjectIdentifier =
+        ObjectIdentifier::new_unwrap("1.3.6.1.4.1.311.2.1.22");
+
+    pub const MS_LIFETIME_SIGNING: ObjectIdentifier =
+        ObjectIdentifier::new_unwrap("1.3.6.1.4.1.311.10.3.13");
+
+    pub const MS_KERNEL_MODE_CODE_SIGNING: ObjectIdentifier =
+        ObjectIdentifier::new_unwrap("1.3.6.1.4.1.311.61.1.1");
+
     /// Similar to 1.2.840.113549.1.1.5. Obsolete, but still present in some files
     /// like: 111aeddc6a6dbf64b28cb565aa12af9ee3cc0a56ce31e4da0068cf6b474c3288
     pub const SHA1_WITH_RSA_ENCRYPTION_OBSOLETE: ObjectIdentifier =
@@ -116,6 +130,175 @@ pub fn oid_to_str(oid: &Oid) -> Cow<'static, str> {
     }
 }
 
+/// Like [`oid_to_str`], but for OIDs that identify algorithms.
+///
+/// OIDs that don't correspond to any known algorithm are returned as
+/// `unknown:<oid>`, where `<oid>` is the OID in dotted form.
+pub fn algorithm_to_str(oid: &Oid) -> Cow<'static, str> {
+    match oid_to_str(oid) {
+        Cow::Owned(oid) => Cow::Owned(format!("unknown:{oid}")),
+        name => name,
+    }
+}
+
+/// Flags that represent the purposes in the extended key usage extension of
+/// a certificate, as returned by [`extended_key_usage`]. The values are the
+/// same ones used by the `ExtendedKeyUsage` enums in the protos.
+pub mod eku {
+    pub const ANY: u32 = 0x0001;
+    pub const SERVER_AUTH: u32 = 0x0002;
+    pub const CLIENT_AUTH: u32 = 0x0004;
+    pub const CODE_SIGNING: u32 = 0x0008;
+    pub const EMAIL_PROTECTION: u32 = 0x0010;
+    pub const TIME_STAMPING: u32 = 0x0020;
+    pub const OCSP_SIGNING: u32 = 0x0040;
+    pub const SPC_INDIVIDUAL_SP_KEY_PURPOSE: u32 = 0x0080;
+    pub const SPC_COMMERCIAL_SP_KEY_PURPOSE: u32 = 0x0100;
+    pub const LIFETIME_SIGNING: u32 = 0x0200;
+    pub const KERNEL_MODE_CODE_SIGNING: u32 = 0x0400;
+}
+
+/// Returns the purposes in the extended key usage extension of a
+/// certificate, as a bitmask of [`eku`] flags, together with the OIDs of
+/// all the purposes, including the ones not covered by the flags.
+///
+/// Returns `None` if the certificate doesn't have the extension.
+pub fn extended_key_usage(
+    cert: &X509Certificate,
+) -> Option<(u32, Vec<String>)> {
+    let eku = cert.extended_key_usage().ok()??.value;
+    let mut flags = 0;
+    let mut oids = Vec::new();
+
+    let known = [
+        (eku.any, eku::ANY, "2.5.29.37.0"),
+        (eku.server_auth, eku::SERVER_AUTH, "1.3.6.1.5.5.7.3.1"),
+        (eku.client_auth, eku::CLIENT_AUTH, "1.3.6.1.5.5.7.3.2"),
+        (eku.code_signing, eku::CODE_SIGNING, "1.3.6.1.5.5.7.3.3"),
+        (eku.email_protection, eku::EMAIL_PROTECTION, "1.3.6.1.5.5.7.3.4"),
+        (eku.time_stamping, eku::TIME_STAMPING, "1.3.6.1.5.5.7.3.8"),
+        (eku.ocsp_signing, eku::OCSP_SIGNING, "1.3.6.1.5.5.7.3.9"),
+    ];
+
+    for (present, flag, oid) in known {
+        if present {
+            flags |= flag;
+            oids.push(oid.to_string());
+        }
+    }
+
+    for other in &eku.other {
+        flags |= match oid_to_object_identifier(other) {
+            Ok(oid::MS_SPC_INDIVIDUAL_SP_KEY_PURPOSE) => {
+                eku::SPC_INDIVIDUAL_SP_KEY_PURPOSE
+            }
+            Ok(oid::MS_SPC_COMMERCIAL_SP_KEY_PURPOSE) => {
+                eku::SPC_COMMERCIAL_SP_KEY_PURPOSE
+            }
+            Ok(oid::MS_LIFETIME_SIGNING) => eku::LIFETIME_SIGNING,
+            Ok(oid::MS_KERNEL_MODE_CODE_SIGNING) => {
+                eku::KERNEL_MODE_CODE_SIGNING
+            }
+            _ => 0,
+        };
+        oids.push(other.to_id_string());
+    }
+
+    Some((flags, oids))
+}
+
+/// Produces a printable string for a name in the subject alternative name
+/// extension, using the same prefixes as OpenSSL (e.g: `DNS:example.com`).
+///
+/// Returns `None` for the kinds of names that don't have a meaningful
+/// string representation.
+pub fn format_general_name(name: &GeneralName) -> Option<String> {
+    match name {
+        GeneralName::RFC822Name(email) => Some(format!("email:{email}")),
+        GeneralName::DNSName(dns) => Some(format!("DNS:{dns}")),
+        GeneralName::URI(uri) => Some(format!("URI:{uri}")),
+        GeneralName::DirectoryName(dir) => {
+            Some(format!("DirName:{}", format_name(dir)))
+        }
+        GeneralName::IPAddress(ip) => match ip.len() {
+            4 => {
+                let ip: [u8; 4] = (*ip).try_into().ok()?;
+                Some(format!("IP Address:{}", std::net::Ipv4Addr::from(ip)))
+            }
+            16 => {
+                let ip: [u8; 16] = (*ip).try_into().ok()?;
+                Some(format!("IP Address:{}", std::net::Ipv6Addr::from(ip)))
+            }
+            _ => None,
+        },
+        GeneralName::RegisteredID(oid) => {
+            Some(format!("Registered ID:{}", oid.to_id_string()))
+        }
+        _ => None,
+    }
+}
+
+/// Produces a printable string for a x509 name.
+///
+/// The [`X509Name`] type implements the [`std::fmt::Display`] trait, but the
+/// resulting string follows the [RFC 4514], resulting in something like:
+///
+/// ```text
+/// CN=Thawte Timestamping CA,OU=Thawte Certification,O=Thawte,L=Durbanville,ST=Western Cape,C=ZA
+/// ```
+///
+/// However, the format traditionally used by YARA is inherited from OpenSSL
+/// and looks like:
+///
+/// ```text
+/// /C=ZA/ST=Western Cape/L=Durbanville/O=Thawte/OU=Thawte Certification/CN=Thawte Timestamping CA
+/// ```
+///
+/// [RFC 4514]: https://datatracker.ietf.org/doc/html/rfc4514
+pub fn format_name(name: &X509Name) -> String {
+    let mut n = String::new();
+    for rdn in name.iter_rdn() {
+        write!(n, "/").unwrap();
+        for atv in rdn.iter() {
+            let key = oid_to_str(atv.attr_type());
+            let attr_val = atv.attr_value();
+            // Not using `atv.as_str()` because it doesn't take into account
+            // the `Tag::TeletexString` case.
+            let val = match attr_val.tag() {
+                Tag::PrintableString => {
+                    attr_val.as_printablestring().ok().map(|s| s.string())
+                }
+                Tag::Utf8String => {
+                    attr_val.as_utf8string().ok().map(|s| s.string())
+                }
+                Tag::Ia5String => {
+                    attr_val.as_ia5string().ok().map(|s| s.string())
+                }
+                Tag::TeletexString => {
+                    attr_val.as_teletexstring().ok().map(|s| s.string())
+                }
+                _ => None,
+            };
+            match (key, val) {
+                (key, Some(val)) => {
+                    write!(n, "{key}=").unwrap();
+                    for char in val.chars() {
+                        n.write_char(char).unwrap();
+                    }
+                }
+                (key, None) => {
+                    write!(n, "{key}=#").unwrap();
+                    for c in attr_val.data {
+                        write!(n, "{c:02x}").unwrap();
+                    }
+                }
+            }
+        }
+    }
+
+    n
+}
+
 pub struct ContentInfo<'a> {
     pub content_type: ObjectIdentifier,
     pub content: Any<'a>,
@@ -0,0 +1,44 @@
+/// Limits applied by module parsers to the number of items extracted from
+/// a file.
+///
+/// File headers can declare an arbitrary number of sections, imports,
+/// symbols, etc. Malformed or hostile files use this for making parsers
+/// spend large amounts of time and memory. Parsers stop extracting items of
+/// a kind when the corresponding limit is reached, and the rest of them are
+/// ignored.
+///
+/// Each module starts from [`ParseLimits::DEFAULT`], and can override the
+/// limits that don't suit its file format.
+#[derive(Debug, Clone, Copy)]
+pub struct ParseLimits {
+    /// Maximum number of sections or segments.
+    pub max_sections: usize,
+    /// Maximum number of imported functions.
+    pub max_imports: usize,
+    /// Maximum number of exported functions.
+    pub max_exports: usize,
+    /// Maximum number of resources.
+    pub max_resources: usize,
+    /// Maximum number of entries in a symbol table.
+    pub max_symbols: usize,
+    /// Maximum length of imported and exported function names.
+    pub max_name_length: usize,
+}
+
+impl ParseLimits {
+    /// Limits used by default.
+    pub const DEFAULT: Self = Self {
+        max_sections: 65_536,
+        max_imports: 16_384,
+        max_exports: 65_536,
+        max_resources: 65_536,
+        max_symbols: 1_000_000,
+        max_name_length: 1024,
+    };
+}
+
+impl Default for ParseLimits {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
@@ -1,3 +1,8 @@
+#[cfg(feature = "crypto")]
 pub mod asn1;
+#[cfg(feature = "crypto")]
 pub mod crypto;
 pub mod leb128;
+pub mod limits;
+#[cfg(any(feature = "math-module", feature = "pe-module"))]
+pub mod stats;
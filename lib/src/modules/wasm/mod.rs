@@ -0,0 +1,27 @@
+/*! YARA module that parses WebAssembly binaries.
+
+WebAssembly modules are used in malicious web pages for tasks like
+cryptocurrency mining or obfuscating exploits. This module exposes the
+sections, imports, exports and custom sections in WebAssembly binaries,
+which allows creating rules targeting them.
+ */
+
+use crate::modules::prelude::*;
+use crate::modules::protos::wasm::*;
+
+mod parser;
+
+#[cfg(test)]
+mod tests;
+
+#[module_main]
+fn main(data: &[u8], _meta: Option<&[u8]>) -> Result<Wasm, ModuleError> {
+    match parser::WasmParser::new().parse(data) {
+        Some(wasm) => Ok(wasm),
+        None => {
+            let mut wasm = Wasm::new();
+            wasm.set_is_wasm(false);
+            Ok(wasm)
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use nom::bytes::complete::{tag, take};
+use nom::combinator::{cond, verify};
+use nom::number::complete::{le_u32, u8};
+use nom::{IResult, Parser};
+use protobuf::EnumOrUnknown;
+
+use crate::modules::protos::wasm;
+use crate::modules::utils::leb128::{sleb128, uleb128};
+use crate::modules::utils::limits::ParseLimits;
+
+/// A WebAssembly binary parser.
+///
+/// WebAssembly binaries start with the magic `\0asm` followed by a version
+/// number, and then a sequence of sections. Each section starts with its
+/// identifier and its size, both encoded as LEB128, which allows walking
+/// the sections without understanding their contents. This parser only
+/// looks into the sections that contain the imports, the exports, and the
+/// number of functions.
+///
+/// See: https://webassembly.github.io/spec/core/binary/modules.html
+pub struct WasmParser {
+    result: wasm::Wasm,
+}
+
+impl WasmParser {
+    const LIMITS: ParseLimits = ParseLimits::DEFAULT;
+
+    /// The only version of the binary format for core WebAssembly modules.
+    /// Other values are used by components, which have a different
+    /// structure.
+    const CORE_VERSION: u32 = 1;
+
+    const SECTION_CUSTOM: u8 = 0;
+    const SECTION_IMPORT: u8 = 2;
+    const SECTION_FUNCTION: u8 = 3;
+    const SECTION_EXPORT: u8 = 7;
+
+    pub fn new() -> Self {
+        Self { result: wasm::Wasm::new() }
+    }
+
+    pub fn parse(mut self, data: &[u8]) -> Option<wasm::Wasm> {
+        let (mut remainder, (_magic, version)) =
+            (tag::<_, _, nom::error::Error<_>>(b"\0asm".as_slice()), le_u32)
+                .parse(data)
+                .ok()?;
+
+        self.result.set_is_wasm(true);
+        self.result.set_version(version);
+
+        if version != Self::CORE_VERSION {
+            return Some(self.result);
+        }
+
+        while !remainder.is_empty()
+            && self.result.sections.len() < Self::LIMITS.max_sections
+        {
+            let offset = data.len() - remainder.len();
+
+            let Ok((rest, (id, size))) = (u8, uleb128).parse(remainder) else {
+                break;
+            };
+
+            // Sections that exceed the end of the file are truncated.
+            let size = (size as usize).min(rest.len());
+            let (content, rest) = rest.split_at(size);
+
+            remainder = rest;
+
+            let mut section = wasm::Section::new();
+            section.type_ = Some(EnumOrUnknown::from_i32(id.into()));
+            section.set_offset(offset as u64);
+            section.set_size(size as u64);
+
+            match id {
+                Self::SECTION_CUSTOM => {
+                    if let Ok((_, name)) = name(content) {
+                        section.set_name(name.clone());
+                        self.result.custom_sections.push(name);
+                    }
+                }
+                Self::SECTION_IMPORT => self.parse_imports(content),
+                Self::SECTION_FUNCTION => {
+                    if let Ok((_, count)) = uleb128(content) {
+                        self.result.set_number_of_functions(count);
+                    }
+                }
+                Self::SECTION_EXPORT => self.parse_exports(content),
+                _ => {}
+            }
+
+            self.result.sections.push(section);
+        }
+
+        self.result.set_number_of_sections(self.result.sections.len() as u64);
+
+        Some(self.result)
+    }
+
+    /// Parses the import section. Parsing stops at the first import that
+    /// can't be parsed.
+    fn parse_imports(&mut self, content: &[u8]) {
+        let Ok((mut remainder, count)) = uleb128(content) else {
+            return;
+        };
+
+        for _ in 0..count {
+            if self.result.imports.len() >= Self::LIMITS.max_imports {
+                break;
+            }
+
+            let Ok((rest, (module, name, kind))) =
+                (name, name, import_desc).parse(remainder)
+            else {
+                break;
+            };
+
+            remainder = rest;
+
+            let mut import = wasm::Import::new();
+            import.set_module(module);
+            import.set_name(name);
+            import.kind = Some(EnumOrUnknown::from_i32(kind.into()));
+
+            self.result.imports.push(import);
+        }
+    }
+
+    /// Parses the export section. Parsing stops at the first export that
+    /// can't be parsed.
+    fn parse_exports(&mut self, content: &[u8]) {
+        let Ok((mut remainder, count)) = uleb128(content) else {
+            return;
+        };
+
+        for _ in 0..count {
+            if self.result.exports.len() >= Self::LIMITS.max_exports {
+                break;
+            }
+
+            let Ok((rest, (name, kind, index))) =
+                (name, u8, uleb128).parse(remainder)
+            else {
+                break;
+            };
+
+            remainder = rest;
+
+            let mut export = wasm::Export::new();
+            export.set_name(name);
+            export.kind = Some(EnumOrUnknown::from_i32(kind.into()));
+            export.set_index(index as u32);
+
+            self.result.exports.push(export);
+        }
+    }
+}
+
+/// Parses a name, which is a UTF-8 string prefixed by its length.
+///
+/// Names longer than [`ParseLimits::max_name_length`] are truncated.
+fn name(input: &[u8]) -> IResult<&[u8], String> {
+    let (remainder, len) = uleb128(input)?;
+    let (remainder, name) = take(len).parse(remainder)?;
+
+    let name = &name[..name.len().min(WasmParser::LIMITS.max_name_length)];
+
+    Ok((remainder, String::from_utf8_lossy(name).into_owned()))
+}
+
+/// Parses the descriptor of an import, returning its kind.
+///
+/// The descriptor is not used, but it must be parsed for knowing where the
+/// next import starts.
+fn import_desc(input: &[u8]) -> IResult<&[u8], u8> {
+    let (remainder, kind) = u8(input)?;
+
+    let remainder = match kind {
+        // Function: index of the function type.
+        0x00 => uleb128(remainder)?.0,
+        // Table: reference type followed by limits.
+        0x01 => (val_type, limits).parse(remainder)?.0,
+        // Memory: limits.
+        0x02 => limits(remainder)?.0,
+        // Global: value type followed by mutability.
+        0x03 => (val_type, u8).parse(remainder)?.0,
+        // Tag: attribute followed by the index of the function type.
+        0x04 => (u8, uleb128).parse(remainder)?.0,
+        _ => {
+            return Err(nom::Err::Error(nom::error::Error::new(
+                input,
+                nom::error::ErrorKind::Switch,
+            )))
+        }
+    };
+
+    Ok((remainder, kind))
+}
+
+/// Parses a value type.
+///
+/// Most value types are encoded as a single byte, but the reference types
+/// introduced by the GC proposal (`ref` and `ref null`) are followed by a
+/// heap type, encoded as a signed LEB128.
+fn val_type(input: &[u8]) -> IResult<&[u8], ()> {
+    let (remainder, ty) = u8(input)?;
+    let (remainder, _heap_type) =
+        cond(ty == 0x63 || ty == 0x64, sleb128).parse(remainder)?;
+    Ok((remainder, ()))
+}
+
+/// Parses the limits of a table or memory.
+///
+/// The flags indicate whether there is a maximum (bit 0), and whether the
+/// memory uses a custom page size (bit 3), in which case the limits are
+/// followed by the logarithm of the page size.
+fn limits(input: &[u8]) -> IResult<&[u8], ()> {
+    let (remainder, flags) = verify(u8, |flags| *flags < 0x10).parse(input)?;
+    let (remainder, (_min, _max, _page_size)) = (
+        uleb128,
+        cond(flags & 0x01 != 0, uleb128),
+        cond(flags & 0x08 != 0, uleb128),
+    )
+        .parse(remainder)?;
+    Ok((remainder, ()))
+}
@@ -0,0 +1,59 @@
+use crate::modules::tests::create_binary_from_zipped_ihex;
+use crate::tests::rule_false;
+use crate::tests::rule_true;
+use crate::tests::test_rule;
+
+#[test]
+fn imports_and_exports() {
+    let wasm = create_binary_from_zipped_ihex(
+        "src/modules/wasm/tests/testdata/wasm-module.in.zip",
+    );
+
+    rule_true!(
+        r#"
+        import "wasm"
+        rule test {
+          condition:
+            wasm.is_wasm and
+            wasm.version == 1 and
+            wasm.number_of_sections == 7 and
+            wasm.number_of_functions == 2 and
+            wasm.sections[0].type == wasm.SECTION_TYPE and
+            wasm.custom_sections[0] == "producers" and
+            wasm.custom_sections[1] == "name"
+        }
+        "#,
+        &wasm
+    );
+
+    rule_true!(
+        r#"
+        import "wasm"
+        rule test {
+          condition:
+            for any imp in wasm.imports : (
+              imp.module == "wasi_snapshot_preview1" and
+              imp.name == "fd_write" and
+              imp.kind == wasm.EXTERNAL_FUNCTION
+            ) and
+            wasm.imports[4].name == "stack_pointer" and
+            wasm.imports[4].kind == wasm.EXTERNAL_GLOBAL and
+            for any export in wasm.exports : (
+              export.name == "mine_block" and export.index == 3
+            )
+        }
+        "#,
+        &wasm
+    );
+
+    rule_false!(
+        r#"
+        import "wasm"
+        rule test {
+          condition:
+            wasm.is_wasm
+        }
+        "#,
+        b"\0asn\x01\0\0\0"
+    );
+}
@@ -0,0 +1,29 @@
+/*! YARA module that parses X.509 certificates.
+
+This module parses files containing X.509 certificates, like `.cer`, `.crt`,
+`.pem` and `.p7b` files. Certificates can be DER-encoded, enclosed in PEM
+blocks, or contained in a PKCS#7 bundle. The module exposes the same
+information about certificates as the `pe` module does for Authenticode
+signatures, which allows hunting for malicious code-signing certificates
+distributed as standalone files.
+ */
+
+use crate::modules::prelude::*;
+use crate::modules::protos::x509::*;
+
+mod parser;
+
+#[cfg(test)]
+mod tests;
+
+#[module_main]
+fn main(data: &[u8], _meta: Option<&[u8]>) -> Result<X509, ModuleError> {
+    match parser::parse(data) {
+        Some(x509) => Ok(x509),
+        None => {
+            let mut x509 = X509::new();
+            x509.set_is_x509(false);
+            Ok(x509)
+        }
+    }
+}
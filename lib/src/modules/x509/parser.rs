@@ -0,0 +1,238 @@
+use const_oid::db::rfc5911;
+use der_parser::asn1_rs::{OptTaggedParser, ParseResult, Tag};
+use der_parser::ber::{parse_ber_any, parse_ber_integer};
+use der_parser::error::BerResult;
+use der_parser::error::Error::BerValueError;
+use x509_parser::extensions::ParsedExtension;
+use x509_parser::pem::Pem;
+use x509_parser::public_key::PublicKey;
+
+use crate::modules::protos::x509;
+use crate::modules::utils::asn1::{
+    algorithm_to_str, extended_key_usage, format_general_name, format_name,
+    oid_to_str, Certificate, ContentInfo, SignedData,
+};
+
+/// Parses a file containing X.509 certificates.
+///
+/// The file can contain one or more DER-encoded certificates, a DER-encoded
+/// PKCS#7 bundle, or PEM blocks with certificates or PKCS#7 bundles. Returns
+/// `None` if the file doesn't contain any certificate.
+pub fn parse(data: &[u8]) -> Option<x509::X509> {
+    let (format, certificates) = if let Some(certs) = der_certificates(data) {
+        (x509::Format::FORMAT_DER, certs)
+    } else if let Some(certs) = pkcs7_certificates(data) {
+        (x509::Format::FORMAT_PKCS7, certs)
+    } else if let Some(certs) = pem_certificates(data) {
+        (x509::Format::FORMAT_PEM, certs)
+    } else {
+        return None;
+    };
+
+    let mut result = x509::X509::new();
+
+    result.set_is_x509(true);
+    result.set_format(format);
+    result.set_number_of_certificates(certificates.len() as u64);
+    result.certificates = certificates;
+
+    Some(result)
+}
+
+/// Parses one or more consecutive DER-encoded certificates.
+fn der_certificates(data: &[u8]) -> Option<Vec<x509::Certificate>> {
+    let (_, certs) = SignedData::parse_certificates(data);
+
+    if certs.is_empty() {
+        return None;
+    }
+
+    Some(certs.iter().map(x509::Certificate::from).collect())
+}
+
+/// Parses a PKCS#7 bundle, and returns the certificates in it.
+fn pkcs7_certificates(data: &[u8]) -> Option<Vec<x509::Certificate>> {
+    let content_info = ContentInfo::from_ber(data).ok()?;
+
+    if content_info.content_type != rfc5911::ID_SIGNED_DATA {
+        return None;
+    }
+
+    let (_, certs) =
+        signed_data_certificates(content_info.content.data).ok()?;
+
+    if certs.is_empty() {
+        return None;
+    }
+
+    Some(certs.iter().map(x509::Certificate::from).collect())
+}
+
+/// Parses the PEM blocks in a file, and returns the certificates in them.
+///
+/// Blocks containing a single certificate (`CERTIFICATE`) and blocks
+/// containing a PKCS#7 bundle (`PKCS7`) are taken into account, the rest
+/// of them are ignored.
+fn pem_certificates(data: &[u8]) -> Option<Vec<x509::Certificate>> {
+    // Skip everything before the first PEM block, which also avoids
+    // reading the whole file when it doesn't contain any block.
+    let start = memchr::memmem::find(data, b"-----BEGIN ")?;
+    let mut certs = Vec::new();
+
+    for pem in Pem::iter_from_buffer(&data[start..]) {
+        let Ok(pem) = pem else {
+            break;
+        };
+        let contents = pem.contents.as_slice();
+        let pem_certs = match pem.label.as_str() {
+            "CERTIFICATE" | "X509 CERTIFICATE" | "TRUSTED CERTIFICATE" => {
+                der_certificates(contents)
+            }
+            "PKCS7" => pkcs7_certificates(contents),
+            _ => None,
+        };
+        certs.extend(pem_certs.unwrap_or_default());
+    }
+
+    if certs.is_empty() {
+        return None;
+    }
+
+    Some(certs)
+}
+
+/// Returns the certificates in a [SignedData][1] structure.
+///
+/// This doesn't use [`SignedData`] because PKCS#7 bundles are "degenerate"
+/// SignedData structures, without encapsulated content and with no
+/// signers, and here we are interested only in the certificates.
+///
+/// [1]: https://datatracker.ietf.org/doc/html/rfc5652#section-5.1
+fn signed_data_certificates(
+    input: &[u8],
+) -> BerResult<'_, Vec<Certificate<'_>>> {
+    let (remainder, _version) = parse_ber_integer(input)?;
+    let (remainder, _digest_algorithms) = parse_ber_any(remainder)?;
+    let (remainder, _content_info) = parse_ber_any(remainder)?;
+
+    let (remainder, certificates) = OptTaggedParser::from(0)
+        .parse_ber(remainder, |_, raw_certs| -> ParseResult<'_, Vec<_>> {
+            Ok(SignedData::parse_certificates(raw_certs))
+        })
+        .map_err(|_| BerValueError)?;
+
+    Ok((remainder, certificates.unwrap_or_default()))
+}
+
+impl From<&Certificate<'_>> for x509::Certificate {
+    fn from(value: &Certificate) -> Self {
+        let tbs = &value.x509.tbs_certificate;
+        let mut cert = x509::Certificate::new();
+
+        // Versions are 0-based, add 1 for getting the actual version.
+        cert.set_version(tbs.version.0 as i64 + 1);
+        cert.set_issuer(format_name(&tbs.issuer));
+        cert.set_subject(format_name(&tbs.subject));
+        cert.set_serial(value.x509.raw_serial_as_string());
+        cert.set_self_signed(tbs.issuer == tbs.subject);
+
+        cert.set_algorithm_oid(
+            value.x509.signature_algorithm.algorithm.to_id_string(),
+        );
+
+        cert.set_algorithm(
+            algorithm_to_str(&value.x509.signature_algorithm.algorithm)
+                .into_owned(),
+        );
+
+        cert.set_thumbprint(value.thumbprint.clone());
+        cert.set_not_before(tbs.validity.not_before.timestamp());
+        cert.set_not_after(tbs.validity.not_after.timestamp());
+
+        let spki = &tbs.subject_pki;
+
+        cert.set_key_algorithm_oid(spki.algorithm.algorithm.to_id_string());
+        cert.set_key_algorithm(
+            oid_to_str(&spki.algorithm.algorithm).into_owned(),
+        );
+
+        let key_size = match spki.parsed() {
+            Ok(PublicKey::RSA(rsa)) => rsa_key_size(rsa.modulus),
+            Ok(key) => key.key_size() as u64,
+            Err(_) => 0,
+        };
+
+        if key_size > 0 {
+            cert.set_key_size(key_size);
+        }
+
+        // For elliptic curve keys the algorithm parameters contain the OID
+        // of the curve.
+        if let Some(curve) = spki
+            .algorithm
+            .parameters
+            .as_ref()
+            .filter(|params| params.tag() == Tag::Oid)
+            .and_then(|params| params.as_oid().ok())
+        {
+            cert.set_key_curve(oid_to_str(&curve).into_owned());
+        }
+
+        if let Ok(Some(basic_constraints)) = value.x509.basic_constraints() {
+            cert.set_is_ca(basic_constraints.value.ca);
+        }
+
+        if let Ok(Some(key_usage)) = value.x509.key_usage() {
+            cert.set_key_usage(key_usage.value.flags.into());
+        }
+
+        if let Some((flags, oids)) = extended_key_usage(&value.x509) {
+            cert.set_extended_key_usage(flags);
+            cert.extended_key_usage_oids = oids;
+        }
+
+        if let Ok(Some(san)) = value.x509.subject_alternative_name() {
+            cert.subject_alternative_names = san
+                .value
+                .general_names
+                .iter()
+                .filter_map(format_general_name)
+                .collect();
+        }
+
+        for ext in value.x509.extensions() {
+            match ext.parsed_extension() {
+                ParsedExtension::SubjectKeyIdentifier(key_id) => {
+                    cert.set_subject_key_identifier(hex::encode(key_id.0));
+                }
+                ParsedExtension::AuthorityKeyIdentifier(aki) => {
+                    if let Some(key_id) = &aki.key_identifier {
+                        cert.set_authority_key_identifier(hex::encode(
+                            key_id.0,
+                        ));
+                    }
+                }
+                _ => {}
+            }
+
+            let mut extension = x509::Extension::new();
+            extension.set_oid(ext.oid.to_id_string());
+            extension.set_name(oid_to_str(&ext.oid).into_owned());
+            extension.set_critical(ext.critical);
+            cert.extensions.push(extension);
+        }
+
+        cert
+    }
+}
+
+/// Returns the size in bits of an RSA key, given its modulus as a
+/// big-endian integer, possibly with leading zeroes.
+fn rsa_key_size(modulus: &[u8]) -> u64 {
+    match modulus.iter().position(|b| *b != 0) {
+        Some(i) => {
+            (modulus.len() - i) as u64 * 8 - modulus[i].leading_zeros() as u64
+        }
+        None => 0,
+    }
+}
@@ -0,0 +1,110 @@
+use crate::modules::tests::create_binary_from_zipped_ihex;
+use crate::tests::rule_false;
+use crate::tests::rule_true;
+use crate::tests::test_rule;
+
+#[test]
+fn der() {
+    let cert = create_binary_from_zipped_ihex(
+        "src/modules/x509/tests/testdata/x509-der.in.zip",
+    );
+
+    rule_true!(
+        r#"
+        import "x509"
+        rule test {
+          condition:
+            x509.is_x509 and
+            x509.format == x509.FORMAT_DER and
+            x509.number_of_certificates == 1 and
+            x509.certificates[0].subject == "/C=CN/O=Shady Software Ltd/CN=Shady Software Ltd" and
+            x509.certificates[0].key_algorithm == "id-ecPublicKey" and
+            x509.certificates[0].key_curve == "secp256r1" and
+            x509.certificates[0].key_size == 256 and
+            not x509.certificates[0].is_ca and
+            not x509.certificates[0].self_signed
+        }
+        "#,
+        &cert
+    );
+
+    rule_true!(
+        r#"
+        import "x509"
+        rule test {
+          condition:
+            x509.certificates[0].extended_key_usage & x509.EKU_CODE_SIGNING != 0 and
+            x509.certificates[0].extended_key_usage & x509.EKU_SPC_COMMERCIAL_SP_KEY_PURPOSE != 0 and
+            x509.certificates[0].key_usage == x509.KEY_USAGE_DIGITAL_SIGNATURE and
+            for any oid in x509.certificates[0].extended_key_usage_oids : (
+              oid == "1.3.6.1.4.1.99999.1"
+            ) and
+            for any ext in x509.certificates[0].extensions : (
+              ext.name == "id-ce-basicConstraints" and ext.critical
+            )
+        }
+        "#,
+        &cert
+    );
+}
+
+#[test]
+fn pem_and_pkcs7() {
+    let pem = create_binary_from_zipped_ihex(
+        "src/modules/x509/tests/testdata/x509-pem.in.zip",
+    );
+
+    let pkcs7 = create_binary_from_zipped_ihex(
+        "src/modules/x509/tests/testdata/x509-pkcs7.in.zip",
+    );
+
+    let rule = r#"
+        import "x509"
+        rule test {
+          condition:
+            x509.number_of_certificates == 2 and
+            for any cert in x509.certificates : (
+              cert.is_ca and
+              cert.self_signed and
+              cert.key_algorithm == "rsaEncryption" and
+              cert.key_size == 3072
+            )
+        }
+        "#;
+
+    rule_true!(rule, &pem);
+    rule_true!(rule, &pkcs7);
+
+    rule_true!(
+        r#"
+        import "x509"
+        rule test {
+          condition:
+            x509.format == x509.FORMAT_PEM
+        }
+        "#,
+        &pem
+    );
+
+    rule_true!(
+        r#"
+        import "x509"
+        rule test {
+          condition:
+            x509.format == x509.FORMAT_PKCS7
+        }
+        "#,
+        &pkcs7
+    );
+
+    rule_false!(
+        r#"
+        import "x509"
+        rule test {
+          condition:
+            x509.is_x509
+        }
+        "#,
+        b"-----BEGIN CERTIFICATE-----\nfoo\n-----END CERTIFICATE-----\n"
+    );
+}
@@ -1,3 +1,4 @@
+use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
 use std::mem;
 use std::ops::{RangeFrom, RangeInclusive};
@@ -71,6 +72,13 @@ impl PartialEq for Hir {
     }
 }
 
+impl Display for Hir {
+    /// Writes the regular expression in regex syntax.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
 impl From<regex_syntax::hir::Hir> for Hir {
     fn from(value: regex_syntax::hir::Hir) -> Self {
         Self { inner: value, greedy: None }
@@ -300,6 +308,76 @@ impl Hir {
         .into()
     }
 
+    /// Returns an expression that matches `text` ignoring case, according
+    /// to Unicode simple case folding rules. For instance, `"straße"` matches
+    /// `"STRAẞE"`, and `"k"` matches both `"K"` and the Kelvin sign (U+212A).
+    ///
+    /// The expression matches the UTF-8 encoding of the text if `utf8` is
+    /// true, and its UTF-16LE encoding if `utf16` is true.
+    pub fn case_folded_text(text: &str, utf8: bool, utf16: bool) -> Hir {
+        let variants: Vec<Vec<char>> = text
+            .chars()
+            .map(|c| {
+                let mut class =
+                    ClassUnicode::new([ClassUnicodeRange::new(c, c)]);
+                class.case_fold_simple();
+                class
+                    .iter()
+                    .flat_map(|range| range.start()..=range.end())
+                    .collect()
+            })
+            .collect();
+
+        let encode = |encode_char: &dyn Fn(char) -> Vec<u8>| {
+            regex_syntax::hir::Hir::concat(
+                variants
+                    .iter()
+                    .map(|chars| {
+                        regex_syntax::hir::Hir::alternation(
+                            chars
+                                .iter()
+                                .map(|c| {
+                                    regex_syntax::hir::Hir::literal(
+                                        encode_char(*c),
+                                    )
+                                })
+                                .collect(),
+                        )
+                    })
+                    .collect(),
+            )
+        };
+
+        let mut alternatives = Vec::new();
+
+        if utf8 {
+            alternatives.push(encode(&|c| c.to_string().into_bytes()));
+        }
+
+        if utf16 {
+            alternatives.push(encode(&|c| {
+                c.encode_utf16(&mut [0; 2])
+                    .iter()
+                    .flat_map(|u| u.to_le_bytes())
+                    .collect()
+            }));
+        }
+
+        regex_syntax::hir::Hir::alternation(alternatives).into()
+    }
+
+    /// Returns true if the Unicode simple case folding of `text` produces
+    /// characters that are not ASCII, which means that matching `text` with
+    /// the `nocase` modifier differs from matching it with
+    /// [`Hir::case_folded_text`].
+    pub fn has_non_ascii_case_folding(text: &str) -> bool {
+        text.chars().any(|c| {
+            let mut class = ClassUnicode::new([ClassUnicodeRange::new(c, c)]);
+            class.case_fold_simple();
+            class.iter().any(|range| !range.end().is_ascii())
+        })
+    }
+
     /// Returns an expression that is a repetition of any byte
     /// that repeats at least `min` times and at most `max` time, w
     pub fn any_byte_repetition(
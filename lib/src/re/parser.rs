@@ -154,6 +154,23 @@ impl Parser {
 
     /// Parses the regexp and returns its HIR.
     pub fn parse(&self, regexp: &impl Regexp) -> Result<Hir, Error> {
+        self.parse_impl(regexp, false).map(|(hir, _)| hir)
+    }
+
+    /// Like [`Parser::parse`], but also returns the constructs in the regexp
+    /// that can degrade scanning performance. See [`SlowConstruct`].
+    pub fn parse_with_slow_constructs(
+        &self,
+        regexp: &impl Regexp,
+    ) -> Result<(Hir, Vec<SlowConstruct>), Error> {
+        self.parse_impl(regexp, true)
+    }
+
+    fn parse_impl(
+        &self,
+        regexp: &impl Regexp,
+        find_slow_constructs: bool,
+    ) -> Result<(Hir, Vec<SlowConstruct>), Error> {
         let mut re_src = Cow::Borrowed(regexp.source());
         let mut span_delta = 0_isize;
 
@@ -281,6 +298,12 @@ impl Parser {
             .dot_matches_new_line(regexp.dot_matches_new_line())
             .validate(&ast)?;
 
+        let slow_constructs = if find_slow_constructs {
+            SlowConstructFinder::new(re_src.as_ref()).find(&ast)
+        } else {
+            Vec::new()
+        };
+
         let case_insensitive = if self.force_case_insensitive {
             true
         } else {
@@ -303,7 +326,7 @@ impl Parser {
                 }
             })?;
 
-        Ok(Hir { inner: hir, greedy })
+        Ok((Hir { inner: hir, greedy }, slow_constructs))
     }
 }
 
@@ -559,6 +582,149 @@ impl re::ast::Visitor for &mut Validator {
     }
 }
 
+/// A construct in a regular expression that can degrade scanning performance.
+#[derive(Debug)]
+pub(crate) enum SlowConstruct {
+    /// The regexp starts with an unbounded repetition of `.`, like in
+    /// `/.*foo/`. Patterns are searched at every offset of the scanned data
+    /// anyways, so the leading repetition only makes the scan slower.
+    LeadingWildcard { span: re::ast::Span, snippet: String },
+    /// An unbounded repetition of `.` somewhere else in the regexp, like in
+    /// `/foo.*bar/`. `bounded` is the same repetition with an upper bound,
+    /// like `.{0,N}`.
+    UnboundedWildcard { span: re::ast::Span, bounded: String },
+    /// An unbounded repetition nested inside another unbounded repetition,
+    /// like in `/(a+)+/`.
+    NestedQuantifier { span: re::ast::Span, snippet: String },
+}
+
+impl SlowConstruct {
+    /// Returns the span of the slow construct, relative to the start of the
+    /// regexp.
+    pub fn span(&self) -> &re::ast::Span {
+        match self {
+            SlowConstruct::LeadingWildcard { span, .. }
+            | SlowConstruct::UnboundedWildcard { span, .. }
+            | SlowConstruct::NestedQuantifier { span, .. } => span,
+        }
+    }
+}
+
+/// Finds constructs in a regexp that can degrade scanning performance.
+///
+/// The constructs detected are leading unbounded repetitions of `.` (e.g:
+/// `/.*foo/`), unbounded repetitions of `.` in any other place (e.g:
+/// `/foo.*bar/`) and unbounded repetitions nested inside other unbounded
+/// repetitions (e.g: `/(a+)+/`).
+struct SlowConstructFinder<'a> {
+    re_src: &'a str,
+    leading: Option<re::ast::Span>,
+    /// Stack with the repetitions that enclose the current AST node. Each
+    /// item contains the repetition's span, whether it is unbounded, and
+    /// whether it has been already reported as a nested quantifier.
+    reps: Vec<(re::ast::Span, bool, bool)>,
+    slow_constructs: Vec<SlowConstruct>,
+}
+
+impl<'a> SlowConstructFinder<'a> {
+    fn new(re_src: &'a str) -> Self {
+        Self {
+            re_src,
+            leading: None,
+            reps: Vec::new(),
+            slow_constructs: Vec::new(),
+        }
+    }
+
+    fn find(mut self, ast: &Ast) -> Vec<SlowConstruct> {
+        self.leading = match ast {
+            Ast::Concat(concat) => concat.asts.first().map(|ast| *ast.span()),
+            ast => Some(*ast.span()),
+        };
+        // The visitor never fails.
+        re::ast::visit(ast, &mut self).unwrap();
+        self.slow_constructs
+    }
+
+    fn snippet(&self, span: &re::ast::Span) -> String {
+        self.re_src[span.start.offset..span.end.offset].to_string()
+    }
+}
+
+impl re::ast::Visitor for &mut SlowConstructFinder<'_> {
+    type Output = ();
+    type Err = ();
+
+    fn finish(self) -> Result<Self::Output, Self::Err> {
+        Ok(())
+    }
+
+    fn visit_pre(&mut self, ast: &Ast) -> Result<(), Self::Err> {
+        let Ast::Repetition(rep) = ast else {
+            return Ok(());
+        };
+
+        let (min, unbounded) = match rep.op.kind {
+            RepetitionKind::ZeroOrMore => (0, true),
+            RepetitionKind::OneOrMore => (1, true),
+            RepetitionKind::Range(RepetitionRange::AtLeast(n)) => (n, true),
+            _ => (0, false),
+        };
+
+        if unbounded {
+            // Only the innermost unbounded repetition enclosing this one is
+            // reported, and only once.
+            if let Some(outer) =
+                self.reps.iter_mut().rev().find(|(_, unbounded, _)| *unbounded)
+            {
+                if !outer.2 {
+                    outer.2 = true;
+                    let span = outer.0;
+                    self.slow_constructs.push(
+                        SlowConstruct::NestedQuantifier {
+                            span,
+                            snippet: self.snippet(&span),
+                        },
+                    );
+                }
+            }
+
+            if matches!(rep.ast.as_ref(), Ast::Dot(_)) {
+                if self.leading == Some(rep.span) {
+                    self.slow_constructs.push(
+                        SlowConstruct::LeadingWildcard {
+                            span: rep.span,
+                            snippet: self.snippet(&rep.span),
+                        },
+                    );
+                } else {
+                    let bounded = format!(
+                        ".{{{min},N}}{}",
+                        if rep.greedy { "" } else { "?" }
+                    );
+                    self.slow_constructs.push(
+                        SlowConstruct::UnboundedWildcard {
+                            span: rep.span,
+                            bounded,
+                        },
+                    );
+                }
+            }
+        }
+
+        self.reps.push((rep.span, unbounded, false));
+
+        Ok(())
+    }
+
+    fn visit_post(&mut self, ast: &Ast) -> Result<(), Self::Err> {
+        if let Ast::Repetition(_) = ast {
+            self.reps.pop();
+        }
+        Ok(())
+    }
+}
+
 /// Performs some transformations to the regexp AST.
 ///
 /// This type takes an AST produced by the `regex_syntax` crate and returns
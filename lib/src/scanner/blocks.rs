@@ -13,10 +13,13 @@ use std::time::Duration;
 
 use wasmtime::Store;
 
+use crate::compiler::RuleId;
 use crate::errors::VariableError;
+use crate::models::Rule;
 use crate::scanner::context::{create_wasm_store_and_ctx, ScanState};
+use crate::scanner::input::ScanInput;
 use crate::scanner::{DataSnippets, ScanContext};
-use crate::{Rules, ScanError, ScanResults, Variable};
+use crate::{MatchOverlapPolicy, Rules, ScanError, ScanResults, Variable};
 
 /// Scans data in blocks
 ///
@@ -84,8 +87,17 @@ pub struct Scanner<'r> {
     wasm_store: Pin<Box<Store<ScanContext<'static, 'static>>>>,
     needs_reset: bool,
     snippets: BTreeMap<usize, Vec<u8>>,
+    block_size: usize,
+    block_overlap: usize,
 }
 
+/// Default size of the blocks read by [`Scanner::scan_input`].
+const DEFAULT_BLOCK_SIZE: usize = 1024 * 1024;
+
+/// Default number of bytes shared by consecutive blocks read by
+/// [`Scanner::scan_input`].
+const DEFAULT_BLOCK_OVERLAP: usize = 4096;
+
 impl<'r> Scanner<'r> {
     /// Creates a new block scanner.
     pub fn new(rules: &'r Rules) -> Scanner<'r> {
@@ -94,6 +106,8 @@ impl<'r> Scanner<'r> {
             wasm_store: create_wasm_store_and_ctx(rules),
             needs_reset: true,
             snippets: BTreeMap::new(),
+            block_size: DEFAULT_BLOCK_SIZE,
+            block_overlap: DEFAULT_BLOCK_OVERLAP,
         }
     }
 }
@@ -157,6 +171,9 @@ impl<'r> Scanner<'r> {
 
         ctx.scan_state = ScanState::Idle;
 
+        // Number of bytes added to the snippets while processing this block.
+        let mut snippets_size = 0;
+
         for (_, match_list) in ctx.pattern_matches.matches_per_pattern() {
             // Here we iterate the matches in order to gather snippets of data
             // from where the matches occurred. Notice however that we are only
@@ -180,12 +197,15 @@ impl<'r> Scanner<'r> {
                             let snippet = entry.get_mut();
                             if match_data.len() > snippet.len() {
                                 debug_assert!(match_data.starts_with(snippet));
+                                snippets_size +=
+                                    match_data.len() - snippet.len();
                                 entry.insert(match_data.to_vec());
                             } else {
                                 debug_assert!(snippet.starts_with(match_data));
                             }
                         }
                         Entry::Vacant(entry) => {
+                            snippets_size += match_data.len();
                             entry.insert(match_data.to_vec());
                         }
                     }
@@ -195,9 +215,73 @@ impl<'r> Scanner<'r> {
             }
         }
 
+        ctx.track_mem_usage(snippets_size);
+
+        if ctx.mem_limit_reached() {
+            return Err(ScanError::MemLimit);
+        }
+
         Ok(self)
     }
 
+    /// Scans all the data in `input` and finalizes the scanning process.
+    ///
+    /// The data is read in blocks of the size set with
+    /// [`Scanner::block_size`], and each block is scanned as soon as it is
+    /// read, so the whole input is never held in memory. Consecutive blocks
+    /// share the number of bytes set with [`Scanner::block_overlap`], which
+    /// allows finding matches that cross the boundary between blocks, as
+    /// long as they are not longer than the overlap.
+    ///
+    /// This is equivalent to calling [`Scanner::scan`] for each block,
+    /// followed by [`Scanner::finish`].
+    pub fn scan_input<I: ScanInput>(
+        &mut self,
+        mut input: I,
+    ) -> Result<ScanResults<'_, 'r>, ScanError> {
+        let mut buf = vec![0; self.block_size];
+        // Offset of `buf[0]` within the input.
+        let mut base = 0_u64;
+        // Number of valid bytes in `buf`.
+        let mut len = 0;
+        // Number of bytes at the start of `buf` that were already scanned
+        // as part of the previous block.
+        let mut carried = 0;
+
+        loop {
+            let mut eof = false;
+
+            while len < buf.len() {
+                let n = input
+                    .read_at(base + len as u64, &mut buf[len..])
+                    .map_err(|err| ScanError::InputError { err })?;
+                if n == 0 {
+                    eof = true;
+                    break;
+                }
+                len += n;
+            }
+
+            if len > carried {
+                self.scan(base as usize, &buf[..len])?;
+            }
+
+            if eof {
+                break;
+            }
+
+            // Keep the last bytes of the current block at the start of the
+            // buffer, they will be scanned again as part of the next block.
+            let overlap = self.block_overlap.min(buf.len() - 1);
+            buf.copy_within(len - overlap..len, 0);
+            base += (len - overlap) as u64;
+            len = overlap;
+            carried = overlap;
+        }
+
+        self.finish()
+    }
+
     /// Finalizes the scanning process.
     ///
     /// After all data blocks have been scanned, this method evaluates the
@@ -217,7 +301,7 @@ impl<'r> Scanner<'r> {
             mem::take(&mut self.snippets),
         ));
 
-        Ok(ScanResults::new(ctx))
+        Ok(ScanResults::new(ctx, false))
     }
 
     /// Sets the value of a global variable.
@@ -253,15 +337,70 @@ impl<'r> Scanner<'r> {
         self
     }
 
+    /// Sets the size of the blocks read by [`Scanner::scan_input`].
+    ///
+    /// The default block size is 1MB.
+    ///
+    /// # Panics
+    ///
+    /// If `size` is zero.
+    pub fn block_size(&mut self, size: usize) -> &mut Self {
+        assert!(size > 0);
+        self.block_size = size;
+        self
+    }
+
+    /// Sets the number of bytes shared by consecutive blocks read by
+    /// [`Scanner::scan_input`].
+    ///
+    /// Matches that cross the boundary between two blocks are found only if
+    /// they are not longer than the overlap. The default overlap is 4KB.
+    /// If the overlap is not less than the block size, the block size minus
+    /// one is used instead.
+    pub fn block_overlap(&mut self, overlap: usize) -> &mut Self {
+        self.block_overlap = overlap;
+        self
+    }
+
     /// Sets the maximum number of matches per pattern.
     ///
-    /// When some pattern reaches the maximum number of patterns it won't
-    /// produce more matches.
+    /// When some pattern reaches the maximum number of matches it won't
+    /// produce more matches, and [`crate::Pattern::limit_reached`] returns
+    /// `true` for it.
     pub fn max_matches_per_pattern(&mut self, n: usize) -> &mut Self {
         self.scan_context_mut().pattern_matches.max_matches_per_pattern(n);
         self
     }
 
+    /// Sets the policy for overlapping matches of the same pattern.
+    ///
+    /// See [`crate::Scanner::match_overlap_policy`].
+    pub fn match_overlap_policy(
+        &mut self,
+        policy: MatchOverlapPolicy,
+    ) -> &mut Self {
+        self.scan_context_mut().pattern_matches.overlap_policy(policy);
+        self
+    }
+
+    /// Sets the maximum amount of memory in bytes that a scan can use.
+    ///
+    /// See [`crate::Scanner::max_memory_usage`]. In block scanning mode
+    /// this includes the snippets of matching data retained until the
+    /// scan finishes.
+    pub fn max_memory_usage(&mut self, bytes: usize) -> &mut Self {
+        self.scan_context_mut().max_mem_usage = Some(bytes);
+        self
+    }
+
+    /// Sets the maximum number of iterations for `for` loops.
+    ///
+    /// See [`crate::Scanner::max_loop_iterations`].
+    pub fn max_loop_iterations(&mut self, n: u64) -> &mut Self {
+        self.scan_context_mut().set_max_loop_iterations(n);
+        self
+    }
+
     /// Sets a callback that is invoked every time a YARA rule calls the
     /// `console` module.
     ///
@@ -277,6 +416,58 @@ impl<'r> Scanner<'r> {
         self
     }
 
+    /// Associates arbitrary data of type `T` with the scanner.
+    ///
+    /// The data can be retrieved with [`crate::ScanResults::user_data`] after
+    /// each scan, which allows correlating the results with some object
+    /// owned by the user (e.g. the job that requested the scan). The data
+    /// is kept until it is replaced by another call to this function.
+    pub fn set_user_data<T: 'static>(&mut self, data: T) -> &mut Self {
+        self.scan_context_mut().user_data = Some(Box::new(data));
+        self
+    }
+
+    /// Restricts the set of rules evaluated by the scanner.
+    ///
+    /// See [`crate::Scanner::set_rule_filter`] for details.
+    pub fn set_rule_filter<F>(&mut self, mut filter: F) -> &mut Self
+    where
+        F: FnMut(&Rule) -> bool,
+    {
+        let ctx = self.scan_context_mut();
+        let rules = ctx.compiled_rules;
+        for (rule_id, rule) in rules.iter().enumerate() {
+            let rule_id = RuleId::from(rule_id);
+            let skipped =
+                !filter(&rule) || ctx.disabled_rules.contains(&rule_id);
+            ctx.set_rule_skipped(rule_id, skipped);
+        }
+        self
+    }
+
+    /// Disables the rule with identifier `ident` in namespace `namespace`.
+    ///
+    /// See [`crate::Scanner::disable_rule`] for details.
+    pub fn disable_rule(
+        &mut self,
+        namespace: &str,
+        ident: &str,
+    ) -> Result<&mut Self, ScanError> {
+        let ctx = self.scan_context_mut();
+        let rule_id = ctx
+            .compiled_rules
+            .iter()
+            .position(|rule| {
+                rule.namespace() == namespace && rule.identifier() == ident
+            })
+            .ok_or_else(|| ScanError::UnknownRule {
+                namespace: namespace.to_string(),
+                rule: ident.to_string(),
+            })?;
+        ctx.disable_rule(RuleId::from(rule_id));
+        Ok(self)
+    }
+
     /// Returns profiling data for the slowest N rules.
     ///
     /// The profiling data reflects the cumulative execution time of each rule
@@ -330,6 +521,8 @@ impl<'r> From<crate::scanner::Scanner<'r>> for Scanner<'r> {
             wasm_store: scanner.wasm_store,
             needs_reset: true,
             snippets: Default::default(),
+            block_size: DEFAULT_BLOCK_SIZE,
+            block_overlap: DEFAULT_BLOCK_OVERLAP,
         }
     }
 }
@@ -337,6 +530,7 @@ impl<'r> From<crate::scanner::Scanner<'r>> for Scanner<'r> {
 #[cfg(test)]
 mod tests {
     use crate::scanner::blocks::Scanner;
+    use crate::scanner::input::{RangedInput, ReaderInput};
     use crate::{compile, Compiler};
     use std::time::Duration;
 
@@ -507,4 +701,89 @@ mod tests {
 
         assert_eq!(results.matching_rules().len(), 1);
     }
+
+    #[test]
+    fn block_scanner_input() {
+        let rules = compile(
+            r#"
+            rule test { strings: $a = "ipsum" condition: #a == 2 }"#,
+        )
+        .unwrap();
+
+        let data = b"Lorem ipsum dolor ipsum sit amet";
+
+        let mut scanner = Scanner::new(&rules);
+        scanner.block_size(8).block_overlap(5);
+
+        let results =
+            scanner.scan_input(ReaderInput::new(data.as_slice())).unwrap();
+
+        let rule = results.matching_rules().next().unwrap();
+        let pattern = rule.patterns().next().unwrap();
+        let ranges: Vec<_> = pattern.matches().map(|m| m.range()).collect();
+
+        assert_eq!(ranges, vec![6..11, 18..23]);
+
+        // Without overlap, matches that cross the boundary between blocks
+        // are not found.
+        scanner.block_overlap(0);
+
+        let results =
+            scanner.scan_input(ReaderInput::new(data.as_slice())).unwrap();
+
+        assert_eq!(results.matching_rules().len(), 0);
+    }
+
+    #[test]
+    fn block_scanner_ranged_input() {
+        let rules = compile(
+            r#"
+            rule test { strings: $a = "amet" condition: $a at 28 }"#,
+        )
+        .unwrap();
+
+        let data = b"Lorem ipsum dolor ipsum sit amet";
+        let mut requests = 0;
+
+        let input = RangedInput::new(|range: std::ops::Range<u64>| {
+            requests += 1;
+            Ok(data
+                .get(range.start as usize..)
+                .unwrap_or_default()
+                .iter()
+                .take((range.end - range.start) as usize)
+                .copied()
+                .collect())
+        });
+
+        let mut scanner = Scanner::new(&rules);
+        scanner.block_size(16).block_overlap(4);
+
+        let results = scanner.scan_input(input).unwrap();
+
+        assert_eq!(results.matching_rules().len(), 1);
+        // Blocks start at offsets 0, 12 and 24, plus the final request
+        // that returns no data.
+        assert_eq!(requests, 4);
+
+        let input = RangedInput::new(|_| Ok(vec![0; 100])).with_size(1000);
+
+        let err = scanner.scan_input(input).err().unwrap();
+
+        assert_eq!(
+            err.to_string(),
+            "can not read input: requested 16 bytes, but received 100"
+        );
+
+        // Data past the size set with `with_size` is not accepted, even if
+        // it fits in the scanner's buffer.
+        let input = RangedInput::new(|_| Ok(vec![0; 16])).with_size(20);
+
+        let err = scanner.scan_input(input).err().unwrap();
+
+        assert_eq!(
+            err.to_string(),
+            "can not read input: requested 4 bytes, but received 16"
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use std::cell::RefCell;
+use std::any::Any;
 use std::collections::VecDeque;
 #[cfg(feature = "rules-profiling")]
 use std::iter;
@@ -9,21 +9,22 @@ use std::ops::{Deref, Range};
 use std::pin::Pin;
 use std::ptr::NonNull;
 use std::rc::Rc;
-use std::sync::atomic::Ordering;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 use std::{cmp, mem, thread};
 
+use aho_corasick::AhoCorasick;
 use base64::Engine;
 use bitvec::order::Lsb0;
 use bitvec::slice::BitSlice;
 use bstr::{BString, ByteSlice};
 use indexmap::IndexMap;
 use protobuf::{MessageDyn, MessageFull};
-use regex_automata::meta::Regex;
 use rustc_hash::{FxHashMap, FxHashSet};
 use wasmtime::{
     AsContext, AsContextMut, Global, GlobalType, Instance, MemoryType,
-    Mutability, Store, TypedFunc, Val, ValType,
+    Mutability, Store, TypedFunc, UpdateDeadline, Val, ValType,
 };
 
 use crate::compiler::{
@@ -38,7 +39,7 @@ use crate::re::Action;
 use crate::scanner::matches::{Match, PatternMatches, UnconfirmedMatch};
 #[cfg(feature = "rules-profiling")]
 use crate::scanner::ProfilingData;
-use crate::scanner::{DataSnippets, ScanError, ScannedData};
+use crate::scanner::{DataSnippets, ModuleNotice, ScanError, ScannedData};
 use crate::scanner::{HEARTBEAT_COUNTER, INIT_HEARTBEAT};
 use crate::types::{Array, Map, Struct, TypeValue};
 use crate::wasm::MATCHING_RULES_BITMAP_BASE;
@@ -77,11 +78,34 @@ pub(crate) struct ScanContext<'r, 'd> {
     /// WASM global variable that contains a boolean that indicates if
     /// pattern search was done.
     pub wasm_pattern_search_done: Option<Global>,
+    /// WASM global variable that contains the maximum number of iterations
+    /// for loops, or -1 if there's no limit.
+    pub wasm_max_loop_iterations: Option<Global>,
     /// Map where keys are object handles and values are objects used during
     /// the evaluation of rule conditions. Handles are opaque integer values
     /// that can be passed to and received from WASM code. Each handle identify
     /// an object (string, struct, array or map).
     pub runtime_objects: IndexMap<RuntimeObjectHandle, RuntimeObject>,
+    /// Hash map where keys are the strings stored in `runtime_objects`, and
+    /// values are their handles. Strings returned by lookups and functions
+    /// are interned, so that identical strings are stored only once, no
+    /// matter how many times they are produced while evaluating conditions.
+    pub runtime_strings: FxHashMap<Rc<BString>, RuntimeObjectHandle>,
+    /// Maximum capacity retained by `runtime_objects` and `runtime_strings`
+    /// between scans. Both are cleared at the start of each scan, but if
+    /// their capacity exceeds this value, they are shrunk to it, so that a
+    /// single scan that produced many runtime objects doesn't keep the
+    /// memory allocated for subsequent scans.
+    pub max_runtime_objects: usize,
+    /// Approximate amount of memory in bytes allocated during the current
+    /// scan for storing pattern matches, runtime strings and module outputs.
+    pub mem_usage: usize,
+    /// Maximum value reached by `mem_usage` during the current scan.
+    pub peak_mem_usage: usize,
+    /// Maximum value allowed for `mem_usage`. When exceeded, the scan is
+    /// aborted with [`ScanError::MemLimit`]. See
+    /// [`crate::Scanner::max_memory_usage`].
+    pub max_mem_usage: Option<usize>,
     /// The time that can be spent in a scan operation, including the
     /// execution of the rule conditions.
     pub scan_timeout: Option<Duration>,
@@ -104,13 +128,11 @@ pub(crate) struct ScanContext<'r, 'd> {
     /// Compiled rules for this scan.
     pub compiled_rules: &'r Rules,
     /// Structure that contains top-level symbols, like module names
-    /// and external variables. Symbols are normally looked up in this
-    /// structure, except if `current_struct` is set to some other
-    /// structure that overrides `root_struct`.
+    /// and external variables. Lookups start at this structure, unless
+    /// they start at some structure stored in a variable, like the one
+    /// bound to the variable of a `for` loop that iterates over an array
+    /// of structures.
     pub root_struct: Struct,
-    /// Currently active structure that overrides the `root_struct` if
-    /// set.
-    pub current_struct: Option<Rc<Struct>>,
     /// Hash map that contains the protobuf messages returned by YARA modules.
     /// Keys are the fully qualified protobuf message name, and values are
     /// the message returned by the main function of the corresponding module.
@@ -135,15 +157,47 @@ pub(crate) struct ScanContext<'r, 'd> {
     /// Set that contains the PatternId for those patterns that have reached
     /// the maximum number of matches indicated by `max_matches_per_pattern`.
     pub limit_reached: FxHashSet<PatternId>,
+    /// Set that contains the RuleId for the rules disabled with
+    /// [`crate::Scanner::disable_rule`].
+    pub disabled_rules: FxHashSet<RuleId>,
+    /// Set that contains the PatternId for those patterns that are not
+    /// searched for because all the rules that use them are disabled.
+    pub disabled_patterns: FxHashSet<PatternId>,
+    /// Maximum number of iterations for loops over ranges whose bounds are
+    /// not known at compile time. See [`crate::Scanner::max_loop_iterations`].
+    pub max_loop_iterations: Option<i64>,
+    /// Rules containing some loop that reached `max_loop_iterations`, in
+    /// the order in which the limit was reached.
+    pub loop_limit_reached: Vec<RuleId>,
     /// When [`HEARTBEAT_COUNTER`] is larger than this value, the scan is
     /// aborted due to a timeout.
     pub deadline: u64,
-    /// Hash map that serves as a cache for regexps used in expressions like
-    /// `some_var matches /foobar/`. Compiling a regexp is a expensive
-    /// operation. Instead of compiling the regexp each time the expression
-    /// is evaluated, it is compiled the first time and stored in this hash
-    /// map.
-    pub regexp_cache: RefCell<FxHashMap<RegexpId, Regex>>,
+    /// Flag shared with the [`crate::scanner::CancellationToken`] returned
+    /// by [`crate::Scanner::cancellation_token`]. When set to true, the scan
+    /// is aborted with [`ScanError::Cancelled`].
+    pub cancelled: Arc<AtomicBool>,
+    /// Hash map that serves as a cache for the values of computed fields.
+    /// Keys are the address of the structure that contains the field and
+    /// the field's name, values are the results of the methods computing
+    /// the fields. See [`ScanContext::computed_field`]. As the address of a
+    /// structure can be reused after the structure is dropped, the entries
+    /// for computed fields must be removed with
+    /// [`ScanContext::invalidate_computed_fields`] every time some field
+    /// in the root structure is replaced.
+    pub computed_fields: FxHashMap<(usize, &'static str), Box<dyn Any>>,
+    /// Hash map that serves as a cache for field lookups that start at the
+    /// root structure. Keys are the field indexes that form the path to
+    /// the field, values are the values of the fields. The root structure
+    /// doesn't change while the rule conditions are being evaluated, so
+    /// the result of each lookup can be reused by every rule during the
+    /// current scan. See [`crate::wasm::lookup_field`].
+    pub field_lookup_cache: FxHashMap<Vec<i32>, TypeValue>,
+    /// Notices emitted by modules while processing the scanned data. See
+    /// [`crate::modules::emit_notice`].
+    pub module_notices: Vec<ModuleNotice>,
+    /// Arbitrary data provided by the user with
+    /// [`crate::Scanner::set_user_data`].
+    pub user_data: Option<Box<dyn Any>>,
     /// Callback invoked every time a YARA rule calls `console.log`.
     pub console_log: Option<Box<dyn FnMut(String) + 'r>>,
     /// Hash map that tracks the time spend on each pattern. Keys are pattern
@@ -240,6 +294,7 @@ impl ScanContext<'_, '_> {
 
 impl ScanContext<'_, '_> {
     const DEFAULT_SCAN_TIMEOUT: u64 = 315_360_000;
+    pub(crate) const DEFAULT_MAX_RUNTIME_OBJECTS: usize = 4096;
 
     /// Returns a slice with the data being scanned.
     ///
@@ -267,11 +322,170 @@ impl ScanContext<'_, '_> {
         regexp_id: RegexpId,
         haystack: &[u8],
     ) -> bool {
-        self.regexp_cache
-            .borrow_mut()
-            .entry(regexp_id)
-            .or_insert_with(|| self.compiled_rules.get_regexp(regexp_id))
-            .is_match(haystack)
+        self.compiled_rules.get_regexp(regexp_id).is_match(haystack)
+    }
+
+    /// Returns the number of non-overlapping matches of the regexp
+    /// identified by the given [`RegexpId`] in `haystack`.
+    pub(crate) fn regexp_count_matches(
+        &self,
+        regexp_id: RegexpId,
+        haystack: &[u8],
+    ) -> usize {
+        self.compiled_rules.get_regexp(regexp_id).find_iter(haystack).count()
+    }
+
+    /// Returns a reference to the data set with
+    /// [`crate::Scanner::set_user_data`], or `None` if no data was set or
+    /// if it isn't of type `T`.
+    pub(crate) fn user_data<T: 'static>(&self) -> Option<&T> {
+        self.user_data.as_ref()?.downcast_ref::<T>()
+    }
+
+    /// Returns the value of a computed field, calling `f` for computing it
+    /// only the first time the field is accessed during the current scan.
+    ///
+    /// Computed fields are declared with `(yara.field_options).computed`,
+    /// and their values are computed by a method that is invoked every time
+    /// the field is used in a condition. When computing the value is
+    /// expensive, the method should use this function, so that the value
+    /// is computed once per scan, no matter how many rules use the field.
+    ///
+    /// `structure` is the structure that contains the field, and `name` is
+    /// the field's name.
+    pub(crate) fn computed_field<T, F>(
+        &mut self,
+        structure: &Rc<Struct>,
+        name: &'static str,
+        f: F,
+    ) -> T
+    where
+        T: Clone + 'static,
+        F: FnOnce(&mut Self) -> T,
+    {
+        self.memoize((Rc::as_ptr(structure) as usize, name), f)
+    }
+
+    /// Like [`ScanContext::computed_field`], but for values that don't
+    /// belong to any particular structure, like the ones returned by module
+    /// functions without arguments (e.g: `pe.imphash()`).
+    ///
+    /// `name` must be unique across all modules, like `pe.imphash`.
+    pub(crate) fn computed_value<T, F>(
+        &mut self,
+        name: &'static str,
+        f: F,
+    ) -> T
+    where
+        T: Clone + 'static,
+        F: FnOnce(&mut Self) -> T,
+    {
+        self.memoize((0, name), f)
+    }
+
+    /// Returns a mutable reference to a value of type `T` that lives until
+    /// the end of the current scan, creating it with `T::default()` the
+    /// first time it's accessed during the scan.
+    ///
+    /// This is for per-scan state that is more complex than a single value,
+    /// like a cache indexed by the arguments of a module function. Modules
+    /// must not keep per-scan state in thread-local variables, because with
+    /// [`crate::Scanner::module_timeout`] the main function runs in a
+    /// different thread than the rest of the module's functions.
+    ///
+    /// `name` must be unique across all modules, like `hash.md5`.
+    pub(crate) fn scan_local<T: Default + 'static>(
+        &mut self,
+        name: &'static str,
+    ) -> &mut T {
+        self.computed_fields
+            .entry((0, name))
+            .or_insert_with(|| Box::new(T::default()))
+            .downcast_mut::<T>()
+            .expect("the same name was used with different types")
+    }
+
+    /// Removes the cached values of computed fields, keeping the ones
+    /// stored by [`ScanContext::computed_value`] and
+    /// [`ScanContext::scan_local`], which don't depend on any structure.
+    ///
+    /// This must be called every time a field in the root structure is
+    /// replaced, as the cached values are keyed by the address of the
+    /// structure that contains the field, and the new structure could be
+    /// allocated at the address of a structure that was dropped.
+    pub(crate) fn invalidate_computed_fields(&mut self) {
+        self.computed_fields.retain(|(addr, _), _| *addr == 0);
+    }
+
+    /// Returns the value associated to `key` in `computed_fields`, calling
+    /// `f` for computing it if not present.
+    fn memoize<T, F>(&mut self, key: (usize, &'static str), f: F) -> T
+    where
+        T: Clone + 'static,
+        F: FnOnce(&mut Self) -> T,
+    {
+        if let Some(value) = self
+            .computed_fields
+            .get(&key)
+            .and_then(|value| value.downcast_ref::<T>())
+        {
+            return value.clone();
+        }
+
+        let value = f(self);
+        self.computed_fields.insert(key, Box::new(value.clone()));
+        value
+    }
+
+    /// Returns the matches of a pattern contributed by a module.
+    ///
+    /// Modules can contribute their own patterns to the search phase by
+    /// declaring them in the `patterns` field of `yara.module_options`.
+    /// `module` is the module's name and `ident` is the identifier of the
+    /// pattern. If the search phase was not executed yet, this function
+    /// triggers it, so that both the patterns declared by the module and
+    /// those declared in rules are searched for in a single pass.
+    ///
+    /// # Panics
+    ///
+    /// If the module doesn't declare a pattern with the given identifier.
+    pub(crate) fn module_pattern_matches(
+        &mut self,
+        module: &str,
+        ident: &str,
+    ) -> impl Iterator<Item = Range<usize>> + '_ {
+        let pattern_id = self
+            .compiled_rules
+            .module_pattern_id(module, ident)
+            .unwrap_or_else(|| {
+                panic!("module `{module}` doesn't declare pattern `{ident}`")
+            });
+
+        let search_done = self
+            .wasm_pattern_search_done
+            .unwrap()
+            .get(self.wasm_store_mut())
+            .unwrap_i32()
+            != 0;
+
+        if !search_done
+            && matches!(
+                self.scan_state,
+                ScanState::ScanningData(_) | ScanState::ScanningBlock(_)
+            )
+            && self.search_for_patterns().is_err()
+        {
+            // Force the WASM runtime to abort the evaluation of the rule
+            // conditions as soon as possible, see `search_for_patterns`
+            // in `wasm/mod.rs` for details.
+            self.wasm_store_mut().set_epoch_deadline(0);
+        }
+
+        self.pattern_matches
+            .get(pattern_id)
+            .into_iter()
+            .flat_map(|match_list| match_list.iter())
+            .map(|m| m.range.clone())
     }
 
     /// Returns the protobuf struct produced by a module.
@@ -316,6 +530,8 @@ impl ScanContext<'_, '_> {
             // The new type must match the old one.
             if type_value.eq_type(&field.type_value) {
                 field.type_value = type_value;
+                self.field_lookup_cache.clear();
+                self.invalidate_computed_fields();
             } else {
                 return Err(VariableError::InvalidType {
                     variable: ident.to_string(),
@@ -351,15 +567,45 @@ impl ScanContext<'_, '_> {
         obj_ref
     }
 
+    /// Stores a string in the runtime objects and returns its handle.
+    ///
+    /// If an identical string was already stored during the current scan,
+    /// the handle of the existing string is returned.
     pub(crate) fn store_string(
         &mut self,
         s: Rc<BString>,
     ) -> RuntimeObjectHandle {
+        if let Some(handle) = self.runtime_strings.get(&s) {
+            return *handle;
+        }
         let obj_ref = RuntimeObjectHandle(Rc::<BString>::as_ptr(&s) as i64);
+        self.track_mem_usage(s.len() + mem::size_of::<BString>());
+        // If the memory limit was exceeded, force the WASM runtime to abort
+        // the evaluation of the rule conditions as soon as possible. The
+        // string is stored anyway, because the caller expects a valid
+        // handle.
+        if self.mem_limit_reached() {
+            self.wasm_store_mut().set_epoch_deadline(0);
+        }
+        self.runtime_strings.insert(s.clone(), obj_ref);
         self.runtime_objects.insert_full(obj_ref, RuntimeObject::String(s));
         obj_ref
     }
 
+    /// Increases the amount of memory used by the current scan by `bytes`.
+    #[inline]
+    pub(crate) fn track_mem_usage(&mut self, bytes: usize) {
+        self.mem_usage += bytes;
+        self.peak_mem_usage = cmp::max(self.peak_mem_usage, self.mem_usage);
+    }
+
+    /// Returns true if the memory used by the current scan exceeds the
+    /// limit set with [`crate::Scanner::max_memory_usage`].
+    #[inline]
+    pub(crate) fn mem_limit_reached(&self) -> bool {
+        self.max_mem_usage.is_some_and(|max| self.mem_usage > max)
+    }
+
     /// Gets the value of the global variable `filesize`.
     pub(crate) fn get_filesize(&mut self) -> i64 {
         self.wasm_filesize.unwrap().get(self.wasm_store_mut()).i64().unwrap()
@@ -373,6 +619,17 @@ impl ScanContext<'_, '_> {
             .unwrap();
     }
 
+    /// Sets the maximum number of iterations for loops over ranges whose
+    /// bounds are not known at compile time.
+    pub(crate) fn set_max_loop_iterations(&mut self, n: u64) {
+        let n = i64::try_from(n).unwrap_or(i64::MAX);
+        self.max_loop_iterations = Some(n);
+        self.wasm_max_loop_iterations
+            .unwrap()
+            .set(self.wasm_store_mut(), Val::I64(n))
+            .unwrap();
+    }
+
     /// Sets the value of the flag that indicates if the pattern search
     /// phase was already executed.
     pub(crate) fn set_pattern_search_done(&mut self, done: bool) {
@@ -382,6 +639,13 @@ impl ScanContext<'_, '_> {
             .unwrap();
     }
 
+    /// Returns true if the scan was cancelled with
+    /// [`crate::scanner::CancellationToken::cancel`].
+    #[inline]
+    pub(crate) fn cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
     /// Sets a timeout for scan operations.
     pub(crate) fn set_timeout(&mut self, timeout: Duration) -> &mut Self {
         self.scan_timeout = Some(timeout);
@@ -395,6 +659,9 @@ impl ScanContext<'_, '_> {
     /// This will return [ScanError::Timeout], if a timeout occurs while
     /// searching for patterns or evaluating the conditions.
     pub(crate) fn eval_conditions(&mut self) -> Result<(), ScanError> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("eval_conditions").entered();
+
         // Save the time in which the evaluation started.
         #[cfg(feature = "rules-profiling")]
         {
@@ -474,6 +741,8 @@ impl ScanContext<'_, '_> {
         match eval_result {
             Ok(0) => match self.scan_state {
                 ScanState::Timeout => Err(ScanError::Timeout),
+                _ if self.cancelled() => Err(ScanError::Cancelled),
+                _ if self.mem_limit_reached() => Err(ScanError::MemLimit),
                 _ => Ok(()),
             },
             Ok(v) => panic!("WASM main returned: {v}"),
@@ -486,6 +755,27 @@ impl ScanContext<'_, '_> {
         }
     }
 
+    /// Limits the number of iterations of a loop in the given rule to the
+    /// maximum set with [`crate::Scanner::max_loop_iterations`].
+    ///
+    /// Returns the number of iterations that the loop must actually
+    /// perform, which is `n` if the limit was not reached.
+    pub(crate) fn limit_loop_iterations(
+        &mut self,
+        rule_id: RuleId,
+        n: i64,
+    ) -> i64 {
+        match self.max_loop_iterations {
+            Some(max) if n > max => {
+                if !self.loop_limit_reached.contains(&rule_id) {
+                    self.loop_limit_reached.push(rule_id);
+                }
+                max
+            }
+            _ => n,
+        }
+    }
+
     /// Resets the scan context to its initial state, making it ready for
     /// another scan.
     ///
@@ -497,23 +787,31 @@ impl ScanContext<'_, '_> {
 
         self.scan_state = ScanState::Idle;
 
-        // Free all runtime objects left around by previous scans.
+        // Free all runtime objects left around by previous scans, releasing
+        // the excess of memory if some previous scan produced an unusually
+        // large number of them.
         self.runtime_objects.clear();
+        self.runtime_objects.shrink_to(self.max_runtime_objects);
+        self.runtime_strings.clear();
+        self.runtime_strings.shrink_to(self.max_runtime_objects);
 
         // Clear the array that tracks the patterns that reached the maximum
         // number of patterns.
         self.limit_reached.clear();
+        self.loop_limit_reached.clear();
 
         self.unconfirmed_matches.clear();
+        self.mem_usage = 0;
+        self.peak_mem_usage = 0;
         self.num_matching_private_rules = 0;
         self.num_non_matching_private_rules = 0;
 
-        // Clear the value of `current_struct` as it may contain a reference
-        // to some struct.
-        self.current_struct = None;
-
-        // Clear module outputs from previous scans.
+        // Clear module outputs from previous scans, and the values computed
+        // from them.
         self.module_outputs.clear();
+        self.computed_fields.clear();
+        self.field_lookup_cache.clear();
+        self.module_notices.clear();
 
         // Move the matching rules to the `matching_rules` vector, leaving the
         // `matching_rules_per_ns` map empty.
@@ -571,11 +869,27 @@ impl ScanContext<'_, '_> {
 
         let wasm_store = self.wasm_store_mut();
 
-        // Sets the deadline for the WASM store. The WASM main function
-        // will abort if the deadline is reached while the function is being
-        // executed.
-        wasm_store.set_epoch_deadline(timeout_secs);
-        wasm_store.epoch_deadline_callback(|_| Err(ScanError::Timeout.into()));
+        // The WASM store's deadline expires every time the epoch is
+        // incremented, which happens once per second in the heartbeat thread,
+        // and also when a scan is cancelled with `CancellationToken::cancel`.
+        // The callback decides whether the execution of the WASM main
+        // function must be aborted or not. The deadline is also forced to
+        // expire when the memory limit is exceeded or when the search for
+        // patterns is aborted.
+        wasm_store.set_epoch_deadline(1);
+        wasm_store.epoch_deadline_callback(|store| {
+            let ctx = store.data();
+            if ctx.cancelled() {
+                Err(ScanError::Cancelled.into())
+            } else if ctx.mem_limit_reached() {
+                Err(ScanError::MemLimit.into())
+            } else if HEARTBEAT_COUNTER.load(Ordering::Relaxed) >= ctx.deadline
+            {
+                Err(ScanError::Timeout.into())
+            } else {
+                Ok(UpdateDeadline::Continue(1))
+            }
+        });
 
         // If some timeout was specified, start the heartbeat thread, if
         // not previously started. The heartbeat thread increments the WASM
@@ -586,7 +900,7 @@ impl ScanContext<'_, '_> {
             INIT_HEARTBEAT.call_once(|| {
                 thread::spawn(|| loop {
                     thread::sleep(Duration::from_secs(1));
-                    wasm::get_engine().increment_epoch();
+                    wasm::increment_epoch();
                     HEARTBEAT_COUNTER
                         .fetch_update(
                             Ordering::SeqCst,
@@ -741,11 +1055,66 @@ impl ScanContext<'_, '_> {
 
         bits.set(pattern_id.into(), true);
 
-        if !self.pattern_matches.add(pattern_id, match_, replace_if_longer) {
+        if self.pattern_matches.add(pattern_id, match_, replace_if_longer) {
+            self.track_mem_usage(mem::size_of::<Match>());
+        } else {
             self.limit_reached.insert(pattern_id);
         }
     }
 
+    /// Disables the rule identified by `rule_id`.
+    ///
+    /// The rule is skipped while evaluating conditions, and the patterns
+    /// that are not shared with other enabled rules are excluded from the
+    /// pattern search phase.
+    pub(crate) fn disable_rule(&mut self, rule_id: RuleId) {
+        if !self.disabled_rules.insert(rule_id) {
+            return;
+        }
+
+        self.set_rule_skipped(rule_id, true);
+
+        let rules = self.compiled_rules;
+
+        for pattern in rules.get(rule_id).patterns.iter() {
+            // Identical patterns in different rules share the same
+            // PatternId, the pattern can be excluded only if all the rules
+            // that use it are disabled.
+            let shared = rules.rules().iter().enumerate().any(|(id, rule)| {
+                !self.disabled_rules.contains(&RuleId::from(id))
+                    && rule
+                        .patterns
+                        .iter()
+                        .any(|p| p.pattern_id == pattern.pattern_id)
+            });
+            if !shared {
+                self.disabled_patterns.insert(pattern.pattern_id);
+            }
+        }
+    }
+
+    /// Sets or clears the bit that indicates whether the rule identified by
+    /// `rule_id` must be skipped while evaluating conditions.
+    ///
+    /// Skipped rules are not evaluated at all, they are reported as
+    /// non-matching. The state of this bit persists across scans.
+    pub(crate) fn set_rule_skipped(&mut self, rule_id: RuleId, skipped: bool) {
+        let wasm_store = self.wasm_store_mut();
+        let mem = self.wasm_main_memory.unwrap().data_mut(wasm_store);
+        let num_rules = self.compiled_rules.num_rules();
+        let num_patterns = self.compiled_rules.num_patterns();
+
+        let base = MATCHING_RULES_BITMAP_BASE as usize
+            + num_rules.div_ceil(8)
+            + num_patterns.div_ceil(8);
+
+        let bits = BitSlice::<u8, Lsb0>::from_slice_mut(
+            &mut mem[base..base + num_rules.div_ceil(8)],
+        );
+
+        bits.set(rule_id.into(), skipped);
+    }
+
     /// The Aho-Corasick search loop.
     pub(crate) fn ac_search_loop(
         &mut self,
@@ -771,232 +1140,271 @@ impl ScanContext<'_, '_> {
                 atom_matches += 1;
             }
 
-            if HEARTBEAT_COUNTER.load(Ordering::Relaxed) >= self.deadline {
-                return Err(ScanError::Timeout);
-            }
+            self.check_scan_limits()?;
 
             let atom =
                 unsafe { atoms.get_unchecked(ac_match.pattern().as_usize()) };
 
-            // Subtract the backtrack value from the offset where the atom
-            // matched. If the result is negative the atom can't be inside
-            // the scanned data and therefore is not a possible match.
-            let atom_pos = if let Some(atom_pos) =
-                ac_match.start().checked_sub(atom.backtrack())
-            {
+            self.verify_atom_match(
+                &mut vm,
+                base,
+                data,
+                atom,
+                ac_match.start(),
+                filesize,
+                block_scanning_mode,
+            );
+        }
+
+        #[cfg(feature = "logging")]
+        log::info!("Atom matches: {}", atom_matches);
+
+        Ok(())
+    }
+
+    /// Returns an error if the scan timed out, was cancelled or exceeded
+    /// the memory limit.
+    #[inline]
+    fn check_scan_limits(&self) -> Result<(), ScanError> {
+        if HEARTBEAT_COUNTER.load(Ordering::Relaxed) >= self.deadline {
+            return Err(ScanError::Timeout);
+        }
+
+        if self.cancelled() {
+            return Err(ScanError::Cancelled);
+        }
+
+        if self.mem_limit_reached() {
+            return Err(ScanError::MemLimit);
+        }
+
+        Ok(())
+    }
+
+    /// Verifies whether the sub-pattern that contains `atom` matches, given
+    /// that the Aho-Corasick automaton found the atom at offset `atom_start`
+    /// within `data`.
+    #[allow(clippy::too_many_arguments)]
+    fn verify_atom_match(
+        &mut self,
+        vm: &mut VM<'_>,
+        base: usize,
+        data: &[u8],
+        atom: &SubPatternAtom,
+        atom_start: usize,
+        filesize: i64,
+        block_scanning_mode: bool,
+    ) {
+        // Subtract the backtrack value from the offset where the atom
+        // matched. If the result is negative the atom can't be inside
+        // the scanned data and therefore is not a possible match.
+        let atom_pos =
+            if let Some(atom_pos) = atom_start.checked_sub(atom.backtrack()) {
                 atom_pos
             } else {
-                continue;
+                return;
             };
 
-            // Each atom belongs to a sub-pattern.
-            let sub_pattern_id = atom.sub_pattern_id();
+        // Each atom belongs to a sub-pattern.
+        let sub_pattern_id = atom.sub_pattern_id();
 
-            // Each sub-pattern belongs to a pattern.
-            let (pattern_id, sub_pattern) =
-                &self.compiled_rules.get_sub_pattern(sub_pattern_id);
+        // Each sub-pattern belongs to a pattern.
+        let (pattern_id, sub_pattern) =
+            &self.compiled_rules.get_sub_pattern(sub_pattern_id);
 
-            // Check if the potentially matching pattern has reached the
-            // maximum number of allowed matches. In that case continue without
-            // verifying the match.
-            if self.limit_reached.contains(pattern_id) {
-                continue;
-            }
+        // Check if the potentially matching pattern has reached the
+        // maximum number of allowed matches. In that case continue without
+        // verifying the match.
+        if self.limit_reached.contains(pattern_id) {
+            return;
+        }
 
-            // If there are file size bounds associated to the pattern, but
-            // the currently scanned file does not satisfy them, no further
-            // confirmation is needed. The rule won't match regardless of
-            // whether the pattern matches or not. This is not done in block
-            // scanning mode as `filesize` is undefined in that mode.
-            if !block_scanning_mode {
-                if let Some(bounds) =
-                    self.compiled_rules.filesize_bounds(*pattern_id)
-                {
-                    if !bounds.contains(filesize) {
-                        continue;
-                    }
+        // Patterns that are used only by disabled rules are not
+        // verified, as they can't make any rule match.
+        if self.disabled_patterns.contains(pattern_id) {
+            return;
+        }
+
+        // If there are file size bounds associated to the pattern, but
+        // the currently scanned file does not satisfy them, no further
+        // confirmation is needed. The rule won't match regardless of
+        // whether the pattern matches or not. This is not done in block
+        // scanning mode as `filesize` is undefined in that mode.
+        if !block_scanning_mode {
+            if let Some(bounds) =
+                self.compiled_rules.filesize_bounds(*pattern_id)
+            {
+                if !bounds.contains(filesize) {
+                    return;
                 }
             }
+        }
 
-            #[cfg(feature = "rules-profiling")]
-            let verification_start = self.clock.raw();
-
-            // If the atom is exact no further verification is needed, except
-            // for making sure that the fullword requirements are met. An exact
-            // atom is enough to guarantee that the whole sub-pattern matched.
-            #[cfg(feature = "exact-atoms")]
-            if atom.is_exact() {
-                let flags = match sub_pattern {
-                    SubPattern::Literal { flags, .. }
-                    | SubPattern::LiteralChainHead { flags, .. }
-                    | SubPattern::LiteralChainTail { flags, .. }
-                    | SubPattern::Regexp { flags, .. }
-                    | SubPattern::RegexpChainHead { flags, .. }
-                    | SubPattern::RegexpChainTail { flags, .. } => flags,
-                    _ => unreachable!(),
-                };
+        #[cfg(feature = "rules-profiling")]
+        let verification_start = self.clock.raw();
+
+        // If the atom is exact no further verification is needed, except
+        // for making sure that the fullword requirements are met. An exact
+        // atom is enough to guarantee that the whole sub-pattern matched.
+        #[cfg(feature = "exact-atoms")]
+        if atom.is_exact() {
+            let flags = match sub_pattern {
+                SubPattern::Literal { flags, .. }
+                | SubPattern::LiteralChainHead { flags, .. }
+                | SubPattern::LiteralChainTail { flags, .. }
+                | SubPattern::Regexp { flags, .. }
+                | SubPattern::RegexpChainHead { flags, .. }
+                | SubPattern::RegexpChainTail { flags, .. } => flags,
+                _ => unreachable!(),
+            };
 
-                let match_range = atom_pos..atom_pos + atom.len();
+            let match_range = atom_pos..atom_pos + atom.len();
 
-                if verify_full_word(data, &match_range, *flags, None) {
+            if verify_full_word(data, &match_range, *flags, None) {
+                self.handle_sub_pattern_match(
+                    sub_pattern_id,
+                    sub_pattern,
+                    *pattern_id,
+                    Match::new(match_range).rebase(base),
+                );
+            }
+
+            return;
+        }
+
+        match sub_pattern {
+            SubPattern::Literal { pattern, flags, .. }
+            | SubPattern::LiteralChainHead { pattern, flags, .. }
+            | SubPattern::LiteralChainTail { pattern, flags, .. } => {
+                let pattern = self
+                    .compiled_rules
+                    .lit_pool()
+                    .get_bytes(*pattern)
+                    .unwrap();
+
+                if verify_literal_match(pattern, data, atom_pos, *flags) {
                     self.handle_sub_pattern_match(
                         sub_pattern_id,
                         sub_pattern,
                         *pattern_id,
-                        Match::new(match_range).rebase(base),
+                        Match::new(atom_pos..atom_pos + pattern.len())
+                            .rebase(base),
                     );
                 }
-
-                continue;
             }
-
-            match sub_pattern {
-                SubPattern::Literal { pattern, flags, .. }
-                | SubPattern::LiteralChainHead { pattern, flags, .. }
-                | SubPattern::LiteralChainTail { pattern, flags, .. } => {
-                    let pattern = self
-                        .compiled_rules
-                        .lit_pool()
-                        .get_bytes(*pattern)
-                        .unwrap();
-
-                    if verify_literal_match(pattern, data, atom_pos, *flags) {
+            SubPattern::Regexp { flags, .. }
+            | SubPattern::RegexpChainHead { flags, .. }
+            | SubPattern::RegexpChainTail { flags, .. } => {
+                verify_regexp_match(
+                    vm,
+                    data,
+                    atom_pos,
+                    atom,
+                    *flags,
+                    |match_range| {
                         self.handle_sub_pattern_match(
                             sub_pattern_id,
                             sub_pattern,
                             *pattern_id,
-                            Match::new(atom_pos..atom_pos + pattern.len())
-                                .rebase(base),
+                            Match::new(match_range).rebase(base),
                         );
-                    }
-                }
-                SubPattern::Regexp { flags, .. }
-                | SubPattern::RegexpChainHead { flags, .. }
-                | SubPattern::RegexpChainTail { flags, .. } => {
-                    verify_regexp_match(
-                        &mut vm,
-                        data,
-                        atom_pos,
-                        atom,
-                        *flags,
-                        |match_range| {
-                            self.handle_sub_pattern_match(
-                                sub_pattern_id,
-                                sub_pattern,
-                                *pattern_id,
-                                Match::new(match_range).rebase(base),
-                            );
-                        },
-                    )
-                }
+                    },
+                )
+            }
 
-                SubPattern::Xor { pattern, flags } => {
-                    let pattern = self
-                        .compiled_rules
-                        .lit_pool()
-                        .get_bytes(*pattern)
-                        .unwrap();
+            SubPattern::Xor { pattern, flags } => {
+                let pattern = self
+                    .compiled_rules
+                    .lit_pool()
+                    .get_bytes(*pattern)
+                    .unwrap();
 
-                    if let Some(key) =
-                        verify_xor_match(pattern, data, atom_pos, atom, *flags)
-                    {
-                        self.handle_sub_pattern_match(
-                            sub_pattern_id,
-                            sub_pattern,
-                            *pattern_id,
-                            Match::new(atom_pos..atom_pos + pattern.len())
-                                .rebase(base)
-                                .xor_key(key),
-                        );
-                    }
+                if let Some(key) =
+                    verify_xor_match(pattern, data, atom_pos, atom, *flags)
+                {
+                    self.handle_sub_pattern_match(
+                        sub_pattern_id,
+                        sub_pattern,
+                        *pattern_id,
+                        Match::new(atom_pos..atom_pos + pattern.len())
+                            .rebase(base)
+                            .xor_key(key),
+                    );
                 }
+            }
 
-                SubPattern::Base64 { pattern, padding }
-                | SubPattern::Base64Wide { pattern, padding } => {
-                    if let Some(match_range) = verify_base64_match(
-                        self.compiled_rules
-                            .lit_pool()
-                            .get_bytes(*pattern)
-                            .unwrap(),
-                        data,
-                        (*padding).into(),
-                        atom_pos,
-                        None,
-                        matches!(sub_pattern, SubPattern::Base64Wide { .. }),
-                    ) {
-                        self.handle_sub_pattern_match(
-                            sub_pattern_id,
-                            sub_pattern,
-                            *pattern_id,
-                            Match::new(match_range).rebase(base),
-                        );
-                    }
+            SubPattern::Base64 { pattern, padding }
+            | SubPattern::Base64Wide { pattern, padding } => {
+                if let Some(match_range) = verify_base64_match(
+                    self.compiled_rules
+                        .lit_pool()
+                        .get_bytes(*pattern)
+                        .unwrap(),
+                    data,
+                    (*padding).into(),
+                    atom_pos,
+                    None,
+                    matches!(sub_pattern, SubPattern::Base64Wide { .. }),
+                ) {
+                    self.handle_sub_pattern_match(
+                        sub_pattern_id,
+                        sub_pattern,
+                        *pattern_id,
+                        Match::new(match_range).rebase(base),
+                    );
                 }
+            }
 
-                SubPattern::CustomBase64 { pattern, alphabet, padding }
-                | SubPattern::CustomBase64Wide {
-                    pattern,
-                    alphabet,
-                    padding,
-                } => {
-                    let alphabet = self
-                        .compiled_rules
-                        .lit_pool()
-                        .get_str(*alphabet)
-                        .map(|alphabet| {
+            SubPattern::CustomBase64 { pattern, alphabet, padding }
+            | SubPattern::CustomBase64Wide { pattern, alphabet, padding } => {
+                let alphabet =
+                    self.compiled_rules.lit_pool().get_str(*alphabet).map(
+                        |alphabet| {
                             // `Alphabet::new` validates the string again. This
                             // is not really necessary as we already know that
                             // the string represents a valid alphabet, it would
                             // be better if we could use the private function
                             // `Alphabet::from_str_unchecked`
                             base64::alphabet::Alphabet::new(alphabet).unwrap()
-                        });
+                        },
+                    );
 
-                    assert!(alphabet.is_some());
+                assert!(alphabet.is_some());
 
-                    if let Some(match_range) = verify_base64_match(
-                        self.compiled_rules
-                            .lit_pool()
-                            .get_bytes(*pattern)
-                            .unwrap(),
-                        data,
-                        (*padding).into(),
-                        atom_pos,
-                        alphabet,
-                        matches!(
-                            sub_pattern,
-                            SubPattern::CustomBase64Wide { .. }
-                        ),
-                    ) {
-                        self.handle_sub_pattern_match(
-                            sub_pattern_id,
-                            sub_pattern,
-                            *pattern_id,
-                            Match::new(match_range).rebase(base),
-                        );
-                    }
+                if let Some(match_range) = verify_base64_match(
+                    self.compiled_rules
+                        .lit_pool()
+                        .get_bytes(*pattern)
+                        .unwrap(),
+                    data,
+                    (*padding).into(),
+                    atom_pos,
+                    alphabet,
+                    matches!(sub_pattern, SubPattern::CustomBase64Wide { .. }),
+                ) {
+                    self.handle_sub_pattern_match(
+                        sub_pattern_id,
+                        sub_pattern,
+                        *pattern_id,
+                        Match::new(match_range).rebase(base),
+                    );
                 }
-            };
-
-            #[cfg(feature = "rules-profiling")]
-            {
-                let time_spent = self
-                    .clock
-                    .delta_as_nanos(verification_start, self.clock.raw());
-
-                self.time_spent_in_pattern
-                    .entry(*pattern_id)
-                    .and_modify(|t| {
-                        t.add_assign(time_spent);
-                    })
-                    .or_insert(time_spent);
             }
-        }
-
-        #[cfg(feature = "logging")]
-        log::info!("Atom matches: {}", atom_matches);
+        };
 
-        Ok(())
+        #[cfg(feature = "rules-profiling")]
+        {
+            let time_spent = self
+                .clock
+                .delta_as_nanos(verification_start, self.clock.raw());
+
+            self.time_spent_in_pattern
+                .entry(*pattern_id)
+                .and_modify(|t| {
+                    t.add_assign(time_spent);
+                })
+                .or_insert(time_spent);
+        }
     }
 
     /// Search for patterns in the scanned data.
@@ -1012,8 +1420,12 @@ impl ScanContext<'_, '_> {
     /// called only once.
     ///
     /// In case of timeout, this function returns [ScanError::Timeout] and sets
-    /// the scan state to [ScanState::Timeout].
+    /// the scan state to [ScanState::Timeout]. If the memory limit is exceeded
+    /// it returns [ScanError::MemLimit].
     pub(crate) fn search_for_patterns(&mut self) -> Result<(), ScanError> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("search_for_patterns").entered();
+
         // Take ownership of the scan state, while searching for
         // the patterns, `self.scan_state` is left as `Idle`.
         let state = self.scan_state.take();
@@ -1034,6 +1446,7 @@ impl ScanContext<'_, '_> {
         let result = match self.ac_search_loop(base, data, block_scanning_mode)
         {
             Ok(_) => {
+                self.pattern_matches.remove_overlapping();
                 self.scan_state = state;
                 Ok(())
             }
@@ -1041,6 +1454,10 @@ impl ScanContext<'_, '_> {
                 self.scan_state = ScanState::Timeout;
                 Err(ScanError::Timeout)
             }
+            Err(err @ (ScanError::MemLimit | ScanError::Cancelled)) => {
+                self.scan_state = state;
+                Err(err)
+            }
             _ => unreachable!(),
         };
 
@@ -1073,6 +1490,77 @@ impl ScanContext<'_, '_> {
         result
     }
 
+    /// Searches for the patterns of several scan contexts with a single
+    /// pass of an Aho-Corasick automaton over the scanned data.
+    ///
+    /// `ac` must be built from the atoms of all the contexts, in the same
+    /// order as `ctxs`, and `first_atoms` contains the index within `ac` of
+    /// the first atom of each context. All the contexts must be scanning
+    /// `data`. Unlike [`ScanContext::search_for_patterns`], this is not
+    /// done lazily, it must be called before evaluating the conditions, and
+    /// marks the pattern search phase as done in every context.
+    pub(crate) fn search_for_patterns_in_all<'r>(
+        ctxs: &mut [&mut ScanContext<'r, '_>],
+        ac: &AhoCorasick,
+        first_atoms: &[usize],
+        data: &[u8],
+    ) -> Result<(), ScanError> {
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::info_span!("search_for_patterns_in_all").entered();
+
+        let mut vms: Vec<VM<'r>> = ctxs
+            .iter()
+            .map(|ctx| VM {
+                pike_vm: PikeVM::new(ctx.compiled_rules.re_code()),
+                fast_vm: FastVM::new(ctx.compiled_rules.re_code()),
+            })
+            .collect();
+
+        let filesize = data.len() as i64;
+
+        for ctx in ctxs.iter_mut() {
+            ctx.verify_anchored_patterns(0, data);
+        }
+
+        for ac_match in ac.find_overlapping_iter(data) {
+            let atom_index = ac_match.pattern().as_usize();
+
+            // Find the context the atom belongs to. Contexts without atoms
+            // have the same first atom as the next one, `partition_point`
+            // skips them.
+            let i =
+                first_atoms.partition_point(|first| *first <= atom_index) - 1;
+
+            let ctx = &mut ctxs[i];
+
+            ctx.check_scan_limits()?;
+
+            let atom = unsafe {
+                ctx.compiled_rules
+                    .atoms()
+                    .get_unchecked(atom_index - first_atoms[i])
+            };
+
+            ctx.verify_atom_match(
+                &mut vms[i],
+                0,
+                data,
+                atom,
+                ac_match.start(),
+                filesize,
+                false,
+            );
+        }
+
+        for ctx in ctxs.iter_mut() {
+            ctx.pattern_matches.remove_overlapping();
+            ctx.set_pattern_search_done(true);
+        }
+
+        Ok(())
+    }
+
     fn verify_anchored_patterns(&mut self, base: usize, data: &[u8]) {
         for (sub_pattern_id, (pattern_id, sub_pattern)) in self
             .compiled_rules
@@ -1080,6 +1568,9 @@ impl ScanContext<'_, '_> {
             .iter()
             .map(|id| (id, self.compiled_rules.get_sub_pattern(*id)))
         {
+            if self.disabled_patterns.contains(pattern_id) {
+                continue;
+            }
             match sub_pattern {
                 SubPattern::Literal {
                     pattern,
@@ -1150,7 +1641,8 @@ impl ScanContext<'_, '_> {
                     .push(UnconfirmedMatch {
                         range: match_.range,
                         chain_length: 0,
-                    })
+                    });
+                self.track_mem_usage(mem::size_of::<UnconfirmedMatch>());
             }
             SubPattern::LiteralChainTail {
                 chained_to, gap, flags, ..
@@ -1182,6 +1674,9 @@ impl ScanContext<'_, '_> {
                                 range: match_.range,
                                 chain_length: 0,
                             });
+                        self.track_mem_usage(
+                            mem::size_of::<UnconfirmedMatch>(),
+                        );
                     }
                 }
             }
@@ -1789,9 +2284,13 @@ pub fn create_wasm_store_and_ctx<'r>(
 
     let ctx = ScanContext {
         runtime_objects: IndexMap::new(),
+        runtime_strings: FxHashMap::default(),
+        max_runtime_objects: ScanContext::DEFAULT_MAX_RUNTIME_OBJECTS,
+        mem_usage: 0,
+        peak_mem_usage: 0,
+        max_mem_usage: None,
         compiled_rules: rules,
         console_log: None,
-        current_struct: None,
         scan_timeout: None,
         scan_state: ScanState::Idle,
         root_struct: rules.globals().make_root(),
@@ -1805,13 +2304,22 @@ pub fn create_wasm_store_and_ctx<'r>(
         wasm_main_func: None,
         wasm_filesize: None,
         wasm_pattern_search_done: None,
+        wasm_max_loop_iterations: None,
         module_outputs: FxHashMap::default(),
         user_provided_module_outputs: FxHashMap::default(),
         pattern_matches: PatternMatches::new(),
         unconfirmed_matches: FxHashMap::default(),
         deadline: 0,
+        cancelled: Arc::new(AtomicBool::new(false)),
         limit_reached: FxHashSet::default(),
-        regexp_cache: RefCell::new(FxHashMap::default()),
+        disabled_rules: FxHashSet::default(),
+        disabled_patterns: FxHashSet::default(),
+        max_loop_iterations: None,
+        loop_limit_reached: Vec::new(),
+        computed_fields: FxHashMap::default(),
+        field_lookup_cache: FxHashMap::default(),
+        module_notices: Vec::new(),
+        user_data: None,
         #[cfg(feature = "rules-profiling")]
         time_spent_in_pattern: FxHashMap::default(),
         #[cfg(feature = "rules-profiling")]
@@ -1839,9 +2347,12 @@ pub fn create_wasm_store_and_ctx<'r>(
     // This is safe to do because the Store only lives for the time that
     // the scanner lives, and 'r is the lifetime for the rules passed to
     // the scanner, which are guaranteed to outlive the scanner.
-    let mut wasm_store = Box::pin(Store::new(wasm::get_engine(), unsafe {
-        transmute::<ScanContext<'r, '_>, ScanContext<'static, 'static>>(ctx)
-    }));
+    let mut wasm_store =
+        Box::pin(Store::new(wasm::get_engine_for(rules.backend()), unsafe {
+            transmute::<ScanContext<'r, '_>, ScanContext<'static, 'static>>(
+                ctx,
+            )
+        }));
 
     // Initialize the ScanContext.wasm_store pointer that was initially
     // dangling.
@@ -1867,6 +2378,17 @@ pub fn create_wasm_store_and_ctx<'r>(
     )
     .unwrap();
 
+    // Global variable that holds the maximum number of iterations for loops
+    // over ranges whose bounds are not known at compile time. This is
+    // initialized to -1 (which means no limit), so that the WASM code
+    // doesn't call `loop_iterations` unless a limit is set.
+    let max_loop_iterations = Global::new(
+        wasm_store.as_context_mut(),
+        GlobalType::new(ValType::I64, Mutability::Var),
+        Val::I64(-1),
+    )
+    .unwrap();
+
     // Compute the base offset for the bitmap that contains matching
     // information for patterns. This bitmap has 1 bit per pattern, the
     // N-th bit is set if pattern with PatternId = N matched. The bitmap
@@ -1875,9 +2397,16 @@ pub fn create_wasm_store_and_ctx<'r>(
     let matching_patterns_bitmap_base =
         MATCHING_RULES_BITMAP_BASE as u32 + num_rules.div_ceil(8);
 
+    // Compute the base offset for the bitmap that tells which rules must
+    // be skipped during the evaluation. This bitmap has 1 bit per rule, and
+    // starts right after the bitmap that contains matching information for
+    // patterns.
+    let skipped_rules_bitmap_base =
+        matching_patterns_bitmap_base + num_patterns.div_ceil(8);
+
     // Compute the required memory size in 64KB pages.
     let mem_size = u32::div_ceil(
-        matching_patterns_bitmap_base + num_patterns.div_ceil(8),
+        skipped_rules_bitmap_base + num_rules.div_ceil(8),
         65536,
     );
 
@@ -1888,6 +2417,13 @@ pub fn create_wasm_store_and_ctx<'r>(
     )
     .unwrap();
 
+    let skipped_rules_bitmap_base = Global::new(
+        wasm_store.as_context_mut(),
+        GlobalType::new(ValType::I32, Mutability::Const),
+        Val::I32(skipped_rules_bitmap_base as i32),
+    )
+    .unwrap();
+
     // Create module's main memory.
     let main_memory = wasmtime::Memory::new(
         wasm_store.as_context_mut(),
@@ -1898,7 +2434,7 @@ pub fn create_wasm_store_and_ctx<'r>(
     // Instantiate the module. This takes the wasm code provided by the
     // `wasm_mod` function and links its imported functions with the
     // implementations that YARA provides.
-    let wasm_instance = wasm::new_linker()
+    let wasm_instance = wasm::new_linker(rules.backend())
         .define(wasm_store.as_context(), "yara_x", "filesize", filesize)
         .unwrap()
         .define(
@@ -1908,6 +2444,13 @@ pub fn create_wasm_store_and_ctx<'r>(
             pattern_search_done,
         )
         .unwrap()
+        .define(
+            wasm_store.as_context(),
+            "yara_x",
+            "max_loop_iterations",
+            max_loop_iterations,
+        )
+        .unwrap()
         .define(
             wasm_store.as_context(),
             "yara_x",
@@ -1915,6 +2458,13 @@ pub fn create_wasm_store_and_ctx<'r>(
             matching_patterns_bitmap_base,
         )
         .unwrap()
+        .define(
+            wasm_store.as_context(),
+            "yara_x",
+            "skipped_rules_bitmap_base",
+            skipped_rules_bitmap_base,
+        )
+        .unwrap()
         .define(wasm_store.as_context(), "yara_x", "main_memory", main_memory)
         .unwrap()
         .instantiate(wasm_store.as_context_mut(), rules.wasm_mod())
@@ -1932,6 +2482,7 @@ pub fn create_wasm_store_and_ctx<'r>(
     ctx.wasm_main_func = Some(main_fn);
     ctx.wasm_filesize = Some(filesize);
     ctx.wasm_pattern_search_done = Some(pattern_search_done);
+    ctx.wasm_max_loop_iterations = Some(max_loop_iterations);
 
     wasm_store
 }
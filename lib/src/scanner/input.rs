@@ -0,0 +1,180 @@
+/*! Data sources that can be scanned in blocks.
+
+This module defines the [`ScanInput`] trait, which abstracts the source of
+the scanned data, together with implementations for local files
+([`FileInput`]), arbitrary readers ([`ReaderInput`]) and remote objects that
+support ranged reads, like HTTP resources or objects stored in S3
+([`RangedInput`]).
+
+Inputs are scanned with [`crate::blocks::Scanner::scan_input`], which reads
+the data in blocks and scans them as they arrive, without loading the whole
+input in memory.
+*/
+use std::fs::File;
+use std::io;
+use std::io::{Read, Seek, SeekFrom};
+use std::ops::Range;
+use std::path::Path;
+
+/// A source of data that can be scanned in blocks.
+pub trait ScanInput {
+    /// Reads data starting at `offset` into `buf`.
+    ///
+    /// Returns the number of bytes read, which can be less than the length
+    /// of `buf`. A return value of 0 indicates that there is no more data.
+    ///
+    /// The scanner reads the input sequentially, each call starts at the
+    /// offset where the previous one ended.
+    fn read_at(&mut self, offset: u64, buf: &mut [u8]) -> io::Result<usize>;
+}
+
+/// Input that reads data from a local file.
+pub struct FileInput {
+    file: File,
+}
+
+impl FileInput {
+    /// Opens the file at the given path.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(Self { file: File::open(path)? })
+    }
+}
+
+impl From<File> for FileInput {
+    fn from(file: File) -> Self {
+        Self { file }
+    }
+}
+
+impl ScanInput for FileInput {
+    fn read_at(&mut self, offset: u64, buf: &mut [u8]) -> io::Result<usize> {
+        self.file.seek(SeekFrom::Start(offset))?;
+        self.file.read(buf)
+    }
+}
+
+/// Input that reads data from any type that implements [`Read`].
+///
+/// Readers can't seek, so this input only supports sequential reads.
+pub struct ReaderInput<R: Read> {
+    reader: R,
+    pos: u64,
+}
+
+impl<R: Read> ReaderInput<R> {
+    /// Creates a new input that reads data from `reader`.
+    pub fn new(reader: R) -> Self {
+        Self { reader, pos: 0 }
+    }
+}
+
+impl<R: Read> ScanInput for ReaderInput<R> {
+    fn read_at(&mut self, offset: u64, buf: &mut [u8]) -> io::Result<usize> {
+        if offset != self.pos {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "reader input can only be read sequentially",
+            ));
+        }
+        let n = self.reader.read(buf)?;
+        self.pos += n as u64;
+        Ok(n)
+    }
+}
+
+/// Input that reads data from a remote object with ranged requests.
+///
+/// The data is obtained by calling a user-provided function that receives
+/// the range of bytes to fetch, and returns them. This allows scanning
+/// any object that supports ranged reads, like HTTP resources (with the
+/// `Range: bytes=<start>-<end - 1>` header) or objects stored in S3 (with
+/// the `Range` parameter of `GetObject`), using whatever client is more
+/// convenient.
+///
+/// The function can return fewer bytes than requested, but never more. When
+/// the range starts beyond the end of the object, it must return an empty
+/// vector. If the size of the object is known in advance, it can be set
+/// with [`RangedInput::with_size`], which avoids requesting data past the
+/// end of the object.
+///
+/// This type doesn't implement any transport by itself, sending the
+/// requests to the server, handling authentication and retrying failed
+/// requests is left to the `fetch` function.
+///
+/// # Example
+///
+/// ```
+/// # use yara_x::{blocks, compile};
+/// # use yara_x::input::RangedInput;
+/// let object = b"some data stored in a remote server";
+///
+/// let input = RangedInput::new(|range: std::ops::Range<u64>| {
+///     // A real implementation would send a ranged request here.
+///     Ok(object[range.start as usize..range.end as usize].to_vec())
+/// })
+/// .with_size(object.len() as u64);
+///
+/// let rules = compile(r#"rule test { strings: $a = "remote" condition: $a }"#).unwrap();
+/// let mut scanner = blocks::Scanner::new(&rules);
+/// let results = scanner.scan_input(input).unwrap();
+///
+/// assert_eq!(results.matching_rules().len(), 1);
+/// ```
+pub struct RangedInput<F>
+where
+    F: FnMut(Range<u64>) -> io::Result<Vec<u8>>,
+{
+    fetch: F,
+    size: Option<u64>,
+}
+
+impl<F> RangedInput<F>
+where
+    F: FnMut(Range<u64>) -> io::Result<Vec<u8>>,
+{
+    /// Creates a new input that obtains the data by calling `fetch`.
+    pub fn new(fetch: F) -> Self {
+        Self { fetch, size: None }
+    }
+
+    /// Sets the size of the remote object.
+    pub fn with_size(mut self, size: u64) -> Self {
+        self.size = Some(size);
+        self
+    }
+}
+
+impl<F> ScanInput for RangedInput<F>
+where
+    F: FnMut(Range<u64>) -> io::Result<Vec<u8>>,
+{
+    fn read_at(&mut self, offset: u64, buf: &mut [u8]) -> io::Result<usize> {
+        let mut end = offset.saturating_add(buf.len() as u64);
+
+        if let Some(size) = self.size {
+            end = end.min(size);
+        }
+
+        if offset >= end {
+            return Ok(0);
+        }
+
+        let data = (self.fetch)(offset..end)?;
+        let requested = (end - offset) as usize;
+
+        if data.len() > requested {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "requested {} bytes, but received {}",
+                    requested,
+                    data.len()
+                ),
+            ));
+        }
+
+        buf[..data.len()].copy_from_slice(&data);
+
+        Ok(data.len())
+    }
+}
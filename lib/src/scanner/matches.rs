@@ -1,10 +1,13 @@
+use std::cmp::Reverse;
 use std::collections::hash_map::Entry;
+use std::collections::BTreeMap;
 use std::ops::{Add, Range, RangeInclusive, Sub};
 
 use core::slice::Iter;
 use rustc_hash::FxHashMap;
 
 use crate::compiler::PatternId;
+use crate::scanner::MatchOverlapPolicy;
 
 /// Represents the match of a pattern.
 #[derive(Debug, Clone)]
@@ -174,6 +177,51 @@ impl MatchList {
         self.matches.iter()
     }
 
+    /// Removes overlapping matches from the list according to the given
+    /// [`MatchOverlapPolicy`].
+    pub fn remove_overlapping(&mut self, policy: MatchOverlapPolicy) {
+        match policy {
+            MatchOverlapPolicy::All => {}
+            MatchOverlapPolicy::NonOverlapping => {
+                let mut end = 0;
+                self.matches.retain(|m| {
+                    if m.range.start >= end {
+                        end = m.range.end;
+                        true
+                    } else {
+                        false
+                    }
+                });
+            }
+            MatchOverlapPolicy::Greedy => {
+                let mut order: Vec<usize> = (0..self.matches.len()).collect();
+
+                // Sorting is stable, matches with the same length remain
+                // sorted by starting offset.
+                order.sort_by_key(|i| Reverse(self.matches[*i].range.len()));
+
+                // Maps the start of each accepted match to its end. Accepted
+                // matches never overlap, so the only one that can overlap
+                // with a given range is the one with the highest start that
+                // is lower than the range's end.
+                let mut accepted = BTreeMap::new();
+
+                for i in order {
+                    let range = &self.matches[i].range;
+                    let overlaps = accepted
+                        .range(..range.end)
+                        .next_back()
+                        .is_some_and(|(_, end)| *end > range.start);
+                    if !overlaps {
+                        accepted.insert(range.start, range.end);
+                    }
+                }
+
+                self.matches.retain(|m| accepted.contains_key(&m.range.start));
+            }
+        }
+    }
+
     /// Searches for a match that starts at the given offset.
     ///
     /// If a match starting at `offset` is found, then [`Ok`] is returned
@@ -219,6 +267,7 @@ pub struct UnconfirmedMatch {
 pub(crate) struct PatternMatches {
     matches: FxHashMap<PatternId, MatchList>,
     max_matches_per_pattern: usize,
+    overlap_policy: MatchOverlapPolicy,
     capacity: usize,
 }
 
@@ -230,6 +279,7 @@ impl PatternMatches {
         Self {
             matches: FxHashMap::default(),
             max_matches_per_pattern: Self::DEFAULT_MAX_MATCHES_PER_PATTERN,
+            overlap_policy: MatchOverlapPolicy::default(),
             capacity: 0,
         }
     }
@@ -242,6 +292,26 @@ impl PatternMatches {
         self
     }
 
+    /// Sets the policy for overlapping matches.
+    ///
+    /// The policy is not applied while matches are being added, but when
+    /// [`PatternMatches::remove_overlapping`] is called.
+    pub fn overlap_policy(&mut self, policy: MatchOverlapPolicy) -> &mut Self {
+        self.overlap_policy = policy;
+        self
+    }
+
+    /// Removes overlapping matches from all patterns according to the
+    /// policy set with [`PatternMatches::overlap_policy`].
+    pub fn remove_overlapping(&mut self) {
+        if self.overlap_policy == MatchOverlapPolicy::All {
+            return;
+        }
+        for matches in self.matches.values_mut() {
+            matches.remove_overlapping(self.overlap_policy);
+        }
+    }
+
     /// Returns the list of matches for a given pattern.
     pub fn get(&self, pattern_id: PatternId) -> Option<&MatchList> {
         self.matches.get(&pattern_id)
@@ -325,6 +395,7 @@ impl PatternMatches {
 #[cfg(test)]
 mod test {
     use crate::scanner::matches::{Match, MatchList};
+    use crate::scanner::MatchOverlapPolicy;
     use std::ops::Range;
 
     #[test]
@@ -343,4 +414,33 @@ mod test {
             vec![(1..15), (2..10), (3..10), (4..10), (5..10)]
         )
     }
+
+    #[test]
+    fn remove_overlapping() {
+        let matches = || {
+            let mut ml = MatchList::with_capacity(5);
+            ml.add(Match::new(0..2), false);
+            ml.add(Match::new(1..6), false);
+            ml.add(Match::new(2..4), false);
+            ml.add(Match::new(4..6), false);
+            ml.add(Match::new(6..7), false);
+            ml
+        };
+
+        let ranges = |ml: MatchList| {
+            ml.iter().map(|m| m.range.clone()).collect::<Vec<Range<usize>>>()
+        };
+
+        let mut ml = matches();
+        ml.remove_overlapping(MatchOverlapPolicy::All);
+        assert_eq!(ranges(ml), vec![(0..2), (1..6), (2..4), (4..6), (6..7)]);
+
+        let mut ml = matches();
+        ml.remove_overlapping(MatchOverlapPolicy::NonOverlapping);
+        assert_eq!(ranges(ml), vec![(0..2), (2..4), (4..6), (6..7)]);
+
+        let mut ml = matches();
+        ml.remove_overlapping(MatchOverlapPolicy::Greedy);
+        assert_eq!(ranges(ml), vec![(1..6), (6..7)]);
+    }
 }
@@ -2,7 +2,7 @@
 
 The scanner takes the rules produces by the compiler and scans data with them.
 */
-use std::collections::{hash_map, BTreeMap, HashMap};
+use std::collections::{btree_map, BTreeMap, HashMap};
 use std::fmt::{Debug, Formatter};
 use std::fs;
 use std::io::Read;
@@ -10,9 +10,10 @@ use std::mem::transmute;
 use std::ops::Range;
 use std::path::{Path, PathBuf};
 use std::pin::Pin;
+use std::rc::Rc;
 use std::slice::Iter;
-use std::sync::atomic::AtomicU64;
-use std::sync::Once;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Once};
 use std::time::Duration;
 
 use bitvec::prelude::*;
@@ -22,13 +23,13 @@ use thiserror::Error;
 use wasmtime::Store;
 
 use crate::compiler::{RuleId, Rules};
-use crate::models::Rule;
+use crate::models::{Pattern, Rule};
 use crate::modules::{Module, ModuleError, BUILTIN_MODULES};
 use crate::scanner::context::create_wasm_store_and_ctx;
 use crate::types::{Struct, TypeValue};
 use crate::variables::VariableError;
 use crate::wasm::MATCHING_RULES_BITMAP_BASE;
-use crate::{modules, Variable};
+use crate::{modules, wasm, Variable};
 
 pub(crate) use crate::scanner::context::RuntimeObject;
 pub(crate) use crate::scanner::context::RuntimeObjectHandle;
@@ -40,6 +41,8 @@ mod context;
 mod matches;
 
 pub mod blocks;
+pub mod input;
+pub mod multi;
 
 #[cfg(test)]
 mod tests;
@@ -51,6 +54,13 @@ pub enum ScanError {
     /// The scan was aborted after the timeout period.
     #[error("timeout")]
     Timeout,
+    /// The scan was aborted with [`CancellationToken::cancel`].
+    #[error("scan cancelled")]
+    Cancelled,
+    /// The scan was aborted because it exceeded the memory limit set with
+    /// [`Scanner::max_memory_usage`].
+    #[error("memory limit exceeded")]
+    MemLimit,
     /// Could not open the scanned file.
     #[error("can not open `{path}`: {err}")]
     OpenError {
@@ -59,6 +69,12 @@ pub enum ScanError {
         /// Error that occurred.
         err: std::io::Error,
     },
+    /// Could not read data from a [`input::ScanInput`].
+    #[error("can not read input: {err}")]
+    InputError {
+        /// Error that occurred.
+        err: std::io::Error,
+    },
     /// Could not map the scanned file into memory.
     #[error("can not map `{path}`: {err}")]
     MapError {
@@ -81,6 +97,14 @@ pub enum ScanError {
         /// Module name.
         module: String,
     },
+    /// The rule is unknown.
+    #[error("unknown rule `{rule}` in namespace `{namespace}`")]
+    UnknownRule {
+        /// Namespace name.
+        namespace: String,
+        /// Rule identifier.
+        rule: String,
+    },
     /// Some module produced an error when it was invoked.
     #[error("error in module `{module}`: {err}")]
     ModuleError {
@@ -89,6 +113,16 @@ pub enum ScanError {
         /// Error that occurred.
         err: ModuleError,
     },
+    /// The scanned data is larger than the limit set with
+    /// [`Scanner::max_file_size`], and the policy is
+    /// [`OversizedDataPolicy::Skip`].
+    #[error("data is too large ({size} bytes, maximum is {max_size} bytes)")]
+    DataTooLarge {
+        /// Size of the data.
+        size: u64,
+        /// Maximum size allowed.
+        max_size: u64,
+    },
 }
 
 /// Global counter that gets incremented every 1 second by a dedicated thread.
@@ -99,14 +133,46 @@ static HEARTBEAT_COUNTER: AtomicU64 = AtomicU64::new(0);
 /// Used for spawning the thread that increments `HEARTBEAT_COUNTER`.
 static INIT_HEARTBEAT: Once = Once::new();
 
+/// A handle that allows aborting a scan from another thread.
+///
+/// Cancellation tokens are obtained with [`Scanner::cancellation_token`].
+/// Once [`CancellationToken::cancel`] is called, the scan in progress, and
+/// any subsequent scan performed with the same token, fails with
+/// [`ScanError::Cancelled`]. Cancellation is cooperative: the scanner checks
+/// the token periodically while searching for patterns, and the evaluation
+/// of rule conditions is interrupted as soon as the cancellation is
+/// requested.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Requests the cancellation of the scan.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+        // Incrementing the epoch forces the WASM runtime to invoke the epoch
+        // deadline callback, which aborts the evaluation of the conditions.
+        wasm::increment_epoch();
+    }
+
+    /// Returns true if [`CancellationToken::cancel`] has been called.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
 /// Represents the data being scanned.
 ///
 /// The scanned data can be backed by a slice owned by someone else, or a
-/// vector or memory-mapped file owned by `ScannedData` itself.
+/// vector or memory-mapped file owned by `ScannedData` itself. The data can
+/// also be shared by several scanners that scan it at the same time, like
+/// the ones used by [`multi::Scanner`].
 pub enum ScannedData<'d> {
     Slice(&'d [u8]),
     Vec(Vec<u8>),
     Mmap(Mmap),
+    Shared(Rc<ScannedData<'d>>),
 }
 
 impl AsRef<[u8]> for ScannedData<'_> {
@@ -115,6 +181,7 @@ impl AsRef<[u8]> for ScannedData<'_> {
             ScannedData::Slice(s) => s,
             ScannedData::Vec(v) => v.as_ref(),
             ScannedData::Mmap(m) => m.as_ref(),
+            ScannedData::Shared(s) => s.as_ref().as_ref(),
         }
     }
 }
@@ -172,6 +239,49 @@ impl<'a> ScanOptions<'a> {
     }
 }
 
+/// Determines what the scanner does with data larger than the limit set
+/// with [`Scanner::max_file_size`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OversizedDataPolicy {
+    /// The data is not scanned at all, scan functions return
+    /// [`ScanError::DataTooLarge`].
+    #[default]
+    Skip,
+    /// Only the first bytes of the data, up to the size limit, are scanned.
+    /// The `filesize` keyword returns the size of the scanned portion, and
+    /// [`ScanResults::is_truncated`] returns `true`.
+    Truncate,
+}
+
+/// Determines how the scanner handles overlapping matches of the same
+/// pattern.
+///
+/// Two matches of the same pattern never start at the same offset, but they
+/// can overlap. For instance, pattern `"aa"` matches `"aaaa"` at offsets 0,
+/// 1 and 2. The policy is applied once the pattern search phase finishes,
+/// therefore it affects both the matches reported in [`ScanResults`] and the
+/// values of `#a`, `@a[i]` and `!a[i]` seen by rule conditions. The policy
+/// never removes all the matches of a pattern that matched.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MatchOverlapPolicy {
+    /// All matches are reported, including the overlapping ones.
+    #[default]
+    All,
+    /// Matches are visited in ascending order of starting offset, and any
+    /// match that starts before the end of the previously reported match is
+    /// discarded. For pattern `"aa"` and data `"aaaa"` this reports the
+    /// matches at offsets 0 and 2.
+    NonOverlapping,
+    /// Longer matches are preferred over shorter ones. Matches are visited
+    /// in descending order of length (ties are resolved in favour of the
+    /// lowest starting offset), and any match that overlaps with a previously
+    /// reported match is discarded. For pattern `/ab|bcde/` and data
+    /// `"abcde"` this reports only the match at offset 1, while
+    /// [`MatchOverlapPolicy::NonOverlapping`] reports only the match at
+    /// offset 0.
+    Greedy,
+}
+
 /// Scans data with already compiled YARA rules.
 ///
 /// The scanner receives a set of compiled [`Rules`] and scans data with those
@@ -182,13 +292,29 @@ pub struct Scanner<'r> {
     _rules: &'r Rules,
     wasm_store: Pin<Box<Store<ScanContext<'static, 'static>>>>,
     use_mmap: bool,
+    max_file_size: Option<u64>,
+    oversized_data_policy: OversizedDataPolicy,
+    module_timeout: Option<Duration>,
+    module_max_output_size: Option<usize>,
+    /// Number of threads started by this scanner for running main functions
+    /// that didn't finish before `module_timeout` and are still running.
+    abandoned_threads: Arc<AtomicUsize>,
 }
 
 impl<'r> Scanner<'r> {
     /// Creates a new scanner.
     pub fn new(rules: &'r Rules) -> Self {
         let wasm_store = create_wasm_store_and_ctx(rules);
-        Self { _rules: rules, wasm_store, use_mmap: true }
+        Self {
+            _rules: rules,
+            wasm_store,
+            use_mmap: true,
+            max_file_size: None,
+            oversized_data_policy: OversizedDataPolicy::default(),
+            module_timeout: None,
+            module_max_output_size: None,
+            abandoned_threads: Arc::new(AtomicUsize::new(0)),
+        }
     }
 
     /// Sets a timeout for scan operations.
@@ -204,15 +330,162 @@ impl<'r> Scanner<'r> {
         self
     }
 
+    /// Returns a token that can be used for cancelling scans from another
+    /// thread.
+    ///
+    /// Each call to this function returns a new token, which replaces the
+    /// one returned by a previous call. This means that once a token is
+    /// cancelled, a fresh one must be obtained before the next scan.
+    ///
+    /// ```
+    /// # use yara_x::{Compiler, ScanError, Scanner};
+    /// let rules = yara_x::compile("rule test { condition: true }").unwrap();
+    /// let mut scanner = Scanner::new(&rules);
+    /// let token = scanner.cancellation_token();
+    ///
+    /// // The token can be sent to another thread.
+    /// std::thread::spawn(move || token.cancel()).join().unwrap();
+    ///
+    /// assert!(matches!(scanner.scan(b""), Err(ScanError::Cancelled)));
+    /// ```
+    pub fn cancellation_token(&mut self) -> CancellationToken {
+        let token = CancellationToken::default();
+        self.scan_context_mut().cancelled = token.cancelled.clone();
+        token
+    }
+
     /// Sets the maximum number of matches per pattern.
     ///
-    /// When some pattern reaches the maximum number of patterns it won't
-    /// produce more matches.
+    /// When some pattern reaches the maximum number of matches it won't
+    /// produce more matches, and [`crate::Pattern::limit_reached`] returns
+    /// `true` for it. The default limit is 1,000,000 matches.
     pub fn max_matches_per_pattern(&mut self, n: usize) -> &mut Self {
         self.scan_context_mut().pattern_matches.max_matches_per_pattern(n);
         self
     }
 
+    /// Sets the policy for overlapping matches of the same pattern.
+    ///
+    /// The default policy is [`MatchOverlapPolicy::All`]. Notice that the
+    /// limit set with [`Scanner::max_matches_per_pattern`] is applied before
+    /// the policy, while matches are being found.
+    pub fn match_overlap_policy(
+        &mut self,
+        policy: MatchOverlapPolicy,
+    ) -> &mut Self {
+        self.scan_context_mut().pattern_matches.overlap_policy(policy);
+        self
+    }
+
+    /// Sets the maximum number of iterations for `for` loops.
+    ///
+    /// The limit applies to `for` loops whose number of iterations is known
+    /// only at scan time: loops over a range whose bounds are not known at
+    /// compile time, like `for any i in (0..filesize)`, and loops over
+    /// arrays and maps, like `for any section in pe.sections`. Such loops
+    /// iterate at most `n` times, skipping the rest of the range, array or
+    /// map, and [`ScanResults::loop_limit_reached`] returns the rules that
+    /// contain them. Loops with a number of iterations fixed at compile
+    /// time, like loops over a set of patterns, a tuple of expressions or
+    /// a range with constant bounds, are not limited. By default there's no
+    /// limit.
+    pub fn max_loop_iterations(&mut self, n: u64) -> &mut Self {
+        self.scan_context_mut().set_max_loop_iterations(n);
+        self
+    }
+
+    /// Sets the maximum number of runtime objects retained between scans.
+    ///
+    /// While evaluating rule conditions, the scanner creates runtime objects,
+    /// like the strings returned by module fields and functions. These
+    /// objects are freed at the start of every scan, but the memory used
+    /// for tracking them is kept for reuse, up to the given number of
+    /// objects. Identical strings produced during a scan are stored only
+    /// once. The default is 4096.
+    pub fn max_runtime_objects(&mut self, n: usize) -> &mut Self {
+        self.scan_context_mut().max_runtime_objects = n;
+        self
+    }
+
+    /// Sets the maximum amount of memory in bytes that a scan can use.
+    ///
+    /// The limit applies to the memory allocated while scanning, like the
+    /// memory used for storing pattern matches, the strings produced while
+    /// evaluating rule conditions, and the outputs of YARA modules. The
+    /// memory used by the scanned data itself and by the compiled rules is
+    /// not included. When the limit is exceeded the scan is aborted with
+    /// [`ScanError::MemLimit`]. By default there's no limit.
+    ///
+    /// The amount of memory used is an estimate, see
+    /// [`ScanResults::peak_memory_usage`].
+    pub fn max_memory_usage(&mut self, bytes: usize) -> &mut Self {
+        self.scan_context_mut().max_mem_usage = Some(bytes);
+        self
+    }
+
+    /// Sets the maximum size in bytes of the data that will be scanned.
+    ///
+    /// What happens with data larger than this limit is determined by
+    /// [`Scanner::oversized_data_policy`]. By default, such data is not
+    /// scanned. The limit applies both to files and in-memory data. Files
+    /// larger than the limit are never read beyond the limit.
+    pub fn max_file_size(&mut self, size: u64) -> &mut Self {
+        self.max_file_size = Some(size);
+        self
+    }
+
+    /// Sets the policy for data larger than the limit set with
+    /// [`Scanner::max_file_size`].
+    ///
+    /// The default policy is [`OversizedDataPolicy::Skip`].
+    pub fn oversized_data_policy(
+        &mut self,
+        policy: OversizedDataPolicy,
+    ) -> &mut Self {
+        self.oversized_data_policy = policy;
+        self
+    }
+
+    /// Sets the maximum time that the main function of each YARA module can
+    /// spend processing the scanned data.
+    ///
+    /// When a timeout is set, the main functions run in a separate thread,
+    /// and the scanned data is copied for sharing it with that thread. If
+    /// some of them doesn't finish in time, the scan continues as if the
+    /// module didn't produce any output, and a [`ModuleNotice`] with code
+    /// `timeout` is added to the scan results.
+    ///
+    /// Threads can't be stopped, so a main function that times out keeps
+    /// running in the background until it returns, holding its copy of the
+    /// data. To bound the resources used by such threads, while 8 of the
+    /// threads started by this scanner are still running, main functions
+    /// are not invoked at all and a [`ModuleNotice`] with code `skipped` is
+    /// reported instead. The `cuckoo` module is not affected by the
+    /// timeout, as it only parses the metadata passed with
+    /// [`ScanOptions::set_module_metadata`]. By default there's no timeout.
+    pub fn module_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.module_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the maximum size in bytes of the output produced by the main
+    /// function of each YARA module.
+    ///
+    /// The size is the one of the protobuf message returned by the main
+    /// function, once serialized. If it exceeds the limit, the scan continues
+    /// as if the module didn't produce any output, and a [`ModuleNotice`]
+    /// with code `output_size_limit` is added to the scan results. By
+    /// default there's no limit.
+    ///
+    /// This is not a limit on the memory used by the main function. The size
+    /// is checked after the main function returns, so it limits the data
+    /// retained from the output during the scan, but not the memory that the
+    /// main function allocates while parsing the data.
+    pub fn module_max_output_size(&mut self, bytes: usize) -> &mut Self {
+        self.module_max_output_size = Some(bytes);
+        self
+    }
+
     /// Specifies whether [`Scanner::scan_file`] and [`Scanner::scan_file_with_options`]
     /// may use memory-mapped files to read input.
     ///
@@ -244,12 +517,99 @@ impl<'r> Scanner<'r> {
         self
     }
 
+    /// Associates arbitrary data of type `T` with the scanner.
+    ///
+    /// The data can be retrieved with [`ScanResults::user_data`] after
+    /// each scan, which allows correlating the results with some object
+    /// owned by the user (e.g. the job that requested the scan). The data
+    /// is kept until it is replaced by another call to this function.
+    pub fn set_user_data<T: 'static>(&mut self, data: T) -> &mut Self {
+        self.scan_context_mut().user_data = Some(Box::new(data));
+        self
+    }
+
+    /// Restricts the set of rules evaluated by the scanner.
+    ///
+    /// The `filter` function is called once for every rule, and only the
+    /// rules for which it returns `true` are evaluated in subsequent scans.
+    /// The rest of the rules are skipped and reported as non-matching. A
+    /// skipped global rule doesn't prevent other rules in its namespace from
+    /// matching.
+    ///
+    /// The filter is applied immediately, and stays in effect until this
+    /// function is called again. Use `|_| true` for evaluating all rules
+    /// again.
+    ///
+    /// ```
+    /// # use yara_x;
+    /// let rules = yara_x::compile(r#"
+    ///     rule foo : bar { condition: true }
+    ///     rule baz { condition: true }
+    /// "#).unwrap();
+    ///
+    /// let mut scanner = yara_x::Scanner::new(&rules);
+    ///
+    /// scanner.set_rule_filter(|rule| {
+    ///     rule.tags().any(|tag| tag.identifier() == "bar")
+    /// });
+    ///
+    /// let results = scanner.scan(b"").unwrap();
+    ///
+    /// assert_eq!(results.matching_rules().len(), 1);
+    /// ```
+    pub fn set_rule_filter<F>(&mut self, mut filter: F) -> &mut Self
+    where
+        F: FnMut(&Rule) -> bool,
+    {
+        let ctx = self.scan_context_mut();
+        let rules = ctx.compiled_rules;
+        for (rule_id, rule) in rules.iter().enumerate() {
+            let rule_id = RuleId::from(rule_id);
+            let skipped =
+                !filter(&rule) || ctx.disabled_rules.contains(&rule_id);
+            ctx.set_rule_skipped(rule_id, skipped);
+        }
+        self
+    }
+
+    /// Disables the rule with identifier `ident` in namespace `namespace`.
+    ///
+    /// Disabled rules are not evaluated in subsequent scans, and they are
+    /// reported as non-matching. Other rules that depend on a disabled rule
+    /// see it as false. Patterns that are used only by disabled rules are
+    /// excluded from the pattern search phase. This allows disabling rules
+    /// that produce false positives without having to recompile the rules.
+    ///
+    /// Once disabled, a rule can't be enabled again, not even with
+    /// [`Scanner::set_rule_filter`]. Returns [`ScanError::UnknownRule`] if
+    /// the rule doesn't exist.
+    pub fn disable_rule(
+        &mut self,
+        namespace: &str,
+        ident: &str,
+    ) -> Result<&mut Self, ScanError> {
+        let ctx = self.scan_context_mut();
+        let rule_id = ctx
+            .compiled_rules
+            .iter()
+            .position(|rule| {
+                rule.namespace() == namespace && rule.identifier() == ident
+            })
+            .ok_or_else(|| ScanError::UnknownRule {
+                namespace: namespace.to_string(),
+                rule: ident.to_string(),
+            })?;
+        ctx.disable_rule(RuleId::from(rule_id));
+        Ok(self)
+    }
+
     /// Scans in-memory data.
     pub fn scan<'a>(
         &'a mut self,
         data: &'a [u8],
     ) -> Result<ScanResults<'a, 'r>, ScanError> {
-        self.scan_impl(data.try_into()?, None)
+        let (data, truncated) = self.limit_data(data)?;
+        self.scan_impl(data, truncated, None)
     }
 
     /// Scans a file.
@@ -260,7 +620,8 @@ impl<'r> Scanner<'r> {
     where
         P: AsRef<Path>,
     {
-        self.scan_impl(self.load_file(target.as_ref())?, None)
+        let (data, truncated) = self.load_file(target.as_ref())?;
+        self.scan_impl(data, truncated, None)
     }
 
     /// Like [`Scanner::scan`], but allows to specify additional scan options.
@@ -269,7 +630,8 @@ impl<'r> Scanner<'r> {
         data: &'a [u8],
         options: ScanOptions<'opts>,
     ) -> Result<ScanResults<'a, 'r>, ScanError> {
-        self.scan_impl(ScannedData::Slice(data), Some(options))
+        let (data, truncated) = self.limit_data(data)?;
+        self.scan_impl(data, truncated, Some(options))
     }
 
     /// Like [`Scanner::scan_file`], but allows to specify additional scan
@@ -282,7 +644,8 @@ impl<'r> Scanner<'r> {
     where
         P: AsRef<Path>,
     {
-        self.scan_impl(self.load_file(target.as_ref())?, Some(options))
+        let (data, truncated) = self.load_file(target.as_ref())?;
+        self.scan_impl(data, truncated, Some(options))
     }
 
     /// Sets the value of a global variable.
@@ -432,7 +795,7 @@ impl<'r> Scanner<'r> {
 }
 
 impl<'r> Scanner<'r> {
-    #[cfg(feature = "rules-profiling")]
+    #[cfg(any(feature = "rules-profiling", test))]
     #[inline]
     fn scan_context<'a>(&self) -> &ScanContext<'r, 'a> {
         unsafe {
@@ -451,15 +814,48 @@ impl<'r> Scanner<'r> {
         }
     }
 
+    /// Returns the size of the data that must be scanned, given the total
+    /// size of the data, and whether the data must be truncated to that size.
+    ///
+    /// Returns an error if the data is larger than the limit set with
+    /// [`Scanner::max_file_size`] and the policy is to skip it.
+    fn size_to_scan(&self, size: u64) -> Result<(u64, bool), ScanError> {
+        match self.max_file_size {
+            Some(max_size) if size > max_size => {
+                match self.oversized_data_policy {
+                    OversizedDataPolicy::Skip => {
+                        Err(ScanError::DataTooLarge { size, max_size })
+                    }
+                    OversizedDataPolicy::Truncate => Ok((max_size, true)),
+                }
+            }
+            _ => Ok((size, false)),
+        }
+    }
+
+    /// Applies the size limit set with [`Scanner::max_file_size`] to
+    /// in-memory data.
+    fn limit_data<'a>(
+        &self,
+        data: &'a [u8],
+    ) -> Result<(ScannedData<'a>, bool), ScanError> {
+        let (size, truncated) = self.size_to_scan(data.len() as u64)?;
+        Ok((ScannedData::Slice(&data[..size as usize]), truncated))
+    }
+
+    /// Loads the file that will be scanned. The returned bool indicates
+    /// whether the file was truncated because it exceeds the limit set with
+    /// [`Scanner::max_file_size`].
     fn load_file<'a>(
         &self,
         path: &Path,
-    ) -> Result<ScannedData<'a>, ScanError> {
-        let mut file = fs::File::open(path).map_err(|err| {
+    ) -> Result<(ScannedData<'a>, bool), ScanError> {
+        let file = fs::File::open(path).map_err(|err| {
             ScanError::OpenError { path: path.to_path_buf(), err }
         })?;
 
         let size = file.metadata().map(|m| m.len()).unwrap_or(0);
+        let (size, truncated) = self.size_to_scan(size)?;
 
         let mut buffered_file;
         let mapped_file;
@@ -467,39 +863,73 @@ impl<'r> Scanner<'r> {
         // For files smaller than ~500MB reading the whole file is faster than
         // using a memory-mapped file.
         let data = if self.use_mmap && size > 500_000_000 {
+            let mut options = MmapOptions::new();
+            if truncated {
+                options.len(size as usize);
+            }
             mapped_file = unsafe {
-                MmapOptions::new().map_copy_read_only(&file).map_err(|err| {
+                options.map_copy_read_only(&file).map_err(|err| {
                     ScanError::MapError { path: path.to_path_buf(), err }
                 })
             }?;
             ScannedData::Mmap(mapped_file)
         } else {
             buffered_file = Vec::with_capacity(size as usize);
-            file.read_to_end(&mut buffered_file).map_err(|err| {
-                ScanError::OpenError { path: path.to_path_buf(), err }
-            })?;
+            // If there's a size limit, never read beyond it, even if the file
+            // grew after obtaining its size.
+            let limit = self.max_file_size.unwrap_or(u64::MAX);
+            file.take(limit).read_to_end(&mut buffered_file).map_err(
+                |err| ScanError::OpenError { path: path.to_path_buf(), err },
+            )?;
             ScannedData::Vec(buffered_file)
         };
 
-        Ok(data)
+        Ok((data, truncated))
     }
 
     fn scan_impl<'a, 'opts>(
         &'a mut self,
         data: ScannedData<'a>,
+        truncated: bool,
         options: Option<ScanOptions<'opts>>,
     ) -> Result<ScanResults<'a, 'r>, ScanError> {
+        self.start_scan(data, options)?;
+        self.finish_scan(truncated)
+    }
+
+    /// Prepares the scanner for scanning the given data, and invokes the
+    /// main functions of the modules imported by the rules.
+    ///
+    /// After this the scan is completed with [`Scanner::finish_scan`].
+    fn start_scan<'a, 'opts>(
+        &mut self,
+        data: ScannedData<'a>,
+        options: Option<ScanOptions<'opts>>,
+    ) -> Result<(), ScanError> {
+        let module_timeout = self.module_timeout;
+        let module_max_output_size = self.module_max_output_size;
+        let abandoned_threads = self.abandoned_threads.clone();
+
         let ctx = self.scan_context_mut();
 
         // Clear information about matches found in a previous scan, if any.
         ctx.reset();
 
+        if ctx.cancelled() {
+            return Err(ScanError::Cancelled);
+        }
+
         // Set the global variable `filesize` to the size of the scanned data.
         ctx.set_filesize(data.as_ref().len() as i64);
 
         // Indicate that the scanner is currently scanning the given data.
         ctx.scan_state = ScanState::ScanningData(data);
 
+        // Copy of the scanned data shared with the threads where the
+        // modules' main functions run when `module_timeout` is set. It's
+        // created only when needed.
+        let mut shared_data: Option<Arc<[u8]>> = None;
+
         for module_name in ctx.compiled_rules.imports() {
             // Lookup the module in the list of built-in modules.
             let module = modules::BUILTIN_MODULES
@@ -524,14 +954,71 @@ impl<'r> Scanner<'r> {
                     });
 
                 if let Some(main_fn) = module.main_fn {
-                    module_output = Some(
-                        main_fn(ctx.scanned_data().unwrap(), meta).map_err(
-                            |err| ScanError::ModuleError {
-                                module: module_name.to_string(),
-                                err,
-                            },
-                        )?,
-                    );
+                    #[cfg(feature = "tracing")]
+                    let _span = tracing::info_span!(
+                        "module_main_fn",
+                        module = module_name
+                    )
+                    .entered();
+
+                    let timeout = module_timeout
+                        .filter(|_| !module.main_fn_in_scan_thread);
+
+                    let (output, notices) = if let Some(timeout) = timeout {
+                        let data = shared_data
+                            .get_or_insert_with(|| {
+                                Arc::from(ctx.scanned_data().unwrap())
+                            })
+                            .clone();
+
+                        match modules::invoke_main_fn_with_timeout(
+                            main_fn,
+                            data,
+                            meta.map(|meta| meta.to_vec()),
+                            timeout,
+                            &abandoned_threads,
+                        ) {
+                            Ok((output, notices)) => (Some(output), notices),
+                            Err(notice) => (None, vec![notice]),
+                        }
+                    } else {
+                        let output =
+                            main_fn(ctx.scanned_data().unwrap(), meta);
+                        (Some(output), modules::take_notices())
+                    };
+
+                    ctx.module_notices.extend(notices.into_iter().map(
+                        |(code, message)| ModuleNotice {
+                            module: module_name.to_string(),
+                            code,
+                            message,
+                        },
+                    ));
+
+                    module_output = output
+                        .transpose()
+                        .map_err(|err| ScanError::ModuleError {
+                            module: module_name.to_string(),
+                            err,
+                        })?
+                        .filter(|output| {
+                            // Discard the output if it's larger than the
+                            // limit set with `Scanner::module_max_output_size`.
+                            let Some(max) = module_max_output_size else {
+                                return true;
+                            };
+                            let size = output.compute_size_dyn() as usize;
+                            if size > max {
+                                ctx.module_notices.push(ModuleNotice {
+                                    module: module_name.to_string(),
+                                    code: "output_size_limit",
+                                    message: format!(
+                                        "output size is {size} bytes, maximum is {max} bytes"
+                                    ),
+                                });
+                            }
+                            size <= max
+                        });
                 } else {
                     module_output = None;
                 }
@@ -582,15 +1069,24 @@ impl<'r> Scanner<'r> {
             );
 
             if let Some(module_output) = module_output {
+                ctx.track_mem_usage(module_output.compute_size_dyn() as usize);
                 ctx.module_outputs
                     .insert(root_struct_name.to_string(), module_output);
             }
 
+            if ctx.mem_limit_reached() {
+                return Err(ScanError::MemLimit);
+            }
+
             // The data structure obtained from the module is added to the
             // root structure. Any data from previous scans will be replaced
             // with the new data structure.
             ctx.root_struct
                 .add_field(module_name, TypeValue::Struct(module_struct));
+
+            // The cached values of computed fields could refer to the
+            // structure that was just replaced.
+            ctx.invalidate_computed_fields();
         }
 
         // The user provided module outputs are not needed anymore. Let's
@@ -602,6 +1098,17 @@ impl<'r> Scanner<'r> {
         // Clear the flag that indicates that the search phase was done.
         ctx.set_pattern_search_done(false);
 
+        Ok(())
+    }
+
+    /// Evaluates the conditions for the data passed to
+    /// [`Scanner::start_scan`], and returns the results.
+    fn finish_scan<'a>(
+        &'a mut self,
+        truncated: bool,
+    ) -> Result<ScanResults<'a, 'r>, ScanError> {
+        let ctx = self.scan_context_mut();
+
         // Evaluate the conditions of every rule, this will call
         // `ScanContext::search_for_patterns` if necessary.
         ctx.eval_conditions()?;
@@ -613,7 +1120,7 @@ impl<'r> Scanner<'r> {
 
         ctx.scan_state = ScanState::Finished(DataSnippets::SingleBlock(data));
 
-        Ok(ScanResults::new(ctx))
+        Ok(ScanResults::new(ctx, truncated))
     }
 }
 
@@ -663,11 +1170,41 @@ impl DataSnippets<'_> {
     }
 }
 
+/// A notice emitted by a YARA module about some recoverable anomaly found
+/// in the scanned data.
+///
+/// See [`ScanResults::module_notices`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModuleNotice {
+    module: String,
+    code: &'static str,
+    message: String,
+}
+
+impl ModuleNotice {
+    /// Name of the module that emitted the notice (e.g. `pe`).
+    pub fn module(&self) -> &str {
+        self.module.as_str()
+    }
+
+    /// Short identifier for the kind of anomaly (e.g.
+    /// `truncated_directory`).
+    pub fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// Human-readable description of the anomaly.
+    pub fn message(&self) -> &str {
+        self.message.as_str()
+    }
+}
+
 /// Results of a scan operation.
 ///
 /// Allows iterating over both the matching and non-matching rules.
 pub struct ScanResults<'a, 'r> {
     ctx: &'a ScanContext<'r, 'a>,
+    truncated: bool,
 }
 
 impl Debug for ScanResults<'_, '_> {
@@ -677,8 +1214,53 @@ impl Debug for ScanResults<'_, '_> {
 }
 
 impl<'a, 'r> ScanResults<'a, 'r> {
-    fn new(ctx: &'a ScanContext<'r, 'a>) -> Self {
-        Self { ctx }
+    fn new(ctx: &'a ScanContext<'r, 'a>, truncated: bool) -> Self {
+        Self { ctx, truncated }
+    }
+
+    /// Returns true if only part of the data was scanned, because it was
+    /// larger than the limit set with [`Scanner::max_file_size`] and the
+    /// policy is [`OversizedDataPolicy::Truncate`].
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Returns the data that was scanned.
+    ///
+    /// When the data was truncated (see [`ScanResults::is_truncated`]) only
+    /// the part that was actually scanned is returned. Returns `None` for
+    /// the results produced by [`blocks::Scanner`], as the blocks are not
+    /// kept in memory after being scanned.
+    pub fn data(&self) -> Option<&[u8]> {
+        match &self.ctx.scan_state {
+            ScanState::Finished(DataSnippets::SingleBlock(data)) => {
+                Some(data.as_ref())
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the peak amount of memory in bytes used during the scan.
+    ///
+    /// This is an estimate of the memory allocated for storing pattern
+    /// matches, the strings produced while evaluating rule conditions, and
+    /// the outputs of YARA modules. It's the value compared against the
+    /// limit set with [`Scanner::max_memory_usage`].
+    pub fn peak_memory_usage(&self) -> usize {
+        self.ctx.peak_mem_usage
+    }
+
+    /// Returns the total number of pattern matches found during the scan.
+    ///
+    /// This includes the matches of every pattern, including patterns
+    /// declared in rules that didn't match, and private patterns. Patterns
+    /// shared by multiple rules are counted once.
+    pub fn num_pattern_matches(&self) -> usize {
+        self.ctx
+            .pattern_matches
+            .matches_per_pattern()
+            .map(|(_, match_list)| match_list.len())
+            .sum()
     }
 
     /// Returns an iterator that yields the matching rules in arbitrary order.
@@ -692,6 +1274,55 @@ impl<'a, 'r> ScanResults<'a, 'r> {
         NonMatchingRules::new(self.ctx)
     }
 
+    /// Returns the rules containing some loop that reached the limit set
+    /// with [`Scanner::max_loop_iterations`].
+    ///
+    /// The loops that reached the limit didn't iterate over their whole
+    /// range, so the result of these rules may differ from the result
+    /// they would have without the limit. The rules can be either matching
+    /// or non-matching ones.
+    pub fn loop_limit_reached(
+        &self,
+    ) -> impl ExactSizeIterator<Item = Rule<'a, 'r>> + 'a {
+        let ctx = self.ctx;
+        let rules = ctx.compiled_rules;
+        ctx.loop_limit_reached.iter().map(move |rule_id| Rule {
+            ctx: Some(ctx),
+            rule_info: rules.get(*rule_id),
+            rules,
+        })
+    }
+
+    /// Returns an iterator that yields the patterns that matched during the
+    /// scan, together with the rule that defines them.
+    ///
+    /// Contrary to the patterns returned by [`Rule::patterns`] for matching
+    /// rules, this includes the patterns of rules that didn't match, and
+    /// private patterns, which is useful for finding out which patterns
+    /// match in a corpus of files. Patterns are searched only if the
+    /// condition of some rule needs them, if all conditions were resolved
+    /// without searching for patterns, the iterator yields nothing. Rules
+    /// and patterns are yielded in the same order as in [`Rules::iter`]
+    /// and [`Rule::patterns`].
+    pub fn matching_patterns(
+        &self,
+    ) -> impl Iterator<Item = (Rule<'a, 'r>, Pattern<'a, 'r>)> + 'a {
+        let ctx = self.ctx;
+        let rules = ctx.compiled_rules;
+        let rule_infos =
+            if ctx.pattern_matches.is_empty() { &[] } else { rules.rules() };
+
+        rule_infos.iter().flat_map(move |rule_info| {
+            let rule = Rule { ctx: Some(ctx), rule_info, rules };
+            rule.patterns()
+                .include_private(true)
+                .filter(|pattern| pattern.matches().len() > 0)
+                .map(move |pattern| {
+                    (Rule { ctx: Some(ctx), rule_info, rules }, pattern)
+                })
+        })
+    }
+
     /// Returns the protobuf produced by a YARA module after processing the
     /// data.
     ///
@@ -710,6 +1341,40 @@ impl<'a, 'r> ScanResults<'a, 'r> {
         Some(module_output)
     }
 
+    /// Returns the notices emitted by YARA modules while processing the
+    /// scanned data.
+    ///
+    /// Modules emit notices when they find recoverable anomalies in the
+    /// data, like truncated or corrupt structures. Notices are yielded in
+    /// the order in which they were emitted.
+    pub fn module_notices(
+        &self,
+    ) -> impl ExactSizeIterator<Item = &'a ModuleNotice> + 'a {
+        self.ctx.module_notices.iter()
+    }
+
+    /// Returns a reference to the data set with [`Scanner::set_user_data`].
+    ///
+    /// The result is `None` if no data was set, or if the data is not of
+    /// type `T`.
+    pub fn user_data<T: 'static>(&self) -> Option<&'a T> {
+        self.ctx.user_data()
+    }
+
+    /// Returns the scan results as a protobuf message.
+    ///
+    /// The message contains the matching rules and the structures produced
+    /// by the modules, as returned by [`ModuleOutputs::into_proto`]. This is
+    /// useful for transporting the results in a compact binary form.
+    pub fn to_proto(&self) -> crate::proto::ScanResults {
+        let mut results = crate::proto::ScanResults::new();
+        results.matching_rules =
+            self.matching_rules().map(|rule| rule.to_proto()).collect();
+        results.modules =
+            protobuf::MessageField::some(self.module_outputs().into_proto());
+        results
+    }
+
     /// Returns an iterator that yields tuples composed of a YARA module name
     /// and the protobuf produced by that module.
     ///
@@ -717,6 +1382,16 @@ impl<'a, 'r> ScanResults<'a, 'r> {
     pub fn module_outputs(&self) -> ModuleOutputs<'a, 'r> {
         ModuleOutputs::new(self.ctx)
     }
+
+    /// Returns profiling data for the slowest N rules.
+    ///
+    /// This is equivalent to [`Scanner::slowest_rules`]. The data is
+    /// cumulative across all the scans performed by the scanner since it
+    /// was created, or since [`Scanner::clear_profiling_data`] was called.
+    #[cfg(feature = "rules-profiling")]
+    pub fn slowest_rules(&self, n: usize) -> Vec<ProfilingData<'a>> {
+        self.ctx.slowest_rules(n)
+    }
 }
 
 /// Iterator that yields the rules that matched during a scan.
@@ -877,7 +1552,7 @@ impl ExactSizeIterator for NonMatchingRules<'_, '_> {
 pub struct ModuleOutputs<'a, 'r> {
     ctx: &'a ScanContext<'r, 'a>,
     len: usize,
-    iterator: hash_map::Iter<'a, &'a str, Module>,
+    iterator: btree_map::Iter<'a, &'a str, Module>,
 }
 
 impl<'a, 'r> ModuleOutputs<'a, 'r> {
@@ -890,6 +1565,40 @@ impl<'a, 'r> ModuleOutputs<'a, 'r> {
     }
 }
 
+impl ModuleOutputs<'_, '_> {
+    /// Returns the module outputs as a [`crate::mods::Modules`] message.
+    ///
+    /// Modules that don't have a corresponding field in that message are
+    /// not included.
+    pub fn into_proto(self) -> modules::protos::mods::Modules {
+        use protobuf::reflect::{
+            ReflectValueBox, RuntimeFieldType, RuntimeType,
+        };
+        use protobuf::MessageFull;
+
+        let mut modules = modules::protos::mods::Modules::new();
+        let descriptor = modules::protos::mods::Modules::descriptor();
+
+        for (name, output) in self {
+            let Some(field) = descriptor.field_by_name(name) else {
+                continue;
+            };
+            if let RuntimeFieldType::Singular(RuntimeType::Message(m)) =
+                field.runtime_field_type()
+            {
+                if m == output.descriptor_dyn() {
+                    field.set_singular_field(
+                        &mut modules,
+                        ReflectValueBox::Message(output.clone_box()),
+                    );
+                }
+            }
+        }
+
+        modules
+    }
+}
+
 impl ExactSizeIterator for ModuleOutputs<'_, '_> {
     #[inline]
     fn len(&self) -> usize {
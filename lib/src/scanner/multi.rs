@@ -0,0 +1,402 @@
+/*! Scanner for scanning data with multiple sets of rules.
+
+This scanner is intended for scenarios where rules are compiled and
+distributed as separate bundles, but each file must be scanned with all of
+them. The patterns of all the rule sets are searched for with a single pass
+over the data.
+
+This module also provides [`RuleSet`], a collection of independently
+compiled rule sets, one per namespace. Namespaces can be added, replaced or
+removed without recompiling the rest of them.
+*/
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::rc::Rc;
+use std::time::Duration;
+
+use aho_corasick::AhoCorasick;
+
+use crate::errors::{CompileError, VariableError};
+use crate::scanner::{ScanContext, ScanState, ScannedData};
+use crate::{
+    Compiler, OversizedDataPolicy, Rules, ScanError, ScanResults, SourceCode,
+    Variable,
+};
+
+/// Scans data with multiple sets of rules.
+///
+/// Each set of [`Rules`] is compiled independently, and produces its own
+/// [`ScanResults`], which are the same that a [`crate::Scanner`] would
+/// produce for it. The results are returned in the same order in which the
+/// rules were passed to [`Scanner::new`].
+///
+/// The scanner builds an Aho-Corasick automaton with the atoms extracted
+/// from the patterns of all the rule sets, so the data is read only once,
+/// no matter the number of rule sets. The matches found by the automaton are
+/// verified by the rule set they belong to. Then the conditions of each rule
+/// set are evaluated. Unlike [`crate::Scanner`], which searches for patterns
+/// only when some condition needs them, this scanner always searches for
+/// them before evaluating the conditions. The main functions of the modules
+/// are invoked once per rule set that imports them.
+///
+/// # Examples
+///
+/// ```
+/// # use yara_x::{compile, multi};
+///
+/// let rules_1 = compile(r#"rule foo { strings: $a = "foo" condition: $a }"#).unwrap();
+/// let rules_2 = compile(r#"rule bar { strings: $a = "bar" condition: $a }"#).unwrap();
+///
+/// let mut scanner = multi::Scanner::new([&rules_1, &rules_2]);
+/// let results = scanner.scan(b"foobar").unwrap();
+///
+/// assert_eq!(results.len(), 2);
+/// assert_eq!(results[0].matching_rules().len(), 1);
+/// assert_eq!(results[1].matching_rules().len(), 1);
+/// ```
+pub struct Scanner<'r> {
+    scanners: Vec<crate::Scanner<'r>>,
+    /// Aho-Corasick automaton with the atoms of all the rule sets.
+    ac: AhoCorasick,
+    /// Index within `ac` of the first atom of each rule set.
+    first_atoms: Vec<usize>,
+}
+
+impl<'r> Scanner<'r> {
+    /// Creates a new scanner for the given sets of rules.
+    pub fn new<I>(rules: I) -> Self
+    where
+        I: IntoIterator<Item = &'r Rules>,
+    {
+        let rules: Vec<&'r Rules> = rules.into_iter().collect();
+        let mut first_atoms = Vec::with_capacity(rules.len());
+        let mut num_atoms = 0;
+
+        for rules in rules.iter() {
+            first_atoms.push(num_atoms);
+            num_atoms += rules.atoms().len();
+        }
+
+        let ac = AhoCorasick::new(rules.iter().flat_map(|rules| {
+            rules.atoms().iter().map(|atom| atom.as_slice())
+        }))
+        .expect("failed to build Aho-Corasick automaton");
+
+        Self {
+            scanners: rules.into_iter().map(crate::Scanner::new).collect(),
+            ac,
+            first_atoms,
+        }
+    }
+
+    /// Sets a timeout for scan operations.
+    ///
+    /// The timeout applies to the whole scan, including all the sets of
+    /// rules. See [`crate::Scanner::set_timeout`].
+    pub fn set_timeout(&mut self, timeout: Duration) -> &mut Self {
+        for scanner in self.scanners.iter_mut() {
+            scanner.set_timeout(timeout);
+        }
+        self
+    }
+
+    /// Sets the maximum number of matches per pattern.
+    ///
+    /// See [`crate::Scanner::max_matches_per_pattern`].
+    pub fn max_matches_per_pattern(&mut self, n: usize) -> &mut Self {
+        for scanner in self.scanners.iter_mut() {
+            scanner.max_matches_per_pattern(n);
+        }
+        self
+    }
+
+    /// Sets the maximum number of iterations for `for` loops.
+    ///
+    /// See [`crate::Scanner::max_loop_iterations`].
+    pub fn max_loop_iterations(&mut self, n: u64) -> &mut Self {
+        for scanner in self.scanners.iter_mut() {
+            scanner.max_loop_iterations(n);
+        }
+        self
+    }
+
+    /// Sets the maximum size in bytes of the data that will be scanned.
+    ///
+    /// See [`crate::Scanner::max_file_size`].
+    pub fn max_file_size(&mut self, size: u64) -> &mut Self {
+        for scanner in self.scanners.iter_mut() {
+            scanner.max_file_size(size);
+        }
+        self
+    }
+
+    /// Sets the policy for data larger than the limit set with
+    /// [`Scanner::max_file_size`].
+    ///
+    /// See [`crate::Scanner::oversized_data_policy`].
+    pub fn oversized_data_policy(
+        &mut self,
+        policy: OversizedDataPolicy,
+    ) -> &mut Self {
+        for scanner in self.scanners.iter_mut() {
+            scanner.oversized_data_policy(policy);
+        }
+        self
+    }
+
+    /// Specifies whether [`Scanner::scan_file`] may use memory-mapped files
+    /// to read input.
+    ///
+    /// See [`crate::Scanner::use_mmap`].
+    pub fn use_mmap(&mut self, yes: bool) -> &mut Self {
+        for scanner in self.scanners.iter_mut() {
+            scanner.use_mmap(yes);
+        }
+        self
+    }
+
+    /// Sets a callback that is invoked every time a YARA rule calls the
+    /// `console` module.
+    ///
+    /// Each set of rules receives its own clone of `callback`.
+    pub fn console_log<F>(&mut self, callback: F) -> &mut Self
+    where
+        F: FnMut(String) + Clone + 'r,
+    {
+        for scanner in self.scanners.iter_mut() {
+            scanner.console_log(callback.clone());
+        }
+        self
+    }
+
+    /// Sets the value of a global variable.
+    ///
+    /// The value is set in every set of rules that defines the variable,
+    /// and ignored in the rest. Returns [`VariableError::Undefined`] if none
+    /// of them defines it.
+    pub fn set_global<T: TryInto<Variable> + Clone>(
+        &mut self,
+        ident: &str,
+        value: T,
+    ) -> Result<&mut Self, VariableError>
+    where
+        VariableError: From<<T as TryInto<Variable>>::Error>,
+    {
+        let mut defined = false;
+
+        for scanner in self.scanners.iter_mut() {
+            match scanner.set_global(ident, value.clone()) {
+                Ok(_) => defined = true,
+                Err(VariableError::Undefined(_)) => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        if !defined {
+            return Err(VariableError::Undefined(ident.to_string()));
+        }
+
+        Ok(self)
+    }
+
+    /// Scans in-memory data.
+    ///
+    /// Returns the results for each set of rules.
+    pub fn scan<'a>(
+        &'a mut self,
+        data: &'a [u8],
+    ) -> Result<Vec<ScanResults<'a, 'r>>, ScanError> {
+        let Some(first) = self.scanners.first() else {
+            return Ok(Vec::new());
+        };
+        // All the scanners have the same settings, the size limit is
+        // applied by the first one.
+        let (data, truncated) = first.limit_data(data)?;
+        self.scan_impl(data, truncated)
+    }
+
+    /// Scans a file.
+    ///
+    /// Returns the results for each set of rules.
+    pub fn scan_file<'a, P>(
+        &'a mut self,
+        target: P,
+    ) -> Result<Vec<ScanResults<'a, 'r>>, ScanError>
+    where
+        P: AsRef<Path>,
+    {
+        let Some(first) = self.scanners.first() else {
+            return Ok(Vec::new());
+        };
+        let (data, truncated) = first.load_file(target.as_ref())?;
+        self.scan_impl(data, truncated)
+    }
+
+    fn scan_impl<'a>(
+        &'a mut self,
+        data: ScannedData<'a>,
+        truncated: bool,
+    ) -> Result<Vec<ScanResults<'a, 'r>>, ScanError> {
+        if let Err(err) = self.search_for_patterns(data) {
+            // Release the data held by the scanners that started the scan.
+            for scanner in self.scanners.iter_mut() {
+                scanner.scan_context_mut().scan_state = ScanState::Idle;
+            }
+            return Err(err);
+        }
+
+        self.scanners
+            .iter_mut()
+            .map(|scanner| scanner.finish_scan(truncated))
+            .collect()
+    }
+
+    /// Starts the scan in all the scanners, and searches for the patterns
+    /// of all of them with a single pass over the data.
+    fn search_for_patterns(
+        &mut self,
+        data: ScannedData<'_>,
+    ) -> Result<(), ScanError> {
+        // The data is shared by all the scanners, and it's released when
+        // the last of them starts a new scan, like with `crate::Scanner`.
+        let data = Rc::new(data);
+
+        for scanner in self.scanners.iter_mut() {
+            scanner.start_scan(ScannedData::Shared(data.clone()), None)?;
+        }
+
+        let mut ctxs: Vec<&mut ScanContext> = self
+            .scanners
+            .iter_mut()
+            .map(|scanner| scanner.scan_context_mut())
+            .collect();
+
+        ScanContext::search_for_patterns_in_all(
+            ctxs.as_mut_slice(),
+            &self.ac,
+            &self.first_atoms,
+            data.as_ref().as_ref(),
+        )
+    }
+}
+
+/// A collection of independently compiled rule sets, one per namespace.
+///
+/// Compiling a large set of rules takes time, and with a single [`Rules`]
+/// any change, no matter how small, requires compiling all of them again.
+/// With [`RuleSet`] each namespace is compiled by its own [`Compiler`] into
+/// its own [`Rules`], so adding, replacing or removing a namespace only
+/// requires compiling that namespace. The whole collection is scanned with
+/// a [`Scanner`] returned by [`RuleSet::scanner`], which produces separate
+/// [`ScanResults`] for each namespace.
+///
+/// This is not incremental compilation of a single [`Rules`]: a namespace
+/// is always compiled as a whole, and namespaces don't share anything.
+/// In particular:
+///
+/// - Rules in one namespace can't use rules from other namespaces, and
+///   global rules only affect the rules in their own namespace.
+/// - Modules are imported by each namespace separately, and their main
+///   functions are invoked once per namespace that imports them.
+/// - Global variables must be defined in the [`Compiler`] used for each
+///   namespace that needs them, see [`RuleSet::insert`].
+///
+/// # Examples
+///
+/// ```
+/// # use yara_x::multi::RuleSet;
+///
+/// let mut rule_set = RuleSet::new();
+///
+/// rule_set
+///     .add_source("foo", r#"rule foo { strings: $a = "foo" condition: $a }"#)
+///     .unwrap()
+///     .add_source("bar", r#"rule bar { strings: $a = "bar" condition: $a }"#)
+///     .unwrap();
+///
+/// // Replace the `bar` namespace, `foo` is not recompiled.
+/// rule_set
+///     .add_source("bar", r#"rule bar { strings: $a = "baz" condition: $a }"#)
+///     .unwrap();
+///
+/// let mut scanner = rule_set.scanner();
+/// let results = scanner.scan(b"foobar").unwrap();
+///
+/// assert_eq!(results[0].matching_rules().len(), 0);
+/// assert_eq!(results[1].matching_rules().len(), 1);
+/// ```
+#[derive(Default)]
+pub struct RuleSet {
+    namespaces: BTreeMap<String, Rules>,
+}
+
+impl RuleSet {
+    /// Creates a new, empty, [`RuleSet`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compiles `src` and puts the resulting rules in the given namespace.
+    ///
+    /// If the namespace already exists, it's replaced with the new rules.
+    /// If the source code contains errors, the namespace is left untouched.
+    pub fn add_source<'src, S>(
+        &mut self,
+        namespace: &str,
+        src: S,
+    ) -> Result<&mut Self, CompileError>
+    where
+        S: Into<SourceCode<'src>>,
+    {
+        let mut compiler = Compiler::new();
+        compiler.new_namespace(namespace);
+        compiler.add_source(src)?;
+        self.insert(namespace, compiler.build());
+        Ok(self)
+    }
+
+    /// Puts already compiled rules in the given namespace.
+    ///
+    /// This allows using a [`Compiler`] with custom settings, like global
+    /// variables or features. The compiled rules don't need to be in the
+    /// namespace with the same name, `namespace` is used only for
+    /// identifying them in the [`RuleSet`]. Returns the rules previously
+    /// in the namespace, if any.
+    pub fn insert(&mut self, namespace: &str, rules: Rules) -> Option<Rules> {
+        self.namespaces.insert(namespace.to_string(), rules)
+    }
+
+    /// Removes a namespace, returning its rules.
+    pub fn remove(&mut self, namespace: &str) -> Option<Rules> {
+        self.namespaces.remove(namespace)
+    }
+
+    /// Returns the rules in a namespace.
+    pub fn get(&self, namespace: &str) -> Option<&Rules> {
+        self.namespaces.get(namespace)
+    }
+
+    /// Returns an iterator over the namespaces and their rules, sorted by
+    /// namespace.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&str, &Rules)> {
+        self.namespaces.iter().map(|(ns, rules)| (ns.as_str(), rules))
+    }
+
+    /// Returns the number of namespaces.
+    pub fn len(&self) -> usize {
+        self.namespaces.len()
+    }
+
+    /// Returns `true` if there are no namespaces.
+    pub fn is_empty(&self) -> bool {
+        self.namespaces.is_empty()
+    }
+
+    /// Creates a [`Scanner`] for all the namespaces in the set.
+    ///
+    /// The scanner returns the results for each namespace in the same order
+    /// as [`RuleSet::iter`].
+    pub fn scanner(&self) -> Scanner<'_> {
+        Scanner::new(self.namespaces.values())
+    }
+}
@@ -1,14 +1,18 @@
+use std::ops::Range;
+
 use pretty_assertions::assert_eq;
 use protobuf::MessageDyn;
 use protobuf::{Message, MessageFull};
 use serde_json::json;
 
 use crate::models::MetaValue;
+use crate::scanner::RuntimeObject;
 use crate::variables::VariableError;
 use crate::Scanner;
-use crate::{mods, ScanOptions};
+use crate::{
+    mods, MatchOverlapPolicy, OversizedDataPolicy, ScanError, ScanOptions,
+};
 
-#[cfg(feature = "rules-profiling")]
 use std::time::Duration;
 
 #[test]
@@ -225,6 +229,211 @@ fn reuse_scanner() {
     );
 }
 
+#[test]
+fn user_data() {
+    let rules = crate::compile(r#"rule test { condition: true }"#).unwrap();
+    let mut scanner = Scanner::new(&rules);
+
+    assert_eq!(scanner.scan(b"").unwrap().user_data::<u32>(), None);
+
+    scanner.set_user_data(1234_u32);
+
+    let scan_results = scanner.scan(b"").unwrap();
+
+    assert_eq!(scan_results.user_data::<u32>(), Some(&1234));
+    assert_eq!(scan_results.user_data::<String>(), None);
+
+    scanner.set_user_data(String::from("job"));
+
+    assert_eq!(
+        scanner.scan(b"").unwrap().user_data::<String>().map(String::as_str),
+        Some("job")
+    );
+}
+
+#[cfg(feature = "test_proto2-module")]
+#[test]
+fn module_notices() {
+    let rules = crate::compile(
+        r#"
+        import "test_proto2"
+        rule test { condition: true }
+        "#,
+    )
+    .unwrap();
+
+    let mut scanner = Scanner::new(&rules);
+    let scan_results = scanner.scan(b"").expect("scan should not fail");
+    let notices: Vec<_> = scan_results.module_notices().collect();
+
+    assert_eq!(notices.len(), 1);
+    assert_eq!(notices[0].module(), "test_proto2");
+    assert_eq!(notices[0].code(), "empty_data");
+    assert_eq!(notices[0].message(), "the scanned data is empty");
+
+    // Notices from a previous scan are not kept.
+    let scan_results = scanner.scan(b"foo").expect("scan should not fail");
+    assert_eq!(scan_results.module_notices().len(), 0);
+}
+
+#[cfg(feature = "test_proto2-module")]
+#[test]
+fn module_budget() {
+    let rules = crate::compile(
+        r#"
+        import "test_proto2"
+        rule test { condition: defined test_proto2.int32_one }
+        "#,
+    )
+    .unwrap();
+
+    let mut scanner = Scanner::new(&rules);
+
+    scanner.module_timeout(Duration::from_secs(60));
+
+    // The module finishes in time, and the notices emitted by it in a
+    // separate thread are reported.
+    let scan_results = scanner.scan(b"").unwrap();
+    let notices: Vec<_> = scan_results.module_notices().collect();
+
+    assert_eq!(scan_results.matching_rules().len(), 1);
+    assert_eq!(notices.len(), 1);
+    assert_eq!(notices[0].code(), "empty_data");
+
+    scanner.module_timeout(Duration::from_millis(10));
+
+    // The metadata "sleep" makes the module slower than the timeout, the
+    // scan continues as if the module didn't produce any output.
+    let scan_results = scanner
+        .scan_with_options(
+            b"foo",
+            ScanOptions::new().set_module_metadata("test_proto2", b"sleep"),
+        )
+        .unwrap();
+
+    let notices: Vec<_> = scan_results.module_notices().collect();
+
+    assert_eq!(scan_results.matching_rules().len(), 0);
+    assert!(scan_results.module_output("test_proto2").is_none());
+    assert_eq!(notices.len(), 1);
+    assert_eq!(notices[0].module(), "test_proto2");
+    assert_eq!(notices[0].code(), "timeout");
+
+    // Main functions run in a separate thread, but the rest of the module's
+    // functions run in the scanning thread. Values cached by them during a
+    // scan must not be reused in the next one.
+    let hash_rules = crate::compile(
+        r#"
+        import "hash"
+        rule test {
+          condition:
+            hash.md5(0, filesize) == "acbd18db4cc2f85cedef654fccc4a4d8"
+        }
+        "#,
+    )
+    .unwrap();
+
+    let mut hash_scanner = Scanner::new(&hash_rules);
+
+    hash_scanner.module_timeout(Duration::from_secs(60));
+
+    assert_eq!(hash_scanner.scan(b"foo").unwrap().matching_rules().len(), 1);
+    assert_eq!(hash_scanner.scan(b"bar").unwrap().matching_rules().len(), 0);
+
+    // Once the maximum number of main functions that didn't finish in time
+    // are running in the background, main functions are not invoked. The
+    // limit is per scanner, threads started by other scanners, including
+    // the ones started by other tests running in parallel, don't count.
+    for _ in 1..crate::modules::MAX_ABANDONED_THREADS {
+        scanner
+            .scan_with_options(
+                b"foo",
+                ScanOptions::new()
+                    .set_module_metadata("test_proto2", b"sleep"),
+            )
+            .unwrap();
+    }
+
+    let scan_results = scanner.scan(b"foo").unwrap();
+    let notices: Vec<_> = scan_results.module_notices().collect();
+
+    assert_eq!(scan_results.matching_rules().len(), 0);
+    assert_eq!(notices.len(), 1);
+    assert_eq!(notices[0].code(), "skipped");
+
+    // Other scanners are not affected by the threads abandoned by this one.
+    let mut other_scanner = Scanner::new(&rules);
+
+    other_scanner.module_timeout(Duration::from_secs(60));
+
+    let scan_results = other_scanner.scan(b"foo").unwrap();
+
+    assert_eq!(scan_results.matching_rules().len(), 1);
+    assert_eq!(scan_results.module_notices().len(), 0);
+
+    let mut scanner = Scanner::new(&rules);
+
+    scanner.module_max_output_size(1);
+
+    let scan_results = scanner.scan(b"foo").unwrap();
+    let notices: Vec<_> = scan_results.module_notices().collect();
+
+    assert_eq!(scan_results.matching_rules().len(), 0);
+    assert_eq!(notices.len(), 1);
+    assert_eq!(notices[0].code(), "output_size_limit");
+
+    scanner.module_max_output_size(1_000_000);
+
+    let scan_results = scanner.scan(b"foo").unwrap();
+
+    assert_eq!(scan_results.matching_rules().len(), 1);
+    assert_eq!(scan_results.module_notices().len(), 0);
+}
+
+#[cfg(feature = "test_proto2-module")]
+#[test]
+fn field_lookup_cache() {
+    let rules = crate::compile(
+        r#"
+        import "test_proto2"
+        rule test_1 {
+            condition:
+                test_proto2.file_size == 3
+        }
+        rule test_2 {
+            condition:
+                test_proto2.file_size > 2 and test_proto2.string_foo == "foo"
+        }
+        "#,
+    )
+    .unwrap();
+
+    let mut scanner = Scanner::new(&rules);
+
+    assert_eq!(
+        scanner
+            .scan(b"123")
+            .expect("scan should not fail")
+            .matching_rules()
+            .len(),
+        2
+    );
+
+    // `test_proto2.file_size` was looked up by both rules, but it's cached
+    // only once.
+    assert_eq!(scanner.scan_context().field_lookup_cache.len(), 2);
+
+    // The cache doesn't survive across scans.
+    assert_eq!(
+        scanner
+            .scan(b"1234")
+            .expect("scan should not fail")
+            .matching_rules()
+            .len(),
+        1
+    );
+}
+
 #[cfg(feature = "test_proto2-module")]
 #[test]
 fn module_output() {
@@ -621,6 +830,7 @@ fn max_matches_per_pattern() {
         scanner.scan(b"foofoofoo").expect("scan should not fail");
 
     assert_eq!(scan_results.matching_rules().len(), 1);
+    assert_eq!(scan_results.num_pattern_matches(), 1);
 
     let mut matches = scan_results
         .matching_rules()
@@ -640,9 +850,147 @@ fn max_matches_per_pattern() {
 
     assert!(matches.next().is_none());
 
+    // The pattern reports that some matches were dropped.
+    assert!(scan_results
+        .matching_rules()
+        .next()
+        .unwrap()
+        .patterns()
+        .next()
+        .unwrap()
+        .limit_reached());
+
     // If the scanner is used again it should produce results because the
     // number of matches must be reset to 0 for the new scan.
-    assert_eq!(scanner.scan(b"foo").unwrap().matching_rules().len(), 1);
+    let scan_results = scanner.scan(b"foo").unwrap();
+    let rule = scan_results.matching_rules().next().unwrap();
+
+    assert!(!rule.patterns().next().unwrap().limit_reached());
+}
+
+#[test]
+fn max_loop_iterations() {
+    let rules = crate::compile(
+        r#"
+        rule test_1 {
+            condition:
+              for any i in (0..filesize - 1) : ( uint8(i) == 0xFF )
+        }
+        rule test_2 {
+            condition:
+              for any i in (0..3) : ( uint8(i) == 0xFF )
+        }
+        "#,
+    )
+    .unwrap();
+
+    let mut scanner = Scanner::new(&rules);
+
+    // Without a limit the loop finds the 0xFF at the end of the data.
+    let scan_results = scanner.scan(b"\x00\x00\x00\x00\xFF").unwrap();
+    assert_eq!(scan_results.matching_rules().len(), 1);
+    assert_eq!(scan_results.loop_limit_reached().len(), 0);
+
+    // With a limit of 3 iterations only the first 3 bytes are checked.
+    scanner.max_loop_iterations(3);
+
+    let scan_results = scanner.scan(b"\x00\x00\x00\x00\xFF").unwrap();
+    assert_eq!(scan_results.matching_rules().len(), 0);
+
+    let rules_with_limit_reached: Vec<_> = scan_results
+        .loop_limit_reached()
+        .map(|rule| rule.identifier())
+        .collect();
+
+    // The loop in `test_2` has constant bounds, the limit doesn't apply
+    // to it.
+    assert_eq!(rules_with_limit_reached, vec!["test_1"]);
+
+    // Loops that don't reach the limit are not reported.
+    let scan_results = scanner.scan(b"\xFF\x00").unwrap();
+    assert_eq!(scan_results.matching_rules().len(), 2);
+    assert_eq!(scan_results.loop_limit_reached().len(), 0);
+
+    // Loops over arrays and maps are limited too.
+    let rules = crate::compile(
+        r#"
+        import "test_proto2"
+        rule test_1 {
+            condition:
+              for any x in test_proto2.array_int64 : ( x == 100 )
+        }
+        rule test_2 {
+            condition:
+              for any k, v in test_proto2.map_string_int64 : ( v == 1 )
+        }
+        "#,
+    )
+    .unwrap();
+
+    let mut scanner = Scanner::new(&rules);
+
+    let scan_results = scanner.scan(b"").unwrap();
+    assert_eq!(scan_results.matching_rules().len(), 2);
+    assert_eq!(scan_results.loop_limit_reached().len(), 0);
+
+    // The array has 3 items and the map 1, the third item of the array
+    // and the only one in the map are not evaluated.
+    scanner.max_loop_iterations(2);
+
+    let scan_results = scanner.scan(b"").unwrap();
+    assert_eq!(scan_results.matching_rules().len(), 1);
+    assert_eq!(scan_results.loop_limit_reached().len(), 1);
+
+    scanner.max_loop_iterations(0);
+
+    let scan_results = scanner.scan(b"").unwrap();
+    assert_eq!(scan_results.matching_rules().len(), 0);
+    assert_eq!(scan_results.loop_limit_reached().len(), 2);
+}
+
+#[test]
+fn matching_patterns() {
+    let rules = crate::compile(
+        r#"
+        rule test_1 {
+            strings:
+              $a = "foo"
+              $b = "bar"
+              $c = "baz"
+            condition:
+              $a and $b and $c
+        }
+        rule test_2 {
+            strings:
+              $a = "qux"
+              $b = "foo" private
+            condition:
+              all of them
+        }
+        "#,
+    )
+    .unwrap();
+
+    let mut scanner = Scanner::new(&rules);
+    let scan_results = scanner.scan(b"foo baz").unwrap();
+
+    // No rule matched, but some of their patterns did.
+    assert_eq!(scan_results.matching_rules().len(), 0);
+
+    let matching_patterns: Vec<_> = scan_results
+        .matching_patterns()
+        .map(|(rule, pattern)| {
+            (rule.identifier(), pattern.identifier(), pattern.matches().len())
+        })
+        .collect();
+
+    assert_eq!(
+        matching_patterns,
+        vec![("test_1", "$a", 1), ("test_1", "$c", 1), ("test_2", "$b", 1)]
+    );
+
+    let scan_results = scanner.scan(b"").unwrap();
+    assert_eq!(scan_results.matching_patterns().count(), 0);
 }
 
 #[test]
@@ -748,61 +1096,576 @@ fn namespaces() {
 }
 
 #[test]
-fn scan_file() {
-    let rules = crate::compile(
-        r#"
-    rule test {
-      strings:
-        $a = "aaaa"
-      condition: 
-        $a
-    }
-    "#,
-    )
-    .unwrap();
+fn rule_filter() {
+    let mut compiler = crate::Compiler::new();
+
+    compiler
+        .new_namespace("foo")
+        .add_source(
+            r#"
+            global rule global_foo : skip { condition: false }
+            rule foo_1 : a { condition: true }
+            rule foo_2 : a b { condition: true }
+            "#,
+        )
+        .unwrap()
+        .new_namespace("bar")
+        .add_source(r#"rule bar : b { condition: true }"#)
+        .unwrap();
 
+    let rules = compiler.build();
     let mut scanner = Scanner::new(&rules);
-    let scan_results =
-        scanner.scan_file("src/tests/testdata/jumps.bin").unwrap();
 
-    assert_eq!(scan_results.matching_rules().len(), 1);
+    let matching_rules = |scanner: &mut Scanner| -> Vec<String> {
+        let scan_results = scanner.scan(b"").expect("scan should not fail");
+        let mut rules: Vec<_> = scan_results
+            .matching_rules()
+            .map(|rule| rule.identifier().to_string())
+            .collect();
+        rules.sort();
+        rules
+    };
 
-    let scan_results = scanner
-        .scan_file_with_options(
-            "src/tests/testdata/jumps.bin",
-            ScanOptions::default(),
-        )
-        .unwrap();
+    // The global rule is false, so no rule in namespace `foo` matches.
+    assert_eq!(matching_rules(&mut scanner), vec!["bar"]);
 
-    assert_eq!(scan_results.matching_rules().len(), 1)
+    // Skip the global rule, the rest of the rules in `foo` can match now.
+    scanner.set_rule_filter(|rule| {
+        !rule.tags().any(|tag| tag.identifier() == "skip")
+    });
+
+    assert_eq!(matching_rules(&mut scanner), vec!["bar", "foo_1", "foo_2"]);
+
+    // The filter persists across scans, and is replaced by a new one.
+    scanner.set_rule_filter(|rule| {
+        rule.tags().any(|tag| tag.identifier() == "b")
+    });
+
+    assert_eq!(matching_rules(&mut scanner), vec!["bar", "foo_2"]);
+
+    scanner.set_rule_filter(|rule| rule.namespace() == "bar");
+    assert_eq!(matching_rules(&mut scanner), vec!["bar"]);
+
+    // Skipped rules are reported as non-matching.
+    let scan_results = scanner.scan(b"").expect("scan should not fail");
+    assert_eq!(scan_results.non_matching_rules().len(), 3);
+
+    scanner.set_rule_filter(|_| true);
+    assert_eq!(matching_rules(&mut scanner), vec!["bar"]);
 }
 
 #[test]
-fn scan_no_mmap() {
+fn disable_rule() {
     let rules = crate::compile(
         r#"
-    rule test {
-      strings:
-        $a = "aaaa"
-      condition:
-        $a
-    }
-    "#,
+        rule foo { strings: $a = "foo" condition: $a }
+        rule bar { strings: $a = "bar" $b = "foo" condition: $a and $b }
+        rule baz { condition: foo }
+        "#,
     )
     .unwrap();
 
     let mut scanner = Scanner::new(&rules);
 
-    let scan_results = scanner
-        .use_mmap(false)
-        .scan_file("src/tests/testdata/jumps.bin")
-        .unwrap();
+    assert!(matches!(
+        scanner.disable_rule("default", "qux"),
+        Err(ScanError::UnknownRule { .. })
+    ));
 
-    assert_eq!(scan_results.matching_rules().len(), 1);
-}
+    assert!(matches!(
+        scanner.disable_rule("qux", "foo"),
+        Err(ScanError::UnknownRule { .. })
+    ));
 
-#[cfg(feature = "rules-profiling")]
-#[test]
+    scanner.disable_rule("default", "foo").unwrap();
+
+    let scan_results = scanner.scan(b"foobar").expect("scan should not fail");
+    let matching_rules: Vec<_> =
+        scan_results.matching_rules().map(|rule| rule.identifier()).collect();
+
+    // `foo` is disabled, and `baz` depends on it. The pattern "foo" is still
+    // searched for because `bar` uses it too.
+    assert_eq!(matching_rules, vec!["bar"]);
+    assert!(scanner.scan_context_mut().disabled_patterns.is_empty());
+
+    // The rule can't be enabled again with a filter.
+    scanner.set_rule_filter(|_| true);
+    scanner.disable_rule("default", "bar").unwrap();
+
+    // Now both patterns are used only by disabled rules.
+    assert_eq!(scanner.scan_context_mut().disabled_patterns.len(), 2);
+
+    let scan_results = scanner.scan(b"foobar").expect("scan should not fail");
+    assert_eq!(scan_results.matching_rules().len(), 0);
+    assert_eq!(scan_results.non_matching_rules().len(), 3);
+}
+
+#[test]
+fn match_overlap_policy() {
+    let rules = crate::compile(
+        r#"
+        rule test {
+          strings:
+            $a = "aa"
+            $b = /ab|bcde/
+          condition:
+            $a and $b
+        }
+        "#,
+    )
+    .unwrap();
+
+    let mut scanner = Scanner::new(&rules);
+
+    let matches = |scanner: &mut Scanner| -> Vec<Vec<Range<usize>>> {
+        let scan_results =
+            scanner.scan(b"aaaa abcde").expect("scan should not fail");
+        let rule = scan_results.matching_rules().next().unwrap();
+        rule.patterns()
+            .map(|pattern| pattern.matches().map(|m| m.range()).collect())
+            .collect()
+    };
+
+    assert_eq!(
+        matches(&mut scanner),
+        vec![vec![0..2, 1..3, 2..4], vec![5..7, 6..10]]
+    );
+
+    scanner.match_overlap_policy(MatchOverlapPolicy::NonOverlapping);
+
+    assert_eq!(matches(&mut scanner), vec![vec![0..2, 2..4], vec![5..7]]);
+
+    scanner.match_overlap_policy(MatchOverlapPolicy::Greedy);
+
+    assert_eq!(matches(&mut scanner), vec![vec![0..2, 2..4], vec![6..10]]);
+}
+
+#[test]
+fn scan_file() {
+    let rules = crate::compile(
+        r#"
+    rule test {
+      strings:
+        $a = "aaaa"
+      condition: 
+        $a
+    }
+    "#,
+    )
+    .unwrap();
+
+    let mut scanner = Scanner::new(&rules);
+    let scan_results =
+        scanner.scan_file("src/tests/testdata/jumps.bin").unwrap();
+
+    assert_eq!(scan_results.matching_rules().len(), 1);
+
+    let scan_results = scanner
+        .scan_file_with_options(
+            "src/tests/testdata/jumps.bin",
+            ScanOptions::default(),
+        )
+        .unwrap();
+
+    assert_eq!(scan_results.matching_rules().len(), 1)
+}
+
+#[test]
+fn scan_no_mmap() {
+    let rules = crate::compile(
+        r#"
+    rule test {
+      strings:
+        $a = "aaaa"
+      condition:
+        $a
+    }
+    "#,
+    )
+    .unwrap();
+
+    let mut scanner = Scanner::new(&rules);
+
+    let scan_results = scanner
+        .use_mmap(false)
+        .scan_file("src/tests/testdata/jumps.bin")
+        .unwrap();
+
+    assert_eq!(scan_results.matching_rules().len(), 1);
+}
+
+#[cfg(feature = "test_proto2-module")]
+#[test]
+fn runtime_strings_interning() {
+    let rules = crate::compile(
+        r#"
+        import "test_proto2"
+        rule test {
+            condition:
+                for all i in (1..100) : (
+                    test_proto2.get_foo() == "foo"
+                )
+        }
+        "#,
+    )
+    .unwrap();
+
+    let mut scanner = Scanner::new(&rules);
+
+    scanner.max_runtime_objects(0);
+
+    for _ in 0..2 {
+        assert_eq!(scanner.scan(b"").unwrap().matching_rules().len(), 1);
+
+        // The 100 strings returned by `get_foo` are identical, only one of
+        // them is stored.
+        let ctx = scanner.scan_context();
+        let num_strings = ctx
+            .runtime_objects
+            .values()
+            .filter(|obj| matches!(obj, RuntimeObject::String(_)))
+            .count();
+
+        assert_eq!(num_strings, 1);
+        assert_eq!(ctx.runtime_strings.len(), 1);
+    }
+}
+
+#[test]
+fn max_file_size() {
+    let rules = crate::compile(
+        r#"
+    rule test {
+      strings:
+        $a = "foo"
+      condition:
+        $a and filesize == 6
+    }
+    "#,
+    )
+    .unwrap();
+
+    let mut scanner = Scanner::new(&rules);
+
+    scanner.max_file_size(6);
+
+    // Data within the limit is scanned normally.
+    let scan_results = scanner.scan(b"xxxfoo").unwrap();
+    assert_eq!(scan_results.matching_rules().len(), 1);
+    assert!(!scan_results.is_truncated());
+
+    // By default, data larger than the limit is skipped.
+    assert!(matches!(
+        scanner.scan(b"xxxfooxxx"),
+        Err(ScanError::DataTooLarge { size: 9, max_size: 6 })
+    ));
+
+    scanner.oversized_data_policy(OversizedDataPolicy::Truncate);
+
+    let scan_results = scanner.scan(b"xxxfooxxx").unwrap();
+    assert_eq!(scan_results.matching_rules().len(), 1);
+    assert!(scan_results.is_truncated());
+    assert_eq!(scan_results.data(), Some(b"xxxfoo".as_slice()));
+
+    // The pattern is beyond the limit.
+    let scan_results = scanner.scan(b"xxxxfoo").unwrap();
+    assert_eq!(scan_results.matching_rules().len(), 0);
+    assert!(scan_results.is_truncated());
+
+    // Files are truncated too.
+    scanner.max_file_size(16);
+
+    let scan_results =
+        scanner.scan_file("src/tests/testdata/jumps.bin").unwrap();
+
+    assert!(scan_results.is_truncated());
+
+    scanner.oversized_data_policy(OversizedDataPolicy::Skip);
+
+    assert!(matches!(
+        scanner.scan_file("src/tests/testdata/jumps.bin"),
+        Err(ScanError::DataTooLarge { max_size: 16, .. })
+    ));
+}
+
+#[test]
+fn max_memory_usage() {
+    let rules = crate::compile(
+        r#"
+    rule test {
+      strings:
+        $a = "foo"
+      condition:
+        $a
+    }
+    "#,
+    )
+    .unwrap();
+
+    let data = b"foo".repeat(1000);
+    let mut scanner = Scanner::new(&rules);
+
+    // Without a limit the scan succeeds, and reports the memory used for
+    // storing the 1000 matches.
+    let scan_results = scanner.scan(data.as_slice()).unwrap();
+    let peak_memory_usage = scan_results.peak_memory_usage();
+
+    assert_eq!(scan_results.matching_rules().len(), 1);
+    assert!(peak_memory_usage >= 1000);
+
+    // The peak is computed per scan.
+    assert!(scanner.scan(b"foo").unwrap().peak_memory_usage() < 1000);
+
+    scanner.max_memory_usage(peak_memory_usage);
+    assert!(scanner.scan(data.as_slice()).is_ok());
+
+    scanner.max_memory_usage(peak_memory_usage / 2);
+    assert!(matches!(scanner.scan(data.as_slice()), Err(ScanError::MemLimit)));
+
+    // The scanner can be used again after the limit was exceeded.
+    assert_eq!(scanner.scan(b"foo").unwrap().matching_rules().len(), 1);
+
+    // The outputs of modules are also accounted.
+    let rules = crate::compile(
+        r#"
+    import "test_proto2"
+    rule test { condition: test_proto2.int32_one == 1 }
+    "#,
+    )
+    .unwrap();
+
+    let mut scanner = Scanner::new(&rules);
+
+    assert!(scanner.scan(b"").unwrap().peak_memory_usage() > 0);
+
+    scanner.max_memory_usage(1);
+    assert!(matches!(scanner.scan(b""), Err(ScanError::MemLimit)));
+
+    // And the strings produced while evaluating conditions. Here the limit
+    // is exceeded by the string returned by `get_foo`, not by the module
+    // output.
+    let rules = crate::compile(
+        r#"
+    import "test_proto2"
+    rule test { condition: test_proto2.get_foo() == "foo" }
+    "#,
+    )
+    .unwrap();
+
+    let mut scanner = Scanner::new(&rules);
+    let peak_memory_usage = scanner.scan(b"").unwrap().peak_memory_usage();
+
+    scanner.max_memory_usage(peak_memory_usage - 1);
+    assert!(matches!(scanner.scan(b""), Err(ScanError::MemLimit)));
+
+    // In block scanning mode the snippets of matching data are accounted.
+    let rules = crate::compile(
+        r#"
+    rule test {
+      strings:
+        $a = "foo"
+      condition:
+        $a
+    }
+    "#,
+    )
+    .unwrap();
+
+    let mut scanner = crate::blocks::Scanner::new(&rules);
+
+    scanner.max_memory_usage(peak_memory_usage / 2);
+    assert!(matches!(
+        scanner.scan(0, data.as_slice()),
+        Err(ScanError::MemLimit)
+    ));
+}
+
+#[test]
+fn cancellation() {
+    let rules = crate::compile(
+        r#"
+    rule test {
+      condition:
+        for all i in (0..filesize) : (
+          for all j in (0..filesize) : ( uint8(i) | uint8(j) != 0xFFFF )
+        )
+    }
+    "#,
+    )
+    .unwrap();
+
+    let data = vec![0_u8; 100_000];
+    let mut scanner = Scanner::new(&rules);
+    let token = scanner.cancellation_token();
+
+    let canceller = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(100));
+        token.cancel();
+    });
+
+    // The evaluation of the condition takes much longer than 100ms, the scan
+    // is aborted when the token is cancelled.
+    assert!(matches!(
+        scanner.scan(data.as_slice()),
+        Err(ScanError::Cancelled)
+    ));
+
+    canceller.join().unwrap();
+
+    // Scans keep failing until a new token is obtained.
+    assert!(matches!(scanner.scan(b""), Err(ScanError::Cancelled)));
+
+    let token = scanner.cancellation_token();
+    assert!(!token.is_cancelled());
+    assert!(scanner.scan(b"").is_ok());
+}
+
+#[test]
+fn multi_scanner() {
+    let rules_1 = crate::compile(
+        r#"
+    rule foo {
+      strings:
+        $a = "aaaa"
+      condition:
+        $a
+    }
+    "#,
+    )
+    .unwrap();
+
+    let mut compiler = crate::Compiler::new();
+
+    compiler.define_global("int", 0).unwrap();
+    compiler
+        .add_source(
+            r#"
+    rule bar {
+      strings:
+        $a = "bbbb"
+      condition:
+        $a and int == 1
+    }
+    "#,
+        )
+        .unwrap();
+
+    let rules_2 = compiler.build();
+
+    let mut scanner = crate::multi::Scanner::new([&rules_1, &rules_2]);
+
+    // `int` is defined only in the second set of rules.
+    scanner.set_global("int", 1).unwrap();
+
+    assert!(matches!(
+        scanner.set_global("undefined", 1),
+        Err(VariableError::Undefined(_))
+    ));
+
+    let scan_results =
+        scanner.scan_file("src/tests/testdata/jumps.bin").unwrap();
+
+    assert_eq!(scan_results.len(), 2);
+    assert_eq!(scan_results[0].matching_rules().len(), 1);
+    assert_eq!(scan_results[1].matching_rules().len(), 1);
+
+    scanner.max_file_size(16);
+    scanner.oversized_data_policy(OversizedDataPolicy::Truncate);
+
+    let scan_results =
+        scanner.scan_file("src/tests/testdata/jumps.bin").unwrap();
+
+    assert!(scan_results.iter().all(|r| r.is_truncated()));
+
+    // Rule sets without patterns are scanned together with the rest, and
+    // matches are verified by the rule set that contains the pattern.
+    let rules_3 =
+        crate::compile("rule baz { condition: filesize == 10 }").unwrap();
+
+    let rules_4 = crate::compile(
+        r#"
+    rule qux {
+      strings:
+        $a = "bbbb"
+        $b = "aaaa"
+      condition:
+        @a[1] == 6 and not $b
+    }
+    "#,
+    )
+    .unwrap();
+
+    let mut scanner =
+        crate::multi::Scanner::new([&rules_3, &rules_1, &rules_4]);
+
+    let scan_results = scanner.scan(b"xxaaaabbbb").unwrap();
+
+    assert_eq!(scan_results.len(), 3);
+    assert_eq!(scan_results[0].matching_rules().len(), 1);
+    assert_eq!(scan_results[1].matching_rules().len(), 1);
+    assert_eq!(scan_results[2].matching_rules().len(), 0);
+
+    let rule = scan_results[1].matching_rules().next().unwrap();
+    let m = rule.patterns().next().unwrap().matches().next().unwrap();
+
+    assert_eq!(m.range(), 2..6);
+    assert_eq!(m.data(), b"aaaa");
+
+    let scan_results = scanner.scan(b"xxxxxxbbbb").unwrap();
+
+    assert_eq!(scan_results[1].matching_rules().len(), 0);
+    assert_eq!(scan_results[2].matching_rules().len(), 1);
+
+    let mut scanner = crate::multi::Scanner::new([]);
+
+    assert!(scanner.scan(b"foo").unwrap().is_empty());
+}
+
+#[test]
+fn rule_set() {
+    let mut rule_set = crate::multi::RuleSet::new();
+
+    rule_set
+        .add_source("foo", r#"rule foo { strings: $a = "foo" condition: $a }"#)
+        .unwrap()
+        .add_source("bar", r#"rule bar { strings: $a = "bar" condition: $a }"#)
+        .unwrap();
+
+    assert_eq!(
+        rule_set.iter().map(|(ns, _)| ns).collect::<Vec<_>>(),
+        ["bar", "foo"]
+    );
+
+    // A namespace with errors doesn't replace the existing one.
+    assert!(rule_set
+        .add_source("bar", "rule bar { condition: baz }")
+        .is_err());
+
+    let mut scanner = rule_set.scanner();
+    let scan_results = scanner.scan(b"foobar").unwrap();
+
+    assert_eq!(scan_results[0].matching_rules().len(), 1);
+    assert_eq!(scan_results[1].matching_rules().len(), 1);
+
+    // The rules in the namespace keep the namespace's name.
+    let rule = scan_results[0].matching_rules().next().unwrap();
+    assert_eq!(rule.namespace(), "bar");
+
+    drop(scanner);
+
+    assert!(rule_set.remove("bar").is_some());
+    assert!(rule_set.remove("bar").is_none());
+    assert_eq!(rule_set.len(), 1);
+
+    let mut scanner = rule_set.scanner();
+    let scan_results = scanner.scan(b"foobar").unwrap();
+
+    assert_eq!(scan_results.len(), 1);
+    assert_eq!(scan_results[0].matching_rules().len(), 1);
+}
+
+#[cfg(feature = "rules-profiling")]
+#[test]
 fn rules_profiling() {
     let rules = crate::compile(
         r#"
@@ -818,7 +1681,11 @@ fn rules_profiling() {
 
     let mut scanner = Scanner::new(&rules);
 
-    scanner.scan(b"foobar").unwrap();
+    let scan_results = scanner.scan(b"foobar").unwrap();
+    let slowest_rules = scan_results.slowest_rules(10);
+
+    assert_eq!(slowest_rules.len(), 1);
+    assert_eq!(slowest_rules[0].rule, "slow");
 
     let slowest_rules = scanner.slowest_rules(10);
 
@@ -830,3 +1697,95 @@ fn rules_profiling() {
     let slowest_rules = scanner.slowest_rules(10);
     assert_eq!(slowest_rules.len(), 0);
 }
+
+#[cfg(feature = "pulley-backend")]
+#[test]
+fn pulley_backend() {
+    let mut compiler = crate::Compiler::new();
+
+    compiler
+        .backend(crate::Backend::Pulley)
+        .add_source(
+            r#"
+    import "test_proto2"
+    rule foo {
+      strings:
+        $a = "foo"
+      condition:
+        #a == 2 and for all i in (1..#a) : (@a[i] < 10)
+    }
+    rule bar {
+      condition:
+        foo and test_proto2.add(1, 2) == 3
+    }
+    "#,
+        )
+        .unwrap();
+
+    let rules = compiler.build();
+    let mut scanner = Scanner::new(&rules);
+    let results = scanner.scan(b"foofoo").unwrap();
+
+    assert_eq!(results.matching_rules().len(), 2);
+
+    // Rules keep using Pulley after being deserialized.
+    let rules = crate::Rules::deserialize(rules.serialize().unwrap()).unwrap();
+
+    assert_eq!(rules.backend(), crate::Backend::Pulley);
+
+    let mut scanner = Scanner::new(&rules);
+    let results = scanner.scan(b"foo").unwrap();
+
+    assert_eq!(results.matching_rules().len(), 0);
+}
+
+#[cfg(feature = "pe-module")]
+#[test]
+fn scan_results_to_proto() {
+    let rules = crate::compile(
+        r#"
+        import "pe"
+        rule test : foo {
+            meta:
+                int = 1
+                str = "bar"
+            strings:
+                $a = "abc" xor(1)
+            condition:
+                $a and not pe.is_pe
+        }
+        "#,
+    )
+    .unwrap();
+
+    let mut scanner = Scanner::new(&rules);
+    let scan_results = scanner.scan(b"xx`cb").unwrap();
+    let results = scan_results.to_proto();
+
+    assert_eq!(results.matching_rules.len(), 1);
+
+    let rule = &results.matching_rules[0];
+
+    assert_eq!(rule.namespace(), "default");
+    assert_eq!(rule.identifier(), "test");
+    assert_eq!(rule.tags, vec!["foo".to_string()]);
+    assert_eq!(rule.metadata[0].identifier(), "int");
+    assert_eq!(rule.metadata[0].integer(), 1);
+    assert_eq!(rule.metadata[1].string(), "bar");
+
+    let m = &rule.patterns[0].matches[0];
+
+    assert_eq!(m.offset(), 2);
+    assert_eq!(m.length(), 3);
+    assert_eq!(m.xor_key(), 1);
+
+    assert!(!results.modules.pe.is_pe());
+    assert!(results.modules.elf.is_none());
+
+    // The message can be serialized and parsed back.
+    let bytes = results.write_to_bytes().unwrap();
+    assert_eq!(
+        crate::proto::ScanResults::parse_from_bytes(&bytes).unwrap(),
+        results
+    );
+}
@@ -56,12 +56,24 @@ where
         }
     }
 
+    /// Returns the total size in bytes of the interned strings.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
     /// Returns the string corresponding to a given `id` if it was previously
     /// interned. If not returns [`None`].
     #[inline]
     pub fn get(&self, id: T) -> Option<&str> {
         self.pool.get(Symbol::from(id.into()))
     }
+
+    /// Returns an iterator over the interned strings and their IDs, in the
+    /// order in which they were interned.
+    pub fn iter(&self) -> impl Iterator<Item = (T, &str)> {
+        self.pool.iter().map(|(symbol, s)| (T::from(symbol.id()), s))
+    }
 }
 
 impl<T> Serialize for StringPool<T>
@@ -171,6 +183,12 @@ where
         }
     }
 
+    /// Returns the total size in bytes of the interned strings.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
     /// Returns the string corresponding to a given ID if it was previously
     /// interned. If not returns [`None`].
     #[inline]
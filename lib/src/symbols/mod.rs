@@ -38,6 +38,9 @@ pub(crate) enum Symbol {
         /// If the field is deprecated, this will contain the message shown when
         /// the field is used in a rule.
         deprecation_notice: Option<DeprecationNotice>,
+        /// True if the field's value is computed by a method. In that case
+        /// `type_value` is the method that computes the value.
+        computed: bool,
     },
     /// The symbol refers to a rule.
     Rule {
@@ -214,6 +217,18 @@ impl SymbolTable {
         self.map.insert(ident.into(), symbol)
     }
 
+    /// Returns the symbol associated to the given identifier, if any.
+    ///
+    /// Contrary to [`SymbolLookup::lookup`], this doesn't mark the symbol
+    /// as used.
+    #[inline]
+    pub fn get<I>(&self, ident: I) -> Option<&Symbol>
+    where
+        I: AsRef<str>,
+    {
+        self.map.get(ident.as_ref())
+    }
+
     /// Returns true if the symbol table already contains a symbol with
     /// the given identifier.
     #[inline]
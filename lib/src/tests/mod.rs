@@ -478,6 +478,132 @@ fn floatxx() {
     );
 }
 
+#[test]
+fn float_coercions() {
+    condition_true!("1 == 1.0");
+    condition_true!("1 < 1.5");
+    condition_true!("uint8(0) + 0.5 == 65.5", b"A");
+    condition_true!("uint8(0) \\ 2 == 32", b"A");
+    condition_true!("uint8(0) \\ 2.0 == 32.5", b"A");
+    condition_true!("uint8(0) * 1.0 \\ 0.0 > 1000000.0", b"A");
+    condition_false!(
+        "(uint8(0) - 65.0) \\ 0.0 == (uint8(0) - 65.0) \\ 0.0",
+        b"A"
+    );
+    condition_true!("9007199254740993 == 9007199254740992.0");
+
+    // Integer items in tuples that also contain floats are promoted to
+    // float.
+    condition_true!("for any x in (1, 2.5): (x > 2)");
+    condition_true!("for all x in (uint8(0), 0.5): (x <= 65.0)", b"A");
+
+    // Integer arguments are promoted to float when the function expects
+    // a float.
+    condition_true!("test_proto2.add(1, 2.0) == 3.0");
+    condition_true!("test_proto2.add(1, 2) == 3");
+    condition_true!("test_proto2.add(uint8(0), 0.5) == 65.5", b"A");
+}
+
+#[test]
+fn string_indexing() {
+    condition_true!("test_proto2.string_foo[0] == 0x66");
+    condition_true!("test_proto2.string_foo[2] == 0x6f");
+    condition_false!("defined test_proto2.string_foo[3]");
+    condition_true!("test_proto2.array_struct[1].nested_string[0] == 0x66");
+    condition_true!(
+        "with s = test_proto2.head(4): (s[3] == 0x44 and s[uint8(0) - 0x41] == 0x41)",
+        b"ABCD"
+    );
+    condition_true!(
+        "for any s in test_proto2.array_string: (s[0] == 0x62 and s[1] == 0x61)"
+    );
+}
+
+#[test]
+fn substr() {
+    condition_true!(r#"substr("abcdef", 1, 2) == "bc""#);
+    condition_true!(r#"substr("abcdef", 4, 10) == "ef""#);
+    condition_true!(r#"substr("abcdef", 0, 0) == """#);
+    condition_true!(r#"substr("abcdef", 2) == "cdef""#);
+    condition_true!(r#"substr("abcdef", 6) == """#);
+    condition_false!(r#"defined substr("abcdef", 7)"#);
+    condition_false!(r#"defined substr("abcdef", -1, 2)"#);
+    condition_false!(r#"defined substr("abcdef", 1, -2)"#);
+    condition_true!(r#"substr(test_proto2.string_foo, 1, 1) == "o""#);
+    condition_true!(r#"substr(test_proto2.head(4), 1) == "BCD""#, b"ABCD");
+    condition_true!(
+        r#"substr(test_proto2.head(4), uint8(3) - 0x44, 2) == "AB""#,
+        b"ABCD"
+    );
+}
+
+#[test]
+fn regexps_against_strings() {
+    condition_true!(r#"test_proto2.string_foo matches /^fo+$/"#);
+    condition_true!(r#"test_proto2.string_foo matches /^FOO$/i"#);
+    condition_false!(r#"test_proto2.string_foo matches /^FOO$/"#);
+    condition_true!(r#"test_proto2.array_string[2] matches /z$/"#);
+    condition_true!(r#"test_proto2.head(4) matches /b.d/i"#, b"ABCD");
+    // Case-insensitive matching only folds ASCII characters, like in YARA.
+    condition_false!(r#""\xc4BC" matches /\xe4bc/i"#);
+    condition_true!(r#""a\nb" matches /a.b/s"#);
+    condition_false!(r#""a\nb" matches /a.b/"#);
+}
+
+#[test]
+fn count_matches() {
+    condition_true!(r#"count_matches("abcabc", /b./) == 2"#);
+    condition_true!(r#"count_matches("aaaa", /aa/) == 2"#);
+    condition_true!(r#"count_matches("abc", /x/) == 0"#);
+    condition_true!(r#"count_matches(test_proto2.string_foo, /O/i) == 2"#);
+    condition_true!(
+        r#"count_matches(test_proto2.head(4), /[A-C]/) == 3"#,
+        b"ABCD"
+    );
+}
+
+#[test]
+fn min_max_abs() {
+    condition_true!("min(1, 2) == 1");
+    condition_true!("min(-1, 2) == -1");
+    condition_true!("max(1, 2) == 2");
+    condition_true!("max(-1, -2) == -1");
+    condition_true!("min(1.5, 2.5) == 1.5");
+    condition_true!("max(1.5, 2.5) == 2.5");
+    condition_true!("max(uint8(0), uint8(1)) == 2", &[0x01, 0x02]);
+    condition_true!("abs(-1) == 1");
+    condition_true!("abs(1) == 1");
+    condition_true!("abs(-1.5) == 1.5");
+    condition_false!("defined abs(-0x7fffffffffffffff - 1)");
+
+    // Rules can shadow built-in functions.
+    rule_true!(
+        r#"
+        private rule min { condition: true }
+        rule test { condition: min }
+        "#
+    );
+}
+
+#[test]
+fn bit_ops() {
+    condition_true!("popcount(0) == 0");
+    condition_true!("popcount(0xff) == 8");
+    condition_true!("popcount(-1) == 64");
+    condition_true!("rotl(1, 1) == 2");
+    condition_true!("rotl(1, 65) == 2");
+    condition_true!("rotl(1, -1) == -0x7fffffffffffffff - 1");
+    condition_true!("rotr(1, 1) == -0x7fffffffffffffff - 1");
+    condition_true!("rotr(2, 1) == 1");
+    condition_true!("rotl(0x80, 1, 8) == 1");
+    condition_true!("rotr(1, 1, 8) == 0x80");
+    condition_true!("rotr(0x12345678, 8, 32) == 0x78123456");
+    condition_true!("rotl(0x12345678, 8, 32) == 0x34567812");
+    condition_true!("rotl(0x1234, 4, 16) == 0x2341");
+    condition_false!("defined rotl(1, 1, 7)");
+    condition_false!("defined rotr(1, 1, 0)");
+}
+
 #[test]
 fn for_in() {
     condition_true!("for any i in (0..1): ( 1 )");
@@ -1149,6 +1275,49 @@ fn hex_patterns() {
         &[0x01, 0x02, 0x03, 0x04, 0x05]
     );
 
+    pattern_match!(
+        r#"{ 01 ~0? [1-2] ~?3 04 }"#,
+        &[0x01, 0x10, 0xFF, 0x02, 0x04],
+        &[0x01, 0x10, 0xFF, 0x02, 0x04]
+    );
+
+    pattern_match!(
+        r#"{ ~01 02 [-] 03 ~?4 }"#,
+        &[0x00, 0x02, 0xFF, 0xFF, 0x03, 0x05],
+        &[0x00, 0x02, 0xFF, 0xFF, 0x03, 0x05]
+    );
+
+    let data = [&[0x01, 0x02, 0x03][..], &[0xFF; 300], &[0x00, 0x05]].concat();
+
+    pattern_match!(
+        r#"{ 01 02 03 [300-] ~04 05 }"#,
+        data.as_slice(),
+        data.as_slice()
+    );
+
+    pattern_match!(
+        r#"{ 01 02 ?3 [2-4] 4? ~05 06 }"#,
+        &[0x01, 0x02, 0xF3, 0xFF, 0xFF, 0xFF, 0x4A, 0x15, 0x06],
+        &[0x01, 0x02, 0xF3, 0xFF, 0xFF, 0xFF, 0x4A, 0x15, 0x06]
+    );
+
+    pattern_false!(
+        r#"{ 01 ~0? [1-2] ~?3 04 }"#,
+        &[0x01, 0x0F, 0xFF, 0x02, 0x04]
+    );
+
+    pattern_false!(
+        r#"{ 01 ~0? [1-2] ~?3 04 }"#,
+        &[0x01, 0x10, 0xFF, 0x13, 0x04]
+    );
+
+    pattern_false!(
+        r#"{ 01 02 03 [300-] ~04 05 }"#,
+        [&[0x01, 0x02, 0x03][..], &[0xFF; 300], &[0x04, 0x05]]
+            .concat()
+            .as_slice()
+    );
+
     pattern_match!(
         r#"{ (01|11) (02|12) (03|13) (04|14) (05|15) (06|16) (07|17) }"#,
         &[0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07],
@@ -2307,6 +2476,39 @@ fn match_at() {
         "#,
         b"barfoo"
     );
+
+    #[cfg(feature = "test_proto2-module")]
+    rule_true!(
+        r#"
+        import "test_proto2"
+
+        rule test {
+            strings:
+                $a = "foo"
+            condition:
+                $a at test_proto2.int64_one and
+                $a at test_proto2.array_struct[0].nested_int64_one and
+                $a at test_proto2.nested.nested_array_int64[1] - 9 and
+                $a at test_proto2.map_string_int64["one"] * 5
+        }
+        "#,
+        b"xfooxfoo"
+    );
+
+    #[cfg(feature = "test_proto2-module")]
+    rule_false!(
+        r#"
+        import "test_proto2"
+
+        rule test {
+            strings:
+                $a = "foo"
+            condition:
+                $a at test_proto2.nested.nested_undef
+        }
+        "#,
+        b"foo"
+    );
 }
 
 #[test]
@@ -2434,6 +2636,39 @@ fn match_in() {
         "#,
         b"foobar"
     );
+
+    #[cfg(feature = "test_proto2-module")]
+    rule_true!(
+        r#"
+        import "test_proto2"
+
+        rule test {
+            strings:
+                $a = "bar"
+            condition:
+                $a in (test_proto2.array_struct[1].nested_int64_one..
+                       test_proto2.array_struct[1].nested_int64_one + 100) and
+                not $a in (test_proto2.int64_zero..test_proto2.int64_one) and
+                $a in (test_proto2.int64_one * 2..filesize)
+        }
+        "#,
+        b"foobar"
+    );
+
+    #[cfg(feature = "test_proto2-module")]
+    rule_false!(
+        r#"
+        import "test_proto2"
+
+        rule test {
+            strings:
+                $a = "foo"
+            condition:
+                $a in (test_proto2.int64_zero..test_proto2.nested.nested_undef)
+        }
+        "#,
+        b"foobar"
+    );
 }
 
 #[test]
@@ -3646,6 +3881,71 @@ fn of() {
         b"barbaz"
     );
 
+    rule_true!(
+        r#"
+        rule test {
+          strings:
+            $a1 = "foo"
+            $a2 = "bar"
+            $a3 = "baz"
+            $b = "qux"
+          condition:
+            2 of ($a*) in (0..3) and
+            not 3 of ($a*) in (0..3) and
+            all of ($a2, $b) in (3..filesize) and
+            none of ($a*) in (7..filesize) and
+            50% of them in (0..6)
+        }
+        "#,
+        b"foobarqux"
+    );
+
+    rule_true!(
+        r#"
+        rule test {
+          strings:
+            $a1 = "foo"
+            $a2 = "bar"
+            $b = "qux"
+          condition:
+            1 of ($a*) at 3 and
+            none of ($a*) at 6 and
+            any of ($a*, $b) at 6 and
+            all of ($b) at 6
+        }
+        "#,
+        b"foobarqux"
+    );
+
+    rule_false!(
+        r#"
+        rule test {
+          strings:
+            $a1 = "foo"
+            $a2 = "bar"
+          condition:
+            any of ($a*) at 1
+        }
+        "#,
+        b"foobar"
+    );
+
+    #[cfg(feature = "test_proto2-module")]
+    rule_true!(
+        r#"
+        import "test_proto2"
+        rule test {
+          strings:
+            $a1 = "foo"
+            $a2 = "bar"
+          condition:
+            any of ($a*) at test_proto2.int64_one * 3 and
+            all of them in (test_proto2.int64_zero..test_proto2.int64_one * 3)
+        }
+        "#,
+        b"foobar"
+    );
+
     rule_true!(
         r#"
         rule test_1 {
@@ -3954,3 +4254,66 @@ fn short_circuit() {
         b"foobar"
     );
 }
+
+#[cfg(feature = "test_proto2-module")]
+#[test]
+fn import_alias() {
+    rule_true!(
+        r#"
+        import "test_proto2" as t
+        rule test {
+            condition:
+                t.int64_one == 1 and t.add(1, 2) == 3
+        }
+        "#
+    );
+
+    rule_true!(
+        r#"
+        import "test_proto2"
+        import "test_proto2" as t
+        rule test {
+            condition:
+                test_proto2.int64_one == t.int64_one
+        }
+        "#
+    );
+}
+
+#[test]
+fn unicode_case_folding() {
+    // String operators that ignore case take Unicode into account.
+    condition_true!(r#""ÁRBOL" icontains "árbol""#);
+    condition_true!(r#""ΣΊΣΥΦΟΣ" iequals "σίσυφοσ""#);
+
+    let src = r#"
+        rule test {
+            strings:
+                $a = "árbol" nocase
+                $b = "kelvin" nocase wide
+            condition:
+                #a == 2 and #b == 1
+        }
+        "#;
+
+    // Without Unicode case folding only ASCII letters are folded.
+    let rules = crate::compile(src).unwrap();
+    let data = "ÁRBOL Árbol KELVIN".as_bytes();
+    let mut scanner = crate::Scanner::new(&rules);
+    assert_eq!(scanner.scan(data).unwrap().matching_rules().len(), 0);
+
+    let mut compiler = crate::Compiler::new();
+    compiler.unicode_case_folding(true).add_source(src).unwrap();
+    let rules = compiler.build();
+    let mut scanner = crate::Scanner::new(&rules);
+
+    let mut data = "ÁRBOL Árbol ".as_bytes().to_vec();
+
+    // "\u{212A}ELVIN" encoded as UTF-16LE, where \u{212A} is the Kelvin sign.
+    data.extend("\u{212A}ELVIN".encode_utf16().flat_map(|c| c.to_le_bytes()));
+
+    assert_eq!(
+        scanner.scan(data.as_slice()).unwrap().matching_rules().len(),
+        1
+    );
+}
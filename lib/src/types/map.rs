@@ -5,7 +5,7 @@ use std::cell::OnceCell;
 use std::rc::Rc;
 
 use crate::symbols::{Symbol, SymbolTable};
-use crate::types::TypeValue;
+use crate::types::{Struct, TypeValue};
 use crate::wasm::WasmExport;
 
 thread_local! {
@@ -48,6 +48,27 @@ impl Map {
         })
     }
 
+    /// Calls `f` with all the structures contained in the map, including
+    /// the deputy value.
+    pub fn enum_substructures<F>(&mut self, f: &mut F)
+    where
+        F: FnMut(&mut Struct),
+    {
+        let values: Box<dyn Iterator<Item = &mut TypeValue>> = match self {
+            Map::IntegerKeys { deputy, map } => {
+                Box::new(deputy.iter_mut().chain(map.values_mut()))
+            }
+            Map::StringKeys { deputy, map } => {
+                Box::new(deputy.iter_mut().chain(map.values_mut()))
+            }
+        };
+        for value in values {
+            if let TypeValue::Struct(s) = value {
+                Rc::<Struct>::get_mut(s).unwrap().enum_substructures(f);
+            }
+        }
+    }
+
     pub fn deputy(&self) -> TypeValue {
         match self {
             Map::IntegerKeys { deputy, .. } => {
@@ -104,6 +104,10 @@ pub(crate) struct StructField {
     /// Deprecation notice that must be shown when the field is used in a
     /// rule. This is `None` for non-deprecated fields.
     pub deprecation_notice: Option<DeprecationNotice>,
+    /// True if the value of this field is computed by a method with the same
+    /// name. At compile time the type of computed fields is the method that
+    /// computes their values.
+    pub computed: bool,
 }
 
 /// A dynamic structure with one or more fields.
@@ -148,6 +152,7 @@ impl SymbolLookup for Struct {
             type_value: field.type_value.clone(),
             acl: field.acl.clone(),
             deprecation_notice: field.deprecation_notice.clone(),
+            computed: field.computed,
         })
     }
 }
@@ -210,6 +215,7 @@ impl Struct {
                     number: 0,
                     acl: None,
                     deprecation_notice: None,
+                    computed: false,
                 });
 
             if let TypeValue::Struct(ref mut s) = field.type_value {
@@ -232,6 +238,7 @@ impl Struct {
                     number: 0,
                     acl: None,
                     deprecation_notice: None,
+                    computed: false,
                 },
             )
         }
@@ -327,6 +334,9 @@ impl Struct {
                 TypeValue::Array(a) => {
                     Rc::<Array>::get_mut(a).unwrap().enum_substructures(f);
                 }
+                TypeValue::Map(m) => {
+                    Rc::<Map>::get_mut(m).unwrap().enum_substructures(f);
+                }
                 _ => {}
             }
         }
@@ -398,7 +408,10 @@ impl Struct {
             let mut value = match field_ty {
                 RuntimeFieldType::Singular(ty) => Self::new_value(
                     &ty,
-                    msg.and_then(|msg| fd.get_singular(msg)),
+                    msg.and_then(|msg| {
+                        fd.get_singular(msg)
+                            .or_else(|| Self::default_value(&fd))
+                    }),
                     generate_fields_for_enums,
                     syntax,
                 ),
@@ -436,13 +449,14 @@ impl Struct {
                     type_value: value,
                     acl: Self::acl(&fd),
                     deprecation_notice: Self::deprecation_notice(&fd),
+                    computed: Self::computed(&fd),
                     number,
                 },
             ));
         }
 
         // Sort fields by field numbers specified in the proto.
-        fields.sort_by(|a, b| a.1.number.cmp(&b.1.number));
+        fields.sort_by_key(|a| a.1.number);
 
         // Insert the fields in a map, checking for duplicate fields.
         let mut field_index = IndexMap::new();
@@ -741,6 +755,40 @@ impl Struct {
             .unwrap_or(false)
     }
 
+    /// Given a [`FieldDescriptor`] returns `true` if the field's value is
+    /// computed by a method.
+    ///
+    /// Computed fields are annotated in the protobuf definition as follows:
+    ///
+    /// ```text
+    /// string foo = 1 [(yara.field_options).computed = true];
+    /// ```
+    fn computed(field_descriptor: &FieldDescriptor) -> bool {
+        field_options
+            .get(&field_descriptor.proto().options)
+            .and_then(|options| options.computed)
+            .unwrap_or(false)
+    }
+
+    /// Given a [`FieldDescriptor`] returns the default value for the field,
+    /// if the protobuf definition specifies one.
+    ///
+    /// In proto2, fields can have explicit default values, which are used
+    /// when the field is not set:
+    ///
+    /// ```text
+    /// optional int64 foo = 1 [default = 42];
+    /// ```
+    fn default_value(
+        field_descriptor: &FieldDescriptor,
+    ) -> Option<ReflectValueRef<'_>> {
+        field_descriptor
+            .proto()
+            .default_value
+            .is_some()
+            .then(|| field_descriptor.singular_default_value())
+    }
+
     /// Given a [`FieldDescriptor`] returns the information that must be
     /// shown if the field is deprecated.
     ///
@@ -1224,11 +1272,9 @@ impl From<&Module> for Rc<Struct> {
         if let Some(rust_module_name) = module.rust_module_name {
             let functions = WasmExport::get_functions(|export| {
                 export.public
-                    && export.rust_module_path.ends_with(rust_module_name)
+                    && export.is_from_rust_module(rust_module_name)
                     && export.method_of.is_none()
-            })
-            .into_iter()
-            .sorted_by_key(|(name, _)| *name);
+            });
 
             for (name, func) in functions {
                 let func = TypeValue::Func(Rc::new(func));
@@ -1243,16 +1289,45 @@ impl From<&Module> for Rc<Struct> {
         // Iterate over all substructures of the module's main structure and
         // add any methods defined for them.
         module_struct_mut.enum_substructures(&mut |sub_struct| {
-            let methods = sub_struct.protobuf_type_name().map(WasmExport::get_methods);
-            if let Some(methods) = methods {
-                for (name, func) in methods {
-                    let func = TypeValue::Func(Rc::new(func));
-                    if sub_struct.add_field(name, func).is_some() {
+            let Some(type_name) =
+                sub_struct.protobuf_type_name().map(String::from)
+            else {
+                return;
+            };
+            for (name, func) in WasmExport::get_methods(&type_name) {
+                // If the structure has a computed field with the same name
+                // as the method, the method is the one that computes the
+                // field's value.
+                if let Some(field) = sub_struct
+                    .field_by_name_mut(name)
+                    .filter(|field| field.computed)
+                {
+                    let ty = field.type_value.ty();
+                    if !func.signatures().iter().any(|signature| {
+                        signature.args.len() == 1
+                            && signature.result.ty() == ty
+                    }) {
                         panic!(
-                            "method `{name}` has the same name than a field in `{}`",
-                            sub_struct.protobuf_type_name().unwrap(),
+                            "method `{name}` can't compute field `{name}` in `{type_name}`, it must receive no arguments and return `{ty}`",
                         )
-                    };
+                    }
+                    field.type_value = TypeValue::Func(Rc::new(func));
+                    continue;
+                }
+                let func = TypeValue::Func(Rc::new(func));
+                if sub_struct.add_field(name, func).is_some() {
+                    panic!(
+                        "method `{name}` has the same name than a field in `{type_name}`",
+                    )
+                };
+            }
+            for (name, field) in sub_struct.fields() {
+                if field.computed
+                    && !matches!(field.type_value, TypeValue::Func(_))
+                {
+                    panic!(
+                        "computed field `{name}` in `{type_name}` doesn't have a method that computes its value",
+                    )
                 }
             }
         });
@@ -1309,6 +1384,8 @@ mod tests {
                 Some("test_proto2.TestProto2".to_string()),
                 Some("test_proto2.NestedProto2".to_string()),
                 Some("test_proto2.NestedProto2".to_string()),
+                Some("test_proto2.NestedProto2".to_string()),
+                Some("test_proto2.NestedProto2".to_string()),
                 None,
                 None,
                 None,
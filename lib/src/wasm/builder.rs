@@ -110,6 +110,19 @@ macro_rules! global_const {
 /// }
 /// ```
 ///
+/// The code for each rule is enclosed in a block that is skipped entirely if
+/// the rule's bit is set in the skipped rules bitmap (see
+/// [`crate::Scanner::set_rule_filter`]). Skipped rules don't match, but
+/// they don't prevent other rules in the namespace from matching either,
+/// even if they are global.
+///
+/// ```text
+/// block rule_n {
+///   br_if rule_n         ;; exit the block if the rule is skipped
+///   ... code for rule n
+/// }
+/// ```
+///
 /// Each of the functions containing rules (i.e: `rules_N`) return one of the
 /// following values:
 ///
@@ -125,11 +138,17 @@ pub(crate) struct WasmModuleBuilder {
     rules_func: FunctionBuilder,
     namespace_block: InstrSeqId,
     rule_id: RuleId,
+    /// Block containing the code for the rule that is currently being built.
+    rule_block: InstrSeqId,
     num_rules: usize,
     num_namespaces: usize,
     namespaces_per_func: usize,
     rules_per_func: usize,
     global_rule: bool,
+    /// Rules included in the rules function that is currently being built.
+    func_rules: Vec<RuleId>,
+    /// Functions containing rules, together with the rules they contain.
+    rule_funcs: Vec<(FunctionId, Vec<RuleId>)>,
 }
 
 impl WasmModuleBuilder {
@@ -156,8 +175,10 @@ impl WasmModuleBuilder {
         }
 
         global_const!(module, matching_patterns_bitmap_base, I32);
+        global_const!(module, skipped_rules_bitmap_base, I32);
         global_var!(module, filesize, I64);
         global_var!(module, pattern_search_done, I32);
+        global_var!(module, max_loop_iterations, I64);
 
         let (main_memory, _) = module.add_import_memory(
             "yara_x",
@@ -178,9 +199,11 @@ impl WasmModuleBuilder {
 
         let wasm_symbols = WasmSymbols {
             main_memory,
+            skipped_rules_bitmap_base,
             check_for_pattern_match,
             filesize,
             pattern_search_done,
+            max_loop_iterations,
             i64_tmp_a: module.locals.add(I64),
             i64_tmp_b: module.locals.add(I64),
             i32_tmp: module.locals.add(I32),
@@ -190,12 +213,14 @@ impl WasmModuleBuilder {
         let mut namespace_func =
             FunctionBuilder::new(&mut module.types, &[], &[]);
 
-        let rules_func = FunctionBuilder::new(
+        let mut rules_func = FunctionBuilder::new(
             &mut module.types,
             &[],
             &Self::RULES_FUNC_RET,
         );
 
+        let rule_block = rules_func.dangling_instr_seq(None).id();
+
         // The main function receives no arguments and returns an I32.
         let main_func = FunctionBuilder::new(&mut module.types, &[], &[I32]);
         let namespace_block = namespace_func.dangling_instr_seq(None).id();
@@ -209,11 +234,14 @@ impl WasmModuleBuilder {
             rules_func,
             namespace_block,
             rule_id: RuleId::default(),
+            rule_block,
             num_rules: 0,
             num_namespaces: 0,
             namespaces_per_func: 10,
             rules_per_func: 10,
             global_rule: false,
+            func_rules: Vec::new(),
+            rule_funcs: Vec::new(),
         }
     }
 
@@ -259,8 +287,31 @@ impl WasmModuleBuilder {
         self.num_rules += 1;
         self.rule_id = rule_id;
         self.global_rule = global;
-
-        self.rules_func.func_body()
+        self.func_rules.push(rule_id);
+
+        self.rule_block = self.rules_func.dangling_instr_seq(None).id();
+        self.rules_func.func_body().instr(Block { seq: self.rule_block });
+
+        let rule_id: usize = rule_id.into();
+        let mut instr = self.rules_func.instr_seq(self.rule_block);
+
+        // Load the byte in the skipped rules bitmap that contains the bit
+        // for this rule, and exit the block if the bit is set.
+        instr
+            .global_get(self.wasm_symbols.skipped_rules_bitmap_base)
+            .load(
+                self.wasm_symbols.main_memory,
+                LoadKind::I32_8 { kind: ZeroExtend },
+                MemArg {
+                    align: mem::size_of::<i8>() as u32,
+                    offset: (rule_id / 8) as u32,
+                },
+            )
+            .i32_const(1 << (rule_id % 8))
+            .binop(BinaryOp::I32And)
+            .br_if(self.rule_block);
+
+        instr
     }
 
     /// This finishes the code for a rule.
@@ -274,7 +325,7 @@ impl WasmModuleBuilder {
         let rule_match =
             self.function_id(wasm::export__rule_match.mangled_name);
 
-        let mut instr = self.rules_func.func_body();
+        let mut instr = self.rules_func.instr_seq(self.rule_block);
 
         // Check if the result from the condition is zero (false).
         instr.unop(UnaryOp::I32Eqz).if_else(
@@ -346,6 +397,48 @@ impl WasmModuleBuilder {
         self.module.exports.add("main", main_func);
         self.module
     }
+
+    /// Builds the WASM module like [`WasmModuleBuilder::build`], but gives
+    /// names to the functions in the module.
+    ///
+    /// The name of each function containing rules is built from the names
+    /// of the rules it contains, which are obtained by calling `rule_name`.
+    /// Imported functions are named after their fully qualified mangled
+    /// names. These names are stored in the module's name section, making
+    /// the module easier to read when converted to text format.
+    #[cfg(feature = "emit-wat")]
+    pub fn build_with_names<F>(mut self, rule_name: F) -> walrus::Module
+    where
+        F: Fn(RuleId) -> String,
+    {
+        self.finish_rule_func();
+
+        let rule_funcs = mem::take(&mut self.rule_funcs);
+        let wasm_exports = mem::take(&mut self.wasm_exports);
+        let check_for_pattern_match =
+            self.wasm_symbols.check_for_pattern_match;
+
+        let mut module = self.build();
+
+        for (name, func_id) in wasm_exports {
+            module.funcs.get_mut(func_id).name = Some(name);
+        }
+
+        module.funcs.get_mut(check_for_pattern_match).name =
+            Some("check_for_pattern_match".to_string());
+
+        for (func_id, rule_ids) in rule_funcs {
+            module.funcs.get_mut(func_id).name = Some(
+                rule_ids
+                    .into_iter()
+                    .map(&rule_name)
+                    .collect::<Vec<_>>()
+                    .join(","),
+            );
+        }
+
+        module
+    }
 }
 
 impl WasmModuleBuilder {
@@ -409,12 +502,16 @@ impl WasmModuleBuilder {
             // function exits early with a return value of 1.
             rule_func.func_body().i32_const(0);
 
+            let rule_func_id =
+                self.module.funcs.add_local(rule_func.local_func(Vec::new()));
+
+            self.rule_funcs
+                .push((rule_func_id, mem::take(&mut self.func_rules)));
+
             let mut namespace_block =
                 self.namespace_func.instr_seq(self.namespace_block);
 
-            namespace_block.call(
-                self.module.funcs.add_local(rule_func.local_func(Vec::new())),
-            );
+            namespace_block.call(rule_func_id);
 
             let namespace_block_id = namespace_block.id();
 
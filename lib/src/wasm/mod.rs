@@ -46,6 +46,11 @@ The memory of these WASM modules is organized as follows.
   │                          │
   :                          :
   │                          │
+  ├──────────────────────────┤
+  │ Skipped rules bitmap     │
+  │                          │
+  :                          :
+  │                          │
   └──────────────────────────┘
 ```
 
@@ -78,6 +83,7 @@ See the [`lookup_field`] function.
 
  */
 use std::any::{type_name, TypeId};
+use std::collections::BTreeMap;
 use std::mem;
 use std::ops::RangeInclusive;
 use std::rc::Rc;
@@ -86,7 +92,6 @@ use std::sync::{LazyLock, OnceLock};
 use bstr::{BString, ByteSlice};
 #[cfg(not(feature = "inventory"))]
 use linkme::distributed_slice;
-use rustc_hash::FxHashMap;
 use smallvec::{smallvec, SmallVec};
 use wasmtime::{
     AsContext, AsContextMut, Caller, Config, Engine, FuncType, Linker, ValRaw,
@@ -94,16 +99,16 @@ use wasmtime::{
 
 use yara_x_macros::wasm_export;
 
-use crate::compiler::{LiteralId, PatternId, RegexpId, RuleId};
+use crate::compiler::{Backend, LiteralId, PatternId, RegexpId, RuleId};
 use crate::modules::BUILTIN_MODULES;
 use crate::scanner::{RuntimeObjectHandle, ScanContext};
 use crate::types::{
     Array, Func, FuncSignature, Map, Struct, TypeValue, Value,
 };
+use crate::wasm;
 use crate::wasm::integer::RangedInteger;
 use crate::wasm::string::RuntimeString;
 use crate::wasm::string::String as _;
-use crate::{wasm, ScanError};
 
 pub(crate) mod builder;
 pub(crate) mod integer;
@@ -185,14 +190,30 @@ impl WasmExport {
         if self.method_of.is_some() {
             return self.mangled_name.to_string();
         }
-        for (module_name, module) in BUILTIN_MODULES.iter() {
-            if let Some(rust_module_name) = module.rust_module_name {
-                if self.rust_module_path.contains(rust_module_name) {
-                    return format!("{}.{}", module_name, self.mangled_name);
-                }
+        match self.module_name() {
+            Some(module_name) => {
+                format!("{}.{}", module_name, self.mangled_name)
             }
+            None => self.mangled_name.to_owned(),
         }
-        self.mangled_name.to_owned()
+    }
+
+    /// Returns the name of the YARA module that exports this function (e.g:
+    /// `pe`), or `None` if the function doesn't belong to any module.
+    pub fn module_name(&self) -> Option<&'static str> {
+        BUILTIN_MODULES.iter().find_map(|(module_name, module)| {
+            module
+                .rust_module_name
+                .filter(|name| self.is_from_rust_module(name))
+                .map(|_| *module_name)
+        })
+    }
+
+    /// Returns true if this export is defined in the Rust module with the
+    /// given name (e.g: `pe` for functions in `yara_x::modules::pe`).
+    pub fn is_from_rust_module(&self, rust_module_name: &str) -> bool {
+        self.rust_module_path.strip_prefix("yara_x::modules::")
+            == Some(rust_module_name)
     }
 
     /// Returns true if this export comes from YARA itself, not for a YARA
@@ -201,18 +222,19 @@ impl WasmExport {
         self.rust_module_path.strip_prefix("yara_x::modules::").is_none()
     }
 
-    /// Returns a hash map with all function exported to WASM that match the
+    /// Returns a map with all function exported to WASM that match the
     /// given predicate.
     ///
     /// Keys are function names and values are [`Func`] structures. Overloaded
     /// functions appear in the map as a single entry where the [`Func`] has
-    /// multiple signatures.
-    pub fn get_functions<P>(predicate: P) -> FxHashMap<&'static str, Func>
+    /// multiple signatures. The map is sorted by function name, so that
+    /// iterating over it produces the same results across builds, regardless
+    /// of the order in which the functions were exported.
+    pub fn get_functions<P>(predicate: P) -> BTreeMap<&'static str, Func>
     where
         P: FnMut(&&WasmExport) -> bool,
     {
-        let mut functions: FxHashMap<&'static str, Func> =
-            FxHashMap::default();
+        let mut functions: BTreeMap<&'static str, Func> = BTreeMap::new();
 
         // Iterate over the WASM exports looking for those that match the
         // predicate. Add them to `functions` map, or update the `Func`
@@ -239,13 +261,13 @@ impl WasmExport {
     /// the `module_export` macro. For instance, in the example below we
     /// specify that `some_method` is a method of `my_module.MyStructure`. If
     /// we call `get_methods` with `"my_module.MyStructure"` it returns
-    /// a hash map that contains a [`Func`] describing `some_method`.
+    /// a map that contains a [`Func`] describing `some_method`.
     ///
     /// ```text
     /// #[module_export(method_of = "my_module.MyStructure")]
     /// fn some_method(...) { ... }
     /// ```
-    pub fn get_methods(type_name: &str) -> FxHashMap<&'static str, Func> {
+    pub fn get_methods(type_name: &str) -> BTreeMap<&'static str, Func> {
         WasmExport::get_functions(|export| {
             export.method_of.is_some_and(|name| name == type_name)
         })
@@ -713,6 +735,10 @@ pub(crate) struct WasmSymbols {
     /// The WASM module's main memory.
     pub main_memory: walrus::MemoryId,
 
+    /// Global variable that contains the offset within the main memory
+    /// where the bitmap of skipped rules starts.
+    pub skipped_rules_bitmap_base: walrus::GlobalId,
+
     /// Function that checks if a pattern matched or not. This function
     /// receives the pattern ID and returns a boolean.
     pub check_for_pattern_match: walrus::FunctionId,
@@ -727,6 +753,11 @@ pub(crate) struct WasmSymbols {
     /// evaluated and some of them needs to know if a pattern matched or not.
     pub pattern_search_done: walrus::GlobalId,
 
+    /// Global variable that contains the maximum number of iterations for
+    /// loops over ranges whose bounds are not known at compile time, or -1
+    /// if there's no limit.
+    pub max_loop_iterations: walrus::GlobalId,
+
     /// Local variables used for temporary storage.
     pub i64_tmp_a: walrus::LocalId,
     pub i64_tmp_b: walrus::LocalId,
@@ -795,6 +826,45 @@ pub(crate) fn get_engine<'a>() -> &'a Engine {
     }
 }
 
+/// The global WASM engine used for rules that use [`Backend::Pulley`].
+///
+/// Like [`ENGINE`], it needs to be mutable so that [`free_engine`] can
+/// destroy it.
+#[cfg(feature = "pulley-backend")]
+static mut PULLEY_ENGINE: OnceLock<Engine> = OnceLock::new();
+
+/// Returns the global WASM engine used for the given backend.
+pub(crate) fn get_engine_for<'a>(backend: Backend) -> &'a Engine {
+    match backend {
+        Backend::Native => get_engine(),
+        #[cfg(feature = "pulley-backend")]
+        Backend::Pulley => unsafe {
+            // See the comment in `get_engine`.
+            #[allow(static_mut_refs)]
+            PULLEY_ENGINE.get_or_init(|| {
+                let mut config = CONFIG.clone();
+                let target = if cfg!(target_pointer_width = "64") {
+                    "pulley64"
+                } else {
+                    "pulley32"
+                };
+                config.target(target).unwrap();
+                Engine::new(&config).unwrap()
+            })
+        },
+    }
+}
+
+/// Increments the epoch of all the WASM engines that have been initialized.
+pub(crate) fn increment_epoch() {
+    get_engine().increment_epoch();
+    #[cfg(feature = "pulley-backend")]
+    #[allow(static_mut_refs)]
+    if let Some(engine) = unsafe { PULLEY_ENGINE.get() } {
+        engine.increment_epoch();
+    }
+}
+
 /// Frees the global WASM engine.
 ///
 /// This function only needs to be called in a very specific scenario:
@@ -819,6 +889,12 @@ pub(crate) fn get_engine<'a>() -> &'a Engine {
 ///   `wasmtime` cannot reliably restore the original state, which may lead
 ///   to undefined behavior.
 pub(crate) unsafe fn free_engine() {
+    // The engine used by `Backend::Pulley` doesn't install signal handlers,
+    // it only needs to be dropped.
+    #[cfg(feature = "pulley-backend")]
+    #[allow(static_mut_refs)]
+    drop(PULLEY_ENGINE.take());
+
     // `unload_process_handlers` is called only in the platforms where
     // wasmtime actually installs signal handlers. See:
     // https://github.com/bytecodealliance/wasmtime/blob/9362e47987363c350a8d9d09aa56677425496fb7/crates/wasmtime/build.rs#L20
@@ -834,8 +910,10 @@ pub(crate) unsafe fn free_engine() {
     }
 }
 
-pub(crate) fn new_linker() -> Linker<ScanContext<'static, 'static>> {
-    let engine = get_engine();
+pub(crate) fn new_linker(
+    backend: Backend,
+) -> Linker<ScanContext<'static, 'static>> {
+    let engine = get_engine_for(backend);
     let mut linker = Linker::<ScanContext<'static, 'static>>::new(engine);
 
     for export in wasm_exports() {
@@ -875,11 +953,9 @@ pub(crate) fn search_for_patterns(caller: &mut Caller<'_, ScanContext>) {
     // WASM runtime to raise its own timeout by setting the epoch
     // deadline to 0 (immediate expiry). This forces the WASM runtime
     // to abort the execution of rule conditions as soon as possible
-    // after the timeout was detected by `search_for_patterns`.
-    if matches!(
-        caller.data_mut().search_for_patterns(),
-        Err(ScanError::Timeout)
-    ) {
+    // after the timeout was detected by `search_for_patterns`. The same
+    // happens if the memory limit was exceeded or the scan was cancelled.
+    if caller.data_mut().search_for_patterns().is_err() {
         caller.as_context_mut().set_epoch_deadline(0);
     }
 }
@@ -902,6 +978,21 @@ pub(crate) fn rule_no_match(
     caller.data_mut().track_rule_no_match(rule_id);
 }
 
+/// Invoked from WASM before executing a `for` loop whose number of
+/// iterations is not known at compile time.
+///
+/// Receives the number of iterations `n` and returns the number of
+/// iterations that the loop will actually perform, which is limited by
+/// [`crate::Scanner::max_loop_iterations`].
+#[wasm_export]
+pub(crate) fn loop_iterations(
+    caller: &mut Caller<'_, ScanContext>,
+    rule_id: RuleId,
+    n: i64,
+) -> i64 {
+    caller.data_mut().limit_loop_iterations(rule_id, n)
+}
+
 /// Invoked from WASM to ask whether a pattern matches at a given file
 /// offset.
 ///
@@ -1108,6 +1199,11 @@ pub(crate) fn map_len(_: &mut Caller<'_, ScanContext>, map: Rc<Map>) -> i64 {
 /// The sequence of indexes is stored in WASM main memory, starting at
 /// `LOOKUP_INDEXES_START`, and the number of indexes is indicated by the
 /// argument `num_lookup_indexes`.
+///
+/// The results of lookups that start at the root structure are cached in
+/// [`ScanContext::field_lookup_cache`] for the rest of the scan, so that
+/// multiple rules accessing the same field (e.g. `pe.entry_point`) don't
+/// walk the structure again.
 fn lookup_field(
     caller: &mut Caller<'_, ScanContext>,
     structure: Option<Rc<Struct>>,
@@ -1133,6 +1229,16 @@ fn lookup_field(
         )
     };
 
+    let starts_at_root = structure.is_none();
+
+    if starts_at_root {
+        if let Some(type_value) =
+            store_ctx.data().field_lookup_cache.get(lookup_indexes)
+        {
+            return type_value.clone();
+        }
+    }
+
     // If the passed structure is None, it means that we should start the
     // at the root structure.
     let mut structure =
@@ -1166,7 +1272,16 @@ fn lookup_field(
         }
     }
 
-    final_field.unwrap().type_value.clone()
+    let type_value = final_field.unwrap().type_value.clone();
+
+    if starts_at_root {
+        store_ctx
+            .data_mut()
+            .field_lookup_cache
+            .insert(lookup_indexes.to_vec(), type_value.clone());
+    }
+
+    type_value
 }
 
 /// Lookup a field of string type and returns its value.
@@ -1209,6 +1324,97 @@ pub(crate) fn lookup_object(
     }
 }
 
+/// Looks up multiple fields in a structure, storing their values in
+/// variables.
+///
+/// This is used for reducing the number of calls to the host when the code
+/// accesses multiple fields of the same structure (see
+/// [`crate::compiler::IR::bulk_lookups`]). The memory area that starts at
+/// `LOOKUP_INDEXES_START` contains `num_lookups` entries, each entry
+/// consisting of the following `i32` values:
+///
+/// - The index of the variable where the field's value is stored.
+/// - The number of field indexes that follow.
+/// - The field indexes that form the path from `structure` to the field,
+///   as in [`lookup_field`].
+///
+/// The variables are flagged as undefined if the field's value is unknown.
+#[wasm_export]
+pub(crate) fn lookup_fields(
+    caller: &mut Caller<'_, ScanContext>,
+    structure: Rc<Struct>,
+    num_lookups: i32,
+) {
+    let mut store_ctx = caller.as_context_mut();
+    let main_memory = store_ctx.data().wasm_main_memory.unwrap();
+
+    let words: Vec<i32> = main_memory.data(&store_ctx)
+        [LOOKUP_INDEXES_START as usize..LOOKUP_INDEXES_END as usize]
+        .chunks_exact(size_of::<i32>())
+        .map(|word| i32::from_le_bytes(word.try_into().unwrap()))
+        .collect();
+
+    let ctx = store_ctx.data_mut();
+    let mut words = words.into_iter();
+    let mut values = Vec::with_capacity(num_lookups as usize);
+
+    for _ in 0..num_lookups {
+        let var_index = words.next().unwrap();
+        let path_len = words.next().unwrap() as usize;
+
+        let mut current = structure.as_ref();
+        let mut type_value = None;
+
+        for field_index in words.by_ref().take(path_len) {
+            let field = current.field_by_index(field_index as usize).unwrap();
+            if let TypeValue::Struct(s) = &field.type_value {
+                current = s;
+            }
+            type_value = Some(&field.type_value);
+        }
+
+        // Convert the value to the representation it has in WASM.
+        let value = match type_value.unwrap() {
+            TypeValue::Integer { value, .. } => value.extract().cloned(),
+            TypeValue::Float { value } => {
+                value.extract().map(|v| v.to_bits() as i64)
+            }
+            TypeValue::Bool { value } => value.extract().map(|v| *v as i64),
+            TypeValue::String { value, .. } => value
+                .extract()
+                .map(|s| RuntimeString::Rc(s.clone()).into_wasm_with_ctx(ctx)),
+            _ => unreachable!(),
+        };
+
+        values.push((var_index, value));
+    }
+
+    let mem = main_memory.data_mut(&mut store_ctx);
+
+    for (var_index, value) in values {
+        // The flags that indicate if variables are undefined use the same
+        // layout as in `emit::set_var_undef`.
+        let flags_offset = (var_index / 64) as usize;
+        let flags_range = flags_offset..flags_offset + size_of::<i64>();
+        let mut flags =
+            i64::from_le_bytes(mem[flags_range.clone()].try_into().unwrap());
+
+        let bit = 1i64 << (var_index % 64);
+
+        if let Some(value) = value {
+            let var_offset = VARS_STACK_START as usize
+                + var_index as usize * size_of::<i64>();
+            mem[var_offset..var_offset + size_of::<i64>()]
+                .copy_from_slice(&value.to_le_bytes());
+            flags &= !bit;
+        } else {
+            flags |= bit;
+        }
+
+        mem[flags_range].copy_from_slice(&flags.to_le_bytes());
+    }
+}
+
 macro_rules! gen_lookup_fn {
     ($name:ident, $return_type:ty, $type:path) => {
         #[wasm_export]
@@ -1599,6 +1805,52 @@ pub(crate) fn str_len(
     s.len(caller.data()) as i64
 }
 
+/// Returns the byte at the given index in a string, or undefined if the
+/// index is out of bounds. This implements the `s[i]` indexing operation.
+#[wasm_export]
+pub(crate) fn str_index(
+    caller: &mut Caller<'_, ScanContext>,
+    s: RuntimeString,
+    index: i64,
+) -> Option<RangedInteger<0, 255>> {
+    let index = usize::try_from(index).ok()?;
+    let byte = s.as_bstr(caller.data()).get(index).copied()?;
+    Some(RangedInteger::new(byte as i64))
+}
+
+/// Returns the substring that starts at `offset` and has at most `length`
+/// bytes. The result is undefined if `offset` is larger than the length of
+/// the string or if any of the arguments is negative.
+#[wasm_export(public = true)]
+pub(crate) fn substr(
+    caller: &mut Caller<'_, ScanContext>,
+    s: RuntimeString,
+    offset: i64,
+    length: i64,
+) -> Option<RuntimeString> {
+    let offset = usize::try_from(offset).ok()?;
+    let length = usize::try_from(length).ok()?;
+    let ctx = caller.data();
+    let tail = s.as_bstr(ctx).get(offset..)?;
+    let substr = &tail[..length.min(tail.len())];
+    Some(RuntimeString::from_slice(ctx, substr))
+}
+
+/// Returns the substring that goes from `offset` to the end of the string.
+/// The result is undefined if `offset` is negative or larger than the length
+/// of the string.
+#[wasm_export(name = "substr", public = true)]
+pub(crate) fn substr_to_end(
+    caller: &mut Caller<'_, ScanContext>,
+    s: RuntimeString,
+    offset: i64,
+) -> Option<RuntimeString> {
+    let offset = usize::try_from(offset).ok()?;
+    let ctx = caller.data();
+    let tail = s.as_bstr(ctx).get(offset..)?;
+    Some(RuntimeString::from_slice(ctx, tail))
+}
+
 #[wasm_export]
 pub(crate) fn str_matches(
     caller: &mut Caller<'_, ScanContext>,
@@ -1609,6 +1861,16 @@ pub(crate) fn str_matches(
     ctx.regexp_matches(rhs, lhs.as_bstr(ctx))
 }
 
+#[wasm_export(public = true)]
+pub(crate) fn count_matches(
+    caller: &mut Caller<'_, ScanContext>,
+    s: RuntimeString,
+    regexp: RegexpId,
+) -> i64 {
+    let ctx = caller.data();
+    ctx.regexp_count_matches(regexp, s.as_bstr(ctx)) as i64
+}
+
 macro_rules! gen_int_fn {
     ($name:ident, $return_type:ty, $from_fn:ident, $min:expr, $max:expr) => {
         #[wasm_export(public = true)]
@@ -1666,3 +1928,115 @@ gen_float_fn!(float32, f32, from_le_bytes);
 gen_float_fn!(float64, f64, from_le_bytes);
 gen_float_fn!(float32be, f32, from_be_bytes);
 gen_float_fn!(float64be, f64, from_be_bytes);
+
+#[wasm_export(name = "min", public = true)]
+pub(crate) fn min_i64(
+    _caller: &mut Caller<'_, ScanContext>,
+    a: i64,
+    b: i64,
+) -> i64 {
+    i64::min(a, b)
+}
+
+#[wasm_export(name = "min", public = true)]
+pub(crate) fn min_f64(
+    _caller: &mut Caller<'_, ScanContext>,
+    a: f64,
+    b: f64,
+) -> f64 {
+    f64::min(a, b)
+}
+
+#[wasm_export(name = "max", public = true)]
+pub(crate) fn max_i64(
+    _caller: &mut Caller<'_, ScanContext>,
+    a: i64,
+    b: i64,
+) -> i64 {
+    i64::max(a, b)
+}
+
+#[wasm_export(name = "max", public = true)]
+pub(crate) fn max_f64(
+    _caller: &mut Caller<'_, ScanContext>,
+    a: f64,
+    b: f64,
+) -> f64 {
+    f64::max(a, b)
+}
+
+/// Returns the absolute value of `x`, or undefined if `x` is the minimum
+/// integer value, as its absolute value can't be represented.
+#[wasm_export(name = "abs", public = true)]
+pub(crate) fn abs_i64(
+    _caller: &mut Caller<'_, ScanContext>,
+    x: i64,
+) -> Option<i64> {
+    x.checked_abs()
+}
+
+#[wasm_export(name = "abs", public = true)]
+pub(crate) fn abs_f64(_caller: &mut Caller<'_, ScanContext>, x: f64) -> f64 {
+    x.abs()
+}
+
+/// Returns the number of bits set to 1 in `x`.
+#[wasm_export(public = true)]
+pub(crate) fn popcount(_caller: &mut Caller<'_, ScanContext>, x: i64) -> i64 {
+    x.count_ones() as i64
+}
+
+/// Rotates the `width` least significant bits of `x` by `n` bits to the
+/// left. `width` must be 8, 16, 32 or 64, otherwise the result is undefined.
+/// A negative `n` rotates to the right.
+fn rotate_left(x: i64, n: i64, width: i64) -> Option<i64> {
+    if !matches!(width, 8 | 16 | 32 | 64) {
+        return None;
+    }
+    let n = n.rem_euclid(width) as u32;
+    let result = match width {
+        8 => (x as u8).rotate_left(n) as i64,
+        16 => (x as u16).rotate_left(n) as i64,
+        32 => (x as u32).rotate_left(n) as i64,
+        _ => x.rotate_left(n),
+    };
+    Some(result)
+}
+
+#[wasm_export(name = "rotl", public = true)]
+pub(crate) fn rotl(
+    _caller: &mut Caller<'_, ScanContext>,
+    x: i64,
+    n: i64,
+) -> i64 {
+    x.rotate_left(n.rem_euclid(64) as u32)
+}
+
+#[wasm_export(name = "rotl", public = true)]
+pub(crate) fn rotl_width(
+    _caller: &mut Caller<'_, ScanContext>,
+    x: i64,
+    n: i64,
+    width: i64,
+) -> Option<i64> {
+    rotate_left(x, n, width)
+}
+
+#[wasm_export(name = "rotr", public = true)]
+pub(crate) fn rotr(
+    _caller: &mut Caller<'_, ScanContext>,
+    x: i64,
+    n: i64,
+) -> i64 {
+    x.rotate_right(n.rem_euclid(64) as u32)
+}
+
+#[wasm_export(name = "rotr", public = true)]
+pub(crate) fn rotr_width(
+    _caller: &mut Caller<'_, ScanContext>,
+    x: i64,
+    n: i64,
+    width: i64,
+) -> Option<i64> {
+    rotate_left(x, n.wrapping_neg(), width)
+}
@@ -9,6 +9,7 @@ use bitflags::bitflags;
 use dashmap::mapref::one::Ref;
 
 use yara_x_parser::cst::{Immutable, SyntaxKind, Token, Utf16};
+use yara_x_parser::highlight::{classify, TokenClass};
 
 use crate::documents::document::Document;
 use crate::documents::storage::DocumentStorage;
@@ -59,114 +60,24 @@ fn token_type_index(value: SemanticTokenType) -> u32 {
 fn token_type(
     token: &Token<Immutable>,
 ) -> Option<(SemanticTokenType, Modifiers)> {
-    match token.kind() {
-        SyntaxKind::ALL_KW
-        | SyntaxKind::AND_KW
-        | SyntaxKind::ANY_KW
-        | SyntaxKind::ASCII_KW
-        | SyntaxKind::BASE64_KW
-        | SyntaxKind::BASE64WIDE_KW
-        | SyntaxKind::CONDITION_KW
-        | SyntaxKind::CONTAINS_KW
-        | SyntaxKind::DEFINED_KW
-        | SyntaxKind::ENDSWITH_KW
-        | SyntaxKind::ENTRYPOINT_KW
-        | SyntaxKind::FALSE_KW
-        | SyntaxKind::FILESIZE_KW
-        | SyntaxKind::FOR_KW
-        | SyntaxKind::FULLWORD_KW
-        | SyntaxKind::GLOBAL_KW
-        | SyntaxKind::ICONTAINS_KW
-        | SyntaxKind::IENDSWITH_KW
-        | SyntaxKind::IEQUALS_KW
-        | SyntaxKind::IMPORT_KW
-        | SyntaxKind::IN_KW
-        | SyntaxKind::INCLUDE_KW
-        | SyntaxKind::ISTARTSWITH_KW
-        | SyntaxKind::MATCHES_KW
-        | SyntaxKind::META_KW
-        | SyntaxKind::NOCASE_KW
-        | SyntaxKind::NONE_KW
-        | SyntaxKind::NOT_KW
-        | SyntaxKind::OF_KW
-        | SyntaxKind::OR_KW
-        | SyntaxKind::PRIVATE_KW
-        | SyntaxKind::RULE_KW
-        | SyntaxKind::STARTSWITH_KW
-        | SyntaxKind::STRINGS_KW
-        | SyntaxKind::THEM_KW
-        | SyntaxKind::TRUE_KW
-        | SyntaxKind::WIDE_KW
-        | SyntaxKind::XOR_KW
-        | SyntaxKind::WITH_KW => {
-            Some((SemanticTokenType::KEYWORD, Modifiers::empty()))
-        }
-
-        SyntaxKind::ADD
-        | SyntaxKind::SUB
-        | SyntaxKind::MUL
-        | SyntaxKind::DIV
-        | SyntaxKind::MOD
-        | SyntaxKind::MINUS
-        | SyntaxKind::SHL
-        | SyntaxKind::SHR
-        | SyntaxKind::BITWISE_AND
-        | SyntaxKind::BITWISE_OR
-        | SyntaxKind::BITWISE_XOR
-        | SyntaxKind::BITWISE_NOT
-        | SyntaxKind::EQ
-        | SyntaxKind::NE
-        | SyntaxKind::LT
-        | SyntaxKind::LE
-        | SyntaxKind::GT
-        | SyntaxKind::GE => {
-            Some((SemanticTokenType::OPERATOR, Modifiers::empty()))
-        }
-
-        SyntaxKind::REGEXP => {
-            Some((SemanticTokenType::REGEXP, Modifiers::empty()))
-        }
-
-        SyntaxKind::STRING_LIT => {
-            Some((SemanticTokenType::STRING, Modifiers::empty()))
-        }
-
-        SyntaxKind::INTEGER_LIT | SyntaxKind::FLOAT_LIT => {
-            Some((SemanticTokenType::NUMBER, Modifiers::empty()))
-        }
-
-        SyntaxKind::COMMENT => {
-            Some((SemanticTokenType::COMMENT, Modifiers::empty()))
-        }
-
-        SyntaxKind::IDENT => match token.parent().unwrap().kind() {
-            SyntaxKind::RULE_DECL => {
-                Some((SemanticTokenType::CLASS, Modifiers::empty()))
-            }
-            SyntaxKind::WITH_DECL => {
-                Some((SemanticTokenType::VARIABLE, Modifiers::Definition))
-            }
-            SyntaxKind::FUNC_CALL => {
-                Some((SemanticTokenType::FUNCTION, Modifiers::empty()))
-            }
-            _ => Some((SemanticTokenType::VARIABLE, Modifiers::empty())),
-        },
-
-        SyntaxKind::PATTERN_IDENT => match token.parent().unwrap().kind() {
-            SyntaxKind::PATTERN_DEF => {
-                Some((SemanticTokenType::VARIABLE, Modifiers::Definition))
-            }
-            _ => Some((SemanticTokenType::VARIABLE, Modifiers::empty())),
-        },
-
-        SyntaxKind::PATTERN_COUNT
-        | SyntaxKind::PATTERN_OFFSET
-        | SyntaxKind::PATTERN_LENGTH => {
-            Some((SemanticTokenType::VARIABLE, Modifiers::empty()))
-        }
-
-        _ => None,
-    }
+    let token = classify(token)?;
+    let token_type = match token.class {
+        TokenClass::Keyword => SemanticTokenType::KEYWORD,
+        TokenClass::Operator => SemanticTokenType::OPERATOR,
+        TokenClass::Number => SemanticTokenType::NUMBER,
+        TokenClass::String => SemanticTokenType::STRING,
+        TokenClass::Regexp => SemanticTokenType::REGEXP,
+        TokenClass::Comment => SemanticTokenType::COMMENT,
+        TokenClass::Rule => SemanticTokenType::CLASS,
+        TokenClass::Function => SemanticTokenType::FUNCTION,
+        _ => SemanticTokenType::VARIABLE,
+    };
+    let modifiers = if token.definition {
+        Modifiers::Definition
+    } else {
+        Modifiers::empty()
+    };
+    Some((token_type, modifiers))
 }
 
 /// An iterator that returns semantic tokens given a CST.
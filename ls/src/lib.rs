@@ -37,3 +37,23 @@ pub async fn serve(
 
     server.run_buffered(input, output).await
 }
+
+/// Starts the Language Server Main Loop using Standard Input Output.
+#[cfg(not(target_family = "wasm"))]
+pub async fn serve_stdio() -> Result<(), async_lsp::Error> {
+    #[cfg(unix)]
+    let (stdin, stdout) = (
+        async_lsp::stdio::PipeStdin::lock_tokio()?,
+        async_lsp::stdio::PipeStdout::lock_tokio()?,
+    );
+
+    #[cfg(not(unix))]
+    let (stdin, stdout) = (
+        tokio_util::compat::TokioAsyncReadCompatExt::compat(tokio::io::stdin()),
+        tokio_util::compat::TokioAsyncWriteCompatExt::compat_write(
+            tokio::io::stdout(),
+        ),
+    );
+
+    serve(stdin, stdout).await
+}
@@ -0,0 +1,606 @@
+/*! Programmatic construction of YARA rules.
+
+[`RuleBuilder`] generates the source code for a YARA rule from its parts,
+taking care of escaping strings and validating identifiers, so that rules
+can be generated safely without resorting to string templates.
+
+```
+use yara_x_parser::ast::builder::{Condition, Pattern, RuleBuilder};
+
+let rule = RuleBuilder::new("foo")
+    .tag("apt")
+    .meta("author", "Jane \"JD\" Doe")
+    .pattern("$a", Pattern::text("foo\n").ascii().wide())
+    .pattern("$b", Pattern::hex([0x4D, 0x5A]))
+    .condition(Condition::pattern("$a").and(Condition::pattern("$b")))
+    .build()
+    .unwrap();
+
+assert_eq!(
+    rule,
+    r#"rule foo : apt {
+  meta:
+    author = "Jane \"JD\" Doe"
+  strings:
+    $a = "foo\n" ascii wide
+    $b = { 4D 5A }
+  condition:
+    $a and $b
+}
+"#
+);
+```
+
+The generated source code is parsed before being returned, which guarantees
+that it is syntactically valid and contains exactly the rule described by the
+builder. Semantic errors, like using a modifier that is not accepted by some
+kind of pattern, are detected only when the rule is compiled.
+*/
+
+use std::fmt::{Display, Formatter, Write};
+
+use itertools::Itertools;
+
+use crate::ast::{Item, AST};
+
+/// Error returned by [`RuleBuilder::build`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum BuildError {
+    /// Invalid identifier for a rule, tag or metadata entry.
+    InvalidIdentifier(String),
+    /// Invalid pattern identifier.
+    InvalidPatternIdentifier(String),
+    /// The same pattern identifier was used more than once.
+    DuplicatePattern(String),
+    /// Hex pattern containing characters that are not allowed.
+    InvalidHexPattern(String),
+    /// The rule doesn't have a condition.
+    MissingCondition,
+    /// The generated source code is not a single valid rule. This happens
+    /// when some raw fragment of source code, like the ones passed to
+    /// [`Condition::raw`] or [`Pattern::hex_str`], is invalid.
+    SyntaxError(String),
+}
+
+impl Display for BuildError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BuildError::InvalidIdentifier(ident) => {
+                write!(f, "invalid identifier `{ident}`")
+            }
+            BuildError::InvalidPatternIdentifier(ident) => {
+                write!(f, "invalid pattern identifier `{ident}`")
+            }
+            BuildError::DuplicatePattern(ident) => {
+                write!(f, "duplicate pattern `{ident}`")
+            }
+            BuildError::InvalidHexPattern(hex) => {
+                write!(f, "invalid hex pattern `{hex}`")
+            }
+            BuildError::MissingCondition => {
+                write!(f, "the rule doesn't have a condition")
+            }
+            BuildError::SyntaxError(src) => {
+                write!(f, "the generated rule is invalid:\n{src}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// A value for a metadata entry.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+
+impl From<i64> for Value {
+    fn from(value: i64) -> Self {
+        Value::Integer(value)
+    }
+}
+
+impl From<i32> for Value {
+    fn from(value: i32) -> Self {
+        Value::Integer(value.into())
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Value::Float(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::String(value.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::String(value)
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Bool(b) => write!(f, "{b}"),
+            Value::Integer(i) => write!(f, "{i}"),
+            Value::Float(n) => write!(f, "{n:?}"),
+            Value::String(s) => write!(f, "\"{}\"", escape_str(s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum PatternKind {
+    Text(Vec<u8>),
+    Hex(String),
+    Regexp(String),
+}
+
+/// A pattern (a.k.a. string) for [`RuleBuilder::pattern`].
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    kind: PatternKind,
+    modifiers: Vec<String>,
+}
+
+impl Pattern {
+    /// Creates a text pattern that matches the given bytes.
+    ///
+    /// Bytes that are not printable ASCII characters are escaped.
+    pub fn text<B: AsRef<[u8]>>(bytes: B) -> Self {
+        Self::new(PatternKind::Text(bytes.as_ref().to_vec()))
+    }
+
+    /// Creates a hex pattern that matches exactly the given bytes.
+    pub fn hex<B: AsRef<[u8]>>(bytes: B) -> Self {
+        let hex = bytes.as_ref().iter().map(|b| format!("{b:02X}")).join(" ");
+        Self::new(PatternKind::Hex(hex))
+    }
+
+    /// Creates a hex pattern from its textual representation, without the
+    /// enclosing braces, like `4D 5A ?? [2-4] (90 | 91)`.
+    pub fn hex_str<S: Into<String>>(hex: S) -> Self {
+        Self::new(PatternKind::Hex(hex.into()))
+    }
+
+    /// Creates a regular expression pattern.
+    ///
+    /// The regular expression must not include the enclosing slashes.
+    /// Slashes inside the regular expression are escaped.
+    pub fn regexp<S: Into<String>>(regexp: S) -> Self {
+        Self::new(PatternKind::Regexp(regexp.into()))
+    }
+
+    /// Adds the `ascii` modifier.
+    pub fn ascii(self) -> Self {
+        self.modifier("ascii")
+    }
+
+    /// Adds the `wide` modifier.
+    pub fn wide(self) -> Self {
+        self.modifier("wide")
+    }
+
+    /// Adds the `nocase` modifier.
+    pub fn nocase(self) -> Self {
+        self.modifier("nocase")
+    }
+
+    /// Adds the `fullword` modifier.
+    pub fn fullword(self) -> Self {
+        self.modifier("fullword")
+    }
+
+    /// Adds the `private` modifier.
+    pub fn private(self) -> Self {
+        self.modifier("private")
+    }
+
+    /// Adds the `xor` modifier.
+    pub fn xor(self) -> Self {
+        self.modifier("xor")
+    }
+
+    /// Adds the `xor` modifier with a range of keys.
+    pub fn xor_range(self, start: u8, end: u8) -> Self {
+        self.modifier(format!("xor({start}-{end})"))
+    }
+
+    /// Adds the `base64` modifier.
+    pub fn base64(self) -> Self {
+        self.modifier("base64")
+    }
+
+    /// Adds the `base64wide` modifier.
+    pub fn base64wide(self) -> Self {
+        self.modifier("base64wide")
+    }
+
+    fn new(kind: PatternKind) -> Self {
+        Self { kind, modifiers: Vec::new() }
+    }
+
+    fn modifier<S: Into<String>>(mut self, modifier: S) -> Self {
+        self.modifiers.push(modifier.into());
+        self
+    }
+}
+
+impl Display for Pattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            PatternKind::Text(bytes) => {
+                write!(f, "\"{}\"", escape_bytes(bytes))?
+            }
+            PatternKind::Hex(hex) => write!(f, "{{ {} }}", hex.trim())?,
+            PatternKind::Regexp(regexp) => {
+                write!(f, "/{}/", escape_slashes(regexp))?
+            }
+        }
+        for modifier in &self.modifiers {
+            write!(f, " {modifier}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Operator precedence, used for deciding where parentheses are required.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Precedence {
+    Or,
+    And,
+    Not,
+    Atom,
+}
+
+/// A rule condition for [`RuleBuilder::condition`].
+///
+/// Conditions can be combined with [`Condition::and`], [`Condition::or`]
+/// and [`Condition::not`], parentheses are added where needed.
+#[derive(Debug, Clone)]
+pub struct Condition {
+    expr: String,
+    precedence: Precedence,
+}
+
+impl Condition {
+    /// A condition that is always `true` or `false`.
+    pub fn bool(value: bool) -> Self {
+        Self::atom(value.to_string())
+    }
+
+    /// A condition that is true if the given pattern matches.
+    ///
+    /// The identifier must include the `$` prefix.
+    pub fn pattern(ident: &str) -> Self {
+        Self::atom(ident.to_string())
+    }
+
+    /// `all of them`.
+    pub fn all_of_them() -> Self {
+        Self::atom("all of them".to_string())
+    }
+
+    /// `any of them`.
+    pub fn any_of_them() -> Self {
+        Self::atom("any of them".to_string())
+    }
+
+    /// `filesize < size`.
+    pub fn filesize_lt(size: u64) -> Self {
+        Self::atom(format!("filesize < {size}"))
+    }
+
+    /// `filesize > size`.
+    pub fn filesize_gt(size: u64) -> Self {
+        Self::atom(format!("filesize > {size}"))
+    }
+
+    /// A condition given as YARA source code, like `#a > 2`.
+    ///
+    /// The expression is enclosed in parentheses when combined with other
+    /// conditions.
+    pub fn raw<S: Into<String>>(expr: S) -> Self {
+        Self { expr: expr.into(), precedence: Precedence::Or }
+    }
+
+    /// `self and other`.
+    pub fn and(self, other: Condition) -> Self {
+        Self {
+            expr: format!(
+                "{} and {}",
+                self.operand(Precedence::And),
+                other.operand(Precedence::And)
+            ),
+            precedence: Precedence::And,
+        }
+    }
+
+    /// `self or other`.
+    pub fn or(self, other: Condition) -> Self {
+        Self {
+            expr: format!(
+                "{} or {}",
+                self.operand(Precedence::Or),
+                other.operand(Precedence::Or)
+            ),
+            precedence: Precedence::Or,
+        }
+    }
+
+    /// `not self`.
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Self {
+        Self {
+            expr: format!("not {}", self.operand(Precedence::Not)),
+            precedence: Precedence::Not,
+        }
+    }
+
+    fn atom(expr: String) -> Self {
+        Self { expr, precedence: Precedence::Atom }
+    }
+
+    /// Returns the expression as an operand for an operator with the given
+    /// precedence, enclosing it in parentheses if necessary.
+    fn operand(&self, precedence: Precedence) -> String {
+        if self.precedence < precedence {
+            format!("({})", self.expr)
+        } else {
+            self.expr.clone()
+        }
+    }
+}
+
+/// Builds the source code for a YARA rule.
+///
+/// See the [module documentation][crate::ast::builder] for an example.
+#[derive(Debug, Clone)]
+pub struct RuleBuilder {
+    identifier: String,
+    private: bool,
+    global: bool,
+    tags: Vec<String>,
+    meta: Vec<(String, Value)>,
+    patterns: Vec<(String, Pattern)>,
+    condition: Option<Condition>,
+}
+
+impl RuleBuilder {
+    /// Creates a builder for a rule with the given identifier.
+    pub fn new<S: Into<String>>(identifier: S) -> Self {
+        Self {
+            identifier: identifier.into(),
+            private: false,
+            global: false,
+            tags: Vec::new(),
+            meta: Vec::new(),
+            patterns: Vec::new(),
+            condition: None,
+        }
+    }
+
+    /// Makes the rule private.
+    pub fn private(mut self) -> Self {
+        self.private = true;
+        self
+    }
+
+    /// Makes the rule global.
+    pub fn global(mut self) -> Self {
+        self.global = true;
+        self
+    }
+
+    /// Adds a tag to the rule.
+    pub fn tag<S: Into<String>>(mut self, tag: S) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Adds a metadata entry to the rule.
+    pub fn meta<S: Into<String>, V: Into<Value>>(
+        mut self,
+        identifier: S,
+        value: V,
+    ) -> Self {
+        self.meta.push((identifier.into(), value.into()));
+        self
+    }
+
+    /// Adds a pattern to the rule. The identifier must include the `$`
+    /// prefix.
+    pub fn pattern<S: Into<String>>(
+        mut self,
+        identifier: S,
+        pattern: Pattern,
+    ) -> Self {
+        self.patterns.push((identifier.into(), pattern));
+        self
+    }
+
+    /// Sets the rule's condition.
+    pub fn condition(mut self, condition: Condition) -> Self {
+        self.condition = Some(condition);
+        self
+    }
+
+    /// Returns the source code for the rule.
+    pub fn build(&self) -> Result<String, BuildError> {
+        validate_identifier(&self.identifier)?;
+
+        for tag in &self.tags {
+            validate_identifier(tag)?;
+        }
+
+        for (identifier, _) in &self.meta {
+            validate_identifier(identifier)?;
+        }
+
+        for (i, (identifier, pattern)) in self.patterns.iter().enumerate() {
+            let name = identifier.strip_prefix('$').ok_or_else(|| {
+                BuildError::InvalidPatternIdentifier(identifier.clone())
+            })?;
+
+            if !name.is_empty() && validate_identifier(name).is_err() {
+                return Err(BuildError::InvalidPatternIdentifier(
+                    identifier.clone(),
+                ));
+            }
+
+            if self.patterns[..i].iter().any(|(ident, _)| ident == identifier)
+            {
+                return Err(BuildError::DuplicatePattern(identifier.clone()));
+            }
+
+            if let PatternKind::Hex(hex) = &pattern.kind {
+                if !hex.chars().all(|c| {
+                    c.is_ascii_hexdigit()
+                        || c.is_ascii_whitespace()
+                        || "?~[]-()|".contains(c)
+                }) {
+                    return Err(BuildError::InvalidHexPattern(hex.clone()));
+                }
+            }
+        }
+
+        let condition =
+            self.condition.as_ref().ok_or(BuildError::MissingCondition)?;
+
+        let mut src = String::new();
+
+        if self.private {
+            src.push_str("private ");
+        }
+
+        if self.global {
+            src.push_str("global ");
+        }
+
+        write!(src, "rule {}", self.identifier).unwrap();
+
+        if !self.tags.is_empty() {
+            write!(src, " : {}", self.tags.join(" ")).unwrap();
+        }
+
+        src.push_str(" {\n");
+
+        if !self.meta.is_empty() {
+            src.push_str("  meta:\n");
+            for (identifier, value) in &self.meta {
+                writeln!(src, "    {identifier} = {value}").unwrap();
+            }
+        }
+
+        if !self.patterns.is_empty() {
+            src.push_str("  strings:\n");
+            for (identifier, pattern) in &self.patterns {
+                writeln!(src, "    {identifier} = {pattern}").unwrap();
+            }
+        }
+
+        writeln!(src, "  condition:\n    {}\n}}", condition.expr).unwrap();
+
+        // Make sure that the generated code contains a single valid rule
+        // with the expected patterns. Raw code fragments could otherwise
+        // produce invalid code, or even additional rules.
+        let ast = AST::from(src.as_str());
+
+        let valid = ast.errors().is_empty()
+            && matches!(
+                ast.items.as_slice(),
+                [Item::Rule(rule)]
+                    if rule.patterns.as_ref().map_or(0, |p| p.len())
+                        == self.patterns.len()
+            );
+
+        if !valid {
+            return Err(BuildError::SyntaxError(src));
+        }
+
+        Ok(src)
+    }
+}
+
+/// Returns an error if `ident` is not a valid YARA identifier.
+fn validate_identifier(ident: &str) -> Result<(), BuildError> {
+    let mut chars = ident.chars();
+
+    let valid =
+        chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if valid {
+        Ok(())
+    } else {
+        Err(BuildError::InvalidIdentifier(ident.to_string()))
+    }
+}
+
+/// Escapes a string for using it in a string literal. Non-ASCII characters
+/// are kept as they are.
+fn escape_str(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str(r#"\""#),
+            '\\' => result.push_str(r"\\"),
+            '\n' => result.push_str(r"\n"),
+            '\r' => result.push_str(r"\r"),
+            '\t' => result.push_str(r"\t"),
+            c if c.is_ascii_control() => {
+                write!(result, r"\x{:02x}", c as u8).unwrap()
+            }
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+/// Escapes arbitrary bytes for using them in a text pattern. Any byte that
+/// is not a printable ASCII character is escaped.
+fn escape_bytes(bytes: &[u8]) -> String {
+    let mut result = String::with_capacity(bytes.len());
+    for b in bytes {
+        match b {
+            b'"' => result.push_str(r#"\""#),
+            b'\\' => result.push_str(r"\\"),
+            b'\n' => result.push_str(r"\n"),
+            b'\r' => result.push_str(r"\r"),
+            b'\t' => result.push_str(r"\t"),
+            b' '..=b'~' => result.push(*b as char),
+            b => write!(result, r"\x{b:02x}").unwrap(),
+        }
+    }
+    result
+}
+
+/// Escapes the slashes in a regular expression that are not escaped yet.
+fn escape_slashes(regexp: &str) -> String {
+    let mut result = String::with_capacity(regexp.len());
+    let mut escaped = false;
+    for c in regexp.chars() {
+        if c == '/' && !escaped {
+            result.push('\\');
+        }
+        escaped = c == '\\' && !escaped;
+        result.push(c);
+    }
+    result
+}
@@ -0,0 +1,91 @@
+/*! Functions that return canonical representations of rules and patterns.
+
+The canonical representation of a rule captures its logic, ignoring details
+that don't affect it, like formatting, comments, metadata, tags, and the
+rule's name. Two rules with the same canonical representation are
+duplicates, even if their source code differs.
+*/
+
+use ::ascii_tree::write_tree;
+use ::ascii_tree::Tree;
+use ::ascii_tree::Tree::{Leaf, Node};
+use itertools::Itertools;
+
+use crate::ast::ascii_tree::{expr_ascii_tree, hex_tokens_ascii_tree};
+use crate::ast::*;
+
+/// Returns the canonical representation of a rule.
+///
+/// Patterns are sorted by identifier, and the operands of `and` and `or`
+/// expressions are sorted, so that the order in which they appear in the
+/// source code doesn't matter.
+pub(crate) fn rule_canonical_form(rule: &Rule) -> String {
+    let mut result = String::new();
+
+    if let Some(patterns) = &rule.patterns {
+        for pattern in
+            patterns.iter().sorted_by_key(|pattern| pattern.identifier().name)
+        {
+            result.push_str(pattern.identifier().name);
+            result.push_str(" = ");
+            result.push_str(&pattern_canonical_form(pattern));
+            result.push('\n');
+        }
+    }
+
+    result.push_str(&render(&sort_operands(expr_ascii_tree(&rule.condition))));
+    result
+}
+
+/// Returns the canonical representation of a pattern, not including the
+/// pattern's identifier.
+///
+/// Escape sequences in text patterns are resolved, and modifiers are sorted.
+pub(crate) fn pattern_canonical_form(pattern: &Pattern) -> String {
+    let modifiers = pattern
+        .modifiers()
+        .iter()
+        .map(|modifier| modifier.to_string())
+        .sorted()
+        .join(" ");
+
+    match pattern {
+        Pattern::Text(p) => format!("{:?} {}", p.text.value, modifiers),
+        Pattern::Regexp(p) => format!(
+            "/{}/{}{} {}",
+            p.regexp.src,
+            if p.regexp.case_insensitive { "i" } else { "" },
+            if p.regexp.dot_matches_new_line { "s" } else { "" },
+            modifiers
+        ),
+        Pattern::Hex(p) => format!(
+            "{}{}",
+            render(&hex_tokens_ascii_tree(&p.sub_patterns)),
+            modifiers
+        ),
+    }
+}
+
+/// Sorts the operands of `and` and `or` expressions in a tree produced by
+/// [`expr_ascii_tree`].
+fn sort_operands(tree: Tree) -> Tree {
+    match tree {
+        Node(label, children) => {
+            let mut children: Vec<Tree> =
+                children.into_iter().map(sort_operands).collect();
+
+            if label == "and" || label == "or" {
+                children.sort_by_cached_key(render);
+            }
+
+            Node(label, children)
+        }
+        leaf @ Leaf(_) => leaf,
+    }
+}
+
+fn render(tree: &Tree) -> String {
+    let mut result = String::new();
+    write_tree(&mut result, tree).unwrap();
+    result
+}
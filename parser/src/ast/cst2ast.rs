@@ -8,7 +8,7 @@ use bstr::{ByteSlice, ByteVec};
 use itertools::Itertools;
 use num_traits::{Bounded, CheckedMul, FromPrimitive, Num};
 
-use crate::ast::errors::Error;
+use crate::ast::errors::{Error, Fix};
 use crate::ast::*;
 use crate::cst::SyntaxKind::*;
 use crate::cst::{Event, SyntaxKind};
@@ -221,9 +221,10 @@ where
         span: &Span,
     ) -> Result<&'src str, BuilderError> {
         from_utf8(self.get_source(span)).map_err(|err| {
-            self.errors.push(Error::InvalidUTF8(
-                span.subspan(err.valid_up_to(), err.valid_up_to() + 1),
-            ));
+            self.errors.push(Error::InvalidUTF8 {
+                span: span.subspan(err.valid_up_to(), err.valid_up_to() + 1),
+                fix: None,
+            });
             BuilderError::Abort
         })
     }
@@ -486,8 +487,22 @@ where
         self.begin(IMPORT_STMT)?;
         let span = self.expect(IMPORT_KW)?;
         let (module_name, module_name_span) = self.utf8_string_lit()?;
+
+        let alias = if let Event::Token { kind: AS_KW, .. } = self.peek() {
+            self.expect(AS_KW)?;
+            Some(self.identifier()?)
+        } else {
+            None
+        };
+
         self.end(IMPORT_STMT)?;
-        Ok(Import { module_name, span: span.combine(&module_name_span) })
+
+        let span = match &alias {
+            Some(alias) => span.combine(&alias.span()),
+            None => span.combine(&module_name_span),
+        };
+
+        Ok(Import { module_name, alias, span })
     }
 
     fn rule_decl(&mut self) -> Result<Rule<'src>, BuilderError> {
@@ -1766,7 +1781,20 @@ where
         allow_escape_char: bool,
     ) -> Result<(Cow<'src, BStr>, &'src str, Span), BuilderError> {
         let span = self.expect(STRING_LIT)?;
-        let literal = self.get_source_str(&span)?;
+        let source = self.get_source(&span);
+
+        let literal = from_utf8(source).map_err(|err| {
+            self.errors.push(Error::InvalidUTF8 {
+                span: span.subspan(err.valid_up_to(), err.valid_up_to() + 1),
+                // If the literal accepts escape sequences, the fix consists
+                // in replacing the invalid bytes with `\xNN` sequences.
+                fix: allow_escape_char.then(|| Fix {
+                    span: span.clone(),
+                    replacement: escape_invalid_utf8(source),
+                }),
+            });
+            BuilderError::Abort
+        })?;
 
         let num_quotes = if literal.starts_with("\"\"\"") {
             debug_assert!(literal.starts_with("\"\"\""));
@@ -1847,15 +1875,25 @@ where
                                 _ => {
                                     let (escaped_char_pos, _) = escaped_char;
 
+                                    let span = string_span
+                                        .offset(first_backslash as isize)
+                                        .subspan(
+                                            backslash,
+                                            escaped_char_pos + 1,
+                                        );
+
                                     self.errors
                                         .push(Error::InvalidEscapeSequence {
                                             message:
                                             r"expecting two hex digits after `\x`"
                                                 .to_string(),
-                                            span: string_span.offset(first_backslash as isize).subspan(
-                                                backslash,
-                                                escaped_char_pos + 1,
-                                            ),
+                                            // Escaping the backslash makes
+                                            // `\x` a literal.
+                                            fix: Some(Fix {
+                                                span: span.clone(),
+                                                replacement: r"\\x".to_string(),
+                                            }),
+                                            span,
                                         });
 
                                     return Err(BuilderError::Abort);
@@ -1869,15 +1907,24 @@ where
                             let escaped_char_end_pos =
                                 escaped_char_pos + escaped_char.len_utf8();
 
+                            let sequence =
+                                &remaining[backslash..escaped_char_end_pos];
+
+                            let span = string_span
+                                .offset(first_backslash as isize)
+                                .subspan(backslash, escaped_char_end_pos);
+
                             self.errors.push(Error::InvalidEscapeSequence {
                                 message: format!(
-                                    "invalid escape sequence `{}`",
-                                    &remaining
-                                        [backslash..escaped_char_end_pos]
+                                    "invalid escape sequence `{sequence}`"
                                 ),
-                                span: string_span
-                                    .offset(first_backslash as isize)
-                                    .subspan(backslash, escaped_char_end_pos),
+                                // Escaping the backslash makes the sequence
+                                // a literal.
+                                fix: Some(Fix {
+                                    span: span.clone(),
+                                    replacement: format!("\\{sequence}"),
+                                }),
+                                span,
                             });
 
                             return Err(BuilderError::Abort);
@@ -1892,3 +1939,16 @@ where
         Ok((Cow::Owned(result), literal, span))
     }
 }
+
+/// Returns a copy of `bytes` where every byte that is not part of a valid
+/// UTF-8 sequence is replaced with the equivalent `\xNN` escape sequence.
+fn escape_invalid_utf8(bytes: &[u8]) -> String {
+    let mut result = String::with_capacity(bytes.len());
+    for chunk in bytes.utf8_chunks() {
+        result.push_str(chunk.valid());
+        for b in chunk.invalid() {
+            result.push_str(&format!("\\x{b:02X}"));
+        }
+    }
+    result
+}
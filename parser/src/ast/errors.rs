@@ -1,13 +1,54 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
 use crate::Span;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 /// Error occurred while parsing the YARA source code.
 pub enum Error {
     SyntaxError { message: String, span: Span },
     InvalidInteger { message: String, span: Span },
     InvalidFloat { message: String, span: Span },
     InvalidRegexpModifier { message: String, span: Span },
-    InvalidEscapeSequence { message: String, span: Span },
-    InvalidUTF8(Span),
+    InvalidEscapeSequence { message: String, span: Span, fix: Option<Fix> },
+    InvalidUTF8 { span: Span, fix: Option<Fix> },
     UnexpectedEscapeSequence(Span),
 }
+
+impl Error {
+    /// Returns the span of the source code where the error occurred.
+    pub fn span(&self) -> &Span {
+        match self {
+            Error::SyntaxError { span, .. }
+            | Error::InvalidInteger { span, .. }
+            | Error::InvalidFloat { span, .. }
+            | Error::InvalidRegexpModifier { span, .. }
+            | Error::InvalidEscapeSequence { span, .. }
+            | Error::InvalidUTF8 { span, .. }
+            | Error::UnexpectedEscapeSequence(span) => span,
+        }
+    }
+
+    /// Returns the suggested fix for the error, if any.
+    pub fn fix(&self) -> Option<&Fix> {
+        match self {
+            Error::InvalidEscapeSequence { fix, .. }
+            | Error::InvalidUTF8 { fix, .. } => fix.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// A suggested fix for an [`Error`].
+///
+/// Applying the fix consists in replacing the source code covered by
+/// [`Fix::span`] with [`Fix::replacement`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Fix {
+    /// Portion of the source code that must be replaced.
+    pub span: Span,
+    /// Code that replaces the one covered by `span`.
+    pub replacement: String,
+}
@@ -13,6 +13,8 @@ use std::slice::Iter;
 use ::ascii_tree::write_tree;
 use bitflags::bitflags;
 use bstr::{BStr, BString, ByteSlice, Utf8Error};
+#[cfg(feature = "serde")]
+use serde::Serialize;
 
 use crate::ast::cst2ast::Builder;
 use crate::cst::SyntaxKind::{
@@ -23,16 +25,21 @@ use crate::cst::{CSTStream, Event};
 use crate::{Parser, Span};
 
 mod ascii_tree;
+mod canonical;
 mod cst2ast;
 mod errors;
+#[cfg(feature = "serde")]
+mod serialize;
 #[cfg(test)]
 mod tests;
 
+pub mod builder;
 pub mod dfs;
 
-pub use errors::Error;
+pub use errors::{Error, Fix};
 
 /// Abstract Syntax Tree (AST) for YARA rules.
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct AST<'src> {
     /// The list of items in the AST (imports, includes, and rules).
     pub items: Vec<Item<'src>>,
@@ -41,6 +48,7 @@ pub struct AST<'src> {
 }
 
 /// Top level items in the AST.
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum Item<'src> {
     Import(Import<'src>),
     Include(Include<'src>),
@@ -162,13 +170,26 @@ impl Debug for AST<'_> {
 
 /// An import statement.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Import<'src> {
     span: Span,
     pub module_name: &'src str,
+    /// Alternative name for the module, as in `import "pe" as p`. When
+    /// present, the module is accessible only by this name.
+    pub alias: Option<Ident<'src>>,
+}
+
+impl Import<'_> {
+    /// Returns the identifier used for referring to the module in rule
+    /// conditions. This is the alias, if any, or the module's name.
+    pub fn ident(&self) -> &str {
+        self.alias.as_ref().map_or(self.module_name, |alias| alias.name)
+    }
 }
 
 /// An include statement.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Include<'src> {
     span: Span,
     pub file_name: &'src str,
@@ -176,6 +197,7 @@ pub struct Include<'src> {
 
 /// A YARA rule.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Rule<'src> {
     pub flags: RuleFlags,
     pub identifier: Ident<'src>,
@@ -185,9 +207,42 @@ pub struct Rule<'src> {
     pub condition: Expr<'src>,
 }
 
+impl Rule<'_> {
+    /// Returns a canonical representation of the rule's logic.
+    ///
+    /// The canonical representation ignores the rule's identifier, tags,
+    /// metadata and flags, as well as comments and formatting. The order of
+    /// patterns and the order of operands in `and` and `or` expressions are
+    /// also ignored. Two rules with the same canonical representation are
+    /// duplicates of each other.
+    ///
+    /// ```
+    /// # use yara_x_parser::ast::AST;
+    /// let ast = AST::from(r#"
+    /// rule a { strings: $a = "foo" $b = "bar" condition: $a and $b }
+    /// rule b : tag {
+    ///   meta:
+    ///     author = "Jane Doe"
+    ///   strings:
+    ///     $b = "bar"
+    ///     $a = "\x66oo"  // same as "foo"
+    ///   condition:
+    ///     $b and $a
+    /// }
+    /// "#);
+    ///
+    /// let rules: Vec<_> = ast.rules().collect();
+    /// assert_eq!(rules[0].canonical_form(), rules[1].canonical_form());
+    /// ```
+    pub fn canonical_form(&self) -> String {
+        canonical::rule_canonical_form(self)
+    }
+}
+
 bitflags! {
     /// A set of flags associated to a YARA rule.
     #[derive(Debug)]
+    #[cfg_attr(feature = "serde", derive(Serialize))]
     pub struct RuleFlags: u8 {
         const Private = 0x01;
         const Global = 0x02;
@@ -203,11 +258,16 @@ pub struct Meta<'src> {
 
 /// Each of the possible values that can have a metadata entry.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum MetaValue<'src> {
     Bool((bool, Span)),
     Integer((i64, Span)),
     Float((f64, Span)),
     String((&'src str, Span)),
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "serialize::bytes_with_span")
+    )]
     Bytes((BString, Span)),
 }
 
@@ -225,6 +285,7 @@ impl Display for MetaValue<'_> {
 
 /// An identifier (e.g. `some_ident`).
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Ident<'src> {
     span: Span,
     #[doc(hidden)]
@@ -248,6 +309,7 @@ impl<'src> Ident<'src> {
 /// The range is optional thought, so expressions like `#a` are also
 /// represented by this struct.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct IdentWithRange<'src> {
     span: Span,
     pub identifier: Ident<'src>,
@@ -260,6 +322,7 @@ pub struct IdentWithRange<'src> {
 /// The index is optional thought, so expressions like `@a` are also
 /// represented by this struct.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct IdentWithIndex<'src> {
     span: Span,
     pub identifier: Ident<'src>,
@@ -270,6 +333,7 @@ pub struct IdentWithIndex<'src> {
 ///
 /// Possible types are: text patterns, hex patterns and regular expressions.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum Pattern<'src> {
     Text(Box<TextPattern<'src>>),
     Hex(Box<HexPattern<'src>>),
@@ -292,6 +356,16 @@ impl<'src> Pattern<'src> {
             Pattern::Regexp(p) => &p.modifiers,
         }
     }
+
+    /// Returns a canonical representation of the pattern, which doesn't
+    /// include the pattern's identifier.
+    ///
+    /// Escape sequences in text patterns are resolved and modifiers are
+    /// sorted, so `"\x66oo" wide ascii` and `"foo" ascii wide` have the same
+    /// canonical representation.
+    pub fn canonical_form(&self) -> String {
+        canonical::pattern_canonical_form(self)
+    }
 }
 
 /// A text pattern (a.k.a. text string) in a YARA rule.
@@ -312,6 +386,7 @@ pub struct RegexpPattern<'src> {
 
 /// A hex pattern (a.k.a. hex string) in a YARA rule.
 #[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct HexPattern<'src> {
     span: Span,
     pub identifier: Ident<'src>,
@@ -332,6 +407,7 @@ impl<'src> HexPattern<'src> {
 
 /// A sequence of tokens that conform a hex pattern (a.k.a. hex string).
 #[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct HexSubPattern(pub Vec<HexToken>);
 
 impl HexSubPattern {
@@ -356,6 +432,7 @@ impl HexSubPattern {
 /// A token can be a single byte, a negated byte (e.g. `~XX`), an
 /// alternative (e.g `(XXXX|YYYY)`), or a jump (e.g `[0-10]`).
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum HexToken {
     Byte(HexByte),
     NotByte(HexByte),
@@ -374,6 +451,7 @@ pub enum HexToken {
 /// For example, for pattern `A?` the value is `A0` and the mask is `F0`, and
 /// for pattern `?1` the value is `01` and the mask is `0F`.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct HexByte {
     span: Span,
     pub value: u8,
@@ -391,6 +469,7 @@ impl HexByte {
 ///
 /// Alternatives are sequences of hex sub-patterns separated by `|`.
 #[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct HexAlternative {
     span: Span,
     pub alternatives: Vec<HexSubPattern>,
@@ -405,6 +484,7 @@ impl HexAlternative {
 
 /// A jump in a hex pattern (a.k.a. hex string).
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct HexJump {
     span: Span,
     pub start: Option<u32>,
@@ -432,6 +512,7 @@ impl Display for HexJump {
 /// An `of` expression (e.g. `1 of ($a, $b)`, `all of them`,
 /// `any of (true, false)`)
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Of<'src> {
     span: Span,
     pub quantifier: Quantifier<'src>,
@@ -442,6 +523,7 @@ pub struct Of<'src> {
 /// A `for .. of` expression (e.g `for all of them : (..)`,
 /// `for 1 of ($a,$b) : (..)`)
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct ForOf<'src> {
     span: Span,
     pub quantifier: Quantifier<'src>,
@@ -451,6 +533,7 @@ pub struct ForOf<'src> {
 
 /// A `for .. in` expression (e.g `for all x in iterator : (..)`)
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct ForIn<'src> {
     span: Span,
     pub quantifier: Quantifier<'src>,
@@ -461,6 +544,7 @@ pub struct ForIn<'src> {
 
 /// Items in a `of` expression.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum OfItems<'src> {
     PatternSet(PatternSet<'src>),
     BoolExprTuple(Vec<Expr<'src>>),
@@ -468,6 +552,7 @@ pub enum OfItems<'src> {
 
 /// A `with` expression (e.g `with foo = 1 + 1 : (..)`)
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct With<'src> {
     span: Span,
     pub declarations: Vec<WithDeclaration<'src>>,
@@ -476,6 +561,7 @@ pub struct With<'src> {
 
 /// Items in a `with` expression.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct WithDeclaration<'src> {
     span: Span,
     pub identifier: Ident<'src>,
@@ -484,6 +570,7 @@ pub struct WithDeclaration<'src> {
 
 /// A quantifier used in `for` and `of` expressions.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum Quantifier<'src> {
     None {
         span: Span,
@@ -502,6 +589,7 @@ pub enum Quantifier<'src> {
 
 /// Possible iterable expressions that can use in a [`ForIn`].
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum Iterable<'src> {
     Range(Range<'src>),
     ExprTuple(Vec<Expr<'src>>),
@@ -511,6 +599,7 @@ pub enum Iterable<'src> {
 /// Either a set of pattern identifiers (possibly with wildcards), or the
 /// special set `them`, which includes all the patterns declared in the rule.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum PatternSet<'src> {
     Them { span: Span },
     Set(Vec<PatternSetItem<'src>>),
@@ -521,6 +610,7 @@ pub enum PatternSet<'src> {
 /// In the pattern set `($a, $b*)`, `$a` and `$b*` are represented by a
 /// [`PatternSetItem`].
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct PatternSetItem<'src> {
     span: Span,
     pub identifier: &'src str,
@@ -543,6 +633,7 @@ impl PatternSetItem<'_> {
 
 /// An expression in the AST.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum Expr<'src> {
     True {
         span: Span,
@@ -701,6 +792,8 @@ pub enum Expr<'src> {
 
 /// A set of modifiers associated to a pattern.
 #[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct PatternModifiers<'src> {
     modifiers: Vec<PatternModifier<'src>>,
 }
@@ -796,6 +889,7 @@ impl<'src> Iterator for PatternModifiersIter<'src> {
 
 /// A pattern (a.k.a. string) modifier.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum PatternModifier<'src> {
     Ascii { span: Span },
     Wide { span: Span },
@@ -881,6 +975,7 @@ pub struct PatternMatch<'src> {
 /// where the match can occur.
 /// (e.g. `at <expr>`, `in <range>`).
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum MatchAnchor<'src> {
     At(Box<At<'src>>),
     In(Box<In<'src>>),
@@ -889,6 +984,7 @@ pub enum MatchAnchor<'src> {
 /// In expressions like `$a at 0`, this type represents the anchor
 /// (e.g. `at <expr>`).
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct At<'src> {
     span: Span,
     pub expr: Expr<'src>,
@@ -896,6 +992,7 @@ pub struct At<'src> {
 
 /// A pair of values conforming a range (e.g. `(0..10)`).
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Range<'src> {
     span: Span,
     pub lower_bound: Expr<'src>,
@@ -905,6 +1002,7 @@ pub struct Range<'src> {
 /// In expressions like `$a in (0..10)`, this struct represents the anchor
 /// e.g. `in <range>`).
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct In<'src> {
     span: Span,
     pub range: Range<'src>,
@@ -932,6 +1030,7 @@ impl FuncCall<'_> {
 
 /// A lookup operation in an array or dictionary.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Lookup<'src> {
     span: Span,
     pub primary: Expr<'src>,
@@ -940,6 +1039,7 @@ pub struct Lookup<'src> {
 
 /// A literal string (e.g: `"abcd"`).
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct LiteralString<'src> {
     span: Span,
     /// The literal string as it appears in the source code, including the
@@ -947,6 +1047,7 @@ pub struct LiteralString<'src> {
     pub literal: &'src str,
     /// The value of the string literal. Escaped characters, if any, are
     /// unescaped. Doesn't include the quotes.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize::bytes"))]
     pub value: Cow<'src, BStr>,
 }
 
@@ -967,6 +1068,7 @@ impl LiteralString<'_> {
 
 /// A literal integer (e.g: `1`, `0xAB`).
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct LiteralInteger<'src> {
     span: Span,
     /// The literal value as it appears in the source code.
@@ -977,6 +1079,7 @@ pub struct LiteralInteger<'src> {
 
 /// A literal float (e.g: `2.0`, `3.14`).
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct LiteralFloat<'src> {
     span: Span,
     /// The literal value as it appears in the source code.
@@ -990,6 +1093,7 @@ pub struct LiteralFloat<'src> {
 /// Used both as part of a [`RegexpPattern`] and as the right operand
 /// of a `matches` operator.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Regexp<'src> {
     span: Span,
     /// The regular expressions as it appears in the source code, including
@@ -1006,6 +1110,7 @@ pub struct Regexp<'src> {
 
 /// An expression with a single operand.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct UnaryExpr<'src> {
     span: Span,
     pub operand: Expr<'src>,
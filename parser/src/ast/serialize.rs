@@ -0,0 +1,105 @@
+/*! Serialization of AST nodes that don't store their own span.
+
+Most nodes in the AST derive [`Serialize`], and their span is serialized
+as any other field. The nodes implemented here compute their span from
+their children, so the span is added explicitly during serialization. This
+guarantees that every node in the serialized AST has a `span` field.
+*/
+
+use bstr::{BStr, BString, ByteSlice};
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+
+use crate::ast::*;
+use crate::Span;
+
+/// Serializes a byte string as a string, replacing invalid UTF-8 sequences
+/// with the replacement character.
+pub(super) fn bytes<S: Serializer>(
+    value: &BStr,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&value.to_str_lossy())
+}
+
+/// Like [`bytes`], but for the `(BString, Span)` tuples used by
+/// [`MetaValue::Bytes`].
+pub(super) fn bytes_with_span<S: Serializer>(
+    value: &(BString, Span),
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    (value.0.to_str_lossy(), &value.1).serialize(serializer)
+}
+
+impl Serialize for Meta<'_> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let mut state = s.serialize_struct("Meta", 3)?;
+        state.serialize_field("span", &self.span())?;
+        state.serialize_field("identifier", &self.identifier)?;
+        state.serialize_field("value", &self.value)?;
+        state.end()
+    }
+}
+
+impl Serialize for TextPattern<'_> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let mut state = s.serialize_struct("TextPattern", 4)?;
+        state.serialize_field("span", &self.span())?;
+        state.serialize_field("identifier", &self.identifier)?;
+        state.serialize_field("text", &self.text)?;
+        state.serialize_field("modifiers", &self.modifiers)?;
+        state.end()
+    }
+}
+
+impl Serialize for RegexpPattern<'_> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let mut state = s.serialize_struct("RegexpPattern", 4)?;
+        state.serialize_field("span", &self.span())?;
+        state.serialize_field("identifier", &self.identifier)?;
+        state.serialize_field("regexp", &self.regexp)?;
+        state.serialize_field("modifiers", &self.modifiers)?;
+        state.end()
+    }
+}
+
+impl Serialize for PatternMatch<'_> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let mut state = s.serialize_struct("PatternMatch", 3)?;
+        state.serialize_field("span", &self.span())?;
+        state.serialize_field("identifier", &self.identifier)?;
+        state.serialize_field("anchor", &self.anchor)?;
+        state.end()
+    }
+}
+
+impl Serialize for FuncCall<'_> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let mut state = s.serialize_struct("FuncCall", 5)?;
+        state.serialize_field("span", &self.span())?;
+        state.serialize_field("args_span", &self.args_span)?;
+        state.serialize_field("object", &self.object)?;
+        state.serialize_field("identifier", &self.identifier)?;
+        state.serialize_field("args", &self.args)?;
+        state.end()
+    }
+}
+
+impl Serialize for BinaryExpr<'_> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let mut state = s.serialize_struct("BinaryExpr", 3)?;
+        state.serialize_field("span", &self.span())?;
+        state.serialize_field("lhs", &self.lhs)?;
+        state.serialize_field("rhs", &self.rhs)?;
+        state.end()
+    }
+}
+
+impl Serialize for NAryExpr<'_> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let mut state = s.serialize_struct("NAryExpr", 2)?;
+        state.serialize_field("span", &self.span())?;
+        state.serialize_field("operands", &self.operands)?;
+        state.end()
+    }
+}
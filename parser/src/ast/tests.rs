@@ -31,3 +31,98 @@ fn ast_from_cst() {
         }
     );
 }
+
+#[test]
+fn rule_builder() {
+    use crate::ast::builder::{BuildError, Condition, Pattern, RuleBuilder};
+
+    let rule = RuleBuilder::new("test")
+        .private()
+        .global()
+        .meta("int", 1)
+        .meta("float", 1.0)
+        .meta("bool", true)
+        .meta("string", "\\foo\t\"bar\"\x01ñ")
+        .pattern("$a", Pattern::text(b"\x00a\"b\\c\xff").nocase().fullword())
+        .pattern("$b", Pattern::hex_str("4D 5A ?? [2-4] (90 | 91)"))
+        .pattern("$c", Pattern::regexp(r"foo/bar\/baz").xor_range(1, 2))
+        .pattern("$", Pattern::text("qux").base64().base64wide().private())
+        .condition(
+            Condition::pattern("$a")
+                .or(Condition::pattern("$b"))
+                .and(Condition::raw("#c > 2").not())
+                .and(Condition::filesize_lt(100).or(Condition::all_of_them())),
+        )
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        rule,
+        r#"private global rule test {
+  meta:
+    int = 1
+    float = 1.0
+    bool = true
+    string = "\\foo\t\"bar\"\x01ñ"
+  strings:
+    $a = "\x00a\"b\\c\xff" nocase fullword
+    $b = { 4D 5A ?? [2-4] (90 | 91) }
+    $c = /foo\/bar\/baz/ xor(1-2)
+    $ = "qux" base64 base64wide private
+  condition:
+    ($a or $b) and not (#c > 2) and (filesize < 100 or all of them)
+}
+"#
+    );
+
+    assert_eq!(
+        RuleBuilder::new("1nvalid").condition(Condition::bool(true)).build(),
+        Err(BuildError::InvalidIdentifier("1nvalid".to_string()))
+    );
+
+    assert_eq!(
+        RuleBuilder::new("test")
+            .tag("foo bar")
+            .condition(Condition::bool(true))
+            .build(),
+        Err(BuildError::InvalidIdentifier("foo bar".to_string()))
+    );
+
+    assert_eq!(
+        RuleBuilder::new("test")
+            .pattern("a", Pattern::text("foo"))
+            .condition(Condition::any_of_them())
+            .build(),
+        Err(BuildError::InvalidPatternIdentifier("a".to_string()))
+    );
+
+    assert_eq!(
+        RuleBuilder::new("test")
+            .pattern("$a", Pattern::text("foo"))
+            .pattern("$a", Pattern::text("bar"))
+            .condition(Condition::any_of_them())
+            .build(),
+        Err(BuildError::DuplicatePattern("$a".to_string()))
+    );
+
+    assert_eq!(
+        RuleBuilder::new("test")
+            .pattern("$a", Pattern::hex_str("01 } $b = { 02"))
+            .condition(Condition::any_of_them())
+            .build(),
+        Err(BuildError::InvalidHexPattern("01 } $b = { 02".to_string()))
+    );
+
+    assert_eq!(
+        RuleBuilder::new("test").build(),
+        Err(BuildError::MissingCondition)
+    );
+
+    // Raw conditions that inject additional rules are rejected.
+    assert!(matches!(
+        RuleBuilder::new("test")
+            .condition(Condition::raw("true } rule evil { condition: true"))
+            .build(),
+        Err(BuildError::SyntaxError(_))
+    ));
+}
@@ -244,9 +244,6 @@ impl rowan::Language for YARA {
 }
 
 /// A Concrete Syntax Tree (CST).
-///
-/// NOTE: This API is still unstable and should not be used by third-party code.
-#[doc(hidden)]
 pub struct CST {
     root: rowan::GreenNode,
     errors: Vec<(Span, String)>,
@@ -265,6 +262,16 @@ impl Debug for CST {
     }
 }
 
+impl Display for CST {
+    /// Writes the source code represented by the CST.
+    ///
+    /// As the CST is lossless, the output is exactly the same source code
+    /// that was parsed, including comments and whitespaces.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.root, f)
+    }
+}
+
 impl CST {
     /// Returns the root node of the CST.
     ///
@@ -274,6 +281,15 @@ impl CST {
         Node::new(rowan::SyntaxNode::new_root(self.root.clone()))
     }
 
+    /// Returns the errors found while parsing the source code.
+    ///
+    /// Each error is accompanied by the span of the source code where the
+    /// error occurred.
+    #[inline]
+    pub fn errors(&self) -> &[(Span, String)] {
+        self.errors.as_slice()
+    }
+
     /// Returns the parsed source code as an iterator of [`Event`].
     pub fn iter(&self) -> impl Iterator<Item = Event> + '_ {
         CSTIter {
@@ -348,9 +364,6 @@ where
 }
 
 /// Sibling traversal direction.
-///
-/// NOTE: This API is still unstable and should not be used by third-party code.
-#[doc(hidden)]
 pub enum Direction {
     Next,
     Prev,
@@ -366,10 +379,7 @@ pub enum Direction {
 /// Instead, we use the [`Text`] type to represent a logically
 /// contiguous portion of the code, even though it is physically
 /// composed of non-contiguous chunks, each owned by a [`Token`].
-///
-/// NOTE: This API is still unstable and should not be used by third-party code.
 #[derive(PartialEq, Eq)]
-#[doc(hidden)]
 pub struct Text(rowan::SyntaxText);
 
 impl Text {
@@ -446,24 +456,20 @@ impl PartialEq<&'_ str> for Text {
 }
 
 /// Represents the encoding used to interpret column numbers.
-#[doc(hidden)]
 pub trait Encoding {
     fn len(s: &str) -> usize;
 }
 
 /// Represents the UTF-8 encoding.
 #[derive(Debug, PartialEq)]
-#[doc(hidden)]
 pub struct Utf8 {}
 
 /// Represents the UTF-16 encoding.
 #[derive(Debug, PartialEq)]
-#[doc(hidden)]
 pub struct Utf16 {}
 
 /// Represents the UTF-32 encoding.
 #[derive(Debug, PartialEq)]
-#[doc(hidden)]
 pub struct Utf32 {}
 
 impl Encoding for Utf8 {
@@ -506,7 +512,6 @@ impl Encoding for Utf32 {
 /// while in [`Utf8`] and [`Utf16`], the column number reflects the underlying
 /// memory usage, not necessarily the number of visible characters.
 #[derive(Clone, Debug, PartialEq, Eq)]
-#[doc(hidden)]
 pub struct Position<E: Encoding> {
     pub line: usize,
     pub column: usize,
@@ -520,12 +525,14 @@ impl<E: Encoding> From<(usize, usize)> for Position<E> {
     }
 }
 
+/// Marker type for CST nodes and tokens that can be modified.
+///
+/// See [`Node::into_mut`].
 #[derive(Clone, Debug, PartialEq, Eq)]
-#[doc(hidden)]
 pub struct Mutable;
 
+/// Marker type for CST nodes and tokens that can't be modified.
 #[derive(Clone, Debug, PartialEq, Eq)]
-#[doc(hidden)]
 pub struct Immutable;
 
 /// A token in the CST.
@@ -534,10 +541,7 @@ pub struct Immutable;
 /// the grammar, such as keywords, identifiers, whitespaces, punctuation, etc.
 ///
 /// The inner (non-leave) nodes in the CST are of type [`Node`].
-///
-/// NOTE: This API is still unstable and should not be used by third-party code.
 #[derive(Clone, PartialEq, Eq)]
-#[doc(hidden)]
 pub struct Token<M> {
     inner: rowan::SyntaxToken<YARA>,
     _state: PhantomData<M>,
@@ -775,6 +779,10 @@ impl Token<Mutable> {
         self.inner.detach()
     }
 
+    /// Replaces the text of this token with `text`.
+    ///
+    /// Returns the root node of a new tree where the token was replaced. The
+    /// kind of the token doesn't change.
     pub fn replace(&mut self, text: &str) -> Node<Mutable> {
         Node::new(rowan::SyntaxNode::new_root(
             self.inner.replace_with(rowan::GreenToken::new(
@@ -788,9 +796,6 @@ impl Token<Mutable> {
 /// Either a  [`Node`] or a [`Token`].
 ///
 /// In a CST, nodes are the inner nodes of the tree, leaves are tokens.
-///
-/// NOTE: This API is still unstable and should not be used by third-party code.
-#[doc(hidden)]
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum NodeOrToken<M> {
     Node(Node<M>),
@@ -927,9 +932,6 @@ impl<M> From<NodeOrToken<M>> for rowan::SyntaxElement<YARA> {
 /// non-terminal symbols in the grammar.
 ///
 /// The leaves in a CST are of type [`Token`].
-///
-/// NOTE: This API is still unstable and should not be used by third-party code.
-#[doc(hidden)]
 #[derive(Clone, PartialEq, Eq)]
 pub struct Node<M> {
     inner: rowan::SyntaxNode<YARA>,
@@ -1001,6 +1003,24 @@ impl<M: Clone> Node<M> {
         Nodes { inner: self.inner.children(), _mutability: PhantomData }
     }
 
+    /// Returns an iterator over all the nodes in the subtree rooted at this
+    /// node, in pre-order. The first node returned is this node itself.
+    pub fn descendants(&self) -> impl Iterator<Item = Node<M>> {
+        self.inner.descendants().map(Node::new)
+    }
+
+    /// Returns an iterator over all the nodes and tokens in the subtree
+    /// rooted at this node, in pre-order. The first item returned is this
+    /// node itself.
+    ///
+    /// This is useful for visiting every token in the source code, including
+    /// comments and whitespaces.
+    pub fn descendants_with_tokens(
+        &self,
+    ) -> impl Iterator<Item = NodeOrToken<M>> {
+        self.inner.descendants_with_tokens().map(|x| x.into())
+    }
+
     /// Returns the root node of the tree.
     #[inline]
     pub fn root(&self) -> Node<M> {
@@ -1352,9 +1372,6 @@ impl Node<Mutable> {
 /// nodes, not tokens.
 ///
 /// This is the value returned by [`Node::children`].
-///
-/// NOTE: This API is still unstable and should not be used by third-party code.
-#[doc(hidden)]
 pub struct Nodes<M> {
     inner: rowan::SyntaxNodeChildren<YARA>,
     _mutability: PhantomData<M>,
@@ -1372,9 +1389,6 @@ impl<M> Iterator for Nodes<M> {
 /// and tokens.
 ///
 /// This is the value returned by [`Node::children_with_tokens`].
-///
-/// NOTE: This API is still unstable and should not be used by third-party code.
-#[doc(hidden)]
 pub struct NodesAndTokens<M> {
     inner: rowan::SyntaxElementChildren<YARA>,
     _mutability: PhantomData<M>,
@@ -8,6 +8,7 @@ pub enum SyntaxKind {
     ALL_KW,
     AND_KW,
     ANY_KW,
+    AS_KW,
     ASCII_KW,
     AT_KW,
     BASE64_KW,
@@ -180,6 +181,7 @@ impl SyntaxKind {
             SyntaxKind::ALL_KW => TokenId::ALL_KW,
             SyntaxKind::AND_KW => TokenId::AND_KW,
             SyntaxKind::ANY_KW => TokenId::ANY_KW,
+            SyntaxKind::AS_KW => TokenId::AS_KW,
             SyntaxKind::ASCII_KW => TokenId::ASCII_KW,
             SyntaxKind::AT_KW => TokenId::AT_KW,
             SyntaxKind::BASE64_KW => TokenId::BASE64_KW,
@@ -283,6 +285,7 @@ impl From<&Token> for SyntaxKind {
             Token::ALL_KW(_) => SyntaxKind::ALL_KW,
             Token::AND_KW(_) => SyntaxKind::AND_KW,
             Token::ANY_KW(_) => SyntaxKind::ANY_KW,
+            Token::AS_KW(_) => SyntaxKind::AS_KW,
             Token::ASCII_KW(_) => SyntaxKind::ASCII_KW,
             Token::AT_KW(_) => SyntaxKind::AT_KW,
             Token::BASE64_KW(_) => SyntaxKind::BASE64_KW,
@@ -1,4 +1,6 @@
-use crate::cst::{CSTStream, Event, SyntaxKind, Utf16, Utf32, Utf8, CST};
+use crate::cst::{
+    CSTStream, Event, NodeOrToken, SyntaxKind, Utf16, Utf32, Utf8, CST,
+};
 use crate::{Parser, Span};
 
 #[test]
@@ -528,3 +530,63 @@ test {
         Some(Event::Token { kind: SyntaxKind::L_BRACE, span: Span(25..26) })
     );
 }
+
+#[test]
+fn cst_lossless() {
+    let src = r#"
+// comment
+rule test : tag {
+  strings:
+    $a = "foo"  /* another comment */
+  condition:
+    $a and test_2
+}
+"#;
+
+    let cst = CST::from(src);
+
+    // The CST reproduces the original source code.
+    assert_eq!(cst.to_string(), src);
+    assert!(cst.errors().is_empty());
+
+    let comments = cst
+        .root()
+        .descendants_with_tokens()
+        .filter(|n| n.kind() == SyntaxKind::COMMENT)
+        .count();
+
+    assert_eq!(comments, 2);
+
+    let rule_decls = cst
+        .root()
+        .descendants()
+        .filter(|n| n.kind() == SyntaxKind::RULE_DECL)
+        .count();
+
+    assert_eq!(rule_decls, 1);
+
+    // Rename the rule by replacing its identifier.
+    let mut ident = cst
+        .root()
+        .into_mut()
+        .descendants_with_tokens()
+        .find_map(|n| match n {
+            NodeOrToken::Token(t) if t.text() == "test" => Some(t),
+            _ => None,
+        })
+        .unwrap();
+
+    let new_root = ident.replace("renamed");
+
+    assert_eq!(
+        new_root.text(),
+        src.replace("rule test", "rule renamed").as_str()
+    );
+}
+
+#[test]
+fn cst_errors() {
+    let cst = CST::from("rule test { condition: }");
+    assert_eq!(cst.errors().len(), 1);
+    assert_eq!(cst.to_string(), "rule test { condition: }");
+}
@@ -0,0 +1,222 @@
+/*! Finds duplicate and near-duplicate rules.
+
+The [`Deduplicator`] receives YARA source code from any number of files, and
+groups together rules that are duplicates of each other. Two kinds of
+duplicates are reported:
+
+* Exact duplicates: rules with the same logic, as determined by
+  [`Rule::canonical_form`]. They may differ in identifier, tags, metadata,
+  comments, formatting, and the order of patterns and operands in `and` and
+  `or` expressions.
+
+* Near duplicates: rules that have exactly the same set of patterns, maybe
+  with different identifiers, but whose conditions are different.
+
+```
+# use yara_x_parser::dedupe::{Deduplicator, DuplicateKind};
+let mut deduplicator = Deduplicator::new();
+
+deduplicator.add_source("a.yar", r#"
+rule foo { strings: $a = "foo" condition: $a }
+"#).unwrap();
+
+deduplicator.add_source("b.yar", r#"
+rule bar {
+  strings:
+    $a = "foo"
+  condition:
+    $a
+}
+"#).unwrap();
+
+let clusters = deduplicator.clusters();
+
+assert_eq!(clusters.len(), 1);
+assert_eq!(clusters[0].kind, DuplicateKind::Exact);
+assert_eq!(clusters[0].rules[0].identifier, "foo");
+assert_eq!(clusters[0].rules[1].identifier, "bar");
+```
+*/
+
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use itertools::Itertools;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::ast::{Error, Rule, WithSpan, AST};
+
+#[cfg(test)]
+mod tests;
+
+/// The location of a rule in the source code.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct RuleLocation {
+    /// The origin of the source code containing the rule, as passed to
+    /// [`Deduplicator::add_source`]. Usually a file path.
+    pub origin: String,
+    /// The rule's identifier.
+    pub identifier: String,
+    /// Line where the rule's identifier is located (1-based).
+    pub line: usize,
+    /// Column where the rule's identifier is located (1-based).
+    pub column: usize,
+}
+
+/// Kinds of duplicates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum DuplicateKind {
+    /// Rules with the same logic.
+    Exact,
+    /// Rules with the same patterns but different conditions.
+    Near,
+}
+
+/// A group of rules that are duplicates of each other.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Cluster {
+    /// The kind of duplicates in this cluster.
+    pub kind: DuplicateKind,
+    /// The rules in the cluster, in the order they were added.
+    pub rules: Vec<RuleLocation>,
+}
+
+/// Information about each rule added to the [`Deduplicator`].
+struct Entry {
+    location: RuleLocation,
+    /// Hash of the rule's canonical form.
+    rule_hash: u64,
+    /// Hash of the canonical form of the rule's patterns, ignoring their
+    /// identifiers and order. `None` if the rule has no patterns.
+    patterns_hash: Option<u64>,
+}
+
+/// Finds duplicate and near-duplicate rules.
+///
+/// See the [module documentation][crate::dedupe] for details.
+#[derive(Default)]
+pub struct Deduplicator {
+    entries: Vec<Entry>,
+}
+
+impl Deduplicator {
+    /// Creates a new [`Deduplicator`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the rules in some source code.
+    ///
+    /// `origin` identifies the source code in the reported [`RuleLocation`]s,
+    /// usually it is a file path. Returns the first syntax error found in the
+    /// source code, if any, in which case none of its rules are added.
+    pub fn add_source(
+        &mut self,
+        origin: &str,
+        src: &str,
+    ) -> Result<(), Error> {
+        let ast = AST::from(src);
+
+        if !ast.errors().is_empty() {
+            return Err(ast.into_errors().remove(0));
+        }
+
+        for rule in ast.rules() {
+            let (line, column) =
+                rule.identifier.span().start_line_col(src).unwrap_or_default();
+
+            self.entries.push(Entry {
+                location: RuleLocation {
+                    origin: origin.to_string(),
+                    identifier: rule.identifier.name.to_string(),
+                    line,
+                    column,
+                },
+                rule_hash: hash(&rule.canonical_form()),
+                patterns_hash: patterns_hash(rule),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the clusters of duplicate rules.
+    ///
+    /// Each rule appears at most in one cluster of each kind. Clusters of
+    /// exact duplicates come first, followed by clusters of near duplicates.
+    /// A cluster of near duplicates is reported only when it contains rules
+    /// that are not exact duplicates of each other.
+    pub fn clusters(&self) -> Vec<Cluster> {
+        let exact = groups(self.entries.iter().map(|entry| entry.rule_hash))
+            .into_iter()
+            .filter(|group| group.len() > 1)
+            .map(|group| self.cluster(DuplicateKind::Exact, group));
+
+        let near =
+            groups(self.entries.iter().map(|entry| entry.patterns_hash))
+                .into_iter()
+                .filter(|group| {
+                    self.entries[group[0]].patterns_hash.is_some()
+                        && group
+                            .iter()
+                            .map(|i| self.entries[*i].rule_hash)
+                            .unique()
+                            .count()
+                            > 1
+                })
+                .map(|group| self.cluster(DuplicateKind::Near, group));
+
+        exact.chain(near).collect()
+    }
+
+    fn cluster(&self, kind: DuplicateKind, group: Vec<usize>) -> Cluster {
+        Cluster {
+            kind,
+            rules: group
+                .into_iter()
+                .map(|i| self.entries[i].location.clone())
+                .collect(),
+        }
+    }
+}
+
+/// Groups the indexes of the items that have the same key. Groups are
+/// returned in the order in which their first item appears.
+fn groups<K: Hash + Eq>(keys: impl Iterator<Item = K>) -> Vec<Vec<usize>> {
+    let mut group_index = HashMap::new();
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+
+    for (i, key) in keys.enumerate() {
+        let index = *group_index.entry(key).or_insert_with(|| {
+            groups.push(Vec::new());
+            groups.len() - 1
+        });
+        groups[index].push(i);
+    }
+
+    groups
+}
+
+fn hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Returns the hash of the canonical form of the rule's patterns, ignoring
+/// their identifiers and the order in which they are declared.
+fn patterns_hash(rule: &Rule) -> Option<u64> {
+    let patterns = rule.patterns.as_ref().filter(|p| !p.is_empty())?;
+    let canonical_forms: Vec<String> = patterns
+        .iter()
+        .map(|pattern| pattern.canonical_form())
+        .sorted()
+        .collect();
+
+    Some(hash(&canonical_forms))
+}
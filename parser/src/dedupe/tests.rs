@@ -0,0 +1,111 @@
+use pretty_assertions::assert_eq;
+
+use crate::dedupe::{Cluster, Deduplicator, DuplicateKind, RuleLocation};
+
+fn location(origin: &str, identifier: &str, line: usize) -> RuleLocation {
+    RuleLocation {
+        origin: origin.to_string(),
+        identifier: identifier.to_string(),
+        line,
+        column: 6,
+    }
+}
+
+#[test]
+fn dedupe() {
+    let mut deduplicator = Deduplicator::new();
+
+    deduplicator
+        .add_source(
+            "a.yar",
+            r#"rule a1 {
+  strings:
+    $a = "foo" ascii wide
+    $b = { 01 02 ?? 03 }
+  condition:
+    $a and #b > 2
+}
+
+rule a2 { condition: filesize < 100 or true }
+
+rule a3 { strings: $a = "foo" condition: $a }
+"#,
+        )
+        .unwrap();
+
+    deduplicator
+        .add_source(
+            "b.yar",
+            r#"// Same as a1, with patterns and operands reordered.
+rule b1 : tag {
+  meta:
+    author = "Jane Doe"
+  strings:
+    $b = { 01 02 ?? 03 }
+    $a = "\x66oo" wide ascii
+  condition:
+    #b > 2 and $a
+}
+
+// Same as a2.
+rule b2 { condition: true or filesize < 100 }
+
+// Same patterns as a1, but different condition.
+rule b3 {
+  strings:
+    $x = "foo" ascii wide
+    $y = { 01 02 ?? 03 }
+  condition:
+    $x or $y
+}
+
+// Similar to a3, but the pattern is different.
+rule b4 { strings: $a = "bar" condition: $a }
+"#,
+        )
+        .unwrap();
+
+    assert_eq!(
+        deduplicator.clusters(),
+        vec![
+            Cluster {
+                kind: DuplicateKind::Exact,
+                rules: vec![
+                    location("a.yar", "a1", 1),
+                    location("b.yar", "b1", 2)
+                ]
+            },
+            Cluster {
+                kind: DuplicateKind::Exact,
+                rules: vec![
+                    location("a.yar", "a2", 9),
+                    location("b.yar", "b2", 13)
+                ]
+            },
+            Cluster {
+                kind: DuplicateKind::Near,
+                rules: vec![
+                    location("a.yar", "a1", 1),
+                    location("b.yar", "b1", 2),
+                    location("b.yar", "b3", 16)
+                ]
+            },
+        ]
+    );
+}
+
+#[test]
+fn dedupe_syntax_error() {
+    let mut deduplicator = Deduplicator::new();
+
+    assert!(deduplicator
+        .add_source("a.yar", "rule a { condition: true }")
+        .is_ok());
+
+    assert!(deduplicator
+        .add_source("b.yar", "rule b { condition: true ")
+        .is_err());
+
+    // Rules from a source code with errors are not added.
+    assert!(deduplicator.clusters().is_empty());
+}
@@ -0,0 +1,277 @@
+/*! Extracts documentation from YARA rules.
+
+This module produces a [`RuleDoc`] for each rule in some YARA source code,
+containing the rule's metadata, tags, the comments that appear right before
+the rule, and the modules referenced in its condition. This information can
+be used for building catalogs of rules from large rule repositories.
+
+```
+# use yara_x_parser::doc::rule_docs;
+let docs = rule_docs(r#"
+import "pe"
+
+// Detects DLL files.
+rule is_dll : pe {
+  meta:
+    author = "Jane Doe"
+  condition:
+    pe.is_dll()
+}
+"#).unwrap();
+
+assert_eq!(docs[0].identifier, "is_dll");
+assert_eq!(docs[0].tags, vec!["pe"]);
+assert_eq!(docs[0].comments, vec!["Detects DLL files."]);
+assert_eq!(docs[0].modules, vec!["pe"]);
+```
+*/
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::{Display, Formatter};
+
+use bstr::ByteSlice;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::ast::dfs::{DFSContext, DFSEvent, DFSIter};
+use crate::ast::{self, Error, Expr, RuleFlags, WithSpan, AST};
+use crate::cst::{NodeOrToken, SyntaxKind, CST};
+use crate::Span;
+
+#[cfg(test)]
+mod tests;
+
+/// Documentation for a YARA rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct RuleDoc {
+    /// The rule's identifier.
+    pub identifier: String,
+    /// True if the rule is private.
+    pub private: bool,
+    /// True if the rule is global.
+    pub global: bool,
+    /// The rule's tags.
+    pub tags: Vec<String>,
+    /// The rule's metadata, in the order they appear in the source code.
+    pub meta: Vec<Meta>,
+    /// The comments that appear right before the rule declaration, without
+    /// the comment delimiters (`//`, `/*` and `*/`).
+    ///
+    /// Only comments that are not separated from the rule by an empty line
+    /// are included.
+    pub comments: Vec<String>,
+    /// The modules referenced in the rule's condition, sorted
+    /// alphabetically.
+    pub modules: Vec<String>,
+    /// The span of the whole rule declaration in the source code, including
+    /// the rule modifiers (`private`, `global`).
+    pub span: Span,
+}
+
+/// A metadata entry in a [`RuleDoc`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Meta {
+    /// The metadata identifier.
+    pub identifier: String,
+    /// The metadata value.
+    pub value: MetaValue,
+}
+
+/// The value of a metadata entry in a [`RuleDoc`].
+///
+/// Byte strings that are not valid UTF-8 are converted to [`MetaValue::String`],
+/// with invalid sequences replaced by the replacement character.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum MetaValue {
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+}
+
+impl Display for MetaValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bool(v) => write!(f, "{v}"),
+            Self::Integer(v) => write!(f, "{v}"),
+            Self::Float(v) => write!(f, "{v}"),
+            Self::String(v) => write!(f, "{v}"),
+        }
+    }
+}
+
+impl From<&ast::MetaValue<'_>> for MetaValue {
+    fn from(value: &ast::MetaValue<'_>) -> Self {
+        match value {
+            ast::MetaValue::Bool((v, _)) => Self::Bool(*v),
+            ast::MetaValue::Integer((v, _)) => Self::Integer(*v),
+            ast::MetaValue::Float((v, _)) => Self::Float(*v),
+            ast::MetaValue::String((v, _)) => Self::String(v.to_string()),
+            ast::MetaValue::Bytes((v, _)) => {
+                Self::String(v.to_str_lossy().into_owned())
+            }
+        }
+    }
+}
+
+/// Returns the documentation for all the rules in the given source code.
+///
+/// If the source code contains syntax errors, the first error is returned.
+pub fn rule_docs(src: &str) -> Result<Vec<RuleDoc>, Error> {
+    let ast = AST::from(src);
+
+    if !ast.errors().is_empty() {
+        return Err(ast.into_errors().remove(0));
+    }
+
+    let cst = CST::from(src);
+
+    // Comments and spans for each rule declaration in the CST, indexed by
+    // the span of the rule's identifier.
+    let mut rule_decls = HashMap::new();
+
+    for rule_decl in cst.root().children() {
+        if rule_decl.kind() != SyntaxKind::RULE_DECL {
+            continue;
+        }
+        let ident = rule_decl
+            .children_with_tokens()
+            .find(|n| n.kind() == SyntaxKind::IDENT)
+            .map(|n| n.span());
+
+        if let Some(ident) = ident {
+            rule_decls.insert(
+                ident,
+                (
+                    leading_comments(rule_decl.prev_sibling_or_token()),
+                    rule_decl.span(),
+                ),
+            );
+        }
+    }
+
+    let imports: Vec<&str> =
+        ast.imports().map(|import| import.module_name).collect();
+
+    let docs = ast
+        .rules()
+        .map(|rule| {
+            let (comments, span) = rule_decls
+                .remove(&rule.identifier.span())
+                .unwrap_or_else(|| (Vec::new(), rule.identifier.span()));
+
+            RuleDoc {
+                identifier: rule.identifier.name.to_string(),
+                private: rule.flags.contains(RuleFlags::Private),
+                global: rule.flags.contains(RuleFlags::Global),
+                tags: rule
+                    .tags
+                    .iter()
+                    .flatten()
+                    .map(|tag| tag.name.to_string())
+                    .collect(),
+                meta: rule
+                    .meta
+                    .iter()
+                    .flatten()
+                    .map(|meta| Meta {
+                        identifier: meta.identifier.name.to_string(),
+                        value: MetaValue::from(&meta.value),
+                    })
+                    .collect(),
+                comments,
+                modules: referenced_modules(&rule.condition, &imports),
+                span,
+            }
+        })
+        .collect();
+
+    Ok(docs)
+}
+
+/// Collects the comments that precede a rule declaration.
+///
+/// `prev` is the sibling that precedes the rule declaration. Comments are
+/// collected going backwards until some other node or token is found, or
+/// until an empty line is found.
+fn leading_comments<M: Clone>(
+    mut prev: Option<NodeOrToken<M>>,
+) -> Vec<String> {
+    let mut comments = Vec::new();
+    let mut newlines = 0;
+
+    while let Some(NodeOrToken::Token(token)) = prev {
+        match token.kind() {
+            SyntaxKind::WHITESPACE => {}
+            SyntaxKind::NEWLINE => {
+                newlines += 1;
+                if newlines > 1 {
+                    break;
+                }
+            }
+            SyntaxKind::COMMENT => {
+                newlines = 0;
+                comments.push(strip_comment(token.text()));
+            }
+            _ => break,
+        }
+        prev = token.prev_sibling_or_token();
+    }
+
+    comments.reverse();
+    comments
+}
+
+/// Removes the delimiters from a comment.
+///
+/// In multi-line comments, the leading `*` that is frequently used at the
+/// start of each line is removed too.
+fn strip_comment(comment: &str) -> String {
+    if let Some(comment) = comment.strip_prefix("//") {
+        return comment.trim().to_string();
+    }
+
+    let comment = comment.strip_prefix("/*").unwrap_or(comment);
+    let comment = comment.strip_suffix("*/").unwrap_or(comment);
+
+    comment
+        .lines()
+        .map(|line| line.trim().trim_start_matches('*').trim())
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+/// Returns the imported modules that are referenced in a rule condition.
+fn referenced_modules(condition: &Expr, imports: &[&str]) -> Vec<String> {
+    let mut modules = BTreeSet::new();
+
+    let mut iter = DFSIter::new(condition);
+
+    while let Some(event) = iter.next() {
+        if let DFSEvent::Enter(Expr::Ident(ident)) = event {
+            // In field accesses like `pe.sections`, only the first operand
+            // can be a module.
+            if let Some(DFSContext::Operand(Expr::FieldAccess(expr))) =
+                iter.contexts().next()
+            {
+                if !matches!(
+                    expr.operands.first(),
+                    Some(Expr::Ident(first)) if std::ptr::eq(first, ident)
+                ) {
+                    continue;
+                }
+            }
+            if imports.contains(&ident.name) {
+                modules.insert(ident.name.to_string());
+            }
+        }
+    }
+
+    modules.into_iter().collect()
+}
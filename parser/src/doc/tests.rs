@@ -0,0 +1,91 @@
+use pretty_assertions::assert_eq;
+
+use crate::doc::{rule_docs, Meta, MetaValue, RuleDoc};
+use crate::Span;
+
+#[test]
+fn rule_docs_1() {
+    let src = r#"import "pe"
+import "math"
+
+// Not a rule comment.
+
+// Detects DLL files.
+// Second line.
+private rule foo : bar baz {
+  meta:
+    author = "Jane Doe"
+    version = 2
+    score = 0.5
+    active = true
+  condition:
+    pe.is_dll() and pe.sections[0].name == "math"
+}
+
+/* Block
+ * comment. */ global rule bar { condition: math.entropy(0, 1) > 7 }
+
+rule baz { condition: true }
+"#;
+
+    assert_eq!(
+        rule_docs(src).unwrap(),
+        vec![
+            RuleDoc {
+                identifier: "foo".to_string(),
+                private: true,
+                global: false,
+                tags: vec!["bar".to_string(), "baz".to_string()],
+                meta: vec![
+                    Meta {
+                        identifier: "author".to_string(),
+                        value: MetaValue::String("Jane Doe".to_string()),
+                    },
+                    Meta {
+                        identifier: "version".to_string(),
+                        value: MetaValue::Integer(2),
+                    },
+                    Meta {
+                        identifier: "score".to_string(),
+                        value: MetaValue::Float(0.5),
+                    },
+                    Meta {
+                        identifier: "active".to_string(),
+                        value: MetaValue::Bool(true),
+                    },
+                ],
+                comments: vec![
+                    "Detects DLL files.".to_string(),
+                    "Second line.".to_string()
+                ],
+                modules: vec!["pe".to_string()],
+                span: Span(89..264),
+            },
+            RuleDoc {
+                identifier: "bar".to_string(),
+                private: false,
+                global: true,
+                tags: vec![],
+                meta: vec![],
+                comments: vec!["Block\ncomment.".to_string()],
+                modules: vec!["math".to_string()],
+                span: Span(290..343),
+            },
+            RuleDoc {
+                identifier: "baz".to_string(),
+                private: false,
+                global: false,
+                tags: vec![],
+                meta: vec![],
+                comments: vec![],
+                modules: vec![],
+                span: Span(345..373),
+            },
+        ]
+    );
+}
+
+#[test]
+fn rule_docs_error() {
+    assert!(rule_docs("rule foo { condition: }").is_err());
+}
@@ -0,0 +1,248 @@
+/*! Classifies the tokens in YARA source code for syntax highlighting.
+
+This module provides a way of obtaining the tokens in some YARA source code,
+together with their [`TokenClass`] and their [`Span`]. The classification is
+performed on top of the [`CST`], therefore it knows, for instance, that an
+identifier that follows the `rule` keyword is a rule name, and that an
+identifier followed by a list of arguments is a function call. This makes it
+suitable for editors, and for rendering rules as HTML.
+
+```
+# use yara_x_parser::highlight::{tokens, TokenClass};
+let src = "rule test { condition: true }";
+let mut tokens = tokens(src);
+let token = tokens.next().unwrap();
+assert_eq!(token.class, TokenClass::Keyword);
+assert_eq!(&src[token.span.range()], "rule");
+let token = tokens.next().unwrap();
+assert_eq!(token.class, TokenClass::Rule);
+assert_eq!(&src[token.span.range()], "test");
+```
+*/
+
+use std::fmt::Write;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::cst::{SyntaxKind, Token, CST};
+use crate::Span;
+
+#[cfg(test)]
+mod tests;
+
+/// The class of a token.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[non_exhaustive]
+pub enum TokenClass {
+    /// Keywords like `rule`, `condition`, `and`, `wide`, etc.
+    Keyword,
+    /// Arithmetic, bitwise and comparison operators.
+    Operator,
+    /// Integer and float literals.
+    Number,
+    /// String literals.
+    String,
+    /// Regular expressions.
+    Regexp,
+    /// Comments.
+    Comment,
+    /// The name of a rule in a rule declaration.
+    Rule,
+    /// Pattern identifiers, like `$a`, `#a`, `@a` and `!a`.
+    Pattern,
+    /// The name of a function in a function call.
+    Function,
+    /// Any other identifier.
+    Identifier,
+}
+
+impl TokenClass {
+    /// Returns the name of the class as a lowercase string.
+    ///
+    /// This is the name used in the `class` attribute of the `<span>`
+    /// elements produced by [`to_html`].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TokenClass::Keyword => "keyword",
+            TokenClass::Operator => "operator",
+            TokenClass::Number => "number",
+            TokenClass::String => "string",
+            TokenClass::Regexp => "regexp",
+            TokenClass::Comment => "comment",
+            TokenClass::Rule => "rule",
+            TokenClass::Pattern => "pattern",
+            TokenClass::Function => "function",
+            TokenClass::Identifier => "identifier",
+        }
+    }
+}
+
+/// A classified token.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct HighlightedToken {
+    /// The class of the token.
+    pub class: TokenClass,
+    /// The span of the token within the source code.
+    pub span: Span,
+    /// True if the token is the place where a pattern or a variable is
+    /// defined, like `$a` in `$a = "foo"`, or `i` in `with i = 1 : (...)`.
+    pub definition: bool,
+}
+
+/// Returns the classified tokens in the given source code.
+///
+/// Tokens that don't belong to any class, like whitespaces, newlines and
+/// punctuation, are not returned.
+pub fn tokens(src: &str) -> impl Iterator<Item = HighlightedToken> {
+    let cst = CST::from(src);
+    std::iter::successors(cst.root().first_token(), |token| token.next_token())
+        .filter_map(|token| classify(&token))
+}
+
+/// Classifies a single token from a [`CST`].
+///
+/// Returns `None` if the token doesn't belong to any class.
+pub fn classify<M: Clone>(token: &Token<M>) -> Option<HighlightedToken> {
+    let (class, definition) = match token.kind() {
+        SyntaxKind::ALL_KW
+        | SyntaxKind::AND_KW
+        | SyntaxKind::ANY_KW
+        | SyntaxKind::AS_KW
+        | SyntaxKind::ASCII_KW
+        | SyntaxKind::AT_KW
+        | SyntaxKind::BASE64_KW
+        | SyntaxKind::BASE64WIDE_KW
+        | SyntaxKind::CONDITION_KW
+        | SyntaxKind::CONTAINS_KW
+        | SyntaxKind::DEFINED_KW
+        | SyntaxKind::ENDSWITH_KW
+        | SyntaxKind::ENTRYPOINT_KW
+        | SyntaxKind::FALSE_KW
+        | SyntaxKind::FILESIZE_KW
+        | SyntaxKind::FOR_KW
+        | SyntaxKind::FULLWORD_KW
+        | SyntaxKind::GLOBAL_KW
+        | SyntaxKind::ICONTAINS_KW
+        | SyntaxKind::IENDSWITH_KW
+        | SyntaxKind::IEQUALS_KW
+        | SyntaxKind::IMPORT_KW
+        | SyntaxKind::IN_KW
+        | SyntaxKind::INCLUDE_KW
+        | SyntaxKind::ISTARTSWITH_KW
+        | SyntaxKind::MATCHES_KW
+        | SyntaxKind::META_KW
+        | SyntaxKind::NOCASE_KW
+        | SyntaxKind::NONE_KW
+        | SyntaxKind::NOT_KW
+        | SyntaxKind::OF_KW
+        | SyntaxKind::OR_KW
+        | SyntaxKind::PRIVATE_KW
+        | SyntaxKind::RULE_KW
+        | SyntaxKind::STARTSWITH_KW
+        | SyntaxKind::STRINGS_KW
+        | SyntaxKind::THEM_KW
+        | SyntaxKind::TRUE_KW
+        | SyntaxKind::WIDE_KW
+        | SyntaxKind::XOR_KW
+        | SyntaxKind::WITH_KW => (TokenClass::Keyword, false),
+
+        SyntaxKind::ADD
+        | SyntaxKind::SUB
+        | SyntaxKind::MUL
+        | SyntaxKind::DIV
+        | SyntaxKind::MOD
+        | SyntaxKind::MINUS
+        | SyntaxKind::SHL
+        | SyntaxKind::SHR
+        | SyntaxKind::BITWISE_AND
+        | SyntaxKind::BITWISE_OR
+        | SyntaxKind::BITWISE_XOR
+        | SyntaxKind::BITWISE_NOT
+        | SyntaxKind::EQ
+        | SyntaxKind::NE
+        | SyntaxKind::LT
+        | SyntaxKind::LE
+        | SyntaxKind::GT
+        | SyntaxKind::GE => (TokenClass::Operator, false),
+
+        SyntaxKind::INTEGER_LIT | SyntaxKind::FLOAT_LIT => {
+            (TokenClass::Number, false)
+        }
+
+        SyntaxKind::STRING_LIT => (TokenClass::String, false),
+        SyntaxKind::REGEXP => (TokenClass::Regexp, false),
+        SyntaxKind::COMMENT => (TokenClass::Comment, false),
+
+        SyntaxKind::IDENT => {
+            match token.parent().map(|parent| parent.kind()) {
+                Some(SyntaxKind::RULE_DECL) => (TokenClass::Rule, false),
+                Some(SyntaxKind::FUNC_CALL) => (TokenClass::Function, false),
+                Some(SyntaxKind::WITH_DECL) => (TokenClass::Identifier, true),
+                _ => (TokenClass::Identifier, false),
+            }
+        }
+
+        SyntaxKind::PATTERN_IDENT => (
+            TokenClass::Pattern,
+            token.parent().map(|parent| parent.kind())
+                == Some(SyntaxKind::PATTERN_DEF),
+        ),
+
+        SyntaxKind::PATTERN_COUNT
+        | SyntaxKind::PATTERN_OFFSET
+        | SyntaxKind::PATTERN_LENGTH => (TokenClass::Pattern, false),
+
+        _ => return None,
+    };
+
+    Some(HighlightedToken { class, span: token.span(), definition })
+}
+
+/// Renders the given source code as HTML.
+///
+/// Each classified token is wrapped in a `<span>` element with a `class`
+/// attribute of the form `yr-<class>`, where `<class>` is the value returned
+/// by [`TokenClass::as_str`]. The rest of the source code is copied verbatim,
+/// escaping the characters that have special meaning in HTML. The result is
+/// not wrapped in any enclosing element, so it can be embedded in a `<pre>`
+/// element or any other container.
+///
+/// ```
+/// # use yara_x_parser::highlight::to_html;
+/// assert_eq!(
+///     to_html("rule a { condition: true }"),
+///     r#"<span class="yr-keyword">rule</span> <span class="yr-rule">a</span> { <span class="yr-keyword">condition</span>: <span class="yr-keyword">true</span> }"#
+/// );
+/// ```
+pub fn to_html(src: &str) -> String {
+    let mut html = String::with_capacity(src.len() * 2);
+    let mut pos = 0;
+
+    for token in tokens(src) {
+        escape_html(&mut html, &src[pos..token.span.start()]);
+        write!(html, r#"<span class="yr-{}">"#, token.class.as_str()).unwrap();
+        escape_html(&mut html, &src[token.span.range()]);
+        html.push_str("</span>");
+        pos = token.span.end();
+    }
+
+    escape_html(&mut html, &src[pos..]);
+    html
+}
+
+fn escape_html(html: &mut String, s: &str) {
+    for c in s.chars() {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#39;"),
+            c => html.push(c),
+        }
+    }
+}
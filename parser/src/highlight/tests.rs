@@ -0,0 +1,78 @@
+use crate::highlight::{to_html, tokens, TokenClass};
+
+#[test]
+fn highlight_tokens() {
+    let src = r#"import "pe"
+// comment
+rule test : tag {
+  strings:
+    $a = "foo"
+    $b = /bar/
+  condition:
+    #a > 2 and @a[1] == pe.rva_to_offset(0x10) and !b[1] <= 3.5 and
+    for any i in (1..2) : ($b at i)
+}"#;
+
+    let tokens: Vec<_> = tokens(src)
+        .map(|token| (token.class, &src[token.span.range()], token.definition))
+        .collect();
+
+    assert_eq!(
+        tokens,
+        vec![
+            (TokenClass::Keyword, "import", false),
+            (TokenClass::String, r#""pe""#, false),
+            (TokenClass::Comment, "// comment", false),
+            (TokenClass::Keyword, "rule", false),
+            (TokenClass::Rule, "test", false),
+            (TokenClass::Identifier, "tag", false),
+            (TokenClass::Keyword, "strings", false),
+            (TokenClass::Pattern, "$a", true),
+            (TokenClass::String, r#""foo""#, false),
+            (TokenClass::Pattern, "$b", true),
+            (TokenClass::Regexp, "/bar/", false),
+            (TokenClass::Keyword, "condition", false),
+            (TokenClass::Pattern, "#a", false),
+            (TokenClass::Operator, ">", false),
+            (TokenClass::Number, "2", false),
+            (TokenClass::Keyword, "and", false),
+            (TokenClass::Pattern, "@a", false),
+            (TokenClass::Number, "1", false),
+            (TokenClass::Operator, "==", false),
+            (TokenClass::Identifier, "pe", false),
+            (TokenClass::Function, "rva_to_offset", false),
+            (TokenClass::Number, "0x10", false),
+            (TokenClass::Keyword, "and", false),
+            (TokenClass::Pattern, "!b", false),
+            (TokenClass::Number, "1", false),
+            (TokenClass::Operator, "<=", false),
+            (TokenClass::Number, "3.5", false),
+            (TokenClass::Keyword, "and", false),
+            (TokenClass::Keyword, "for", false),
+            (TokenClass::Keyword, "any", false),
+            (TokenClass::Identifier, "i", false),
+            (TokenClass::Keyword, "in", false),
+            (TokenClass::Number, "1", false),
+            (TokenClass::Number, "2", false),
+            (TokenClass::Pattern, "$b", false),
+            (TokenClass::Keyword, "at", false),
+            (TokenClass::Identifier, "i", false),
+        ]
+    );
+}
+
+#[test]
+fn highlight_html() {
+    assert_eq!(
+        to_html(r#"rule a { strings: $a = "<&>" condition: $a }"#),
+        concat!(
+            r#"<span class="yr-keyword">rule</span> "#,
+            r#"<span class="yr-rule">a</span> { "#,
+            r#"<span class="yr-keyword">strings</span>: "#,
+            r#"<span class="yr-pattern">$a</span> = "#,
+            r#"<span class="yr-string">&quot;&lt;&amp;&gt;&quot;</span> "#,
+            r#"<span class="yr-keyword">condition</span>: "#,
+            r#"<span class="yr-pattern">$a</span> }"#,
+        )
+    );
+}
@@ -31,10 +31,16 @@ use serde::Serialize;
 
 pub mod ast;
 pub mod cst;
+pub mod dedupe;
+pub mod doc;
+pub mod highlight;
 
 mod parser;
 mod tokenizer;
 
+#[cfg(test)]
+mod tests;
+
 /// Starting and ending positions of some token inside the source code.
 #[derive(Default, Clone, Debug, Hash, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
@@ -169,4 +175,88 @@ impl Span {
         }
         self
     }
+
+    /// Returns the line and column where the span starts within `src`.
+    ///
+    /// `src` must be the source code the span refers to. Both the line and
+    /// the column are 1-based, and the column is counted in characters, not
+    /// bytes. Returns `None` if the span is out of the bounds of `src`, or
+    /// if it starts in the middle of a multi-byte character.
+    ///
+    /// ```
+    /// # use yara_x_parser::Span;
+    /// let src = "rule test {\n  condition: true\n}";
+    /// assert_eq!(Span(0..4).start_line_col(src), Some((1, 1)));
+    /// assert_eq!(Span(25..29).start_line_col(src), Some((2, 14)));
+    /// assert_eq!(Span(50..52).start_line_col(src), None);
+    /// ```
+    pub fn start_line_col(&self, src: &str) -> Option<(usize, usize)> {
+        byte_offset_to_line_col(src, self.start())
+    }
+
+    /// Returns the line and column where the span ends within `src`.
+    ///
+    /// The returned position is the one immediately after the last character
+    /// in the span. See [`Span::start_line_col`] for details.
+    ///
+    /// ```
+    /// # use yara_x_parser::Span;
+    /// let src = "rule test {\n  condition: true\n}";
+    /// assert_eq!(Span(0..4).end_line_col(src), Some((1, 5)));
+    /// assert_eq!(Span(25..29).end_line_col(src), Some((2, 18)));
+    /// ```
+    pub fn end_line_col(&self, src: &str) -> Option<(usize, usize)> {
+        byte_offset_to_line_col(src, self.end())
+    }
+
+    /// Returns the fragment of `src` covered by the span.
+    ///
+    /// Returns `None` if the span is out of the bounds of `src`, or if it
+    /// doesn't start and end at character boundaries.
+    ///
+    /// ```
+    /// # use yara_x_parser::Span;
+    /// let src = "rule test {\n  condition: true\n}";
+    /// assert_eq!(Span(5..9).snippet(src), Some("test"));
+    /// assert_eq!(Span(30..35).snippet(src), None);
+    /// ```
+    pub fn snippet<'src>(&self, src: &'src str) -> Option<&'src str> {
+        src.get(self.range())
+    }
+}
+
+/// Given a text slice and a position indicated as a byte offset, returns
+/// the same position as a (line, column) pair.
+fn byte_offset_to_line_col(
+    text: &str,
+    byte_offset: usize,
+) -> Option<(usize, usize)> {
+    // Check if the byte_offset is valid
+    if byte_offset > text.len() {
+        return None; // Out of bounds
+    }
+
+    let mut line = 1;
+    let mut col = 1;
+
+    // Iterate through the characters (not bytes) in the string
+    for (i, c) in text.char_indices() {
+        if i == byte_offset {
+            return Some((line, col));
+        }
+        if c == '\n' {
+            line += 1;
+            col = 1; // Reset column to 1 after a newline
+        } else {
+            col += 1;
+        }
+    }
+
+    // If the byte_offset points to the last byte of the string, return the
+    // final position
+    if byte_offset == text.len() {
+        return Some((line, col));
+    }
+
+    None
 }
@@ -76,6 +76,16 @@ impl<'src> Parser<'src> {
         CST::try_from(self)
     }
 
+    /// Parses `data` into both a CST and an AST, discarding the results.
+    ///
+    /// This is the entry point used by fuzz targets. It must not panic,
+    /// whatever the input is, including invalid UTF-8.
+    #[doc(hidden)]
+    pub fn parse_fuzz(data: &[u8]) {
+        let _ = Parser::new(data).try_into_cst();
+        let _ = AST::from(Parser::new(data));
+    }
+
     /// Consumes the parser and returns a Concrete Syntax Tree (CST) as
     /// a stream of events.
     #[inline]
@@ -451,6 +461,11 @@ impl<'src> ParserImpl<'src> {
     /// If the parser is in [`State::Failure`], it will go back to [`State::OK`].
     /// Any token that is not in `recovery_set` will be consumed in put into an
     /// `ERROR` node, regardless of the initial parser state.
+    ///
+    /// Tokens that can only appear at the start of a top-level item (`rule`,
+    /// `global`, `import` and `include`) are never consumed, even if they are
+    /// not in `recovery_set`. This guarantees that an error in some rule, like
+    /// a missing closing brace, doesn't swallow the rules that come after it.
     fn recover(&mut self, recovery_set: &'static TokenSet) -> &mut Self {
         // If parser is out-of-fuel there's nothing to recover from.
         if matches!(self.state, State::OutOfFuel) {
@@ -469,6 +484,7 @@ impl<'src> ParserImpl<'src> {
         let token_span = token.span();
         let token_id = token.id();
         let token_in_recovery_set = recovery_set.contains(token).is_some();
+        let token_starts_item = TOP_LEVEL_ITEM_START.contains(token).is_some();
 
         // If no previous error exists, create an error that tells that we are
         // expecting any of the tokens in the recovery set.
@@ -489,14 +505,17 @@ impl<'src> ParserImpl<'src> {
             self.handle_errors();
         }
 
-        // Consume any token that is not in the recovery set.
-        if !token_in_recovery_set {
+        // Consume any token that is not in the recovery set, stopping at the
+        // start of the next top-level item.
+        if !token_in_recovery_set && !token_starts_item {
             self.trivia();
             self.begin(ERROR);
             self.bump();
 
             while let Some(token) = self.peek_non_trivia() {
-                if recovery_set.contains(token).is_some() {
+                if recovery_set.contains(token).is_some()
+                    || TOP_LEVEL_ITEM_START.contains(token).is_some()
+                {
                     break;
                 } else {
                     self.trivia();
@@ -1070,12 +1089,13 @@ impl ParserImpl<'_> {
     /// Parses an import statement.
     ///
     /// ```text
-    /// IMPORT_STMT ::= `import` STRING_LIT
+    /// IMPORT_STMT ::= `import` STRING_LIT (`as` IDENT)?
     /// ```
     fn import_stmt(&mut self) -> &mut Self {
         self.begin(IMPORT_STMT)
             .expect(t!(IMPORT_KW))
             .expect(t!(STRING_LIT))
+            .if_next(t!(AS_KW), |p| p.expect(t!(AS_KW)).expect(t!(IDENT)))
             .end()
     }
 
@@ -1810,6 +1830,11 @@ struct Bookmark {
 #[derive(Debug)]
 struct TokenSet(&'static [SyntaxKind]);
 
+/// Tokens that can appear only at the start of a top-level item. `private`
+/// is not included because it is also a pattern modifier.
+const TOP_LEVEL_ITEM_START: &TokenSet =
+    &TokenSet(&[GLOBAL_KW, RULE_KW, IMPORT_KW, INCLUDE_KW]);
+
 impl TokenSet {
     #[inline]
     fn is_empty(&self) -> bool {
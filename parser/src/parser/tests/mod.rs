@@ -1,4 +1,4 @@
-use crate::ast::{Error, AST};
+use crate::ast::{Error, Fix, AST};
 use rayon::prelude::*;
 use std::fs;
 use std::io::BufWriter;
@@ -76,6 +76,14 @@ fn ast() {
     });
 }
 
+#[test]
+fn parse_fuzz() {
+    Parser::parse_fuzz(b"");
+    Parser::parse_fuzz(b"rule test { condition: true }");
+    Parser::parse_fuzz(b"rule test_1 { \xFF\xFF condition: ");
+    Parser::parse_fuzz(&[b'('; 10000]);
+}
+
 #[test]
 fn utf8_error_1() {
     // Invalid UTF-8 anywhere.
@@ -106,7 +114,16 @@ rule test_2 { condition: true }";
 
     let ast = AST::from(rules.as_slice());
 
-    assert_eq!(&ast.errors()[0], &Error::InvalidUTF8(Span(27..28)));
+    assert_eq!(
+        &ast.errors()[0],
+        &Error::InvalidUTF8 {
+            span: Span(27..28),
+            fix: Some(Fix {
+                span: Span(26..30),
+                replacement: r#""\xFF\xFF""#.to_string()
+            })
+        }
+    );
 
     // The second rule is correctly parsed because it doesn't have any errors.
     assert_eq!(ast.rules().count(), 1);
@@ -132,6 +149,28 @@ rule test_2 { condition: true }";
 
     let ast = AST::from(rules.as_slice());
 
-    assert_eq!(&ast.errors()[0], &Error::InvalidUTF8(Span(33..34)));
+    assert_eq!(
+        &ast.errors()[0],
+        &Error::InvalidUTF8 { span: Span(33..34), fix: None }
+    );
     assert_eq!(ast.rules().count(), 1);
 }
+
+#[test]
+fn escape_error_fix() {
+    let rules = br#"
+rule test_1 { strings: $a = "foo\qbar" condition: $a }
+rule test_2 { strings: $a = "foo\xZZ" condition: $a }"#;
+
+    let ast = AST::from(rules.as_slice());
+
+    assert_eq!(
+        ast.errors()[0].fix(),
+        Some(&Fix { span: Span(33..35), replacement: r"\\q".to_string() })
+    );
+
+    assert_eq!(
+        ast.errors()[1].fix(),
+        Some(&Fix { span: Span(88..90), replacement: r"\\x".to_string() })
+    );
+}
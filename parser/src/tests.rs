@@ -0,0 +1,46 @@
+use crate::byte_offset_to_line_col;
+
+#[test]
+fn byte_offset_to_line_col_single_line() {
+    let text = "Hello, World!";
+    assert_eq!(byte_offset_to_line_col(text, 0), Some((1, 1))); // Start of the string
+    assert_eq!(byte_offset_to_line_col(text, 7), Some((1, 8))); // Byte offset of 'W'
+    assert_eq!(byte_offset_to_line_col(text, 12), Some((1, 13))); // Byte offset of '!'
+}
+
+#[test]
+fn byte_offset_to_line_col_multiline() {
+    let text = "Hello\nRust\nWorld!";
+    assert_eq!(byte_offset_to_line_col(text, 0), Some((1, 1))); // First character
+    assert_eq!(byte_offset_to_line_col(text, 5), Some((1, 6))); // End of first line (newline)
+    assert_eq!(byte_offset_to_line_col(text, 6), Some((2, 1))); // Start of second line ('R')
+    assert_eq!(byte_offset_to_line_col(text, 9), Some((2, 4))); // Byte offset of 't' in "Rust"
+    assert_eq!(byte_offset_to_line_col(text, 11), Some((3, 1))); // Start of third line ('W')
+}
+
+#[test]
+fn byte_offset_to_line_col_empty_string() {
+    let text = "";
+    assert_eq!(byte_offset_to_line_col(text, 0), Some((1, 1)));
+}
+
+#[test]
+fn byte_offset_to_line_col_out_of_bounds() {
+    let text = "Hello, World!";
+    assert_eq!(byte_offset_to_line_col(text, text.len() + 1), None);
+}
+
+#[test]
+fn byte_offset_to_line_col_end_of_string() {
+    let text = "Hello, World!";
+    assert_eq!(byte_offset_to_line_col(text, text.len()), Some((1, 14))); // Last position after '!'
+}
+
+#[test]
+fn byte_offset_to_line_col_multibyte_characters() {
+    let text = "Hello, 你好!";
+    assert_eq!(byte_offset_to_line_col(text, 7), Some((1, 8))); // Position of '你'
+    assert_eq!(byte_offset_to_line_col(text, 8), None); // Position in the middle of '你'
+    assert_eq!(byte_offset_to_line_col(text, 10), Some((1, 9))); // Position of '好'
+    assert_eq!(byte_offset_to_line_col(text, 13), Some((1, 10))); // Position of '!'
+}
@@ -257,6 +257,8 @@ enum NormalToken<'src> {
     And,
     #[token("any")]
     Any,
+    #[token("as")]
+    As,
     #[token("ascii")]
     Ascii,
     #[token("at")]
@@ -664,6 +666,7 @@ fn convert_normal_token(token: NormalToken, span: Span) -> Token {
         NormalToken::All => Token::ALL_KW(span),
         NormalToken::And => Token::AND_KW(span),
         NormalToken::Any => Token::ANY_KW(span),
+        NormalToken::As => Token::AS_KW(span),
         NormalToken::Ascii => Token::ASCII_KW(span),
         NormalToken::At => Token::AT_KW(span),
         NormalToken::Base64 => Token::BASE64_KW(span),
@@ -10,6 +10,7 @@ pub(crate) enum TokenId {
     ALL_KW,
     AND_KW,
     ANY_KW,
+    AS_KW,
     ASCII_KW,
     AT_KW,
     BASE64_KW,
@@ -120,6 +121,7 @@ impl TokenId {
             TokenId::ALL_KW => "`all`",
             TokenId::AND_KW => "`and`",
             TokenId::ANY_KW => "`any`",
+            TokenId::AS_KW => "`as`",
             TokenId::ASCII_KW => "`ascii`",
             TokenId::AT_KW => "`at`",
             TokenId::BASE64_KW => "`base64`",
@@ -221,6 +223,7 @@ pub(crate) enum Token {
     ALL_KW(Span) = TokenId::ALL_KW as u8,
     AND_KW(Span) = TokenId::AND_KW as u8,
     ANY_KW(Span) = TokenId::ANY_KW as u8,
+    AS_KW(Span) = TokenId::AS_KW as u8,
     ASCII_KW(Span) = TokenId::ASCII_KW as u8,
     AT_KW(Span) = TokenId::AT_KW as u8,
     BASE64_KW(Span) = TokenId::BASE64_KW as u8,
@@ -341,6 +344,7 @@ impl Token {
             Token::ALL_KW(span)
             | Token::AND_KW(span)
             | Token::ANY_KW(span)
+            | Token::AS_KW(span)
             | Token::ASCII_KW(span)
             | Token::AT_KW(span)
             | Token::BASE64_KW(span)
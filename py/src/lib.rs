@@ -70,6 +70,18 @@ enum SupportedModules {
     Crx,
     #[cfg(feature = "dex-module")]
     Dex,
+    #[cfg(feature = "regf-module")]
+    Regf,
+    #[cfg(feature = "email-module")]
+    Email,
+    #[cfg(feature = "x509-module")]
+    X509,
+    #[cfg(feature = "iso9660-module")]
+    Iso9660,
+    #[cfg(feature = "wasm-module")]
+    Wasm,
+    #[cfg(feature = "pcap-module")]
+    Pcap,
 }
 
 /// Formats YARA rules.
@@ -325,6 +337,30 @@ impl Module {
                 SupportedModules::Dex => {
                     yrx::mods::invoke_dyn::<yrx::mods::Dex>(data)
                 }
+                #[cfg(feature = "regf-module")]
+                SupportedModules::Regf => {
+                    yrx::mods::invoke_dyn::<yrx::mods::Regf>(data)
+                }
+                #[cfg(feature = "email-module")]
+                SupportedModules::Email => {
+                    yrx::mods::invoke_dyn::<yrx::mods::Email>(data)
+                }
+                #[cfg(feature = "x509-module")]
+                SupportedModules::X509 => {
+                    yrx::mods::invoke_dyn::<yrx::mods::X509>(data)
+                }
+                #[cfg(feature = "iso9660-module")]
+                SupportedModules::Iso9660 => {
+                    yrx::mods::invoke_dyn::<yrx::mods::Iso9660>(data)
+                }
+                #[cfg(feature = "wasm-module")]
+                SupportedModules::Wasm => {
+                    yrx::mods::invoke_dyn::<yrx::mods::Wasm>(data)
+                }
+                #[cfg(feature = "pcap-module")]
+                SupportedModules::Pcap => {
+                    yrx::mods::invoke_dyn::<yrx::mods::Pcap>(data)
+                }
                 _ => return Ok(py.None().into_bound(py)),
             };
 